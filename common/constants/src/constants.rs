@@ -10,6 +10,9 @@ pub const MILLISECONDS_PER_YEAR: u64 = 31_556_926_000;
 
 pub const SECONDS_PER_MINUTE: u64 = 60;
 
+/// Maximum interval a market can skip index recomputation for between syncs (1 hour)
+pub const MAX_MIN_ACCRUAL_INTERVAL_MS: u64 = 3_600_000;
+
 pub const RAY: u128 = 1_000_000_000_000_000_000_000_000_000;
 pub const DOUBLE_RAY: u128 = 2_000_000_000_000_000_000_000_000_000;
 pub const RAY_PRECISION: usize = 27;
@@ -34,4 +37,22 @@ pub const MIN_LAST_TOLERANCE: usize = 150;
 /// Maximum last tolerance for oracle price fluctuation (100%)
 pub const MAX_LAST_TOLERANCE: usize = BPS;
 
+/// Maximum number of independent price sources that can be required to agree (aggregator, safe, derived)
+pub const MAX_ORACLE_QUORUM: u8 = 3;
+
+/// Number of safe price rounds averaged by the TWAP fallback unless configured otherwise
+pub const DEFAULT_TWAP_ROUNDS: u8 = 4;
+
+/// Maximum number of safe price rounds the TWAP fallback can average
+pub const MAX_TWAP_ROUNDS: u8 = 12;
+
+/// Maximum number of recent activity entries a market can retain on-chain
+pub const MAX_MARKET_ACTIVITY_CAPACITY: usize = 50;
+
+/// Number of most recent liquidations retained on-chain per account
+pub const LIQUIDATION_HISTORY_CAPACITY: usize = 10;
+
+/// Maximum number of account NFTs a single batch strategy call can operate on
+pub const MAX_BATCH_ACCOUNTS: usize = 10;
+
 pub const BASE_NFT_URI: &[u8] = b"https://api.xoxno.com/user/lending/image";
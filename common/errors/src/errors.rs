@@ -128,6 +128,10 @@ pub static ERROR_OPTIMAL_UTILIZATION_TOO_HIGH: &[u8] =
     b"Optimal utilization invalid: must be less than 1.0.";
 pub static ERROR_INVALID_RESERVE_FACTOR: &[u8] =
     b"Reserve factor invalid: must be less than 10000.";
+pub static ERROR_INVALID_MIN_ACCRUAL_INTERVAL: &[u8] =
+    b"Min accrual interval invalid: must be at most one hour.";
+pub static ERROR_INVALID_MAX_ACCRUAL_RATE: &[u8] =
+    b"Max accrual rate invalid: must be greater than base_borrow_rate and at most max_borrow_rate.";
 pub static ERROR_INVALID_ONEDEX_PAIR_ID: &[u8] = b"Invalid onedex pair id.";
 
 pub static ERROR_WRONG_TOKEN: &[u8] = b"Wrong received token.";
@@ -153,3 +157,123 @@ pub static ERROR_POSITION_LIMIT_EXCEEDED: &[u8] =
     b"Position limit exceeded. Maximum positions per NFT reached.";
 
 pub static ERROR_NO_DEBT_PAYMENTS_TO_PROCESS: &[u8] = b"No debt payments to process.";
+
+pub static ERROR_ACCOUNT_HAS_OUTSTANDING_DEBT: &[u8] =
+    b"Account still has outstanding debt and cannot be closed.";
+
+pub static ERROR_ACCOUNT_HAS_OPEN_POSITIONS: &[u8] =
+    b"Account NFT cannot be burned while deposit or borrow positions remain.";
+
+pub static ERROR_ACCOUNT_NOT_HELD_BY_BENEFICIARY: &[u8] =
+    b"Account is not held by the beneficiary.";
+
+pub static ERROR_INVALID_LIQUIDATEE_REBATE: &[u8] =
+    b"Liquidatee rebate invalid: must be less than or equal to 10000.";
+
+pub static ERROR_INVALID_MIN_STRATEGY_HEALTH_FACTOR: &[u8] =
+    b"Minimum strategy health factor invalid: must be greater than or equal to 1.0.";
+
+pub static ERROR_INVALID_MIN_RESERVE_RATIO: &[u8] =
+    b"Minimum reserve ratio invalid: must be less than or equal to 10000.";
+
+pub static ERROR_MIN_RESERVE_RATIO: &[u8] =
+    b"Borrow exceeds the market utilization allowed by its minimum reserve ratio.";
+
+pub static ERROR_AUTO_COMPOUND_NOT_ENABLED: &[u8] =
+    b"Auto-compounding is not enabled for this account.";
+
+pub static ERROR_INVALID_MAX_LIQUIDATION_PRICE_DROP: &[u8] =
+    b"Max liquidation price drop invalid: must be less than or equal to 10000.";
+
+pub static ERROR_INVALID_ORACLE_QUORUM: &[u8] =
+    b"Oracle quorum invalid: must be less than or equal to 3.";
+
+pub static ERROR_ORACLE_QUORUM_NOT_MET: &[u8] =
+    b"Not enough oracle price sources agree within tolerance.";
+
+pub static ERROR_INITIAL_SUPPLY_TOO_LOW: &[u8] =
+    b"First supply must exceed the minimum initial supply of the market.";
+
+pub static ERROR_INVALID_EGLD_MAX_PRICE_STALE_SECONDS: &[u8] =
+    b"EGLD reference price max age must be greater than zero.";
+
+pub static ERROR_INVALID_PRICE_STALENESS_OVERRIDE: &[u8] =
+    b"Price staleness override must expire in the future.";
+
+pub static ERROR_BORROW_ALLOWANCE_EXCEEDED: &[u8] =
+    b"Borrow exceeds the borrow allowance of the account.";
+
+pub static ERROR_INVALID_MARKET_ACTIVITY_CAPACITY: &[u8] =
+    b"Market activity capacity invalid: must be less than or equal to 50.";
+
+pub static ERROR_POST_LIQUIDATION_GRACE: &[u8] =
+    b"Account was recently liquidated and is within its post-liquidation grace period.";
+
+pub static ERROR_INVALID_MAX_BATCH_ACCOUNTS: &[u8] =
+    b"Max batch accounts invalid: must be less than or equal to 10.";
+
+pub static ERROR_BATCH_ACCOUNTS_LIMIT_EXCEEDED: &[u8] =
+    b"Number of account NFTs exceeds the batch accounts limit.";
+
+pub static ERROR_INVALID_DELEVERAGE_FRACTION: &[u8] =
+    b"Deleverage fraction invalid: must be greater than 0 and less than or equal to 10000.";
+
+pub static ERROR_DELEVERAGE_SWAP_SHORTFALL: &[u8] =
+    b"Swapped collateral and payments do not cover the deleveraged debt.";
+
+pub static ERROR_REPAY_SWAP_NO_OUTPUT: &[u8] = b"Swap returned no debt token to repay.";
+
+pub static ERROR_INVALID_MULTIPLY_ORIGINATION_FEE: &[u8] =
+    b"Multiply origination fee invalid: must be less than 10000.";
+
+pub static ERROR_INVALID_FLASH_LOAN_PROTOCOL_SHARE: &[u8] =
+    b"Flash loan protocol share invalid: must not exceed 10000.";
+
+pub static ERROR_DEBT_CREATION_DISABLED: &[u8] = b"Debt creation is disabled protocol-wide.";
+
+pub static ERROR_HEALTH_FACTOR_BELOW_REQUESTED_MINIMUM: &[u8] =
+    b"Health factor after borrow is below the requested minimum.";
+
+pub static ERROR_BORROW_RATE_ABOVE_MAX_ACCEPTABLE: &[u8] =
+    b"Borrow rate after borrow is above the maximum acceptable rate.";
+
+pub static ERROR_INDEX_SNAPSHOT_NOT_FOUND: &[u8] =
+    b"No index snapshot recorded at or before the requested timestamp.";
+
+pub static ERROR_INVALID_LIQUIDATION_CLOSE_FACTOR: &[u8] =
+    b"Liquidation close factor invalid: must be greater than 0 and less than or equal to 10000.";
+
+pub static ERROR_MAX_POSITION_VALUE_EXCEEDED: &[u8] =
+    b"Account value would exceed the maximum position value.";
+
+pub static ERROR_HEALTH_FACTOR_DISABLE_COLLATERAL: &[u8] =
+    b"Health factor will be too low after disabling collateral.";
+
+pub static ERROR_EMODE_COMPANION_COLLATERAL_REQUIRED: &[u8] =
+    b"E-mode collateral cannot back a borrow without another collateral asset.";
+
+pub static ERROR_INVALID_TWAP_ROUNDS: &[u8] = b"TWAP rounds invalid: must be between 1 and 12.";
+
+pub static ERROR_DELISTED_PRICE_TOO_OLD: &[u8] =
+    b"Last known price of the delisted token is older than the allowed fallback age.";
+
+pub static ERROR_SEIZED_COLLATERAL_BELOW_MINIMUM: &[u8] =
+    b"Liquidation seizes less collateral than the minimum seized collateral value.";
+
+pub static ERROR_MARKET_PAUSED: &[u8] = b"Market is paused for new supplies and borrows.";
+
+pub static ERROR_NEW_SUPPLY_DISABLED: &[u8] = b"New supplies of the asset are disabled.";
+
+pub static ERROR_NEW_BORROW_DISABLED: &[u8] = b"New borrows of the asset are disabled.";
+
+pub static ERROR_INVALID_LIQUIDATION_HAIRCUT: &[u8] =
+    b"Liquidation haircut must be lower than 10000 basis points.";
+
+pub static ERROR_THRESHOLD_UPDATE_GRACE: &[u8] =
+    b"Account cannot be liquidated during the grace period after a liquidation threshold reduction.";
+
+pub static ERROR_EMODE_CATEGORY_UNCHANGED: &[u8] =
+    b"Account is already in the requested e-mode category.";
+
+pub static ERROR_INSUFFICIENT_DEPOSIT: &[u8] =
+    b"Deposit of the token is lower than the amount to repay.";
@@ -237,6 +237,23 @@ pub trait EventsModule {
         #[indexed] total_collateral_usd: &ManagedDecimal<Self::Api, NumDecimals>,
     );
 
+    /// Emits an event when an account's bad debt is cleaned and its positions are seized.
+    ///
+    /// # Parameters
+    /// - `account_nonce`: The nonce of the cleaned account.
+    /// - `write_offs`: The debt written off and collateral seized, per token.
+    /// - `total_written_off_usd`: The total USD value of the debt written off.
+    ///
+    /// # Returns
+    /// - Nothing.
+    #[event("bad_debt_cleaned")]
+    fn bad_debt_cleaned_event(
+        &self,
+        #[indexed] account_nonce: u64,
+        #[indexed] write_offs: &ManagedVec<BadDebtWriteOff<Self::Api>>,
+        #[indexed] total_written_off_usd: &ManagedDecimal<Self::Api, NumDecimals>,
+    );
+
     /// Emits an event when an asset's oracle is updated.
     ///
     /// # Parameters
@@ -266,4 +283,42 @@ pub trait EventsModule {
         #[indexed] usd: ManagedDecimal<Self::Api, NumDecimals>,
         #[indexed] nonce: u64,
     );
+
+    /// Emits an event when part or all of a leveraged position is unwound.
+    ///
+    /// # Parameters
+    /// - `account_nonce`: The nonce of the position.
+    /// - `collateral_token`: The collateral asset removed from the position.
+    /// - `collateral_removed`: The collateral amount withdrawn from the position.
+    /// - `debt_token`: The debt asset repaid.
+    /// - `debt_repaid`: The debt amount repaid.
+    ///
+    /// # Returns
+    /// - Nothing.
+    #[event("deleverage")]
+    fn deleverage_event(
+        &self,
+        #[indexed] account_nonce: u64,
+        #[indexed] collateral_token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] collateral_removed: &ManagedDecimal<Self::Api, NumDecimals>,
+        #[indexed] debt_token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] debt_repaid: &ManagedDecimal<Self::Api, NumDecimals>,
+    );
+
+    /// Emits an event when a flash loan fee is split between the pool and the accumulator.
+    ///
+    /// # Parameters
+    /// - `asset`: The flash-loaned asset.
+    /// - `pool_fee`: The part of the fee kept as pool revenue.
+    /// - `accumulator_fee`: The part of the fee routed to the accumulator.
+    ///
+    /// # Returns
+    /// - Nothing.
+    #[event("flash_loan_fee_split")]
+    fn flash_loan_fee_split_event(
+        &self,
+        #[indexed] asset: &EgldOrEsdtTokenIdentifier,
+        #[indexed] pool_fee: &ManagedDecimal<Self::Api, NumDecimals>,
+        #[indexed] accumulator_fee: &ManagedDecimal<Self::Api, NumDecimals>,
+    );
 }
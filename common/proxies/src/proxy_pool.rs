@@ -96,8 +96,9 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
-    /// Handles contract upgrade with empty implementation. 
-    /// Allows code updates without state migration requirements. 
+    /// Handles contract upgrade without state migration. 
+    /// Resumes interest accrual paused by the controller for the upgrade, 
+    /// without accruing interest for the time spent paused. 
     pub fn upgrade(
         self,
     ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
@@ -157,6 +158,130 @@ where
             .original_result()
     }
 
+    /// Switches the borrow rate curve used by the pool. 
+    /// Accrues interest under the previous model before the new one applies. 
+    /// Only callable by owner. 
+    pub fn set_rate_model_type<
+        Arg0: ProxyArg<common_structs::RateModelType>,
+        Arg1: ProxyArg<ManagedDecimal<Env::Api, usize>>,
+    >(
+        self,
+        rate_model_type: Arg0,
+        asset_price: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRateModelType")
+            .argument(&rate_model_type)
+            .argument(&asset_price)
+            .original_result()
+    }
+
+    /// Sets the reserve factor applied while utilization is above the optimal utilization. 
+    /// Accrues interest under the previous reserve factor before the new one applies. 
+    /// Only callable by owner. 
+    pub fn set_reserve_factor_high<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedDecimal<Env::Api, usize>>,
+    >(
+        self,
+        reserve_factor_high: Arg0,
+        asset_price: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setReserveFactorHigh")
+            .argument(&reserve_factor_high)
+            .argument(&asset_price)
+            .original_result()
+    }
+
+    /// Sets the absolute annual borrow rate ceiling enforced at accrual. 
+    /// Accrues interest under the previous ceiling before the new one applies. 
+    /// Only callable by owner. 
+    pub fn set_max_accrual_rate<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedDecimal<Env::Api, usize>>,
+    >(
+        self,
+        max_accrual_rate: Arg0,
+        asset_price: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxAccrualRate")
+            .argument(&max_accrual_rate)
+            .argument(&asset_price)
+            .original_result()
+    }
+
+    /// Sets the minimum time between two index recomputations. 
+    /// Syncs within the interval serve the last indexes; the skipped time accrues on the next real sync. 
+    /// Only callable by owner. 
+    pub fn set_min_accrual_interval<
+        Arg0: ProxyArg<DurationMillis>,
+        Arg1: ProxyArg<ManagedDecimal<Env::Api, usize>>,
+    >(
+        self,
+        min_accrual_interval_ms: Arg0,
+        asset_price: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMinAccrualInterval")
+            .argument(&min_accrual_interval_ms)
+            .argument(&asset_price)
+            .original_result()
+    }
+
+    /// Pauses or resumes interest accrual. 
+    /// Accrues interest up to now before pausing; resuming never accrues the paused window. 
+    /// Takes no asset price, so a stale oracle never blocks pausing a market. 
+    /// Only callable by owner. 
+    pub fn set_accrual_paused<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        paused: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAccrualPaused")
+            .argument(&paused)
+            .original_result()
+    }
+
+    /// Sets the amount of the first deposit burned to seed an empty pool. 
+    /// Protects later suppliers from first-depositor supply index inflation. 
+    /// Only callable by owner. 
+    pub fn set_min_initial_supply<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        min_initial_supply: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMinInitialSupply")
+            .argument(&min_initial_supply)
+            .original_result()
+    }
+
+    /// Chooses whether protocol revenue covers bad debt before it is socialized. 
+    /// Only callable by owner. 
+    pub fn set_revenue_absorbs_bad_debt<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRevenueAbsorbsBadDebt")
+            .argument(&enabled)
+            .original_result()
+    }
+
     /// Retrieves the total scaled amount supplied to the pool. 
     /// This value represents the sum of all supplied principals, each divided by the supply index at the time of their deposit. 
     /// It is stored RAY-scaled. 
@@ -259,6 +384,131 @@ where
             .original_result()
     }
 
+    /// Retrieves the cumulative rounding remainder retained by the pool. 
+    ///  
+    /// Every time a scaled conversion rounds in the pool's favor, the difference between 
+    /// the value received (or owed) and the value credited (or sent) is added here. 
+    ///  
+    /// # Returns 
+    /// - `BigUint`: The accumulated rounding remainder in asset units, RAY-scaled. 
+    pub fn accumulated_rounding(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccumulatedRounding")
+            .original_result()
+    }
+
+    /// Retrieves the minimum initial supply of the pool. 
+    ///  
+    /// When the pool holds no supply, this amount of the first deposit is burned 
+    /// into the total supply without an owner, so the supply index can never be 
+    /// inflated against a near-empty pool. 
+    ///  
+    /// # Returns 
+    /// - `BigUint`: The minimum initial supply in asset units (0 disables seeding). 
+    pub fn min_initial_supply(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinInitialSupply")
+            .original_result()
+    }
+
+    /// Retrieves whether interest accrual is paused. 
+    ///  
+    /// Set by the controller while the pool code is being upgraded. While paused, 
+    /// syncs leave the indexes untouched and no interest accrues for the paused window. 
+    ///  
+    /// # Returns 
+    /// - `bool`: `true` while accrual is paused. 
+    pub fn accrual_paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isAccrualPaused")
+            .original_result()
+    }
+
+    /// Retrieves whether protocol revenue absorbs bad debt before suppliers. 
+    ///  
+    /// When set, written-off debt first burns the protocol's revenue and only the 
+    /// uncovered remainder is socialized across suppliers through the supply index. 
+    ///  
+    /// # Returns 
+    /// - `bool`: `true` when revenue covers bad debt first. 
+    pub fn revenue_absorbs_bad_debt(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isRevenueAbsorbingBadDebt")
+            .original_result()
+    }
+
+    /// Retrieves the borrow rate curve applied to the market parameters. 
+    ///  
+    /// An empty mapper means the three-slope model. 
+    ///  
+    /// # Returns 
+    /// - `RateModelType`: The rate model of the pool. 
+    pub fn rate_model_type(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common_structs::RateModelType> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRateModelType")
+            .original_result()
+    }
+
+    /// Retrieves the reserve factor applied while utilization is above the optimal utilization. 
+    ///  
+    /// Only applies when higher than the base reserve factor; an empty mapper keeps it flat. 
+    ///  
+    /// # Returns 
+    /// - `BigUint`: The elevated reserve factor, BPS-scaled. 
+    pub fn reserve_factor_high(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReserveFactorHigh")
+            .original_result()
+    }
+
+    /// Retrieves the absolute annual borrow rate ceiling enforced at accrual. 
+    ///  
+    /// Caps the rate curve, never above the max borrow rate; an empty mapper disables it. 
+    ///  
+    /// # Returns 
+    /// - `BigUint`: The annual borrow rate ceiling, RAY-scaled. 
+    pub fn max_accrual_rate(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxAccrualRate")
+            .original_result()
+    }
+
+    /// Retrieves the minimum time between two index recomputations. 
+    ///  
+    /// Syncs within the interval keep the last indexes; an empty mapper recomputes on every sync. 
+    ///  
+    /// # Returns 
+    /// - `DurationMillis`: The minimum accrual interval in milliseconds. 
+    pub fn min_accrual_interval(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationMillis> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinAccrualInterval")
+            .original_result()
+    }
+
     /// Updates borrow and supply indexes based on elapsed time since last update. 
     /// Synchronizes global pool state and emits market update event. 
     /// Returns current market indexes. 
@@ -370,6 +620,32 @@ where
             .original_result()
     }
 
+    /// Nets a deposit against a borrow of the same account without moving tokens. 
+    /// Burns the scaled supply and scaled debt of the netted amount, capped at the 
+    /// outstanding debt, so reserves are unchanged while both totals decrease. 
+    /// Returns the updated deposit and borrow positions. 
+    pub fn net_positions<
+        Arg0: ProxyArg<ManagedDecimal<Env::Api, usize>>,
+        Arg1: ProxyArg<common_structs::AccountPosition<Env::Api>>,
+        Arg2: ProxyArg<common_structs::AccountPosition<Env::Api>>,
+        Arg3: ProxyArg<ManagedDecimal<Env::Api, usize>>,
+    >(
+        self,
+        amount: Arg0,
+        deposit_position: Arg1,
+        borrow_position: Arg2,
+        price: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<common_structs::AccountPosition<Env::Api>, common_structs::AccountPosition<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("netPositions")
+            .argument(&amount)
+            .argument(&deposit_position)
+            .argument(&borrow_position)
+            .argument(&price)
+            .original_result()
+    }
+
     /// Adds rewards to the pool. 
     pub fn add_reward<
         Arg0: ProxyArg<ManagedDecimal<Env::Api, usize>>,
@@ -386,6 +662,7 @@ where
     /// Provides atomic flash loan with fee collection. 
     /// Transfers amount to target contract, validates repayment, adds protocol revenue. 
     /// Must be repaid with fees in same transaction. 
+    /// Sends `protocol_share` of the collected fee back to the controller for the accumulator. 
     pub fn flash_loan<
         Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
         Arg1: ProxyArg<ManagedDecimal<Env::Api, usize>>,
@@ -394,6 +671,7 @@ where
         Arg4: ProxyArg<ManagedArgBuffer<Env::Api>>,
         Arg5: ProxyArg<ManagedDecimal<Env::Api, usize>>,
         Arg6: ProxyArg<ManagedDecimal<Env::Api, usize>>,
+        Arg7: ProxyArg<ManagedDecimal<Env::Api, usize>>,
     >(
         self,
         borrowed_token: Arg0,
@@ -402,8 +680,9 @@ where
         endpoint: Arg3,
         arguments: Arg4,
         fees: Arg5,
-        price: Arg6,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        protocol_share: Arg6,
+        price: Arg7,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenPayment<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("flashLoan")
@@ -413,6 +692,7 @@ where
             .argument(&endpoint)
             .argument(&arguments)
             .argument(&fees)
+            .argument(&protocol_share)
             .argument(&price)
             .original_result()
     }
@@ -525,6 +805,23 @@ where
             .original_result()
     }
 
+    /// Returns the borrow and deposit rates the pool would apply at a given utilization. 
+    /// Runs the market's rate model on an arbitrary utilization without touching pool state, 
+    /// letting front-ends sample the full rate curve and integrators check new parameters. 
+    /// Rates are per millisecond, matching `borrowRate` and `depositRate`. 
+    pub fn get_rates_at_utilization<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        utilization_ray: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRatesAtUtilization")
+            .argument(&utilization_ray)
+            .original_result()
+    }
+
     /// Returns milliseconds elapsed since last pool synchronization. 
     /// Indicates accumulated interest awaiting index updates. 
     /// Larger deltas mean more pending interest calculations. 
@@ -159,6 +159,28 @@ where
             .original_result()
     }
 
+    pub fn max_deviation_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxDeviationBps")
+            .original_result()
+    }
+
+    pub fn oracle_weight<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        oracle: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOracleWeight")
+            .argument(&oracle)
+            .original_result()
+    }
+
     pub fn latest_round_data<
         Arg0: ProxyArg<MultiValueEncoded<Env::Api, TokenPair<Env::Api>>>,
     >(
@@ -240,6 +262,35 @@ where
             .argument(&submission_count)
             .original_result()
     }
+
+    pub fn set_max_deviation_bps<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_deviation_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxDeviationBps")
+            .argument(&max_deviation_bps)
+            .original_result()
+    }
+
+    pub fn set_oracle_weight<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        oracle: Arg0,
+        weight: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setOracleWeight")
+            .argument(&oracle)
+            .argument(&weight)
+            .original_result()
+    }
 }
 
 #[type_abi]
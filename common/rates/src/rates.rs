@@ -1,6 +1,6 @@
 #![no_std]
 use common_constants::{MILLISECONDS_PER_YEAR, RAY_PRECISION};
-use common_structs::{MarketIndex, MarketParams};
+use common_structs::{MarketIndex, MarketParams, MarketRateSettings, RateModelType};
 
 multiversx_sc::imports!();
 
@@ -14,49 +14,21 @@ multiversx_sc::imports!();
 pub trait InterestRates: common_math::SharedMathModule {
     /// Calculates per-millisecond borrow rate using piecewise linear model.
     /// Rate increases with utilization: gradual before kink, steep after kink.
-    /// Caps at max_borrow_rate and converts from annual to millisecond rate.
+    /// Caps at max_borrow_rate, then at the market's absolute accrual ceiling when set,
+    /// and converts from annual to millisecond rate.
     fn calculate_borrow_rate(
         &self,
         utilization: ManagedDecimal<Self::Api, NumDecimals>,
         parameters: MarketParams<Self::Api>,
+        settings: &MarketRateSettings<Self::Api>,
     ) -> ManagedDecimal<Self::Api, NumDecimals> {
-        let annual_rate = if utilization < parameters.mid_utilization_ray {
-            // Region 1: utilization < mid_utilization
-            let utilization_ratio = utilization
-                .mul(parameters.slope1_ray)
-                .div(parameters.mid_utilization_ray);
-            parameters.base_borrow_rate_ray.add(utilization_ratio)
-        } else if utilization < parameters.optimal_utilization_ray {
-            // Region 2: mid_utilization <= utilization < optimal_utilization
-            let excess_utilization = utilization.sub(parameters.mid_utilization_ray.clone());
-            let slope_contribution = excess_utilization.mul(parameters.slope2_ray).div(
-                parameters
-                    .optimal_utilization_ray
-                    .sub(parameters.mid_utilization_ray),
-            );
-            parameters
-                .base_borrow_rate_ray
-                .add(parameters.slope1_ray)
-                .add(slope_contribution)
-        } else {
-            // Region 3: utilization >= optimal_utilization, linear growth
-            let base_rate = parameters
-                .base_borrow_rate_ray
-                .add(parameters.slope1_ray)
-                .add(parameters.slope2_ray);
-            let excess_utilization = utilization.sub(parameters.optimal_utilization_ray.clone());
-            let slope_contribution = excess_utilization
-                .mul(parameters.slope3_ray)
-                .div(self.ray().sub(parameters.optimal_utilization_ray));
-            base_rate.add(slope_contribution)
-        };
+        let max_accrual_rate = settings.max_accrual_rate_ray.clone();
+        let mut capped_rate = self.calculate_annual_borrow_rate(utilization, parameters, settings);
 
-        // Cap the rate at max_borrow_rate
-        let capped_rate = if annual_rate > parameters.max_borrow_rate_ray {
-            parameters.max_borrow_rate_ray
-        } else {
-            annual_rate
-        };
+        // Final safety: never accrue above the absolute ceiling, whatever the curve yields
+        if max_accrual_rate != self.ray_zero() && capped_rate > max_accrual_rate {
+            capped_rate = max_accrual_rate;
+        }
 
         // Convert annual rate to per-millisecond rate
         self.div_half_up(
@@ -66,6 +38,193 @@ pub trait InterestRates: common_math::SharedMathModule {
         )
     }
 
+    /// Calculates the annual borrow rate for a utilization using the market's rate model.
+    /// Three-slope: three regions split by mid and optimal utilization.
+    /// Kink: two regions split by optimal utilization. Both are capped at max_borrow_rate.
+    /// Returns the annual rate (RAY-based) before conversion to a per-millisecond rate.
+    fn calculate_annual_borrow_rate(
+        &self,
+        utilization: ManagedDecimal<Self::Api, NumDecimals>,
+        parameters: MarketParams<Self::Api>,
+        settings: &MarketRateSettings<Self::Api>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let annual_rate = match settings.rate_model_type {
+            RateModelType::ThreeSlope => {
+                if utilization < parameters.mid_utilization_ray {
+                    // Region 1: utilization < mid_utilization
+                    let utilization_ratio = utilization
+                        .mul(parameters.slope1_ray)
+                        .div(parameters.mid_utilization_ray);
+                    parameters.base_borrow_rate_ray.add(utilization_ratio)
+                } else if utilization < parameters.optimal_utilization_ray {
+                    // Region 2: mid_utilization <= utilization < optimal_utilization
+                    let excess_utilization =
+                        utilization.sub(parameters.mid_utilization_ray.clone());
+                    let slope_contribution = excess_utilization.mul(parameters.slope2_ray).div(
+                        parameters
+                            .optimal_utilization_ray
+                            .sub(parameters.mid_utilization_ray),
+                    );
+                    parameters
+                        .base_borrow_rate_ray
+                        .add(parameters.slope1_ray)
+                        .add(slope_contribution)
+                } else {
+                    // Region 3: utilization >= optimal_utilization, linear growth
+                    let base_rate = parameters
+                        .base_borrow_rate_ray
+                        .add(parameters.slope1_ray)
+                        .add(parameters.slope2_ray);
+                    let excess_utilization =
+                        utilization.sub(parameters.optimal_utilization_ray.clone());
+                    let slope_contribution = excess_utilization
+                        .mul(parameters.slope3_ray)
+                        .div(self.ray().sub(parameters.optimal_utilization_ray));
+                    base_rate.add(slope_contribution)
+                }
+            },
+            RateModelType::Kink => {
+                if utilization < parameters.optimal_utilization_ray {
+                    // Region 1: utilization < optimal_utilization
+                    let utilization_ratio = utilization
+                        .mul(parameters.slope1_ray)
+                        .div(parameters.optimal_utilization_ray);
+                    parameters.base_borrow_rate_ray.add(utilization_ratio)
+                } else {
+                    // Region 2: utilization >= optimal_utilization, steep slope past the kink
+                    let base_rate = parameters.base_borrow_rate_ray.add(parameters.slope1_ray);
+                    let excess_utilization =
+                        utilization.sub(parameters.optimal_utilization_ray.clone());
+                    let slope_contribution = excess_utilization
+                        .mul(parameters.slope2_ray)
+                        .div(self.ray().sub(parameters.optimal_utilization_ray));
+                    base_rate.add(slope_contribution)
+                }
+            },
+        };
+
+        // Cap the rate at max_borrow_rate
+        if annual_rate > parameters.max_borrow_rate_ray {
+            parameters.max_borrow_rate_ray
+        } else {
+            annual_rate
+        }
+    }
+
+    /// Calculates the utilization at which the market would charge a given annual borrow rate.
+    ///
+    /// **Scope**: Inverts the piecewise linear curve of `calculate_annual_borrow_rate` for either rate model.
+    ///
+    /// **Goal**: Support treasury modeling by answering "at which utilization is rate X reached".
+    ///
+    /// **Formula**:
+    /// - `rate <= base`: `utilization = 0`.
+    /// - Region 1: `utilization = (rate - base) * mid / slope1`.
+    /// - Region 2: `utilization = mid + (rate - base - slope1) * (optimal - mid) / slope2`.
+    /// - Region 3: `utilization = optimal + (rate - base - slope1 - slope2) * (1 - optimal) / slope3`.
+    /// - Kink Region 1: `utilization = (rate - base) * optimal / slope1`.
+    /// - Kink Region 2: `utilization = optimal + (rate - base - slope1) * (1 - optimal) / slope2`.
+    /// - Rates above the curve at full utilization (or above `max_borrow_rate`) return 100%.
+    ///
+    /// # Arguments
+    /// - `target_rate`: Annual borrow rate to invert (`ManagedDecimal<Self::Api, NumDecimals>`), RAY-based.
+    /// - `parameters`: Market rate model parameters.
+    /// - `settings`: Market rate settings selecting the rate model.
+    ///
+    /// # Returns
+    /// - `ManagedDecimal<Self::Api, NumDecimals>`: Utilization ratio (RAY-based), within `[0, 1]`.
+    fn calculate_utilization_for_borrow_rate(
+        &self,
+        target_rate: ManagedDecimal<Self::Api, NumDecimals>,
+        parameters: MarketParams<Self::Api>,
+        settings: &MarketRateSettings<Self::Api>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        if target_rate <= parameters.base_borrow_rate_ray {
+            return self.ray_zero();
+        }
+        if target_rate > parameters.max_borrow_rate_ray {
+            return self.ray();
+        }
+
+        if settings.rate_model_type == RateModelType::Kink {
+            let kink_rate = parameters
+                .base_borrow_rate_ray
+                .clone()
+                .add(parameters.slope1_ray.clone());
+            let utilization = if target_rate <= kink_rate {
+                // Region 1: base < rate <= base + slope1 (slope1 > 0 here)
+                let excess_rate = target_rate.sub(parameters.base_borrow_rate_ray);
+                self.div_half_up(
+                    &self.mul_half_up(
+                        &excess_rate,
+                        &parameters.optimal_utilization_ray,
+                        RAY_PRECISION,
+                    ),
+                    &parameters.slope1_ray,
+                    RAY_PRECISION,
+                )
+            } else {
+                // Region 2: rate above the kink rate, a flat slope can never reach it
+                if parameters.slope2_ray == self.ray_zero() {
+                    return self.ray();
+                }
+                let excess_rate = target_rate.sub(kink_rate);
+                let utilization_range = self.ray().sub(parameters.optimal_utilization_ray.clone());
+                let excess_utilization = self.div_half_up(
+                    &self.mul_half_up(&excess_rate, &utilization_range, RAY_PRECISION),
+                    &parameters.slope2_ray,
+                    RAY_PRECISION,
+                );
+                parameters.optimal_utilization_ray.add(excess_utilization)
+            };
+
+            return self.min(utilization, self.ray());
+        }
+
+        let mid_rate = parameters
+            .base_borrow_rate_ray
+            .clone()
+            .add(parameters.slope1_ray.clone());
+        let optimal_rate = mid_rate.clone().add(parameters.slope2_ray.clone());
+
+        let utilization = if target_rate <= mid_rate {
+            // Region 1: base < rate <= base + slope1 (slope1 > 0 here)
+            let excess_rate = target_rate.sub(parameters.base_borrow_rate_ray);
+            self.div_half_up(
+                &self.mul_half_up(&excess_rate, &parameters.mid_utilization_ray, RAY_PRECISION),
+                &parameters.slope1_ray,
+                RAY_PRECISION,
+            )
+        } else if target_rate <= optimal_rate {
+            // Region 2: mid rate < rate <= optimal rate (slope2 > 0 here)
+            let excess_rate = target_rate.sub(mid_rate);
+            let utilization_range = parameters
+                .optimal_utilization_ray
+                .sub(parameters.mid_utilization_ray.clone());
+            let excess_utilization = self.div_half_up(
+                &self.mul_half_up(&excess_rate, &utilization_range, RAY_PRECISION),
+                &parameters.slope2_ray,
+                RAY_PRECISION,
+            );
+            parameters.mid_utilization_ray.add(excess_utilization)
+        } else {
+            // Region 3: rate above the optimal rate, a flat slope can never reach it
+            if parameters.slope3_ray == self.ray_zero() {
+                return self.ray();
+            }
+            let excess_rate = target_rate.sub(optimal_rate);
+            let utilization_range = self.ray().sub(parameters.optimal_utilization_ray.clone());
+            let excess_utilization = self.div_half_up(
+                &self.mul_half_up(&excess_rate, &utilization_range, RAY_PRECISION),
+                &parameters.slope3_ray,
+                RAY_PRECISION,
+            );
+            parameters.optimal_utilization_ray.add(excess_utilization)
+        };
+
+        self.min(utilization, self.ray())
+    }
+
     /// Calculates the deposit rate based on utilization, borrow rate, and reserve factor.
     ///
     /// **Scope**: Computes the rate suppliers earn from borrowers' interest payments.
@@ -216,11 +375,39 @@ pub trait InterestRates: common_math::SharedMathModule {
         return old_supply_index;
     }
 
+    /// Returns the reserve factor applied at a given utilization.
+    /// Above the optimal utilization the elevated `reserve_factor_high` applies when it exceeds
+    /// the base reserve factor; otherwise the reserve factor stays flat.
+    ///
+    /// # Arguments
+    /// - `utilization`: Current utilization ratio (RAY)
+    /// - `parameters`: The market parameters including the base reserve factor
+    /// - `settings`: The market rate settings including the elevated reserve factor
+    ///
+    /// # Returns
+    /// - Reserve factor in BPS precision
+    fn reserve_factor_at(
+        &self,
+        utilization: &ManagedDecimal<Self::Api, NumDecimals>,
+        parameters: &MarketParams<Self::Api>,
+        settings: &MarketRateSettings<Self::Api>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        if utilization > &parameters.optimal_utilization_ray
+            && settings.reserve_factor_high_bps > parameters.reserve_factor_bps
+        {
+            return settings.reserve_factor_high_bps.clone();
+        }
+
+        parameters.reserve_factor_bps.clone()
+    }
+
     /// Calculates supplier rewards and protocol fees
     /// This simplified version directly distributes accrued interest between suppliers and protocol.
     ///
     /// # Arguments
     /// - `parameters`: The market parameters including reserve factor
+    /// - `settings`: The market rate settings including the elevated reserve factor
+    /// - `utilization`: Utilization the interest accrued at, selecting the reserve factor
     /// - `borrowed`: The total scaled borrowed amount
     /// - `new_borrow_index`: The updated borrow index after interest accrual
     /// - `old_borrow_index`: The previous borrow index
@@ -230,6 +417,8 @@ pub trait InterestRates: common_math::SharedMathModule {
     fn calculate_supplier_rewards(
         &self,
         parameters: MarketParams<Self::Api>,
+        settings: &MarketRateSettings<Self::Api>,
+        utilization: &ManagedDecimal<Self::Api, NumDecimals>,
         borrowed: &ManagedDecimal<Self::Api, NumDecimals>,
         new_borrow_index: &ManagedDecimal<Self::Api, NumDecimals>,
         old_borrow_index: &ManagedDecimal<Self::Api, NumDecimals>,
@@ -246,7 +435,7 @@ pub trait InterestRates: common_math::SharedMathModule {
         // Direct distribution: protocol fee first, then supplier rewards
         let protocol_fee = self.mul_half_up(
             &accrued_interest_ray,
-            &parameters.reserve_factor_bps,
+            &self.reserve_factor_at(utilization, &parameters, settings),
             RAY_PRECISION,
         );
         let supplier_rewards_ray = accrued_interest_ray - protocol_fee.clone();
@@ -323,7 +512,7 @@ pub trait InterestRates: common_math::SharedMathModule {
     ///   current parameters and totals, for read-only contexts and views.
     ///
     /// Methodology
-    /// 1. If `delta == 0`, return current indexes
+    /// 1. If `delta == 0` or below the market's minimum accrual interval, return current indexes
     /// 2. Compute utilization from original totals
     /// 3. Calculate borrow rate and compounded factor for delta
     /// 4. Update borrow index, split accrued interest, update supply index
@@ -336,6 +525,7 @@ pub trait InterestRates: common_math::SharedMathModule {
     /// - `supplied`: Total scaled supplied amount (RAY-scaled)
     /// - `current_supply_index`: Current supply index (RAY)
     /// - `parameters`: Market parameters including reserve factor and slopes
+    /// - `settings`: Market rate settings kept outside of the parameters
     ///
     /// Returns
     /// - `MarketIndex` with updated supply and borrow indexes
@@ -348,14 +538,16 @@ pub trait InterestRates: common_math::SharedMathModule {
         supplied: ManagedDecimal<Self::Api, NumDecimals>,
         current_supply_index: ManagedDecimal<Self::Api, NumDecimals>,
         parameters: MarketParams<Self::Api>,
+        settings: &MarketRateSettings<Self::Api>,
     ) -> MarketIndex<Self::Api> {
         let delta = current_timestamp - last_timestamp;
 
-        if delta > DurationMillis::zero() {
+        if delta > DurationMillis::zero() && delta >= settings.min_accrual_interval_ms {
             let borrowed_original = self.scaled_to_original_ray(&borrowed, &current_borrowed_index);
             let supplied_original = self.scaled_to_original_ray(&supplied, &current_supply_index);
             let utilization = self.utilization(&borrowed_original, &supplied_original);
-            let borrow_rate = self.calculate_borrow_rate(utilization, parameters.clone());
+            let borrow_rate =
+                self.calculate_borrow_rate(utilization.clone(), parameters.clone(), settings);
             let borrow_factor = self.calculate_compounded_interest(borrow_rate.clone(), delta);
             let (new_borrow_index, old_borrow_index) =
                 self.update_borrow_index(current_borrowed_index.clone(), borrow_factor.clone());
@@ -363,6 +555,8 @@ pub trait InterestRates: common_math::SharedMathModule {
             // 3 raw split
             let (supplier_rewards_ray, _) = self.calculate_supplier_rewards(
                 parameters.clone(),
+                settings,
+                &utilization,
                 &borrowed,
                 &new_borrow_index,
                 &old_borrow_index,
@@ -28,6 +28,37 @@ pub struct MarketParams<M: ManagedTypeApi> {
     pub asset_decimals: usize,
 }
 
+/// RateModelType selects the borrow rate curve of a market.
+///
+/// - `ThreeSlope`: Three linear regions split by the mid and optimal utilization,
+///   using `slope1`, `slope2` and `slope3`.
+/// - `Kink`: Two linear regions split by the optimal utilization, using `slope1`
+///   below the kink and `slope2` above it; `mid_utilization` and `slope3` are ignored.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, Eq, PartialEq)]
+pub enum RateModelType {
+    ThreeSlope,
+    Kink,
+}
+
+/// MarketRateSettings groups the rate settings a market keeps outside of `MarketParams`,
+/// each read from its own pool mapper where an empty mapper means the default.
+///
+/// - `rate_model_type`: The shape of the borrow rate curve built from the market parameters.
+/// - `reserve_factor_high`: The reserve factor applied above the optimal utilization,
+///   when higher than `reserve_factor`; zero keeps the reserve factor flat.
+/// - `max_accrual_rate`: Absolute annual borrow rate ceiling enforced at accrual on top of the
+///   curve, never above `max_borrow_rate`; zero disables it.
+/// - `min_accrual_interval_ms`: Minimum time since the last index update before a sync recomputes
+///   the indexes; shorter gaps keep the last indexes and accrue on the next real sync.
+#[derive(Clone)]
+pub struct MarketRateSettings<M: ManagedTypeApi> {
+    pub rate_model_type: RateModelType,
+    pub reserve_factor_high_bps: ManagedDecimal<M, NumDecimals>,
+    pub max_accrual_rate_ray: ManagedDecimal<M, NumDecimals>,
+    pub min_accrual_interval_ms: DurationMillis,
+}
+
 /// AccountPositionType represents the type of a user's position in the pool.
 /// It can either be a deposit position or a borrow position.
 #[type_abi]
@@ -110,7 +141,6 @@ impl<M: ManagedTypeApi> AccountPosition<M> {
     /// - `liquidation_bonus`: The liquidation bonus at entry.
     /// - `liquidation_fees`: The liquidation fees at entry.
     /// - `loan_to_value`: The loan-to-value ratio at entry.
-    /// - `is_vault_position`: A flag indicating if the position is part of a vault.
     ///
     /// # Returns
     /// - `AccountPosition`: A new AccountPosition instance.
@@ -156,6 +186,18 @@ impl<M: ManagedTypeApi> AccountPosition<M> {
     }
 }
 
+/// CapDenomination selects the unit a market cap is expressed in.
+/// - `Token`: the cap is an amount of the asset itself, in asset decimals.
+/// - `Egld`: the cap is an EGLD value in WAD precision, so it stays value-stable as prices move.
+#[type_abi]
+#[derive(
+    ManagedVecItem, NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, Eq, PartialEq,
+)]
+pub enum CapDenomination {
+    Token,
+    Egld,
+}
+
 /// AssetConfig defines the risk and usage configuration for an asset in the market.
 /// It includes risk parameters such as LTV, liquidation thresholds, and fees,
 /// as well as supply/borrow caps and flags for collateral usage, isolation, and flashloan support.
@@ -290,7 +332,7 @@ impl EModeAssetConfig {
 
 /// AccountAttributes encapsulates attributes related to an account’s NFT,
 /// which represents a user's position in the protocol. These attributes include whether the position is isolated,
-/// the e-mode category, and whether it is a vault.
+/// the e-mode category, the position mode and the isolated collateral token.
 #[type_abi]
 #[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, Eq, PartialEq)]
 pub struct AccountAttributes<M: ManagedTypeApi> {
@@ -333,6 +375,8 @@ impl<M: ManagedTypeApi> AccountAttributes<M> {
 /// - `Instant`: Real-time pricing.
 /// - `Aggregator`: Prices obtained from an aggregator.
 /// - `Mix`: A combination of methods (Safe,Aggregator).
+/// - `Twap`: Mix, falling back to a multi-round xExchange TWAP when the aggregator price is
+///   stale or outside the last tolerance.
 #[type_abi]
 #[derive(
     ManagedVecItem, NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, Eq, PartialEq,
@@ -343,6 +387,7 @@ pub enum PricingMethod {
     Instant,
     Aggregator,
     Mix,
+    Twap,
 }
 
 /// OracleType specifies the type of oracle used for price feeds.
@@ -468,6 +513,29 @@ pub struct LiquidationEstimate<M: ManagedTypeApi> {
     pub refunds: ManagedVec<M, EgldOrEsdtTokenPayment<M>>,
     pub max_egld_payment_wad: ManagedDecimal<M, NumDecimals>,
     pub bonus_rate_bps: ManagedDecimal<M, NumDecimals>,
+    /// Seized collateral value net of protocol fees minus the debt repaid, in EGLD.
+    /// Zero when the liquidation does not pay for itself.
+    pub liquidator_profit_egld_wad: ManagedDecimal<M, NumDecimals>,
+    /// Debt of each borrowed token repayable on its own, capped by the asset close factor
+    /// and `max_egld_payment_wad`.
+    pub max_repayable_per_debt_token: ManagedVec<M, EgldOrEsdtTokenPayment<M>>,
+}
+
+/// UserAccountSummary aggregates the valuation of an account NFT for portfolio views.
+///
+/// - `total_collateral_wad`: Unweighted collateral value in EGLD.
+/// - `liquidation_collateral_wad`: Collateral weighted by liquidation thresholds, in EGLD.
+/// - `ltv_collateral_wad`: Collateral weighted by loan-to-value ratios, in EGLD.
+/// - `total_borrow_wad`: Debt value in EGLD.
+/// - `health_factor_ray`: Health factor of the account.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct UserAccountSummary<M: ManagedTypeApi> {
+    pub total_collateral_wad: ManagedDecimal<M, NumDecimals>,
+    pub liquidation_collateral_wad: ManagedDecimal<M, NumDecimals>,
+    pub ltv_collateral_wad: ManagedDecimal<M, NumDecimals>,
+    pub total_borrow_wad: ManagedDecimal<M, NumDecimals>,
+    pub health_factor_ray: ManagedDecimal<M, NumDecimals>,
 }
 
 /// PositionLimits defines the maximum number of positions an NFT can hold.
@@ -484,3 +552,121 @@ pub struct PositionLimits {
     pub max_borrow_positions: u8,
     pub max_supply_positions: u8,
 }
+
+/// PriceStalenessOverride temporarily extends the staleness tolerance of an asset's price feeds.
+///
+/// - `extra_seconds`: Seconds added to the configured `max_price_stale_seconds`.
+/// - `expiry`: Block timestamp (seconds) from which the configured tolerance applies again.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PriceStalenessOverride {
+    pub extra_seconds: DurationSeconds,
+    pub expiry: TimestampSeconds,
+}
+
+/// MarketActivityType identifies the user operation recorded in a market's activity buffer.
+#[type_abi]
+#[derive(
+    ManagedVecItem, NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, Eq, PartialEq,
+)]
+pub enum MarketActivityType {
+    Supply,
+    Borrow,
+    Repay,
+    Withdraw,
+}
+
+/// MarketActivity is a single entry of a market's recent activity buffer.
+///
+/// - `activity_type`: The operation that was executed.
+/// - `amount`: The amount applied to the market, in asset decimals.
+/// - `timestamp`: The block timestamp of the operation, in milliseconds.
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct MarketActivity<M: ManagedTypeApi> {
+    pub activity_type: MarketActivityType,
+    pub amount: ManagedDecimal<M, NumDecimals>,
+    pub timestamp: TimestampMillis,
+}
+
+/// IndexSnapshot records the synchronized indexes of a market at a point in time.
+///
+/// - `timestamp`: The block timestamp of the synchronization, in seconds.
+/// - `supply_index_ray`: The supply index after synchronization (RAY).
+/// - `borrow_index_ray`: The borrow index after synchronization (RAY).
+/// - `reward_index_ray`: The cumulative rewards distributed per scaled unit supplied (RAY).
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct IndexSnapshot<M: ManagedTypeApi> {
+    pub timestamp: TimestampSeconds,
+    pub supply_index_ray: ManagedDecimal<M, NumDecimals>,
+    pub borrow_index_ray: ManagedDecimal<M, NumDecimals>,
+    pub reward_index_ray: ManagedDecimal<M, NumDecimals>,
+}
+
+/// DepositYieldBreakdown splits the projected annual yield of a market's suppliers by source.
+///
+/// - `interest_apy_ray`: Annual supply rate paid by borrowers at the current utilization (RAY).
+/// - `reward_apy_ray`: Annualized rewards distributed to suppliers over the trailing period (RAY).
+/// - `total_apy_ray`: Sum of the interest and reward components (RAY).
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct DepositYieldBreakdown<M: ManagedTypeApi> {
+    pub interest_apy_ray: ManagedDecimal<M, NumDecimals>,
+    pub reward_apy_ray: ManagedDecimal<M, NumDecimals>,
+    pub total_apy_ray: ManagedDecimal<M, NumDecimals>,
+}
+
+/// MarketRateSpreadView reports the interest margin of a market at its current utilization.
+///
+/// - `asset_id`: The market's asset.
+/// - `borrow_rate_ray`: Annual borrow rate (RAY).
+/// - `supply_rate_ray`: Annual supply rate (RAY).
+/// - `spread_ray`: Borrow rate minus supply rate (RAY).
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct MarketRateSpreadView<M: ManagedTypeApi> {
+    pub asset_id: EgldOrEsdtTokenIdentifier<M>,
+    pub borrow_rate_ray: ManagedDecimal<M, NumDecimals>,
+    pub supply_rate_ray: ManagedDecimal<M, NumDecimals>,
+    pub spread_ray: ManagedDecimal<M, NumDecimals>,
+}
+
+/// BadDebtWriteOff records what a bad debt cleanup removed from an account for one token.
+///
+/// - `token_id`: The asset of the cleaned positions.
+/// - `debt_amount_wad`: The debt written off, in token units scaled to WAD.
+/// - `collateral_seized_wad`: The collateral seized by the protocol, in token units scaled to WAD.
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct BadDebtWriteOff<M: ManagedTypeApi> {
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub debt_amount_wad: ManagedDecimal<M, NumDecimals>,
+    pub collateral_seized_wad: ManagedDecimal<M, NumDecimals>,
+}
+
+/// LiquidationRecord is a single entry of an account's liquidation history.
+///
+/// - `timestamp`: The block timestamp of the liquidation, in milliseconds.
+/// - `liquidator`: The address that executed the liquidation.
+/// - `debt_repaid_egld_wad`: The EGLD value of the debt repaid by the liquidator (WAD).
+/// - `collateral_seized_egld_wad`: The EGLD value of the collateral seized, protocol fee included (WAD).
+#[type_abi]
+#[derive(ManagedVecItem, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct LiquidationRecord<M: ManagedTypeApi> {
+    pub timestamp: TimestampMillis,
+    pub liquidator: ManagedAddress<M>,
+    pub debt_repaid_egld_wad: ManagedDecimal<M, NumDecimals>,
+    pub collateral_seized_egld_wad: ManagedDecimal<M, NumDecimals>,
+}
+
+/// PositionEntry tracks the cost basis of a position to report its accrued interest.
+///
+/// - `scaled_amount_ray`: The scaled amount of the position at its last update (RAY).
+/// - `entry_index_ray`: The market index at entry, weighted across increases (RAY).
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PositionEntry<M: ManagedTypeApi> {
+    pub scaled_amount_ray: ManagedDecimal<M, NumDecimals>,
+    pub entry_index_ray: ManagedDecimal<M, NumDecimals>,
+}
@@ -1,5 +1,8 @@
-use common_structs::{AssetConfig, EModeAssetConfig};
-use multiversx_sc::types::{BigUint, ManagedDecimal, NumDecimals};
+use common_structs::{AccountPosition, AccountPositionType, AssetConfig, EModeAssetConfig};
+use multiversx_sc::{
+    codec::{TopDecode, TopEncode},
+    types::{BigUint, EgldOrEsdtTokenIdentifier, ManagedBuffer, ManagedDecimal, NumDecimals},
+};
 use multiversx_sc_scenario::api::StaticApi;
 
 #[test]
@@ -44,3 +47,40 @@ fn emode_asset_config_flags() {
     // No explicit can_supply on EModeAssetConfig; collateralizable flag used in e-mode application.
     assert!(emode.is_collateralizable);
 }
+
+#[test]
+fn account_position_decodes_stored_layout() {
+    let bps = |value: u64| {
+        ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(BigUint::from(value), 4)
+    };
+    let scaled =
+        ManagedDecimal::<StaticApi, NumDecimals>::from_raw_units(BigUint::from(1_000u64), 27);
+    let asset = EgldOrEsdtTokenIdentifier::<StaticApi>::esdt("USDC-123456");
+
+    // Field by field encoding of a position as already written to storage
+    let mut stored = ManagedBuffer::<StaticApi>::new();
+    (
+        AccountPositionType::Deposit,
+        asset.clone(),
+        scaled.clone(),
+        7u64,
+        bps(8_000),
+        bps(500),
+        bps(100),
+        bps(7_500),
+    )
+        .top_encode(&mut stored)
+        .unwrap();
+
+    let position = AccountPosition::<StaticApi>::top_decode(stored.clone()).unwrap();
+    assert!(position.position_type == AccountPositionType::Deposit);
+    assert!(position.asset_id == asset);
+    assert!(position.scaled_amount_ray == scaled);
+    assert_eq!(position.account_nonce, 7);
+    assert!(position.liquidation_threshold_bps == bps(8_000));
+    assert!(position.loan_to_value_bps == bps(7_500));
+
+    let mut reencoded = ManagedBuffer::<StaticApi>::new();
+    position.top_encode(&mut reencoded).unwrap();
+    assert!(reencoded == stored);
+}
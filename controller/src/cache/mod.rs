@@ -24,6 +24,7 @@ where
     pub egld_ticker: ManagedBuffer<C::Api>,
     pub allow_unsafe_price: bool,
     pub flash_loan_ongoing: bool,
+    pub sync_indexes: bool,
     pub safe_price_view: ManagedAddress<C::Api>,
     pub current_timestamp: TimestampMillis,
 }
@@ -41,10 +42,20 @@ where
         let egld_provider = sc_ref.token_oracle(&egld_token_id).get();
         let mut asset_oracles = ManagedMapEncoded::new();
         asset_oracles.put(&egld_token_id, &egld_provider);
+        // The EGLD reference price backs every EGLD-denominated value; honour the
+        // dedicated bound when it is stricter than the oracle one.
+        let mut egld_max_seconds_stale = egld_provider.max_price_stale_seconds;
+        let egld_reference_bound = sc_ref.egld_max_price_stale_seconds();
+        if !egld_reference_bound.is_empty() {
+            let reference_bound = egld_reference_bound.get();
+            if reference_bound < egld_max_seconds_stale {
+                egld_max_seconds_stale = reference_bound;
+            }
+        }
         let egld_price_feed = sc_ref.aggregator_price_feed(
             egld_token_id.clone().into_name(),
             &price_aggregator,
-            egld_provider.max_price_stale_seconds,
+            egld_max_seconds_stale,
             false,
         );
         let egld_usd_price_wad = sc_ref.to_decimal_wad(egld_price_feed.price);
@@ -61,6 +72,7 @@ where
             egld_ticker: egld_token_id.into_name(),
             allow_unsafe_price: true,
             flash_loan_ongoing: sc_ref.flash_loan_ongoing().get(),
+            sync_indexes: true,
             safe_price_view: sc_ref.safe_price_view().get(),
             current_timestamp: sc_ref.blockchain().get_block_timestamp_millis(),
         }
@@ -98,6 +110,7 @@ where
 
     /// Retrieves or caches market index data for a token.
     /// Updates indexes through oracle if not cached, reducing repeated calculations.
+    /// With `sync_indexes` off, returns the indexes stored at the market's last sync instead.
     /// Returns current supply and borrow indexes for the market.
     pub fn cached_market_index(
         &mut self,
@@ -108,7 +121,15 @@ where
             return self.market_indexes.get(token_id);
         }
 
-        let new = self.sc_ref.update_asset_index(token_id, self, true);
+        let new = if self.sync_indexes {
+            self.sc_ref.update_asset_index(token_id, self, true)
+        } else {
+            let pool_address = self.cached_pool_address(token_id);
+            MarketIndex {
+                borrow_index_ray: self.sc_ref.borrow_index(pool_address.clone()).get(),
+                supply_index_ray: self.sc_ref.supply_index(pool_address).get(),
+            }
+        };
         self.market_indexes.put(token_id, &new);
 
         new
@@ -131,7 +152,9 @@ where
             return self.asset_oracles.get(&canonical_token_id);
         }
 
-        let new = self.sc_ref.token_oracle(&canonical_token_id).get();
+        let mut new = self.sc_ref.token_oracle(&canonical_token_id).get();
+        self.sc_ref
+            .apply_price_staleness_override(&canonical_token_id, &mut new);
         self.asset_oracles.put(&canonical_token_id, &new);
 
         new
@@ -4,6 +4,11 @@ use crate::helpers;
 use crate::oracle;
 use crate::storage;
 use crate::utils;
+use crate::validation;
+use common_constants::{
+    BPS, DEFAULT_TWAP_ROUNDS, MAX_BATCH_ACCOUNTS, MAX_MARKET_ACTIVITY_CAPACITY, MAX_ORACLE_QUORUM,
+    MAX_TWAP_ROUNDS, RAY,
+};
 use common_errors::*;
 pub use common_events::*;
 pub use common_proxies::*;
@@ -36,6 +41,7 @@ pub trait ConfigModule:
     + helpers::MathsModule
     + common_math::SharedMathModule
     + common_rates::InterestRates
+    + validation::ValidationModule
 {
     /// Registers a new NFT token for tracking account positions in the lending protocol.
     ///
@@ -94,8 +100,12 @@ pub trait ConfigModule:
     /// - `source`: Exchange source (e.g., XExchange).
     /// - `first_tolerance`, `last_tolerance`: Tolerance values for price fluctuations.
     ///
+    /// The `Twap` pricing method averages `DEFAULT_TWAP_ROUNDS` safe price rounds in its
+    /// fallback until `editTokenOracleTwapRounds` changes the window.
+    ///
     /// # Errors
     /// - `ERROR_ORACLE_TOKEN_NOT_FOUND`: If oracle already exists for the token.
+    /// - `ERROR_INVALID_EXCHANGE_SOURCE`: If the `Twap` pricing method is used without xExchange.
     #[only_owner]
     #[endpoint(setTokenOracle)]
     fn set_token_oracle(
@@ -111,97 +121,79 @@ pub trait ConfigModule:
         max_price_stale_seconds: DurationSeconds,
         optional_one_dex_pair_id: OptionalValue<usize>,
     ) {
-        let mapper = self.token_oracle(market_token);
-
-        require!(mapper.is_empty(), ERROR_ORACLE_TOKEN_EXISTING);
         let one_dex_pair_id = match optional_one_dex_pair_id {
             OptionalValue::Some(id) => id,
             OptionalValue::None => 0,
         };
-        let first_token_id = match source {
-            ExchangeSource::LXOXNO => {
-                let first_token_id = self
-                    .tx()
-                    .to(contract_address)
-                    .typed(proxy_lxoxno::RsLiquidXoxnoProxy)
-                    .main_token()
-                    .returns(ReturnsResult)
-                    .sync_call_readonly();
-                EgldOrEsdtTokenIdentifier::esdt(first_token_id)
-            },
-            ExchangeSource::Onedex => {
-                require!(one_dex_pair_id > 0, ERROR_INVALID_ONEDEX_PAIR_ID);
-                let first_token_id = self
-                    .tx()
-                    .to(contract_address)
-                    .typed(proxy_onedex::OneDexProxy)
-                    .pair_first_token_id(one_dex_pair_id)
-                    .returns(ReturnsResult)
-                    .sync_call_readonly();
-                EgldOrEsdtTokenIdentifier::esdt(first_token_id)
-            },
-            ExchangeSource::XExchange => {
-                let first_token_id = self
-                    .tx()
-                    .to(contract_address)
-                    .typed(proxy_xexchange_pair::PairProxy)
-                    .first_token_id()
-                    .returns(ReturnsResult)
-                    .sync_call_readonly();
-                EgldOrEsdtTokenIdentifier::esdt(first_token_id)
-            },
-            ExchangeSource::XEGLD => EgldOrEsdtTokenIdentifier::egld(),
-            ExchangeSource::LEGLD => EgldOrEsdtTokenIdentifier::egld(),
-            _ => {
-                panic!("Invalid exchange source")
-            },
-        };
-
-        let second_token_id = match source {
-            ExchangeSource::XExchange => {
-                let token_id = self
-                    .tx()
-                    .to(contract_address)
-                    .typed(proxy_xexchange_pair::PairProxy)
-                    .second_token_id()
-                    .returns(ReturnsResult)
-                    .sync_call_readonly();
-                EgldOrEsdtTokenIdentifier::esdt(token_id)
-            },
-            ExchangeSource::Onedex => {
-                let token_id = self
-                    .tx()
-                    .to(contract_address)
-                    .typed(proxy_onedex::OneDexProxy)
-                    .pair_second_token_id(one_dex_pair_id)
-                    .returns(ReturnsResult)
-                    .sync_call_readonly();
-                EgldOrEsdtTokenIdentifier::esdt(token_id)
-            },
-            ExchangeSource::XEGLD => first_token_id.clone(),
-            ExchangeSource::LEGLD => first_token_id.clone(),
-            ExchangeSource::LXOXNO => first_token_id.clone(),
-            _ => {
-                panic!("Invalid exchange source")
-            },
-        };
-
-        let tolerance = self.validate_and_calculate_tolerances(&first_tolerance, &last_tolerance);
-
-        let oracle = OracleProvider {
-            base_token_id: first_token_id,
-            quote_token_id: second_token_id,
-            oracle_contract_address: contract_address.clone(),
-            oracle_type: token_type,
-            exchange_source: source,
-            asset_decimals: decimals,
+        self.configure_token_oracle(
+            market_token,
+            decimals,
+            contract_address,
             pricing_method,
-            tolerance,
-            onedex_pair_id: one_dex_pair_id,
+            token_type,
+            source,
+            first_tolerance,
+            last_tolerance,
             max_price_stale_seconds,
-        };
-        self.update_asset_oracle_event(market_token, &oracle);
-        mapper.set(&oracle);
+            one_dex_pair_id,
+        );
+    }
+
+    /// Configures the oracles for several tokens in a single transaction.
+    /// Applies the same validation as `setTokenOracle` to every entry.
+    ///
+    /// # Arguments
+    /// - `entries`: Oracle configurations as `(market_token, decimals, contract_address,
+    ///   pricing_method, token_type, source, first_tolerance, last_tolerance,
+    ///   max_price_stale_seconds, one_dex_pair_id)`; use `0` as pair id for non-Onedex sources.
+    ///
+    /// # Errors
+    /// - `ERROR_ORACLE_TOKEN_EXISTING`: If an oracle already exists for any entry's token.
+    #[only_owner]
+    #[endpoint(batchSetTokenOracle)]
+    fn batch_set_token_oracle(
+        &self,
+        entries: MultiValueEncoded<
+            MultiValue10<
+                EgldOrEsdtTokenIdentifier,
+                usize,
+                ManagedAddress,
+                PricingMethod,
+                OracleType,
+                ExchangeSource,
+                BigUint,
+                BigUint,
+                DurationSeconds,
+                usize,
+            >,
+        >,
+    ) {
+        for entry in entries {
+            let (
+                market_token,
+                decimals,
+                contract_address,
+                pricing_method,
+                token_type,
+                source,
+                first_tolerance,
+                last_tolerance,
+                max_price_stale_seconds,
+                one_dex_pair_id,
+            ) = entry.into_tuple();
+            self.configure_token_oracle(
+                &market_token,
+                decimals,
+                &contract_address,
+                pricing_method,
+                token_type,
+                source,
+                first_tolerance,
+                last_tolerance,
+                max_price_stale_seconds,
+                one_dex_pair_id,
+            );
+        }
     }
 
     /// Updates the tolerance settings for a token’s oracle.
@@ -233,6 +225,64 @@ pub trait ConfigModule:
         });
     }
 
+    /// Updates the oracle quorum requirement for a token’s oracle.
+    /// Requires that many independent price sources to agree within the last tolerance.
+    ///
+    /// # Arguments
+    /// - `market_token`: Token identifier (EGLD or ESDT).
+    /// - `min_oracle_quorum`: Number of agreeing sources required (0 disables the requirement).
+    ///
+    /// # Errors
+    /// - `ERROR_ORACLE_TOKEN_NOT_FOUND`: If no oracle exists for the token.
+    /// - `ERROR_INVALID_ORACLE_QUORUM`: If the quorum exceeds the number of supported sources.
+    #[only_owner]
+    #[endpoint(editTokenOracleQuorum)]
+    fn edit_token_oracle_quorum(
+        &self,
+        market_token: &EgldOrEsdtTokenIdentifier,
+        min_oracle_quorum: u8,
+    ) {
+        require!(
+            !self.token_oracle(market_token).is_empty(),
+            ERROR_ORACLE_TOKEN_NOT_FOUND
+        );
+        require!(
+            min_oracle_quorum <= MAX_ORACLE_QUORUM,
+            ERROR_INVALID_ORACLE_QUORUM
+        );
+
+        self.min_oracle_quorum(market_token).set(min_oracle_quorum);
+    }
+
+    /// Updates the number of safe price rounds averaged by a token's TWAP fallback.
+    /// Only used by the `Twap` pricing method, when the primary price is unavailable.
+    ///
+    /// # Arguments
+    /// - `market_token`: Token identifier (EGLD or ESDT).
+    /// - `twap_rounds`: Number of 15-minute safe price rounds to average.
+    ///
+    /// # Errors
+    /// - `ERROR_ORACLE_TOKEN_NOT_FOUND`: If no oracle exists for the token.
+    /// - `ERROR_INVALID_TWAP_ROUNDS`: If the rounds are zero or exceed `MAX_TWAP_ROUNDS`.
+    #[only_owner]
+    #[endpoint(editTokenOracleTwapRounds)]
+    fn edit_token_oracle_twap_rounds(
+        &self,
+        market_token: &EgldOrEsdtTokenIdentifier,
+        twap_rounds: u8,
+    ) {
+        require!(
+            !self.token_oracle(market_token).is_empty(),
+            ERROR_ORACLE_TOKEN_NOT_FOUND
+        );
+        require!(
+            twap_rounds > 0 && twap_rounds <= MAX_TWAP_ROUNDS,
+            ERROR_INVALID_TWAP_ROUNDS
+        );
+
+        self.twap_rounds(market_token).set(twap_rounds);
+    }
+
     /// Sets the price aggregator contract address.
     /// Configures the source for aggregated price data.
     ///
@@ -273,6 +323,37 @@ pub trait ConfigModule:
         self.swap_router().set(&address);
     }
 
+    /// Sets the default swap router steps for converting one token into another.
+    ///
+    /// **Purpose**: Lets strategy calls omit the swap steps for common pairs. When a
+    /// strategy swaps `from_token` into `to_token` with empty steps, the router is
+    /// called with these steps instead (e.g. the preferred pools for the pair).
+    ///
+    /// # Arguments
+    /// - `from_token`: Token identifier (EGLD or ESDT) being swapped
+    /// - `to_token`: Token identifier (EGLD or ESDT) received from the swap
+    /// - `steps`: Raw swap router arguments; empty removes the default
+    ///
+    /// # Errors
+    /// - `ERROR_ASSETS_ARE_THE_SAME`: If both tokens are the same
+    #[only_owner]
+    #[endpoint(setDefaultSwapSteps)]
+    fn set_default_swap_steps(
+        &self,
+        from_token: EgldOrEsdtTokenIdentifier,
+        to_token: EgldOrEsdtTokenIdentifier,
+        steps: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        require!(from_token != to_token, ERROR_ASSETS_ARE_THE_SAME);
+
+        if steps.is_empty() {
+            self.default_swap_steps(&from_token, &to_token).clear();
+        } else {
+            self.default_swap_steps(&from_token, &to_token)
+                .set(steps.to_vec());
+        }
+    }
+
     /// Sets the accumulator contract address.
     /// Configures where protocol revenue is collected.
     ///
@@ -462,7 +543,8 @@ pub trait ConfigModule:
     }
 
     /// Adds an asset to an e-mode category with usage flags.
-    /// Configures collateral and borrowability in e-mode.
+    /// Configures collateral and borrowability in e-mode; the asset can back borrows on its own
+    /// until `setEModeCompanionCollateralRequired` requires a companion collateral.
     ///
     /// # Arguments
     /// - `asset`: Token identifier (EGLD or ESDT).
@@ -550,6 +632,37 @@ pub trait ConfigModule:
         map.insert(asset, config);
     }
 
+    /// Sets whether an e-mode collateral needs another eligible collateral to back a borrow.
+    /// A flagged asset is rejected as the sole e-mode collateral of a borrowing account.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT).
+    /// - `category_id`: E-mode category ID.
+    /// - `required`: Whether a companion collateral is required.
+    ///
+    /// # Errors
+    /// - `ERROR_EMODE_CATEGORY_NOT_FOUND`: If the category ID does not exist.
+    /// - `ERROR_ASSET_NOT_SUPPORTED_IN_EMODE`: If the asset is not in the category.
+    #[only_owner]
+    #[endpoint(setEModeCompanionCollateralRequired)]
+    fn set_e_mode_companion_collateral_required(
+        &self,
+        asset: EgldOrEsdtTokenIdentifier,
+        category_id: u8,
+        required: bool,
+    ) {
+        let map = self.e_mode_assets(category_id);
+        require!(!map.is_empty(), ERROR_EMODE_CATEGORY_NOT_FOUND);
+        require!(map.contains_key(&asset), ERROR_ASSET_NOT_SUPPORTED_IN_EMODE);
+
+        let mapper = self.e_mode_companion_collateral_required(category_id, &asset);
+        if required {
+            mapper.set(true);
+        } else {
+            mapper.clear();
+        }
+    }
+
     /// Removes an asset from an e-mode category.
     /// Disables the asset’s e-mode capabilities for the category.
     ///
@@ -578,6 +691,8 @@ pub trait ConfigModule:
         let config = e_mode_assets.remove(&asset);
         let mut asset_e_modes = self.asset_e_modes(&asset);
         asset_e_modes.swap_remove(&category_id);
+        self.e_mode_companion_collateral_required(category_id, &asset)
+            .clear();
 
         self.update_e_mode_asset_event(&asset, &unsafe { config.unwrap_unchecked() }, category_id);
         if asset_e_modes.is_empty() {
@@ -606,12 +721,15 @@ pub trait ConfigModule:
     /// - `is_collateralizable`: Flag for collateral usability.
     /// - `is_borrowable`: Flag for borrowability.
     /// - `isolation_borrow_enabled`: Flag for borrowing in isolation mode.
-    /// - `borrow_cap`: New borrow cap (zero for no cap).
+    /// - `borrow_cap`: New borrow cap (zero for no cap), in the current cap denomination.
     /// - `supply_cap`: New supply cap (zero for no cap).
+    /// - `max_liquidation_close_factor`: Share of the debt a single liquidation can repay in
+    ///   BPS; keeps the current value when omitted.
     ///
     /// # Errors
     /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool or config.
     /// - `ERROR_INVALID_LIQUIDATION_THRESHOLD`: If threshold is not greater than LTV.
+    /// - `ERROR_INVALID_LIQUIDATION_CLOSE_FACTOR`: If the close factor is zero or above 10000.
     #[only_owner]
     #[endpoint(editAssetConfig)]
     fn edit_asset_config(
@@ -631,6 +749,7 @@ pub trait ConfigModule:
         isolation_borrow_enabled: bool,
         borrow_cap: BigUint,
         supply_cap: BigUint,
+        max_liquidation_close_factor: OptionalValue<BigUint>,
     ) {
         require!(
             !self.pools_map(&asset).is_empty(),
@@ -649,6 +768,11 @@ pub trait ConfigModule:
         );
 
         let old_config = map.get();
+        if let Some(close_factor) = max_liquidation_close_factor.into_option() {
+            self.validate_liquidation_close_factor(&close_factor);
+            self.max_liquidation_close_factor_bps(&asset)
+                .set(close_factor);
+        }
 
         let new_config = &AssetConfig {
             loan_to_value_bps: self.to_decimal_bps(loan_to_value),
@@ -681,6 +805,106 @@ pub trait ConfigModule:
         self.update_asset_config_event(&asset, new_config);
     }
 
+    /// Sets the borrow cap of an asset together with the unit it is expressed in.
+    ///
+    /// **Purpose**: A cap in token units drifts in value as the asset's price moves.
+    /// Denominating it in EGLD keeps the cap value-stable: new borrows are checked against
+    /// the EGLD value of the market's outstanding debt at the current price.
+    ///
+    /// **Default Configuration**: token units, as set by `createLiquidityPool`
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT).
+    /// - `borrow_cap`: New borrow cap, in asset decimals for `Token` or EGLD WAD for `Egld`;
+    ///   `0` removes the cap
+    /// - `denomination`: Unit the cap is expressed in
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool or config.
+    #[only_owner]
+    #[endpoint(setBorrowCap)]
+    fn set_borrow_cap(
+        &self,
+        asset: EgldOrEsdtTokenIdentifier,
+        borrow_cap: BigUint,
+        denomination: CapDenomination,
+    ) {
+        require!(
+            !self.pools_map(&asset).is_empty(),
+            ERROR_ASSET_NOT_SUPPORTED
+        );
+
+        let map = self.asset_config(&asset);
+        require!(!map.is_empty(), ERROR_ASSET_NOT_SUPPORTED);
+
+        let mut asset_config = map.get();
+        asset_config.borrow_cap_wad = if borrow_cap == BigUint::zero() {
+            None
+        } else {
+            Some(borrow_cap)
+        };
+        map.set(&asset_config);
+        self.borrow_cap_denomination(&asset).set(denomination);
+
+        self.update_asset_config_event(&asset, &asset_config);
+    }
+
+    /// Sets the dust threshold under which leftover debt is written off on repay.
+    ///
+    /// **Purpose**: A near-full repayment can leave a few wei of debt that keeps the
+    /// borrow position, and the account NFT, alive. Remainders worth less than this
+    /// threshold are absorbed by the market, so a single repay fully closes the position.
+    ///
+    /// **Default Configuration**: `0`, dust is never written off
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT).
+    /// - `dust_threshold`: EGLD value of the largest absorbed remainder (WAD); `0` disables it
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool.
+    #[only_owner]
+    #[endpoint(setDustThreshold)]
+    fn set_dust_threshold(&self, asset: EgldOrEsdtTokenIdentifier, dust_threshold: BigUint) {
+        require!(
+            !self.pools_map(&asset).is_empty(),
+            ERROR_ASSET_NOT_SUPPORTED
+        );
+
+        self.dust_threshold_wad(&asset).set(dust_threshold);
+    }
+
+    /// Sets the liquidation haircut of a collateral asset.
+    ///
+    /// **Purpose**: The mark price of thinly-traded collateral overstates what a liquidator
+    /// can sell it for. The haircut discounts the asset's value in the liquidation collateral,
+    /// and so in the health factor, to account for that slippage. Borrow-side LTV math keeps
+    /// the full value.
+    ///
+    /// **Default Configuration**: `0`, collateral is valued at its mark price
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT).
+    /// - `haircut_bps`: Discount applied to the collateral value (BPS); `0` disables it
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool.
+    /// - `ERROR_INVALID_LIQUIDATION_HAIRCUT`: If `haircut_bps` is 10000 or more.
+    #[only_owner]
+    #[endpoint(setLiquidationHaircut)]
+    fn set_liquidation_haircut(&self, asset: EgldOrEsdtTokenIdentifier, haircut_bps: BigUint) {
+        require!(
+            !self.pools_map(&asset).is_empty(),
+            ERROR_ASSET_NOT_SUPPORTED
+        );
+        require!(
+            haircut_bps < BigUint::from(BPS),
+            ERROR_INVALID_LIQUIDATION_HAIRCUT
+        );
+
+        self.liquidation_haircut_bps(&asset).set(haircut_bps);
+    }
+
     /// Sets the position limits for NFT accounts.
     /// Configures maximum number of borrow and supply positions per NFT.
     ///
@@ -710,22 +934,885 @@ pub trait ConfigModule:
         self.position_limits().set(limits);
     }
 
-    /// Disables the oracle for a token.
-    /// Prevents the token from being used as a price feed.
+    /// Sets how many account NFTs a single batch strategy call can operate on.
+    ///
+    /// **Purpose**: Lets power users managing several accounts top them up together in one
+    /// atomic call, while bounding the gas a batch can consume.
+    ///
+    /// **Default Configuration**: 0 (batch strategies are disabled)
     ///
     /// # Arguments
-    /// - `token_id`: Token identifier (EGLD or ESDT).
+    /// - `max_accounts`: Maximum number of account NFTs per batch; `0` disables batching
     ///
     /// # Errors
-    /// - `ERROR_ORACLE_TOKEN_NOT_FOUND`: If oracle exists for the token.
+    /// - `ERROR_INVALID_MAX_BATCH_ACCOUNTS`: If `max_accounts` exceeds the maximum
     #[only_owner]
-    #[endpoint(disableTokenOracle)]
-    fn disable_token_oracle(&self, token_id: &EgldOrEsdtTokenIdentifier) {
-        let mapper = self.token_oracle(token_id);
-        require!(!mapper.is_empty(), ERROR_ORACLE_TOKEN_NOT_FOUND);
-        mapper.update(|oracle| {
-            oracle.oracle_type = OracleType::None;
-            self.update_asset_oracle_event(token_id, oracle);
-        });
+    #[endpoint(setMaxBatchAccounts)]
+    fn set_max_batch_accounts(&self, max_accounts: usize) {
+        require!(
+            max_accounts <= MAX_BATCH_ACCOUNTS,
+            ERROR_INVALID_MAX_BATCH_ACCOUNTS
+        );
+
+        if max_accounts == 0 {
+            self.max_batch_accounts().clear();
+        } else {
+            self.max_batch_accounts().set(max_accounts);
+        }
+    }
+
+    /// Sets the share of the liquidation bonus returned to the liquidated account.
+    ///
+    /// **Purpose**: Softens the liquidation penalty by leaving a fraction of the
+    /// seized-beyond-debt collateral in the borrower's deposit position instead of
+    /// transferring it to the liquidator.
+    ///
+    /// **Default Configuration**: 0 (the liquidator keeps the full bonus)
+    ///
+    /// # Arguments
+    /// - `rebate_bps`: Share of the bonus portion credited back, in basis points
+    ///
+    /// # Errors
+    /// - `ERROR_INVALID_LIQUIDATEE_REBATE`: If `rebate_bps` exceeds 10000
+    #[only_owner]
+    #[endpoint(setLiquidateeRebate)]
+    fn set_liquidatee_rebate(&self, rebate_bps: BigUint) {
+        require!(
+            rebate_bps <= BigUint::from(BPS),
+            ERROR_INVALID_LIQUIDATEE_REBATE
+        );
+        self.liquidatee_rebate_bps().set(rebate_bps);
+    }
+
+    /// Marks an account as protocol-owned, exempting it from per-account caps.
+    ///
+    /// **Purpose**: Lets accounts held by the protocol itself (e.g. for revenue
+    /// reinvestment) open as many positions as needed without being blocked by
+    /// the limits meant to keep user liquidations within gas bounds. The account
+    /// also skips its borrow allowance and the protocol-wide maximum position value.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: Account NFT nonce to whitelist
+    ///
+    /// # Errors
+    /// - `ERROR_ACCOUNT_NOT_IN_THE_MARKET`: If the account does not exist
+    #[only_owner]
+    #[endpoint(addProtocolOwnedAccount)]
+    fn add_protocol_owned_account(&self, account_nonce: u64) {
+        require!(
+            self.accounts().contains(&account_nonce),
+            ERROR_ACCOUNT_NOT_IN_THE_MARKET
+        );
+        self.protocol_owned_accounts().insert(account_nonce);
+    }
+
+    /// Removes an account from the protocol-owned whitelist.
+    /// Position limits and per-account value caps apply again to any new positions it opens.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: Account NFT nonce to remove from the whitelist
+    #[only_owner]
+    #[endpoint(removeProtocolOwnedAccount)]
+    fn remove_protocol_owned_account(&self, account_nonce: u64) {
+        self.protocol_owned_accounts().swap_remove(&account_nonce);
+    }
+
+    /// Adds or removes addresses from the borrow cap exemption whitelist.
+    ///
+    /// **Purpose**: Lets protocol-operated borrowers (e.g. the treasury or a market
+    /// maker providing protocol-owned liquidity) borrow beyond the asset-wide borrow
+    /// caps. Account-level borrow cap overrides still apply to their accounts.
+    ///
+    /// # Arguments
+    /// - `exempt`: `true` to whitelist the addresses, `false` to remove them
+    /// - `addresses`: Borrower addresses to update
+    #[only_owner]
+    #[endpoint(setBorrowCapExemptAddresses)]
+    fn set_borrow_cap_exempt_addresses(
+        &self,
+        exempt: bool,
+        addresses: MultiValueEncoded<ManagedAddress>,
+    ) {
+        let mut exempt_addresses = self.borrow_cap_exempt_addresses();
+        for address in addresses {
+            if exempt {
+                exempt_addresses.insert(address);
+            } else {
+                exempt_addresses.swap_remove(&address);
+            }
+        }
+    }
+
+    /// Sets the minimum health factor required after leveraged strategy operations.
+    ///
+    /// **Purpose**: Makes `multiply`, `swapDebt` and `swapCollateral` leave a larger
+    /// buffer above the liquidation threshold than regular borrows and withdrawals,
+    /// reducing the risk of a position being liquidatable right after leveraging.
+    ///
+    /// **Default Configuration**: unset (strategies use the regular 1.0 threshold)
+    ///
+    /// # Arguments
+    /// - `min_hf_ray`: Minimum health factor in RAY precision (e.g. 1.1 = 1.1e27)
+    ///
+    /// # Errors
+    /// - `ERROR_INVALID_MIN_STRATEGY_HEALTH_FACTOR`: If `min_hf_ray` is below 1.0
+    #[only_owner]
+    #[endpoint(setMinStrategyHealthFactor)]
+    fn set_min_strategy_health_factor(&self, min_hf_ray: BigUint) {
+        require!(
+            min_hf_ray >= BigUint::from(RAY),
+            ERROR_INVALID_MIN_STRATEGY_HEALTH_FACTOR
+        );
+        self.min_strategy_hf_ray().set(min_hf_ray);
+    }
+
+    /// Sets the origination fee charged when opening leverage with `multiply`.
+    ///
+    /// **Purpose**: Prices leverage as a premium feature. The fee is taken from the
+    /// flash-loaned debt on top of the market's flash loan fee and added to the debt
+    /// market's protocol revenue.
+    ///
+    /// **Default Configuration**: 0 (only the flash loan fee applies)
+    ///
+    /// # Arguments
+    /// - `fee_bps`: Origination fee in basis points of the flash-loaned debt
+    ///
+    /// # Errors
+    /// - `ERROR_INVALID_MULTIPLY_ORIGINATION_FEE`: If `fee_bps` is not below 10000
+    #[only_owner]
+    #[endpoint(setMultiplyOriginationFee)]
+    fn set_multiply_origination_fee(&self, fee_bps: BigUint) {
+        require!(
+            fee_bps < BigUint::from(BPS),
+            ERROR_INVALID_MULTIPLY_ORIGINATION_FEE
+        );
+        self.multiply_origination_fee_bps().set(fee_bps);
+    }
+
+    /// Sets the share of each flash loan fee routed to the accumulator.
+    ///
+    /// **Purpose**: Lets flash loan revenue flow to the protocol fee sink like the
+    /// revenue collected through `claimRevenue`. The share is sent to the accumulator
+    /// in the same transaction as the flash loan; the remainder stays as pool revenue.
+    ///
+    /// **Default Configuration**: 0 (the whole fee stays in the pool)
+    ///
+    /// # Arguments
+    /// - `share_bps`: Share of the flash loan fee in basis points
+    ///
+    /// # Errors
+    /// - `ERROR_INVALID_FLASH_LOAN_PROTOCOL_SHARE`: If `share_bps` exceeds 10000
+    #[only_owner]
+    #[endpoint(setFlashLoanProtocolShare)]
+    fn set_flash_loan_protocol_share(&self, share_bps: BigUint) {
+        require!(
+            share_bps <= BigUint::from(BPS),
+            ERROR_INVALID_FLASH_LOAN_PROTOCOL_SHARE
+        );
+        self.flash_loan_protocol_share_bps().set(share_bps);
+    }
+
+    /// Enables or disables the creation of new debt across every market.
+    ///
+    /// **Purpose**: Acts as a global kill-switch for a fast, coordinated incident
+    /// response. While disabled, `borrow`, `multiply` and `flashLoan` revert in every
+    /// market; supplies, repayments, withdrawals and liquidations keep working so
+    /// users can still reduce their risk.
+    ///
+    /// **Default Configuration**: enabled
+    ///
+    /// # Arguments
+    /// - `enabled`: `false` blocks new debt, `true` allows it again
+    #[only_owner]
+    #[endpoint(setDebtCreationEnabled)]
+    fn set_debt_creation_enabled(&self, enabled: bool) {
+        if enabled {
+            self.debt_creation_disabled().clear();
+        } else {
+            self.debt_creation_disabled().set(true);
+        }
+    }
+
+    /// Pauses new supplies and borrows of a single market.
+    ///
+    /// **Purpose**: Lets risk managers freeze one risky asset without pausing the whole
+    /// controller. While paused, supplying or borrowing the asset reverts; repayments,
+    /// withdrawals and liquidations keep working so users can still exit the market.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market to pause
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool
+    #[only_owner]
+    #[endpoint(pauseMarket)]
+    fn pause_market(&self, asset: EgldOrEsdtTokenIdentifier) {
+        require!(
+            !self.pools_map(&asset).is_empty(),
+            ERROR_ASSET_NOT_SUPPORTED
+        );
+        self.market_paused(&asset).set(true);
+    }
+
+    /// Resumes new supplies and borrows of a market paused with `pauseMarket`.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market to unpause
+    #[only_owner]
+    #[endpoint(unpauseMarket)]
+    fn unpause_market(&self, asset: EgldOrEsdtTokenIdentifier) {
+        self.market_paused(&asset).clear();
+    }
+
+    /// Enables or disables new supplies of a single market.
+    ///
+    /// **Purpose**: Lets governance wind a market down without touching its
+    /// collateral eligibility. While disabled, supplying the asset reverts; existing
+    /// deposits keep backing their borrows and can still be withdrawn or liquidated.
+    ///
+    /// **Default Configuration**: enabled
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market
+    /// - `enabled`: `false` blocks new supplies, `true` allows them again
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool
+    #[only_owner]
+    #[endpoint(setNewSupplyEnabled)]
+    fn set_new_supply_enabled(&self, asset: EgldOrEsdtTokenIdentifier, enabled: bool) {
+        require!(
+            !self.pools_map(&asset).is_empty(),
+            ERROR_ASSET_NOT_SUPPORTED
+        );
+        if enabled {
+            self.new_supply_disabled(&asset).clear();
+        } else {
+            self.new_supply_disabled(&asset).set(true);
+        }
+    }
+
+    /// Enables or disables new borrows of a single market.
+    ///
+    /// **Purpose**: Stops new debt in one asset independently of its borrowability
+    /// flag and of new supplies. While disabled, borrowing the asset reverts; existing
+    /// debt keeps accruing and can still be repaid or liquidated.
+    ///
+    /// **Default Configuration**: enabled
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market
+    /// - `enabled`: `false` blocks new borrows, `true` allows them again
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool
+    #[only_owner]
+    #[endpoint(setNewBorrowEnabled)]
+    fn set_new_borrow_enabled(&self, asset: EgldOrEsdtTokenIdentifier, enabled: bool) {
+        require!(
+            !self.pools_map(&asset).is_empty(),
+            ERROR_ASSET_NOT_SUPPORTED
+        );
+        if enabled {
+            self.new_borrow_disabled(&asset).clear();
+        } else {
+            self.new_borrow_disabled(&asset).set(true);
+        }
+    }
+
+    /// Chooses whether repayments of a market reduce principal or accrued interest first.
+    ///
+    /// **Purpose**: Sets how a partial repayment is split between the principal and the
+    /// accrued interest of a debt, as reported by `getPositionAccruedInterest`. The
+    /// outstanding debt itself is the same under both orders.
+    ///
+    /// **Default Configuration**: interest first
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market
+    /// - `enabled`: `true` applies repayments to principal first, `false` to interest first
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool
+    #[only_owner]
+    #[endpoint(setRepayPrincipalFirst)]
+    fn set_repay_principal_first(&self, asset: EgldOrEsdtTokenIdentifier, enabled: bool) {
+        require!(
+            !self.pools_map(&asset).is_empty(),
+            ERROR_ASSET_NOT_SUPPORTED
+        );
+        if enabled {
+            self.repay_principal_first(&asset).set(true);
+        } else {
+            self.repay_principal_first(&asset).clear();
+        }
+    }
+
+    /// Enables or disables the index sync performed by health factor views.
+    ///
+    /// **Purpose**: Health factor views accrue interest up to the current timestamp
+    /// before valuing positions. Disabling the sync makes them report the value as of
+    /// each market's last on-chain update, which is cheaper for indexers that refresh
+    /// markets themselves. `getHealthFactorSynced` always syncs.
+    ///
+    /// **Default Configuration**: enabled
+    ///
+    /// # Arguments
+    /// - `enabled`: `false` reads indexes as of the last sync, `true` accrues them first
+    #[only_owner]
+    #[endpoint(setViewIndexSyncEnabled)]
+    fn set_view_index_sync_enabled(&self, enabled: bool) {
+        if enabled {
+            self.view_index_sync_disabled().clear();
+        } else {
+            self.view_index_sync_disabled().set(true);
+        }
+    }
+
+    /// Sets the minimum share of a market's liquidity that must stay unborrowed.
+    ///
+    /// **Purpose**: Keeps a liquidity buffer for withdrawals by rejecting borrows that
+    /// would push utilization above `(10000 - min_reserve_ratio_bps) / 10000`. This cap
+    /// is independent of the optimal utilization used by the rate curve.
+    ///
+    /// **Default Configuration**: 0 (borrows may use all available liquidity)
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market
+    /// - `min_reserve_ratio_bps`: Reserve ratio in basis points
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool
+    /// - `ERROR_INVALID_MIN_RESERVE_RATIO`: If `min_reserve_ratio_bps` exceeds 10000
+    #[only_owner]
+    #[endpoint(setMinReserveRatio)]
+    fn set_min_reserve_ratio(
+        &self,
+        asset: EgldOrEsdtTokenIdentifier,
+        min_reserve_ratio_bps: BigUint,
+    ) {
+        require!(
+            !self.pools_map(&asset).is_empty(),
+            ERROR_ASSET_NOT_SUPPORTED
+        );
+        require!(
+            min_reserve_ratio_bps <= BigUint::from(BPS),
+            ERROR_INVALID_MIN_RESERVE_RATIO
+        );
+        self.min_reserve_ratio_bps(&asset)
+            .set(min_reserve_ratio_bps);
+    }
+
+    /// Sets the maximum collateral price drop tolerated by liquidations in a single update.
+    ///
+    /// **Purpose**: Protects borrowers from liquidations triggered by a suspicious price
+    /// crash. When a collateral price fell by more than this share since the last check,
+    /// the liquidation is deferred to the next block instead of executing.
+    ///
+    /// **Default Configuration**: 0 (no regression check)
+    ///
+    /// # Arguments
+    /// - `max_drop_bps`: Maximum price drop in basis points
+    ///
+    /// # Errors
+    /// - `ERROR_INVALID_MAX_LIQUIDATION_PRICE_DROP`: If `max_drop_bps` exceeds 10000
+    #[only_owner]
+    #[endpoint(setMaxLiquidationPriceDrop)]
+    fn set_max_liquidation_price_drop(&self, max_drop_bps: BigUint) {
+        require!(
+            max_drop_bps <= BigUint::from(BPS),
+            ERROR_INVALID_MAX_LIQUIDATION_PRICE_DROP
+        );
+        self.max_liquidation_price_drop_bps().set(max_drop_bps);
+    }
+
+    /// Sets the collateral value credited for claiming after a debt-clearing liquidation.
+    ///
+    /// **Purpose**: Avoids stranding dust collateral in accounts whose debt was fully
+    /// repaid by a liquidation. When no borrow remains and the leftover collateral is
+    /// worth at most this value, it is withdrawn from the pools and credited to the
+    /// account, to be claimed by whoever presents the account NFT next.
+    ///
+    /// **Default Configuration**: 0 (dust collateral stays in the account)
+    ///
+    /// # Arguments
+    /// - `threshold_usd_wad`: Maximum returned collateral value in USD, WAD precision
+    #[only_owner]
+    #[endpoint(setLiquidationDustThreshold)]
+    fn set_liquidation_dust_threshold(&self, threshold_usd_wad: BigUint) {
+        self.liquidation_dust_threshold_usd().set(threshold_usd_wad);
+    }
+
+    /// Sets the borrow allowance of an account, acting as a credit line ceiling.
+    ///
+    /// **Purpose**: Lets governance cap the total debt of an institutional account
+    /// independently of its collateral. Borrows that would push the account's total
+    /// debt above the allowance are rejected, even when the collateral would allow them.
+    ///
+    /// **Default Configuration**: Unlimited (only collateral limits borrowing)
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account
+    /// - `egld_cap`: Maximum total debt in EGLD, WAD precision; `0` removes the allowance
+    ///
+    /// # Errors
+    /// - `ERROR_ACCOUNT_NOT_IN_THE_MARKET`: If the account is not active
+    #[only_owner]
+    #[endpoint(setAccountBorrowAllowance)]
+    fn set_account_borrow_allowance(&self, account_nonce: u64, egld_cap: BigUint) {
+        require!(
+            self.accounts().contains(&account_nonce),
+            ERROR_ACCOUNT_NOT_IN_THE_MARKET
+        );
+
+        if egld_cap == BigUint::zero() {
+            self.account_borrow_allowance(account_nonce).clear();
+        } else {
+            self.account_borrow_allowance(account_nonce).set(egld_cap);
+        }
+    }
+
+    /// Overrides the borrow cap of an asset for a single account.
+    ///
+    /// **Purpose**: Lets governance grant an institutional account more or less exposure
+    /// to an asset than the global cap allows. While an override exists, the account's own
+    /// debt in the asset is checked against it instead of the asset-wide borrow cap, so a
+    /// treasury account can borrow large amounts without raising the cap for everyone.
+    ///
+    /// **Default Configuration**: No override (the asset-wide borrow cap applies)
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account
+    /// - `asset`: Token identifier (EGLD or ESDT)
+    /// - `borrow_cap`: Maximum debt of the account in the asset, in asset decimals;
+    ///   `0` removes the override
+    ///
+    /// # Errors
+    /// - `ERROR_ACCOUNT_NOT_IN_THE_MARKET`: If the account is not active
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool
+    #[only_owner]
+    #[endpoint(setAccountBorrowCap)]
+    fn set_account_borrow_cap(
+        &self,
+        account_nonce: u64,
+        asset: EgldOrEsdtTokenIdentifier,
+        borrow_cap: BigUint,
+    ) {
+        require!(
+            self.accounts().contains(&account_nonce),
+            ERROR_ACCOUNT_NOT_IN_THE_MARKET
+        );
+        require!(
+            !self.pools_map(&asset).is_empty(),
+            ERROR_ASSET_NOT_SUPPORTED
+        );
+
+        if borrow_cap == BigUint::zero() {
+            self.account_borrow_cap_override(account_nonce, &asset)
+                .clear();
+        } else {
+            self.account_borrow_cap_override(account_nonce, &asset)
+                .set(borrow_cap);
+        }
+    }
+
+    /// Sets the maximum value a single account can reach on either side of its position.
+    ///
+    /// **Purpose**: Limits concentration risk by bounding how large any account can grow.
+    /// Deposits that would push the account's total collateral above the cap, and borrows
+    /// that would push its total debt above the cap, are rejected.
+    ///
+    /// **Default Configuration**: Unlimited
+    ///
+    /// # Arguments
+    /// - `egld_cap`: Maximum total collateral and total debt in EGLD, WAD precision;
+    ///   `0` removes the cap
+    #[only_owner]
+    #[endpoint(setMaxPositionValue)]
+    fn set_max_position_value(&self, egld_cap: BigUint) {
+        if egld_cap == BigUint::zero() {
+            self.max_position_value().clear();
+        } else {
+            self.max_position_value().set(egld_cap);
+        }
+    }
+
+    /// Sets the minimum value of collateral a single liquidation must seize.
+    ///
+    /// **Purpose**: Stops griefing liquidations that seize economically meaningless
+    /// amounts of collateral. A liquidation whose seized collateral is worth less than
+    /// the minimum, in EGLD, reverts.
+    ///
+    /// **Default Configuration**: 0 (no minimum)
+    ///
+    /// # Arguments
+    /// - `min_egld_value`: Minimum EGLD value of the seized collateral, WAD precision;
+    ///   `0` removes the minimum
+    #[only_owner]
+    #[endpoint(setMinSeizedCollateralEgld)]
+    fn set_min_seized_collateral_egld(&self, min_egld_value: BigUint) {
+        if min_egld_value == BigUint::zero() {
+            self.min_seized_collateral_egld().clear();
+        } else {
+            self.min_seized_collateral_egld().set(min_egld_value);
+        }
+    }
+
+    /// Sets the grace period that follows a liquidation.
+    ///
+    /// **Purpose**: Prevents back-to-back liquidations of the same account. After a
+    /// liquidation, the account cannot be liquidated again until the grace period has
+    /// elapsed, even if it is still unhealthy, giving its owner time to repay the
+    /// remaining debt. Repayments and other operations stay available during the period.
+    ///
+    /// **Default Configuration**: 0 (accounts can be liquidated again immediately)
+    ///
+    /// # Arguments
+    /// - `grace`: Length of the grace period in seconds; `0` disables it
+    #[only_owner]
+    #[endpoint(setPostLiquidationGrace)]
+    fn set_post_liquidation_grace(&self, grace: DurationSeconds) {
+        if grace == DurationSeconds::zero() {
+            self.post_liquidation_grace().clear();
+        } else {
+            self.post_liquidation_grace().set(grace);
+        }
+    }
+
+    /// Sets the grace period that follows a liquidation threshold reduction.
+    ///
+    /// **Purpose**: Tightening a liquidation threshold through `updateAccountThreshold` can
+    /// briefly push accounts below a health factor of 1, for instance while market indexes
+    /// lag after an oracle recovery. Accounts whose threshold was lowered cannot be
+    /// liquidated until the grace period has elapsed, giving owners time to react.
+    ///
+    /// **Default Configuration**: 0 (lowered thresholds apply to liquidations immediately)
+    ///
+    /// # Arguments
+    /// - `grace`: Length of the grace period in seconds; `0` disables it
+    #[only_owner]
+    #[endpoint(setThresholdUpdateGrace)]
+    fn set_threshold_update_grace(&self, grace: DurationSeconds) {
+        if grace == DurationSeconds::zero() {
+            self.threshold_update_grace().clear();
+        } else {
+            self.threshold_update_grace().set(grace);
+        }
+    }
+
+    /// Sets how long the last known price of a delisted asset stays usable.
+    ///
+    /// **Purpose**: If an asset's oracle is removed while accounts still hold it, every
+    /// price read of that asset reverts and the accounts can neither be liquidated nor
+    /// withdrawn. With a fallback age set, the price last recorded from the live oracle
+    /// (`last_token_price`, refreshed by `updateIndexes`) is used instead while it is
+    /// younger than the age.
+    ///
+    /// **Default Configuration**: 0 (price reads of delisted assets revert)
+    ///
+    /// # Arguments
+    /// - `max_age`: Maximum age of the last known price in seconds; `0` disables the fallback
+    #[only_owner]
+    #[endpoint(setDelistedPriceMaxAge)]
+    fn set_delisted_price_max_age(&self, max_age: DurationSeconds) {
+        if max_age == DurationSeconds::zero() {
+            self.delisted_price_max_age().clear();
+        } else {
+            self.delisted_price_max_age().set(max_age);
+        }
+    }
+
+    /// Temporarily extends the staleness tolerance of an asset's price feeds.
+    ///
+    /// **Purpose**: During an oracle outage, keeps the asset priceable so the protocol stays
+    /// operational without editing the oracle configuration. `extra_seconds` is added to the
+    /// configured `max_price_stale_seconds` until `expiry_timestamp`, after which the
+    /// configured tolerance applies again automatically. The EGLD reference price bound
+    /// (`egld_max_price_stale_seconds`) is not affected.
+    ///
+    /// **Default Configuration**: None (the configured tolerance applies)
+    ///
+    /// # Arguments
+    /// - `token`: Token identifier (EGLD or ESDT) of the priced asset
+    /// - `extra_seconds`: Seconds added to the configured tolerance; `0` removes the override
+    /// - `expiry_timestamp`: Block timestamp in seconds from which the override stops applying
+    ///
+    /// # Errors
+    /// - `ERROR_ORACLE_TOKEN_NOT_FOUND`: If the asset has no oracle configured
+    /// - `ERROR_INVALID_PRICE_STALENESS_OVERRIDE`: If the expiry is not in the future
+    #[only_owner]
+    #[endpoint(setPriceStalenessOverride)]
+    fn set_price_staleness_override(
+        &self,
+        token: EgldOrEsdtTokenIdentifier,
+        extra_seconds: DurationSeconds,
+        expiry_timestamp: TimestampSeconds,
+    ) {
+        require!(
+            !self.token_oracle(&token).is_empty(),
+            ERROR_ORACLE_TOKEN_NOT_FOUND
+        );
+
+        if extra_seconds == DurationSeconds::zero() {
+            self.price_staleness_override(&token).clear();
+            return;
+        }
+
+        require!(
+            expiry_timestamp > self.blockchain().get_block_timestamp_seconds(),
+            ERROR_INVALID_PRICE_STALENESS_OVERRIDE
+        );
+
+        self.price_staleness_override(&token)
+            .set(PriceStalenessOverride {
+                extra_seconds,
+                expiry: expiry_timestamp,
+            });
+    }
+
+    /// Sets the minimum time a deposit must stay supplied to keep its rewards.
+    ///
+    /// **Purpose**: Deters supplying briefly to capture reward distributions and leaving
+    /// right after. A withdrawal made before the duration elapses since the last supply
+    /// forfeits the rewards the deposit accrued during that period to the protocol.
+    /// Principal and interest are never forfeited.
+    ///
+    /// **Default Configuration**: 0 (rewards are never forfeited)
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market
+    /// - `min_duration`: Minimum supply duration in seconds; `0` disables the rule
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool
+    #[only_owner]
+    #[endpoint(setMinSupplyDuration)]
+    fn set_min_supply_duration(
+        &self,
+        asset: EgldOrEsdtTokenIdentifier,
+        min_duration: DurationSeconds,
+    ) {
+        require!(
+            !self.pools_map(&asset).is_empty(),
+            ERROR_ASSET_NOT_SUPPORTED
+        );
+
+        if min_duration == DurationSeconds::zero() {
+            self.min_supply_duration(&asset).clear();
+        } else {
+            self.min_supply_duration(&asset).set(min_duration);
+        }
+    }
+
+    /// Sets how many recent operations a market keeps in its on-chain activity buffer.
+    ///
+    /// **Purpose**: Lets light clients read recent supply, borrow, repay and withdraw
+    /// activity of a market without an indexer. Once full, each new operation
+    /// overwrites the oldest entry, keeping storage bounded. Changing the capacity
+    /// discards the activity recorded so far.
+    ///
+    /// **Default Configuration**: 0 (no activity is recorded)
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market
+    /// - `capacity`: Number of retained entries; `0` disables recording
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool
+    /// - `ERROR_INVALID_MARKET_ACTIVITY_CAPACITY`: If `capacity` exceeds the maximum
+    #[only_owner]
+    #[endpoint(setMarketActivityCapacity)]
+    fn set_market_activity_capacity(&self, asset: EgldOrEsdtTokenIdentifier, capacity: usize) {
+        require!(
+            !self.pools_map(&asset).is_empty(),
+            ERROR_ASSET_NOT_SUPPORTED
+        );
+        require!(
+            capacity <= MAX_MARKET_ACTIVITY_CAPACITY,
+            ERROR_INVALID_MARKET_ACTIVITY_CAPACITY
+        );
+
+        self.market_activity(&asset).clear();
+        self.market_activity_head(&asset).clear();
+        if capacity == 0 {
+            self.market_activity_capacity(&asset).clear();
+        } else {
+            self.market_activity_capacity(&asset).set(capacity);
+        }
+    }
+
+    /// Sets the minimum time between two index snapshots of a market.
+    ///
+    /// **Purpose**: Enables an index history used to reconstruct the interest accrued
+    /// by a position since a given timestamp, e.g. for tax and accounting exports.
+    /// Snapshots are taken by `updateIndexes`, at most once per interval and market.
+    /// Disabling snapshots keeps the history recorded so far.
+    ///
+    /// **Default Configuration**: 0 (no snapshots are recorded)
+    ///
+    /// # Arguments
+    /// - `interval`: Minimum snapshot spacing in seconds; `0` disables snapshots
+    #[only_owner]
+    #[endpoint(setIndexSnapshotInterval)]
+    fn set_index_snapshot_interval(&self, interval: DurationSeconds) {
+        if interval == DurationSeconds::zero() {
+            self.index_snapshot_interval().clear();
+        } else {
+            self.index_snapshot_interval().set(interval);
+        }
+    }
+
+    /// Sets the maximum age of the EGLD reference price.
+    ///
+    /// **Purpose**: Every EGLD-denominated valuation depends on the EGLD/USD reference
+    /// price loaded into the cache. This bound applies to that feed only, on top of the
+    /// per-asset `max_price_stale_seconds` of the EGLD oracle, so that any value-dependent
+    /// operation reverts once the reference price is older than allowed.
+    ///
+    /// **Default Configuration**: Unset (the EGLD oracle bound applies alone)
+    ///
+    /// # Arguments
+    /// - `max_seconds_stale`: Maximum accepted age of the EGLD/USD feed, in seconds
+    ///
+    /// # Errors
+    /// - `ERROR_INVALID_EGLD_MAX_PRICE_STALE_SECONDS`: If the bound is zero
+    #[only_owner]
+    #[endpoint(setEgldMaxPriceStaleSeconds)]
+    fn set_egld_max_price_stale_seconds(&self, max_seconds_stale: DurationSeconds) {
+        require!(
+            max_seconds_stale > DurationSeconds::zero(),
+            ERROR_INVALID_EGLD_MAX_PRICE_STALE_SECONDS
+        );
+        self.egld_max_price_stale_seconds().set(max_seconds_stale);
+    }
+
+    /// Disables the oracle for a token.
+    /// Prevents the token from being used as a price feed.
+    ///
+    /// # Arguments
+    /// - `token_id`: Token identifier (EGLD or ESDT).
+    ///
+    /// # Errors
+    /// - `ERROR_ORACLE_TOKEN_NOT_FOUND`: If oracle exists for the token.
+    #[only_owner]
+    #[endpoint(disableTokenOracle)]
+    fn disable_token_oracle(&self, token_id: &EgldOrEsdtTokenIdentifier) {
+        let mapper = self.token_oracle(token_id);
+        require!(!mapper.is_empty(), ERROR_ORACLE_TOKEN_NOT_FOUND);
+        mapper.update(|oracle| {
+            oracle.oracle_type = OracleType::None;
+            self.update_asset_oracle_event(token_id, oracle);
+        });
+    }
+
+    /// Validates and stores the oracle configuration for a single token.
+    /// Resolves the base/quote tokens from the source contract and emits the oracle update event.
+    #[allow(clippy::too_many_arguments)]
+    fn configure_token_oracle(
+        &self,
+        market_token: &EgldOrEsdtTokenIdentifier,
+        decimals: usize,
+        contract_address: &ManagedAddress,
+        pricing_method: PricingMethod,
+        token_type: OracleType,
+        source: ExchangeSource,
+        first_tolerance: BigUint,
+        last_tolerance: BigUint,
+        max_price_stale_seconds: DurationSeconds,
+        one_dex_pair_id: usize,
+    ) {
+        let mapper = self.token_oracle(market_token);
+
+        require!(mapper.is_empty(), ERROR_ORACLE_TOKEN_EXISTING);
+        // The TWAP fallback samples the xExchange safe price view
+        require!(
+            pricing_method != PricingMethod::Twap || source == ExchangeSource::XExchange,
+            ERROR_INVALID_EXCHANGE_SOURCE
+        );
+        let first_token_id = match source {
+            ExchangeSource::LXOXNO => {
+                let first_token_id = self
+                    .tx()
+                    .to(contract_address)
+                    .typed(proxy_lxoxno::RsLiquidXoxnoProxy)
+                    .main_token()
+                    .returns(ReturnsResult)
+                    .sync_call_readonly();
+                EgldOrEsdtTokenIdentifier::esdt(first_token_id)
+            },
+            ExchangeSource::Onedex => {
+                require!(one_dex_pair_id > 0, ERROR_INVALID_ONEDEX_PAIR_ID);
+                let first_token_id = self
+                    .tx()
+                    .to(contract_address)
+                    .typed(proxy_onedex::OneDexProxy)
+                    .pair_first_token_id(one_dex_pair_id)
+                    .returns(ReturnsResult)
+                    .sync_call_readonly();
+                EgldOrEsdtTokenIdentifier::esdt(first_token_id)
+            },
+            ExchangeSource::XExchange => {
+                let first_token_id = self
+                    .tx()
+                    .to(contract_address)
+                    .typed(proxy_xexchange_pair::PairProxy)
+                    .first_token_id()
+                    .returns(ReturnsResult)
+                    .sync_call_readonly();
+                EgldOrEsdtTokenIdentifier::esdt(first_token_id)
+            },
+            ExchangeSource::XEGLD => EgldOrEsdtTokenIdentifier::egld(),
+            ExchangeSource::LEGLD => EgldOrEsdtTokenIdentifier::egld(),
+            _ => {
+                panic!("Invalid exchange source")
+            },
+        };
+
+        let second_token_id = match source {
+            ExchangeSource::XExchange => {
+                let token_id = self
+                    .tx()
+                    .to(contract_address)
+                    .typed(proxy_xexchange_pair::PairProxy)
+                    .second_token_id()
+                    .returns(ReturnsResult)
+                    .sync_call_readonly();
+                EgldOrEsdtTokenIdentifier::esdt(token_id)
+            },
+            ExchangeSource::Onedex => {
+                let token_id = self
+                    .tx()
+                    .to(contract_address)
+                    .typed(proxy_onedex::OneDexProxy)
+                    .pair_second_token_id(one_dex_pair_id)
+                    .returns(ReturnsResult)
+                    .sync_call_readonly();
+                EgldOrEsdtTokenIdentifier::esdt(token_id)
+            },
+            ExchangeSource::XEGLD => first_token_id.clone(),
+            ExchangeSource::LEGLD => first_token_id.clone(),
+            ExchangeSource::LXOXNO => first_token_id.clone(),
+            _ => {
+                panic!("Invalid exchange source")
+            },
+        };
+
+        let tolerance = self.validate_and_calculate_tolerances(&first_tolerance, &last_tolerance);
+
+        let oracle = OracleProvider {
+            base_token_id: first_token_id,
+            quote_token_id: second_token_id,
+            oracle_contract_address: contract_address.clone(),
+            oracle_type: token_type,
+            exchange_source: source,
+            asset_decimals: decimals,
+            pricing_method,
+            tolerance,
+            onedex_pair_id: one_dex_pair_id,
+            max_price_stale_seconds,
+        };
+        self.update_asset_oracle_event(market_token, &oracle);
+        mapper.set(&oracle);
+        self.twap_rounds(market_token).set(DEFAULT_TWAP_ROUNDS);
     }
 }
@@ -152,6 +152,65 @@ pub trait Controller:
         );
     }
 
+    /// Supplies collateral to an existing account on behalf of its holder.
+    ///
+    /// Purpose: Lets keepers, relayers and vault aggregators top up a user's position
+    /// without holding the account NFT. The collateral comes from the caller and is
+    /// credited to the account held by `beneficiary`.
+    ///
+    /// # Arguments
+    /// - `beneficiary`: Address holding the account NFT.
+    /// - `account_nonce`: Existing account NFT nonce to credit.
+    ///
+    /// # Payment
+    /// - Accepts one or more collateral tokens; the account NFT is not required.
+    ///
+    /// # Errors
+    /// - `ERROR_ACCOUNT_NOT_HELD_BY_BENEFICIARY`: If `beneficiary` is not the holder of the account.
+    #[payable]
+    #[endpoint(supplyFor)]
+    fn supply_for(&self, beneficiary: ManagedAddress, account_nonce: u64) {
+        self.require_not_paused();
+        let mut cache = Cache::new(self);
+        self.reentrancy_guard(cache.flash_loan_ongoing);
+        self.require_non_zero_address(&beneficiary);
+        self.require_active_account(account_nonce);
+
+        let (collaterals, optional_account, caller, optional_attributes) =
+            self.validate_supply_payment(false, true, OptionalValue::Some(account_nonce));
+
+        // An attached NFT moves the holder to the caller, so the check runs after validation
+        let credited_nonce = optional_account.map_or(0, |account| account.token_nonce);
+        require!(
+            credited_nonce == account_nonce
+                && self.account_holder(account_nonce).get() == beneficiary,
+            ERROR_ACCOUNT_NOT_HELD_BY_BENEFICIARY
+        );
+        require!(
+            !collaterals.is_empty(),
+            ERROR_INVALID_NUMBER_OF_ESDT_TRANSFERS
+        );
+
+        let first_collateral = collaterals.get(0);
+        self.validate_payment(&first_collateral);
+
+        // If the asset is isolated, we can only supply one collateral not a bulk
+        if cache
+            .cached_asset_info(&first_collateral.token_identifier)
+            .is_isolated()
+        {
+            require!(collaterals.len() == 1, ERROR_BULK_SUPPLY_NOT_SUPPORTED);
+        }
+
+        self.process_deposit(
+            &caller,
+            account_nonce,
+            unsafe { optional_attributes.unwrap_unchecked() },
+            &collaterals,
+            &mut cache,
+        );
+    }
+
     /// Withdraws collateral from the lending pool.
     ///
     /// Purpose: Transfers requested collateral amounts from the user's deposit
@@ -209,6 +268,30 @@ pub trait Controller:
         self.manage_account_after_withdrawal(&account_payment, &caller);
     }
 
+    /// Claims the dust collateral credited to an account by a debt-clearing liquidation.
+    ///
+    /// Purpose: Pays the collateral set aside by `liquidate` to the current holder of
+    /// the account NFT. `withdraw` pays it out as well.
+    ///
+    /// Methodology:
+    /// 1. Validates the account NFT
+    /// 2. Transfers every credited token to the caller
+    /// 3. Burns the NFT if the account holds no position, otherwise returns it
+    ///
+    /// Payment
+    /// - Requires the account NFT as payment (first and only NFT).
+    #[payable]
+    #[endpoint(claimDustCollateral)]
+    fn claim_dust_collateral(&self) {
+        self.require_not_paused();
+        let (account_payment, caller, _) = self.validate_account(false);
+
+        let cache = Cache::new(self);
+        self.reentrancy_guard(cache.flash_loan_ongoing);
+
+        self.manage_account_after_withdrawal(&account_payment, &caller);
+    }
+
     /// Borrows assets from the lending pool.
     ///
     /// Purpose: Creates or scales borrow positions for the account, with
@@ -217,8 +300,9 @@ pub trait Controller:
     /// Methodology:
     /// 1. Validates account NFT and syncs indexes/prices
     /// 2. Computes LTV collateral value from current deposits
-    /// 3. Validates bulk position limits for all requested borrows
-    /// 4. For each token: validates borrowability, caps, LTV, updates position
+    /// 3. Merges duplicate tokens so each asset is borrowed once with the summed amount
+    /// 4. Validates bulk position limits for all requested borrows
+    /// 5. For each token: validates borrowability, caps, LTV, updates position
     ///
     /// Payment
     /// - Requires the account NFT as payment.
@@ -228,7 +312,75 @@ pub trait Controller:
     #[payable]
     #[endpoint(borrow)]
     fn borrow(&self, borrowed_tokens: MultiValueEncoded<EgldOrEsdtTokenPayment<Self::Api>>) {
+        self.borrow_tokens(borrowed_tokens, None, None);
+    }
+
+    /// Borrows one or more assets and requires a minimum health factor afterwards.
+    ///
+    /// Purpose: Lets cautious borrowers keep a safety buffer above the liquidation
+    /// threshold without computing the maximum safe amount themselves. Behaves like
+    /// `borrow`, then checks the resulting health factor once after all borrows.
+    ///
+    /// Payment
+    /// - Requires the account NFT as payment.
+    ///
+    /// Arguments
+    /// - `min_health_factor_after`: Minimum health factor after the borrows, in RAY precision
+    /// - `borrowed_tokens`: List of tokens and amounts to borrow
+    ///
+    /// Errors
+    /// - `ERROR_HEALTH_FACTOR_BELOW_REQUESTED_MINIMUM`: If the resulting health factor is
+    ///   below `min_health_factor_after`
+    #[payable]
+    #[endpoint(borrowWithMinHealthFactor)]
+    fn borrow_with_min_health_factor(
+        &self,
+        min_health_factor_after: BigUint,
+        borrowed_tokens: MultiValueEncoded<EgldOrEsdtTokenPayment<Self::Api>>,
+    ) {
+        self.borrow_tokens(borrowed_tokens, Some(min_health_factor_after), None);
+    }
+
+    /// Borrows one or more assets and caps the borrow rate they leave behind.
+    ///
+    /// Purpose: Interest rate slippage protection for programmatic borrowers. Behaves
+    /// like `borrow`, then reverts if the annual borrow rate of any borrowed market,
+    /// at its post-borrow utilization, exceeds the caller-supplied ceiling.
+    ///
+    /// Payment
+    /// - Requires the account NFT as payment.
+    ///
+    /// Arguments
+    /// - `max_acceptable_rate`: Maximum annual borrow rate after the borrows, in RAY precision
+    /// - `borrowed_tokens`: List of tokens and amounts to borrow
+    ///
+    /// Errors
+    /// - `ERROR_BORROW_RATE_ABOVE_MAX_ACCEPTABLE`: If a borrowed market's resulting borrow
+    ///   rate is above `max_acceptable_rate`
+    #[payable]
+    #[endpoint(borrowWithMaxRate)]
+    fn borrow_with_max_rate(
+        &self,
+        max_acceptable_rate: BigUint,
+        borrowed_tokens: MultiValueEncoded<EgldOrEsdtTokenPayment<Self::Api>>,
+    ) {
+        self.borrow_tokens(borrowed_tokens, None, Some(max_acceptable_rate));
+    }
+
+    /// Shared implementation of `borrow`, `borrowWithMinHealthFactor` and `borrowWithMaxRate`.
+    ///
+    /// Arguments
+    /// - `borrowed_tokens`: List of tokens and amounts to borrow
+    /// - `min_health_factor_after`: Optional minimum health factor after the borrows (RAY)
+    /// - `max_acceptable_rate`: Optional maximum annual borrow rate after the borrows (RAY)
+    fn borrow_tokens(
+        &self,
+        borrowed_tokens: MultiValueEncoded<EgldOrEsdtTokenPayment<Self::Api>>,
+        min_health_factor_after: Option<BigUint>,
+        max_acceptable_rate: Option<BigUint>,
+    ) {
         self.require_not_paused();
+        self.require_debt_creation_enabled();
         let mut cache = Cache::new(self);
         self.reentrancy_guard(cache.flash_loan_ongoing);
         cache.allow_unsafe_price = false;
@@ -244,15 +396,18 @@ pub trait Controller:
 
         let (_, _, ltv_collateral) = self.calculate_collateral_values(&collaterals, &mut cache);
 
-        let is_bulk_borrow = borrowed_tokens.len() > 1;
+        // A token listed twice is borrowed once, so the index mapper holds one entry per asset
+        let borrowed_tokens_vec = self.merge_duplicate_borrows(&borrowed_tokens.to_vec());
+
+        let is_bulk_borrow = borrowed_tokens_vec.len() > 1;
         let (mut borrows, mut borrow_index_mapper) =
             self.borrow_positions(account_nonce, is_bulk_borrow);
 
         let e_mode = self.e_mode_category(account_attributes.emode_id());
         self.ensure_e_mode_not_deprecated(&e_mode);
+        self.ensure_e_mode_companion_collateral(account_attributes.emode_id(), &collaterals);
 
         // Validate position limits for all new borrow positions in this transaction
-        let borrowed_tokens_vec = borrowed_tokens.to_vec();
         self.validate_bulk_position_limits(
             account_nonce,
             AccountPositionType::Borrow,
@@ -260,7 +415,7 @@ pub trait Controller:
         );
 
         // Process each borrow
-        for borrowed_token in borrowed_tokens_vec {
+        for borrowed_token in borrowed_tokens_vec.iter() {
             self.process_borrow(
                 &mut cache,
                 account_nonce,
@@ -274,6 +429,29 @@ pub trait Controller:
                 &ltv_collateral,
             );
         }
+
+        // Evaluated once for the whole bulk borrow
+        if let Some(min_health_factor) = min_health_factor_after {
+            self.validate_min_health_factor(
+                account_nonce,
+                &mut cache,
+                &self.to_decimal_ray(min_health_factor),
+                ERROR_HEALTH_FACTOR_BELOW_REQUESTED_MINIMUM,
+            );
+        }
+
+        // Checked on the post-borrow utilization of every borrowed market
+        if let Some(max_rate) = max_acceptable_rate {
+            let max_rate_ray = self.to_decimal_ray(max_rate);
+            for borrowed_token in borrowed_tokens_vec.iter() {
+                let (borrow_rate, _) =
+                    self.annual_market_rates(&borrowed_token.token_identifier, &mut cache);
+                require!(
+                    borrow_rate <= max_rate_ray,
+                    ERROR_BORROW_RATE_ABOVE_MAX_ACCEPTABLE
+                );
+            }
+        }
     }
 
     /// Repays borrowed assets for an account.
@@ -281,23 +459,32 @@ pub trait Controller:
     /// Purpose: Decreases or clears debt positions for one or more assets.
     ///
     /// Methodology:
-    /// 1. Validates account and caller
+    /// 1. Validates account and caller, deriving the account from the attached NFT if present
     /// 2. For each payment: validates asset/amount, converts to decimals and EGLD value
     /// 3. Calls process_repayment to update pool and position, tracking isolated debt
+    /// 4. Returns the account NFT to the caller when it was attached
     ///
     /// Arguments
-    /// - `account_nonce`: NFT nonce of the account
+    /// - `opt_account_nonce`: NFT nonce of the account; optional when the account NFT
+    ///   is sent as the first payment, in which case the remaining transfers are repaid
     #[payable]
     #[endpoint(repay)]
-    fn repay(&self, account_nonce: u64) {
+    fn repay(&self, opt_account_nonce: OptionalValue<u64>) {
         self.require_not_paused();
         let mut cache = Cache::new(self);
         self.reentrancy_guard(cache.flash_loan_ongoing);
-        let payments = self.call_value().all_transfers();
-        self.require_active_account(account_nonce);
+        let (payments, opt_account, caller, opt_attributes) =
+            self.validate_supply_payment(false, false, opt_account_nonce);
+        // An attached account NFT is stripped from the repayment transfers
+        let has_account_payment = payments.len() < self.call_value().all_transfers().len();
 
-        let account_attributes = self.account_attributes(account_nonce).get();
-        let caller = self.blockchain().get_caller();
+        require!(opt_account.is_some(), ERROR_ACCOUNT_NOT_IN_THE_MARKET);
+        require!(!payments.is_empty(), ERROR_INVALID_NUMBER_OF_ESDT_TRANSFERS);
+
+        // Both values are set together whenever an account is resolved
+        let account = unsafe { opt_account.unwrap_unchecked() };
+        let account_attributes = unsafe { opt_attributes.unwrap_unchecked() };
+        let account_nonce = account.token_nonce;
         for payment_raw in payments.iter() {
             self.validate_payment(&payment_raw);
 
@@ -316,6 +503,308 @@ pub trait Controller:
                 &account_attributes,
             );
         }
+
+        if has_account_payment {
+            self.tx().to(&caller).payment(&account).transfer();
+        }
+    }
+
+    /// Repays debt with the account's deposit of the same token.
+    ///
+    /// Purpose: Lets accounts that both supply and borrow an asset net the two
+    /// positions without a withdrawal followed by a separate repayment.
+    ///
+    /// Methodology:
+    /// 1. Validates the account NFT and the requested amount
+    /// 2. Calls process_self_repayment to burn the deposit and the debt in the pool
+    /// 3. Validates health factor and returns or burns the account NFT
+    ///
+    /// Payment
+    /// - Requires the account NFT as payment.
+    ///
+    /// Arguments
+    /// - `token_id`: Token both supplied and borrowed by the account
+    /// - `amount`: Amount to repay in asset decimals, capped at the outstanding debt
+    #[payable]
+    #[endpoint(selfRepayFromSupply)]
+    fn self_repay_from_supply(&self, token_id: EgldOrEsdtTokenIdentifier, amount: BigUint) {
+        self.require_not_paused();
+        let (account_payment, caller, account_attributes) = self.validate_account(false);
+
+        let mut cache = Cache::new(self);
+        self.reentrancy_guard(cache.flash_loan_ongoing);
+        self.validate_payment(&EgldOrEsdtTokenPayment::new(
+            token_id.clone(),
+            0,
+            amount.clone(),
+        ));
+
+        let feed = self.token_price(&token_id, &mut cache);
+        let amount = self.to_decimal(amount, feed.asset_decimals);
+        self.process_self_repayment(
+            account_payment.token_nonce,
+            &token_id,
+            &amount,
+            &caller,
+            &feed,
+            &mut cache,
+            &account_attributes,
+        );
+
+        self.validate_is_healthy(account_payment.token_nonce, &mut cache, None);
+
+        self.manage_account_after_withdrawal(&account_payment, &caller);
+    }
+
+    /// Closes an account in a single transaction.
+    ///
+    /// Purpose: Repays all outstanding debt with the provided payments,
+    /// withdraws every remaining collateral position to the caller and
+    /// burns the account NFT.
+    ///
+    /// Methodology:
+    /// 1. Validates the account NFT and extracts the repayment payments
+    /// 2. Repays each payment against the matching borrow position
+    /// 3. Requires that no borrow position remains open
+    /// 4. Withdraws the full balance of each deposit position and burns the NFT
+    ///
+    /// Payment
+    /// - Requires the account NFT as the first payment, followed by the repayment tokens.
+    #[payable]
+    #[endpoint(closeAccount)]
+    fn close_account(&self) {
+        self.require_not_paused();
+        let mut cache = Cache::new(self);
+        self.reentrancy_guard(cache.flash_loan_ongoing);
+        let (repay_payments, opt_account, caller, opt_attributes) =
+            self.validate_supply_payment(true, false, OptionalValue::None);
+
+        // Account payment is required, both values are guaranteed to exist
+        let account = unsafe { opt_account.unwrap_unchecked() };
+        let account_attributes = unsafe { opt_attributes.unwrap_unchecked() };
+
+        for payment in repay_payments.iter() {
+            self.validate_payment(&payment);
+
+            let feed = self.token_price(&payment.token_identifier, &mut cache);
+            let amount_wad = self.to_decimal(payment.amount.clone(), feed.asset_decimals);
+            let egld_value_wad = self.token_egld_value(&amount_wad, &feed.price_wad);
+
+            self.process_repayment(
+                account.token_nonce,
+                &payment.token_identifier,
+                &amount_wad,
+                &caller,
+                egld_value_wad,
+                &feed,
+                &mut cache,
+                &account_attributes,
+            );
+        }
+
+        require!(
+            self.positions(account.token_nonce, AccountPositionType::Borrow)
+                .is_empty(),
+            ERROR_ACCOUNT_HAS_OUTSTANDING_DEBT
+        );
+
+        for mut deposit_position in self
+            .positions(account.token_nonce, AccountPositionType::Deposit)
+            .values()
+        {
+            let feed = self.token_price(&deposit_position.asset_id, &mut cache);
+            let amount = self.total_amount(&deposit_position, &feed, &mut cache);
+
+            let _ = self.process_withdrawal(
+                account.token_nonce,
+                amount,
+                &caller,
+                false,
+                None,
+                &mut cache,
+                &account_attributes,
+                &mut deposit_position,
+                &feed,
+            );
+        }
+
+        self.manage_account_after_withdrawal(&account, &caller);
+    }
+
+    /// Repays debt and withdraws collateral in a single transaction.
+    ///
+    /// Purpose: Reduces a position proportionally; repaying first frees up
+    /// collateral so the withdrawal can go through with a single health check.
+    ///
+    /// Methodology:
+    /// 1. Validates the account NFT and extracts the repayment payments
+    /// 2. Repays each payment against the matching borrow position
+    /// 3. Withdraws each requested collateral amount
+    /// 4. Validates health factor once after both steps and returns or burns the NFT
+    ///
+    /// Payment
+    /// - Requires the account NFT as the first payment, followed by the repayment tokens.
+    ///
+    /// Arguments
+    /// - `collaterals`: List of token identifiers and amounts to withdraw
+    #[payable]
+    #[endpoint(repayAndWithdraw)]
+    fn repay_and_withdraw(
+        &self,
+        collaterals: MultiValueEncoded<EgldOrEsdtTokenPayment<Self::Api>>,
+    ) {
+        self.require_not_paused();
+        let mut cache = Cache::new(self);
+        self.reentrancy_guard(cache.flash_loan_ongoing);
+        let (repay_payments, opt_account, caller, opt_attributes) =
+            self.validate_supply_payment(true, false, OptionalValue::None);
+
+        // Account payment is required, both values are guaranteed to exist
+        let account = unsafe { opt_account.unwrap_unchecked() };
+        let account_attributes = unsafe { opt_attributes.unwrap_unchecked() };
+
+        for payment in repay_payments.iter() {
+            self.validate_payment(&payment);
+
+            let feed = self.token_price(&payment.token_identifier, &mut cache);
+            let amount_wad = self.to_decimal(payment.amount.clone(), feed.asset_decimals);
+            let egld_value_wad = self.token_egld_value(&amount_wad, &feed.price_wad);
+
+            self.process_repayment(
+                account.token_nonce,
+                &payment.token_identifier,
+                &amount_wad,
+                &caller,
+                egld_value_wad,
+                &feed,
+                &mut cache,
+                &account_attributes,
+            );
+        }
+
+        cache.allow_unsafe_price = self
+            .positions(account.token_nonce, AccountPositionType::Borrow)
+            .is_empty();
+
+        for collateral in collaterals {
+            self.validate_payment(&collateral);
+            let mut deposit_position =
+                self.deposit_position(account.token_nonce, &collateral.token_identifier);
+            let feed = self.token_price(&deposit_position.asset_id, &mut cache);
+            let amount_wad =
+                deposit_position.make_amount_decimal(&collateral.amount, feed.asset_decimals);
+
+            let _ = self.process_withdrawal(
+                account.token_nonce,
+                amount_wad,
+                &caller,
+                false,
+                None,
+                &mut cache,
+                &account_attributes,
+                &mut deposit_position,
+                &feed,
+            );
+        }
+
+        // Single health check after the combined reduction
+        self.validate_is_healthy(account.token_nonce, &mut cache, None);
+
+        self.manage_account_after_withdrawal(&account, &caller);
+    }
+
+    /// Moves an account to another e-mode category in a single transaction.
+    ///
+    /// Purpose: Migrates an account between e-mode categories that do not share all
+    /// assets; debt not borrowable in the new category is repaid and collateral can be
+    /// topped up so the account is valid once the switch is applied.
+    ///
+    /// Methodology:
+    /// 1. Validates the account NFT and splits the payments into repayments and collateral
+    /// 2. Repays the repayment payments under the current category
+    /// 3. Switches the category, re-applying its risk parameters to every position
+    /// 4. Supplies the collateral payments under the new category
+    /// 5. Validates health factor once and returns the NFT with the updated attributes
+    ///
+    /// Payment
+    /// - Requires the account NFT as the first payment, followed by `repay_count`
+    ///   repayment tokens and then the additional collateral tokens.
+    ///
+    /// Arguments
+    /// - `new_category`: E-mode category to switch to (0 = disable e-mode)
+    /// - `repay_count`: Number of payments following the account NFT that repay debt
+    #[payable]
+    #[endpoint(rollEMode)]
+    fn roll_e_mode(&self, new_category: u8, repay_count: usize) {
+        self.require_not_paused();
+        let mut cache = Cache::new(self);
+        self.reentrancy_guard(cache.flash_loan_ongoing);
+        let (payments, opt_account, caller, opt_attributes) =
+            self.validate_supply_payment(true, false, OptionalValue::None);
+
+        // Account payment is required, both values are guaranteed to exist
+        let account = unsafe { opt_account.unwrap_unchecked() };
+        let account_attributes = unsafe { opt_attributes.unwrap_unchecked() };
+
+        require!(
+            repay_count <= payments.len(),
+            ERROR_INVALID_NUMBER_OF_ESDT_TRANSFERS
+        );
+        let repay_payments = payments.slice(0, repay_count).unwrap_or_default();
+        let collaterals = payments
+            .slice(repay_count, payments.len())
+            .unwrap_or_default();
+
+        for payment in repay_payments.iter() {
+            self.validate_payment(&payment);
+
+            let feed = self.token_price(&payment.token_identifier, &mut cache);
+            let amount_wad = self.to_decimal(payment.amount.clone(), feed.asset_decimals);
+            let egld_value_wad = self.token_egld_value(&amount_wad, &feed.price_wad);
+
+            self.process_repayment(
+                account.token_nonce,
+                &payment.token_identifier,
+                &amount_wad,
+                &caller,
+                egld_value_wad,
+                &feed,
+                &mut cache,
+                &account_attributes,
+            );
+        }
+
+        let new_attributes = self.roll_account_e_mode(
+            &account,
+            &account_attributes,
+            new_category,
+            &caller,
+            &mut cache,
+        );
+
+        if !collaterals.is_empty() {
+            self.process_deposit(
+                &caller,
+                account.token_nonce,
+                new_attributes,
+                &collaterals,
+                &mut cache,
+            );
+        }
+
+        let borrow_positions = self.positions(account.token_nonce, AccountPositionType::Borrow);
+        if !borrow_positions.is_empty() {
+            let deposit_positions = self
+                .positions(account.token_nonce, AccountPositionType::Deposit)
+                .values()
+                .collect();
+            self.ensure_e_mode_companion_collateral(new_category, &deposit_positions);
+        }
+
+        // Single health check once the account is valued under the new category
+        self.validate_is_healthy(account.token_nonce, &mut cache, None);
+
+        self.tx().to(&caller).payment(&account).transfer();
     }
 
     /// Liquidates an unhealthy position.
@@ -328,15 +817,32 @@ pub trait Controller:
     /// 2. Executes liquidation core to compute repayments and seized collateral
     /// 3. Refunds excess payments, processes repayments and transfers collateral
     ///
+    /// Debt in the native EGLD market can be repaid with a plain EGLD transfer; any
+    /// excess is refunded to the liquidator in native EGLD as well.
+    ///
+    /// Shard locality: every liquidity pool is deployed by this controller from its
+    /// template, so collateral and debt pools always share the controller's shard.
+    /// Repayment and seizure therefore run as synchronous calls and revert atomically
+    /// with the liquidation; no asynchronous cross-shard path is required.
+    ///
     /// Arguments
     /// - `account_nonce`: NFT nonce identifying the liquidated account
+    /// - `preferred_collaterals`: Optional collateral tokens to seize first, in order
     #[payable]
     #[endpoint(liquidate)]
-    fn liquidate(&self, account_nonce: u64) {
+    fn liquidate(
+        &self,
+        account_nonce: u64,
+        preferred_collaterals: OptionalValue<MultiValueEncoded<EgldOrEsdtTokenIdentifier>>,
+    ) {
         self.require_not_paused();
         let payments = self.call_value().all_transfers();
         let caller = self.blockchain().get_caller();
-        self.process_liquidation(account_nonce, &payments, &caller);
+        let preferred_collaterals = match preferred_collaterals {
+            OptionalValue::Some(tokens) => tokens.to_vec(),
+            OptionalValue::None => ManagedVec::new(),
+        };
+        self.process_liquidation(account_nonce, &payments, &caller, &preferred_collaterals);
     }
 
     /// Executes a flash loan.
@@ -348,6 +854,8 @@ pub trait Controller:
     /// 1. Validates shard, endpoint, amount and that asset supports flashloans
     /// 2. Pushes caller as final argument and forwards funds to pool flash_loan
     /// 3. Enforces flash_loan_ongoing guard around the call
+    /// 4. Deposits the `flash_loan_protocol_share_bps` part of the fee into the accumulator;
+    ///    without an accumulator the whole fee stays in the pool as revenue
     ///
     /// Arguments
     /// - `borrowed_asset_id`: Token to borrow
@@ -365,6 +873,7 @@ pub trait Controller:
         mut arguments: ManagedArgBuffer<Self::Api>,
     ) {
         self.require_not_paused();
+        self.require_debt_creation_enabled();
         let mut cache = Cache::new(self);
         let caller = self.blockchain().get_caller();
         self.reentrancy_guard(cache.flash_loan_ongoing);
@@ -377,9 +886,15 @@ pub trait Controller:
         self.validate_flash_loan_endpoint(&endpoint);
 
         let feed = self.token_price(borrowed_asset_id, &mut cache);
+        let protocol_share_bps = if self.is_accumulator_set() {
+            self.to_decimal_bps(self.flash_loan_protocol_share_bps().get())
+        } else {
+            self.bps_zero()
+        };
         self.flash_loan_ongoing().set(true);
         arguments.push_arg(caller);
-        self.tx()
+        let protocol_fee = self
+            .tx()
             .to(pool_address)
             .typed(proxy_pool::LiquidityPoolProxy)
             .flash_loan(
@@ -389,12 +904,23 @@ pub trait Controller:
                 endpoint,
                 arguments,
                 asset_config.flashloan_fee_bps.clone(),
+                protocol_share_bps,
                 feed.price_wad.clone(),
             )
             .returns(ReturnsResult)
             .sync_call();
 
         self.flash_loan_ongoing().set(false);
+
+        if protocol_fee.amount > 0 {
+            self.tx()
+                .to(self.require_accumulator_address())
+                .typed(proxy_accumulator::AccumulatorProxy)
+                .deposit()
+                .payment(protocol_fee)
+                .returns(ReturnsResult)
+                .sync_call();
+        }
     }
 
     /// Updates account thresholds for a specific asset.
@@ -442,6 +968,15 @@ pub trait Controller:
     /// Updates interest rate indexes for specified assets.
     ///
     /// Purpose: Synchronizes supply/borrow indexes using current prices.
+    /// When the liquidation price regression check is enabled, also seeds its
+    /// reference prices while they are empty. When the delisted price fallback
+    /// is enabled, records the current prices as the last known ones. When index
+    /// snapshots are enabled, records the synchronized indexes.
+    ///
+    /// Methodology:
+    /// 1. Drops repeated assets so each pool is synced once
+    /// 2. Reads every price into the cache before any pool call
+    /// 3. Syncs each pool with its cached price
     ///
     /// Arguments
     /// - `assets`: Asset identifiers to update
@@ -450,9 +985,160 @@ pub trait Controller:
         self.require_not_paused();
         let mut cache = Cache::new(self);
         self.reentrancy_guard(cache.flash_loan_ongoing);
+
+        let mut unique_assets = ManagedVec::<Self::Api, EgldOrEsdtTokenIdentifier>::new();
         for asset_id in assets {
-            self.update_asset_index(&asset_id, &mut cache, false);
+            if !unique_assets.contains(&asset_id) {
+                unique_assets.push(asset_id);
+            }
+        }
+
+        // Prices are read once per asset, the pool calls below hit the cache
+        for asset_id in unique_assets.iter() {
+            let _ = self.token_price(&asset_id, &mut cache);
         }
+
+        for asset_id in unique_assets.iter() {
+            let market_index = self.update_asset_index(&asset_id, &mut cache, false);
+            self.record_last_token_price(&asset_id, &mut cache);
+            self.record_index_snapshot(&asset_id, &market_index);
+        }
+    }
+
+    /// Opts an account in or out of explicit compounding of supply interest.
+    ///
+    /// Purpose: Lets integrations that track principal receive explicit
+    /// compounding events instead of deriving interest from index changes.
+    ///
+    /// Methodology:
+    /// 1. Validates the account NFT and returns it to the caller
+    /// 2. On opt-in, snapshots the current balance of every deposit as its principal
+    /// 3. On opt-out, clears the tracked principals
+    ///
+    /// Arguments
+    /// - `enabled`: Whether auto-compounding should be active
+    #[payable]
+    #[endpoint(setAutoCompound)]
+    fn set_auto_compound(&self, enabled: bool) {
+        self.require_not_paused();
+        let (account_payment, _, _) = self.validate_account(true);
+        let account_nonce = account_payment.token_nonce;
+        let mut cache = Cache::new(self);
+        self.reentrancy_guard(cache.flash_loan_ongoing);
+
+        if enabled {
+            self.auto_compound(account_nonce).set(true);
+        } else {
+            self.auto_compound(account_nonce).clear();
+        }
+
+        for position in self
+            .positions(account_nonce, AccountPositionType::Deposit)
+            .values()
+        {
+            if enabled {
+                self.sync_compounded_principal(&position, &mut cache);
+            } else {
+                self.compounded_principal(account_nonce, &position.asset_id)
+                    .clear();
+            }
+        }
+    }
+
+    /// Stops a deposit from counting as collateral.
+    ///
+    /// Purpose: Lets users earn supply yield on an asset without exposing it
+    /// to liquidation. Supply-only deposits are excluded from LTV, health
+    /// factor and liquidation seizure.
+    ///
+    /// Methodology:
+    /// 1. Validates the account NFT and returns it to the caller
+    /// 2. Marks the deposit as supply-only
+    /// 3. Validates the health factor stays at or above 1.0 without it
+    ///
+    /// Arguments
+    /// - `asset_id`: Deposited asset to exclude from collateral
+    #[payable]
+    #[endpoint(disableAsCollateral)]
+    fn disable_as_collateral(&self, asset_id: EgldOrEsdtTokenIdentifier) {
+        self.require_not_paused();
+        let (account_payment, caller, account_attributes) = self.validate_account(true);
+        let account_nonce = account_payment.token_nonce;
+        let mut cache = Cache::new(self);
+        self.reentrancy_guard(cache.flash_loan_ongoing);
+        cache.allow_unsafe_price = self
+            .positions(account_nonce, AccountPositionType::Borrow)
+            .is_empty();
+
+        self.update_position_collateral_flag(
+            account_nonce,
+            &asset_id,
+            false,
+            &caller,
+            &account_attributes,
+            &mut cache,
+        );
+    }
+
+    /// Makes a supply-only deposit count as collateral again.
+    ///
+    /// Methodology:
+    /// 1. Validates the account NFT and returns it to the caller
+    /// 2. Marks the deposit as collateral
+    ///
+    /// Arguments
+    /// - `asset_id`: Deposited asset to include in collateral
+    #[payable]
+    #[endpoint(enableAsCollateral)]
+    fn enable_as_collateral(&self, asset_id: EgldOrEsdtTokenIdentifier) {
+        self.require_not_paused();
+        let (account_payment, caller, account_attributes) = self.validate_account(true);
+        let mut cache = Cache::new(self);
+        self.reentrancy_guard(cache.flash_loan_ongoing);
+
+        self.update_position_collateral_flag(
+            account_payment.token_nonce,
+            &asset_id,
+            true,
+            &caller,
+            &account_attributes,
+            &mut cache,
+        );
+    }
+
+    /// Compounds the accrued supply interest of a deposit into its principal.
+    ///
+    /// Purpose: Keeper-callable compounding event for accounts that opted in.
+    /// Index math already compounds balances; this re-bases the tracked
+    /// principal to the current balance and emits the realized interest.
+    ///
+    /// Methodology:
+    /// 1. Validates the account opted in and holds a deposit of the asset
+    /// 2. Synchronizes the market indexes
+    /// 3. Emits the interest accrued since the last re-base and stores the new principal
+    ///
+    /// Arguments
+    /// - `account_nonce`: NFT nonce of the account
+    /// - `asset_id`: Deposited asset to compound
+    #[endpoint(compoundInterest)]
+    fn compound_interest(&self, account_nonce: u64, asset_id: EgldOrEsdtTokenIdentifier) {
+        self.require_not_paused();
+        self.require_active_account(account_nonce);
+        require!(
+            self.auto_compound(account_nonce).get(),
+            ERROR_AUTO_COMPOUND_NOT_ENABLED
+        );
+        let mut cache = Cache::new(self);
+        self.reentrancy_guard(cache.flash_loan_ongoing);
+
+        let position = self
+            .positions(account_nonce, AccountPositionType::Deposit)
+            .get(&asset_id);
+        require!(position.is_some(), ERROR_POSITION_NOT_FOUND);
+        let position = unsafe { position.unwrap_unchecked() };
+
+        self.update_asset_index(&asset_id, &mut cache, false);
+        self.compound_position_interest(&position, &mut cache);
     }
 
     /// Cleans bad debt from an account.
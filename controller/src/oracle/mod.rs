@@ -10,6 +10,7 @@
 //! - **Aggregator feeds:** Off-chain USD price data with staleness checks
 //! - **Safe prices:** On-chain 15-minute TWAP from DEX contracts
 //! - **Mixed validation:** Cross-validation between sources with tolerance bounds
+//! - **TWAP fallback:** Multi-round DEX average when the aggregator is stale or deviates
 //!
 //! ### Token Type Support
 //! - **Normal tokens:** Standard ERC-20 tokens with direct price feeds
@@ -81,10 +82,12 @@
 
 multiversx_sc::imports!();
 use common_constants::{
-    BPS_PRECISION, RAY_PRECISION, SECONDS_PER_MINUTE, USD_TICKER, WAD_HALF_PRECISION,
-    WAD_PRECISION, WEGLD_TICKER,
+    BPS_PRECISION, DEFAULT_TWAP_ROUNDS, RAY_PRECISION, SECONDS_PER_MINUTE, USD_TICKER,
+    WAD_HALF_PRECISION, WAD_PRECISION, WEGLD_TICKER,
+};
+use common_errors::{
+    ERROR_ORACLE_QUORUM_NOT_MET, ERROR_PRICE_FEED_STALE, ERROR_UN_SAFE_PRICE_NOT_ALLOWED,
 };
-use common_errors::{ERROR_PRICE_FEED_STALE, ERROR_UN_SAFE_PRICE_NOT_ALLOWED};
 use common_proxies::{proxy_pool, proxy_xexchange_pair};
 use common_structs::{
     ExchangeSource, MarketIndex, OracleProvider, OracleType, PriceFeedShort, PricingMethod,
@@ -102,9 +105,9 @@ use crate::{
     proxy_price_aggregator::PriceFeed,
     proxy_xegld,
     proxy_xexchange_pair::State as StateXExchange,
-    storage, ERROR_INVALID_EXCHANGE_SOURCE, ERROR_INVALID_ORACLE_TOKEN_TYPE,
-    ERROR_NO_LAST_PRICE_FOUND, ERROR_ORACLE_TOKEN_NOT_FOUND, ERROR_PAIR_NOT_ACTIVE,
-    ERROR_PRICE_AGGREGATOR_NOT_SET,
+    storage, ERROR_DELISTED_PRICE_TOO_OLD, ERROR_INVALID_EXCHANGE_SOURCE,
+    ERROR_INVALID_ORACLE_TOKEN_TYPE, ERROR_NO_LAST_PRICE_FOUND, ERROR_ORACLE_TOKEN_NOT_FOUND,
+    ERROR_PAIR_NOT_ACTIVE, ERROR_PRICE_AGGREGATOR_NOT_SET,
 };
 
 #[multiversx_sc::module]
@@ -144,12 +147,18 @@ pub trait OracleModule:
     ) -> MarketIndex<Self::Api> {
         let pool_address = cache.cached_pool_address(asset_id);
         if simulate {
-            let last_timestamp = self.last_timestamp(pool_address.clone()).get();
+            // A paused pool keeps its indexes frozen until accrual resumes
+            let last_timestamp = if self.accrual_paused(pool_address.clone()).get() {
+                cache.current_timestamp
+            } else {
+                self.last_timestamp(pool_address.clone()).get()
+            };
             let borrowed = self.borrowed(pool_address.clone()).get();
             let current_borrowed_index = self.borrow_index(pool_address.clone()).get();
             let supplied = self.supplied(pool_address.clone()).get();
             let current_supply_index = self.supply_index(pool_address.clone()).get();
             let parameters = self.parameters(pool_address.clone()).get();
+            let rate_settings = self.rate_settings(pool_address.clone());
             self.simulate_update_indexes(
                 cache.current_timestamp,
                 last_timestamp,
@@ -158,6 +167,7 @@ pub trait OracleModule:
                 supplied,
                 current_supply_index,
                 parameters,
+                &rate_settings,
             )
         } else {
             let asset_price = self.token_price(asset_id, cache);
@@ -208,9 +218,14 @@ pub trait OracleModule:
         }
 
         let oracle_data = self.token_oracle(token_id);
-        require!(!oracle_data.is_empty(), ERROR_ORACLE_TOKEN_NOT_FOUND);
+        if oracle_data.is_empty() {
+            let feed = self.delisted_token_price(token_id, cache);
+            cache.prices_cache.put(token_id, &feed);
+            return feed;
+        }
 
-        let data = oracle_data.get();
+        let mut data = oracle_data.get();
+        self.apply_price_staleness_override(token_id, &mut data);
 
         let feed = if data.oracle_type == OracleType::None {
             PriceFeedShort {
@@ -219,6 +234,14 @@ pub trait OracleModule:
             }
         } else {
             let price = self.find_price_feed(&data, token_id, cache);
+            let min_oracle_quorum = self.min_oracle_quorum(token_id).get();
+            if min_oracle_quorum > 1 {
+                let agreeing_sources = self.agreeing_price_sources(&data, token_id, &price, cache);
+                require!(
+                    agreeing_sources >= min_oracle_quorum,
+                    ERROR_ORACLE_QUORUM_NOT_MET
+                );
+            }
 
             PriceFeedShort {
                 asset_decimals: data.asset_decimals,
@@ -231,6 +254,164 @@ pub trait OracleModule:
         feed
     }
 
+    /// Extends the staleness tolerance of an asset's price feeds while an override is active.
+    ///
+    /// **Purpose:** Lets the owner keep an asset priceable through an oracle outage without
+    /// editing its oracle configuration. The configured tolerance applies again from the
+    /// override expiry onwards, without any further transaction.
+    fn apply_price_staleness_override(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        configs: &mut OracleProvider<Self::Api>,
+    ) {
+        let override_mapper = self.price_staleness_override(token_id);
+        if override_mapper.is_empty() {
+            return;
+        }
+
+        let staleness_override = override_mapper.get();
+        if self.blockchain().get_block_timestamp_seconds() < staleness_override.expiry {
+            configs.max_price_stale_seconds += staleness_override.extra_seconds;
+        }
+    }
+
+    /// Prices an asset whose oracle was removed while positions still hold it.
+    ///
+    /// **Purpose:** Keeps accounts holding a delisted asset manageable, so they can still
+    /// be liquidated and withdrawn instead of reverting on every price read.
+    ///
+    /// **How it works:**
+    /// - Uses the last price recorded from the live oracle (`last_token_price`)
+    /// - Rejects it once older than `delisted_price_max_age`
+    /// - Reads the asset decimals from the market parameters
+    ///
+    /// **Returns:** Price feed with the last known price
+    ///
+    /// # Errors
+    /// - `ERROR_ORACLE_TOKEN_NOT_FOUND`: If the fallback is disabled or no price was recorded
+    /// - `ERROR_DELISTED_PRICE_TOO_OLD`: If the last known price is older than the fallback age
+    fn delisted_token_price(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        cache: &mut Cache<Self>,
+    ) -> PriceFeedShort<Self::Api> {
+        let max_age_mapper = self.delisted_price_max_age();
+        let last_price_mapper = self.last_token_price(token_id);
+        let last_timestamp_mapper = self.last_token_price_timestamp(token_id);
+        require!(
+            !max_age_mapper.is_empty()
+                && !last_price_mapper.is_empty()
+                && !last_timestamp_mapper.is_empty(),
+            ERROR_ORACLE_TOKEN_NOT_FOUND
+        );
+        require!(
+            self.blockchain().get_block_timestamp_seconds() - last_timestamp_mapper.get()
+                <= max_age_mapper.get(),
+            ERROR_DELISTED_PRICE_TOO_OLD
+        );
+
+        let pool_address = cache.cached_pool_address(token_id);
+        PriceFeedShort {
+            asset_decimals: self.parameters(pool_address).get().asset_decimals,
+            price_wad: last_price_mapper.get(),
+        }
+    }
+
+    /// Counts the independent price sources that agree with the final price.
+    ///
+    /// **Purpose:** Supports the per-asset oracle quorum, requiring critical assets to be
+    /// confirmed by several sources beyond the final price selection logic.
+    ///
+    /// **How it works:**
+    /// - **Normal tokens:** Aggregator and safe prices, as enabled by the pricing method
+    /// - **Twap tokens:** Fresh aggregator price when usable, safe price and multi-round TWAP
+    /// - **LP tokens:** On-chain reserve price and off-chain aggregator price
+    /// - **Derived tokens:** The exchange rate derived price is a single source
+    /// - A source agrees when it lies within the last tolerance of the final price
+    ///
+    /// **Returns:** Number of agreeing sources
+    fn agreeing_price_sources(
+        &self,
+        configs: &OracleProvider<Self::Api>,
+        original_market_token: &EgldOrEsdtTokenIdentifier,
+        final_price: &ManagedDecimal<Self::Api, NumDecimals>,
+        cache: &mut Cache<Self>,
+    ) -> u8 {
+        let agrees = |price: &ManagedDecimal<Self::Api, NumDecimals>| {
+            self.is_within_anchor(
+                price,
+                final_price,
+                &configs.tolerance.last_upper_ratio_bps,
+                &configs.tolerance.last_lower_ratio_bps,
+            )
+        };
+
+        let mut agreeing_sources = 0u8;
+        match configs.oracle_type {
+            OracleType::Normal if configs.pricing_method == PricingMethod::Twap => {
+                // The aggregator is optional for Twap tokens, the multi-round TWAP is a source
+                if let Some(price) = self.fresh_aggregator_price_in_egld(
+                    original_market_token,
+                    configs.max_price_stale_seconds,
+                    cache,
+                ) {
+                    if agrees(&price) {
+                        agreeing_sources += 1;
+                    }
+                }
+                let safe_price = self.safe_price(configs, original_market_token, cache);
+                let twap_price = self.twap_price(configs, original_market_token, cache);
+                for price in [safe_price, twap_price].iter() {
+                    if agrees(price) {
+                        agreeing_sources += 1;
+                    }
+                }
+            },
+            OracleType::Normal => {
+                if let OptionalValue::Some(price) =
+                    self.aggregator_price_if_applicable(configs, original_market_token, cache)
+                {
+                    if agrees(&price) {
+                        agreeing_sources += 1;
+                    }
+                }
+                if let OptionalValue::Some(price) =
+                    self.safe_price_if_applicable(configs, original_market_token, cache)
+                {
+                    if agrees(&price) {
+                        agreeing_sources += 1;
+                    }
+                }
+            },
+            OracleType::Lp => {
+                let (reserve_first, reserve_second, total_supply) =
+                    self.lp_reserves(configs, cache);
+                let on_chain_price = self.lp_on_chain_price(
+                    configs,
+                    &reserve_first,
+                    &reserve_second,
+                    &total_supply,
+                    cache,
+                );
+                let off_chain_price = self.off_chain_lp_price(
+                    configs,
+                    &reserve_first,
+                    &reserve_second,
+                    &total_supply,
+                    cache,
+                );
+                for price in [on_chain_price, off_chain_price].iter() {
+                    if agrees(price) {
+                        agreeing_sources += 1;
+                    }
+                }
+            },
+            _ => agreeing_sources = 1,
+        }
+
+        agreeing_sources
+    }
+
     /// Routes price discovery to appropriate method based on oracle token type.
     ///
     /// **Purpose:** Dispatches price calculation to specialized functions based on
@@ -610,6 +791,18 @@ pub trait OracleModule:
         configs: &OracleProvider<Self::Api>,
         token_id: &EgldOrEsdtTokenIdentifier,
         cache: &mut Cache<Self>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        self.safe_price_at_offset(configs, token_id, SECONDS_PER_MINUTE * 15, cache)
+    }
+
+    /// Retrieves the DEX safe price observed `offset_seconds` in the past, in EGLD.
+    /// Shares the pair status and exchange validation of `safe_price`.
+    fn safe_price_at_offset(
+        &self,
+        configs: &OracleProvider<Self::Api>,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        offset_seconds: u64,
+        cache: &mut Cache<Self>,
     ) -> ManagedDecimal<Self::Api, NumDecimals> {
         let one_token = BigUint::from(10u64).pow(configs.asset_decimals as u32);
 
@@ -636,7 +829,7 @@ pub trait OracleModule:
                 .get_safe_price_by_timestamp_offset(
                     from_identifier.clone(),
                     to_identifier.clone().unwrap_esdt(),
-                    SECONDS_PER_MINUTE * 15,
+                    offset_seconds,
                     EsdtTokenPayment::new(from_identifier, 0, one_token),
                 )
                 .returns(ReturnsResult)
@@ -653,7 +846,7 @@ pub trait OracleModule:
             self.safe_price_proxy(cache.safe_price_view.clone())
                 .get_safe_price_by_timestamp_offset(
                     &configs.oracle_contract_address,
-                    SECONDS_PER_MINUTE * 15,
+                    offset_seconds,
                     EsdtTokenPayment::new(token_id.clone().unwrap_esdt(), 0, one_token),
                 )
                 .returns(ReturnsResult)
@@ -688,6 +881,8 @@ pub trait OracleModule:
     /// - **Aggregator:** Off-chain price feeds (fast, comprehensive)
     /// - **Safe:** On-chain TWAP (manipulation-resistant, slower)
     /// - **Mix:** Both sources with cross-validation
+    /// - **Twap:** Mix while the aggregator is fresh and within the last tolerance,
+    ///   otherwise a multi-round xExchange TWAP
     ///
     /// **Security considerations:**
     /// - **First tolerance:** Tight bounds for normal operation
@@ -702,12 +897,149 @@ pub trait OracleModule:
         original_market_token: &EgldOrEsdtTokenIdentifier,
         cache: &mut Cache<Self>,
     ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        if configs.pricing_method == PricingMethod::Twap {
+            return self.twap_guarded_price(configs, original_market_token, cache);
+        }
+
         let aggregator_price =
             self.aggregator_price_if_applicable(configs, original_market_token, cache);
         let safe_price = self.safe_price_if_applicable(configs, original_market_token, cache);
         self.calculate_final_price(aggregator_price, safe_price, configs, cache)
     }
 
+    /// Prices a `Twap` token, falling back to a multi-round TWAP when the aggregator cannot be trusted.
+    ///
+    /// **How it works:**
+    /// 1. **Primary path:** A fresh aggregator price within the last tolerance of the
+    ///    15-minute safe price is validated exactly like `Mix`
+    /// 2. **Fallback:** A stale, missing or paused aggregator feed, or one deviating beyond
+    ///    the last tolerance, is replaced by the average of `twap_rounds` safe price rounds
+    ///
+    /// **Security considerations:**
+    /// - The fallback never reverts on aggregator outages, keeping the market usable
+    /// - Averaging several 15-minute windows widens the manipulation window an attacker must hold
+    ///
+    /// **Returns:** Token price in EGLD (WAD precision)
+    fn twap_guarded_price(
+        &self,
+        configs: &OracleProvider<Self::Api>,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        cache: &mut Cache<Self>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let safe_price = self.safe_price(configs, token_id, cache);
+        let aggregator_price =
+            self.fresh_aggregator_price_in_egld(token_id, configs.max_price_stale_seconds, cache);
+
+        match aggregator_price {
+            Some(aggregator_price)
+                if self.is_within_anchor(
+                    &aggregator_price,
+                    &safe_price,
+                    &configs.tolerance.last_upper_ratio_bps,
+                    &configs.tolerance.last_lower_ratio_bps,
+                ) =>
+            {
+                self.calculate_final_price(
+                    OptionalValue::Some(aggregator_price),
+                    OptionalValue::Some(safe_price),
+                    configs,
+                    cache,
+                )
+            },
+            _ => self.twap_price(configs, token_id, cache),
+        }
+    }
+
+    /// Averages `twap_rounds` consecutive 15-minute safe price rounds, the most recent first.
+    ///
+    /// **Returns:** Token price in EGLD (WAD precision)
+    fn twap_price(
+        &self,
+        configs: &OracleProvider<Self::Api>,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        cache: &mut Cache<Self>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let rounds_mapper = self.twap_rounds(token_id);
+        let rounds = if rounds_mapper.is_empty() {
+            DEFAULT_TWAP_ROUNDS
+        } else {
+            rounds_mapper.get().max(1)
+        };
+        let mut total = self.wad_zero();
+        for round in 1..=rounds {
+            total += self.safe_price_at_offset(
+                configs,
+                token_id,
+                SECONDS_PER_MINUTE * 15 * round as u64,
+                cache,
+            );
+        }
+
+        self.div_half_up(
+            &total,
+            &self.to_decimal(BigUint::from(rounds as u64), 0),
+            WAD_PRECISION,
+        )
+    }
+
+    /// Reads the aggregator price in EGLD without reverting.
+    /// Returns `None` when the aggregator is unset or paused, the feed is missing, or older than `max_seconds_stale`.
+    fn fresh_aggregator_price_in_egld(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        max_seconds_stale: DurationSeconds,
+        cache: &mut Cache<Self>,
+    ) -> Option<ManagedDecimal<Self::Api, NumDecimals>> {
+        self.fresh_aggregator_feed_in_egld(token_id, max_seconds_stale, cache)
+            .map(|(price, _)| price)
+    }
+
+    /// Returns `Some((price_in_egld_wad, feed_timestamp_secs))`, or `None` where
+    /// `fresh_aggregator_price_in_egld` does.
+    fn fresh_aggregator_feed_in_egld(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        max_seconds_stale: DurationSeconds,
+        cache: &mut Cache<Self>,
+    ) -> Option<(ManagedDecimal<Self::Api, NumDecimals>, TimestampSeconds)> {
+        let price_aggregator_sc = cache.price_aggregator_sc.clone();
+        if price_aggregator_sc.is_zero()
+            || self
+                .price_aggregator_paused_state(price_aggregator_sc.clone())
+                .get()
+        {
+            return None;
+        }
+
+        let ticker = self.token_ticker(token_id, cache);
+        let round_values = self.rounds(
+            price_aggregator_sc,
+            ticker,
+            ManagedBuffer::new_from_bytes(USD_TICKER),
+        );
+        if round_values.is_empty() {
+            return None;
+        }
+
+        let last_price = round_values.get();
+        if self.blockchain().get_block_timestamp_seconds() - last_price.timestamp
+            >= max_seconds_stale
+        {
+            return None;
+        }
+
+        let token_usd_price_wad = self.to_decimal_wad(last_price.price);
+        let price_in_egld = self.rescale_half_up(
+            &self.div_half_up(
+                &token_usd_price_wad,
+                &cache.egld_usd_price_wad,
+                RAY_PRECISION,
+            ),
+            WAD_PRECISION,
+        );
+        Some((price_in_egld, last_price.timestamp))
+    }
+
     /// Conditionally fetches aggregator price based on configured pricing method.
     ///
     /// **Purpose:** Retrieves off-chain oracle price when aggregator pricing
@@ -749,7 +1081,7 @@ pub trait OracleModule:
     /// is enabled in the token's configuration.
     ///
     /// **How it works:**
-    /// - Checks if pricing method includes safe price (Safe or Mix)
+    /// - Checks if pricing method includes safe price (Safe, Mix or Twap)
     /// - Returns OptionalValue for conditional processing
     /// - Delegates to TWAP price fetching with DEX validation
     ///
@@ -767,6 +1099,7 @@ pub trait OracleModule:
     ) -> OptionalValue<ManagedDecimal<Self::Api, NumDecimals>> {
         if configs.pricing_method == PricingMethod::Safe
             || configs.pricing_method == PricingMethod::Mix
+            || configs.pricing_method == PricingMethod::Twap
         {
             OptionalValue::Some(self.safe_price(configs, original_market_token, cache))
         } else {
@@ -1296,7 +1629,8 @@ pub trait OracleModule:
 
         let oracle_data = self.token_oracle(token_id);
         require!(!oracle_data.is_empty(), ERROR_ORACLE_TOKEN_NOT_FOUND);
-        let configs = oracle_data.get();
+        let mut configs = oracle_data.get();
+        self.apply_price_staleness_override(token_id, &mut configs);
 
         match configs.oracle_type {
             OracleType::Lp => {
@@ -1343,8 +1677,20 @@ pub trait OracleModule:
             },
             OracleType::Normal => {
                 let uses_aggregator = configs.pricing_method == PricingMethod::Aggregator
-                    || configs.pricing_method == PricingMethod::Mix;
-                let (aggregator_price, aggregator_timestamp_secs) = if uses_aggregator {
+                    || configs.pricing_method == PricingMethod::Mix
+                    || configs.pricing_method == PricingMethod::Twap;
+                let is_twap = configs.pricing_method == PricingMethod::Twap;
+                let (aggregator_price, aggregator_timestamp_secs) = if is_twap {
+                    // Same fallback as the price path: an unusable aggregator never reverts
+                    match self.fresh_aggregator_feed_in_egld(
+                        token_id,
+                        configs.max_price_stale_seconds,
+                        cache,
+                    ) {
+                        Some((price, ts)) => (OptionalValue::Some(price), ts),
+                        None => (OptionalValue::None, TimestampSeconds::zero()),
+                    }
+                } else if uses_aggregator {
                     let (price, ts) = self.aggregator_feed_in_egld(
                         token_id,
                         configs.max_price_stale_seconds,
@@ -1356,12 +1702,16 @@ pub trait OracleModule:
                 };
 
                 let safe_price = self.safe_price_if_applicable(&configs, token_id, cache);
-                let final_price = self.calculate_final_price(
-                    aggregator_price.clone(),
-                    safe_price.clone(),
-                    &configs,
-                    cache,
-                );
+                let final_price = if is_twap {
+                    self.twap_guarded_price(&configs, token_id, cache)
+                } else {
+                    self.calculate_final_price(
+                        aggregator_price.clone(),
+                        safe_price.clone(),
+                        &configs,
+                        cache,
+                    )
+                };
 
                 let (within_first, within_second) = match (&aggregator_price, &safe_price) {
                     (OptionalValue::Some(agg), OptionalValue::Some(safe)) => self
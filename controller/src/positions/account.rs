@@ -1,9 +1,10 @@
 use common_constants::BASE_NFT_URI;
-use common_structs::{AccountAttributes, PositionMode};
+use common_structs::{AccountAttributes, AccountPositionType, PositionMode};
 
 use crate::storage;
 use common_errors::{
-    ERROR_ACCOUNT_ATTRIBUTES_MISMATCH, ERROR_ACCOUNT_NOT_IN_THE_MARKET, ERROR_ADDRESS_IS_ZERO,
+    ERROR_ACCOUNT_ATTRIBUTES_MISMATCH, ERROR_ACCOUNT_HAS_OPEN_POSITIONS,
+    ERROR_ACCOUNT_NOT_IN_THE_MARKET, ERROR_ADDRESS_IS_ZERO,
 };
 
 multiversx_sc::imports!();
@@ -25,7 +26,7 @@ pub trait PositionAccountModule: common_events::EventsModule + storage::Storage
     /// **NFT Attributes Structure**:
     /// - `is_isolated_position`: Flag for isolation mode
     /// - `e_mode_category_id`: E-mode category (0 = disabled)
-    /// - `mode`: Position mode (standard, vault, etc.)
+    /// - `mode`: Position mode (normal, multiply, long or short)
     /// - `isolated_token`: Token identifier for isolated positions
     ///
     /// **Security Considerations**:
@@ -101,10 +102,75 @@ pub trait PositionAccountModule: common_events::EventsModule + storage::Storage
         let _ = self.accounts().insert(account_nonce);
         self.account_attributes(account_nonce)
             .set(attributes.clone());
+        self.set_account_holder(account_nonce, caller);
 
         (account_payment, attributes)
     }
 
+    /// Records the address holding an account NFT and keeps the reverse index in sync.
+    /// The account moves out of the previous holder's accounts when the NFT changed hands.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position
+    /// - `holder`: Address that presented the account NFT
+    fn set_account_holder(&self, account_nonce: u64, holder: &ManagedAddress) {
+        let holder_mapper = self.account_holder(account_nonce);
+        if !holder_mapper.is_empty() {
+            let previous_holder = holder_mapper.get();
+            if &previous_holder == holder {
+                return;
+            }
+            self.user_accounts(&previous_holder)
+                .swap_remove(&account_nonce);
+        }
+
+        holder_mapper.set(holder);
+        let _ = self.user_accounts(holder).insert(account_nonce);
+    }
+
+    /// Removes the holder of a closed account NFT together with its reverse index entry.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position
+    fn clear_account_holder(&self, account_nonce: u64) {
+        let holder_mapper = self.account_holder(account_nonce);
+        if holder_mapper.is_empty() {
+            return;
+        }
+
+        self.user_accounts(&holder_mapper.get())
+            .swap_remove(&account_nonce);
+        holder_mapper.clear();
+    }
+
+    /// Burns a closed account NFT and clears every storage entry keyed by its nonce.
+    /// Refuses to burn while any deposit or borrow position remains, so funds can
+    /// never be stranded behind a burned NFT.
+    ///
+    /// # Arguments
+    /// - `account_payment`: Account NFT payment held by the controller
+    fn burn_account(&self, account_payment: &EsdtTokenPayment<Self::Api>) {
+        let account_nonce = account_payment.token_nonce;
+        require!(
+            self.positions(account_nonce, AccountPositionType::Deposit)
+                .is_empty()
+                && self
+                    .positions(account_nonce, AccountPositionType::Borrow)
+                    .is_empty(),
+            ERROR_ACCOUNT_HAS_OPEN_POSITIONS
+        );
+
+        self.account()
+            .nft_burn(account_nonce, &account_payment.amount);
+        self.accounts().swap_remove(&account_nonce);
+        self.account_attributes(account_nonce).clear();
+        self.auto_compound(account_nonce).clear();
+        self.clear_account_holder(account_nonce);
+        self.account_borrow_allowance(account_nonce).clear();
+        self.last_liquidation_timestamp(account_nonce).clear();
+        self.liquidation_grace_until(account_nonce).clear();
+    }
+
     /// Retrieves an existing position or creates a new one.
     ///
     /// **Purpose**: Manages position NFT lifecycle by either using existing positions
@@ -278,6 +344,8 @@ pub trait PositionAccountModule: common_events::EventsModule + storage::Storage
             ERROR_ACCOUNT_ATTRIBUTES_MISMATCH
         );
 
+        self.set_account_holder(account_payment.token_nonce, &caller);
+
         if return_account {
             // Transfer the account NFT back to the caller right after validation
             self.tx().to(&caller).payment(&account_payment).transfer();
@@ -1,13 +1,15 @@
 use common_structs::{
-    AccountAttributes, AccountPosition, AccountPositionType, AssetConfig, EModeCategory,
-    PriceFeedShort,
+    AccountAttributes, AccountPosition, AccountPositionType, AssetConfig, CapDenomination,
+    EModeCategory, MarketActivityType, PriceFeedShort,
 };
 
 use crate::{cache::Cache, helpers, oracle, proxy_pool, storage, utils, validation};
+use common_constants::RAY_PRECISION;
 use common_errors::{
     ERROR_ASSET_NOT_BORROWABLE, ERROR_ASSET_NOT_BORROWABLE_IN_ISOLATION,
-    ERROR_ASSET_NOT_BORROWABLE_IN_SILOED, ERROR_BORROW_CAP, ERROR_DEBT_CEILING_REACHED,
-    ERROR_INSUFFICIENT_COLLATERAL, ERROR_INVALID_PAYMENTS, ERROR_WRONG_TOKEN,
+    ERROR_ASSET_NOT_BORROWABLE_IN_SILOED, ERROR_BORROW_ALLOWANCE_EXCEEDED, ERROR_BORROW_CAP,
+    ERROR_DEBT_CEILING_REACHED, ERROR_INSUFFICIENT_COLLATERAL, ERROR_INVALID_PAYMENTS,
+    ERROR_MAX_POSITION_VALUE_EXCEEDED, ERROR_MIN_RESERVE_RATIO, ERROR_WRONG_TOKEN,
 };
 
 use super::{account, emode, update};
@@ -40,8 +42,8 @@ pub trait PositionBorrowModule:
     /// 1. Validate asset support and apply e-mode adjustments if active
     /// 2. Ensure asset is borrowable and compatible with account constraints
     /// 3. Convert raw amount to decimal using token decimals
-    /// 4. Validate borrow cap and isolated-debt ceiling
-    /// 5. Compute flash fee: fee = amount * fee_bps / BPS
+    /// 4. Validate borrow cap, account borrow allowance and isolated-debt ceiling
+    /// 5. Compute strategy fee: fee = amount * (flash_fee_bps + origination_fee_bps) / BPS
     /// 6. Call pool.create_strategy to mint/update borrow position
     /// 7. Emit update event, persist position, validate back-transfers, and return amount
     ///
@@ -56,6 +58,7 @@ pub trait PositionBorrowModule:
     /// - `debt_token_id`: Token to borrow
     /// - `amount_raw`: Borrow amount in raw units
     /// - `debt_config`: Mutable asset config (may be updated by e-mode)
+    /// - `origination_fee_bps`: Additional fee charged on the amount, routed to revenue
     /// - `caller`: Borrower address for events
     /// - `account_attributes`: NFT attributes with mode/e-mode/isolated
     /// - `cache`: Protocol cache (prices, pools, indexes)
@@ -68,6 +71,7 @@ pub trait PositionBorrowModule:
         debt_token_id: &EgldOrEsdtTokenIdentifier,
         amount_raw: &BigUint,
         debt_config: &mut AssetConfig<Self::Api>,
+        origination_fee_bps: &ManagedDecimal<Self::Api, NumDecimals>,
         caller: &ManagedAddress,
         account_attributes: &AccountAttributes<Self::Api>,
         cache: &mut Cache<Self>,
@@ -93,11 +97,29 @@ pub trait PositionBorrowModule:
         let price_feed = self.token_price(debt_token_id, cache);
         let amount = borrow_position.make_amount_decimal(amount_raw, price_feed.asset_decimals);
 
-        self.validate_borrow_cap(debt_config, &amount, debt_token_id, cache);
+        self.validate_borrow_cap(
+            account_nonce,
+            caller,
+            debt_config,
+            &amount,
+            debt_token_id,
+            &price_feed,
+            cache,
+        );
+        self.validate_borrow_allowance(account_nonce, &amount, &borrows, &price_feed, cache);
+        self.validate_borrow_position_value(account_nonce, &amount, &borrows, &price_feed, cache);
+        self.validate_min_reserve_ratio(&amount, debt_token_id, cache);
 
         self.handle_isolated_debt(cache, &amount, account_attributes, &price_feed);
+        self.adjust_e_mode_debt_egld(
+            account_attributes.emode_id(),
+            self.token_egld_value(&amount, &price_feed.price_wad),
+            true,
+        );
 
         let flash_fee = amount.clone() * debt_config.flashloan_fee_bps.clone() / self.bps();
+        let origination_fee = amount.clone() * origination_fee_bps.clone() / self.bps();
+        let strategy_fee = flash_fee + origination_fee;
 
         let pool_address = cache.cached_pool_address(&borrow_position.asset_id);
 
@@ -117,7 +139,7 @@ pub trait PositionBorrowModule:
             .create_strategy(
                 borrow_position,
                 amount.clone(),
-                flash_fee.clone(),
+                strategy_fee,
                 price_feed.price_wad.clone(),
             )
             .returns(ReturnsResult)
@@ -132,6 +154,7 @@ pub trait PositionBorrowModule:
             caller,
             account_attributes,
         );
+        self.record_market_activity(debt_token_id, MarketActivityType::Borrow, &amount, cache);
 
         self.store_updated_position(account_nonce, &updated_borrow_position);
         require!(back_transfers.payments.len() == 1, ERROR_INVALID_PAYMENTS);
@@ -193,6 +216,7 @@ pub trait PositionBorrowModule:
             caller,
             account,
         );
+        self.record_market_activity(token_id, MarketActivityType::Borrow, &amount, cache);
 
         borrow_position
     }
@@ -284,19 +308,63 @@ pub trait PositionBorrowModule:
         })
     }
 
+    /// Returns the unit the borrow cap of an asset is expressed in.
+    /// Markets that never set a denomination keep their cap in token units.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier.
+    fn cap_denomination(&self, asset: &EgldOrEsdtTokenIdentifier) -> CapDenomination {
+        let mapper = self.borrow_cap_denomination(asset);
+        if mapper.is_empty() {
+            CapDenomination::Token
+        } else {
+            mapper.get()
+        }
+    }
+
     /// Ensures a new borrow stays within the asset's borrow cap.
+    /// EGLD-denominated caps are checked against the EGLD value of the market's debt.
+    /// An account-level override replaces the asset-wide cap and bounds the account's own debt.
+    /// Callers whitelisted in `borrow_cap_exempt_addresses` skip the asset-wide cap.
     ///
     /// # Arguments
+    /// - `account_nonce`: Position NFT nonce.
+    /// - `caller`: Borrower address.
     /// - `asset_config`: Borrowed asset configuration.
     /// - `amount`: Borrow amount.
     /// - `asset`: Token identifier.
+    /// - `feed`: Price feed of the borrowed asset.
     fn validate_borrow_cap(
         &self,
+        account_nonce: u64,
+        caller: &ManagedAddress,
         asset_config: &AssetConfig<Self::Api>,
         amount: &ManagedDecimal<Self::Api, NumDecimals>,
         asset: &EgldOrEsdtTokenIdentifier,
+        feed: &PriceFeedShort<Self::Api>,
         cache: &mut Cache<Self>,
     ) {
+        let override_mapper = self.account_borrow_cap_override(account_nonce, asset);
+        if !override_mapper.is_empty() {
+            let borrowed_amount = match self
+                .positions(account_nonce, AccountPositionType::Borrow)
+                .get(asset)
+            {
+                Some(position) => self.total_amount(&position, feed, cache),
+                None => self.to_decimal(BigUint::zero(), amount.scale()),
+            };
+            require!(
+                borrowed_amount + amount.clone()
+                    <= self.to_decimal(override_mapper.get(), amount.scale()),
+                ERROR_BORROW_CAP
+            );
+            return;
+        }
+
+        if self.borrow_cap_exempt_addresses().contains(caller) {
+            return;
+        }
+
         match &asset_config.borrow_cap_wad {
             Some(borrow_cap) => {
                 let pool = cache.cached_pool_address(asset);
@@ -308,11 +376,18 @@ pub trait PositionBorrowModule:
                     amount.scale(),
                 );
 
-                require!(
-                    borrowed_amount.clone() + amount.clone()
-                        <= self.to_decimal(borrow_cap.clone(), borrowed_amount.scale()),
-                    ERROR_BORROW_CAP
-                );
+                let new_borrowed_amount = borrowed_amount + amount.clone();
+
+                let within_cap = match self.cap_denomination(asset) {
+                    CapDenomination::Token => {
+                        new_borrowed_amount <= self.to_decimal(borrow_cap.clone(), amount.scale())
+                    },
+                    CapDenomination::Egld => {
+                        self.token_egld_value(&new_borrowed_amount, &feed.price_wad)
+                            <= self.to_decimal_wad(borrow_cap.clone())
+                    },
+                };
+                require!(within_cap, ERROR_BORROW_CAP);
             },
             None => {
                 // No borrow cap set, do nothing
@@ -320,6 +395,46 @@ pub trait PositionBorrowModule:
         }
     }
 
+    /// Ensures a new borrow leaves the market's minimum reserve unborrowed.
+    /// Utilization after the borrow may not exceed `(BPS - min_reserve_ratio_bps) / BPS`.
+    ///
+    /// # Arguments
+    /// - `amount`: Borrow amount.
+    /// - `asset`: Token identifier.
+    /// - `cache`: Mutable storage cache.
+    fn validate_min_reserve_ratio(
+        &self,
+        amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        asset: &EgldOrEsdtTokenIdentifier,
+        cache: &mut Cache<Self>,
+    ) {
+        let min_reserve_ratio = self.min_reserve_ratio_bps(asset).get();
+        if min_reserve_ratio == BigUint::zero() {
+            return;
+        }
+
+        let pool = cache.cached_pool_address(asset);
+        let index = cache.cached_market_index(asset);
+        let borrowed_amount = self.scaled_to_original(
+            &self.borrowed(pool.clone()).get(),
+            &index.borrow_index_ray,
+            amount.scale(),
+        );
+        let supplied_amount = self.scaled_to_original(
+            &self.supplied(pool).get(),
+            &index.supply_index_ray,
+            amount.scale(),
+        );
+
+        let max_utilization = self.bps() - self.to_decimal_bps(min_reserve_ratio);
+        let max_borrowed_amount = supplied_amount * max_utilization / self.bps();
+
+        require!(
+            borrowed_amount + amount.clone() <= max_borrowed_amount,
+            ERROR_MIN_RESERVE_RATIO
+        );
+    }
+
     /// Validates sufficient collateral for a borrow operation.
     ///
     /// # Arguments
@@ -361,6 +476,74 @@ pub trait PositionBorrowModule:
         self.validate_borrow_collateral(ltv_base_amount, &egld_total_borrowed, &egld_amount);
     }
 
+    /// Validates the new borrow against the account's borrow allowance.
+    /// Accounts without an allowance, and protocol-owned accounts, are only limited by their
+    /// collateral.
+    ///
+    /// Arguments
+    /// - `account_nonce`: Position NFT nonce
+    /// - `amount`: Borrow amount in token decimals
+    /// - `borrow_positions`: Current borrow positions
+    /// - `feed`: Price feed for borrowed token
+    /// - `cache`: Mutable storage cache
+    fn validate_borrow_allowance(
+        &self,
+        account_nonce: u64,
+        amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        borrow_positions: &ManagedVec<AccountPosition<Self::Api>>,
+        feed: &PriceFeedShort<Self::Api>,
+        cache: &mut Cache<Self>,
+    ) {
+        let allowance_mapper = self.account_borrow_allowance(account_nonce);
+        if allowance_mapper.is_empty() || self.protocol_owned_accounts().contains(&account_nonce) {
+            return;
+        }
+
+        let allowance =
+            self.rescale_half_up(&self.to_decimal_wad(allowance_mapper.get()), RAY_PRECISION);
+        let egld_amount = self.token_egld_value_ray(amount, &feed.price_wad);
+        let egld_total_borrowed = self.calculate_total_borrow_in_egld(borrow_positions, cache);
+
+        require!(
+            egld_total_borrowed + egld_amount <= allowance,
+            ERROR_BORROW_ALLOWANCE_EXCEEDED
+        );
+    }
+
+    /// Validates the new borrow against the protocol-wide maximum position value.
+    /// Without a configured cap, debt is only limited by collateral and allowances.
+    /// Protocol-owned accounts are exempt from the cap.
+    ///
+    /// Arguments
+    /// - `account_nonce`: Position NFT nonce
+    /// - `amount`: Borrow amount in token decimals
+    /// - `borrow_positions`: Current borrow positions
+    /// - `feed`: Price feed for borrowed token
+    /// - `cache`: Mutable storage cache
+    fn validate_borrow_position_value(
+        &self,
+        account_nonce: u64,
+        amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        borrow_positions: &ManagedVec<AccountPosition<Self::Api>>,
+        feed: &PriceFeedShort<Self::Api>,
+        cache: &mut Cache<Self>,
+    ) {
+        let max_value_mapper = self.max_position_value();
+        if max_value_mapper.is_empty() || self.protocol_owned_accounts().contains(&account_nonce) {
+            return;
+        }
+
+        let max_value =
+            self.rescale_half_up(&self.to_decimal_wad(max_value_mapper.get()), RAY_PRECISION);
+        let egld_amount = self.token_egld_value_ray(amount, &feed.price_wad);
+        let egld_total_borrowed = self.calculate_total_borrow_in_egld(borrow_positions, cache);
+
+        require!(
+            egld_total_borrowed + egld_amount <= max_value,
+            ERROR_MAX_POSITION_VALUE_EXCEEDED
+        );
+    }
+
     /// Validates an asset's borrowability under position constraints.
     ///
     /// # Arguments
@@ -438,13 +621,15 @@ pub trait PositionBorrowModule:
     /// 1. Validates payment structure and asset configuration
     /// 2. Applies e-mode parameters if position has active e-mode
     /// 3. Validates asset borrowability under current position constraints
-    /// 4. Performs LTV collateral validation against total debt
-    /// 5. Validates borrow cap and isolated debt constraints
+    /// 4. Performs LTV collateral validation against total debt, the account's borrow allowance
+    ///    and the maximum position value
+    /// 5. Validates borrow cap, minimum reserve ratio and isolated debt constraints
     /// 6. Executes position update through handle_borrow_position
     /// 7. Updates bulk borrow tracking if applicable
     ///
     /// **Security Checks**:
     /// - Payment validation prevents malformed inputs
+    /// - Paused markets reject new borrows
     /// - E-mode compatibility ensures proper risk parameters
     /// - Asset borrowability validation enforces isolation/siloed rules
     /// - LTV validation prevents undercollateralized positions
@@ -486,6 +671,8 @@ pub trait PositionBorrowModule:
     ) {
         // Basic validations
         self.validate_payment(borrowed_token);
+        self.require_market_not_paused(&borrowed_token.token_identifier);
+        self.require_new_borrow_enabled(&borrowed_token.token_identifier);
 
         // Get and validate asset configuration
         let mut asset_config = cache.cached_asset_info(&borrowed_token.token_identifier);
@@ -513,14 +700,25 @@ pub trait PositionBorrowModule:
 
         // Validate borrow amounts and caps
         self.validate_ltv_collateral(ltv_collateral, &amount, borrows, &price_feed, cache);
+        self.validate_borrow_allowance(account_nonce, &amount, borrows, &price_feed, cache);
+        self.validate_borrow_position_value(account_nonce, &amount, borrows, &price_feed, cache);
         self.validate_borrow_cap(
+            account_nonce,
+            caller,
             &asset_config,
             &amount,
             &borrowed_token.token_identifier,
+            &price_feed,
             cache,
         );
+        self.validate_min_reserve_ratio(&amount, &borrowed_token.token_identifier, cache);
 
         self.handle_isolated_debt(cache, &amount, account_attributes, &price_feed);
+        self.adjust_e_mode_debt_egld(
+            account_attributes.emode_id(),
+            self.token_egld_value(&amount, &price_feed.price_wad),
+            true,
+        );
 
         // Handle the borrow position
         let updated_position = self.handle_borrow_position(
@@ -1,9 +1,9 @@
-use common_structs::{AssetConfig, EModeAssetConfig, EModeCategory};
+use common_structs::{AccountPosition, AssetConfig, EModeAssetConfig, EModeCategory};
 
 use crate::storage;
 use common_errors::{
     ERROR_CANNOT_USE_EMODE_WITH_ISOLATED_ASSETS, ERROR_EMODE_CATEGORY_DEPRECATED,
-    ERROR_EMODE_CATEGORY_NOT_FOUND,
+    ERROR_EMODE_CATEGORY_NOT_FOUND, ERROR_EMODE_COMPANION_COLLATERAL_REQUIRED,
 };
 
 multiversx_sc::imports!();
@@ -123,6 +123,54 @@ pub trait EModeModule: storage::Storage {
         );
     }
 
+    /// Ensures e-mode collateral flagged as companion-required is not the only collateral.
+    ///
+    /// **Purpose**: Lets a category accept assets as collateral only alongside other
+    /// eligible collateral, so a borrow is never backed by such an asset alone.
+    ///
+    /// **Methodology**:
+    /// - Skips accounts without e-mode (ID = 0)
+    /// - Counts collateral deposits usable as collateral in the category
+    /// - Rejects when a companion-required asset is the only eligible collateral
+    ///
+    /// # Arguments
+    /// - `e_mode_id`: E-mode category ID of the account (0 = disabled)
+    /// - `collaterals`: Deposit positions of the account
+    fn ensure_e_mode_companion_collateral(
+        &self,
+        e_mode_id: u8,
+        collaterals: &ManagedVec<AccountPosition<Self::Api>>,
+    ) {
+        if e_mode_id == 0 {
+            return;
+        }
+
+        let e_mode_assets = self.e_mode_assets(e_mode_id);
+        let mut eligible_collaterals = 0usize;
+        let mut requires_companion = false;
+        for position in collaterals.iter() {
+            if self
+                .position_collateral_disabled(position.account_nonce, &position.asset_id)
+                .get()
+            {
+                continue;
+            }
+            if let Some(config) = e_mode_assets.get(&position.asset_id) {
+                if config.can_supply() {
+                    eligible_collaterals += 1;
+                    requires_companion |= self
+                        .e_mode_companion_collateral_required(e_mode_id, &position.asset_id)
+                        .get();
+                }
+            }
+        }
+
+        require!(
+            !requires_companion || eligible_collaterals > 1,
+            ERROR_EMODE_COMPANION_COLLATERAL_REQUIRED
+        );
+    }
+
     /// Retrieves valid e-mode configuration for a token.
     ///
     /// **Purpose**: Safely loads asset-specific e-mode configuration with validation
@@ -1,9 +1,14 @@
-use common_constants::{RAY_PRECISION, WAD_PRECISION};
-use common_structs::{AccountPosition, AccountPositionType, PriceFeedShort};
+use common_constants::{LIQUIDATION_HISTORY_CAPACITY, RAY_PRECISION, WAD_PRECISION};
+use common_structs::{
+    AccountAttributes, AccountPosition, AccountPositionType, BadDebtWriteOff, LiquidationRecord,
+    PriceFeedShort,
+};
 
 use crate::{cache::Cache, helpers, oracle, proxy_pool, storage, utils, validation};
 use common_errors::{
     ERROR_HEALTH_FACTOR, ERROR_INVALID_PAYMENTS, ERROR_NO_DEBT_PAYMENTS_TO_PROCESS,
+    ERROR_POST_LIQUIDATION_GRACE, ERROR_SEIZED_COLLATERAL_BELOW_MINIMUM,
+    ERROR_THRESHOLD_UPDATE_GRACE,
 };
 
 use super::{account, borrow, emode, repay, update, withdraw};
@@ -64,6 +69,8 @@ pub trait PositionLiquidationModule:
     /// # Arguments
     /// - `account_nonce`: Position NFT nonce identifying the borrower's account
     /// - `debt_payments`: Vector of ERC20/EGLD payments for debt repayment
+    /// - `preferred_collaterals`: Collateral tokens to seize first, in order; empty keeps the
+    ///   proportional seizure
     /// - `cache`: Mutable storage cache for price feeds and pool addresses
     ///
     /// # Returns
@@ -77,6 +84,7 @@ pub trait PositionLiquidationModule:
         &self,
         account_nonce: u64,
         debt_payments: &ManagedVec<EgldOrEsdtTokenPayment<Self::Api>>,
+        preferred_collaterals: &ManagedVec<EgldOrEsdtTokenIdentifier>,
         is_view: bool,
         cache: &mut Cache<Self>,
     ) -> (
@@ -93,9 +101,15 @@ pub trait PositionLiquidationModule:
         ManagedDecimal<Self::Api, NumDecimals>,
     ) {
         let mut refunds = ManagedVec::new();
+        // Supply-only deposits are not collateral and can never be seized
         let deposit_positions = self
             .positions(account_nonce, AccountPositionType::Deposit)
             .values()
+            .filter(|position| {
+                !self
+                    .position_collateral_disabled(account_nonce, &position.asset_id)
+                    .get()
+            })
             .collect();
 
         let (borrow_positions, map_debt_indexes) = self.borrow_positions(account_nonce, true);
@@ -134,6 +148,7 @@ pub trait PositionLiquidationModule:
             &total_collateral,
             &max_debt_to_repay_ray,
             &bonus_rate_ray,
+            preferred_collaterals,
             cache,
         );
 
@@ -177,17 +192,27 @@ pub trait PositionLiquidationModule:
     /// 1. **Security Setup**: Establishes reentrancy protection and cache initialization
     /// 2. **Payment Validation**: Validates liquidator payments and authorization
     /// 3. **Account Verification**: Confirms account exists and is active
-    /// 4. **Liquidation Execution**: Runs core liquidation algorithm via `execute_liquidation`
-    /// 5. **Refund Processing**: Returns excess payments to liquidator if any
-    /// 6. **Debt Settlement**: Processes each debt repayment through respective liquidity pools
-    /// 7. **Collateral Transfer**: Handles seized collateral transfers with protocol fees
+    /// 4. **Grace Check**: Rejects accounts liquidated less than `post_liquidation_grace` ago
+    /// 5. **Price Regression Check**: Defers the liquidation and refunds the liquidator when a
+    ///    collateral price dropped more than `max_liquidation_price_drop_bps` since the last check
+    /// 6. **Liquidation Execution**: Runs core liquidation algorithm via `execute_liquidation`
+    /// 7. **Refund Processing**: Returns excess payments to liquidator if any
+    /// 8. **Debt Settlement**: Processes each debt repayment through respective liquidity pools
+    /// 9. **Collateral Transfer**: Handles seized collateral transfers with protocol fees
+    /// 10. **Minimum Seizure**: Rejects liquidations seizing less than `min_seized_collateral_egld`
+    /// 11. **Dust Credit**: Credits leftover dust collateral to the account for its NFT holder to claim
+    /// 12. **History**: Appends the liquidation to the account's bounded liquidation history
+    /// 13. **Grace Start**: Records the liquidation time to open the account's grace period
     ///
     /// # Security Checks Implemented
     /// - Reentrancy protection via `cache.flash_loan_ongoing` guard
     /// - Payment validation for all debt repayments
     /// - Caller address validation (non-zero address requirement)
     /// - Account existence and active status verification
+    /// - Post-liquidation grace period enforcement
+    /// - Minimum seized collateral value enforcement
     /// - Safe price oracle usage (unsafe prices disabled)
+    /// - Sudden collateral price regression detection (deferral to the next block)
     ///
     /// # Integration Points
     /// The function integrates with multiple protocol components:
@@ -201,11 +226,13 @@ pub trait PositionLiquidationModule:
     /// - `account_nonce`: Position NFT nonce identifying the borrower's account
     /// - `debt_payments`: Vector of ERC20/EGLD payments for debt repayment
     /// - `caller`: Address of the liquidator initiating the liquidation
+    /// - `preferred_collaterals`: Collateral tokens the liquidator wants seized first, in order
     fn process_liquidation(
         &self,
         account_nonce: u64,
         debt_payments: &ManagedVec<EgldOrEsdtTokenPayment<Self::Api>>,
         caller: &ManagedAddress,
+        preferred_collaterals: &ManagedVec<EgldOrEsdtTokenIdentifier>,
     ) {
         let mut cache = Cache::new(self);
         self.reentrancy_guard(cache.flash_loan_ongoing);
@@ -213,11 +240,29 @@ pub trait PositionLiquidationModule:
         self.validate_liquidation_payments(debt_payments, caller);
 
         self.require_active_account(account_nonce);
+        self.validate_post_liquidation_grace(account_nonce);
+        require!(
+            !self.is_within_liquidation_grace(account_nonce),
+            ERROR_THRESHOLD_UPDATE_GRACE
+        );
+
+        if self.is_liquidation_deferred(account_nonce, &mut cache) {
+            self.tx()
+                .to(caller)
+                .payment(debt_payments.clone())
+                .transfer_if_not_empty();
+            return;
+        }
 
         let account_attributes = self.account_attributes(account_nonce).get();
 
-        let (seized_collaterals, repaid_tokens, refunds, _, _) =
-            self.execute_liquidation(account_nonce, debt_payments, false, &mut cache);
+        let (seized_collaterals, repaid_tokens, refunds, _, _) = self.execute_liquidation(
+            account_nonce,
+            debt_payments,
+            preferred_collaterals,
+            false,
+            &mut cache,
+        );
 
         if !refunds.is_empty() {
             self.tx()
@@ -228,8 +273,11 @@ pub trait PositionLiquidationModule:
 
         require!(!repaid_tokens.is_empty(), ERROR_NO_DEBT_PAYMENTS_TO_PROCESS);
 
+        let mut debt_repaid_egld_ray = self.ray_zero();
+        let mut collateral_seized_egld_wad = self.wad_zero();
         for debt_payment_data in repaid_tokens {
             let (debt_payment, debt_egld_value, debt_price_feed) = debt_payment_data.into_tuple();
+            debt_repaid_egld_ray += &debt_egld_value;
             self.process_repayment(
                 account_nonce,
                 &debt_payment.token_identifier,
@@ -249,6 +297,7 @@ pub trait PositionLiquidationModule:
             let price_feed = self.token_price(&deposit_position.asset_id, &mut cache);
             let amount = deposit_position
                 .make_amount_decimal(&seized_collateral.amount, price_feed.asset_decimals);
+            collateral_seized_egld_wad += self.token_egld_value(&amount, &price_feed.price_wad);
             let _ = self.process_withdrawal(
                 account_nonce,
                 amount,
@@ -261,6 +310,306 @@ pub trait PositionLiquidationModule:
                 &price_feed,
             );
         }
+
+        self.validate_min_seized_collateral(&collateral_seized_egld_wad);
+
+        self.credit_dust_collateral(account_nonce, &account_attributes, &mut cache);
+
+        self.record_liquidation(
+            account_nonce,
+            caller,
+            self.rescale_half_up(&debt_repaid_egld_ray, WAD_PRECISION),
+            collateral_seized_egld_wad,
+            &cache,
+        );
+
+        if !self.post_liquidation_grace().is_empty() && self.accounts().contains(&account_nonce) {
+            self.last_liquidation_timestamp(account_nonce)
+                .set(self.blockchain().get_block_timestamp_seconds());
+        }
+    }
+
+    /// Appends a liquidation to the account's bounded liquidation history.
+    ///
+    /// **Purpose**: Lets users review how their account was liquidated without an
+    /// indexer, while keeping storage bounded by `LIQUIDATION_HISTORY_CAPACITY`.
+    ///
+    /// **Methodology**:
+    /// - Appends records until the buffer holds `LIQUIDATION_HISTORY_CAPACITY` items
+    /// - Afterwards overwrites the oldest record and advances the head slot
+    ///
+    /// # Arguments
+    /// - `account_nonce`: Position NFT nonce identifying the liquidated account
+    /// - `liquidator`: Address of the liquidator
+    /// - `debt_repaid_egld_wad`: EGLD value of the repaid debt (WAD)
+    /// - `collateral_seized_egld_wad`: EGLD value of the seized collateral (WAD)
+    /// - `cache`: Storage cache for the block timestamp
+    fn record_liquidation(
+        &self,
+        account_nonce: u64,
+        liquidator: &ManagedAddress,
+        debt_repaid_egld_wad: ManagedDecimal<Self::Api, NumDecimals>,
+        collateral_seized_egld_wad: ManagedDecimal<Self::Api, NumDecimals>,
+        cache: &Cache<Self>,
+    ) {
+        let record = LiquidationRecord {
+            timestamp: cache.current_timestamp,
+            liquidator: liquidator.clone(),
+            debt_repaid_egld_wad,
+            collateral_seized_egld_wad,
+        };
+
+        let mut history = self.liquidation_history(account_nonce);
+        if history.len() < LIQUIDATION_HISTORY_CAPACITY {
+            history.push(&record);
+            return;
+        }
+
+        let head_mapper = self.liquidation_history_head(account_nonce);
+        let head = head_mapper.get();
+        history.set(head + 1, &record);
+        head_mapper.set((head + 1) % LIQUIDATION_HISTORY_CAPACITY);
+    }
+
+    /// Rejects the liquidation of an account still within its post-liquidation grace period.
+    ///
+    /// After a liquidation the account is protected for `post_liquidation_grace`, letting its
+    /// owner repay the remaining debt instead of facing back-to-back liquidations.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: Position NFT nonce identifying the borrower's account
+    ///
+    /// # Errors
+    /// - `ERROR_POST_LIQUIDATION_GRACE`: If the last liquidation happened less than the grace period ago
+    fn validate_post_liquidation_grace(&self, account_nonce: u64) {
+        let grace_mapper = self.post_liquidation_grace();
+        let last_liquidation_mapper = self.last_liquidation_timestamp(account_nonce);
+        if grace_mapper.is_empty() || last_liquidation_mapper.is_empty() {
+            return;
+        }
+
+        require!(
+            self.blockchain().get_block_timestamp_seconds() - last_liquidation_mapper.get()
+                >= grace_mapper.get(),
+            ERROR_POST_LIQUIDATION_GRACE
+        );
+    }
+
+    /// Checks whether an account is protected after a liquidation threshold reduction.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: Position NFT nonce identifying the borrower's account
+    ///
+    /// # Returns
+    /// - `true` while the block timestamp is before the account's `liquidation_grace_until`
+    fn is_within_liquidation_grace(&self, account_nonce: u64) -> bool {
+        let grace_until_mapper = self.liquidation_grace_until(account_nonce);
+        !grace_until_mapper.is_empty()
+            && self.blockchain().get_block_timestamp_seconds() < grace_until_mapper.get()
+    }
+
+    /// Rejects a liquidation that seizes less collateral than `min_seized_collateral_egld`.
+    ///
+    /// # Arguments
+    /// - `collateral_seized_egld_wad`: EGLD value of the collateral seized by the liquidation (WAD)
+    ///
+    /// # Errors
+    /// - `ERROR_SEIZED_COLLATERAL_BELOW_MINIMUM`: If the seized collateral is worth less than the minimum
+    fn validate_min_seized_collateral(
+        &self,
+        collateral_seized_egld_wad: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        let min_mapper = self.min_seized_collateral_egld();
+        if min_mapper.is_empty() {
+            return;
+        }
+
+        require!(
+            *collateral_seized_egld_wad >= self.to_decimal_wad(min_mapper.get()),
+            ERROR_SEIZED_COLLATERAL_BELOW_MINIMUM
+        );
+    }
+
+    /// Credits dust collateral left in an account after a liquidation cleared all of its debt.
+    ///
+    /// When the account has no borrow position left and its remaining collateral is worth at
+    /// most `liquidation_dust_threshold_usd`, every collateral deposit is withdrawn to the
+    /// controller and credited to the account (`dust_collateral`) instead of staying stranded.
+    /// The credit is paid to whoever presents the account NFT next, never to a stored holder
+    /// that may no longer own it. Supply-only deposits are not valued as collateral and stay
+    /// in the account.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: Position NFT nonce identifying the liquidated account
+    /// - `account_attributes`: Attributes of the liquidated account
+    /// - `cache`: Mutable storage cache for price feeds and pool addresses
+    fn credit_dust_collateral(
+        &self,
+        account_nonce: u64,
+        account_attributes: &AccountAttributes<Self::Api>,
+        cache: &mut Cache<Self>,
+    ) {
+        let threshold = self.liquidation_dust_threshold_usd().get();
+        if threshold == 0
+            || !self
+                .positions(account_nonce, AccountPositionType::Borrow)
+                .is_empty()
+        {
+            return;
+        }
+
+        // Supply-only deposits are not valued as collateral and are left untouched
+        let collateral_positions: ManagedVec<AccountPosition<Self::Api>> = self
+            .positions(account_nonce, AccountPositionType::Deposit)
+            .values()
+            .filter(|position| {
+                !self
+                    .position_collateral_disabled(account_nonce, &position.asset_id)
+                    .get()
+            })
+            .collect();
+        if collateral_positions.is_empty() {
+            return;
+        }
+
+        let (_, total_collateral, _) =
+            self.calculate_collateral_values(&collateral_positions, cache);
+        let total_collateral_usd =
+            self.egld_usd_value(&total_collateral, &cache.egld_usd_price_wad);
+        if total_collateral_usd > self.to_decimal_wad(threshold) {
+            return;
+        }
+
+        let controller = self.blockchain().get_sc_address();
+        let mut dust_mapper = self.dust_collateral(account_nonce);
+        for mut deposit_position in collateral_positions {
+            let price_feed = self.token_price(&deposit_position.asset_id, cache);
+            let amount = self.total_amount(&deposit_position, &price_feed, cache);
+            let payment = self.process_withdrawal(
+                account_nonce,
+                amount,
+                &controller,
+                false,
+                None,
+                cache,
+                account_attributes,
+                &mut deposit_position,
+                &price_feed,
+            );
+
+            let credited = dust_mapper
+                .get(&payment.token_identifier)
+                .unwrap_or_else(BigUint::zero);
+            dust_mapper.insert(payment.token_identifier, credited + payment.amount);
+        }
+    }
+
+    /// Detects a suspicious collateral price regression before a liquidation executes.
+    ///
+    /// Compares the current price of every collateral asset of the account with its reference
+    /// price (`liquidation_reference_price`). When any of them dropped by more than
+    /// `max_liquidation_price_drop_bps` in a single update, liquidations holding that asset are
+    /// deferred for the rest of the current block.
+    ///
+    /// The reference is only written here and seeded by `updateIndexes` while empty, so no
+    /// other endpoint can move it to a crashed price ahead of a liquidation. It follows the
+    /// price on every check that passes, but not within a deferral window, so repeated
+    /// liquidation attempts cannot move it. Once the deferral expires, the price observed then
+    /// becomes the reference and a persisting drop is accepted.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: Position NFT nonce identifying the borrower's account
+    /// - `cache`: Mutable storage cache for price feeds and block timestamp
+    ///
+    /// # Returns
+    /// - `true` if the liquidation must be deferred to a later block
+    fn is_liquidation_deferred(&self, account_nonce: u64, cache: &mut Cache<Self>) -> bool {
+        let max_drop_bps = self.max_liquidation_price_drop_bps().get();
+        if max_drop_bps == 0 {
+            return false;
+        }
+
+        let allowed_bps = self.bps() - self.to_decimal_bps(max_drop_bps);
+        let mut deferred = false;
+        for asset in self
+            .positions(account_nonce, AccountPositionType::Deposit)
+            .keys()
+        {
+            let deferred_at_mapper = self.liquidation_deferred_at(&asset);
+            if !deferred_at_mapper.is_empty() {
+                if deferred_at_mapper.get() == cache.current_timestamp {
+                    deferred = true;
+                    continue;
+                }
+
+                // The deferral expired: the price observed now becomes the reference
+                deferred_at_mapper.clear();
+                let feed = self.token_price(&asset, cache);
+                self.liquidation_reference_price(&asset).set(feed.price_wad);
+                continue;
+            }
+
+            let feed = self.token_price(&asset, cache);
+            let reference_mapper = self.liquidation_reference_price(&asset);
+            if !reference_mapper.is_empty() {
+                let min_price = reference_mapper.get() * allowed_bps.clone() / self.bps();
+                if feed.price_wad < min_price {
+                    deferred_at_mapper.set(cache.current_timestamp);
+                    deferred = true;
+                }
+            }
+
+            reference_mapper.set(feed.price_wad);
+        }
+
+        deferred
+    }
+
+    /// Records the current price of an asset for the delisted asset price fallback, and seeds
+    /// the reference of the liquidation price regression check while it is empty. An existing
+    /// reference is never moved, so `updateIndexes` cannot hide a price crash from the check.
+    /// Does nothing while both are disabled.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier to record the price for
+    /// - `cache`: Mutable storage cache for price feeds
+    fn record_last_token_price(&self, asset: &EgldOrEsdtTokenIdentifier, cache: &mut Cache<Self>) {
+        let seed_reference = self.max_liquidation_price_drop_bps().get() > 0
+            && self.liquidation_reference_price(asset).is_empty();
+        let record_last_price = !self.delisted_price_max_age().is_empty();
+        if !seed_reference && !record_last_price {
+            return;
+        }
+
+        let feed = self.token_price(asset, cache);
+        if seed_reference {
+            self.liquidation_reference_price(asset)
+                .set(feed.price_wad.clone());
+        }
+        if record_last_price {
+            self.store_last_token_price(asset, feed.price_wad);
+        }
+    }
+
+    /// Stores the last known price of an asset together with the current block timestamp.
+    /// Prices of assets without an oracle come from the fallback itself and are not stored,
+    /// so the fallback window is never extended.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier to store the price for
+    /// - `price_wad`: Price of the asset in EGLD (WAD)
+    fn store_last_token_price(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+        price_wad: ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        if self.token_oracle(asset).is_empty() {
+            return;
+        }
+
+        self.last_token_price(asset).set(price_wad);
+        self.last_token_price_timestamp(asset)
+            .set(self.blockchain().get_block_timestamp_seconds());
     }
 
     /// Validates that the position's health factor qualifies for liquidation and prevents healthy position liquidation.
@@ -357,17 +706,25 @@ pub trait PositionLiquidationModule:
     /// 2. **Base Seizure**: Applies proportion to total debt repayment amount
     /// 3. **Bonus Application**: Adds liquidation bonus (multiplier = BPS + bonus_rate)
     /// 4. **Protocol Fee Extraction**: Calculates fee only on bonus portion using `liquidation_fees`
-    /// 5. **Precision Management**: Rescales from RAY precision to asset-specific decimals
-    /// 6. **Safety Bounds**: Ensures seized amount never exceeds total deposited amount
+    /// 5. **Liquidatee Rebate**: Leaves `liquidatee_rebate_bps` of the bonus portion in the borrower's position
+    /// 6. **Precision Management**: Rescales from RAY precision to asset-specific decimals
+    /// 7. **Safety Bounds**: Ensures seized amount never exceeds total deposited amount
+    ///
+    /// # Seizure Preference
+    /// When the liquidator lists `preferred_collaterals`, those assets are seized first, in the
+    /// listed order, each up to its whole deposited amount. The debt they do not cover is then
+    /// seized proportionally from the remaining assets using the formulas below, with
+    /// `total_collateral_value` and `debt_to_repay` restricted to those assets.
     ///
     /// # Mathematical Formulas
     /// For each collateral asset `i`:
     /// ```
     /// proportion_i = asset_value_i / total_collateral_value
     /// base_seizure_i = debt_to_repay * proportion_i
-    /// bonus_seizure_i = base_seizure_i * (1 + bonus_rate)
-    /// protocol_fee_i = (bonus_seizure_i - base_seizure_i) * liquidation_fees_i
-    /// final_seizure_i = min(bonus_seizure_i, total_deposited_i)
+    /// bonus_seizure_i = min(base_seizure_i * (1 + bonus_rate), total_deposited_i)
+    /// rebate_i = (bonus_seizure_i - base_seizure_i) * liquidatee_rebate
+    /// protocol_fee_i = (bonus_seizure_i - base_seizure_i - rebate_i) * liquidation_fees_i
+    /// final_seizure_i = bonus_seizure_i - rebate_i
     /// ```
     ///
     /// All calculations performed in RAY precision (27 decimals) then rescaled to asset decimals.
@@ -391,6 +748,7 @@ pub trait PositionLiquidationModule:
     /// - `total_collateral_value`: Total collateral value across all assets (EGLD-denominated)
     /// - `debt_to_be_repaid_ray`: Amount of debt being repaid (RAY precision)
     /// - `bonus_rate_ray`: Liquidation bonus rate (RAY precision)
+    /// - `preferred_collaterals`: Collateral tokens to seize first, in order
     /// - `cache`: Mutable storage cache for price feeds and asset data
     ///
     /// # Returns
@@ -403,6 +761,7 @@ pub trait PositionLiquidationModule:
         total_collateral_value: &ManagedDecimal<Self::Api, NumDecimals>,
         debt_to_be_repaid_ray: &ManagedDecimal<Self::Api, NumDecimals>,
         bonus_rate_ray: &ManagedDecimal<Self::Api, NumDecimals>,
+        preferred_collaterals: &ManagedVec<EgldOrEsdtTokenIdentifier>,
         cache: &mut Cache<Self>,
     ) -> ManagedVec<MultiValue2<EgldOrEsdtTokenPayment, ManagedDecimal<Self::Api, NumDecimals>>>
     {
@@ -410,8 +769,15 @@ pub trait PositionLiquidationModule:
 
         // Pre-calculate bonus multiplier
         let bonus_multiplier_ray = self.ray() + bonus_rate_ray.clone();
+        let rebate_bps = self.to_decimal_bps(self.liquidatee_rebate_bps().get());
 
-        for position in deposit_positions {
+        // Preferred assets come first and are seized greedily; the rest share the remaining debt
+        let (ordered_positions, preferred_count, preferred_value_ray) =
+            self.order_by_seizure_preference(deposit_positions, preferred_collaterals, cache);
+        let remaining_collateral_value = total_collateral_value.clone() - preferred_value_ray;
+        let mut remaining_debt_ray = debt_to_be_repaid_ray.clone();
+
+        for (index, position) in ordered_positions.iter().enumerate() {
             let asset_price_feed = self.token_price(&position.asset_id, cache);
             // Tokens with price 0 are not collateralizable thus no need to calculate anything, they will just be skipped from seized.
             if asset_price_feed.price_wad == self.wad_zero() {
@@ -421,13 +787,26 @@ pub trait PositionLiquidationModule:
             let asset_egld_value_ray =
                 self.token_egld_value_ray(&total_amount_ray, &asset_price_feed.price_wad);
 
-            // Calculate proportion in RAY precision
-            let asset_proportion_ray =
-                self.div_half_up(&asset_egld_value_ray, total_collateral_value, RAY_PRECISION);
+            let seized_egld_ray = if index < preferred_count {
+                // Seize as much of the preferred asset as the remaining debt allows
+                let max_seizable_egld_ray =
+                    self.div_half_up(&asset_egld_value_ray, &bonus_multiplier_ray, RAY_PRECISION);
+                let seized_egld_ray = self.min(remaining_debt_ray.clone(), max_seizable_egld_ray);
+                remaining_debt_ray -= &seized_egld_ray;
+                seized_egld_ray
+            } else if remaining_collateral_value == self.ray_zero() {
+                self.ray_zero()
+            } else {
+                // Calculate proportion in RAY precision
+                let asset_proportion_ray = self.div_half_up(
+                    &asset_egld_value_ray,
+                    &remaining_collateral_value,
+                    RAY_PRECISION,
+                );
 
-            // Calculate seized EGLD amount
-            let seized_egld_ray =
-                self.mul_half_up(&asset_proportion_ray, debt_to_be_repaid_ray, RAY_PRECISION);
+                // Calculate seized EGLD amount
+                self.mul_half_up(&asset_proportion_ray, &remaining_debt_ray, RAY_PRECISION)
+            };
 
             // Apply liquidation bonus
             let seized_egld_with_bonus_ray =
@@ -450,18 +829,23 @@ pub trait PositionLiquidationModule:
             let liquidation_bonus_units_ray =
                 capped_units_with_bonus_ray.clone() - seized_base_units_ray;
 
-            // Protocol fee on the capped bonus portion
+            // Share of the bonus that stays in the liquidated account
+            let rebate_units_ray =
+                self.mul_half_up(&liquidation_bonus_units_ray, &rebate_bps, RAY_PRECISION);
+            let liquidator_bonus_units_ray = liquidation_bonus_units_ray - rebate_units_ray.clone();
+
+            // Protocol fee on the capped bonus portion kept by the liquidator
             let protocol_fee_ray = self.mul_half_up(
-                &liquidation_bonus_units_ray,
+                &liquidator_bonus_units_ray,
                 &position.liquidation_fees_bps,
                 RAY_PRECISION,
             );
             let protocol_fee_scaled =
                 self.rescale_half_up(&protocol_fee_ray, asset_price_feed.asset_decimals);
 
-            // Final seized transfer amount is the capped units
+            // Final seized transfer amount is the capped units minus the liquidatee rebate
             let final_seizure_amount = self.rescale_half_up(
-                &capped_units_with_bonus_ray,
+                &(capped_units_with_bonus_ray - rebate_units_ray),
                 asset_price_feed.asset_decimals,
             );
             let seized_asset = EgldOrEsdtTokenPayment::new(
@@ -475,6 +859,66 @@ pub trait PositionLiquidationModule:
         seized_amounts_by_collateral
     }
 
+    /// Orders collateral positions for seizure, placing the liquidator's preferred assets first.
+    ///
+    /// Preferred tokens are taken in the listed order; tokens the account does not hold as
+    /// collateral and duplicates are ignored. The other positions follow in their existing order.
+    ///
+    /// # Arguments
+    /// - `deposit_positions`: Borrower's collateral positions
+    /// - `preferred_collaterals`: Collateral tokens to seize first, in order
+    /// - `cache`: Mutable storage cache for price feeds
+    ///
+    /// # Returns
+    /// - Ordered positions
+    /// - Number of preferred positions at the front
+    /// - Total EGLD value of the preferred positions (RAY precision)
+    fn order_by_seizure_preference(
+        &self,
+        deposit_positions: &ManagedVec<AccountPosition<Self::Api>>,
+        preferred_collaterals: &ManagedVec<EgldOrEsdtTokenIdentifier>,
+        cache: &mut Cache<Self>,
+    ) -> (
+        ManagedVec<AccountPosition<Self::Api>>,
+        usize,
+        ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        if preferred_collaterals.is_empty() {
+            return (deposit_positions.clone(), 0, self.ray_zero());
+        }
+
+        let mut ordered_positions = ManagedVec::new();
+        let mut preferred_value_ray = self.ray_zero();
+        for token_id in preferred_collaterals {
+            let already_ordered = ordered_positions
+                .iter()
+                .any(|position| position.asset_id == *token_id);
+            if already_ordered {
+                continue;
+            }
+
+            let preferred_position = deposit_positions
+                .iter()
+                .find(|position| position.asset_id == *token_id);
+            if let Some(position) = preferred_position {
+                let price_feed = self.token_price(&position.asset_id, cache);
+                let total_amount_ray = self.total_amount_ray(&position, cache);
+                preferred_value_ray +=
+                    self.token_egld_value_ray(&total_amount_ray, &price_feed.price_wad);
+                ordered_positions.push(position.clone());
+            }
+        }
+
+        let preferred_count = ordered_positions.len();
+        for position in deposit_positions {
+            if !preferred_collaterals.contains(&position.asset_id) {
+                ordered_positions.push(position.clone());
+            }
+        }
+
+        (ordered_positions, preferred_count, preferred_value_ray)
+    }
+
     /// Computes total debt repayment with intelligent excess payment handling and automatic refund generation.
     ///
     /// # Purpose and Scope
@@ -482,14 +926,15 @@ pub trait PositionLiquidationModule:
     /// - Validates each payment against existing borrow positions
     /// - Calculates EGLD-equivalent values for all payments
     /// - Detects and handles excess payments with automatic refunds
-    /// - Ensures liquidators cannot pay more than outstanding debt per asset
+    /// - Ensures liquidators cannot pay more than the repayable debt per asset
     /// - Aggregates total repayment value for liquidation calculations
     ///
     /// # How It Works (Payment Processing Methodology)
     /// 1. **Payment Validation**: Checks each payment against corresponding borrow position
     /// 2. **Price Conversion**: Converts token amounts to EGLD-equivalent using oracle prices
-    /// 3. **Debt Comparison**: Compares payment amount against outstanding debt for each asset
-    /// 4. **Excess Detection**: Identifies payments exceeding outstanding debt amounts
+    /// 3. **Close Factor**: Caps the repayable debt of each asset at its
+    ///    `max_liquidation_close_factor_bps` share of the outstanding debt
+    /// 4. **Excess Detection**: Identifies payments exceeding the repayable debt amounts
     /// 5. **Refund Generation**: Creates refund payments for excess amounts
     /// 6. **Aggregation**: Sums all valid payments to total EGLD repayment value
     ///
@@ -497,10 +942,10 @@ pub trait PositionLiquidationModule:
     /// For each payment token `i`:
     /// ```
     /// payment_egld_value_i = payment_amount_i * price_i
-    /// outstanding_debt_egld_i = borrowed_amount_i * price_i
+    /// repayable_debt_egld_i = borrowed_amount_i * close_factor_i * price_i
     ///
-    /// if payment_egld_value_i > outstanding_debt_egld_i:
-    ///     excess_egld_i = payment_egld_value_i - outstanding_debt_egld_i
+    /// if payment_egld_value_i > repayable_debt_egld_i:
+    ///     excess_egld_i = payment_egld_value_i - repayable_debt_egld_i
     ///     excess_tokens_i = excess_egld_i / price_i
     ///     refund_i = excess_tokens_i
     ///     valid_payment_i = payment_amount_i - excess_tokens_i
@@ -518,7 +963,7 @@ pub trait PositionLiquidationModule:
     /// - Zero-amount payment filtering
     ///
     /// # Excess Payment Handling Logic
-    /// When a liquidator pays more than the outstanding debt for a specific asset:
+    /// When a liquidator pays more than the repayable debt for a specific asset:
     /// 1. Calculate excess amount in EGLD terms
     /// 2. Convert excess back to original token amount using current price
     /// 3. Reduce the payment amount by the excess
@@ -583,11 +1028,14 @@ pub trait PositionLiquidationModule:
                 self.token_egld_value_ray(&payment_amount_decimal, &token_price_feed.price_wad);
 
             let outstanding_debt_ray = self.total_amount_ray(&original_borrow_position, cache);
-            let outstanding_debt_egld_ray =
-                self.token_egld_value_ray(&outstanding_debt_ray, &token_price_feed.price_wad);
+            // A single liquidation may only repay the close factor share of the debt
+            let repayable_debt_ray = self
+                .close_factor_repayable_debt(&payment_ref.token_identifier, outstanding_debt_ray);
+            let repayable_debt_egld_ray =
+                self.token_egld_value_ray(&repayable_debt_ray, &token_price_feed.price_wad);
             let mut adjusted_payment = payment_ref.clone();
-            if payment_egld_value_ray > outstanding_debt_egld_ray {
-                let excess_egld_ray = payment_egld_value_ray - outstanding_debt_egld_ray.clone();
+            if payment_egld_value_ray > repayable_debt_egld_ray {
+                let excess_egld_ray = payment_egld_value_ray - repayable_debt_egld_ray.clone();
                 let excess_token_amount_decimal =
                     self.convert_egld_to_tokens(&excess_egld_ray, &token_price_feed);
                 let excess_token_units = excess_token_amount_decimal.as_raw_units().clone();
@@ -603,15 +1051,9 @@ pub trait PositionLiquidationModule:
                     ));
                 }
 
-                total_repaid += &outstanding_debt_egld_ray;
-                repaid_tokens.push(
-                    (
-                        adjusted_payment,
-                        outstanding_debt_egld_ray,
-                        token_price_feed,
-                    )
-                        .into(),
-                );
+                total_repaid += &repayable_debt_egld_ray;
+                repaid_tokens
+                    .push((adjusted_payment, repayable_debt_egld_ray, token_price_feed).into());
             } else {
                 total_repaid += &payment_egld_value_ray;
                 repaid_tokens
@@ -622,6 +1064,33 @@ pub trait PositionLiquidationModule:
         (total_repaid, repaid_tokens)
     }
 
+    /// Caps the outstanding debt of an asset at the share a single liquidation may repay.
+    ///
+    /// # Arguments
+    /// - `asset_id`: Debt asset identifier
+    /// - `outstanding_debt_ray`: Outstanding debt of the position (RAY precision)
+    ///
+    /// # Returns
+    /// - Repayable debt in token units (RAY precision); the full debt when no
+    ///   `max_liquidation_close_factor_bps` is configured
+    fn close_factor_repayable_debt(
+        &self,
+        asset_id: &EgldOrEsdtTokenIdentifier,
+        outstanding_debt_ray: ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let close_factor_mapper = self.max_liquidation_close_factor_bps(asset_id);
+        let close_factor_bps = if close_factor_mapper.is_empty() {
+            self.bps()
+        } else {
+            self.to_decimal_bps(close_factor_mapper.get())
+        };
+        if close_factor_bps < self.bps() {
+            self.mul_half_up(&outstanding_debt_ray, &close_factor_bps, RAY_PRECISION)
+        } else {
+            outstanding_debt_ray
+        }
+    }
+
     /// Calculates weighted liquidation parameters by aggregating asset-specific thresholds and bonuses.
     ///
     /// # Purpose and Scope
@@ -634,7 +1103,8 @@ pub trait PositionLiquidationModule:
     /// # How It Works (Weighted Aggregation Methodology)
     /// 1. **Asset Valuation**: Calculate EGLD-equivalent value for each collateral position
     /// 2. **Weight Calculation**: Determine each asset's proportion of total collateral value
-    /// 3. **Threshold Weighting**: Apply asset-specific liquidation thresholds weighted by value
+    /// 3. **Threshold Weighting**: Apply asset-specific liquidation thresholds weighted by value,
+    ///    discounted by each asset's liquidation haircut
     /// 4. **Bonus Weighting**: Apply asset-specific liquidation bonuses weighted by value
     /// 5. **Aggregation**: Sum weighted values to produce portfolio-level parameters
     /// 6. **Precision Scaling**: Convert results to basis points precision for downstream use
@@ -643,7 +1113,7 @@ pub trait PositionLiquidationModule:
     /// For each collateral asset `i`:
     /// ```
     /// weight_i = asset_value_i / total_collateral_value
-    /// weighted_threshold_i = weight_i * liquidation_threshold_i
+    /// weighted_threshold_i = weight_i * (1 - liquidation_haircut_i) * liquidation_threshold_i
     /// weighted_bonus_i = weight_i * liquidation_bonus_i
     /// ```
     ///
@@ -704,9 +1174,11 @@ pub trait PositionLiquidationModule:
                 total_collateral_in_egld,
                 RAY_PRECISION,
             );
+            let liquidation_weight_ray =
+                self.apply_liquidation_haircut(&deposit_position.asset_id, &portfolio_weight_ray);
 
             proportion_seized += self.mul_half_up(
-                &portfolio_weight_ray,
+                &liquidation_weight_ray,
                 &deposit_position.liquidation_threshold_bps,
                 RAY_PRECISION,
             );
@@ -1164,6 +1636,8 @@ pub trait PositionLiquidationModule:
     /// 4. **Pool Integration**: Liquidity pools update their bad debt accounting and collateral reserves
     /// 5. **Position Cleanup**: All position mappings are cleared from storage
     /// 6. **Account Closure**: Account NFT and attributes are completely removed
+    /// 7. **Reporting**: A `bad_debt_cleaned` event lists the written-off debt and seized
+    ///    collateral per token, together with the total USD value written off
     ///
     /// # Security Checks Implemented
     /// - Caller address validation for event emission
@@ -1184,6 +1658,7 @@ pub trait PositionLiquidationModule:
     /// - Transfers collateral ownership to the liquidity pool
     /// - Adds seized assets to pool's reserve fund
     /// - Helps offset bad debt losses through collateral recovery
+    /// - Skips supply-only deposits, which keep the account alive for their owner
     ///
     /// # Bad Debt Accounting Flow
     /// ```
@@ -1191,11 +1666,12 @@ pub trait PositionLiquidationModule:
     ///   pool.add_bad_debt(position, current_price)
     ///   emit_position_update_event(zero_position, updated_position)
     ///
-    /// For each deposit position:
+    /// For each collateral deposit position:
     ///   pool.seize_dust_collateral(position, current_price)
     ///   emit_position_update_event(zero_position, updated_position)
     ///
-    /// Clear all position mappings and account data
+    /// Clear seized position mappings, and account data once no deposit remains
+    /// emit_bad_debt_cleaned_event(write_offs, total_debt_usd)
     /// ```
     ///
     /// # Isolated Debt Special Handling
@@ -1216,15 +1692,28 @@ pub trait PositionLiquidationModule:
     fn perform_bad_debt_cleanup(&self, account_nonce: u64, cache: &mut Cache<Self>) {
         let caller = self.blockchain().get_caller();
         let account_attributes = self.account_attributes(account_nonce).get();
+        let mut write_offs = ManagedVec::<Self::Api, BadDebtWriteOff<Self::Api>>::new();
+        let mut token_index_mapper =
+            ManagedMapEncoded::<Self::Api, EgldOrEsdtTokenIdentifier, usize>::new();
+        let mut total_debt_egld = self.ray_zero();
 
         // Add all remaining debt as bad debt, clean isolated debt if any
         let borrow_positions = self.positions(account_nonce, AccountPositionType::Borrow);
         for (token_id, mut position) in borrow_positions.iter() {
             let feed = self.token_price(&token_id, cache);
             let pool_address = cache.cached_pool_address(&token_id);
+            let debt_ray = self.total_amount_ray(&position, cache);
+            total_debt_egld += self.token_egld_value_ray(&debt_ray, &feed.price_wad);
+            token_index_mapper.put(&token_id, &(write_offs.len() + 1));
+            write_offs.push(BadDebtWriteOff {
+                token_id: token_id.clone(),
+                debt_amount_wad: self.rescale_half_up(&debt_ray, WAD_PRECISION),
+                collateral_seized_wad: self.wad_zero(),
+            });
             if account_attributes.is_isolated() {
                 self.clear_position_isolated_debt(&mut position, &feed, &account_attributes, cache);
             }
+            self.clear_position_e_mode_debt(&position, &feed, &account_attributes, cache);
 
             // Call the add_bad_debt function on the liquidity pool
             let updated_position = self
@@ -1245,11 +1734,36 @@ pub trait PositionLiquidationModule:
             );
         }
 
-        // Seize all remaining collateral + interest
-        let deposit_positions = self.positions(account_nonce, AccountPositionType::Deposit);
+        // Seize all remaining collateral + interest, supply-only deposits stay with the account
+        let mut deposit_positions = self.positions(account_nonce, AccountPositionType::Deposit);
+        let mut seized_tokens = ManagedVec::<Self::Api, EgldOrEsdtTokenIdentifier>::new();
         for (token_id, position) in deposit_positions.iter() {
+            if self
+                .position_collateral_disabled(account_nonce, &token_id)
+                .get()
+            {
+                continue;
+            }
+
+            seized_tokens.push(token_id.clone());
+            self.supply_timestamp(account_nonce, &token_id).clear();
+            self.supply_reward_index(account_nonce, &token_id).clear();
             let feed = self.token_price(&token_id, cache);
             let pool_address = cache.cached_pool_address(&token_id);
+            let collateral_wad =
+                self.rescale_half_up(&self.total_amount_ray(&position, cache), WAD_PRECISION);
+            if token_index_mapper.contains(&token_id) {
+                let index = token_index_mapper.get(&token_id) - 1;
+                let mut existing = write_offs.get(index).clone();
+                existing.collateral_seized_wad = collateral_wad;
+                let _ = write_offs.set(index, existing);
+            } else {
+                write_offs.push(BadDebtWriteOff {
+                    token_id: token_id.clone(),
+                    debt_amount_wad: self.wad_zero(),
+                    collateral_seized_wad: collateral_wad,
+                });
+            }
             // Call the seize_dust_collateral function on the liquidity pool
             let updated_position = self
                 .tx()
@@ -1271,9 +1785,21 @@ pub trait PositionLiquidationModule:
 
         self.positions(account_nonce, AccountPositionType::Borrow)
             .clear();
-        self.positions(account_nonce, AccountPositionType::Deposit)
-            .clear();
-        self.accounts().swap_remove(&account_nonce);
-        self.account_attributes(account_nonce).clear();
+        for token_id in seized_tokens.iter() {
+            deposit_positions.remove(&token_id);
+        }
+        if deposit_positions.is_empty() && self.dust_collateral(account_nonce).is_empty() {
+            self.accounts().swap_remove(&account_nonce);
+            self.account_attributes(account_nonce).clear();
+            self.auto_compound(account_nonce).clear();
+            self.clear_account_holder(account_nonce);
+            self.account_borrow_allowance(account_nonce).clear();
+            self.last_liquidation_timestamp(account_nonce).clear();
+            self.liquidation_grace_until(account_nonce).clear();
+        }
+
+        let total_written_off_usd =
+            self.egld_usd_value(&total_debt_egld, &cache.egld_usd_price_wad);
+        self.bad_debt_cleaned_event(account_nonce, &write_offs, &total_written_off_usd);
     }
 }
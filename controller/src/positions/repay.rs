@@ -1,9 +1,12 @@
 use common_constants::WAD_PRECISION;
-use common_structs::{AccountAttributes, AccountPosition, AccountPositionType, PriceFeedShort};
+use common_errors::ERROR_INSUFFICIENT_DEPOSIT;
+use common_structs::{
+    AccountAttributes, AccountPosition, AccountPositionType, MarketActivityType, PriceFeedShort,
+};
 
 use crate::{cache::Cache, helpers, oracle, proxy_pool, storage, utils, validation};
 
-use super::{account, borrow, emode, update};
+use super::{account, borrow, emode, update, withdraw};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -22,6 +25,7 @@ pub trait PositionRepayModule:
     + common_math::SharedMathModule
     + common_rates::InterestRates
     + emode::EModeModule
+    + withdraw::PositionWithdrawModule
 {
     /// Updates isolated debt tracking post-repayment.
     ///
@@ -76,6 +80,58 @@ pub trait PositionRepayModule:
         }
     }
 
+    /// Updates the e-mode debt aggregate post-repayment.
+    ///
+    /// **Purpose**: Reduces the EGLD debt tracked for the account's e-mode category
+    /// (category 0 without e-mode) by the portion of the repayment that actually
+    /// reduces this borrow, so overpayments refunded to the caller are not counted.
+    ///
+    /// # Arguments
+    /// - `position`: Borrow position before the repayment
+    /// - `repay_amount_egld`: Repayment amount in EGLD denomination
+    /// - `feed`: Price feed for debt valuation
+    /// - `cache`: Storage cache for index access
+    /// - `position_attributes`: Position attributes containing the e-mode category
+    fn update_e_mode_debt_after_repayment(
+        &self,
+        position: &AccountPosition<Self::Api>,
+        repay_amount_egld: &ManagedDecimal<Self::Api, NumDecimals>,
+        feed: &PriceFeedShort<Self::Api>,
+        cache: &mut Cache<Self>,
+        position_attributes: &AccountAttributes<Self::Api>,
+    ) {
+        let current_debt_ray = self.total_amount_ray(position, cache);
+        let current_debt_egld_wad = self.rescale_half_up(
+            &self.token_egld_value_ray(&current_debt_ray, &feed.price_wad),
+            WAD_PRECISION,
+        );
+        let applied_egld_wad = self.min(current_debt_egld_wad, repay_amount_egld.clone());
+
+        self.adjust_e_mode_debt_egld(position_attributes.emode_id(), applied_egld_wad, false);
+    }
+
+    /// Removes the full debt of a written-off borrow position from its e-mode debt aggregate.
+    ///
+    /// # Arguments
+    /// - `position`: Borrow position being cleared
+    /// - `feed`: Price feed for debt valuation
+    /// - `position_attributes`: Position attributes containing the e-mode category
+    /// - `cache`: Storage cache for index access
+    fn clear_position_e_mode_debt(
+        &self,
+        position: &AccountPosition<Self::Api>,
+        feed: &PriceFeedShort<Self::Api>,
+        position_attributes: &AccountAttributes<Self::Api>,
+        cache: &mut Cache<Self>,
+    ) {
+        let amount = self.total_amount_ray(position, cache);
+        let egld_amount = self.rescale_half_up(
+            &self.token_egld_value_ray(&amount, &feed.price_wad),
+            WAD_PRECISION,
+        );
+        self.adjust_e_mode_debt_egld(position_attributes.emode_id(), egld_amount, false);
+    }
+
     /// Clears all isolated debt for a position being fully repaid.
     ///
     /// **Purpose**: Removes all tracked isolated debt when a borrow position
@@ -149,6 +205,10 @@ pub trait PositionRepayModule:
     /// new_debt = total_debt - repayment_applied
     /// ```
     ///
+    /// **Application Order**: The repayment reduces the single scaled debt balance; the
+    /// position entry then attributes it to accrued interest first, or to principal first
+    /// when the market is configured with `setRepayPrincipalFirst`.
+    ///
     /// # Arguments
     /// - `account_nonce`: Position NFT nonce for storage operations
     /// - `token_id`: Token identifier being repaid
@@ -182,6 +242,13 @@ pub trait PositionRepayModule:
             cache,
             position_attributes,
         );
+        self.update_e_mode_debt_after_repayment(
+            &borrow_position,
+            &repay_amount_in_egld,
+            feed,
+            cache,
+            position_attributes,
+        );
 
         let pool_address = cache.cached_pool_address(token_id);
 
@@ -202,10 +269,206 @@ pub trait PositionRepayModule:
             caller,
             position_attributes,
         );
+        self.record_market_activity(
+            token_id,
+            MarketActivityType::Repay,
+            &actual_repayment_amount,
+            cache,
+        );
+
+        let borrow_position =
+            self.absorb_dust_debt(borrow_position, feed, caller, cache, position_attributes);
+        self.update_or_remove_position(account_nonce, &borrow_position);
+    }
+
+    /// Repays a borrow with the account's deposit of the same token.
+    ///
+    /// **Purpose**: Nets opposite positions held in the same market. The liquidity pool
+    /// burns the deposit and the debt in a single call, saving the withdrawal transfer
+    /// and the repayment round-trip.
+    ///
+    /// **Methodology**:
+    /// 1. Validates both positions exist
+    /// 2. Forfeits rewards of a deposit still within its minimum supply period
+    /// 3. Caps the amount at the outstanding debt and requires the deposit to cover it
+    /// 4. Updates isolated and e-mode debt tracking for the netted amount
+    /// 5. Nets both positions in the liquidity pool and emits an event for each
+    /// 6. Updates or removes both positions
+    ///
+    /// Health factor validation is left to the caller.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: Position NFT nonce for storage operations
+    /// - `token_id`: Token identifier of both the deposit and the borrow
+    /// - `amount`: Amount to repay in asset decimals
+    /// - `caller`: Account owner for event emission
+    /// - `feed`: Price feed for valuation
+    /// - `cache`: Storage cache for pool address and index access
+    /// - `position_attributes`: Position attributes for isolation and e-mode handling
+    ///
+    /// # Errors
+    /// - `ERROR_INSUFFICIENT_DEPOSIT`: If the deposit is lower than the netted amount
+    fn process_self_repayment(
+        &self,
+        account_nonce: u64,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        caller: &ManagedAddress,
+        feed: &PriceFeedShort<Self::Api>,
+        cache: &mut Cache<Self>,
+        position_attributes: &AccountAttributes<Self::Api>,
+    ) {
+        let borrow_position = self.validate_borrow_position_existence(account_nonce, token_id);
+        let mut deposit_position = self.deposit_position(account_nonce, token_id);
+
+        self.forfeit_early_supply_rewards(
+            account_nonce,
+            caller,
+            position_attributes,
+            &mut deposit_position,
+            feed,
+            cache,
+        );
+
+        let total_debt = self.total_amount(&borrow_position, feed, cache);
+        let netted_amount = self.min(amount.clone(), total_debt);
+        require!(
+            self.total_amount(&deposit_position, feed, cache) >= netted_amount,
+            ERROR_INSUFFICIENT_DEPOSIT
+        );
+
+        let netted_egld = self.token_egld_value(&netted_amount, &feed.price_wad);
+        self.update_isolated_debt_after_repayment(
+            &borrow_position,
+            &netted_egld,
+            feed,
+            cache,
+            position_attributes,
+        );
+        self.update_e_mode_debt_after_repayment(
+            &borrow_position,
+            &netted_egld,
+            feed,
+            cache,
+            position_attributes,
+        );
 
+        let pool_address = cache.cached_pool_address(token_id);
+        let (deposit_position, borrow_position) = self
+            .tx()
+            .to(pool_address)
+            .typed(proxy_pool::LiquidityPoolProxy)
+            .net_positions(
+                netted_amount.clone(),
+                deposit_position,
+                borrow_position,
+                feed.price_wad.clone(),
+            )
+            .returns(ReturnsResult)
+            .sync_call()
+            .into_tuple();
+
+        for (position, activity) in [
+            (&deposit_position, MarketActivityType::Withdraw),
+            (&borrow_position, MarketActivityType::Repay),
+        ] {
+            self.emit_position_update_event(
+                cache,
+                &netted_amount,
+                position,
+                feed.price_wad.clone(),
+                caller,
+                position_attributes,
+            );
+            self.record_market_activity(token_id, activity, &netted_amount, cache);
+        }
+
+        self.update_or_remove_position(account_nonce, &deposit_position);
+        if deposit_position.can_remove() {
+            self.supply_timestamp(account_nonce, token_id).clear();
+            self.supply_reward_index(account_nonce, token_id).clear();
+            self.position_collateral_disabled(account_nonce, token_id)
+                .clear();
+        }
+
+        let borrow_position =
+            self.absorb_dust_debt(borrow_position, feed, caller, cache, position_attributes);
         self.update_or_remove_position(account_nonce, &borrow_position);
     }
 
+    /// Writes off a debt remainder worth less than the market's dust threshold.
+    ///
+    /// **Purpose**: Near-full repayments can leave a few wei of debt that block the
+    /// account from closing. Such remainders are absorbed by the market so the borrow
+    /// position is removed by the same repayment.
+    ///
+    /// **Methodology**:
+    /// 1. Skips fully repaid positions and markets without a dust threshold
+    /// 2. Values the remaining debt in EGLD and compares it to `dust_threshold_wad`
+    /// 3. Clears the remainder from isolated debt tracking if applicable
+    /// 4. Seizes the remainder in the pool, spreading it over the market like bad debt
+    /// 5. Emits a position update event with a zero amount
+    ///
+    /// # Arguments
+    /// - `borrow_position`: Borrow position after the repayment
+    /// - `feed`: Price feed for debt valuation
+    /// - `caller`: Repayer's address for event emission
+    /// - `cache`: Storage cache for index and pool address access
+    /// - `position_attributes`: Position attributes for isolation handling
+    ///
+    /// # Returns
+    /// - The borrow position, emptied when the remainder was absorbed
+    fn absorb_dust_debt(
+        &self,
+        mut borrow_position: AccountPosition<Self::Api>,
+        feed: &PriceFeedShort<Self::Api>,
+        caller: &ManagedAddress,
+        cache: &mut Cache<Self>,
+        position_attributes: &AccountAttributes<Self::Api>,
+    ) -> AccountPosition<Self::Api> {
+        if borrow_position.can_remove() {
+            return borrow_position;
+        }
+
+        let dust_threshold_wad =
+            self.to_decimal_wad(self.dust_threshold_wad(&borrow_position.asset_id).get());
+        if dust_threshold_wad == self.wad_zero() {
+            return borrow_position;
+        }
+
+        let remaining_ray = self.total_amount_ray(&borrow_position, cache);
+        let remaining_egld_wad = self.rescale_half_up(
+            &self.token_egld_value_ray(&remaining_ray, &feed.price_wad),
+            WAD_PRECISION,
+        );
+        if remaining_egld_wad >= dust_threshold_wad {
+            return borrow_position;
+        }
+
+        self.clear_position_isolated_debt(&mut borrow_position, feed, position_attributes, cache);
+        self.clear_position_e_mode_debt(&borrow_position, feed, position_attributes, cache);
+
+        let pool_address = cache.cached_pool_address(&borrow_position.asset_id);
+        let updated_position = self
+            .tx()
+            .to(pool_address)
+            .typed(proxy_pool::LiquidityPoolProxy)
+            .seize_position(borrow_position.clone(), feed.price_wad.clone())
+            .returns(ReturnsResult)
+            .sync_call();
+
+        self.emit_position_update_event(
+            cache,
+            &borrow_position.zero_decimal(),
+            &updated_position,
+            feed.price_wad.clone(),
+            caller,
+            position_attributes,
+        );
+
+        updated_position
+    }
+
     /// Ensures a borrow position exists for repayment.
     ///
     /// **Purpose**: Validates that a borrow position exists for the specified token
@@ -1,10 +1,15 @@
 use crate::{cache::Cache, helpers, oracle, proxy_pool, storage, utils, validation};
+use common_constants::{RAY_PRECISION, WAD_PRECISION};
 use common_errors::{
-    ERROR_ACCOUNT_ATTRIBUTES_MISMATCH, ERROR_ASSET_NOT_SUPPORTED_AS_COLLATERAL,
-    ERROR_INVALID_NUMBER_OF_ESDT_TRANSFERS, ERROR_MIX_ISOLATED_COLLATERAL, ERROR_SUPPLY_CAP,
+    ERROR_ACCOUNT_ATTRIBUTES_MISMATCH, ERROR_ASSET_NOT_BORROWABLE,
+    ERROR_ASSET_NOT_SUPPORTED_AS_COLLATERAL, ERROR_CANNOT_USE_EMODE_WITH_ISOLATED_ASSETS,
+    ERROR_EMODE_CATEGORY_UNCHANGED, ERROR_HEALTH_FACTOR_DISABLE_COLLATERAL,
+    ERROR_INVALID_NUMBER_OF_ESDT_TRANSFERS, ERROR_MAX_POSITION_VALUE_EXCEEDED,
+    ERROR_MIX_ISOLATED_COLLATERAL, ERROR_POSITION_NOT_FOUND, ERROR_SUPPLY_CAP,
 };
 use common_structs::{
-    AccountAttributes, AccountPosition, AccountPositionType, AssetConfig, PriceFeedShort,
+    AccountAttributes, AccountPosition, AccountPositionType, AssetConfig, MarketActivityType,
+    PriceFeedShort,
 };
 
 use super::{account, emode, update};
@@ -27,8 +32,8 @@ pub trait PositionDepositModule:
     + common_rates::InterestRates
 {
     /// Orchestrates deposit flow with e-mode validation, isolation constraints, and position updates.
-    /// Validates each payment, checks supply caps, and calls liquidity pool for position scaling.
-    /// Ensures compliance with risk parameters and market limits.
+    /// Validates each payment and market pause, checks supply caps, and calls liquidity pool for position scaling.
+    /// Ensures compliance with risk parameters, market limits and the maximum position value.
     fn process_deposit(
         &self,
         caller: &ManagedAddress,
@@ -49,6 +54,8 @@ pub trait PositionDepositModule:
 
         for deposit_payment in deposit_payments {
             self.validate_payment(&deposit_payment);
+            self.require_market_not_paused(&deposit_payment.token_identifier);
+            self.require_new_supply_enabled(&deposit_payment.token_identifier);
 
             let mut asset_info = cache.cached_asset_info(&deposit_payment.token_identifier);
             let asset_emode_config = self.token_e_mode_config(
@@ -82,6 +89,38 @@ pub trait PositionDepositModule:
                 cache,
             );
         }
+
+        self.validate_deposit_position_value(account_nonce, cache);
+    }
+
+    /// Validates the account's total collateral against the protocol-wide maximum position value.
+    /// Runs after all deposits of the transaction have been applied. Protocol-owned accounts
+    /// are exempt from the cap.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: Position NFT nonce
+    /// - `cache`: Storage cache for price feeds and market indexes
+    ///
+    /// # Errors
+    /// - `ERROR_MAX_POSITION_VALUE_EXCEEDED`: If total collateral exceeds the cap
+    fn validate_deposit_position_value(&self, account_nonce: u64, cache: &mut Cache<Self>) {
+        let max_value_mapper = self.max_position_value();
+        if max_value_mapper.is_empty() || self.protocol_owned_accounts().contains(&account_nonce) {
+            return;
+        }
+
+        let max_value =
+            self.rescale_half_up(&self.to_decimal_wad(max_value_mapper.get()), RAY_PRECISION);
+        let deposit_positions = self
+            .positions(account_nonce, AccountPositionType::Deposit)
+            .values()
+            .collect();
+        let (_, total_collateral, _) = self.calculate_collateral_values(&deposit_positions, cache);
+
+        require!(
+            total_collateral <= max_value,
+            ERROR_MAX_POSITION_VALUE_EXCEEDED
+        );
     }
 
     /// Retrieves or creates a deposit position for a token.
@@ -186,6 +225,7 @@ pub trait PositionDepositModule:
         }
 
         let amount_decimal = position.make_amount_decimal(&collateral.amount, feed.asset_decimals);
+        let previous_scaled_ray = position.scaled_amount_ray.clone();
 
         self.update_market_position(
             &mut position,
@@ -194,6 +234,7 @@ pub trait PositionDepositModule:
             feed,
             cache,
         );
+        self.start_min_supply_period(account_nonce, &position, &previous_scaled_ray);
 
         self.emit_position_update_event(
             cache,
@@ -203,6 +244,12 @@ pub trait PositionDepositModule:
             caller,
             attributes,
         );
+        self.record_market_activity(
+            &position.asset_id,
+            MarketActivityType::Supply,
+            &amount_decimal,
+            cache,
+        );
 
         // Update storage with the latest position
         self.store_updated_position(account_nonce, &position);
@@ -210,6 +257,63 @@ pub trait PositionDepositModule:
         position
     }
 
+    /// Starts or extends the minimum supply period of a deposit.
+    ///
+    /// **Purpose**: Tracks when a deposit was last topped up so that an early withdrawal
+    /// can forfeit the rewards accrued since its minimum supply period began.
+    ///
+    /// **Methodology**:
+    /// - Skips markets without a minimum supply duration
+    /// - Starts a new period when none is running, snapshotting the market reward index
+    /// - A supply during a running period restarts its clock and weights the snapshot by
+    ///   scaled amount, so rewards accrued by the previous principal remain subject to
+    ///   forfeiture while the added principal is only charged from now on
+    ///
+    /// **Mathematical Operations**:
+    /// ```
+    /// snapshot' = (previous_scaled * snapshot + added_scaled * reward_index) / new_scaled
+    /// ```
+    ///
+    /// # Arguments
+    /// - `account_nonce`: Position NFT nonce of the deposit
+    /// - `position`: Deposit position after the supply
+    /// - `previous_scaled_ray`: Scaled amount of the deposit before the supply (RAY)
+    fn start_min_supply_period(
+        &self,
+        account_nonce: u64,
+        position: &AccountPosition<Self::Api>,
+        previous_scaled_ray: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        let asset = &position.asset_id;
+        let min_duration_mapper = self.min_supply_duration(asset);
+        if min_duration_mapper.is_empty() {
+            return;
+        }
+
+        let now = self.blockchain().get_block_timestamp_seconds();
+        let timestamp_mapper = self.supply_timestamp(account_nonce, asset);
+        let snapshot_mapper = self.supply_reward_index(account_nonce, asset);
+        let reward_index = self.current_market_reward_index(asset);
+        let period_running = !timestamp_mapper.is_empty()
+            && !snapshot_mapper.is_empty()
+            && now - timestamp_mapper.get() < min_duration_mapper.get();
+        if period_running {
+            let added_scaled = position.scaled_amount_ray.clone() - previous_scaled_ray.clone();
+            let weighted =
+                self.mul_half_up(previous_scaled_ray, &snapshot_mapper.get(), RAY_PRECISION)
+                    + self.mul_half_up(&added_scaled, &reward_index, RAY_PRECISION);
+            snapshot_mapper.set(self.div_half_up(
+                &weighted,
+                &position.scaled_amount_ray,
+                RAY_PRECISION,
+            ));
+        } else {
+            snapshot_mapper.set(reward_index);
+        }
+
+        timestamp_mapper.set(now);
+    }
+
     /// Updates a market position via the liquidity pool.
     ///
     /// **Purpose**: Executes cross-contract call to liquidity pool for position updates,
@@ -315,6 +419,8 @@ pub trait PositionDepositModule:
                 ERROR_ACCOUNT_ATTRIBUTES_MISMATCH
             );
 
+            self.set_account_holder(account_payment.token_nonce, &caller);
+
             if return_nft {
                 // Refund NFT
                 self.tx().to(&caller).payment(&account_payment).transfer();
@@ -481,7 +587,8 @@ pub trait PositionDepositModule:
     /// 3. Applies e-mode parameters to asset configuration
     /// 4. Updates either LTV parameters (safe) or liquidation thresholds (risky)
     /// 5. For risky updates: validates health factor remains above minimum
-    /// 6. Emits position update event for monitoring
+    /// 6. When the liquidation threshold is lowered, starts the account's liquidation grace
+    /// 7. Emits position update event for monitoring
     ///
     /// **Security Considerations**:
     /// - Health factor validation prevents immediate liquidations
@@ -525,6 +632,9 @@ pub trait PositionDepositModule:
 
         if has_risks {
             if dp.liquidation_threshold_bps != asset_config.liquidation_threshold_bps {
+                if asset_config.liquidation_threshold_bps < dp.liquidation_threshold_bps {
+                    self.start_liquidation_grace(account_nonce);
+                }
                 dp.liquidation_threshold_bps = asset_config.liquidation_threshold_bps.clone();
             }
         } else {
@@ -560,4 +670,191 @@ pub trait PositionDepositModule:
             &account_attributes,
         );
     }
+
+    /// Protects an account from liquidation for `threshold_update_grace` seconds.
+    /// Does nothing when no grace period is configured.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: Position NFT nonce whose liquidation threshold was lowered
+    fn start_liquidation_grace(&self, account_nonce: u64) {
+        let grace_mapper = self.threshold_update_grace();
+        if grace_mapper.is_empty() {
+            return;
+        }
+
+        self.liquidation_grace_until(account_nonce)
+            .set(self.blockchain().get_block_timestamp_seconds() + grace_mapper.get());
+    }
+
+    /// Moves an account with all its positions to another e-mode category.
+    ///
+    /// **Purpose**: Re-applies the risk parameters of the new category to every open
+    /// position so the account is valued as if it had been opened in that category.
+    ///
+    /// **Methodology**:
+    /// 1. Validates the category changes, is compatible with isolation and is not deprecated
+    /// 2. Requires every deposit to be suppliable and every borrow to be borrowable in it
+    /// 3. Refreshes LTV, liquidation threshold, bonus and fees on each position
+    /// 4. Moves the EGLD value of the outstanding debt between the e-mode debt aggregates
+    /// 5. Updates the account NFT and stored attributes with the new category
+    ///
+    /// **Security Considerations**:
+    /// - The account NFT must be held by the contract to update its attributes
+    /// - Health factor is not validated here; the caller validates it once all
+    ///   repayments and deposits of the transaction are processed
+    ///
+    /// # Arguments
+    /// - `account`: Account NFT payment held by the contract
+    /// - `account_attributes`: Current attributes of the account
+    /// - `new_category`: E-mode category to switch to (0 = disable e-mode)
+    /// - `caller`: Account owner for event emission
+    /// - `cache`: Storage cache for asset configs, prices and indexes
+    ///
+    /// # Returns
+    /// - `AccountAttributes` with the new e-mode category
+    fn roll_account_e_mode(
+        &self,
+        account: &EsdtTokenPayment<Self::Api>,
+        account_attributes: &AccountAttributes<Self::Api>,
+        new_category: u8,
+        caller: &ManagedAddress,
+        cache: &mut Cache<Self>,
+    ) -> AccountAttributes<Self::Api> {
+        let previous_category = account_attributes.emode_id();
+        require!(
+            new_category != previous_category,
+            ERROR_EMODE_CATEGORY_UNCHANGED
+        );
+        require!(
+            !(account_attributes.is_isolated() && new_category != 0),
+            ERROR_CANNOT_USE_EMODE_WITH_ISOLATED_ASSETS
+        );
+
+        let e_mode = self.e_mode_category(new_category);
+        self.ensure_e_mode_not_deprecated(&e_mode);
+
+        let new_attributes = AccountAttributes {
+            e_mode_category_id: new_category,
+            ..account_attributes.clone()
+        };
+
+        for position_type in [AccountPositionType::Deposit, AccountPositionType::Borrow] {
+            for mut position in self.positions(account.token_nonce, position_type).values() {
+                let mut asset_config = cache.cached_asset_info(&position.asset_id);
+                self.ensure_e_mode_compatible_with_asset(&asset_config, new_category);
+                let asset_emode_config = self.token_e_mode_config(new_category, &position.asset_id);
+                self.apply_e_mode_to_asset_config(&mut asset_config, &e_mode, asset_emode_config);
+
+                let feed = self.token_price(&position.asset_id, cache);
+                if position.position_type == AccountPositionType::Deposit {
+                    require!(
+                        asset_config.can_supply(),
+                        ERROR_ASSET_NOT_SUPPORTED_AS_COLLATERAL
+                    );
+                } else {
+                    require!(asset_config.can_borrow(), ERROR_ASSET_NOT_BORROWABLE);
+
+                    let debt_egld_wad = self.rescale_half_up(
+                        &self.token_egld_value_ray(
+                            &self.total_amount_ray(&position, cache),
+                            &feed.price_wad,
+                        ),
+                        WAD_PRECISION,
+                    );
+                    self.adjust_e_mode_debt_egld(previous_category, debt_egld_wad.clone(), false);
+                    self.adjust_e_mode_debt_egld(new_category, debt_egld_wad, true);
+                }
+
+                position.loan_to_value_bps = asset_config.loan_to_value_bps.clone();
+                position.liquidation_threshold_bps = asset_config.liquidation_threshold_bps.clone();
+                position.liquidation_bonus_bps = asset_config.liquidation_bonus_bps.clone();
+                position.liquidation_fees_bps = asset_config.liquidation_fees_bps.clone();
+                self.store_updated_position(account.token_nonce, &position);
+
+                self.emit_position_update_event(
+                    cache,
+                    &position.zero_decimal(),
+                    &position,
+                    feed.price_wad.clone(),
+                    caller,
+                    &new_attributes,
+                );
+            }
+        }
+
+        self.send().nft_update_attributes(
+            &account.token_identifier,
+            account.token_nonce,
+            &new_attributes,
+        );
+        self.account_attributes(account.token_nonce)
+            .set(&new_attributes);
+
+        new_attributes
+    }
+
+    /// Toggles whether a deposit counts as collateral for its account.
+    ///
+    /// **Purpose**: Lets users earn supply yield without exposing a deposit to liquidation.
+    /// Supply-only deposits are excluded from LTV, health factor and liquidation seizure.
+    ///
+    /// **Methodology**:
+    /// - Loads the deposit position, reverting when the account does not hold the asset
+    /// - Stores the new collateral flag for the deposit
+    /// - When excluding collateral, requires the health factor to stay at or above 1.0 so
+    ///   the account cannot remove collateral it is borrowing against
+    /// - Emits a position update event with a zero amount
+    ///
+    /// # Arguments
+    /// - `account_nonce`: Position NFT nonce of the account
+    /// - `asset_id`: Deposited asset to toggle
+    /// - `is_collateral`: Whether the deposit should count as collateral
+    /// - `caller`: Address performing the operation
+    /// - `account_attributes`: Account attributes for the update event
+    /// - `cache`: Storage cache for price and index access
+    fn update_position_collateral_flag(
+        &self,
+        account_nonce: u64,
+        asset_id: &EgldOrEsdtTokenIdentifier,
+        is_collateral: bool,
+        caller: &ManagedAddress,
+        account_attributes: &AccountAttributes<Self::Api>,
+        cache: &mut Cache<Self>,
+    ) {
+        let position = self
+            .positions(account_nonce, AccountPositionType::Deposit)
+            .get(asset_id);
+        require!(position.is_some(), ERROR_POSITION_NOT_FOUND);
+        let position = unsafe { position.unwrap_unchecked() };
+
+        let collateral_disabled = self.position_collateral_disabled(account_nonce, asset_id);
+        if is_collateral {
+            collateral_disabled.clear();
+        } else {
+            collateral_disabled.set(true);
+        }
+
+        if !is_collateral
+            && !self
+                .positions(account_nonce, AccountPositionType::Borrow)
+                .is_empty()
+        {
+            self.validate_min_health_factor(
+                account_nonce,
+                cache,
+                &self.ray(),
+                ERROR_HEALTH_FACTOR_DISABLE_COLLATERAL,
+            );
+        }
+
+        let feed = self.token_price(asset_id, cache);
+        self.emit_position_update_event(
+            cache,
+            &position.zero_decimal(),
+            &position,
+            feed.price_wad,
+            caller,
+            account_attributes,
+        );
+    }
 }
@@ -1,4 +1,8 @@
-use common_structs::{AccountAttributes, AccountPosition, AccountPositionType};
+use common_constants::RAY_PRECISION;
+use common_structs::{
+    AccountAttributes, AccountPosition, AccountPositionType, IndexSnapshot, MarketActivity,
+    MarketActivityType, MarketIndex, PositionEntry,
+};
 
 use super::account;
 use crate::{cache::Cache, helpers, oracle, storage, utils, validation};
@@ -177,12 +181,295 @@ pub trait PositionUpdateModule:
         };
 
         self.update_position_event(
-            index,
+            index.clone(),
             amount,
             position,
             OptionalValue::Some(price),
             OptionalValue::Some(caller),
             OptionalValue::Some(attributes),
         );
+
+        self.sync_position_entry(position, index);
+        self.sync_compounded_principal(position, cache);
+    }
+
+    /// Re-weights the entry index of a position after it changed.
+    ///
+    /// **Purpose**: Tracks the cost basis of a position so its accrued interest can be
+    /// reported as the difference between its current value and its principal.
+    ///
+    /// **Methodology**:
+    /// - Clears the entry once the position is closed
+    /// - Opens the entry at the current index for new positions
+    /// - On increases, weights the entry index by scaled amount:
+    ///   `entry' = (scaled * entry + added * index) / scaled'`
+    /// - On debt decreases, applies the repayment to accrued interest or principal first,
+    ///   following the market's repayment order
+    /// - On deposit decreases, keeps the entry index so accrued interest is withdrawn pro rata
+    ///
+    /// # Arguments
+    /// - `position`: Position state after the operation
+    /// - `index`: Current market index matching the position type (RAY)
+    fn sync_position_entry(
+        &self,
+        position: &AccountPosition<Self::Api>,
+        index: ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        let entry_mapper = self.position_entry(
+            position.account_nonce,
+            position.position_type.clone(),
+            &position.asset_id,
+        );
+        if position.can_remove() {
+            entry_mapper.clear();
+            return;
+        }
+
+        let new_scaled = position.scaled_amount_ray.clone();
+        let entry_index_ray = if entry_mapper.is_empty() {
+            index
+        } else {
+            let entry = entry_mapper.get();
+            if new_scaled > entry.scaled_amount_ray {
+                let added = new_scaled.clone() - entry.scaled_amount_ray.clone();
+                let weighted = self.mul_half_up(
+                    &entry.scaled_amount_ray,
+                    &entry.entry_index_ray,
+                    RAY_PRECISION,
+                ) + self.mul_half_up(&added, &index, RAY_PRECISION);
+                self.div_half_up(&weighted, &new_scaled, RAY_PRECISION)
+            } else if position.position_type == AccountPositionType::Borrow
+                && new_scaled < entry.scaled_amount_ray
+            {
+                self.repaid_entry_index(&position.asset_id, &entry, &new_scaled, &index)
+            } else {
+                entry.entry_index_ray
+            }
+        };
+
+        entry_mapper.set(PositionEntry {
+            scaled_amount_ray: new_scaled,
+            entry_index_ray,
+        });
+    }
+
+    /// Re-bases the entry index of a debt after a partial repayment.
+    ///
+    /// **Purpose**: Splits the repaid amount between accrued interest and principal so the
+    /// remaining principal reflects the market's repayment order.
+    ///
+    /// **Methodology**:
+    /// - Interest first: principal only shrinks by the part of the repayment above the
+    ///   accrued interest
+    /// - Principal first: principal shrinks by the whole repayment, down to zero
+    /// - The entry index is re-based as `entry' = principal' / scaled'`
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier of the debt
+    /// - `entry`: Position entry before the repayment
+    /// - `new_scaled`: Scaled debt left after the repayment (RAY)
+    /// - `index`: Current borrow index (RAY)
+    ///
+    /// # Returns
+    /// - Re-based entry index of the remaining debt (RAY)
+    fn repaid_entry_index(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+        entry: &PositionEntry<Self::Api>,
+        new_scaled: &ManagedDecimal<Self::Api, NumDecimals>,
+        index: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let principal = self.mul_half_up(
+            &entry.scaled_amount_ray,
+            &entry.entry_index_ray,
+            RAY_PRECISION,
+        );
+        let balance = self.mul_half_up(&entry.scaled_amount_ray, index, RAY_PRECISION);
+        let repaid = self.mul_half_up(
+            &(entry.scaled_amount_ray.clone() - new_scaled.clone()),
+            index,
+            RAY_PRECISION,
+        );
+
+        let principal_repaid = if self.repay_principal_first(asset).get() {
+            self.min(repaid, principal.clone())
+        } else {
+            let interest = if balance > principal {
+                balance - principal.clone()
+            } else {
+                self.ray_zero()
+            };
+            if repaid > interest {
+                self.min(repaid - interest, principal.clone())
+            } else {
+                self.ray_zero()
+            }
+        };
+
+        self.div_half_up(&(principal - principal_repaid), new_scaled, RAY_PRECISION)
+    }
+
+    /// Re-bases the tracked principal of a deposit in an auto-compounding account.
+    ///
+    /// **Purpose**: Keeps the principal equal to the balance after the latest position
+    /// change, so the next compounding event only reports interest accrued since then.
+    ///
+    /// **Methodology**:
+    /// - Skips borrow positions and accounts that did not opt in
+    /// - Clears the principal once the position is fully withdrawn
+    /// - Otherwise stores the current balance in asset decimals
+    ///
+    /// # Arguments
+    /// - `position`: Position state after the operation
+    /// - `cache`: Storage cache for price and index access
+    fn sync_compounded_principal(
+        &self,
+        position: &AccountPosition<Self::Api>,
+        cache: &mut Cache<Self>,
+    ) {
+        if position.position_type != AccountPositionType::Deposit
+            || !self.auto_compound(position.account_nonce).get()
+        {
+            return;
+        }
+
+        let principal = self.compounded_principal(position.account_nonce, &position.asset_id);
+        if position.can_remove() {
+            principal.clear();
+            return;
+        }
+
+        let feed = self.token_price(&position.asset_id, cache);
+        principal.set(self.total_amount(position, &feed, cache));
+    }
+
+    /// Records an operation in the recent activity buffer of its market.
+    ///
+    /// **Purpose**: Lets light clients display recent market activity without an
+    /// indexer. Recording is opt-in per market through its activity capacity.
+    ///
+    /// **Methodology**:
+    /// - Skips markets without a configured capacity
+    /// - Appends entries until the buffer holds `capacity` items
+    /// - Afterwards overwrites the oldest entry and advances the head slot
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier of the market
+    /// - `activity_type`: Operation being recorded
+    /// - `amount`: Amount applied to the market, in asset decimals
+    /// - `cache`: Storage cache for the block timestamp
+    fn record_market_activity(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+        activity_type: MarketActivityType,
+        amount: &ManagedDecimal<Self::Api, NumDecimals>,
+        cache: &Cache<Self>,
+    ) {
+        let capacity = self.market_activity_capacity(asset).get();
+        if capacity == 0 {
+            return;
+        }
+
+        let activity = MarketActivity {
+            activity_type,
+            amount: amount.clone(),
+            timestamp: cache.current_timestamp,
+        };
+
+        let mut buffer = self.market_activity(asset);
+        if buffer.len() < capacity {
+            buffer.push(&activity);
+            return;
+        }
+
+        let head_mapper = self.market_activity_head(asset);
+        let head = head_mapper.get();
+        buffer.set(head + 1, &activity);
+        head_mapper.set((head + 1) % capacity);
+    }
+
+    /// Records the synchronized indexes of a market as a snapshot.
+    ///
+    /// **Purpose**: Keeps an index history so interest accrued over a period can be
+    /// reconstructed for accounting exports. Recording is opt-in through the snapshot
+    /// interval, which also bounds how often a snapshot can be taken.
+    ///
+    /// **Methodology**:
+    /// - Skips recording when no snapshot interval is configured
+    /// - Skips recording when the last snapshot is younger than the interval
+    /// - Otherwise appends a snapshot stamped with the block timestamp
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier of the market
+    /// - `market_index`: Indexes returned by the pool synchronization
+    fn record_index_snapshot(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+        market_index: &MarketIndex<Self::Api>,
+    ) {
+        let interval_mapper = self.index_snapshot_interval();
+        if interval_mapper.is_empty() {
+            return;
+        }
+
+        let now = self.blockchain().get_block_timestamp_seconds();
+        let mut snapshots = self.index_snapshots(asset);
+        if !snapshots.is_empty() {
+            let last = snapshots.get(snapshots.len());
+            if now - last.timestamp < interval_mapper.get() {
+                return;
+            }
+        }
+
+        snapshots.push(&IndexSnapshot {
+            timestamp: now,
+            supply_index_ray: market_index.supply_index_ray.clone(),
+            borrow_index_ray: market_index.borrow_index_ray.clone(),
+            reward_index_ray: self.current_market_reward_index(asset),
+        });
+    }
+
+    /// Folds the accrued supply interest of a deposit into its tracked principal.
+    ///
+    /// **Purpose**: Produces an explicit compounding event for integrations that track
+    /// principal. Index math already compounds the balance, so this only re-bases the
+    /// principal and reports the difference as realized interest.
+    ///
+    /// **Mathematical Operations**:
+    /// ```
+    /// balance = position.scaled_amount * supply_index / RAY
+    /// interest = max(balance - principal, 0)
+    /// principal = balance
+    /// ```
+    ///
+    /// # Arguments
+    /// - `position`: Deposit position to compound
+    /// - `cache`: Storage cache for price and index access
+    fn compound_position_interest(
+        &self,
+        position: &AccountPosition<Self::Api>,
+        cache: &mut Cache<Self>,
+    ) {
+        let feed = self.token_price(&position.asset_id, cache);
+        let balance = self.total_amount(position, &feed, cache);
+
+        let principal = self.compounded_principal(position.account_nonce, &position.asset_id);
+        let interest = if !principal.is_empty() && balance > principal.get() {
+            balance.clone() - principal.get()
+        } else {
+            self.to_decimal(BigUint::zero(), feed.asset_decimals)
+        };
+        principal.set(balance);
+
+        let market_index = cache.cached_market_index(&position.asset_id);
+        let attributes = self.account_attributes(position.account_nonce).get();
+        self.update_position_event(
+            market_index.supply_index_ray,
+            &interest,
+            position,
+            OptionalValue::Some(feed.price_wad),
+            OptionalValue::None,
+            OptionalValue::Some(&attributes),
+        );
     }
 }
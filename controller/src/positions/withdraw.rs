@@ -1,6 +1,12 @@
-use common_structs::{AccountAttributes, AccountPosition, AccountPositionType, PriceFeedShort};
+use common_structs::{
+    AccountAttributes, AccountPosition, AccountPositionType, MarketActivityType, PriceFeedShort,
+};
 
-use crate::{cache::Cache, helpers, oracle, proxy_pool, storage, utils, validation};
+use crate::{
+    cache::Cache, helpers, oracle, proxy_accumulator, proxy_pool, storage, utils, validation,
+};
+use common_constants::RAY_PRECISION;
+use common_errors::ERROR_NO_ACCUMULATOR_FOUND;
 
 use super::{account, update};
 
@@ -26,11 +32,12 @@ pub trait PositionWithdrawModule:
     /// withdrawals and liquidation scenarios with proper validation and position updates.
     ///
     /// **Methodology**:
-    /// 1. Retrieves pool address for the deposit asset
-    /// 2. Executes withdrawal through liquidity pool with amount validation
-    /// 3. Handles liquidation fees if applicable
-    /// 4. Emits position update event for monitoring
-    /// 5. Updates or removes position based on remaining balance
+    /// 1. Forfeits rewards of a deposit withdrawn before its minimum supply duration
+    /// 2. Retrieves pool address for the deposit asset
+    /// 3. Executes withdrawal through liquidity pool with amount validation
+    /// 4. Handles liquidation fees if applicable
+    /// 5. Emits position update event for monitoring
+    /// 6. Updates or removes position based on remaining balance
     ///
     /// **Security Considerations**:
     /// - Amount validation performed at pool level to account for accrued interest
@@ -69,6 +76,17 @@ pub trait PositionWithdrawModule:
         deposit_position: &mut AccountPosition<Self::Api>,
         feed: &PriceFeedShort<Self::Api>,
     ) -> EgldOrEsdtTokenPayment<Self::Api> {
+        if !is_liquidation {
+            self.forfeit_early_supply_rewards(
+                account_nonce,
+                caller,
+                position_attributes,
+                deposit_position,
+                feed,
+                cache,
+            );
+        }
+
         let pool_address = cache.cached_pool_address(&deposit_position.asset_id);
         let total_amount = self.total_amount(deposit_position, feed, cache);
         let actual_withdrawal_amount = self.min(amount.clone(), total_amount);
@@ -91,12 +109,123 @@ pub trait PositionWithdrawModule:
             caller,
             position_attributes,
         );
+        self.record_market_activity(
+            &deposit_position.asset_id,
+            MarketActivityType::Withdraw,
+            &actual_withdrawal_amount,
+            cache,
+        );
 
         self.update_or_remove_position(account_nonce, deposit_position);
+        if deposit_position.can_remove() {
+            self.supply_timestamp(account_nonce, &deposit_position.asset_id)
+                .clear();
+            self.supply_reward_index(account_nonce, &deposit_position.asset_id)
+                .clear();
+            self.position_collateral_disabled(account_nonce, &deposit_position.asset_id)
+                .clear();
+        }
 
         payment
     }
 
+    /// Forfeits the rewards a deposit accrued during an unfinished minimum supply period.
+    ///
+    /// **Purpose**: Deters hit-and-run supplies that only aim to capture reward
+    /// distributions. Only rewards are forfeited; principal and interest are untouched.
+    ///
+    /// **Methodology**:
+    /// 1. Skips markets without a minimum supply duration and matured deposits
+    /// 2. Values the rewards received since the period began from the market reward index
+    /// 3. Withdraws them from the deposit to the controller
+    /// 4. Transfers them to the accumulator as protocol revenue
+    /// 5. Re-snapshots the reward index so they are not forfeited twice
+    ///
+    /// **Mathematical Operations**:
+    /// ```
+    /// forfeited = scaled_amount * (reward_index - supply_reward_index)
+    /// ```
+    ///
+    /// # Arguments
+    /// - `account_nonce`: Position NFT nonce of the deposit
+    /// - `caller`: Withdrawer's address for event emission
+    /// - `position_attributes`: Position attributes for event emission
+    /// - `deposit_position`: Deposit position reduced by the forfeited rewards
+    /// - `feed`: Price feed for decimal conversion
+    /// - `cache`: Storage cache for pool address and index access
+    ///
+    /// # Errors
+    /// - `ERROR_NO_ACCUMULATOR_FOUND`: If rewards must be forfeited but no accumulator is set
+    fn forfeit_early_supply_rewards(
+        &self,
+        account_nonce: u64,
+        caller: &ManagedAddress,
+        position_attributes: &AccountAttributes<Self::Api>,
+        deposit_position: &mut AccountPosition<Self::Api>,
+        feed: &PriceFeedShort<Self::Api>,
+        cache: &mut Cache<Self>,
+    ) {
+        let asset = deposit_position.asset_id.clone();
+        let min_duration_mapper = self.min_supply_duration(&asset);
+        let timestamp_mapper = self.supply_timestamp(account_nonce, &asset);
+        if min_duration_mapper.is_empty() || timestamp_mapper.is_empty() {
+            return;
+        }
+
+        let elapsed = self.blockchain().get_block_timestamp_seconds() - timestamp_mapper.get();
+        if elapsed >= min_duration_mapper.get() {
+            return;
+        }
+
+        let reward_index = self.current_market_reward_index(&asset);
+        let snapshot_mapper = self.supply_reward_index(account_nonce, &asset);
+        let accrued_index = reward_index.clone() - snapshot_mapper.get();
+        let forfeited_ray = self.mul_half_up(
+            &deposit_position.scaled_amount_ray,
+            &accrued_index,
+            RAY_PRECISION,
+        );
+        let forfeited = self.min(
+            self.rescale_half_up(&forfeited_ray, feed.asset_decimals),
+            self.total_amount(deposit_position, feed, cache),
+        );
+        if forfeited == self.to_decimal(BigUint::zero(), feed.asset_decimals) {
+            return;
+        }
+
+        require!(self.is_accumulator_set(), ERROR_NO_ACCUMULATOR_FOUND);
+
+        let pool_address = cache.cached_pool_address(&asset);
+        let payment = self.process_market_withdrawal(
+            pool_address,
+            &self.blockchain().get_sc_address(),
+            &forfeited,
+            deposit_position,
+            false,
+            None,
+            feed,
+        );
+
+        self.emit_position_update_event(
+            cache,
+            &forfeited,
+            deposit_position,
+            feed.price_wad.clone(),
+            caller,
+            position_attributes,
+        );
+        self.store_updated_position(account_nonce, deposit_position);
+        snapshot_mapper.set(reward_index);
+
+        self.tx()
+            .to(self.accumulator_address().get())
+            .typed(proxy_accumulator::AccumulatorProxy)
+            .deposit()
+            .payment(payment)
+            .returns(ReturnsResult)
+            .sync_call();
+    }
+
     /// Executes a market withdrawal via the liquidity pool.
     ///
     /// **Purpose**: Performs the core cross-contract call to execute withdrawal
@@ -183,9 +312,10 @@ pub trait PositionWithdrawModule:
     /// or returning active positions to the user after withdrawal operations.
     ///
     /// **Methodology**:
-    /// 1. Counts remaining deposit and borrow positions
-    /// 2. If no positions remain: burns NFT and clears storage
-    /// 3. If positions remain: transfers NFT back to caller
+    /// 1. Pays the dust collateral credited to the account to the caller
+    /// 2. Counts remaining deposit and borrow positions
+    /// 3. If no positions remain: burns NFT and clears storage
+    /// 4. If positions remain: transfers NFT back to caller
     ///
     /// **Position Lifecycle Rules**:
     /// - NFT represents active lending position
@@ -210,6 +340,8 @@ pub trait PositionWithdrawModule:
         account_payment: &EsdtTokenPayment<Self::Api>,
         caller: &ManagedAddress,
     ) {
+        self.pay_dust_collateral(account_payment.token_nonce, caller);
+
         let deposit_positions_count = self
             .positions(account_payment.token_nonce, AccountPositionType::Deposit)
             .len();
@@ -219,15 +351,30 @@ pub trait PositionWithdrawModule:
 
         // Burn NFT if position is fully closed
         if deposit_positions_count == 0 && borrow_positions_count == 0 {
-            self.account()
-                .nft_burn(account_payment.token_nonce, &account_payment.amount);
-            self.accounts().swap_remove(&account_payment.token_nonce);
-            self.account_attributes(account_payment.token_nonce).clear();
+            self.burn_account(account_payment);
         } else {
             self.tx().to(caller).payment(account_payment).transfer();
         }
     }
 
+    /// Pays the dust collateral credited to an account by a debt-clearing liquidation.
+    /// Only called while the account NFT is presented, so the credit always goes to its
+    /// current holder.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position
+    /// - `caller`: Address presenting the account NFT
+    fn pay_dust_collateral(&self, account_nonce: u64, caller: &ManagedAddress) {
+        let mut dust_mapper = self.dust_collateral(account_nonce);
+        for (token_id, amount) in dust_mapper.iter() {
+            self.tx()
+                .to(caller)
+                .egld_or_single_esdt(&token_id, 0, &amount)
+                .transfer_if_not_empty();
+        }
+        dust_mapper.clear();
+    }
+
     /// Retrieves a deposit position for a token.
     ///
     /// **Purpose**: Safely fetches an existing deposit position with validation
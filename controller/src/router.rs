@@ -4,7 +4,7 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 use common_errors::ERROR_TEMPLATE_EMPTY;
-use common_structs::AssetConfig;
+use common_structs::{AssetConfig, RateModelType};
 
 use crate::{
     cache::Cache, helpers, oracle, positions, proxy_accumulator, proxy_pool, storage, utils,
@@ -138,6 +138,10 @@ pub trait RouterModule:
     /// - `asset_decimals`: Token decimal precision
     /// - `borrow_cap`: Maximum total borrows (0 = unlimited)
     /// - `supply_cap`: Maximum total supply (0 = unlimited)
+    /// - `max_liquidation_close_factor`: Share of the debt a single liquidation can repay
+    ///   (basis points, defaults to 10000)
+    /// - `min_initial_supply`: Reserve buffer in asset units burned from the first deposit,
+    ///   so the pool never returns to an empty supply (defaults to 0, disabled)
     ///
     /// # Returns
     /// Address of the newly deployed liquidity pool contract
@@ -146,6 +150,7 @@ pub trait RouterModule:
     /// - `ERROR_ASSET_ALREADY_SUPPORTED`: Asset already has an active pool
     /// - `ERROR_INVALID_TICKER`: Invalid asset identifier format
     /// - `ERROR_INVALID_LIQUIDATION_THRESHOLD`: Threshold not greater than LTV
+    /// - `ERROR_INVALID_LIQUIDATION_CLOSE_FACTOR`: Close factor zero or above 10000
     #[only_owner]
     #[endpoint(createLiquidityPool)]
     fn create_liquidity_pool(
@@ -174,6 +179,8 @@ pub trait RouterModule:
         asset_decimals: usize,
         borrow_cap_wad: BigUint,
         supply_cap_wad: BigUint,
+        max_liquidation_close_factor: OptionalValue<BigUint>,
+        min_initial_supply: OptionalValue<BigUint>,
     ) -> ManagedAddress {
         require!(
             self.pools_map(&base_asset).is_empty(),
@@ -198,6 +205,17 @@ pub trait RouterModule:
         self.pools_map(&base_asset).set(address.clone());
         self.pools().insert(address.clone());
 
+        // Seed the first deposit with an unowned buffer before any supply or borrow can happen
+        if let Some(min_initial_supply) = min_initial_supply.into_option() {
+            if min_initial_supply > BigUint::zero() {
+                self.tx()
+                    .to(&address)
+                    .typed(proxy_pool::LiquidityPoolProxy)
+                    .set_min_initial_supply(min_initial_supply)
+                    .sync_call();
+            }
+        }
+
         // Init ManagedDecimal for future usage and avoiding storage decode errors for checks
         self.isolated_asset_debt_usd(&base_asset)
             .set(self.to_decimal(BigUint::zero(), asset_decimals));
@@ -207,6 +225,12 @@ pub trait RouterModule:
             ERROR_INVALID_LIQUIDATION_THRESHOLD
         );
 
+        if let Some(close_factor) = max_liquidation_close_factor.into_option() {
+            self.validate_liquidation_close_factor(&close_factor);
+            self.max_liquidation_close_factor_bps(&base_asset)
+                .set(close_factor);
+        }
+
         let asset_config = &AssetConfig {
             loan_to_value_bps: self.to_decimal_bps(ltv),
             liquidation_threshold_bps: self.to_decimal_bps(liquidation_threshold_bps),
@@ -253,6 +277,9 @@ pub trait RouterModule:
 
     /// Upgrades an existing liquidity pool with new parameters.
     /// Adjusts interest rate model and reserve settings.
+    /// Interest accrual of the market is paused for the upgrade and resumed by the
+    /// upgraded pool, so indexes never advance mid-upgrade. Pausing needs no price,
+    /// so a stale oracle never blocks an upgrade.
     ///
     /// # Arguments
     /// - `base_asset`: Token identifier (EGLD or ESDT) of the asset.
@@ -270,6 +297,7 @@ pub trait RouterModule:
         require!(!self.pools_map(base_asset).is_empty(), ERROR_NO_POOL_FOUND);
 
         let pool_address = self.pool_address(base_asset);
+        self.set_pool_accrual_paused(pool_address.clone(), true);
         self.upgrade_pool(pool_address);
     }
 
@@ -316,6 +344,189 @@ pub trait RouterModule:
         );
     }
 
+    /// Sets the minimum initial supply burned on the first deposit into a market.
+    ///
+    /// Purpose: Seed an empty pool with unowned supply so a first depositor cannot
+    /// inflate the supply index through donations and dilute later suppliers.
+    ///
+    /// Arguments
+    /// - `base_asset`: Market asset identifier
+    /// - `min_initial_supply`: Amount in asset units burned from the first deposit (0 disables)
+    #[only_owner]
+    #[endpoint(setMarketMinInitialSupply)]
+    fn set_market_min_initial_supply(
+        &self,
+        base_asset: &EgldOrEsdtTokenIdentifier,
+        min_initial_supply: BigUint,
+    ) {
+        require!(!self.pools_map(base_asset).is_empty(), ERROR_NO_POOL_FOUND);
+
+        let pool_address = self.pool_address(base_asset);
+        self.tx()
+            .to(pool_address)
+            .typed(proxy_pool::LiquidityPoolProxy)
+            .set_min_initial_supply(min_initial_supply)
+            .sync_call();
+    }
+
+    /// Chooses how a market absorbs debt written off by bad debt cleanup.
+    ///
+    /// Purpose: By default the loss is socialized across all suppliers by reducing the
+    /// supply index proportionally. When enabled, the market's protocol revenue is burned
+    /// first and only the uncovered remainder reduces the supply index.
+    ///
+    /// Arguments
+    /// - `base_asset`: Market asset identifier
+    /// - `enabled`: `true` to cover bad debt with protocol revenue before suppliers
+    #[only_owner]
+    #[endpoint(setMarketRevenueAbsorbsBadDebt)]
+    fn set_market_revenue_absorbs_bad_debt(
+        &self,
+        base_asset: &EgldOrEsdtTokenIdentifier,
+        enabled: bool,
+    ) {
+        require!(!self.pools_map(base_asset).is_empty(), ERROR_NO_POOL_FOUND);
+
+        let pool_address = self.pool_address(base_asset);
+        self.tx()
+            .to(pool_address)
+            .typed(proxy_pool::LiquidityPoolProxy)
+            .set_revenue_absorbs_bad_debt(enabled)
+            .sync_call();
+    }
+
+    /// Selects the borrow rate curve of a market.
+    ///
+    /// Purpose: Switch a market between the three-slope and the kink rate model while
+    /// keeping its rate parameters. Interest accrued so far is settled under the
+    /// previous model before the switch.
+    ///
+    /// Arguments
+    /// - `base_asset`: Market asset identifier
+    /// - `rate_model_type`: Rate model applied to the market's parameters
+    #[only_owner]
+    #[endpoint(setMarketRateModelType)]
+    fn set_market_rate_model_type(
+        &self,
+        base_asset: &EgldOrEsdtTokenIdentifier,
+        rate_model_type: RateModelType,
+    ) {
+        require!(!self.pools_map(base_asset).is_empty(), ERROR_NO_POOL_FOUND);
+
+        let pool_address = self.pool_address(base_asset);
+        let mut cache = Cache::new(self);
+        let feed = self.token_price(base_asset, &mut cache);
+        self.tx()
+            .to(pool_address)
+            .typed(proxy_pool::LiquidityPoolProxy)
+            .set_rate_model_type(rate_model_type, feed.price_wad)
+            .sync_call();
+    }
+
+    /// Sets the elevated reserve factor of a market.
+    ///
+    /// Purpose: Let the protocol take a larger share of interest while a market runs
+    /// above its optimal utilization. Zero keeps the reserve factor flat. Interest
+    /// accrued so far is settled under the previous reserve factor.
+    ///
+    /// Arguments
+    /// - `base_asset`: Market asset identifier
+    /// - `reserve_factor_high`: Reserve factor above the optimal utilization (BPS)
+    #[only_owner]
+    #[endpoint(setMarketReserveFactorHigh)]
+    fn set_market_reserve_factor_high(
+        &self,
+        base_asset: &EgldOrEsdtTokenIdentifier,
+        reserve_factor_high: BigUint,
+    ) {
+        require!(!self.pools_map(base_asset).is_empty(), ERROR_NO_POOL_FOUND);
+
+        let pool_address = self.pool_address(base_asset);
+        let mut cache = Cache::new(self);
+        let feed = self.token_price(base_asset, &mut cache);
+        self.tx()
+            .to(pool_address)
+            .typed(proxy_pool::LiquidityPoolProxy)
+            .set_reserve_factor_high(reserve_factor_high, feed.price_wad)
+            .sync_call();
+    }
+
+    /// Sets the absolute borrow rate ceiling of a market enforced at accrual.
+    ///
+    /// Purpose: Final safety against punitive interest at extreme utilization. The
+    /// borrow rate accrues at most at this ceiling whatever the rate curve yields.
+    /// Zero disables it; otherwise it must lie above the base borrow rate and at most
+    /// at the max borrow rate. Interest accrued so far is settled under the previous ceiling.
+    ///
+    /// Arguments
+    /// - `base_asset`: Market asset identifier
+    /// - `max_accrual_rate`: Annual borrow rate ceiling (RAY)
+    #[only_owner]
+    #[endpoint(setMarketMaxAccrualRate)]
+    fn set_market_max_accrual_rate(
+        &self,
+        base_asset: &EgldOrEsdtTokenIdentifier,
+        max_accrual_rate: BigUint,
+    ) {
+        require!(!self.pools_map(base_asset).is_empty(), ERROR_NO_POOL_FOUND);
+
+        let pool_address = self.pool_address(base_asset);
+        let mut cache = Cache::new(self);
+        let feed = self.token_price(base_asset, &mut cache);
+        self.tx()
+            .to(pool_address)
+            .typed(proxy_pool::LiquidityPoolProxy)
+            .set_max_accrual_rate(max_accrual_rate, feed.price_wad)
+            .sync_call();
+    }
+
+    /// Sets the minimum time between two index recomputations of a market.
+    ///
+    /// Purpose: Save gas on high-frequency `updateIndexes` calls. Syncs within the
+    /// interval keep serving the last computed indexes and leave the last update
+    /// timestamp untouched, so the skipped time accrues on the next real sync.
+    /// Zero recomputes the indexes on every sync.
+    ///
+    /// Arguments
+    /// - `base_asset`: Market asset identifier
+    /// - `min_accrual_interval_ms`: Minimum interval between index updates (ms), at most one hour
+    #[only_owner]
+    #[endpoint(setMarketMinAccrualInterval)]
+    fn set_market_min_accrual_interval(
+        &self,
+        base_asset: &EgldOrEsdtTokenIdentifier,
+        min_accrual_interval_ms: DurationMillis,
+    ) {
+        require!(!self.pools_map(base_asset).is_empty(), ERROR_NO_POOL_FOUND);
+
+        let pool_address = self.pool_address(base_asset);
+        let mut cache = Cache::new(self);
+        let feed = self.token_price(base_asset, &mut cache);
+        self.tx()
+            .to(pool_address)
+            .typed(proxy_pool::LiquidityPoolProxy)
+            .set_min_accrual_interval(min_accrual_interval_ms, feed.price_wad)
+            .sync_call();
+    }
+
+    /// Pauses or resumes interest accrual of a market.
+    ///
+    /// Purpose: Freeze a market's indexes outside of `upgradeLiquidityPool`, e.g. to
+    /// resume a market whose upgrade did not complete. Interest accrued so far is
+    /// settled before pausing, and the paused window never accrues interest.
+    ///
+    /// Arguments
+    /// - `base_asset`: Market asset identifier
+    /// - `paused`: `true` to freeze the indexes, `false` to resume accrual
+    #[only_owner]
+    #[endpoint(setMarketAccrualPaused)]
+    fn set_market_accrual_paused(&self, base_asset: &EgldOrEsdtTokenIdentifier, paused: bool) {
+        require!(!self.pools_map(base_asset).is_empty(), ERROR_NO_POOL_FOUND);
+
+        let pool_address = self.pool_address(base_asset);
+        self.set_pool_accrual_paused(pool_address, paused);
+    }
+
     /// Deploys new liquidity pool contract from template with interest rate model.
     /// Initializes pool with asset configuration and returns deployed contract address.
     /// Ensures upgradeable code metadata for future protocol improvements.
@@ -393,6 +604,26 @@ pub trait RouterModule:
             .sync_call()
     }
 
+    /// Pauses or resumes interest accrual of a pool, without reading the asset price.
+    fn set_pool_accrual_paused(&self, lp_address: ManagedAddress, paused: bool) {
+        self.tx()
+            .to(lp_address)
+            .typed(proxy_pool::LiquidityPoolProxy)
+            .set_accrual_paused(paused)
+            .sync_call();
+    }
+
+    /// Returns the configured accumulator address.
+    /// A zero address counts as unset, so revenue is never sent to it.
+    ///
+    /// # Errors
+    /// - `ERROR_NO_ACCUMULATOR_FOUND`: If the accumulator is unset or the zero address
+    fn require_accumulator_address(&self) -> ManagedAddress {
+        require!(self.is_accumulator_set(), ERROR_NO_ACCUMULATOR_FOUND);
+
+        self.accumulator_address().get()
+    }
+
     /// Upgrades pool contract code to latest template version.
     /// Preserves pool state while updating implementation logic.
     /// Exits current execution context after initiating upgrade.
@@ -472,19 +703,14 @@ pub trait RouterModule:
     /// Nothing - transfers revenue from pools to accumulator
     ///
     /// # Errors
-    /// - `ERROR_NO_ACCUMULATOR_FOUND`: Accumulator address not configured
+    /// - `ERROR_NO_ACCUMULATOR_FOUND`: Accumulator address not configured or zero; the revenue
+    ///   stays in the pools
     #[endpoint(claimRevenue)]
     fn claim_revenue(&self, assets: MultiValueEncoded<EgldOrEsdtTokenIdentifier>) {
         let mut cache = Cache::new(self);
         self.reentrancy_guard(cache.flash_loan_ongoing);
-        let accumulator_address_mapper = self.accumulator_address();
+        let accumulator_address = self.require_accumulator_address();
 
-        require!(
-            !accumulator_address_mapper.is_empty(),
-            ERROR_NO_ACCUMULATOR_FOUND
-        );
-
-        let accumulator_address = accumulator_address_mapper.get();
         for asset in assets {
             let pool_address = cache.cached_pool_address(&asset);
             let data = self.token_price(&asset, &mut cache);
@@ -515,21 +741,19 @@ pub trait RouterModule:
         let mut cache = Cache::new(self);
         let payment = self.call_value().egld_or_single_esdt();
 
-        let accumulator_address_mapper = self.accumulator_address();
-
-        require!(
-            !accumulator_address_mapper.is_empty(),
-            ERROR_NO_ACCUMULATOR_FOUND
-        );
+        self.require_accumulator_address();
 
         let pool_address = cache.cached_pool_address(&payment.token_identifier);
         let data = self.token_price(&payment.token_identifier, &mut cache);
+        let reward = self.to_decimal(payment.amount.clone(), data.asset_decimals);
         self.tx()
-            .to(pool_address)
+            .to(pool_address.clone())
             .typed(proxy_pool::LiquidityPoolProxy)
             .add_reward(data.price_wad.clone())
-            .payment(payment)
+            .payment(&payment)
             .returns(ReturnsResult)
             .sync_call();
+
+        self.accrue_market_reward_index(&payment.token_identifier, pool_address, &reward);
     }
 }
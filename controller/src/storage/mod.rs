@@ -1,9 +1,12 @@
+use common_constants::{BPS_PRECISION, RAY_PRECISION};
 use common_events::MarketParams;
 use common_proxies::proxy_onedex::State as StateOnedex;
 use common_proxies::proxy_xexchange_pair::State as StateXExchange;
 use common_structs::{
-    AccountAttributes, AccountPosition, AccountPositionType, AssetConfig, EModeAssetConfig,
-    EModeCategory, OracleProvider, PositionLimits,
+    AccountAttributes, AccountPosition, AccountPositionType, AssetConfig, CapDenomination,
+    EModeAssetConfig, EModeCategory, IndexSnapshot, LiquidationRecord, MarketActivity,
+    MarketRateSettings, OracleProvider, PositionEntry, PositionLimits, PriceStalenessOverride,
+    RateModelType,
 };
 use price_aggregator::structs::TimestampedPrice;
 multiversx_sc::imports!();
@@ -89,6 +92,16 @@ pub trait Storage {
     #[storage_mapper("swap_router_address")]
     fn swap_router(&self) -> SingleValueMapper<ManagedAddress>;
 
+    /// Get the default swap router steps used to convert one token into another
+    /// Strategies fall back to these steps when called with empty steps for the pair
+    #[view(getDefaultSwapSteps)]
+    #[storage_mapper("default_swap_steps")]
+    fn default_swap_steps(
+        &self,
+        from_token: &EgldOrEsdtTokenIdentifier,
+        to_token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<ManagedVec<ManagedBuffer>>;
+
     /// Get the asset config
     /// This storage mapper holds the configuration of an asset, used to retrieve the config of an asset.
     #[view(getAssetConfig)]
@@ -122,6 +135,16 @@ pub trait Storage {
     #[storage_mapper("e_mode_assets")]
     fn e_mode_assets(&self, id: u8) -> MapMapper<EgldOrEsdtTokenIdentifier, EModeAssetConfig>;
 
+    /// Get whether an e-mode collateral needs another eligible collateral to back a borrow
+    /// An empty mapper lets the asset back e-mode borrows on its own
+    #[view(isEModeCompanionCollateralRequired)]
+    #[storage_mapper("e_mode_companion_collateral_required")]
+    fn e_mode_companion_collateral_required(
+        &self,
+        id: u8,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<bool>;
+
     /// Get the debt in USD for isolated assets
     /// This storage mapper holds the debt in USD for isolated assets.
     #[view(getIsolatedAssetDebtUsd)]
@@ -131,6 +154,14 @@ pub trait Storage {
         asset: &EgldOrEsdtTokenIdentifier,
     ) -> SingleValueMapper<ManagedDecimal<Self::Api, NumDecimals>>;
 
+    /// Get the debt in EGLD borrowed by accounts of an e-mode category (WAD)
+    /// Category 0 holds the debt of accounts without e-mode; valued at borrow and repay time.
+    #[storage_mapper("e_mode_debt_egld")]
+    fn e_mode_debt_egld(
+        &self,
+        category_id: u8,
+    ) -> SingleValueMapper<ManagedDecimal<Self::Api, NumDecimals>>;
+
     /// Get the token oracle
     /// This storage mapper holds the oracle of a token, used to get the price of a token.
     #[view(getTokenOracle)]
@@ -140,6 +171,18 @@ pub trait Storage {
         asset: &EgldOrEsdtTokenIdentifier,
     ) -> SingleValueMapper<OracleProvider<Self::Api>>;
 
+    /// Get the number of agreeing price sources required before a token price is accepted
+    /// An empty mapper, 0 or 1 disables the requirement
+    #[view(getTokenOracleQuorum)]
+    #[storage_mapper("min_oracle_quorum")]
+    fn min_oracle_quorum(&self, asset: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<u8>;
+
+    /// Get the number of safe price rounds averaged by the `Twap` fallback of a token
+    /// An empty mapper averages `DEFAULT_TWAP_ROUNDS` rounds
+    #[view(getTokenOracleTwapRounds)]
+    #[storage_mapper("twap_rounds")]
+    fn twap_rounds(&self, asset: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<u8>;
+
     /// Reentrancy guard flag for flash loans
     /// Indicates if a flash loan is currently in progress to block nested calls.
     ///
@@ -156,6 +199,381 @@ pub trait Storage {
     #[storage_mapper("position_limits")]
     fn position_limits(&self) -> SingleValueMapper<PositionLimits>;
 
+    /// Get the maximum number of account NFTs a batch strategy call can operate on
+    /// An empty mapper disables batch strategies
+    #[view(getMaxBatchAccounts)]
+    #[storage_mapper("max_batch_accounts")]
+    fn max_batch_accounts(&self) -> SingleValueMapper<usize>;
+
+    /// Get the share of the liquidation bonus credited back to the liquidated account
+    /// Expressed in basis points of the bonus portion of each seized collateral
+    /// An empty mapper means no rebate is applied
+    #[view(getLiquidateeRebateBps)]
+    #[storage_mapper("liquidatee_rebate_bps")]
+    fn liquidatee_rebate_bps(&self) -> SingleValueMapper<BigUint>;
+
+    /// Get the account nonces owned by the protocol itself
+    /// These accounts (e.g. revenue reinvestment) are exempt from position limits, borrow allowances
+    /// and the maximum position value
+    #[view(getProtocolOwnedAccounts)]
+    #[storage_mapper("protocol_owned_accounts")]
+    fn protocol_owned_accounts(&self) -> UnorderedSetMapper<u64>;
+
+    /// Get the addresses exempt from asset-wide borrow caps
+    /// Meant for protocol-operated borrowers such as the treasury or market makers
+    #[view(getBorrowCapExemptAddresses)]
+    #[storage_mapper("borrow_cap_exempt_addresses")]
+    fn borrow_cap_exempt_addresses(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Get the minimum health factor required after strategy operations (RAY)
+    /// Applies to multiply, swap debt and swap collateral to leave a buffer above liquidation
+    /// An empty mapper falls back to the regular 1.0 threshold
+    #[view(getMinStrategyHealthFactor)]
+    #[storage_mapper("min_strategy_hf_ray")]
+    fn min_strategy_hf_ray(&self) -> SingleValueMapper<BigUint>;
+
+    /// Get the origination fee charged when opening leverage with multiply
+    /// Expressed in basis points of the flash-loaned debt and routed to the debt market revenue
+    /// An empty mapper means no origination fee
+    #[view(getMultiplyOriginationFeeBps)]
+    #[storage_mapper("multiply_origination_fee_bps")]
+    fn multiply_origination_fee_bps(&self) -> SingleValueMapper<BigUint>;
+
+    /// Get the share of each flash loan fee routed to the accumulator
+    /// Expressed in basis points of the fee, the remainder stays as pool revenue
+    /// An empty mapper keeps the whole fee in the pool
+    #[view(getFlashLoanProtocolShareBps)]
+    #[storage_mapper("flash_loan_protocol_share_bps")]
+    fn flash_loan_protocol_share_bps(&self) -> SingleValueMapper<BigUint>;
+
+    /// Get whether the global kill-switch blocking new debt is engaged
+    /// Stored inverted so that debt creation stays enabled by default
+    #[view(isDebtCreationDisabled)]
+    #[storage_mapper("debt_creation_disabled")]
+    fn debt_creation_disabled(&self) -> SingleValueMapper<bool>;
+
+    /// Get whether a single market is paused for new supplies and borrows
+    /// Repayments, withdrawals and liquidations of the asset keep working while paused
+    #[view(isMarketPaused)]
+    #[storage_mapper("market_paused")]
+    fn market_paused(&self, asset: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<bool>;
+
+    /// Get whether new supplies of an asset are disabled
+    /// Existing deposits keep counting as collateral and can still be withdrawn
+    /// Stored inverted so that new supplies stay enabled by default
+    #[view(isNewSupplyDisabled)]
+    #[storage_mapper("new_supply_disabled")]
+    fn new_supply_disabled(&self, asset: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<bool>;
+
+    /// Get whether new borrows of an asset are disabled
+    /// Existing debt keeps accruing and can still be repaid or liquidated
+    /// Stored inverted so that new borrows stay enabled by default
+    #[view(isNewBorrowDisabled)]
+    #[storage_mapper("new_borrow_disabled")]
+    fn new_borrow_disabled(&self, asset: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<bool>;
+
+    /// Get whether health factor views skip accruing interest up to the current timestamp
+    /// Stored inverted so that views stay exactly current by default
+    #[view(isViewIndexSyncDisabled)]
+    #[storage_mapper("view_index_sync_disabled")]
+    fn view_index_sync_disabled(&self) -> SingleValueMapper<bool>;
+
+    /// Get the minimum reserve ratio of a market
+    /// Share of supplied liquidity, in basis points, that borrows can never consume
+    /// An empty mapper means no reserve requirement
+    #[view(getMinReserveRatioBps)]
+    #[storage_mapper("min_reserve_ratio_bps")]
+    fn min_reserve_ratio_bps(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    /// Get the unit the borrow cap of an asset is expressed in
+    /// An empty mapper keeps the cap in token units
+    #[view(getBorrowCapDenomination)]
+    #[storage_mapper("borrow_cap_denomination")]
+    fn borrow_cap_denomination(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<CapDenomination>;
+
+    /// Get the EGLD value (WAD) under which debt left after a repay is written off
+    /// An empty mapper never writes off dust debt
+    #[view(getDustThreshold)]
+    #[storage_mapper("dust_threshold_wad")]
+    fn dust_threshold_wad(&self, asset: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Get the discount applied to a collateral asset's value in liquidation math (BPS)
+    /// An empty mapper values the collateral at its mark price
+    #[view(getLiquidationHaircut)]
+    #[storage_mapper("liquidation_haircut_bps")]
+    fn liquidation_haircut_bps(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    /// Get the maximum share of the debt in an asset a single liquidation can repay (BPS)
+    /// An empty mapper lets a single liquidation repay the full debt
+    #[view(getMaxLiquidationCloseFactorBps)]
+    #[storage_mapper("max_liquidation_close_factor_bps")]
+    fn max_liquidation_close_factor_bps(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    /// Get the governance borrow allowance of an account in EGLD (WAD)
+    /// Caps the total debt of the account regardless of its collateral; empty means unlimited
+    #[view(getAccountBorrowAllowance)]
+    #[storage_mapper("account_borrow_allowance")]
+    fn account_borrow_allowance(&self, account_nonce: u64) -> SingleValueMapper<BigUint>;
+
+    /// Get the governance borrow cap override of an account for an asset, in asset decimals
+    /// Replaces the asset-wide borrow cap for this account's debt in the asset; empty means no override
+    #[view(getAccountBorrowCapOverride)]
+    #[storage_mapper("account_borrow_cap_override")]
+    fn account_borrow_cap_override(
+        &self,
+        account_nonce: u64,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    /// Get the maximum total collateral and total debt of a single account in EGLD (WAD)
+    /// Bounds how large any position can grow; empty means unlimited
+    #[view(getMaxPositionValue)]
+    #[storage_mapper("max_position_value")]
+    fn max_position_value(&self) -> SingleValueMapper<BigUint>;
+
+    /// Get the minimum EGLD value (WAD) of collateral a single liquidation must seize
+    /// Rejects liquidations of economically meaningless amounts; empty disables the floor
+    #[view(getMinSeizedCollateralEgld)]
+    #[storage_mapper("min_seized_collateral_egld")]
+    fn min_seized_collateral_egld(&self) -> SingleValueMapper<BigUint>;
+
+    /// Get whether an account opted into explicit compounding of supply interest
+    #[view(isAutoCompoundEnabled)]
+    #[storage_mapper("auto_compound")]
+    fn auto_compound(&self, account_nonce: u64) -> SingleValueMapper<bool>;
+
+    /// Get the tracked principal of a deposit in an auto-compounding account
+    /// Re-based to the current balance on every position update and compounding event
+    #[view(getCompoundedPrincipal)]
+    #[storage_mapper("compounded_principal")]
+    fn compounded_principal(
+        &self,
+        account_nonce: u64,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<ManagedDecimal<Self::Api, NumDecimals>>;
+
+    /// Get the cost basis of a position, used to report its accrued interest
+    /// Re-weighted on every increase of the position and cleared once it is closed
+    #[view(getPositionEntry)]
+    #[storage_mapper("position_entry")]
+    fn position_entry(
+        &self,
+        account_nonce: u64,
+        position_type: AccountPositionType,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<PositionEntry<Self::Api>>;
+
+    /// Get whether repayments of an asset reduce principal before accrued interest
+    /// Stored so that repayments apply to accrued interest first by default
+    #[view(isRepayPrincipalFirst)]
+    #[storage_mapper("repay_principal_first")]
+    fn repay_principal_first(&self, asset: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<bool>;
+
+    /// Get the maximum collateral price drop between two liquidation price checks (BPS)
+    /// A larger drop in a single update defers liquidations holding that collateral to the next block
+    /// An empty mapper disables the check
+    #[view(getMaxLiquidationPriceDropBps)]
+    #[storage_mapper("max_liquidation_price_drop_bps")]
+    fn max_liquidation_price_drop_bps(&self) -> SingleValueMapper<BigUint>;
+
+    /// Get the reference price of a token for the liquidation price regression check (WAD)
+    /// Only written by the check itself, so no other endpoint can move it
+    #[view(getLiquidationReferencePrice)]
+    #[storage_mapper("liquidation_reference_price")]
+    fn liquidation_reference_price(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<ManagedDecimal<Self::Api, NumDecimals>>;
+
+    /// Get the last price of a token recorded from a live oracle by `updateIndexes` (WAD)
+    #[view(getLastTokenPrice)]
+    #[storage_mapper("last_token_price")]
+    fn last_token_price(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<ManagedDecimal<Self::Api, NumDecimals>>;
+
+    /// Get the block timestamp at which the last token price was recorded from a live oracle
+    #[view(getLastTokenPriceTimestamp)]
+    #[storage_mapper("last_token_price_timestamp")]
+    fn last_token_price_timestamp(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<TimestampSeconds>;
+
+    /// Get the maximum age of the last token price used for assets removed from the oracle
+    /// Keeps accounts holding a delisted asset liquidatable and withdrawable; empty disables the fallback
+    #[view(getDelistedPriceMaxAge)]
+    #[storage_mapper("delisted_price_max_age")]
+    fn delisted_price_max_age(&self) -> SingleValueMapper<DurationSeconds>;
+
+    /// Get the collateral value under which a debt-free account is emptied after liquidation (USD, WAD)
+    /// Remaining collateral of an account fully cleared of debt is credited for its NFT holder to claim
+    /// An empty mapper keeps the dust collateral in the account
+    #[view(getLiquidationDustThreshold)]
+    #[storage_mapper("liquidation_dust_threshold_usd")]
+    fn liquidation_dust_threshold_usd(&self) -> SingleValueMapper<BigUint>;
+
+    /// Get the maximum age of the EGLD reference price
+    /// Applied on top of the EGLD oracle bound when stricter; empty keeps the oracle bound
+    #[view(getEgldMaxPriceStaleSeconds)]
+    #[storage_mapper("egld_max_price_stale_seconds")]
+    fn egld_max_price_stale_seconds(&self) -> SingleValueMapper<DurationSeconds>;
+
+    /// Get the temporary staleness tolerance extension of an asset's price feeds
+    /// Ignored once its expiry is reached; empty applies the configured tolerance
+    #[view(getPriceStalenessOverride)]
+    #[storage_mapper("price_staleness_override")]
+    fn price_staleness_override(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<PriceStalenessOverride>;
+
+    /// Get the number of recent activity entries retained for a market
+    /// An empty mapper disables activity recording for the market
+    #[view(getMarketActivityCapacity)]
+    #[storage_mapper("market_activity_capacity")]
+    fn market_activity_capacity(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<usize>;
+
+    /// Ring buffer of the most recent supply, borrow, repay and withdraw operations of a market
+    #[storage_mapper("market_activity")]
+    fn market_activity(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> VecMapper<MarketActivity<Self::Api>>;
+
+    /// Zero-based slot of the oldest entry, overwritten by the next recorded operation once full
+    #[storage_mapper("market_activity_head")]
+    fn market_activity_head(&self, asset: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<usize>;
+
+    /// Get the minimum time between two index snapshots of a market
+    /// An empty mapper disables index snapshots
+    #[view(getIndexSnapshotInterval)]
+    #[storage_mapper("index_snapshot_interval")]
+    fn index_snapshot_interval(&self) -> SingleValueMapper<DurationSeconds>;
+
+    /// Index snapshots of a market recorded by `updateIndexes`, ordered by timestamp
+    #[storage_mapper("index_snapshots")]
+    fn index_snapshots(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> VecMapper<IndexSnapshot<Self::Api>>;
+
+    /// Get the minimum time a deposit must stay supplied to keep the rewards it accrued
+    /// Withdrawing earlier forfeits those rewards to the protocol; empty disables the rule
+    #[view(getMinSupplyDuration)]
+    #[storage_mapper("min_supply_duration")]
+    fn min_supply_duration(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<DurationSeconds>;
+
+    /// Get the cumulative rewards distributed per scaled unit supplied to a market (RAY)
+    #[view(getMarketRewardIndex)]
+    #[storage_mapper("market_reward_index")]
+    fn market_reward_index(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<ManagedDecimal<Self::Api, NumDecimals>>;
+
+    /// Get the block timestamp of the last supply starting or extending the minimum supply period of a deposit
+    #[view(getSupplyTimestamp)]
+    #[storage_mapper("supply_timestamp")]
+    fn supply_timestamp(
+        &self,
+        account_nonce: u64,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<TimestampSeconds>;
+
+    /// Market reward index observed when the running minimum supply period of a deposit started
+    #[storage_mapper("supply_reward_index")]
+    fn supply_reward_index(
+        &self,
+        account_nonce: u64,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<ManagedDecimal<Self::Api, NumDecimals>>;
+
+    /// Get whether a deposit is supply-only and excluded from collateral
+    /// Stored inverted so deposits count as collateral unless explicitly disabled
+    #[view(isPositionCollateralDisabled)]
+    #[storage_mapper("position_collateral_disabled")]
+    fn position_collateral_disabled(
+        &self,
+        account_nonce: u64,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<bool>;
+
+    /// Get the period after a liquidation during which the account cannot be liquidated again
+    /// Gives the liquidatee time to repay the remaining debt; empty disables the grace period
+    #[view(getPostLiquidationGrace)]
+    #[storage_mapper("post_liquidation_grace")]
+    fn post_liquidation_grace(&self) -> SingleValueMapper<DurationSeconds>;
+
+    /// Get the block timestamp of the last liquidation of an account
+    #[view(getLastLiquidationTimestamp)]
+    #[storage_mapper("last_liquidation_timestamp")]
+    fn last_liquidation_timestamp(&self, account_nonce: u64)
+        -> SingleValueMapper<TimestampSeconds>;
+
+    /// Get the period after a liquidation threshold reduction during which affected accounts
+    /// cannot be liquidated; empty disables the grace period
+    #[view(getThresholdUpdateGrace)]
+    #[storage_mapper("threshold_update_grace")]
+    fn threshold_update_grace(&self) -> SingleValueMapper<DurationSeconds>;
+
+    /// Get the block timestamp until which an account cannot be liquidated
+    /// Set when `updateAccountThreshold` lowers one of the account's liquidation thresholds
+    #[view(getLiquidationGraceUntil)]
+    #[storage_mapper("liquidation_grace_until")]
+    fn liquidation_grace_until(&self, account_nonce: u64) -> SingleValueMapper<TimestampSeconds>;
+
+    /// Ring buffer of the most recent liquidations of an account
+    #[storage_mapper("liquidation_history")]
+    fn liquidation_history(&self, account_nonce: u64) -> VecMapper<LiquidationRecord<Self::Api>>;
+
+    /// Zero-based slot of the oldest liquidation record, overwritten by the next one once full
+    #[storage_mapper("liquidation_history_head")]
+    fn liquidation_history_head(&self, account_nonce: u64) -> SingleValueMapper<usize>;
+
+    /// Get the last address that presented the account NFT to the protocol
+    #[view(getAccountHolder)]
+    #[storage_mapper("account_holder")]
+    fn account_holder(&self, account_nonce: u64) -> SingleValueMapper<ManagedAddress>;
+
+    /// Get the account NFT nonces last presented by an address
+    /// Reverse index of the account holder, kept in sync whenever the holder changes
+    #[view(getUserAccounts)]
+    #[storage_mapper("user_accounts")]
+    fn user_accounts(&self, holder: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    /// Get the dust collateral credited to an account after a liquidation cleared all of its debt
+    /// Paid out to the next address presenting the account NFT
+    #[view(getDustCollateral)]
+    #[storage_mapper("dust_collateral")]
+    fn dust_collateral(&self, account_nonce: u64) -> MapMapper<EgldOrEsdtTokenIdentifier, BigUint>;
+
+    /// Block timestamp at which liquidations holding a token were last deferred
+    #[storage_mapper("liquidation_deferred_at")]
+    fn liquidation_deferred_at(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<TimestampMillis>;
+
     /// PROXY STORAGE ///
     ///
     /// Retrieves the total scaled amount supplied to the pool.
@@ -228,6 +646,104 @@ pub trait Storage {
         liquidity_pool_address: ManagedAddress,
     ) -> SingleValueMapper<TimestampMillis, ManagedAddress>;
 
+    /// Retrieves whether interest accrual of the pool is paused.
+    ///
+    /// # Returns
+    /// - `bool`: `true` while the pool is being upgraded and its indexes are frozen.
+    #[storage_mapper_from_address("accrual_paused")]
+    fn accrual_paused(
+        &self,
+        liquidity_pool_address: ManagedAddress,
+    ) -> SingleValueMapper<bool, ManagedAddress>;
+
+    /// Retrieves the protocol revenue accumulated in the pool.
+    /// It is stored as scaled supply that appreciates with the supply index.
+    ///
+    /// # Returns
+    /// - `ManagedDecimal<Self::Api, NumDecimals>`: The scaled protocol revenue, RAY-scaled.
+    #[storage_mapper_from_address("revenue")]
+    fn revenue(
+        &self,
+        liquidity_pool_address: ManagedAddress,
+    ) -> SingleValueMapper<ManagedDecimal<Self::Api, NumDecimals>, ManagedAddress>;
+
+    /// Retrieves the cumulative rounding remainder retained by the pool.
+    ///
+    /// # Returns
+    /// - `BigUint`: The accumulated rounding remainder in asset units, RAY-scaled.
+    #[storage_mapper_from_address("accumulated_rounding")]
+    fn accumulated_rounding(
+        &self,
+        liquidity_pool_address: ManagedAddress,
+    ) -> SingleValueMapper<BigUint, ManagedAddress>;
+
+    /// Retrieves the borrow rate curve of the pool.
+    /// An empty mapper means the three-slope model.
+    ///
+    /// # Returns
+    /// - `RateModelType`: The rate model applied to the market parameters.
+    #[storage_mapper_from_address("rate_model_type")]
+    fn rate_model_type(
+        &self,
+        liquidity_pool_address: ManagedAddress,
+    ) -> SingleValueMapper<RateModelType, ManagedAddress>;
+
+    /// Retrieves the reserve factor the pool applies above the optimal utilization.
+    /// An empty mapper keeps the reserve factor flat.
+    ///
+    /// # Returns
+    /// - `BigUint`: The elevated reserve factor, BPS-scaled.
+    #[storage_mapper_from_address("reserve_factor_high")]
+    fn reserve_factor_high(
+        &self,
+        liquidity_pool_address: ManagedAddress,
+    ) -> SingleValueMapper<BigUint, ManagedAddress>;
+
+    /// Retrieves the absolute annual borrow rate ceiling the pool enforces at accrual.
+    /// An empty mapper disables the ceiling.
+    ///
+    /// # Returns
+    /// - `BigUint`: The annual borrow rate ceiling, RAY-scaled.
+    #[storage_mapper_from_address("max_accrual_rate")]
+    fn max_accrual_rate(
+        &self,
+        liquidity_pool_address: ManagedAddress,
+    ) -> SingleValueMapper<BigUint, ManagedAddress>;
+
+    /// Retrieves the minimum time between two index recomputations of the pool.
+    /// An empty mapper recomputes the indexes on every sync.
+    ///
+    /// # Returns
+    /// - `DurationMillis`: The minimum accrual interval in milliseconds.
+    #[storage_mapper_from_address("min_accrual_interval")]
+    fn min_accrual_interval(
+        &self,
+        liquidity_pool_address: ManagedAddress,
+    ) -> SingleValueMapper<DurationMillis, ManagedAddress>;
+
+    /// Assembles the rate settings the pool keeps outside of its market parameters.
+    ///
+    /// # Returns
+    /// - `MarketRateSettings<Self::Api>`: The rate settings of the pool.
+    fn rate_settings(
+        &self,
+        liquidity_pool_address: ManagedAddress,
+    ) -> MarketRateSettings<Self::Api> {
+        MarketRateSettings {
+            rate_model_type: self.rate_model_type(liquidity_pool_address.clone()).get(),
+            reserve_factor_high_bps: ManagedDecimal::from_raw_units(
+                self.reserve_factor_high(liquidity_pool_address.clone())
+                    .get(),
+                BPS_PRECISION,
+            ),
+            max_accrual_rate_ray: ManagedDecimal::from_raw_units(
+                self.max_accrual_rate(liquidity_pool_address.clone()).get(),
+                RAY_PRECISION,
+            ),
+            min_accrual_interval_ms: self.min_accrual_interval(liquidity_pool_address).get(),
+        }
+    }
+
     /// Retrieves a timestamped price from the aggregator by token pair names.
     ///
     /// Arguments
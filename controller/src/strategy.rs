@@ -2,8 +2,10 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 use common_errors::{
-    ERROR_ASSETS_ARE_THE_SAME, ERROR_INVALID_PAYMENTS, ERROR_INVALID_POSITION_MODE,
-    ERROR_MULTIPLY_REQUIRE_EXTRA_STEPS, ERROR_SWAP_DEBT_NOT_SUPPORTED,
+    ERROR_ACCOUNT_ATTRIBUTES_MISMATCH, ERROR_ACCOUNT_NOT_IN_THE_MARKET, ERROR_ASSETS_ARE_THE_SAME,
+    ERROR_BATCH_ACCOUNTS_LIMIT_EXCEEDED, ERROR_DELEVERAGE_SWAP_SHORTFALL,
+    ERROR_INVALID_DELEVERAGE_FRACTION, ERROR_INVALID_PAYMENTS, ERROR_INVALID_POSITION_MODE,
+    ERROR_MULTIPLY_REQUIRE_EXTRA_STEPS, ERROR_REPAY_SWAP_NO_OUTPUT, ERROR_SWAP_DEBT_NOT_SUPPORTED,
 };
 use common_structs::{AccountAttributes, AccountPositionType, PositionMode};
 
@@ -47,7 +49,8 @@ pub trait SnapModule:
     /// increase their exposure to an asset without having the full collateral amount upfront.
     ///
     /// # Methodology and Process
-    /// 1. **Flash Loan Initiation**: Borrows `debt_to_flash_loan` amount of debt tokens from liquidity layer
+    /// 1. **Flash Loan Initiation**: Borrows `debt_to_flash_loan` amount of debt tokens from liquidity layer,
+    ///    deducting the flash loan fee and the optional `multiply_origination_fee_bps`
     /// 2. **Token Conversion**: Swaps the borrowed debt tokens to collateral tokens using the swap router
     /// 3. **Collateral Supply**: Deposits the received collateral tokens to the lending pool
     /// 4. **Position Management**: Creates or updates the user's position with new collateral and debt
@@ -97,6 +100,7 @@ pub trait SnapModule:
         optional_steps_payment: OptionalValue<ManagedArgBuffer<Self::Api>>,
     ) {
         self.require_not_paused();
+        self.require_debt_creation_enabled();
         // Initialize secure cache with price safety enabled
         let mut cache = Cache::new(self);
         cache.allow_unsafe_price = false; // Enforce secure price feeds only
@@ -221,11 +225,13 @@ pub trait SnapModule:
 
         // Execute flash loan borrow operation
         // This creates the debt position that will be backed by swapped collateral
+        let origination_fee_bps = self.to_decimal_bps(self.multiply_origination_fee_bps().get());
         let received_debt = self.handle_create_borrow_strategy(
             account_nonce,
             debt_token,
             &debt_to_flash_loan,
             &mut debt_config,
+            &origination_fee_bps,
             &caller,
             &nft_attributes,
             &mut cache,
@@ -245,6 +251,7 @@ pub trait SnapModule:
         final_collateral.amount += collateral_to_be_supplied.as_raw_units();
 
         // Deposit the final collateral amount to complete the leveraged position
+        let e_mode_id = nft_attributes.emode_id();
         self.process_deposit(
             &caller,
             account_nonce,
@@ -257,7 +264,7 @@ pub trait SnapModule:
         cache.clean_prices_cache();
         // CRITICAL: Validate position health after leverage creation
         // Ensures the position is not immediately liquidatable due to slippage or market conditions
-        self.validate_is_healthy(account_nonce, &mut cache, None);
+        self.validate_is_healthy_for_strategy(account_nonce, &mut cache);
 
         // Enforce final-state borrow position limits after multiply completes.
         self.validate_bulk_position_limits(
@@ -265,6 +272,14 @@ pub trait SnapModule:
             AccountPositionType::Borrow,
             &ManagedVec::new(),
         );
+        // The leveraged collateral must not back the new debt on its own in e-mode
+        self.ensure_e_mode_companion_collateral(
+            e_mode_id,
+            &self
+                .positions(account_nonce, AccountPositionType::Deposit)
+                .values()
+                .collect(),
+        );
 
         if let Some(initial_multiply_payment) = initial_multiply_payment {
             self.emit_initial_multiply_payment(
@@ -367,6 +382,7 @@ pub trait SnapModule:
             new_debt_token,
             new_debt_amount_raw,
             &mut debt_config,
+            &self.bps_zero(),
             &caller,
             &account_attributes,
             &mut cache,
@@ -409,7 +425,7 @@ pub trait SnapModule:
         cache.clean_prices_cache();
         // CRITICAL: Validate position health after debt swap completion
         // Ensures the position is not liquidatable due to swap slippage or price movements
-        self.validate_is_healthy(account.token_nonce, &mut cache, None);
+        self.validate_is_healthy_for_strategy(account.token_nonce, &mut cache);
 
         // Enforce final-state borrow position limits.
         self.validate_bulk_position_limits(
@@ -545,7 +561,7 @@ pub trait SnapModule:
         if !allow_unsafe_price {
             // CRITICAL: Validate position health after collateral swap completion
             // Ensures the position is not liquidatable due to swap slippage or different liquidation thresholds
-            self.validate_is_healthy(account.token_nonce, &mut cache, None);
+            self.validate_is_healthy_for_strategy(account.token_nonce, &mut cache);
         }
     }
 
@@ -701,6 +717,363 @@ pub trait SnapModule:
         self.manage_account_after_withdrawal(&account, &caller);
     }
 
+    /// **REPAY WITH SWAP: Repay Debt Using Funds in Another Token**
+    ///
+    /// # Purpose and Scope
+    /// Lets a user holding one token repay debt owed in another without swapping manually first.
+    /// Counterpart of `repayDebtWithCollateral` for funds sent with the call instead of collateral.
+    ///
+    /// # Methodology and Process
+    /// 1. **Payment Validation**: Requires a single payment in `payment_token` for an active account
+    /// 2. **Debt Validation**: Requires an open borrow position in `debt_token` before swapping
+    /// 3. **Token Conversion**: Swaps the payment to `debt_token` through the swap router
+    /// 4. **Debt Repayment**: Repays whatever the swap returned; any amount above the outstanding
+    ///    debt is refunded by the pool, and tokens the router returns unused are refunded to the caller
+    ///
+    /// # Security Checks Implemented
+    /// - **Reentrancy Protection**: Guards against flash loan reentrancy attacks
+    /// - **Token Validation**: Requires different payment and debt tokens
+    /// - **Swap Output Validation**: Reverts when the swap returns no debt token
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account whose debt is repaid
+    /// - `debt_token`: Debt token repaid
+    /// - `payment_token`: Token sent with the call and swapped to the debt token
+    /// - `steps`: Swap router steps for payment token to debt token conversion; empty uses
+    ///   the default steps configured for the pair
+    ///
+    /// # Payment
+    /// - A single payment in `payment_token`, optionally preceded by the account NFT,
+    ///   which then selects the account and is returned to the caller
+    ///
+    /// # Risk Considerations
+    /// - Swap slippage reduces the repaid amount; a partial repayment leaves the rest of the debt open
+    #[payable]
+    #[endpoint(repayWithSwap)]
+    fn repay_with_swap(
+        &self,
+        account_nonce: u64,
+        debt_token: &EgldOrEsdtTokenIdentifier,
+        payment_token: &EgldOrEsdtTokenIdentifier,
+        steps: ManagedArgBuffer<Self::Api>,
+    ) {
+        self.require_not_paused();
+        let mut cache = Cache::new(self);
+        self.reentrancy_guard(cache.flash_loan_ongoing);
+        require!(debt_token != payment_token, ERROR_ASSETS_ARE_THE_SAME);
+
+        let (payments, opt_account, caller, opt_attributes) =
+            self.validate_supply_payment(false, false, OptionalValue::Some(account_nonce));
+        // An attached account NFT is stripped from the payments and returned below
+        let has_account_payment = payments.len() < self.call_value().all_transfers().len();
+        require!(opt_account.is_some(), ERROR_ACCOUNT_NOT_IN_THE_MARKET);
+        require!(payments.len() == 1, ERROR_INVALID_PAYMENTS);
+
+        // Both values are set together whenever an account is resolved
+        let account = unsafe { opt_account.unwrap_unchecked() };
+        let account_attributes = unsafe { opt_attributes.unwrap_unchecked() };
+        let account_nonce = account.token_nonce;
+        let payment = payments.get(0);
+        require!(
+            payment.token_identifier == *payment_token,
+            ERROR_INVALID_PAYMENTS
+        );
+        self.validate_payment(&payment);
+        let _ = self.validate_borrow_position_existence(account_nonce, debt_token);
+
+        let received =
+            self.convert_token_from_to(debt_token, payment_token, &payment.amount, &caller, steps);
+        require!(received.amount > 0, ERROR_REPAY_SWAP_NO_OUTPUT);
+
+        let feed = self.token_price(debt_token, &mut cache);
+        let amount_wad = self.to_decimal(received.amount, feed.asset_decimals);
+        let egld_value_wad = self.token_egld_value(&amount_wad, &feed.price_wad);
+
+        self.process_repayment(
+            account_nonce,
+            debt_token,
+            &amount_wad,
+            &caller,
+            egld_value_wad,
+            &feed,
+            &mut cache,
+            &account_attributes,
+        );
+
+        if has_account_payment {
+            self.tx().to(&caller).payment(&account).transfer();
+        }
+    }
+
+    /// **DELEVERAGE STRATEGY: Partial or Full Unwind of a Leveraged Position**
+    ///
+    /// # Purpose and Scope
+    /// Symmetric counterpart of `multiply`: reduces the debt of a leveraged position by a fraction
+    /// in one transaction, paying it back with the position's own collateral.
+    ///
+    /// # Methodology and Process
+    /// 1. **Debt Sizing**: Computes the debt to repay as `fraction_bps` of the current debt
+    /// 2. **Collateral Withdrawal**: Withdraws collateral worth the same amount at oracle prices (no bonus)
+    /// 3. **Token Conversion**: Swaps the withdrawn collateral to the debt token via the swap router
+    /// 4. **Debt Repayment**: Repays the debt fraction, refunding swap surplus to the caller
+    /// 5. **Position Closure**: On a full deleverage, withdraws the remaining collateral and burns the NFT
+    ///    when no other positions remain
+    /// 6. **Health Validation**: Ensures a partially deleveraged position stays healthy
+    ///
+    /// # Mathematical Formula
+    /// Debt Repaid = Current Debt * fraction_bps / 10000
+    /// Collateral Withdrawn = Debt Repaid * Debt Price / Collateral Price
+    ///
+    /// # Security Checks Implemented
+    /// - **Reentrancy Protection**: Guards against flash loan reentrancy attacks
+    /// - **Token Validation**: Ensures collateral and debt tokens are different assets
+    /// - **Position Mode Validation**: Ensures the position is in leverage mode
+    /// - **Fraction Validation**: Requires a fraction between 1 and 10000 BPS
+    /// - **Repayment Coverage**: Swap output plus extra payments must cover the debt fraction
+    /// - **Health Factor Validation**: Verifies the remaining position is healthy
+    ///
+    /// # Arguments
+    /// - `collateral_token`: Collateral token withdrawn and swapped
+    /// - `debt_token`: Debt token repaid
+    /// - `fraction_bps`: Fraction of the debt to repay, in BPS (10000 closes the debt)
+    /// - `steps`: Swap router steps for collateral token to debt token conversion
+    ///
+    /// # Payment
+    /// - Account NFT, optionally followed by debt token payments covering swap slippage
+    ///
+    /// # Risk Considerations
+    /// - Swap fees and slippage make the swap output fall short of the debt fraction; the
+    ///   shortfall must be covered by extra debt token payments or the call reverts
+    #[payable]
+    #[endpoint(deleverage)]
+    fn deleverage(
+        &self,
+        collateral_token: &EgldOrEsdtTokenIdentifier,
+        debt_token: &EgldOrEsdtTokenIdentifier,
+        fraction_bps: BigUint,
+        steps: ManagedArgBuffer<Self::Api>,
+    ) {
+        self.require_not_paused();
+        let mut cache = Cache::new(self);
+        cache.allow_unsafe_price = false; // Enforce secure price feeds only
+        self.reentrancy_guard(cache.flash_loan_ongoing);
+
+        require!(collateral_token != debt_token, ERROR_ASSETS_ARE_THE_SAME);
+
+        let fraction = self.to_decimal_bps(fraction_bps);
+        require!(
+            fraction > self.bps_zero() && fraction <= self.bps(),
+            ERROR_INVALID_DELEVERAGE_FRACTION
+        );
+        let is_full_deleverage = fraction == self.bps();
+
+        // Parameters: require_account_payment=true, return_nft=false
+        let (payments, opt_account, caller, opt_attributes) =
+            self.validate_supply_payment(true, false, OptionalValue::None);
+        let account = unsafe { opt_account.unwrap_unchecked() };
+        let account_attributes = unsafe { opt_attributes.unwrap_unchecked() };
+
+        require!(
+            account_attributes.mode == PositionMode::Multiply
+                || account_attributes.mode == PositionMode::Long
+                || account_attributes.mode == PositionMode::Short,
+            ERROR_INVALID_POSITION_MODE
+        );
+
+        // Size the debt fraction and the collateral of equal value
+        let debt_feed = self.token_price(debt_token, &mut cache);
+        let borrow_position =
+            self.validate_borrow_position_existence(account.token_nonce, debt_token);
+        let total_debt = self.total_amount(&borrow_position, &debt_feed, &mut cache);
+        let debt_to_repay = if is_full_deleverage {
+            total_debt
+        } else {
+            self.mul_half_up(&total_debt, &fraction, debt_feed.asset_decimals)
+        };
+        let debt_egld_value = self.token_egld_value(&debt_to_repay, &debt_feed.price_wad);
+
+        let collateral_feed = self.token_price(collateral_token, &mut cache);
+        let deposit_position = self.deposit_position(account.token_nonce, collateral_token);
+        let deposited = self.total_amount(&deposit_position, &collateral_feed, &mut cache);
+        let collateral_removed = self.min(
+            self.convert_egld_to_tokens(&debt_egld_value, &collateral_feed),
+            deposited,
+        );
+
+        let mut received = self.common_swap_collateral(
+            collateral_token,
+            collateral_removed.as_raw_units().clone(),
+            debt_token,
+            steps,
+            account.token_nonce,
+            &caller,
+            &account_attributes,
+            &mut cache,
+        );
+
+        // Extra debt token payments cover the swap slippage
+        for payment in payments.iter() {
+            require!(
+                payment.token_identifier == *debt_token,
+                ERROR_INVALID_PAYMENTS
+            );
+            self.validate_payment(&payment);
+            received.amount += &payment.amount;
+        }
+        require!(
+            &received.amount >= debt_to_repay.as_raw_units(),
+            ERROR_DELEVERAGE_SWAP_SHORTFALL
+        );
+
+        self.process_repayment(
+            account.token_nonce,
+            debt_token,
+            &debt_to_repay,
+            &caller,
+            debt_egld_value,
+            &debt_feed,
+            &mut cache,
+            &account_attributes,
+        );
+
+        let surplus = &received.amount - debt_to_repay.as_raw_units();
+        self.tx()
+            .to(&caller)
+            .egld_or_single_esdt(debt_token, 0, &surplus)
+            .transfer_if_not_empty();
+
+        // Remove the prices from the cache to have a fresh value after the swaps to prevent a bad HF
+        cache.clean_prices_cache();
+
+        // CRITICAL: Validate position health after the partial unwind
+        self.validate_is_healthy(account.token_nonce, &mut cache, None);
+
+        let mut total_collateral_removed = collateral_removed;
+        let has_no_debt = self
+            .positions(account.token_nonce, AccountPositionType::Borrow)
+            .is_empty();
+        if is_full_deleverage && has_no_debt {
+            let opt_remaining = self
+                .positions(account.token_nonce, AccountPositionType::Deposit)
+                .get(collateral_token);
+            if let Some(mut remaining_position) = opt_remaining {
+                let price_feed = self.token_price(collateral_token, &mut cache);
+                let amount = self.total_amount(&remaining_position, &price_feed, &mut cache);
+                let withdrawn = self.process_withdrawal(
+                    account.token_nonce,
+                    amount,
+                    &caller,
+                    false,
+                    None,
+                    &mut cache,
+                    &account_attributes,
+                    &mut remaining_position,
+                    &price_feed,
+                );
+                total_collateral_removed +=
+                    &self.to_decimal(withdrawn.amount, price_feed.asset_decimals);
+            }
+        }
+
+        self.deleverage_event(
+            account.token_nonce,
+            collateral_token,
+            &total_collateral_removed,
+            debt_token,
+            &debt_to_repay,
+        );
+
+        // Manage account lifecycle (burns NFT if position is fully closed)
+        self.manage_account_after_withdrawal(&account, &caller);
+    }
+
+    /// **BATCH COLLATERAL TOP-UP: Multi-Account Supply**
+    ///
+    /// # Purpose and Scope
+    /// Lets users managing several accounts add collateral to all of them in a single call.
+    /// The operation is atomic: if any account or collateral payment is invalid, the whole
+    /// batch reverts and no account is modified.
+    ///
+    /// # Methodology and Process
+    /// 1. **Batch Validation**: Splits payments into account NFTs followed by one collateral payment per account
+    /// 2. **Limit Check**: Ensures the number of accounts does not exceed `max_batch_accounts`
+    /// 3. **Account Validation**: Verifies each NFT is active and its attributes match storage
+    /// 4. **Collateral Supply**: Deposits the i-th collateral payment into the i-th account
+    /// 5. **NFT Return**: Sends every account NFT back to the caller
+    ///
+    /// # Security Checks Implemented
+    /// - **Reentrancy Protection**: Guards against flash loan reentrancy attacks
+    /// - **Payment Layout Validation**: Requires exactly one collateral payment per account NFT
+    /// - **Account Validation**: Rejects inactive accounts and tampered attributes
+    /// - **Deposit Validation**: Applies the regular supply checks (caps, e-mode, isolation) to each account
+    ///
+    /// # Payment
+    /// - Account NFTs first, then one collateral payment per NFT in the same order
+    ///
+    /// # Risk Considerations
+    /// - Batch strategies are disabled until the owner sets `max_batch_accounts`
+    /// - A single invalid entry reverts the top-up of every account in the batch
+    #[payable]
+    #[endpoint(batchAddCollateral)]
+    fn batch_add_collateral(&self) {
+        self.require_not_paused();
+        let mut cache = Cache::new(self);
+        self.reentrancy_guard(cache.flash_loan_ongoing);
+
+        let caller = self.blockchain().get_caller();
+        self.require_non_zero_address(&caller);
+
+        let payments = self.call_value().all_transfers();
+        let account_token = self.account().get_token_id();
+
+        let mut accounts_count = 0;
+        while accounts_count < payments.len()
+            && account_token == payments.get(accounts_count).token_identifier
+        {
+            accounts_count += 1;
+        }
+
+        require!(
+            accounts_count > 0 && payments.len() == accounts_count * 2,
+            ERROR_INVALID_PAYMENTS
+        );
+        require!(
+            accounts_count <= self.max_batch_accounts().get(),
+            ERROR_BATCH_ACCOUNTS_LIMIT_EXCEEDED
+        );
+
+        let mut accounts = ManagedVec::<Self::Api, EsdtTokenPayment<Self::Api>>::new();
+        for index in 0..accounts_count {
+            let account = payments.get(index).clone().unwrap_esdt();
+            self.require_active_account(account.token_nonce);
+
+            let account_attributes = self.nft_attributes(&account);
+            require!(
+                account_attributes == self.account_attributes(account.token_nonce).get(),
+                ERROR_ACCOUNT_ATTRIBUTES_MISMATCH
+            );
+            self.set_account_holder(account.token_nonce, &caller);
+
+            let collateral = payments.get(accounts_count + index).clone();
+            require!(
+                account_token != collateral.token_identifier,
+                ERROR_INVALID_PAYMENTS
+            );
+
+            self.process_deposit(
+                &caller,
+                account.token_nonce,
+                account_attributes,
+                &ManagedVec::from_single_item(collateral),
+                &mut cache,
+            );
+
+            accounts.push(account);
+        }
+
+        self.tx().to(&caller).payment(accounts).transfer();
+    }
+
     /// **COMMON COLLATERAL SWAP HELPER: Withdraw and Convert Collateral**
     ///
     /// # Purpose and Scope
@@ -855,7 +1228,8 @@ pub trait SnapModule:
     /// - `from_token`: Source token being swapped
     /// - `from_amount`: Amount of source token to swap
     /// - `caller`: Address to receive any refunded tokens
-    /// - `args`: Raw swap router arguments (path, slippage, etc.)
+    /// - `args`: Raw swap router arguments (path, slippage, etc.); empty arguments fall back
+    ///   to the default steps configured for the pair
     ///
     /// # Returns
     /// - `EgldOrEsdtTokenPayment`: Payment containing the target token amount received
@@ -877,6 +1251,8 @@ pub trait SnapModule:
         caller: &ManagedAddress,
         args: ManagedArgBuffer<Self::Api>,
     ) -> EgldOrEsdtTokenPayment {
+        let args = self.swap_steps_or_default(from_token, wanted_token, args);
+
         self.flash_loan_ongoing().set(true);
 
         // Execute swap via external router with source tokens and configuration
@@ -920,6 +1296,26 @@ pub trait SnapModule:
         target_token_result
     }
 
+    /// Returns the provided swap steps, or the default steps configured for the pair
+    /// when none were provided and a default exists.
+    fn swap_steps_or_default(
+        &self,
+        from_token: &EgldOrEsdtTokenIdentifier,
+        to_token: &EgldOrEsdtTokenIdentifier,
+        steps: ManagedArgBuffer<Self::Api>,
+    ) -> ManagedArgBuffer<Self::Api> {
+        if !steps.is_empty() {
+            return steps;
+        }
+
+        let default_steps = self.default_swap_steps(from_token, to_token);
+        if default_steps.is_empty() {
+            return steps;
+        }
+
+        ManagedArgBuffer::from(default_steps.get())
+    }
+
     /// Emits event for initial multiply payment with token amount and USD value.
     /// Calculates USD equivalent through EGLD conversion for transparency.
     /// Records initial collateral contribution for leverage position tracking.
@@ -56,6 +56,57 @@ pub trait LendingUtilsModule:
         pool_address
     }
 
+    /// Retrieves the cumulative reward index of a market, zero before any reward distribution.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier of the market
+    ///
+    /// # Returns
+    /// - Rewards distributed per scaled unit supplied, in RAY precision
+    fn current_market_reward_index(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let mapper = self.market_reward_index(asset);
+        if mapper.is_empty() {
+            self.ray_zero()
+        } else {
+            mapper.get()
+        }
+    }
+
+    /// Accrues a reward distribution into the cumulative reward index of a market.
+    ///
+    /// **Purpose**: Rewards are folded into the supply index by the pool and cannot be told
+    /// apart from interest afterwards. Tracking them per scaled unit supplied lets each
+    /// deposit value the rewards it received over any period.
+    ///
+    /// **Mathematical Operations**:
+    /// ```
+    /// reward_index += reward / supplied_scaled
+    /// ```
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier of the market
+    /// - `pool_address`: Liquidity pool of the market, read after the distribution
+    /// - `reward`: Distributed reward in asset decimals
+    fn accrue_market_reward_index(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+        pool_address: ManagedAddress,
+        reward: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        let supplied = self.supplied(pool_address).get();
+        if supplied == self.ray_zero() {
+            return;
+        }
+
+        let reward_ray = self.rescale_half_up(reward, RAY_PRECISION);
+        let increase = self.div_half_up(&reward_ray, &supplied, RAY_PRECISION);
+        self.market_reward_index(asset)
+            .set(self.current_market_reward_index(asset) + increase);
+    }
+
     /// Calculates the current position amount by applying interest accrual to scaled amounts.
     ///
     /// **Purpose**: Converts a position's scaled amount to its current real value by applying
@@ -141,7 +192,7 @@ pub trait LendingUtilsModule:
     /// for health factor computation, liquidation decisions, and borrowing limit determination.
     ///
     /// **How it works**:
-    /// 1. Iterates through all collateral positions
+    /// 1. Iterates through all collateral positions, skipping supply-only deposits
     /// 2. Gets current position amounts using interest indices
     /// 3. Converts amounts to EGLD value using oracle prices
     /// 4. Applies different risk weightings for each metric
@@ -155,7 +206,7 @@ pub trait LendingUtilsModule:
     ///
     /// // Aggregated values:
     /// total_collateral += egld_value
-    /// weighted_collateral += egld_value * liquidation_threshold
+    /// weighted_collateral += egld_value * (1 - liquidation_haircut) * liquidation_threshold
     /// ltv_collateral += egld_value * loan_to_value
     /// ```
     ///
@@ -205,13 +256,22 @@ pub trait LendingUtilsModule:
         let mut ltv_collateral = self.ray_zero();
 
         for position in positions {
+            if self
+                .position_collateral_disabled(position.account_nonce, &position.asset_id)
+                .get()
+            {
+                continue;
+            }
+
             let price_feed = self.token_price(&position.asset_id, cache);
             let amount = self.total_amount_ray(&position, cache);
             let amount_egld = self.token_egld_value_ray(&amount, &price_feed.price_wad);
 
             total_collateral += &amount_egld;
+            let liquidation_value =
+                self.apply_liquidation_haircut(&position.asset_id, &amount_egld);
             weighted_collateral += self.mul_half_up(
-                &amount_egld,
+                &liquidation_value,
                 &position.liquidation_threshold_bps,
                 RAY_PRECISION,
             );
@@ -222,6 +282,29 @@ pub trait LendingUtilsModule:
         (weighted_collateral, total_collateral, ltv_collateral)
     }
 
+    /// Discounts a collateral value by the asset's liquidation haircut.
+    /// Thinly-traded collateral sells below its mark price during a liquidation; the haircut
+    /// accounts for that slippage in the liquidation collateral only, never in the LTV.
+    ///
+    /// # Arguments
+    /// - `asset_id`: Collateral token identifier.
+    /// - `value`: Collateral value in EGLD.
+    ///
+    /// # Returns
+    /// - Value after the haircut, at the precision of `value`.
+    fn apply_liquidation_haircut(
+        &self,
+        asset_id: &EgldOrEsdtTokenIdentifier,
+        value: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let haircut_bps = self.to_decimal_bps(self.liquidation_haircut_bps(asset_id).get());
+        if haircut_bps == self.bps_zero() {
+            return value.clone();
+        }
+
+        self.mul_half_up(value, &(self.bps() - haircut_bps), value.scale())
+    }
+
     /// Calculates the total borrow value in EGLD for a set of positions.
     /// Sums the EGLD value of all borrowed assets.
     ///
@@ -328,6 +411,40 @@ pub trait LendingUtilsModule:
         self.update_debt_ceiling_event(asset_id, debt_mapper.get());
     }
 
+    /// Adjusts the EGLD debt aggregate of an e-mode category.
+    /// Decreases are capped at the tracked amount so the aggregate never underflows.
+    ///
+    /// # Arguments
+    /// - `category_id`: E-mode category of the account, 0 for accounts without e-mode
+    /// - `amount_in_egld`: EGLD-denominated debt adjustment amount (WAD)
+    /// - `is_increase`: True for borrows, false for repayments
+    fn adjust_e_mode_debt_egld(
+        &self,
+        category_id: u8,
+        amount_in_egld: ManagedDecimal<Self::Api, NumDecimals>,
+        is_increase: bool,
+    ) {
+        if amount_in_egld.eq(&self.wad_zero()) {
+            return;
+        }
+
+        let debt_mapper = self.e_mode_debt_egld(category_id);
+        if debt_mapper.is_empty() {
+            debt_mapper.set(self.wad_zero());
+        }
+        if is_increase {
+            debt_mapper.update(|debt| *debt += amount_in_egld);
+        } else {
+            debt_mapper.update(|debt| {
+                *debt -= if *debt > amount_in_egld {
+                    amount_in_egld
+                } else {
+                    debt.clone()
+                };
+            });
+        }
+    }
+
     /// Efficiently manages borrow position updates for gas optimization.
     ///
     /// **Purpose**: Provides gas-efficient updating of borrow positions when bulk
@@ -402,6 +519,46 @@ pub trait LendingUtilsModule:
         }
     }
 
+    /// Merges borrow requests for the same token into a single request.
+    ///
+    /// **Purpose**: Keeps bulk borrows unambiguous. Each asset gets exactly one entry,
+    /// so `borrow_index_mapper` never sees the same ticker twice in one transaction
+    /// and position limits count every asset once.
+    ///
+    /// **How it works**:
+    /// 1. Tracks the index of each token in the merged list (stored as index + 1)
+    /// 2. For a token already seen: adds the amount to its existing entry
+    /// 3. For a new token: appends the request, keeping the first-seen order
+    ///
+    /// # Arguments
+    /// - `borrowed_tokens`: Requested borrows, possibly listing a token several times
+    ///
+    /// # Returns
+    /// - Borrow requests with one entry per token and summed amounts
+    fn merge_duplicate_borrows(
+        &self,
+        borrowed_tokens: &ManagedVec<EgldOrEsdtTokenPayment<Self::Api>>,
+    ) -> ManagedVec<EgldOrEsdtTokenPayment<Self::Api>> {
+        let mut merged = ManagedVec::<Self::Api, EgldOrEsdtTokenPayment<Self::Api>>::new();
+        let mut token_index_mapper =
+            ManagedMapEncoded::<Self::Api, EgldOrEsdtTokenIdentifier, usize>::new();
+
+        for borrowed_token in borrowed_tokens.iter() {
+            if token_index_mapper.contains(&borrowed_token.token_identifier) {
+                let index = token_index_mapper.get(&borrowed_token.token_identifier) - 1;
+                let mut existing = merged.get(index).clone();
+                existing.amount += &borrowed_token.amount;
+                let _ = merged.set(index, existing);
+            } else {
+                let safe_index = merged.len() + 1;
+                token_index_mapper.put(&borrowed_token.token_identifier, &safe_index);
+                merged.push(borrowed_token.clone());
+            }
+        }
+
+        merged
+    }
+
     /// Verifies that a position remains healthy after withdrawal operations.
     ///
     /// **Purpose**: Critical safety check that prevents withdrawals which would
@@ -465,21 +622,66 @@ pub trait LendingUtilsModule:
             return;
         }
 
+        let min_health_factor = match safety_factor {
+            Some(safety_factor_value) => self.ray() + (self.ray() / safety_factor_value),
+            None => self.ray(),
+        };
+
+        self.validate_min_health_factor(
+            account_nonce,
+            cache,
+            &min_health_factor,
+            ERROR_HEALTH_FACTOR_WITHDRAW,
+        );
+    }
+
+    /// Validates that a position stays above the strategy health factor after leverage changes.
+    ///
+    /// Uses the owner-configured `min_strategy_hf_ray`, never going below the regular 1.0
+    /// threshold, so `multiply`, `swap_debt` and `swap_collateral` leave a liquidation buffer.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce identifying the position to validate
+    /// - `cache`: Performance cache for price feeds and indices
+    ///
+    /// # Errors
+    /// - `ERROR_HEALTH_FACTOR_WITHDRAW`: Health factor below the strategy minimum
+    fn validate_is_healthy_for_strategy(&self, account_nonce: u64, cache: &mut Cache<Self>) {
+        let borrow_positions = self.positions(account_nonce, AccountPositionType::Borrow);
+        if borrow_positions.is_empty() {
+            return;
+        }
+
+        let min_health_factor = self.max(
+            self.ray(),
+            self.to_decimal_ray(self.min_strategy_hf_ray().get()),
+        );
+
+        self.validate_min_health_factor(
+            account_nonce,
+            cache,
+            &min_health_factor,
+            ERROR_HEALTH_FACTOR_WITHDRAW,
+        );
+    }
+
+    /// Computes the account health factor and requires it to reach `min_health_factor`,
+    /// reverting with `error` otherwise.
+    fn validate_min_health_factor(
+        &self,
+        account_nonce: u64,
+        cache: &mut Cache<Self>,
+        min_health_factor: &ManagedDecimal<Self::Api, NumDecimals>,
+        error: &'static [u8],
+    ) {
         let deposit_positions = self.positions(account_nonce, AccountPositionType::Deposit);
+        let borrow_positions = self.positions(account_nonce, AccountPositionType::Borrow);
         let (collateral, _, _) =
             self.calculate_collateral_values(&deposit_positions.values().collect(), cache);
         let borrowed =
             self.calculate_total_borrow_in_egld(&borrow_positions.values().collect(), cache);
         let health_factor = self.compute_health_factor(&collateral, &borrowed);
 
-        let min_health_factor = match safety_factor {
-            Some(safety_factor_value) => self.ray() + (self.ray() / safety_factor_value),
-            None => self.ray(),
-        };
-
-        require!(
-            health_factor >= min_health_factor,
-            ERROR_HEALTH_FACTOR_WITHDRAW
-        );
+        require!(health_factor >= *min_health_factor, error);
     }
 }
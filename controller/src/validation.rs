@@ -1,8 +1,11 @@
 multiversx_sc::imports!();
 
+use common_constants::BPS;
 use common_errors::{
-    ERROR_FLASH_LOAN_ALREADY_ONGOING, ERROR_INVALID_ENDPOINT, ERROR_INVALID_SHARD,
-    ERROR_NOT_A_SMART_CONTRACT, ERROR_POSITION_LIMIT_EXCEEDED,
+    ERROR_DEBT_CREATION_DISABLED, ERROR_FLASH_LOAN_ALREADY_ONGOING, ERROR_INVALID_ENDPOINT,
+    ERROR_INVALID_LIQUIDATION_CLOSE_FACTOR, ERROR_INVALID_SHARD, ERROR_MARKET_PAUSED,
+    ERROR_NEW_BORROW_DISABLED, ERROR_NEW_SUPPLY_DISABLED, ERROR_NOT_A_SMART_CONTRACT,
+    ERROR_POSITION_LIMIT_EXCEEDED,
 };
 
 use crate::{
@@ -119,6 +122,73 @@ pub trait ValidationModule:
         );
     }
 
+    /// Ensures the global kill-switch allows new debt to be created.
+    /// Guards `borrow`, `multiply` and `flashLoan`; repayments, withdrawals and
+    /// liquidations are never blocked.
+    ///
+    /// # Errors
+    /// - `ERROR_DEBT_CREATION_DISABLED`: If debt creation is disabled protocol-wide.
+    fn require_debt_creation_enabled(&self) {
+        require!(
+            !self.debt_creation_disabled().get(),
+            ERROR_DEBT_CREATION_DISABLED
+        );
+    }
+
+    /// Ensures a market is not paused for new supplies and borrows.
+    /// Guards `process_deposit` and `process_borrow`; repayments and withdrawals
+    /// of a paused market are never blocked.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier of the market
+    ///
+    /// # Errors
+    /// - `ERROR_MARKET_PAUSED`: If the market is paused.
+    fn require_market_not_paused(&self, asset: &EgldOrEsdtTokenIdentifier) {
+        require!(!self.market_paused(asset).get(), ERROR_MARKET_PAUSED);
+    }
+
+    /// Ensures new supplies of an asset are enabled.
+    /// Guards `process_deposit`; existing deposits of the asset are never affected.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier of the market
+    ///
+    /// # Errors
+    /// - `ERROR_NEW_SUPPLY_DISABLED`: If new supplies of the asset are disabled.
+    fn require_new_supply_enabled(&self, asset: &EgldOrEsdtTokenIdentifier) {
+        require!(
+            !self.new_supply_disabled(asset).get(),
+            ERROR_NEW_SUPPLY_DISABLED
+        );
+    }
+
+    /// Ensures new borrows of an asset are enabled.
+    /// Guards `process_borrow`; existing debt of the asset is never affected.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier of the market
+    ///
+    /// # Errors
+    /// - `ERROR_NEW_BORROW_DISABLED`: If new borrows of the asset are disabled.
+    fn require_new_borrow_enabled(&self, asset: &EgldOrEsdtTokenIdentifier) {
+        require!(
+            !self.new_borrow_disabled(asset).get(),
+            ERROR_NEW_BORROW_DISABLED
+        );
+    }
+
+    /// Ensures a liquidation close factor lies within `(0, 10000]` basis points.
+    ///
+    /// # Errors
+    /// - `ERROR_INVALID_LIQUIDATION_CLOSE_FACTOR`: If the close factor is zero or above 100%.
+    fn validate_liquidation_close_factor(&self, close_factor: &BigUint) {
+        require!(
+            close_factor > &BigUint::zero() && close_factor <= &BigUint::from(BPS),
+            ERROR_INVALID_LIQUIDATION_CLOSE_FACTOR
+        );
+    }
+
     // --- Helper Functions ---
 
     /// Validates the flash loan target is a smart contract on the same shard.
@@ -286,6 +356,13 @@ pub trait ValidationModule:
         require!(!flash_loan_ongoing, ERROR_FLASH_LOAN_ALREADY_ONGOING);
     }
 
+    /// Returns whether an accumulator is configured.
+    /// A zero address counts as unset.
+    fn is_accumulator_set(&self) -> bool {
+        let accumulator_address_mapper = self.accumulator_address();
+        !accumulator_address_mapper.is_empty() && !accumulator_address_mapper.get().is_zero()
+    }
+
     /// Validates position count limits for bulk operations (multiple positions in one transaction).
     ///
     /// **Purpose**: Enforces governance-controlled limits on the number of positions
@@ -302,6 +379,8 @@ pub trait ValidationModule:
     /// 4. Validates total (existing + new) would not exceed the configured limit
     /// 5. Reverts if limit would be exceeded, allows creation otherwise
     ///
+    /// Protocol-owned accounts (see `protocol_owned_accounts`) skip this check entirely.
+    ///
     /// **Example scenarios**:
     /// - Limit: 10 supply positions
     /// - Current: 9 supply positions  
@@ -331,6 +410,10 @@ pub trait ValidationModule:
         position_type: AccountPositionType,
         payments: &ManagedVec<EgldOrEsdtTokenPayment<Self::Api>>,
     ) {
+        if self.protocol_owned_accounts().contains(&account_nonce) {
+            return;
+        }
+
         let limits = self.position_limits().get();
         let positions_map = self.positions(account_nonce, position_type.clone());
         let current_count = positions_map.len();
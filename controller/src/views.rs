@@ -1,7 +1,12 @@
-use common_constants::{BPS_PRECISION, WAD_PRECISION};
+use common_constants::{BPS_PRECISION, MILLISECONDS_PER_YEAR, RAY_PRECISION, WAD_PRECISION};
+use common_errors::{
+    ERROR_ASSETS_ARE_THE_SAME, ERROR_ASSET_NOT_SUPPORTED, ERROR_INDEX_SNAPSHOT_NOT_FOUND,
+};
 use common_structs::{
-    AccountPositionType, AssetExtendedConfigView, LiquidationEstimate, MarketIndexExtendedView,
-    MarketIndexView,
+    AccountAttributes, AccountPosition, AccountPositionType, AssetConfig, AssetExtendedConfigView,
+    CapDenomination, DepositYieldBreakdown, IndexSnapshot, LiquidationEstimate, LiquidationRecord,
+    MarketActivity, MarketActivityType, MarketIndexExtendedView, MarketIndexView,
+    MarketRateSpreadView, UserAccountSummary,
 };
 
 use crate::{cache::Cache, helpers, oracle, positions, storage, utils, validation};
@@ -29,7 +34,9 @@ pub trait ViewsModule:
     /// Estimates liquidation outcomes for a proposed set of debt payments.
     ///
     /// Purpose: Simulate liquidation to preview seized collateral, protocol fees,
-    /// refunds, and applied bonus, without mutating state.
+    /// refunds, and applied bonus, without mutating state. Also reports whether the
+    /// liquidation is profitable and how much of each debt token can be repaid, so a
+    /// bot can size its payment without re-deriving the bonus math.
     ///
     /// Arguments
     /// - `account_nonce`: Account NFT nonce to simulate
@@ -37,7 +44,8 @@ pub trait ViewsModule:
     ///
     /// Returns
     /// - `LiquidationEstimate` with seized collaterals, protocol fees, refunds,
-    ///   max repay amount (WAD), and bonus rate (BPS)
+    ///   max repay amount (WAD), bonus rate (BPS), liquidator profit (WAD) and
+    ///   the max repayable amount per debt token
     #[view(liquidationEstimations)]
     fn liquidation_estimations(
         &self,
@@ -47,13 +55,27 @@ pub trait ViewsModule:
         let mut cache = Cache::new(self);
         self.require_active_account(account_nonce);
 
-        let (collaterals, _, refunds, max_egld_payment_ray, bonus_rate_ray) =
-            self.execute_liquidation(account_nonce, debt_payments, true, &mut cache);
+        let (collaterals, _, refunds, max_egld_payment_ray, bonus_rate_ray) = self
+            .execute_liquidation(
+                account_nonce,
+                debt_payments,
+                &ManagedVec::new(),
+                true,
+                &mut cache,
+            );
 
         let mut seized_collaterals = ManagedVec::new();
         let mut protocol_fees = ManagedVec::new();
+        let mut seized_net_egld_wad = self.wad_zero();
         for collateral in collaterals {
             let (seized_collateral, fees) = collateral.into_tuple();
+            let feed = self.token_price(&seized_collateral.token_identifier, &mut cache);
+            let net_amount = self.to_decimal(
+                &seized_collateral.amount - fees.as_raw_units(),
+                feed.asset_decimals,
+            );
+            seized_net_egld_wad += self.token_egld_value(&net_amount, &feed.price_wad);
+
             let collateral_view = EgldOrEsdtTokenPayment::new(
                 seized_collateral.token_identifier.clone(),
                 seized_collateral.token_nonce,
@@ -68,12 +90,41 @@ pub trait ViewsModule:
             protocol_fees.push(protocol_fees_view);
         }
 
+        let max_egld_payment_wad = self.rescale_half_up(&max_egld_payment_ray, WAD_PRECISION);
+        let liquidator_profit_egld_wad = if seized_net_egld_wad > max_egld_payment_wad {
+            seized_net_egld_wad - max_egld_payment_wad.clone()
+        } else {
+            self.wad_zero()
+        };
+
+        let mut max_repayable_per_debt_token = ManagedVec::new();
+        for position in self
+            .positions(account_nonce, AccountPositionType::Borrow)
+            .values()
+        {
+            let feed = self.token_price(&position.asset_id, &mut cache);
+            let debt_ray = self.total_amount_ray(&position, &mut cache);
+            let repayable_ray = self.min(
+                self.close_factor_repayable_debt(&position.asset_id, debt_ray),
+                self.convert_egld_to_tokens_ray(&max_egld_payment_ray, &feed),
+            );
+            max_repayable_per_debt_token.push(EgldOrEsdtTokenPayment::new(
+                position.asset_id,
+                0,
+                self.rescale_half_up(&repayable_ray, feed.asset_decimals)
+                    .as_raw_units()
+                    .clone(),
+            ));
+        }
+
         LiquidationEstimate {
             seized_collaterals,
             protocol_fees,
             refunds,
-            max_egld_payment_wad: self.rescale_half_up(&max_egld_payment_ray, WAD_PRECISION),
+            max_egld_payment_wad,
             bonus_rate_bps: self.rescale_half_up(&bonus_rate_ray, BPS_PRECISION),
+            liquidator_profit_egld_wad,
+            max_repayable_per_debt_token,
         }
     }
 
@@ -224,6 +275,8 @@ pub trait ViewsModule:
 
     /// Determines if an account position is eligible for liquidation.
     /// Checks if the health factor is below 1 (100% in WAD precision).
+    /// Accounts within the grace period that follows a liquidation threshold reduction
+    /// are never eligible.
     ///
     /// # Arguments
     /// - `account_nonce`: NFT nonce of the account position.
@@ -232,12 +285,17 @@ pub trait ViewsModule:
     /// - `bool`: `true` if the position can be liquidated.
     #[view(canBeLiquidated)]
     fn can_be_liquidated(&self, account_nonce: u64) -> bool {
+        if self.is_within_liquidation_grace(account_nonce) {
+            return false;
+        }
+
         let health_factor = self.health_factor(account_nonce);
         health_factor < self.ray()
     }
 
     /// Computes the current health factor for an account position.
     /// Indicates position safety; lower values increase liquidation risk.
+    /// Reads indexes as of the last market sync when view index sync is disabled.
     ///
     /// # Arguments
     /// - `account_nonce`: NFT nonce of the account position.
@@ -247,19 +305,9 @@ pub trait ViewsModule:
     #[view(getHealthFactor)]
     fn health_factor(&self, account_nonce: u64) -> ManagedDecimal<Self::Api, NumDecimals> {
         let mut cache = Cache::new(self);
-        let deposit_positions = self.positions(account_nonce, AccountPositionType::Deposit);
-
-        let (weighted_collateral, _, _) =
-            self.calculate_collateral_values(&deposit_positions.values().collect(), &mut cache);
-
-        let borrow_positions = self
-            .positions(account_nonce, AccountPositionType::Borrow)
-            .values()
-            .collect();
-
-        let total_borrow_ray = self.calculate_total_borrow_in_egld(&borrow_positions, &mut cache);
+        cache.sync_indexes = !self.view_index_sync_disabled().get();
 
-        self.compute_health_factor(&weighted_collateral, &total_borrow_ray)
+        self.account_health_factor(account_nonce, &mut cache)
     }
 
     /// Retrieves the collateral amount for a specific token in an account position.
@@ -341,7 +389,7 @@ pub trait ViewsModule:
     }
 
     /// Computes the total collateral value in EGLD for an account position.
-    /// Sums the EGLD value of all collateral assets (unweighted).
+    /// Sums the EGLD value of all collateral assets (unweighted), skipping supply-only deposits.
     ///
     /// # Arguments
     /// - `account_nonce`: NFT nonce of the account position.
@@ -359,6 +407,11 @@ pub trait ViewsModule:
 
         deposit_positions
             .values()
+            .filter(|dp| {
+                !self
+                    .position_collateral_disabled(account_nonce, &dp.asset_id)
+                    .get()
+            })
             .fold(self.wad_zero(), |accumulator, dp| {
                 let feed = self.token_price(&dp.asset_id, &mut cache);
                 let amount = self.total_amount_ray(&dp, &mut cache);
@@ -446,4 +499,2005 @@ pub trait ViewsModule:
 
         data.price_wad
     }
+
+    /// Simulates a full liquidation from the account holder's point of view.
+    ///
+    /// Purpose: Show users their exposure by previewing the collateral they would lose,
+    /// the debt that would be cleared, and the health factor left afterwards when a
+    /// liquidator repays the maximum allowed debt. Does not mutate state.
+    ///
+    /// Arguments
+    /// - `account_nonce`: Account NFT nonce to simulate
+    ///
+    /// Returns
+    /// - `collateral_lost`: EGLD value of the seized collateral, bonus included (WAD)
+    /// - `debt_repaid`: EGLD value of the debt repaid by the liquidator (WAD)
+    /// - `resulting_hf`: Health factor of the account after the liquidation (RAY)
+    ///
+    /// Panics
+    /// - If the account is not active or is not liquidatable
+    #[view(simulateLiquidationImpact)]
+    fn simulate_liquidation_impact(
+        &self,
+        account_nonce: u64,
+    ) -> MultiValue3<
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+    > {
+        let mut cache = Cache::new(self);
+        self.require_active_account(account_nonce);
+
+        let (seized_collaterals, _, _, debt_repaid_ray, _) = self.execute_liquidation(
+            account_nonce,
+            &ManagedVec::new(),
+            &ManagedVec::new(),
+            true,
+            &mut cache,
+        );
+
+        let mut collateral_lost_ray = self.ray_zero();
+        let mut weighted_collateral_ray = self.ray_zero();
+        for position in self
+            .positions(account_nonce, AccountPositionType::Deposit)
+            .values()
+        {
+            if self
+                .position_collateral_disabled(position.account_nonce, &position.asset_id)
+                .get()
+            {
+                continue;
+            }
+            let feed = self.token_price(&position.asset_id, &mut cache);
+            let mut remaining_ray = self.total_amount_ray(&position, &mut cache);
+            for collateral in seized_collaterals.iter() {
+                let (seized_collateral, _) = collateral.clone().into_tuple();
+                if seized_collateral.token_identifier != position.asset_id {
+                    continue;
+                }
+                let seized_ray = self.rescale_half_up(
+                    &self.to_decimal(seized_collateral.amount, feed.asset_decimals),
+                    RAY_PRECISION,
+                );
+                let lost_ray = self.min(seized_ray, remaining_ray.clone());
+                collateral_lost_ray += self.token_egld_value_ray(&lost_ray, &feed.price_wad);
+                remaining_ray -= lost_ray;
+            }
+
+            let remaining_egld_ray = self.token_egld_value_ray(&remaining_ray, &feed.price_wad);
+            weighted_collateral_ray += self.mul_half_up(
+                &remaining_egld_ray,
+                &position.liquidation_threshold_bps,
+                RAY_PRECISION,
+            );
+        }
+
+        let borrow_positions = self
+            .positions(account_nonce, AccountPositionType::Borrow)
+            .values()
+            .collect();
+        let borrowed_ray = self.calculate_total_borrow_in_egld(&borrow_positions, &mut cache);
+        let remaining_debt_ray =
+            borrowed_ray.clone() - self.min(debt_repaid_ray.clone(), borrowed_ray);
+
+        let resulting_hf =
+            self.compute_health_factor(&weighted_collateral_ray, &remaining_debt_ray);
+
+        (
+            self.rescale_half_up(&collateral_lost_ray, WAD_PRECISION),
+            self.rescale_half_up(&debt_repaid_ray, WAD_PRECISION),
+            resulting_hf,
+        )
+            .into()
+    }
+
+    /// Previews the outcome of a `multiply` call without executing it.
+    ///
+    /// Purpose: Let users size a leveraged position before opening it by projecting the
+    /// final collateral, debt, leverage and health factor for a given flash loan amount
+    /// and the swap output they expect from the router. Does not mutate state.
+    ///
+    /// Methodology:
+    /// 1. Starts from the account's current collateral and debt (empty for a new account)
+    /// 2. Adds `expected_swap_out` of collateral, weighted by its e-mode aware threshold
+    /// 3. Adds `debt_to_flash_loan` of debt, as recorded by the flash borrow
+    /// 4. Leverage = collateral / (collateral - debt)
+    ///
+    /// Arguments
+    /// - `account_nonce`: Existing account to lever up, or 0 for a new account without e-mode
+    /// - `collateral_token`: Token deposited after the swap
+    /// - `debt_token`: Token borrowed through the flash loan
+    /// - `debt_to_flash_loan`: Debt amount to borrow (raw units)
+    /// - `expected_swap_out`: Collateral amount expected from the swap (raw units)
+    ///
+    /// Returns
+    /// - `final_collateral`: EGLD value of the account collateral after the multiply (WAD)
+    /// - `final_debt`: EGLD value of the account debt after the multiply (WAD)
+    /// - `leverage`: Collateral over equity (RAY); zero when the debt exceeds the collateral
+    /// - `health_factor`: Projected health factor (RAY)
+    #[view(previewMultiply)]
+    fn preview_multiply(
+        &self,
+        account_nonce: u64,
+        collateral_token: &EgldOrEsdtTokenIdentifier,
+        debt_token: &EgldOrEsdtTokenIdentifier,
+        debt_to_flash_loan: BigUint,
+        expected_swap_out: BigUint,
+    ) -> MultiValue4<
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+    > {
+        let mut cache = Cache::new(self);
+        require!(collateral_token != debt_token, ERROR_ASSETS_ARE_THE_SAME);
+        let _ = self.require_asset_supported(collateral_token);
+        let _ = self.require_asset_supported(debt_token);
+
+        let mut weighted_collateral_ray = self.ray_zero();
+        let mut total_collateral_ray = self.ray_zero();
+        let mut total_debt_ray = self.ray_zero();
+        let mut e_mode_id = 0;
+        let mut existing_threshold = None;
+        if account_nonce != 0 {
+            self.require_active_account(account_nonce);
+            e_mode_id = self.account_attributes(account_nonce).get().emode_id();
+
+            let deposit_positions = self.positions(account_nonce, AccountPositionType::Deposit);
+            existing_threshold = deposit_positions
+                .get(collateral_token)
+                .map(|position| position.liquidation_threshold_bps);
+            let (weighted, total, _) =
+                self.calculate_collateral_values(&deposit_positions.values().collect(), &mut cache);
+            weighted_collateral_ray = weighted;
+            total_collateral_ray = total;
+
+            let borrow_positions = self
+                .positions(account_nonce, AccountPositionType::Borrow)
+                .values()
+                .collect();
+            total_debt_ray = self.calculate_total_borrow_in_egld(&borrow_positions, &mut cache);
+        }
+
+        // Existing deposits keep their stored threshold, new ones take the e-mode aware config
+        let liquidation_threshold = match existing_threshold {
+            Some(threshold) => threshold,
+            None => {
+                let mut asset_info = cache.cached_asset_info(collateral_token);
+                let asset_emode_config = self.token_e_mode_config(e_mode_id, collateral_token);
+                self.apply_e_mode_to_asset_config(
+                    &mut asset_info,
+                    &self.e_mode_category(e_mode_id),
+                    asset_emode_config,
+                );
+                asset_info.liquidation_threshold_bps
+            },
+        };
+
+        let collateral_feed = self.token_price(collateral_token, &mut cache);
+        let collateral_ray = self.rescale_half_up(
+            &self.to_decimal(expected_swap_out, collateral_feed.asset_decimals),
+            RAY_PRECISION,
+        );
+        let collateral_egld_ray =
+            self.token_egld_value_ray(&collateral_ray, &collateral_feed.price_wad);
+        weighted_collateral_ray +=
+            self.mul_half_up(&collateral_egld_ray, &liquidation_threshold, RAY_PRECISION);
+        total_collateral_ray += collateral_egld_ray;
+
+        let debt_feed = self.token_price(debt_token, &mut cache);
+        let debt_ray = self.rescale_half_up(
+            &self.to_decimal(debt_to_flash_loan, debt_feed.asset_decimals),
+            RAY_PRECISION,
+        );
+        total_debt_ray += self.token_egld_value_ray(&debt_ray, &debt_feed.price_wad);
+
+        let leverage = if total_collateral_ray > total_debt_ray {
+            let equity_ray = total_collateral_ray.clone() - total_debt_ray.clone();
+            self.div_half_up(&total_collateral_ray, &equity_ray, RAY_PRECISION)
+        } else {
+            self.ray_zero()
+        };
+        let health_factor = self.compute_health_factor(&weighted_collateral_ray, &total_debt_ray);
+
+        (
+            self.rescale_half_up(&total_collateral_ray, WAD_PRECISION),
+            self.rescale_half_up(&total_debt_ray, WAD_PRECISION),
+            leverage,
+            health_factor,
+        )
+            .into()
+    }
+
+    /// Computes the total value locked across all markets of the protocol.
+    /// Sums the supplied amount of every market, including accrued interest, at current prices.
+    /// Reads the per-market supply aggregates kept by the pools, so no account is scanned.
+    ///
+    /// # Returns
+    /// - `MultiValue2` of the total value locked in EGLD and in USD, both in WAD precision.
+    #[view(getTotalValueLocked)]
+    fn total_value_locked(
+        &self,
+    ) -> MultiValue2<ManagedDecimal<Self::Api, NumDecimals>, ManagedDecimal<Self::Api, NumDecimals>>
+    {
+        let mut cache = Cache::new(self);
+        let mut total_egld_ray = self.ray_zero();
+
+        for pool_address in self.pools().iter() {
+            let asset_id = self.parameters(pool_address.clone()).get().asset_id;
+            let indexes = cache.cached_market_index(&asset_id);
+            let supplied_ray = self.scaled_to_original_ray(
+                &self.supplied(pool_address).get(),
+                &indexes.supply_index_ray,
+            );
+            let feed = self.token_price(&asset_id, &mut cache);
+            total_egld_ray += self.token_egld_value_ray(&supplied_ray, &feed.price_wad);
+        }
+
+        let total_egld = self.rescale_half_up(&total_egld_ray, WAD_PRECISION);
+        let total_usd = self.egld_usd_value(&total_egld, &cache.egld_usd_price_wad);
+
+        (total_egld, total_usd).into()
+    }
+
+    /// Computes the protocol-wide collateral and debt across all markets.
+    /// Sums the supplied and borrowed amounts of every market, including accrued interest,
+    /// valued at current prices within a single cache so every market is priced consistently.
+    ///
+    /// # Returns
+    /// - `MultiValue2` of the total collateral and total debt in EGLD, both in WAD precision.
+    #[view(getProtocolTotals)]
+    fn protocol_totals(
+        &self,
+    ) -> MultiValue2<ManagedDecimal<Self::Api, NumDecimals>, ManagedDecimal<Self::Api, NumDecimals>>
+    {
+        let mut cache = Cache::new(self);
+        let mut total_collateral_egld_ray = self.ray_zero();
+        let mut total_debt_egld_ray = self.ray_zero();
+
+        for pool_address in self.pools().iter() {
+            let asset_id = self.parameters(pool_address.clone()).get().asset_id;
+            let indexes = cache.cached_market_index(&asset_id);
+            let feed = self.token_price(&asset_id, &mut cache);
+
+            let supplied_ray = self.scaled_to_original_ray(
+                &self.supplied(pool_address.clone()).get(),
+                &indexes.supply_index_ray,
+            );
+            let borrowed_ray = self.scaled_to_original_ray(
+                &self.borrowed(pool_address).get(),
+                &indexes.borrow_index_ray,
+            );
+            total_collateral_egld_ray += self.token_egld_value_ray(&supplied_ray, &feed.price_wad);
+            total_debt_egld_ray += self.token_egld_value_ray(&borrowed_ray, &feed.price_wad);
+        }
+
+        (
+            self.rescale_half_up(&total_collateral_egld_ray, WAD_PRECISION),
+            self.rescale_half_up(&total_debt_egld_ray, WAD_PRECISION),
+        )
+            .into()
+    }
+
+    /// Retrieves the outstanding debt in EGLD grouped by e-mode category.
+    /// Category 0 aggregates accounts without e-mode, so risk teams can compare the
+    /// exposure held in high-leverage categories against regular borrowing.
+    /// Debt is valued at borrow, repay and e-mode roll time; accrued interest and later price moves
+    /// are not reflected.
+    ///
+    /// # Returns
+    /// - `MultiValueEncoded` of `(e_mode_category_id, debt)` pairs for every category up to the
+    ///   last one created, with the debt in EGLD (WAD precision).
+    #[view(getAggregateBorrowByEMode)]
+    fn aggregate_borrow_by_e_mode(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<u8, ManagedDecimal<Self::Api, NumDecimals>>> {
+        let mut aggregates = MultiValueEncoded::new();
+        for category_id in 0..=self.last_e_mode_category_id().get() {
+            let debt_mapper = self.e_mode_debt_egld(category_id);
+            let debt = if debt_mapper.is_empty() {
+                self.wad_zero()
+            } else {
+                debt_mapper.get()
+            };
+            aggregates.push((category_id, debt).into());
+        }
+
+        aggregates
+    }
+
+    /// Computes how much of a market's supply cap is already used.
+    /// Divides the current supplied amount, including accrued interest, by the supply cap.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market.
+    ///
+    /// # Returns
+    /// - Supply cap utilization as a `ManagedDecimal` in BPS precision; zero when uncapped.
+    #[view(getSupplyCapUtilization)]
+    fn supply_cap_utilization(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let mut cache = Cache::new(self);
+        let pool_address = cache.cached_pool_address(asset);
+        match cache.cached_asset_info(asset).supply_cap_wad {
+            Some(supply_cap) => {
+                let indexes = cache.cached_market_index(asset);
+                self.cap_utilization(
+                    pool_address.clone(),
+                    &self.supplied(pool_address).get(),
+                    &indexes.supply_index_ray,
+                    supply_cap,
+                )
+            },
+            None => self.to_decimal_bps(BigUint::zero()),
+        }
+    }
+
+    /// Computes how much of a market's borrow cap is already used.
+    /// Divides the current borrowed amount, including accrued interest, by the borrow cap.
+    /// EGLD-denominated caps compare the EGLD value of the borrowed amount at the current price.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market.
+    ///
+    /// # Returns
+    /// - Borrow cap utilization as a `ManagedDecimal` in BPS precision; zero when uncapped.
+    #[view(getBorrowCapUtilization)]
+    fn borrow_cap_utilization(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let mut cache = Cache::new(self);
+        let pool_address = cache.cached_pool_address(asset);
+        let asset_config = cache.cached_asset_info(asset);
+        match asset_config.borrow_cap_wad {
+            Some(borrow_cap) => {
+                let indexes = cache.cached_market_index(asset);
+                match self.cap_denomination(asset) {
+                    CapDenomination::Token => self.cap_utilization(
+                        pool_address.clone(),
+                        &self.borrowed(pool_address).get(),
+                        &indexes.borrow_index_ray,
+                        borrow_cap,
+                    ),
+                    CapDenomination::Egld => {
+                        let feed = self.token_price(asset, &mut cache);
+                        let borrowed = self.scaled_to_original(
+                            &self.borrowed(pool_address).get(),
+                            &indexes.borrow_index_ray,
+                            feed.asset_decimals,
+                        );
+                        let borrowed_egld = self.token_egld_value(&borrowed, &feed.price_wad);
+                        let utilization_ray = self.div_half_up(
+                            &borrowed_egld,
+                            &self.to_decimal_wad(borrow_cap),
+                            RAY_PRECISION,
+                        );
+
+                        self.rescale_half_up(&utilization_ray, BPS_PRECISION)
+                    },
+                }
+            },
+            None => self.to_decimal_bps(BigUint::zero()),
+        }
+    }
+
+    /// Retrieves the debt ceiling usage of an isolated asset in one call.
+    /// Combines the USD debt borrowed against the asset with its `isolation_debt_ceiling_usd_wad`,
+    /// letting front-ends disable isolated borrows once the remaining headroom is exhausted.
+    ///
+    /// # Arguments
+    /// - `token_id`: Token identifier (EGLD or ESDT) of the isolated collateral asset.
+    ///
+    /// # Returns
+    /// - `MultiValue3` of the used debt, the debt ceiling and the remaining headroom, all in USD
+    ///   (WAD precision); the remaining headroom is zero once the ceiling is reached.
+    #[view(getIsolatedDebtInfo)]
+    fn isolated_debt_info(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> MultiValue3<
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+    > {
+        let asset_config_mapper = self.asset_config(token_id);
+        require!(!asset_config_mapper.is_empty(), ERROR_ASSET_NOT_SUPPORTED);
+
+        let ceiling = asset_config_mapper.get().isolation_debt_ceiling_usd_wad;
+        let debt_mapper = self.isolated_asset_debt_usd(token_id);
+        let used = if debt_mapper.is_empty() {
+            self.wad_zero()
+        } else {
+            debt_mapper.get()
+        };
+        let remaining = if ceiling > used {
+            ceiling.clone() - used.clone()
+        } else {
+            self.wad_zero()
+        };
+
+        (used, ceiling, remaining).into()
+    }
+
+    /// Computes the overall collateralization of a market for systemic risk monitoring.
+    /// Compares the EGLD value of everything supplied as collateral in the market with the
+    /// EGLD value of the debt borrowed from it, both including accrued interest.
+    /// Reads the supply and borrow aggregates kept by the pool, so no account is scanned.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market.
+    ///
+    /// # Returns
+    /// - `MultiValue3` of the collateral value and debt value in EGLD (WAD precision), and their
+    ///   ratio in BPS precision; the ratio is zero when nothing is borrowed.
+    #[view(getCollateralizationRatio)]
+    fn collateralization_ratio(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> MultiValue3<
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+    > {
+        let mut cache = Cache::new(self);
+        let pool_address = cache.cached_pool_address(asset);
+        let indexes = cache.cached_market_index(asset);
+        let feed = self.token_price(asset, &mut cache);
+
+        let supplied_ray = self.scaled_to_original_ray(
+            &self.supplied(pool_address.clone()).get(),
+            &indexes.supply_index_ray,
+        );
+        let borrowed_ray = self.scaled_to_original_ray(
+            &self.borrowed(pool_address).get(),
+            &indexes.borrow_index_ray,
+        );
+        let collateral_egld_ray = self.token_egld_value_ray(&supplied_ray, &feed.price_wad);
+        let debt_egld_ray = self.token_egld_value_ray(&borrowed_ray, &feed.price_wad);
+
+        let ratio = if debt_egld_ray == self.ray_zero() {
+            self.to_decimal_bps(BigUint::zero())
+        } else {
+            let ratio_ray = self.div_half_up(&collateral_egld_ray, &debt_egld_ray, RAY_PRECISION);
+            self.rescale_half_up(&ratio_ray, BPS_PRECISION)
+        };
+
+        (
+            self.rescale_half_up(&collateral_egld_ray, WAD_PRECISION),
+            self.rescale_half_up(&debt_egld_ray, WAD_PRECISION),
+            ratio,
+        )
+            .into()
+    }
+
+    /// Ratio between a market aggregate and its cap, both in asset decimals.
+    fn cap_utilization(
+        &self,
+        pool_address: ManagedAddress,
+        total_scaled: &ManagedDecimal<Self::Api, NumDecimals>,
+        index: &ManagedDecimal<Self::Api, NumDecimals>,
+        cap: BigUint,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let asset_decimals = self.parameters(pool_address).get().asset_decimals;
+        let current = self.scaled_to_original(total_scaled, index, asset_decimals);
+
+        let utilization_ray = self.div_half_up(
+            &current,
+            &self.to_decimal(cap, asset_decimals),
+            RAY_PRECISION,
+        );
+
+        self.rescale_half_up(&utilization_ray, BPS_PRECISION)
+    }
+
+    /// Computes the health factor for an account position with interest accrued to now.
+    /// Always syncs market indexes, regardless of the view index sync setting.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - Health factor as a `ManagedDecimal` in WAD precision.
+    #[view(getHealthFactorSynced)]
+    fn health_factor_synced(&self, account_nonce: u64) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let mut cache = Cache::new(self);
+
+        self.account_health_factor(account_nonce, &mut cache)
+    }
+
+    /// Computes the health factors of many account positions in a single query.
+    /// Prices are cached across accounts, so each token is priced once per call.
+    /// Accounts without borrow positions are not priced and report the maximum health factor.
+    /// Reads indexes as of the last market sync when view index sync is disabled.
+    ///
+    /// # Arguments
+    /// - `account_nonces`: NFT nonces of the account positions.
+    ///
+    /// # Returns
+    /// - `MultiValueEncoded` of `(account_nonce, health_factor)` pairs in input order, with the
+    ///   health factor in RAY precision.
+    #[view(getHealthFactors)]
+    fn health_factors(
+        &self,
+        account_nonces: MultiValueEncoded<u64>,
+    ) -> MultiValueEncoded<MultiValue2<u64, ManagedDecimal<Self::Api, NumDecimals>>> {
+        let mut cache = Cache::new(self);
+        cache.sync_indexes = !self.view_index_sync_disabled().get();
+
+        let mut health_factors = MultiValueEncoded::new();
+        for account_nonce in account_nonces {
+            let health_factor = if self
+                .positions(account_nonce, AccountPositionType::Borrow)
+                .is_empty()
+            {
+                self.double_ray()
+            } else {
+                self.account_health_factor(account_nonce, &mut cache)
+            };
+            health_factors.push((account_nonce, health_factor).into());
+        }
+
+        health_factors
+    }
+
+    /// Simulates the health factor of an account after a sequence of hypothetical actions.
+    /// Applies each delta in order to an in-memory copy of the account's positions, so users
+    /// can preview the impact of a supply, withdraw, borrow or repay before submitting it.
+    /// New deposits take the asset's e-mode aware risk parameters; withdrawals and repayments
+    /// are capped at the simulated position. Does not mutate state.
+    /// Reads indexes as of the last market sync when view index sync is disabled.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `deltas`: `(token_id, amount_raw, action)` triples, with amounts in the token's base units.
+    ///
+    /// # Returns
+    /// - Simulated health factor as a `ManagedDecimal` in RAY precision.
+    #[view(simulateHealthFactor)]
+    fn simulate_health_factor(
+        &self,
+        account_nonce: u64,
+        deltas: MultiValueEncoded<
+            MultiValue3<EgldOrEsdtTokenIdentifier, BigUint, MarketActivityType>,
+        >,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let mut cache = Cache::new(self);
+        cache.sync_indexes = !self.view_index_sync_disabled().get();
+        self.require_active_account(account_nonce);
+        let e_mode_id = self.account_attributes(account_nonce).get().emode_id();
+
+        let mut deposit_positions: ManagedVec<AccountPosition<Self::Api>> = self
+            .positions(account_nonce, AccountPositionType::Deposit)
+            .values()
+            .collect();
+        let mut borrow_positions: ManagedVec<AccountPosition<Self::Api>> = self
+            .positions(account_nonce, AccountPositionType::Borrow)
+            .values()
+            .collect();
+
+        for delta in deltas {
+            let (token_id, amount_raw, action) = delta.into_tuple();
+            let feed = self.token_price(&token_id, &mut cache);
+            let amount_ray = self.rescale_half_up(
+                &self.to_decimal(amount_raw, feed.asset_decimals),
+                RAY_PRECISION,
+            );
+
+            let indexes = cache.cached_market_index(&token_id);
+            let (positions, position_type, index) = match action {
+                MarketActivityType::Supply | MarketActivityType::Withdraw => (
+                    &mut deposit_positions,
+                    AccountPositionType::Deposit,
+                    indexes.supply_index_ray,
+                ),
+                MarketActivityType::Borrow | MarketActivityType::Repay => (
+                    &mut borrow_positions,
+                    AccountPositionType::Borrow,
+                    indexes.borrow_index_ray,
+                ),
+            };
+            let scaled_delta = self.div_half_up(&amount_ray, &index, RAY_PRECISION);
+            let is_add =
+                action == MarketActivityType::Supply || action == MarketActivityType::Borrow;
+
+            match positions
+                .iter()
+                .position(|position| position.asset_id == token_id)
+            {
+                Some(position_index) => {
+                    let mut position = positions.get(position_index).clone();
+                    if is_add {
+                        position.scaled_amount_ray += scaled_delta;
+                    } else {
+                        let removed = self.min(scaled_delta, position.scaled_amount_ray.clone());
+                        position.scaled_amount_ray -= removed;
+                    }
+                    let _ = positions.set(position_index, position);
+                },
+                None if is_add => {
+                    let mut asset_info = cache.cached_asset_info(&token_id);
+                    let asset_emode_config = self.token_e_mode_config(e_mode_id, &token_id);
+                    self.apply_e_mode_to_asset_config(
+                        &mut asset_info,
+                        &self.e_mode_category(e_mode_id),
+                        asset_emode_config,
+                    );
+                    positions.push(AccountPosition::new(
+                        position_type,
+                        token_id,
+                        scaled_delta,
+                        account_nonce,
+                        (&asset_info).into(),
+                    ));
+                },
+                None => {},
+            }
+        }
+
+        let (weighted_collateral, _, _) =
+            self.calculate_collateral_values(&deposit_positions, &mut cache);
+        let total_borrow_ray = self.calculate_total_borrow_in_egld(&borrow_positions, &mut cache);
+
+        self.compute_health_factor(&weighted_collateral, &total_borrow_ray)
+    }
+
+    /// Computes how much each collateral position contributes to an account's health factor.
+    /// Each contribution is the position's liquidation-threshold weighted value divided by the
+    /// total borrow value, so the contributions add up to the health factor.
+    /// Accounts without borrows report zero contributions, as their health factor is unbounded.
+    /// Reads indexes as of the last market sync when view index sync is disabled.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - `MultiValueEncoded` of `(token_id, hf_contribution)` pairs, in RAY precision.
+    #[view(getPositionHealthContribution)]
+    fn position_health_contribution(
+        &self,
+        account_nonce: u64,
+    ) -> MultiValueEncoded<
+        MultiValue2<EgldOrEsdtTokenIdentifier, ManagedDecimal<Self::Api, NumDecimals>>,
+    > {
+        self.health_contributions(account_nonce, false)
+    }
+
+    /// Estimates the health factor gained by adding one whole token to each collateral position.
+    /// Uses the same threshold math as `getPositionHealthContribution`, applied to one unit
+    /// of the token instead of the held amount, to help users pick which collateral to add.
+    /// Reads indexes as of the last market sync when view index sync is disabled.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - `MultiValueEncoded` of `(token_id, hf_contribution)` pairs for one unit of each
+    ///   collateral token, in RAY precision.
+    #[view(getMarginalHealthContribution)]
+    fn marginal_health_contribution(
+        &self,
+        account_nonce: u64,
+    ) -> MultiValueEncoded<
+        MultiValue2<EgldOrEsdtTokenIdentifier, ManagedDecimal<Self::Api, NumDecimals>>,
+    > {
+        self.health_contributions(account_nonce, true)
+    }
+
+    /// Computes per-collateral health factor contributions for an account position.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `per_unit`: Values one whole token instead of the held amount when `true`.
+    ///
+    /// # Returns
+    /// - `MultiValueEncoded` of `(token_id, hf_contribution)` pairs, in RAY precision.
+    fn health_contributions(
+        &self,
+        account_nonce: u64,
+        per_unit: bool,
+    ) -> MultiValueEncoded<
+        MultiValue2<EgldOrEsdtTokenIdentifier, ManagedDecimal<Self::Api, NumDecimals>>,
+    > {
+        let mut cache = Cache::new(self);
+        cache.sync_indexes = !self.view_index_sync_disabled().get();
+
+        let borrow_positions = self
+            .positions(account_nonce, AccountPositionType::Borrow)
+            .values()
+            .collect();
+        let total_borrow_ray = self.calculate_total_borrow_in_egld(&borrow_positions, &mut cache);
+
+        let mut contributions = MultiValueEncoded::new();
+        for position in self
+            .positions(account_nonce, AccountPositionType::Deposit)
+            .values()
+        {
+            if self
+                .position_collateral_disabled(position.account_nonce, &position.asset_id)
+                .get()
+            {
+                continue;
+            }
+
+            let contribution = if total_borrow_ray == self.ray_zero() {
+                self.ray_zero()
+            } else {
+                let price_feed = self.token_price(&position.asset_id, &mut cache);
+                let amount = if per_unit {
+                    self.ray()
+                } else {
+                    self.total_amount_ray(&position, &mut cache)
+                };
+                let amount_egld = self.token_egld_value_ray(&amount, &price_feed.price_wad);
+                let liquidation_value =
+                    self.apply_liquidation_haircut(&position.asset_id, &amount_egld);
+                let weighted_egld = self.mul_half_up(
+                    &liquidation_value,
+                    &position.liquidation_threshold_bps,
+                    RAY_PRECISION,
+                );
+                self.div_half_up(&weighted_egld, &total_borrow_ray, RAY_PRECISION)
+            };
+            contributions.push((position.asset_id, contribution).into());
+        }
+
+        contributions
+    }
+
+    /// Lists healthy borrowing accounts ordered by how close they are to liquidation.
+    /// Scans one page of the account set and returns the accounts still at or above the
+    /// liquidation threshold, lowest health factor first, so keepers can pre-stage
+    /// positions about to become liquidatable.
+    /// Reads indexes as of the last market sync when view index sync is disabled.
+    ///
+    /// # Arguments
+    /// - `from`: Zero-based offset into the account set.
+    /// - `size`: Maximum number of accounts scanned from `from`.
+    ///
+    /// # Returns
+    /// - `MultiValueEncoded` of `(account_nonce, health_factor)` pairs sorted ascending by
+    ///   health factor, in RAY precision. Accounts without borrows or already liquidatable
+    ///   are skipped.
+    #[view(getAccountsNearLiquidation)]
+    fn accounts_near_liquidation(
+        &self,
+        from: usize,
+        size: usize,
+    ) -> MultiValueEncoded<MultiValue2<u64, ManagedDecimal<Self::Api, NumDecimals>>> {
+        let mut cache = Cache::new(self);
+        cache.sync_indexes = !self.view_index_sync_disabled().get();
+
+        let accounts = self.accounts();
+        let end = core::cmp::min(from.saturating_add(size), accounts.len());
+
+        let mut nonces = ManagedVec::<Self::Api, u64>::new();
+        let mut health_factors = ManagedVec::<Self::Api, BigUint>::new();
+        for index in (from + 1)..=end {
+            let account_nonce = accounts.get_by_index(index);
+            if self
+                .positions(account_nonce, AccountPositionType::Borrow)
+                .is_empty()
+            {
+                continue;
+            }
+
+            let health_factor = self.account_health_factor(account_nonce, &mut cache);
+            if health_factor < self.ray() {
+                continue;
+            }
+
+            nonces.push(account_nonce);
+            health_factors.push(health_factor.into_raw_units().clone());
+        }
+
+        let mut sorted = MultiValueEncoded::new();
+        while !nonces.is_empty() {
+            let mut lowest = 0;
+            for index in 1..health_factors.len() {
+                if *health_factors.get(index) < *health_factors.get(lowest) {
+                    lowest = index;
+                }
+            }
+
+            let health_factor = self.to_decimal_ray((*health_factors.get(lowest)).clone());
+            sorted.push((nonces.get(lowest), health_factor).into());
+            nonces.remove(lowest);
+            health_factors.remove(lowest);
+        }
+
+        sorted
+    }
+
+    /// Computes the health factor of an account position using a shared cache.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `cache`: Mutable storage cache for price feeds.
+    ///
+    /// # Returns
+    /// - Health factor as a `ManagedDecimal` in RAY precision.
+    fn account_health_factor(
+        &self,
+        account_nonce: u64,
+        cache: &mut Cache<Self>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let deposit_positions = self.positions(account_nonce, AccountPositionType::Deposit);
+
+        let (weighted_collateral, _, _) =
+            self.calculate_collateral_values(&deposit_positions.values().collect(), cache);
+
+        let borrow_positions = self
+            .positions(account_nonce, AccountPositionType::Borrow)
+            .values()
+            .collect();
+
+        let total_borrow_ray = self.calculate_total_borrow_in_egld(&borrow_positions, cache);
+
+        self.compute_health_factor(&weighted_collateral, &total_borrow_ray)
+    }
+
+    /// Checks whether an account's debt is fully backed by collateral eligible under its mode.
+    /// Unlike the health factor, only collateral allowed to back debt under the account's
+    /// isolation and e-mode rules is counted, and every borrow must respect those rules.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if all borrows are allowed under the account's mode and the eligible
+    ///   collateral alone keeps the health factor at or above 1.
+    #[view(isFullyBacked)]
+    fn is_fully_backed(&self, account_nonce: u64) -> bool {
+        let mut cache = Cache::new(self);
+        self.require_active_account(account_nonce);
+        let attributes = self.account_attributes(account_nonce).get();
+
+        let borrow_positions: ManagedVec<AccountPosition<Self::Api>> = self
+            .positions(account_nonce, AccountPositionType::Borrow)
+            .values()
+            .collect();
+        if borrow_positions.is_empty() {
+            return true;
+        }
+
+        for position in borrow_positions.iter() {
+            let asset_config = cache.cached_asset_info(&position.asset_id);
+            if attributes.is_isolated() && !asset_config.can_borrow_in_isolation() {
+                return false;
+            }
+            if asset_config.is_siloed_borrowing() && borrow_positions.len() > 1 {
+                return false;
+            }
+            if attributes.has_emode() {
+                let e_mode_config = self
+                    .e_mode_assets(attributes.emode_id())
+                    .get(&position.asset_id);
+                if !e_mode_config.is_some_and(|config| config.can_borrow()) {
+                    return false;
+                }
+            }
+        }
+
+        let mut eligible_collateral = ManagedVec::new();
+        for position in self
+            .positions(account_nonce, AccountPositionType::Deposit)
+            .values()
+        {
+            let asset_config = cache.cached_asset_info(&position.asset_id);
+            let is_eligible = if self
+                .position_collateral_disabled(position.account_nonce, &position.asset_id)
+                .get()
+            {
+                false
+            } else if attributes.is_isolated() {
+                position.asset_id == attributes.isolated_token()
+            } else if asset_config.is_isolated() {
+                false
+            } else if attributes.has_emode() {
+                self.e_mode_assets(attributes.emode_id())
+                    .get(&position.asset_id)
+                    .is_some_and(|config| config.can_supply())
+            } else {
+                asset_config.can_supply()
+            };
+
+            if is_eligible {
+                eligible_collateral.push(position);
+            }
+        }
+
+        let (weighted_collateral, _, _) =
+            self.calculate_collateral_values(&eligible_collateral, &mut cache);
+        let total_borrow_ray = self.calculate_total_borrow_in_egld(&borrow_positions, &mut cache);
+
+        self.compute_health_factor(&weighted_collateral, &total_borrow_ray) >= self.ray()
+    }
+
+    /// Computes the price of one collateral at which the account becomes liquidatable.
+    /// Solves the health factor equation for that token's price, every other price held constant.
+    /// A token that is also borrowed contributes to the debt side at the same price.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `collateral_token`: Token identifier (EGLD or ESDT) of the collateral to price.
+    ///
+    /// # Returns
+    /// - Liquidation price in USD as a `ManagedDecimal` in WAD precision; zero when no price
+    ///   drop of this collateral can bring the health factor down to 1.
+    ///
+    /// # Panics
+    /// - If the token is not in the account's collateral.
+    #[view(getLiquidationPrice)]
+    fn liquidation_price(
+        &self,
+        account_nonce: u64,
+        collateral_token: &EgldOrEsdtTokenIdentifier,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let mut cache = Cache::new(self);
+        let deposit_positions = self.positions(account_nonce, AccountPositionType::Deposit);
+        let borrow_positions = self.positions(account_nonce, AccountPositionType::Borrow);
+        let collateral = match deposit_positions.get(collateral_token) {
+            Some(dp)
+                if !self
+                    .position_collateral_disabled(account_nonce, collateral_token)
+                    .get() =>
+            {
+                dp
+            },
+            _ => sc_panic!("Token not existing in the account {}", collateral_token),
+        };
+
+        let (weighted_collateral, _, _) =
+            self.calculate_collateral_values(&deposit_positions.values().collect(), &mut cache);
+        let total_borrow =
+            self.calculate_total_borrow_in_egld(&borrow_positions.values().collect(), &mut cache);
+
+        let feed = self.token_price(collateral_token, &mut cache);
+        let collateral_amount = self.total_amount_ray(&collateral, &mut cache);
+        let weighted_amount = self.mul_half_up(
+            &collateral_amount,
+            &collateral.liquidation_threshold_bps,
+            RAY_PRECISION,
+        );
+        let debt_amount = match borrow_positions.get(collateral_token) {
+            Some(bp) => self.total_amount_ray(&bp, &mut cache),
+            None => self.ray_zero(),
+        };
+
+        // Same rounding as the aggregates so the token's share is removed exactly
+        let collateral_egld = self.token_egld_value_ray(&collateral_amount, &feed.price_wad);
+        let weighted_egld = self.mul_half_up(
+            &collateral_egld,
+            &collateral.liquidation_threshold_bps,
+            RAY_PRECISION,
+        );
+
+        // weighted_others + weighted_amount * price == borrow_others + debt_amount * price
+        let weighted_others = weighted_collateral - weighted_egld;
+        let borrow_others = total_borrow - self.token_egld_value_ray(&debt_amount, &feed.price_wad);
+        if borrow_others <= weighted_others || weighted_amount <= debt_amount {
+            return self.wad_zero();
+        }
+
+        let price_egld = self.div_half_up(
+            &(borrow_others - weighted_others),
+            &(weighted_amount - debt_amount),
+            WAD_PRECISION,
+        );
+
+        self.egld_usd_value(&price_egld, &cache.egld_usd_price_wad)
+    }
+
+    /// Retrieves the interest accrued by a position since it was opened.
+    /// Compares the current value of the position with its principal at the entry index.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `token_id`: Token identifier of the position.
+    /// - `is_borrow`: Whether to read the borrow position instead of the deposit.
+    ///
+    /// # Returns
+    /// - Accrued interest in token units as a `ManagedDecimal` (WAD precision).
+    ///
+    /// # Panics
+    /// - If the token is not in the account's positions of the requested type.
+    #[view(getPositionAccruedInterest)]
+    fn position_accrued_interest(
+        &self,
+        account_nonce: u64,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        is_borrow: bool,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let position_type = if is_borrow {
+            AccountPositionType::Borrow
+        } else {
+            AccountPositionType::Deposit
+        };
+        let position = match self
+            .positions(account_nonce, position_type.clone())
+            .get(token_id)
+        {
+            Some(position) => position,
+            None => sc_panic!("Token not existing in the account {}", token_id),
+        };
+
+        let mut cache = Cache::new(self);
+        let market_index = cache.cached_market_index(token_id);
+        let current_index = if is_borrow {
+            market_index.borrow_index_ray
+        } else {
+            market_index.supply_index_ray
+        };
+
+        let entry_mapper = self.position_entry(account_nonce, position_type, token_id);
+        let entry_index = if entry_mapper.is_empty() {
+            current_index.clone()
+        } else {
+            entry_mapper.get().entry_index_ray
+        };
+
+        let current_ray = self.scaled_to_original_ray(&position.scaled_amount_ray, &current_index);
+        let principal_ray = self.scaled_to_original_ray(&position.scaled_amount_ray, &entry_index);
+        if current_ray <= principal_ray {
+            return self.wad_zero();
+        }
+
+        self.rescale_half_up(&(current_ray - principal_ray), WAD_PRECISION)
+    }
+
+    /// Estimates the largest amount of a token an account can borrow right now.
+    /// Mirrors the checks of `borrow`: the e-mode, isolation and siloed borrowing rules decide
+    /// whether the asset can be borrowed at all, then the remaining LTV collateral, the borrow
+    /// allowance, the maximum position value, the isolated debt ceiling and the borrow cap bound
+    /// the amount. Protocol-owned accounts skip the borrow allowance and the maximum position value.
+    /// The borrow cap exemption list is not applied, as it depends on the caller.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `token_id`: Token identifier (EGLD or ESDT) to borrow.
+    ///
+    /// # Returns
+    /// - Maximum borrowable amount in the token's decimals, rounded down; zero when the asset
+    ///   cannot be borrowed by the account.
+    #[view(getMaxBorrowable)]
+    fn max_borrowable(
+        &self,
+        account_nonce: u64,
+        token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let mut cache = Cache::new(self);
+        self.require_active_account(account_nonce);
+        require!(
+            !self.asset_config(token_id).is_empty(),
+            ERROR_ASSET_NOT_SUPPORTED
+        );
+
+        let feed = self.token_price(token_id, &mut cache);
+        let zero = self.to_decimal(BigUint::zero(), feed.asset_decimals);
+        if self.debt_creation_disabled().get()
+            || self.market_paused(token_id).get()
+            || self.new_borrow_disabled(token_id).get()
+        {
+            return zero;
+        }
+
+        let attributes = self.account_attributes(account_nonce).get();
+        let borrow_positions: ManagedVec<AccountPosition<Self::Api>> = self
+            .positions(account_nonce, AccountPositionType::Borrow)
+            .values()
+            .collect();
+        let asset_config = match self.account_borrow_config(
+            token_id,
+            &attributes,
+            &borrow_positions,
+            &mut cache,
+        ) {
+            Some(asset_config) => asset_config,
+            None => return zero,
+        };
+
+        // Limits expressed in EGLD (RAY precision)
+        let collaterals = self
+            .positions(account_nonce, AccountPositionType::Deposit)
+            .values()
+            .collect();
+        let (_, _, ltv_collateral) = self.calculate_collateral_values(&collaterals, &mut cache);
+        let total_borrow_ray = self.calculate_total_borrow_in_egld(&borrow_positions, &mut cache);
+        let mut egld_headroom = self.remaining_headroom(&ltv_collateral, &total_borrow_ray);
+
+        let is_protocol_owned = self.protocol_owned_accounts().contains(&account_nonce);
+        let allowance_mapper = self.account_borrow_allowance(account_nonce);
+        if !allowance_mapper.is_empty() && !is_protocol_owned {
+            let allowance =
+                self.rescale_half_up(&self.to_decimal_wad(allowance_mapper.get()), RAY_PRECISION);
+            egld_headroom = self.min(
+                egld_headroom,
+                self.remaining_headroom(&allowance, &total_borrow_ray),
+            );
+        }
+
+        let max_value_mapper = self.max_position_value();
+        if !max_value_mapper.is_empty() && !is_protocol_owned {
+            let max_value =
+                self.rescale_half_up(&self.to_decimal_wad(max_value_mapper.get()), RAY_PRECISION);
+            egld_headroom = self.min(
+                egld_headroom,
+                self.remaining_headroom(&max_value, &total_borrow_ray),
+            );
+        }
+
+        if attributes.is_isolated() {
+            let isolated_token = attributes.isolated_token();
+            let ceiling = cache
+                .cached_asset_info(&isolated_token)
+                .isolation_debt_ceiling_usd_wad;
+            let used = self.isolated_asset_debt_usd(&isolated_token).get();
+            let remaining_usd = self.remaining_headroom(&ceiling, &used);
+            let remaining_egld =
+                self.div_half_up(&remaining_usd, &cache.egld_usd_price_wad, RAY_PRECISION);
+            egld_headroom = self.min(egld_headroom, remaining_egld);
+        }
+
+        let mut max_amount = self
+            .convert_egld_to_tokens_ray(&egld_headroom, &feed)
+            .rescale(feed.asset_decimals);
+
+        // Limits expressed in token units
+        let override_mapper = self.account_borrow_cap_override(account_nonce, token_id);
+        if !override_mapper.is_empty() {
+            let account_borrowed = match borrow_positions
+                .iter()
+                .find(|position| &position.asset_id == token_id)
+            {
+                Some(position) => self.total_amount(&position, &feed, &mut cache),
+                None => zero.clone(),
+            };
+            let cap = self.to_decimal(override_mapper.get(), feed.asset_decimals);
+            max_amount = self.min(max_amount, self.remaining_headroom(&cap, &account_borrowed));
+        } else if let Some(borrow_cap) = &asset_config.borrow_cap_wad {
+            let pool = cache.cached_pool_address(token_id);
+            let index = cache.cached_market_index(token_id);
+            let market_borrowed = self.scaled_to_original(
+                &self.borrowed(pool).get(),
+                &index.borrow_index_ray,
+                feed.asset_decimals,
+            );
+            let remaining_cap = match self.cap_denomination(token_id) {
+                CapDenomination::Token => {
+                    let cap = self.to_decimal(borrow_cap.clone(), feed.asset_decimals);
+                    self.remaining_headroom(&cap, &market_borrowed)
+                },
+                CapDenomination::Egld => {
+                    let borrowed_egld = self.token_egld_value(&market_borrowed, &feed.price_wad);
+                    let remaining_egld = self.remaining_headroom(
+                        &self.to_decimal_wad(borrow_cap.clone()),
+                        &borrowed_egld,
+                    );
+                    self.convert_egld_to_tokens_ray(&remaining_egld, &feed)
+                        .rescale(feed.asset_decimals)
+                },
+            };
+            max_amount = self.min(max_amount, remaining_cap);
+        }
+
+        max_amount
+    }
+
+    /// Resolves the configuration a borrow of `token_id` would use for an account.
+    /// Applies the account's e-mode and returns `None` where `borrow` would reject the asset
+    /// under the e-mode, isolation or siloed borrowing rules.
+    ///
+    /// # Arguments
+    /// - `token_id`: Token identifier to borrow.
+    /// - `attributes`: NFT attributes of the account.
+    /// - `borrow_positions`: Current borrow positions of the account.
+    /// - `cache`: Mutable storage cache.
+    ///
+    /// # Returns
+    /// - `Option<AssetConfig>` with e-mode parameters applied, or `None` if not borrowable.
+    fn account_borrow_config(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        attributes: &AccountAttributes<Self::Api>,
+        borrow_positions: &ManagedVec<AccountPosition<Self::Api>>,
+        cache: &mut Cache<Self>,
+    ) -> Option<AssetConfig<Self::Api>> {
+        let mut asset_config = cache.cached_asset_info(token_id);
+
+        if attributes.has_emode() {
+            let emode_id = attributes.emode_id();
+            if asset_config.is_isolated() || !self.asset_e_modes(token_id).contains(&emode_id) {
+                return None;
+            }
+            let category = self.e_mode_categories().get(&emode_id)?;
+            if category.is_deprecated() {
+                return None;
+            }
+            let asset_emode_config = self.e_mode_assets(emode_id).get(token_id)?;
+            self.apply_e_mode_to_asset_config(
+                &mut asset_config,
+                &Some(category),
+                Some(asset_emode_config),
+            );
+        }
+
+        if !asset_config.can_borrow() {
+            return None;
+        }
+        if attributes.is_isolated() && !asset_config.can_borrow_in_isolation() {
+            return None;
+        }
+
+        if asset_config.is_siloed_borrowing() && borrow_positions.len() > 1 {
+            return None;
+        }
+        if borrow_positions.len() == 1 {
+            let first_position = borrow_positions.get(0);
+            let first_asset_config = cache.cached_asset_info(&first_position.asset_id);
+            if (first_asset_config.is_siloed_borrowing() || asset_config.is_siloed_borrowing())
+                && token_id != &first_position.asset_id
+            {
+                return None;
+            }
+        }
+
+        Some(asset_config)
+    }
+
+    /// Returns how much of `limit` is left after `used`, or zero once the limit is reached.
+    fn remaining_headroom(
+        &self,
+        limit: &ManagedDecimal<Self::Api, NumDecimals>,
+        used: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        if limit > used {
+            limit.clone() - used.clone()
+        } else {
+            self.to_decimal(BigUint::zero(), limit.scale())
+        }
+    }
+
+    /// Computes the net worth of an account position in EGLD and USD.
+    /// Net worth is total collateral minus total borrow, clamped at zero for insolvent accounts.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - `MultiValue2` of the net worth in EGLD and in USD, both in WAD precision.
+    #[view(getAccountNetWorth)]
+    fn account_net_worth(
+        &self,
+        account_nonce: u64,
+    ) -> MultiValue2<ManagedDecimal<Self::Api, NumDecimals>, ManagedDecimal<Self::Api, NumDecimals>>
+    {
+        let total_collateral = self.total_collateral_in_egld(account_nonce);
+        let total_borrow = self.total_borrow_in_egld(account_nonce);
+
+        let net_worth_egld = if total_collateral > total_borrow {
+            total_collateral - total_borrow
+        } else {
+            self.wad_zero()
+        };
+
+        let cache = Cache::new(self);
+        let net_worth_usd = self.egld_usd_value(&net_worth_egld, &cache.egld_usd_price_wad);
+
+        (net_worth_egld, net_worth_usd).into()
+    }
+
+    /// Summarizes the collateral, debt and health factor of an account position.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - `UserAccountSummary` with collateral and debt values in EGLD (WAD precision) and the
+    ///   health factor in RAY precision.
+    ///
+    /// # Panics
+    /// - If the account is not active in the market.
+    #[view(getAccountSummary)]
+    fn account_summary(&self, account_nonce: u64) -> UserAccountSummary<Self::Api> {
+        self.require_active_account(account_nonce);
+        let mut cache = Cache::new(self);
+
+        self.build_account_summary(account_nonce, &mut cache)
+    }
+
+    /// Summarizes every account position held by a wallet in one paginated call.
+    /// Accounts are read from the holder reverse index, so an NFT appears under the
+    /// address that last presented it to the protocol.
+    ///
+    /// # Arguments
+    /// - `address`: Wallet address holding the account NFTs.
+    /// - `from`: Zero-based index of the first account to return.
+    /// - `size`: Maximum number of accounts to return.
+    ///
+    /// # Returns
+    /// - `MultiValueEncoded` of `(account_nonce, UserAccountSummary)` pairs; empty when `from`
+    ///   is past the wallet's last account.
+    #[view(getWalletSummary)]
+    fn wallet_summary(
+        &self,
+        address: ManagedAddress,
+        from: usize,
+        size: usize,
+    ) -> MultiValueEncoded<MultiValue2<u64, UserAccountSummary<Self::Api>>> {
+        let accounts = self.user_accounts(&address);
+        let end = core::cmp::min(from.saturating_add(size), accounts.len());
+        let mut cache = Cache::new(self);
+
+        let mut summaries = MultiValueEncoded::new();
+        for index in from..end {
+            let account_nonce = accounts.get_by_index(index + 1);
+            let summary = self.build_account_summary(account_nonce, &mut cache);
+            summaries.push((account_nonce, summary).into());
+        }
+
+        summaries
+    }
+
+    /// Builds the summary of an account position from its deposits and borrows.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `cache`: Mutable storage cache for price feeds.
+    ///
+    /// # Returns
+    /// - `UserAccountSummary` of the account.
+    fn build_account_summary(
+        &self,
+        account_nonce: u64,
+        cache: &mut Cache<Self>,
+    ) -> UserAccountSummary<Self::Api> {
+        let deposit_positions = self
+            .positions(account_nonce, AccountPositionType::Deposit)
+            .values()
+            .collect();
+        let borrow_positions = self
+            .positions(account_nonce, AccountPositionType::Borrow)
+            .values()
+            .collect();
+
+        let (weighted_collateral, total_collateral, ltv_collateral) =
+            self.calculate_collateral_values(&deposit_positions, cache);
+        let total_borrow_ray = self.calculate_total_borrow_in_egld(&borrow_positions, cache);
+        let health_factor_ray = self.compute_health_factor(&weighted_collateral, &total_borrow_ray);
+
+        UserAccountSummary {
+            total_collateral_wad: self.rescale_half_up(&total_collateral, WAD_PRECISION),
+            liquidation_collateral_wad: self.rescale_half_up(&weighted_collateral, WAD_PRECISION),
+            ltv_collateral_wad: self.rescale_half_up(&ltv_collateral, WAD_PRECISION),
+            total_borrow_wad: self.rescale_half_up(&total_borrow_ray, WAD_PRECISION),
+            health_factor_ray,
+        }
+    }
+
+    /// Compares the health factor of an account under its e-mode category with the health
+    /// factor it would have under the base asset configuration.
+    /// Both values use the current risk parameters, so they may differ from the thresholds
+    /// stored on positions that were not yet synchronized.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - `MultiValue2` of the health factor with e-mode and without e-mode, both in RAY precision.
+    ///   For accounts without e-mode both values are equal.
+    ///
+    /// # Panics
+    /// - If the account is not active in the market.
+    #[view(getEModeImpact)]
+    fn e_mode_impact(
+        &self,
+        account_nonce: u64,
+    ) -> MultiValue2<ManagedDecimal<Self::Api, NumDecimals>, ManagedDecimal<Self::Api, NumDecimals>>
+    {
+        self.require_active_account(account_nonce);
+
+        let mut cache = Cache::new(self);
+        let e_mode_id = self.account_attributes(account_nonce).get().emode_id();
+        let e_mode_category = self.e_mode_category(e_mode_id);
+
+        let mut weighted_collateral_with_e_mode = self.ray_zero();
+        let mut weighted_collateral_without_e_mode = self.ray_zero();
+        for position in self
+            .positions(account_nonce, AccountPositionType::Deposit)
+            .values()
+        {
+            if self
+                .position_collateral_disabled(position.account_nonce, &position.asset_id)
+                .get()
+            {
+                continue;
+            }
+            let price_feed = self.token_price(&position.asset_id, &mut cache);
+            let amount = self.total_amount_ray(&position, &mut cache);
+            let amount_egld = self.token_egld_value_ray(&amount, &price_feed.price_wad);
+
+            let base_config = cache.cached_asset_info(&position.asset_id);
+            let mut e_mode_config = base_config.clone();
+            self.apply_e_mode_to_asset_config(
+                &mut e_mode_config,
+                &e_mode_category,
+                self.token_e_mode_config(e_mode_id, &position.asset_id),
+            );
+
+            weighted_collateral_with_e_mode += self.mul_half_up(
+                &amount_egld,
+                &e_mode_config.liquidation_threshold_bps,
+                RAY_PRECISION,
+            );
+            weighted_collateral_without_e_mode += self.mul_half_up(
+                &amount_egld,
+                &base_config.liquidation_threshold_bps,
+                RAY_PRECISION,
+            );
+        }
+
+        let borrow_positions = self
+            .positions(account_nonce, AccountPositionType::Borrow)
+            .values()
+            .collect();
+        let total_borrow_ray = self.calculate_total_borrow_in_egld(&borrow_positions, &mut cache);
+
+        (
+            self.compute_health_factor(&weighted_collateral_with_e_mode, &total_borrow_ray),
+            self.compute_health_factor(&weighted_collateral_without_e_mode, &total_borrow_ray),
+        )
+            .into()
+    }
+
+    /// Retrieves the EGLD price of a token together with the age of its aggregator round.
+    /// Lets liquidation bots and risk monitors tell a fresh price from one close to going stale.
+    ///
+    /// # Arguments
+    /// - `token_id`: Token identifier (EGLD or ESDT) to query.
+    ///
+    /// # Returns
+    /// - `MultiValue3` of the EGLD price (WAD precision), the aggregator round timestamp in
+    ///   milliseconds, and whether that round is older than the oracle's
+    ///   `max_price_stale_seconds`. The timestamp is 0 and the price never stale when the
+    ///   token is not priced from the aggregator (EGLD, LP, derived and safe-price-only tokens).
+    #[view(getTokenPriceWithAge)]
+    fn token_price_with_age(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> MultiValue3<ManagedDecimal<Self::Api, NumDecimals>, TimestampMillis, bool> {
+        let mut cache = Cache::new(self);
+        let (_, _, price, aggregator_timestamp_secs, is_stale, _, _) =
+            self.price_components(token_id, &mut cache);
+
+        let last_update_timestamp_ms =
+            TimestampMillis::new(aggregator_timestamp_secs.as_u64_seconds() * 1000);
+
+        (price, last_update_timestamp_ms, is_stale).into()
+    }
+
+    /// Retrieves the USD price of a token together with a confidence band.
+    /// The band is centered on the price and its half-width is half the absolute divergence
+    /// between the aggregator and safe prices, so its total width equals that divergence.
+    ///
+    /// # Arguments
+    /// - `token_id`: Token identifier (EGLD or ESDT) to query.
+    ///
+    /// # Returns
+    /// - `MultiValue3` of the price, lower bound and upper bound in USD, WAD precision.
+    ///   The band is empty when the token is priced from a single source; the lower
+    ///   bound never goes below zero.
+    #[view(getOraclePriceWithConfidence)]
+    fn oracle_price_with_confidence(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> MultiValue3<
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+    > {
+        let mut cache = Cache::new(self);
+        let (safe_price, aggregator_price, final_price, _, _, _, _) =
+            self.price_components(token_id, &mut cache);
+
+        let price = self.egld_usd_value(&final_price, &cache.egld_usd_price_wad);
+        let spread = match (safe_price, aggregator_price) {
+            (Some(safe), Some(aggregator)) => {
+                let safe_usd = self.egld_usd_value(&safe, &cache.egld_usd_price_wad);
+                let aggregator_usd = self.egld_usd_value(&aggregator, &cache.egld_usd_price_wad);
+                if safe_usd > aggregator_usd {
+                    safe_usd - aggregator_usd
+                } else {
+                    aggregator_usd - safe_usd
+                }
+            },
+            _ => self.wad_zero(),
+        };
+
+        // The upper half takes the odd unit so the band width matches the spread exactly
+        let half_spread = spread.clone() / 2;
+        let lower_bound = if price > half_spread {
+            price.clone() - half_spread.clone()
+        } else {
+            self.wad_zero()
+        };
+        let upper_bound = price.clone() + spread - half_spread;
+
+        (price, lower_bound, upper_bound).into()
+    }
+
+    /// Computes the annual borrow rate a market would charge at a given utilization.
+    /// Evaluates the market's piecewise linear rate curve, capped at the max borrow rate.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market.
+    /// - `utilization_ray`: Utilization ratio in RAY precision.
+    ///
+    /// # Returns
+    /// - Annual borrow rate as a `ManagedDecimal` in RAY precision.
+    #[view(getBorrowRateAt)]
+    fn borrow_rate_at(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+        utilization_ray: BigUint,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let pool_address = self.pool_address(asset);
+        let parameters = self.parameters(pool_address.clone()).get();
+
+        self.calculate_annual_borrow_rate(
+            self.to_decimal_ray(utilization_ray),
+            parameters,
+            &self.rate_settings(pool_address),
+        )
+    }
+
+    /// Computes the utilization at which a market would charge a given annual borrow rate.
+    /// Inverts the market's piecewise linear rate curve; rates above the curve map to 100%.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market.
+    /// - `target_rate_ray`: Annual borrow rate in RAY precision.
+    ///
+    /// # Returns
+    /// - Utilization ratio as a `ManagedDecimal` in RAY precision.
+    #[view(getUtilizationForBorrowRate)]
+    fn utilization_for_borrow_rate(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+        target_rate_ray: BigUint,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let pool_address = self.pool_address(asset);
+        let parameters = self.parameters(pool_address.clone()).get();
+
+        self.calculate_utilization_for_borrow_rate(
+            self.to_decimal_ray(target_rate_ray),
+            parameters,
+            &self.rate_settings(pool_address),
+        )
+    }
+
+    /// Quotes the fee a flash loan of a given amount would be charged.
+    /// Mirrors the pool's repayment rounding, so `amount_raw` plus the fee is the exact
+    /// amount the receiver endpoint must return.
+    ///
+    /// # Arguments
+    /// - `token_id`: Token identifier (EGLD or ESDT) of the market.
+    /// - `amount_raw`: Flash loan amount in the token's base units.
+    ///
+    /// # Returns
+    /// - Flash loan fee as a `BigUint` in the token's base units.
+    #[view(getFlashLoanFee)]
+    fn flash_loan_fee(&self, token_id: &EgldOrEsdtTokenIdentifier, amount_raw: BigUint) -> BigUint {
+        let asset_decimals = self
+            .parameters(self.pool_address(token_id))
+            .get()
+            .asset_decimals;
+        let fee_bps = self.asset_config(token_id).get().flashloan_fee_bps;
+
+        let amount = self.to_decimal(amount_raw, asset_decimals);
+        let required_repayment = self.rescale_half_up(
+            &self.mul_half_up(&amount, &(self.bps() + fee_bps), RAY_PRECISION),
+            asset_decimals,
+        );
+
+        (required_repayment - amount).into_raw_units().clone()
+    }
+
+    /// Retrieves the cumulative rounding remainder a market retained from favorable rounding.
+    /// Makes the protocol's rounding revenue transparent for audits.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market.
+    ///
+    /// # Returns
+    /// - Accumulated rounding remainder as a `BigUint` in asset units, RAY-scaled.
+    #[view(getAccumulatedRounding)]
+    fn asset_accumulated_rounding(&self, asset: &EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.accumulated_rounding(self.pool_address(asset)).get()
+    }
+
+    /// Previews the revenue `claimRevenue` would transfer from a market right now.
+    /// Accrues interest up to the current timestamp in memory, as the pool does before a claim,
+    /// and caps the result at the pool's reserves, so keepers can tell whether a claim is worth
+    /// the gas. Does not mutate state.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market.
+    ///
+    /// # Returns
+    /// - Claimable revenue as a `ManagedDecimal` in the asset's decimals.
+    #[view(getClaimableRevenue)]
+    fn claimable_revenue(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let pool_address = self.pool_address(asset);
+        let parameters = self.parameters(pool_address.clone()).get();
+        let rate_settings = self.rate_settings(pool_address.clone());
+        let mut supply_index = self.supply_index(pool_address.clone()).get();
+        let mut revenue_scaled = self.revenue(pool_address.clone()).get();
+
+        let delta = self.blockchain().get_block_timestamp_millis()
+            - self.last_timestamp(pool_address.clone()).get();
+        if !self.accrual_paused(pool_address.clone()).get()
+            && delta > DurationMillis::zero()
+            && delta >= rate_settings.min_accrual_interval_ms
+        {
+            let borrowed = self.borrowed(pool_address.clone()).get();
+            let supplied = self.supplied(pool_address.clone()).get();
+            let borrow_index = self.borrow_index(pool_address.clone()).get();
+
+            let utilization = self.utilization(
+                &self.scaled_to_original_ray(&borrowed, &borrow_index),
+                &self.scaled_to_original_ray(&supplied, &supply_index),
+            );
+            let borrow_rate =
+                self.calculate_borrow_rate(utilization.clone(), parameters.clone(), &rate_settings);
+            let borrow_factor = self.calculate_compounded_interest(borrow_rate, delta);
+            let (new_borrow_index, old_borrow_index) =
+                self.update_borrow_index(borrow_index, borrow_factor);
+            let (supplier_rewards_ray, protocol_fee_ray) = self.calculate_supplier_rewards(
+                parameters.clone(),
+                &rate_settings,
+                &utilization,
+                &borrowed,
+                &new_borrow_index,
+                &old_borrow_index,
+            );
+
+            supply_index = self.update_supply_index(supplied, supply_index, supplier_rewards_ray);
+            if protocol_fee_ray != self.ray_zero() {
+                revenue_scaled += self.div_half_up(&protocol_fee_ray, &supply_index, RAY_PRECISION);
+            }
+        }
+
+        let revenue =
+            self.scaled_to_original(&revenue_scaled, &supply_index, parameters.asset_decimals);
+        let reserves_raw = if asset.is_egld() {
+            self.blockchain().get_balance(&pool_address)
+        } else {
+            self.blockchain()
+                .get_esdt_balance(&pool_address, &asset.clone().unwrap_esdt(), 0)
+        };
+
+        self.min(
+            revenue,
+            self.to_decimal(reserves_raw, parameters.asset_decimals),
+        )
+    }
+
+    /// Retrieves the recent supply, borrow, repay and withdraw operations of a market.
+    /// Reads the market's bounded activity buffer, so light clients need no indexer.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market.
+    ///
+    /// # Returns
+    /// - `ManagedVec<MarketActivity>` ordered from oldest to newest; empty when recording is disabled.
+    #[view(getRecentMarketActivity)]
+    fn recent_market_activity(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+    ) -> ManagedVec<MarketActivity<Self::Api>> {
+        let buffer = self.market_activity(asset);
+        let len = buffer.len();
+        let head = self.market_activity_head(asset).get();
+
+        let mut activity = ManagedVec::new();
+        for offset in 0..len {
+            activity.push(buffer.get((head + offset) % len + 1));
+        }
+
+        activity
+    }
+
+    /// Retrieves the most recent liquidations of an account.
+    /// Reads the account's bounded liquidation history, so users need no indexer.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - `ManagedVec<LiquidationRecord>` ordered from oldest to newest; empty when the account
+    ///   was never liquidated.
+    #[view(getLiquidationHistory)]
+    fn account_liquidation_history(
+        &self,
+        account_nonce: u64,
+    ) -> ManagedVec<LiquidationRecord<Self::Api>> {
+        let buffer = self.liquidation_history(account_nonce);
+        let len = buffer.len();
+        let head = self.liquidation_history_head(account_nonce).get();
+
+        let mut history = ManagedVec::new();
+        for offset in 0..len {
+            history.push(buffer.get((head + offset) % len + 1));
+        }
+
+        history
+    }
+
+    /// Computes the interest accrued by an account on a token since a given timestamp.
+    /// Uses the latest index snapshot at or before `since_timestamp` as the starting point,
+    /// so the result is exact for positions whose scaled amount did not change since then.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `token_id`: Token identifier (EGLD or ESDT) to query.
+    /// - `since_timestamp`: Start of the period, in seconds.
+    ///
+    /// # Returns
+    /// - `MultiValue2` of the borrow interest and the supply interest in asset decimals; zero
+    ///   for a side the account holds no position on.
+    ///
+    /// # Panics
+    /// - If no index snapshot of the market was recorded at or before `since_timestamp`.
+    #[view(getInterestAccruedSince)]
+    fn interest_accrued_since(
+        &self,
+        account_nonce: u64,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        since_timestamp: TimestampSeconds,
+    ) -> MultiValue2<ManagedDecimal<Self::Api, NumDecimals>, ManagedDecimal<Self::Api, NumDecimals>>
+    {
+        let snapshot = self.index_snapshot_at(token_id, since_timestamp);
+        let mut cache = Cache::new(self);
+        let feed = self.token_price(token_id, &mut cache);
+        let current_index = cache.cached_market_index(token_id);
+
+        let borrow_interest = self.position_interest_since(
+            self.positions(account_nonce, AccountPositionType::Borrow)
+                .get(token_id),
+            &snapshot.borrow_index_ray,
+            &current_index.borrow_index_ray,
+            feed.asset_decimals,
+        );
+        let supply_interest = self.position_interest_since(
+            self.positions(account_nonce, AccountPositionType::Deposit)
+                .get(token_id),
+            &snapshot.supply_index_ray,
+            &current_index.supply_index_ray,
+            feed.asset_decimals,
+        );
+
+        (borrow_interest, supply_interest).into()
+    }
+
+    /// Finds the latest index snapshot of a market taken at or before a timestamp.
+    /// Snapshots are stored in timestamp order, so a binary search is used.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier of the market.
+    /// - `timestamp`: Timestamp to look up, in seconds.
+    ///
+    /// # Returns
+    /// - The matching `IndexSnapshot`.
+    fn index_snapshot_at(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+        timestamp: TimestampSeconds,
+    ) -> IndexSnapshot<Self::Api> {
+        let snapshots = self.index_snapshots(asset);
+        let mut low = 1;
+        let mut high = snapshots.len();
+        let mut found = 0;
+        while low <= high {
+            let middle = low + (high - low) / 2;
+            if snapshots.get(middle).timestamp <= timestamp {
+                found = middle;
+                low = middle + 1;
+            } else {
+                high = middle - 1;
+            }
+        }
+
+        require!(found != 0, ERROR_INDEX_SNAPSHOT_NOT_FOUND);
+        snapshots.get(found)
+    }
+
+    /// Computes the interest accrued by a position between two indexes.
+    ///
+    /// # Arguments
+    /// - `position`: Position to evaluate, if the account holds one.
+    /// - `start_index`: Index at the start of the period (RAY).
+    /// - `current_index`: Current index (RAY).
+    /// - `asset_decimals`: Decimals of the position asset.
+    ///
+    /// # Returns
+    /// - Accrued interest in asset decimals; zero without a position or when the index fell.
+    fn position_interest_since(
+        &self,
+        position: Option<AccountPosition<Self::Api>>,
+        start_index: &ManagedDecimal<Self::Api, NumDecimals>,
+        current_index: &ManagedDecimal<Self::Api, NumDecimals>,
+        asset_decimals: usize,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let zero = self.to_decimal(BigUint::zero(), asset_decimals);
+        let position = match position {
+            Some(position) => position,
+            None => return zero,
+        };
+
+        let start_amount =
+            self.scaled_to_original(&position.scaled_amount_ray, start_index, asset_decimals);
+        let current_amount =
+            self.scaled_to_original(&position.scaled_amount_ray, current_index, asset_decimals);
+        if current_amount > start_amount {
+            current_amount - start_amount
+        } else {
+            zero
+        }
+    }
+
+    /// Breaks the projected annual yield of a market's suppliers down into interest and rewards.
+    /// Interest uses the supply rate at the current utilization; rewards are annualized from the
+    /// distributions received since the latest index snapshot at or before `since_timestamp`.
+    ///
+    /// # Arguments
+    /// - `token_id`: Token identifier (EGLD or ESDT) of the market.
+    /// - `since_timestamp`: Start of the trailing reward period, in seconds.
+    ///
+    /// # Returns
+    /// - `DepositYieldBreakdown` with the interest, reward and total annual yield (RAY).
+    ///
+    /// # Panics
+    /// - If no index snapshot of the market was recorded at or before `since_timestamp`.
+    #[view(getDepositYieldBreakdown)]
+    fn deposit_yield_breakdown(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        since_timestamp: TimestampSeconds,
+    ) -> DepositYieldBreakdown<Self::Api> {
+        let snapshot = self.index_snapshot_at(token_id, since_timestamp);
+        let mut cache = Cache::new(self);
+        let (_, interest_apy_ray) = self.annual_market_rates(token_id, &mut cache);
+
+        let elapsed = self.blockchain().get_block_timestamp_seconds() - snapshot.timestamp;
+        let elapsed_ms = elapsed.as_u64_seconds() * 1000;
+        let reward_index = self.current_market_reward_index(token_id);
+        let reward_apy_ray = if elapsed_ms == 0 || reward_index <= snapshot.reward_index_ray {
+            self.ray_zero()
+        } else {
+            // Rewards per unit of deposit value held at the snapshot, scaled to a year
+            let period_yield = self.div_half_up(
+                &(reward_index - snapshot.reward_index_ray),
+                &snapshot.supply_index_ray,
+                RAY_PRECISION,
+            );
+            self.div_half_up(
+                &self.mul_half_up(
+                    &period_yield,
+                    &self.to_decimal(BigUint::from(MILLISECONDS_PER_YEAR), 0),
+                    RAY_PRECISION,
+                ),
+                &self.to_decimal(BigUint::from(elapsed_ms), 0),
+                RAY_PRECISION,
+            )
+        };
+
+        DepositYieldBreakdown {
+            total_apy_ray: interest_apy_ray.clone() + reward_apy_ray.clone(),
+            interest_apy_ray,
+            reward_apy_ray,
+        }
+    }
+
+    /// Retrieves the spread between the borrow and supply rate of multiple markets.
+    /// The spread is the interest margin kept by the protocol and lost to idle liquidity.
+    ///
+    /// # Arguments
+    /// - `assets`: List of token identifiers (EGLD or ESDT) to query.
+    ///
+    /// # Returns
+    /// - Vector of `MarketRateSpreadView` with the annual rates and their spread (RAY).
+    #[view(getMarketRateSpread)]
+    fn market_rate_spread(
+        &self,
+        assets: MultiValueEncoded<EgldOrEsdtTokenIdentifier>,
+    ) -> ManagedVec<MarketRateSpreadView<Self::Api>> {
+        let mut cache = Cache::new(self);
+        let mut spreads = ManagedVec::new();
+
+        for asset in assets {
+            let (borrow_rate_ray, supply_rate_ray) = self.annual_market_rates(&asset, &mut cache);
+            let spread_ray = if borrow_rate_ray > supply_rate_ray {
+                borrow_rate_ray.clone() - supply_rate_ray.clone()
+            } else {
+                self.ray_zero()
+            };
+
+            spreads.push(MarketRateSpreadView {
+                asset_id: asset,
+                borrow_rate_ray,
+                supply_rate_ray,
+                spread_ray,
+            });
+        }
+
+        spreads
+    }
+
+    /// Computes the annual borrow and supply rates of a market at its current utilization.
+    /// Mirrors the pool's `borrowRate` and `depositRate` on indexes accrued up to now,
+    /// annualized from their per-millisecond values.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier of the market.
+    /// - `cache`: Mutable storage cache for pool addresses and market indexes.
+    ///
+    /// # Returns
+    /// - Tuple of the annual borrow rate and the annual supply rate (RAY).
+    fn annual_market_rates(
+        &self,
+        asset: &EgldOrEsdtTokenIdentifier,
+        cache: &mut Cache<Self>,
+    ) -> (
+        ManagedDecimal<Self::Api, NumDecimals>,
+        ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        let pool_address = cache.cached_pool_address(asset);
+        let market_index = cache.cached_market_index(asset);
+        let parameters = self.parameters(pool_address.clone()).get();
+        let rate_settings = self.rate_settings(pool_address.clone());
+        let borrowed = self.scaled_to_original_ray(
+            &self.borrowed(pool_address.clone()).get(),
+            &market_index.borrow_index_ray,
+        );
+        let supplied = self.scaled_to_original_ray(
+            &self.supplied(pool_address).get(),
+            &market_index.supply_index_ray,
+        );
+
+        let utilization = self.utilization(&borrowed, &supplied);
+        let borrow_rate =
+            self.calculate_borrow_rate(utilization.clone(), parameters.clone(), &rate_settings);
+        let reserve_factor = self.reserve_factor_at(&utilization, &parameters, &rate_settings);
+        let supply_rate =
+            self.calculate_deposit_rate(utilization, borrow_rate.clone(), reserve_factor);
+
+        let milliseconds_per_year = self.to_decimal(BigUint::from(MILLISECONDS_PER_YEAR), 0);
+        (
+            self.mul_half_up(&borrow_rate, &milliseconds_per_year, RAY_PRECISION),
+            self.mul_half_up(&supply_rate, &milliseconds_per_year, RAY_PRECISION),
+        )
+    }
+
+    /// Decodes the attributes of an active account NFT into their structured form.
+    /// Lets integrators read e-mode, isolation and position mode without decoding the NFT payload.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - `AccountAttributes` with every attribute field of the account.
+    ///
+    /// # Panics
+    /// - If the account is not active in the market.
+    #[view(decodeAccountAttributes)]
+    fn decode_account_attributes(&self, account_nonce: u64) -> AccountAttributes<Self::Api> {
+        self.require_active_account(account_nonce);
+        self.account_attributes(account_nonce).get()
+    }
 }
@@ -1,5 +1,11 @@
-use common_constants::RAY;
-use controller::{ERROR_BORROW_CAP, ERROR_POSITION_LIMIT_EXCEEDED};
+use common_constants::{MIN_FIRST_TOLERANCE, RAY};
+use controller::{
+    AccountPositionType, CapDenomination, ERROR_ACCOUNT_NOT_IN_THE_MARKET,
+    ERROR_BORROW_ALLOWANCE_EXCEEDED, ERROR_BORROW_CAP, ERROR_BORROW_RATE_ABOVE_MAX_ACCEPTABLE,
+    ERROR_HEALTH_FACTOR_BELOW_REQUESTED_MINIMUM, ERROR_INVALID_MIN_RESERVE_RATIO,
+    ERROR_MAX_POSITION_VALUE_EXCEEDED, ERROR_MIN_RESERVE_RATIO, ERROR_NEW_BORROW_DISABLED,
+    ERROR_POSITION_LIMIT_EXCEEDED,
+};
 use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, MultiValueEncoded};
 use multiversx_sc_scenario::{
     api::StaticApi,
@@ -137,6 +143,495 @@ fn borrow_exceeds_cap_error() {
     );
 }
 
+/// Tests that an EGLD-denominated borrow cap keeps its value as the asset price moves.
+///
+/// Covers:
+/// - Controller::setBorrowCap endpoint with `CapDenomination::Egld`
+/// - Borrow cap validation against the EGLD value of the market debt
+/// - The effective token-unit limit rising when the asset price drops
+#[test]
+fn borrow_egld_denominated_cap_follows_price_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    // Let the final USDC price average the aggregator with the safe price
+    state.edit_token_oracle_tolerance(
+        &EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN),
+        BigUint::from(MIN_FIRST_TOLERANCE),
+        BigUint::from(10_000u64),
+    );
+
+    // 25 EGLD of USDC debt, i.e. 1000 USDC at $1 and $40 per EGLD
+    state.set_borrow_cap(
+        USDC_TOKEN,
+        scaled_amount(25, EGLD_DECIMALS),
+        CapDenomination::Egld,
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    state.borrow_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(900u64),
+        2,
+        USDC_DECIMALS,
+    );
+
+    // 1100 USDC is worth 27.5 EGLD at $1
+    state.borrow_asset_error(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(200u64),
+        2,
+        USDC_DECIMALS,
+        ERROR_BORROW_CAP,
+    );
+
+    // Aggregator drops USDC to $0.6, the averaged final price is $0.8
+    let dropped_price = BigUint::from(6u64) * BigUint::from(10u64).pow(17);
+    state.change_price_denominated(USDC_TICKER, dropped_price, 0);
+
+    // The same cap now admits 1250 USDC, so 1100 USDC (22 EGLD) fits
+    state.borrow_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(200u64),
+        2,
+        USDC_DECIMALS,
+    );
+    state.assert_borrow_raw_eq(
+        2,
+        &USDC_TOKEN,
+        scaled_amount(1100, USDC_DECIMALS),
+        "borrow within the price-adjusted cap should be tracked exactly",
+    );
+
+    // 1300 USDC (26 EGLD) still exceeds it
+    state.borrow_asset_error(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(200u64),
+        2,
+        USDC_DECIMALS,
+        ERROR_BORROW_CAP,
+    );
+}
+
+/// Tests that borrows cannot consume the market's minimum reserve.
+///
+/// Covers:
+/// - Controller::setMinReserveRatio endpoint
+/// - Reserve validation in positions::borrow::PositionBorrowModule
+/// - Borrowing up to the reserve-limited utilization
+/// - ERROR_MIN_RESERVE_RATIO error condition
+#[test]
+fn borrow_exceeds_min_reserve_ratio_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    // Keep 20% of EGLD liquidity unborrowed
+    state.set_min_reserve_ratio(EGLD_TOKEN, BigUint::from(2_000u64));
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Borrowing up to 80% utilization succeeds
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(80u64),
+        2, // account_nonce
+        EGLD_DECIMALS,
+    );
+
+    // Any further borrow would dip into the reserve
+    state.borrow_asset_error(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(1u64),
+        2, // account_nonce
+        EGLD_DECIMALS,
+        ERROR_MIN_RESERVE_RATIO,
+    );
+
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(80, EGLD_DECIMALS),
+        "failed borrow must not mutate reserve-limited debt",
+    );
+}
+
+/// Tests that the minimum reserve ratio cannot exceed 100%.
+///
+/// Covers:
+/// - Controller::setMinReserveRatio endpoint error path
+/// - ERROR_INVALID_MIN_RESERVE_RATIO error condition
+#[test]
+fn borrow_set_min_reserve_ratio_invalid_error() {
+    let mut state = LendingPoolTestState::new();
+
+    state.set_min_reserve_ratio_error(
+        EGLD_TOKEN,
+        BigUint::from(10_001u64),
+        ERROR_INVALID_MIN_RESERVE_RATIO,
+    );
+}
+
+/// Tests that a borrow allowance caps an account's total debt regardless of collateral.
+///
+/// Covers:
+/// - Controller::setAccountBorrowAllowance endpoint
+/// - ERROR_BORROW_ALLOWANCE_EXCEEDED error condition with ample collateral
+/// - Borrowing up to the allowance and again after the allowance is removed
+#[test]
+fn borrow_exceeds_account_borrow_allowance_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    // $5000 of collateral supports about 93 EGLD of debt
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Credit line of 10 EGLD
+    state.set_account_borrow_allowance(2, scaled_amount(10, EGLD_DECIMALS));
+
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(8u64),
+        2, // account_nonce
+        EGLD_DECIMALS,
+    );
+    state.borrow_asset_error(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(3u64),
+        2, // account_nonce
+        EGLD_DECIMALS,
+        ERROR_BORROW_ALLOWANCE_EXCEEDED,
+    );
+
+    // Borrowing exactly up to the allowance is allowed
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(2u64),
+        2, // account_nonce
+        EGLD_DECIMALS,
+    );
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(10, EGLD_DECIMALS),
+        "debt must stop at the account borrow allowance",
+    );
+
+    // Removing the allowance leaves only the collateral limit
+    state.set_account_borrow_allowance(2, BigUint::zero());
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(5u64),
+        2, // account_nonce
+        EGLD_DECIMALS,
+    );
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(15, EGLD_DECIMALS),
+        "borrow must resume without an allowance",
+    );
+}
+
+/// Tests that a borrow allowance can only be set on an active account.
+///
+/// Covers:
+/// - Controller::setAccountBorrowAllowance endpoint error path
+/// - ERROR_ACCOUNT_NOT_IN_THE_MARKET error condition
+#[test]
+fn borrow_set_account_borrow_allowance_inactive_account_error() {
+    let mut state = LendingPoolTestState::new();
+
+    state.set_account_borrow_allowance_error(
+        1,
+        scaled_amount(10, EGLD_DECIMALS),
+        ERROR_ACCOUNT_NOT_IN_THE_MARKET,
+    );
+}
+
+/// Tests that an account-level borrow cap override replaces the asset-wide cap.
+///
+/// Covers:
+/// - Controller::setAccountBorrowCap endpoint
+/// - Override lowering an account's exposure below the asset-wide cap
+/// - Override letting an account borrow beyond the asset-wide cap
+/// - Asset-wide cap applying again once the override is removed
+#[test]
+fn borrow_account_borrow_cap_override_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: CAPPED_TOKEN,
+            amount: BigUint::from(150u64),
+            asset_decimals: CAPPED_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // A lower override rejects a borrow the asset-wide cap of 100 would allow
+    state.set_account_borrow_cap(2, CAPPED_TOKEN, scaled_amount(5, CAPPED_DECIMALS));
+    state.borrow_asset_error(
+        &borrower,
+        CAPPED_TOKEN,
+        BigUint::from(10u64),
+        2, // account_nonce
+        CAPPED_DECIMALS,
+        ERROR_BORROW_CAP,
+    );
+
+    // A higher override admits debt beyond the asset-wide cap
+    state.set_account_borrow_cap(2, CAPPED_TOKEN, scaled_amount(120, CAPPED_DECIMALS));
+    state.borrow_asset(
+        &borrower,
+        CAPPED_TOKEN,
+        BigUint::from(110u64),
+        2, // account_nonce
+        CAPPED_DECIMALS,
+    );
+    state.assert_borrow_raw_eq(
+        2,
+        &CAPPED_TOKEN,
+        scaled_amount(110, CAPPED_DECIMALS),
+        "override must admit debt above the asset-wide cap",
+    );
+    state.borrow_asset_error(
+        &borrower,
+        CAPPED_TOKEN,
+        BigUint::from(20u64),
+        2, // account_nonce
+        CAPPED_DECIMALS,
+        ERROR_BORROW_CAP,
+    );
+
+    // Removing the override restores the asset-wide cap
+    state.set_account_borrow_cap(2, CAPPED_TOKEN, BigUint::zero());
+    state.borrow_asset_error(
+        &borrower,
+        CAPPED_TOKEN,
+        BigUint::from(1u64),
+        2, // account_nonce
+        CAPPED_DECIMALS,
+        ERROR_BORROW_CAP,
+    );
+}
+
+/// Tests that whitelisted addresses can borrow beyond the asset-wide borrow cap.
+///
+/// Covers:
+/// - Controller::setBorrowCapExemptAddresses endpoint
+/// - Whitelisted borrowers skipping the asset-wide cap
+/// - Non-whitelisted borrowers still bound by the cap
+/// - Cap applying again once an address is removed from the whitelist
+#[test]
+fn borrow_cap_exempt_addresses_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    let treasury = TestAddress::new("treasury");
+    let market_maker = TestAddress::new("market_maker");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+    setup_accounts(&mut state, treasury, market_maker);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: CAPPED_TOKEN,
+            amount: BigUint::from(150u64),
+            asset_decimals: CAPPED_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    for user in [&borrower, &treasury, &market_maker] {
+        state.supply_asset(
+            user,
+            SupplyParams {
+                token_id: EGLD_TOKEN,
+                amount: BigUint::from(100u64),
+                asset_decimals: EGLD_DECIMALS,
+                account_nonce: OptionalValue::None,
+                e_mode_category: OptionalValue::None,
+            },
+        );
+    }
+
+    state.set_borrow_cap_exempt_addresses(true, &[treasury, market_maker]);
+
+    // Regular borrowers stay bound by the asset-wide cap of 100
+    state.borrow_asset_error(
+        &borrower,
+        CAPPED_TOKEN,
+        BigUint::from(110u64),
+        2, // account_nonce
+        CAPPED_DECIMALS,
+        ERROR_BORROW_CAP,
+    );
+
+    // Whitelisted borrowers push the market debt beyond the cap
+    state.borrow_asset(
+        &treasury,
+        CAPPED_TOKEN,
+        BigUint::from(110u64),
+        3, // account_nonce
+        CAPPED_DECIMALS,
+    );
+    state.borrow_asset(
+        &market_maker,
+        CAPPED_TOKEN,
+        BigUint::from(30u64),
+        4, // account_nonce
+        CAPPED_DECIMALS,
+    );
+    state.assert_borrow_raw_eq(
+        3,
+        &CAPPED_TOKEN,
+        scaled_amount(110, CAPPED_DECIMALS),
+        "exempt address must borrow above the asset-wide cap",
+    );
+    state.borrow_asset_error(
+        &borrower,
+        CAPPED_TOKEN,
+        BigUint::from(1u64),
+        2, // account_nonce
+        CAPPED_DECIMALS,
+        ERROR_BORROW_CAP,
+    );
+
+    // Removing an address from the whitelist restores the cap for it only
+    state.set_borrow_cap_exempt_addresses(false, &[market_maker]);
+    state.borrow_asset_error(
+        &market_maker,
+        CAPPED_TOKEN,
+        BigUint::from(1u64),
+        4, // account_nonce
+        CAPPED_DECIMALS,
+        ERROR_BORROW_CAP,
+    );
+    state.borrow_asset(
+        &treasury,
+        CAPPED_TOKEN,
+        BigUint::from(5u64),
+        3, // account_nonce
+        CAPPED_DECIMALS,
+    );
+}
+
+/// Tests that a borrow cap override can only be set on an active account.
+///
+/// Covers:
+/// - Controller::setAccountBorrowCap endpoint error path
+/// - ERROR_ACCOUNT_NOT_IN_THE_MARKET error condition
+#[test]
+fn borrow_set_account_borrow_cap_inactive_account_error() {
+    let mut state = LendingPoolTestState::new();
+
+    state.set_account_borrow_cap_error(
+        1,
+        CAPPED_TOKEN,
+        scaled_amount(10, CAPPED_DECIMALS),
+        ERROR_ACCOUNT_NOT_IN_THE_MARKET,
+    );
+}
+
 /// Tests bulk borrowing of multiple assets in a single transaction for new positions.
 ///
 /// Covers:
@@ -202,24 +697,276 @@ fn borrow_bulk_new_positions_success() {
         0,
         BigUint::from(500u64) * BigUint::from(10u64.pow(USDC_DECIMALS as u32)),
     );
-    assets.push(usdc_borrow.clone());
+    assets.push(usdc_borrow.clone());
+
+    // Execute bulk borrow
+    state.borrow_assets(2, &borrower, assets);
+
+    state.assert_borrow_raw_eq(
+        2,
+        &USDC_TOKEN,
+        usdc_borrow.amount.clone(),
+        "USDC borrow recorded precisely",
+    );
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        egld_borrow.amount.clone(),
+        "EGLD borrow recorded precisely",
+    );
+    state.assert_health_factor_at_least(2, RAY);
+}
+
+/// Tests bulk borrowing the same token twice in a single transaction.
+///
+/// Covers:
+/// - Controller::borrow endpoint merging duplicate tokens before processing
+/// - Summed amounts recorded in a single borrow position
+/// - Other tokens of the bulk borrow processed unchanged
+#[test]
+fn borrow_bulk_duplicate_token_merged_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // USDC is listed twice around an EGLD borrow
+    let usdc = EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN.to_esdt_token_identifier());
+    let egld = EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN.to_esdt_token_identifier());
+    let mut assets: MultiValueEncoded<StaticApi, EgldOrEsdtTokenPayment<StaticApi>> =
+        MultiValueEncoded::new();
+    assets.push(EgldOrEsdtTokenPayment::new(
+        usdc.clone(),
+        0,
+        scaled_amount(200, USDC_DECIMALS),
+    ));
+    assets.push(EgldOrEsdtTokenPayment::new(
+        egld,
+        0,
+        scaled_amount(50, EGLD_DECIMALS),
+    ));
+    assets.push(EgldOrEsdtTokenPayment::new(
+        usdc,
+        0,
+        scaled_amount(300, USDC_DECIMALS),
+    ));
+
+    state.borrow_assets(2, &borrower, assets);
+
+    state.assert_borrow_raw_eq(
+        2,
+        &USDC_TOKEN,
+        scaled_amount(500, USDC_DECIMALS),
+        "Duplicate USDC borrows should be summed into one position",
+    );
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(50, EGLD_DECIMALS),
+        "EGLD borrow recorded precisely",
+    );
+    state.assert_health_factor_at_least(2, RAY);
+}
+
+/// Tests the minimum health factor guard on bulk borrows.
+///
+/// Covers:
+/// - Controller::borrowWithMinHealthFactor endpoint
+/// - Guard evaluated once on the health factor after all borrows
+/// - ERROR_HEALTH_FACTOR_BELOW_REQUESTED_MINIMUM when the buffer is not kept
+#[test]
+fn borrow_bulk_min_health_factor_guard() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    // $4000 of EGLD collateral, $3200 after the 80% liquidation threshold
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // $2000 of debt in total, so the resulting health factor is 1.6
+    let bulk_borrow = || {
+        let mut assets: MultiValueEncoded<StaticApi, EgldOrEsdtTokenPayment<StaticApi>> =
+            MultiValueEncoded::new();
+        assets.push(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN.to_esdt_token_identifier()),
+            0,
+            scaled_amount(1000, USDC_DECIMALS),
+        ));
+        assets.push(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN.to_esdt_token_identifier()),
+            0,
+            scaled_amount(25, EGLD_DECIMALS),
+        ));
+        assets
+    };
+
+    state.borrow_assets_with_min_health_factor_error(
+        2,
+        &borrower,
+        BigUint::from(RAY) * 2u64,
+        bulk_borrow(),
+        ERROR_HEALTH_FACTOR_BELOW_REQUESTED_MINIMUM,
+    );
+    state.assert_no_borrow_entry(2, &USDC_TOKEN);
+    state.assert_no_borrow_entry(2, &EGLD_TOKEN);
+
+    state.borrow_assets_with_min_health_factor(
+        2,
+        &borrower,
+        BigUint::from(RAY) * 3u64 / 2u64,
+        bulk_borrow(),
+    );
+    state.assert_borrow_raw_eq(
+        2,
+        &USDC_TOKEN,
+        scaled_amount(1000, USDC_DECIMALS),
+        "USDC borrow recorded after the guard passes",
+    );
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(25, EGLD_DECIMALS),
+        "EGLD borrow recorded after the guard passes",
+    );
+}
+
+/// Tests the maximum borrow rate guard on borrows.
+///
+/// Covers:
+/// - Controller::borrowWithMaxRate endpoint
+/// - Guard evaluated on the borrow rate at the post-borrow utilization
+/// - ERROR_BORROW_RATE_ABOVE_MAX_ACCEPTABLE when the borrow pushes the rate too high
+#[test]
+fn borrow_max_acceptable_rate_guard() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
 
-    // Execute bulk borrow
-    state.borrow_assets(2, &borrower, assets);
+    // 50 EGLD out of 200 supplied leaves the market at 25% utilization
+    let borrow = || {
+        let mut assets: MultiValueEncoded<StaticApi, EgldOrEsdtTokenPayment<StaticApi>> =
+            MultiValueEncoded::new();
+        assets.push(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN.to_esdt_token_identifier()),
+            0,
+            scaled_amount(50, EGLD_DECIMALS),
+        ));
+        assets
+    };
+    let rate_below = state
+        .borrow_rate_at(EGLD_TOKEN, BigUint::from(RAY) / 5u64)
+        .into_raw_units()
+        .clone();
+    let rate_above = state
+        .borrow_rate_at(EGLD_TOKEN, BigUint::from(RAY) * 3u64 / 10u64)
+        .into_raw_units()
+        .clone();
 
-    state.assert_borrow_raw_eq(
+    state.borrow_assets_with_max_rate_error(
         2,
-        &USDC_TOKEN,
-        usdc_borrow.amount.clone(),
-        "USDC borrow recorded precisely",
+        &borrower,
+        rate_below,
+        borrow(),
+        ERROR_BORROW_RATE_ABOVE_MAX_ACCEPTABLE,
     );
+    state.assert_no_borrow_entry(2, &EGLD_TOKEN);
+
+    state.borrow_assets_with_max_rate(2, &borrower, rate_above, borrow());
     state.assert_borrow_raw_eq(
         2,
         &EGLD_TOKEN,
-        egld_borrow.amount.clone(),
-        "EGLD borrow recorded precisely",
+        scaled_amount(50, EGLD_DECIMALS),
+        "EGLD borrow recorded after the guard passes",
     );
-    state.assert_health_factor_at_least(2, RAY);
 }
 
 /// Tests bulk borrowing when the account already has existing borrow positions.
@@ -501,3 +1248,311 @@ fn borrow_bulk_exceeds_position_limit_error() {
         "bulk borrow failure must leave account debt unchanged",
     );
 }
+
+/// Tests that the maximum position value caps an account's total debt.
+///
+/// Covers:
+/// - Controller::setMaxPositionValue endpoint
+/// - Controller::borrow growing the debt exactly to the cap
+/// - ERROR_MAX_POSITION_VALUE_EXCEEDED error condition with ample collateral
+#[test]
+fn borrow_exceeds_max_position_value_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    // $5000 of collateral supports about 93 EGLD of debt
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Positions may hold at most 10 EGLD of debt
+    state.set_max_position_value(scaled_amount(10, EGLD_DECIMALS));
+
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(10u64),
+        2, // account_nonce
+        EGLD_DECIMALS,
+    );
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(10, EGLD_DECIMALS),
+        "debt must grow up to the maximum position value",
+    );
+    state.borrow_asset_error(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(1u64),
+        2, // account_nonce
+        EGLD_DECIMALS,
+        ERROR_MAX_POSITION_VALUE_EXCEEDED,
+    );
+}
+
+/// Tests that protocol-owned accounts skip the per-account value caps on borrow.
+///
+/// Covers:
+/// - Controller::addProtocolOwnedAccount / removeProtocolOwnedAccount endpoints
+/// - Borrow allowance and maximum position value exemption for protocol-owned accounts
+/// - ERROR_BORROW_ALLOWANCE_EXCEEDED once the account leaves the whitelist
+#[test]
+fn borrow_protocol_owned_account_exceeds_value_caps_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    // $5000 of collateral supports about 93 EGLD of debt
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Both caps would stop the account at 10 EGLD of debt
+    state.set_account_borrow_allowance(2, scaled_amount(10, EGLD_DECIMALS));
+    state.set_max_position_value(scaled_amount(10, EGLD_DECIMALS));
+    state.add_protocol_owned_account(2);
+
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(15u64),
+        2, // account_nonce
+        EGLD_DECIMALS,
+    );
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(15, EGLD_DECIMALS),
+        "protocol-owned account should borrow past the value caps",
+    );
+
+    // Once removed from the whitelist, the caps apply again
+    state.remove_protocol_owned_account(2);
+    state.borrow_asset_error(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(1u64),
+        2, // account_nonce
+        EGLD_DECIMALS,
+        ERROR_BORROW_ALLOWANCE_EXCEEDED,
+    );
+}
+
+/// Tests disabling new borrows of a market independently of its supplies.
+///
+/// Covers:
+/// - Controller::setNewBorrowEnabled endpoint
+/// - ERROR_NEW_BORROW_DISABLED on borrow of the disabled asset
+/// - getMaxBorrowable reporting zero while new borrows are disabled
+/// - Repayment of existing debt and new supplies of the asset unaffected
+/// - Borrowing allowed again once new borrows are re-enabled
+#[test]
+fn borrow_new_borrow_disabled_keeps_existing_debt_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(10u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    state.set_new_borrow_enabled(EGLD_TOKEN, false);
+
+    state.borrow_asset_error(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+        ERROR_NEW_BORROW_DISABLED,
+    );
+    assert_eq!(
+        state.max_borrowable(2, &EGLD_TOKEN).into_raw_units(),
+        &BigUint::zero(),
+        "max borrowable should be zero while new borrows are disabled",
+    );
+
+    // Existing debt can be repaid and the market still accepts supplies
+    state.repay_asset(
+        &borrower,
+        &EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(9, EGLD_DECIMALS),
+        "existing debt should stay repayable",
+    );
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(1u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    state.set_new_borrow_enabled(EGLD_TOKEN, true);
+    state.borrow_asset(&borrower, EGLD_TOKEN, BigUint::from(1u64), 2, EGLD_DECIMALS);
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(10, EGLD_DECIMALS),
+        "borrow should succeed once re-enabled",
+    );
+}
+
+/// Tests that borrowing twice blends the entry borrow index of the position.
+///
+/// Covers:
+/// - Controller::borrow endpoint adding to an existing borrow
+/// - The entry index is weighted by the scaled amount added at each index
+/// - Accrued interest is unchanged by the second borrow
+#[test]
+fn borrow_twice_blends_entry_borrow_index_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    let first_index = state.market_borrow_index(state.egld_market.clone());
+    let first_entry = state.position_entry(2, AccountPositionType::Borrow, EGLD_TOKEN);
+    assert_eq!(first_entry.entry_index_ray, first_index);
+
+    state.change_timestamp(SECONDS_PER_DAY * 30);
+    let interest_before = state.position_accrued_interest(2, EGLD_TOKEN, true);
+
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(10u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    let second_index = state.market_borrow_index(state.egld_market.clone());
+    assert!(second_index > first_index);
+
+    // entry' = (scaled * entry + added * index) / scaled'
+    let entry = state.position_entry(2, AccountPositionType::Borrow, EGLD_TOKEN);
+    let first_scaled = first_entry.scaled_amount_ray.into_raw_units().clone();
+    let total_scaled = entry.scaled_amount_ray.into_raw_units().clone();
+    let added_scaled = total_scaled.clone() - first_scaled.clone();
+    let expected = (first_scaled * first_index.into_raw_units()
+        + added_scaled * second_index.into_raw_units())
+        / total_scaled;
+    let blended = entry.entry_index_ray.into_raw_units().clone();
+    assert!(blended.clone() + 1u64 >= expected);
+    assert!(blended <= expected + 1u64);
+    assert!(entry.entry_index_ray > first_index);
+    assert!(entry.entry_index_ray < second_index);
+
+    // The new principal enters at the current index, so it carries no interest
+    let interest_before = interest_before.into_raw_units().clone();
+    let interest_after = state.position_accrued_interest(2, EGLD_TOKEN, true);
+    assert!(interest_after.into_raw_units().clone() + 2u64 >= interest_before);
+    assert!(interest_after.into_raw_units() <= &(interest_before + 2u64));
+}
@@ -233,6 +233,130 @@ fn clean_bad_debt_supply_index_exact_factor() {
     );
 }
 
+#[test]
+fn clean_bad_debt_revenue_absorbs_loss_before_suppliers() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    let liquidator = TestAddress::new("liquidator");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.world.account(liquidator).nonce(1).esdt_balance(
+        USDC_TOKEN,
+        BigUint::from(1_000_000u64) * BigUint::from(10u64).pow(USDC_DECIMALS as u32),
+    );
+
+    let usdc = EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN);
+    let usdc_pool = state.pool_address(usdc.clone());
+    assert!(!state.market_revenue_absorbs_bad_debt(usdc_pool.clone()));
+    state.set_market_revenue_absorbs_bad_debt(&usdc, true);
+    assert!(state.market_revenue_absorbs_bad_debt(usdc_pool.clone()));
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(6000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(20u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(500u64),
+        2,
+        USDC_DECIMALS,
+    );
+
+    // Accrue interest, building protocol revenue, then leave bad debt behind
+    state.change_timestamp(880_000_000u64);
+    let mut markets = MultiValueEncoded::new();
+    markets.push(usdc.clone());
+    state.update_markets(&supplier, markets);
+    state.liquidate_account(
+        &liquidator,
+        &USDC_TOKEN,
+        BigUint::from(760u64),
+        2,
+        USDC_DECIMALS,
+    );
+
+    let supply_index_before = state.market_supply_index(usdc_pool.clone());
+    let supplied_before = state.market_supplied_amount(usdc_pool.clone());
+    let revenue_before = state.market_revenue(usdc_pool.clone());
+    let bad_debt = state.borrow_amount_for_token(2, USDC_TOKEN);
+    assert!(revenue_before.into_raw_units() > &BigUint::zero());
+
+    state.clean_bad_debt(2);
+
+    let supplied_raw = supplied_before.as_raw_units().clone();
+    let revenue_raw = revenue_before.as_raw_units().clone();
+    let debt_raw = bad_debt.as_raw_units().clone();
+    let covered_raw = if debt_raw > revenue_raw {
+        revenue_raw.clone()
+    } else {
+        debt_raw.clone()
+    };
+
+    // Revenue is burned first, up to the written-off debt
+    let revenue_after = state.market_revenue(usdc_pool.clone());
+    let expected_revenue_raw = revenue_raw - &covered_raw;
+    let revenue_after_raw = revenue_after.as_raw_units().clone();
+    let revenue_diff = if revenue_after_raw >= expected_revenue_raw {
+        revenue_after_raw - expected_revenue_raw
+    } else {
+        expected_revenue_raw - revenue_after_raw
+    };
+    assert!(revenue_diff <= BigUint::from(2u64));
+
+    // Only the uncovered remainder reduces the supply index of the remaining suppliers:
+    // index_after = index_before * (supplied - bad_debt) / (supplied - covered)
+    let ray = BigUint::from(RAY);
+    let reduction_factor_ray = half_up_div(
+        &((supplied_raw.clone() - &debt_raw) * &ray),
+        &(supplied_raw.clone() - &covered_raw),
+    );
+    let expected_index_raw = half_up_div(
+        &(supply_index_before.as_raw_units().clone() * &reduction_factor_ray),
+        &ray,
+    );
+    let socialized_index_raw = half_up_div(
+        &(supply_index_before.as_raw_units().clone()
+            * half_up_div(&((supplied_raw.clone() - &debt_raw) * &ray), &supplied_raw)),
+        &ray,
+    );
+
+    let after_raw = state.market_supply_index(usdc_pool).as_raw_units().clone();
+    let tol = small_ray_tolerance();
+    let index_diff = if after_raw >= expected_index_raw {
+        after_raw.clone() - &expected_index_raw
+    } else {
+        expected_index_raw.clone() - &after_raw
+    };
+    assert!(
+        index_diff <= tol,
+        "supply index post-clean differs from expected (±tol). expected={expected_index_raw:?} actual={after_raw:?} tol={tol:?}"
+    );
+    assert!(
+        after_raw > socialized_index_raw,
+        "suppliers must lose less than under full socialization"
+    );
+}
+
 #[test]
 fn clean_bad_debt_supply_index_clamped_min() {
     let mut state = LendingPoolTestState::new();
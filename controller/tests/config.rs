@@ -1,11 +1,13 @@
-use common_constants::{BPS, MIN_FIRST_TOLERANCE, MIN_LAST_TOLERANCE};
+use common_constants::{
+    BPS, DEFAULT_TWAP_ROUNDS, MAX_TWAP_ROUNDS, MIN_FIRST_TOLERANCE, MIN_LAST_TOLERANCE,
+};
 pub use common_constants::{BPS_PRECISION, RAY_PRECISION, WAD_PRECISION};
 
 use controller::{
     EModeAssetConfig, EModeCategory, ERROR_ASSET_ALREADY_SUPPORTED_IN_EMODE,
     ERROR_ASSET_NOT_SUPPORTED, ERROR_ASSET_NOT_SUPPORTED_IN_EMODE, ERROR_EMODE_CATEGORY_NOT_FOUND,
-    ERROR_INVALID_AGGREGATOR, ERROR_INVALID_LIQUIDATION_THRESHOLD,
-    ERROR_INVALID_LIQUIDITY_POOL_TEMPLATE, ERROR_INVALID_ONEDEX_PAIR_ID,
+    ERROR_INVALID_AGGREGATOR, ERROR_INVALID_EXCHANGE_SOURCE, ERROR_INVALID_LIQUIDATION_THRESHOLD,
+    ERROR_INVALID_LIQUIDITY_POOL_TEMPLATE, ERROR_INVALID_ONEDEX_PAIR_ID, ERROR_INVALID_TWAP_ROUNDS,
     ERROR_ORACLE_TOKEN_EXISTING, ERROR_ORACLE_TOKEN_NOT_FOUND, ERROR_UNEXPECTED_ANCHOR_TOLERANCES,
     ERROR_UNEXPECTED_FIRST_TOLERANCE, ERROR_UNEXPECTED_LAST_TOLERANCE,
 };
@@ -136,6 +138,189 @@ fn oracle_set_token_oracle_onedex_missing_pair_id_error() {
     );
 }
 
+/// Tests the TWAP pricing method is restricted to xExchange safe prices.
+///
+/// Covers:
+/// - Controller::setTokenOracle endpoint with PricingMethod::Twap
+/// - ERROR_INVALID_EXCHANGE_SOURCE error condition
+/// - Default TWAP window on newly configured oracles
+#[test]
+fn oracle_set_token_oracle_twap_requires_xexchange() {
+    let mut state = LendingPoolTestState::new();
+
+    let new_token = EgldOrEsdtTokenIdentifier::esdt(
+        TestTokenIdentifier::new("TWAPTOKEN-123456").to_esdt_token_identifier(),
+    );
+    let oracle_address = TestAddress::new("oracle").to_managed_address();
+
+    state.set_token_oracle_error(
+        &new_token,
+        TokenOracleParams {
+            decimals: 18usize,
+            contract_address: oracle_address.clone(),
+            pricing_method: PricingMethod::Twap,
+            oracle_type: OracleType::Normal,
+            source: ExchangeSource::Onedex,
+            first_tolerance: BigUint::from(MIN_FIRST_TOLERANCE),
+            last_tolerance: BigUint::from(MIN_LAST_TOLERANCE),
+            max_price_stale_seconds: DurationSeconds::new(3600),
+            one_dex_pair_id: OptionalValue::Some(1usize),
+        },
+        ERROR_INVALID_EXCHANGE_SOURCE,
+    );
+
+    state.set_token_oracle(
+        &new_token,
+        TokenOracleParams {
+            decimals: 18usize,
+            contract_address: oracle_address.clone(),
+            pricing_method: PricingMethod::Twap,
+            oracle_type: OracleType::Normal,
+            source: ExchangeSource::XExchange,
+            first_tolerance: BigUint::from(MIN_FIRST_TOLERANCE),
+            last_tolerance: BigUint::from(MIN_LAST_TOLERANCE),
+            max_price_stale_seconds: DurationSeconds::new(3600),
+            one_dex_pair_id: OptionalValue::None,
+        },
+    );
+
+    let oracle = state.token_oracle(new_token.clone());
+    assert!(oracle.pricing_method == PricingMethod::Twap);
+    assert_eq!(
+        state.token_oracle_twap_rounds(new_token),
+        DEFAULT_TWAP_ROUNDS
+    );
+}
+
+/// Tests editing the TWAP window of a token oracle.
+///
+/// Covers:
+/// - Controller::editTokenOracleTwapRounds endpoint
+/// - ERROR_INVALID_TWAP_ROUNDS for zero and oversized windows
+/// - ERROR_ORACLE_TOKEN_NOT_FOUND for tokens without an oracle
+#[test]
+fn oracle_edit_token_oracle_twap_rounds() {
+    let mut state = LendingPoolTestState::new();
+    let usdc = EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN.to_esdt_token_identifier());
+
+    state.edit_token_oracle_twap_rounds_error(&usdc, 0, ERROR_INVALID_TWAP_ROUNDS);
+    state.edit_token_oracle_twap_rounds_error(
+        &usdc,
+        MAX_TWAP_ROUNDS + 1,
+        ERROR_INVALID_TWAP_ROUNDS,
+    );
+    state.edit_token_oracle_twap_rounds_error(
+        &EgldOrEsdtTokenIdentifier::esdt(
+            TestTokenIdentifier::new("NOORACLE-123456").to_esdt_token_identifier(),
+        ),
+        2,
+        ERROR_ORACLE_TOKEN_NOT_FOUND,
+    );
+
+    state.edit_token_oracle_twap_rounds(&usdc, MAX_TWAP_ROUNDS);
+    assert_eq!(state.token_oracle_twap_rounds(usdc), MAX_TWAP_ROUNDS);
+}
+
+/// Tests configuring several token oracles in one batch transaction.
+///
+/// Covers:
+/// - Controller::batchSetTokenOracle endpoint
+/// - Each stored oracle matching its entry
+#[test]
+fn oracle_batch_set_token_oracle_success() {
+    let mut state = LendingPoolTestState::new();
+
+    let first_token = EgldOrEsdtTokenIdentifier::esdt(
+        TestTokenIdentifier::new("BATCHA-123456").to_esdt_token_identifier(),
+    );
+    let second_token = EgldOrEsdtTokenIdentifier::esdt(
+        TestTokenIdentifier::new("BATCHB-123456").to_esdt_token_identifier(),
+    );
+    let first_oracle = TestAddress::new("oracle-a").to_managed_address();
+    let second_oracle = TestAddress::new("oracle-b").to_managed_address();
+
+    state.batch_set_token_oracle(vec![
+        (
+            first_token.clone(),
+            TokenOracleParams {
+                decimals: 18usize,
+                contract_address: first_oracle.clone(),
+                pricing_method: PricingMethod::Safe,
+                oracle_type: OracleType::Derived,
+                source: ExchangeSource::XEGLD,
+                first_tolerance: BigUint::from(MIN_FIRST_TOLERANCE),
+                last_tolerance: BigUint::from(MIN_LAST_TOLERANCE),
+                max_price_stale_seconds: DurationSeconds::new(3600),
+                one_dex_pair_id: OptionalValue::None,
+            },
+        ),
+        (
+            second_token.clone(),
+            TokenOracleParams {
+                decimals: 6usize,
+                contract_address: second_oracle.clone(),
+                pricing_method: PricingMethod::Mix,
+                oracle_type: OracleType::Derived,
+                source: ExchangeSource::LEGLD,
+                first_tolerance: BigUint::from(MIN_FIRST_TOLERANCE),
+                last_tolerance: BigUint::from(MIN_LAST_TOLERANCE),
+                max_price_stale_seconds: DurationSeconds::new(600),
+                one_dex_pair_id: OptionalValue::None,
+            },
+        ),
+    ]);
+
+    let first = state.token_oracle(first_token);
+    assert_eq!(first.oracle_contract_address, first_oracle);
+    assert!(first.pricing_method == PricingMethod::Safe);
+    assert!(first.oracle_type == OracleType::Derived);
+    assert!(first.exchange_source == ExchangeSource::XEGLD);
+    assert_eq!(first.asset_decimals, 18usize);
+    assert_eq!(first.max_price_stale_seconds, DurationSeconds::new(3600));
+
+    let second = state.token_oracle(second_token);
+    assert_eq!(second.oracle_contract_address, second_oracle);
+    assert!(second.pricing_method == PricingMethod::Mix);
+    assert!(second.oracle_type == OracleType::Derived);
+    assert!(second.exchange_source == ExchangeSource::LEGLD);
+    assert_eq!(second.asset_decimals, 6usize);
+    assert_eq!(second.max_price_stale_seconds, DurationSeconds::new(600));
+}
+
+/// Tests batch oracle configuration fails when any entry already has an oracle.
+///
+/// Covers:
+/// - Single-asset validation reused per batch entry
+/// - ERROR_ORACLE_TOKEN_EXISTING error condition
+#[test]
+fn oracle_batch_set_token_oracle_already_exists_error() {
+    let mut state = LendingPoolTestState::new();
+
+    let new_token = EgldOrEsdtTokenIdentifier::esdt(
+        TestTokenIdentifier::new("BATCHA-123456").to_esdt_token_identifier(),
+    );
+    let oracle_address = TestAddress::new("oracle").to_managed_address();
+    let params = || TokenOracleParams {
+        decimals: 18usize,
+        contract_address: oracle_address.clone(),
+        pricing_method: PricingMethod::Safe,
+        oracle_type: OracleType::Derived,
+        source: ExchangeSource::XEGLD,
+        first_tolerance: BigUint::from(MIN_FIRST_TOLERANCE),
+        last_tolerance: BigUint::from(MIN_LAST_TOLERANCE),
+        max_price_stale_seconds: DurationSeconds::new(3600),
+        one_dex_pair_id: OptionalValue::None,
+    };
+
+    state.batch_set_token_oracle_error(
+        vec![
+            (new_token, params()),
+            (EgldOrEsdtTokenIdentifier::egld(), params()),
+        ],
+        ERROR_ORACLE_TOKEN_EXISTING,
+    );
+}
+
 /// Tests successful oracle tolerance update.
 ///
 /// Covers:
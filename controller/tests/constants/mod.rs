@@ -34,6 +34,7 @@ pub const LIQ_BONUS: u128 = 500; // 1%
 pub const E_MODE_LIQ_BONUS: u128 = 0; // 5%
 pub const LIQ_BASE_FEE: u128 = 500; // 1%
 pub const FLASH_LOAN_FEE: u128 = 50; // 0.05%
+pub const MAX_LIQUIDATION_CLOSE_FACTOR: u128 = 10_000; // 100%
 
 pub const ACCOUNT_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("ACC-abcdef");
 
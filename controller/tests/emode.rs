@@ -1,9 +1,13 @@
+use common_constants::{RAY, RAY_PRECISION};
 use controller::{
     ERROR_ASSET_NOT_BORROWABLE, ERROR_ASSET_NOT_BORROWABLE_IN_ISOLATION,
-    ERROR_EMODE_CATEGORY_NOT_FOUND,
+    ERROR_EMODE_CATEGORY_NOT_FOUND, ERROR_EMODE_CATEGORY_UNCHANGED,
+    ERROR_EMODE_COMPANION_COLLATERAL_REQUIRED,
 };
-use multiversx_sc::types::ManagedDecimal;
-use multiversx_sc_scenario::imports::{BigUint, OptionalValue, TestAddress};
+use multiversx_sc::types::{
+    EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, ManagedDecimal, ManagedVec, NumDecimals,
+};
+use multiversx_sc_scenario::imports::{BigUint, OptionalValue, StaticApi, TestAddress};
 pub mod constants;
 pub mod proxys;
 pub mod setup;
@@ -67,6 +71,126 @@ fn emode_supply_and_borrow_same_category_success() {
     assert!(collateral > ManagedDecimal::from_raw_units(BigUint::zero(), EGLD_DECIMALS));
 }
 
+/// Tests that outstanding debt is aggregated per E-Mode category.
+///
+/// Covers:
+/// - Controller::getAggregateBorrowByEMode view
+/// - Borrows with and without E-Mode tracked in separate categories
+/// - Repayments reducing only the category of the repaid account
+/// - Overpayments never counted beyond the outstanding debt
+#[test]
+fn emode_aggregate_borrow_by_category_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    // Supplier provides XEGLD and EGLD liquidity
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Account 2 borrows XEGLD under E-Mode category 1
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        XEGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // Account 3 borrows EGLD without E-Mode
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(10_000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(10u64),
+        3,
+        EGLD_DECIMALS,
+    );
+
+    let e_mode_debt = state.total_borrow_in_egld(2);
+    let debt_of = |aggregates: &[(u8, ManagedDecimal<StaticApi, NumDecimals>)], id: u8| {
+        aggregates
+            .iter()
+            .find(|(category_id, _)| *category_id == id)
+            .map(|(_, debt)| debt.into_raw_units().clone())
+            .unwrap()
+    };
+
+    let aggregates = state.aggregate_borrow_by_e_mode();
+    assert_eq!(debt_of(&aggregates, 0), scaled_amount(10, EGLD_DECIMALS));
+    assert_eq!(
+        debt_of(&aggregates, 1),
+        e_mode_debt.into_raw_units().clone()
+    );
+
+    // A partial repayment only reduces the category of the repaid account
+    state.repay_asset(
+        &borrower,
+        &EGLD_TOKEN,
+        BigUint::from(4u64),
+        3,
+        EGLD_DECIMALS,
+    );
+    let aggregates = state.aggregate_borrow_by_e_mode();
+    assert_eq!(debt_of(&aggregates, 0), scaled_amount(6, EGLD_DECIMALS));
+    assert_eq!(
+        debt_of(&aggregates, 1),
+        e_mode_debt.into_raw_units().clone()
+    );
+
+    // Overpaying the E-Mode debt empties its category without touching the other
+    state.repay_asset(
+        &borrower,
+        &XEGLD_TOKEN,
+        BigUint::from(60u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    let aggregates = state.aggregate_borrow_by_e_mode();
+    assert_eq!(debt_of(&aggregates, 0), scaled_amount(6, EGLD_DECIMALS));
+    assert_eq!(debt_of(&aggregates, 1), BigUint::zero());
+}
+
 /// Tests supply attempt with invalid E-Mode category.
 ///
 /// Covers:
@@ -206,3 +330,356 @@ fn emode_borrow_non_borrowable_asset_error() {
         ERROR_ASSET_NOT_BORROWABLE,
     );
 }
+
+/// Tests that an account stops being fully backed once its collateral leaves its E-Mode category.
+///
+/// Covers:
+/// - Controller::isFullyBacked view
+/// - Collateral eligibility under the account's E-Mode category
+/// - Mode-eligibility violation while the health factor still passes
+#[test]
+fn emode_is_fully_backed_after_collateral_removed_from_category() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        XEGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    assert!(state.is_fully_backed(2));
+
+    // EGLD can no longer back debt in the category, the stored position keeps its threshold
+    state.remove_asset_from_e_mode_category(EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN), 1);
+
+    let health_factor = state.account_health_factor(2);
+    assert!(health_factor >= ManagedDecimal::from_raw_units(BigUint::from(RAY), RAY_PRECISION));
+    assert!(!state.is_fully_backed(2));
+}
+
+/// Tests that a companion-required e-mode collateral cannot back a borrow on its own.
+///
+/// Covers:
+/// - Controller::setEModeCompanionCollateralRequired flag
+/// - Controller::borrow rejecting a companion-required asset as sole collateral
+/// - ERROR_EMODE_COMPANION_COLLATERAL_REQUIRED error condition
+#[test]
+fn emode_borrow_sole_companion_required_collateral_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.set_e_mode_companion_collateral_required(
+        EgldOrEsdtTokenIdentifier::esdt(XEGLD_TOKEN.to_esdt_token_identifier()),
+        1,
+        true,
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+
+    // XEGLD is the only collateral of the e-mode account
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: XEGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+
+    state.borrow_asset_error(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(10u64),
+        2,
+        EGLD_DECIMALS,
+        ERROR_EMODE_COMPANION_COLLATERAL_REQUIRED,
+    );
+}
+
+/// Tests that a companion-required e-mode collateral backs a borrow next to another collateral.
+///
+/// Covers:
+/// - Controller::borrow with a companion-required asset and a second eligible collateral
+/// - Companion collateral requirement satisfied by any other e-mode collateral
+#[test]
+fn emode_borrow_companion_required_collateral_with_companion_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.set_e_mode_companion_collateral_required(
+        EgldOrEsdtTokenIdentifier::esdt(XEGLD_TOKEN.to_esdt_token_identifier()),
+        1,
+        true,
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: XEGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: XEGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    // EGLD is an eligible e-mode collateral of the same category
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(10u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+
+    state.borrow_asset(
+        &borrower,
+        XEGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        XEGLD_DECIMALS,
+    );
+
+    let borrowed = state.borrow_amount_for_token(2, XEGLD_TOKEN);
+    assert!(borrowed > ManagedDecimal::from_raw_units(BigUint::zero(), XEGLD_DECIMALS));
+}
+
+/// Opens an E-Mode category 1 account holding EGLD collateral with XEGLD and EGLD debt,
+/// next to a category 2 where XEGLD can only be supplied.
+fn setup_e_mode_roll(
+    state: &mut LendingPoolTestState,
+    supplier: TestAddress,
+    borrower: TestAddress,
+) {
+    state.change_timestamp(0);
+    setup_accounts(state, supplier, borrower);
+
+    state.add_e_mode_category(
+        BigUint::from(8_500u64),
+        BigUint::from(9_000u64),
+        BigUint::from(200u64),
+    );
+    state.add_asset_to_e_mode_category(
+        EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN.to_esdt_token_identifier()),
+        2,
+        true,
+        true,
+    );
+    state.add_asset_to_e_mode_category(
+        EgldOrEsdtTokenIdentifier::esdt(XEGLD_TOKEN.to_esdt_token_identifier()),
+        2,
+        true,
+        false,
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: XEGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        XEGLD_TOKEN,
+        BigUint::from(20u64),
+        2,
+        XEGLD_DECIMALS,
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(10u64),
+        2,
+        EGLD_DECIMALS,
+    );
+}
+
+/// Tests rolling an account into a category where part of its debt is not borrowable.
+///
+/// Covers:
+/// - Controller::rollEMode endpoint error path
+/// - ERROR_ASSET_NOT_BORROWABLE when the incompatible debt is not repaid
+/// - ERROR_EMODE_CATEGORY_UNCHANGED when rolling into the current category
+/// - Account attributes left untouched by the reverted roll
+#[test]
+fn emode_roll_with_incompatible_debt_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    setup_e_mode_roll(&mut state, supplier, borrower);
+
+    // XEGLD debt cannot be carried into category 2
+    state.roll_e_mode_error(
+        &borrower,
+        2,
+        2,
+        ManagedVec::new(),
+        ManagedVec::new(),
+        ERROR_ASSET_NOT_BORROWABLE,
+    );
+    state.roll_e_mode_error(
+        &borrower,
+        2,
+        1,
+        ManagedVec::new(),
+        ManagedVec::new(),
+        ERROR_EMODE_CATEGORY_UNCHANGED,
+    );
+
+    assert_eq!(state.account_attributes(2).e_mode_category_id, 1);
+}
+
+/// Tests rolling an account into another category by repaying its incompatible debt.
+///
+/// Covers:
+/// - Controller::rollEMode repaying, switching and supplying atomically
+/// - Account NFT returned with the new category in its attributes
+/// - Deposit risk parameters re-applied from the new category
+/// - Remaining debt moved between the E-Mode debt aggregates
+#[test]
+fn emode_roll_repaying_incompatible_debt_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    setup_e_mode_roll(&mut state, supplier, borrower);
+
+    let collateral_before = state.collateral_amount_for_token(2, EGLD_TOKEN);
+
+    // Overpay the XEGLD debt and top up EGLD collateral in the same transaction
+    let mut repay_payments = ManagedVec::new();
+    repay_payments.push(EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(XEGLD_TOKEN.to_esdt_token_identifier()),
+        0,
+        scaled_amount(25, XEGLD_DECIMALS),
+    ));
+    let mut collaterals = ManagedVec::new();
+    collaterals.push(EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN.to_esdt_token_identifier()),
+        0,
+        scaled_amount(10, EGLD_DECIMALS),
+    ));
+    state.roll_e_mode(&borrower, 2, 2, repay_payments, collaterals);
+
+    let account_attributes = state.account_attributes(2);
+    assert_eq!(account_attributes.e_mode_category_id, 2);
+    state
+        .world
+        .check_account(borrower)
+        .esdt_nft_balance_and_attributes(ACCOUNT_TOKEN, 2, BigUint::from(1u64), account_attributes);
+
+    assert!(state.collateral_amount_for_token(2, EGLD_TOKEN) > collateral_before);
+    let egld_position = state
+        .deposit_positions(2)
+        .into_iter()
+        .find_map(|entry| {
+            let (token, position) = entry.into_tuple();
+            if token == EGLD_TOKEN {
+                Some(position)
+            } else {
+                None
+            }
+        })
+        .unwrap();
+    assert_eq!(
+        egld_position.liquidation_threshold_bps.into_raw_units(),
+        &BigUint::from(9_000u64)
+    );
+
+    // Only the EGLD debt remains and it now counts towards category 2
+    let aggregates = state.aggregate_borrow_by_e_mode();
+    let debt_of = |id: u8| {
+        aggregates
+            .iter()
+            .find(|(category_id, _)| *category_id == id)
+            .map(|(_, debt)| debt.into_raw_units().clone())
+            .unwrap()
+    };
+    assert_eq!(debt_of(1), BigUint::zero());
+    assert_eq!(debt_of(2), scaled_amount(10, EGLD_DECIMALS));
+    state.assert_health_factor_at_least(2, RAY);
+}
@@ -1,7 +1,10 @@
+use common_constants::BPS;
 use common_errors::*;
 
+use controller::storage::Storage;
 use multiversx_sc::types::{ManagedArgBuffer, ManagedBuffer};
 use multiversx_sc_scenario::imports::{BigUint, OptionalValue, StaticApi, TestAddress};
+use multiversx_sc_scenario::ScenarioTxWhitebox;
 pub mod constants;
 pub mod proxys;
 pub mod setup;
@@ -70,6 +73,233 @@ fn flash_loan_full_repayment_success() {
     );
 }
 
+/// Tests that the quoted flash loan fee is exactly what the pool collects.
+///
+/// Covers:
+/// - Controller::getFlashLoanFee view
+/// - Quote matching the asset's flash loan fee
+/// - Reserves growing by the quoted fee after a flash loan
+#[test]
+fn flash_loan_fee_quote_matches_repayment_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    let fee = state.flash_loan_fee(EGLD_TOKEN, flash_amount_raw());
+    assert_eq!(
+        fee,
+        flash_amount_raw() * BigUint::from(FLASH_LOAN_FEE) / BigUint::from(BPS),
+        "Quoted fee should apply the asset's flash loan fee",
+    );
+
+    let reserves_before = state
+        .market_reserves(state.egld_market.clone())
+        .as_raw_units()
+        .clone();
+
+    state.flash_loan(
+        &OWNER_ADDRESS,
+        &EGLD_TOKEN,
+        flash_amount_raw(),
+        state.flash_mock.clone(),
+        ManagedBuffer::from("flash"),
+        ManagedArgBuffer::new(),
+    );
+
+    let reserves_after = state
+        .market_reserves(state.egld_market.clone())
+        .as_raw_units()
+        .clone();
+    assert_eq!(
+        reserves_after - reserves_before,
+        fee,
+        "Reserves should grow by exactly the quoted fee",
+    );
+}
+
+/// Tests that the quoted flash loan fee is the exact repayment requirement across amounts.
+///
+/// Covers:
+/// - Controller::getFlashLoanFee view for several amounts
+/// - Reserves growing by the quoted fee for each flash loan
+/// - Half-up rounding of the quote rejecting a repayment one unit short
+#[test]
+fn flash_loan_fee_quote_matches_repayment_across_amounts_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    for amount_raw in [
+        scaled_amount(1, EGLD_DECIMALS),
+        scaled_amount(37, EGLD_DECIMALS),
+        scaled_amount(90, EGLD_DECIMALS),
+        BigUint::from(1_000_000u64),
+    ] {
+        let fee = state.flash_loan_fee(EGLD_TOKEN, amount_raw.clone());
+        assert_eq!(
+            fee,
+            amount_raw.clone() * BigUint::from(FLASH_LOAN_FEE) / BigUint::from(BPS),
+            "Quoted fee should apply the asset's flash loan fee",
+        );
+
+        let reserves_before = state
+            .market_reserves(state.egld_market.clone())
+            .as_raw_units()
+            .clone();
+
+        state.flash_loan(
+            &OWNER_ADDRESS,
+            &EGLD_TOKEN,
+            amount_raw,
+            state.flash_mock.clone(),
+            ManagedBuffer::from("flash"),
+            ManagedArgBuffer::new(),
+        );
+
+        let reserves_after = state
+            .market_reserves(state.egld_market.clone())
+            .as_raw_units()
+            .clone();
+        assert_eq!(
+            reserves_after - reserves_before,
+            fee,
+            "Reserves should grow by exactly the quoted fee",
+        );
+    }
+
+    // Half a unit of fee rounds up, so the mock's truncated repayment falls one unit short
+    let amount_raw = BigUint::from(100u64);
+    assert_eq!(
+        state.flash_loan_fee(EGLD_TOKEN, amount_raw.clone()),
+        BigUint::from(1u64),
+        "Quoted fee should round half a unit up",
+    );
+    state.flash_loan_error(
+        &OWNER_ADDRESS,
+        FlashLoanParams {
+            token: EGLD_TOKEN,
+            amount: amount_raw,
+            contract: state.flash_mock.clone(),
+            endpoint: ManagedBuffer::from("flash"),
+            arguments: ManagedArgBuffer::new(),
+        },
+        ERROR_INVALID_FLASHLOAN_REPAYMENT,
+    );
+}
+
+/// Tests that the flash loan protocol share is routed out of the pool.
+///
+/// Covers:
+/// - Controller::setFlashLoanProtocolShare endpoint
+/// - Pool keeping only the remainder of the flash loan fee
+/// - ERROR_INVALID_FLASH_LOAN_PROTOCOL_SHARE above 10000 basis points
+#[test]
+fn flash_loan_protocol_share_routes_fee_to_accumulator_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    state.set_flash_loan_protocol_share_error(
+        BPS as u64 + 1,
+        ERROR_INVALID_FLASH_LOAN_PROTOCOL_SHARE,
+    );
+    state.set_flash_loan_protocol_share(4_000);
+
+    let fee = state.flash_loan_fee(EGLD_TOKEN, flash_amount_raw());
+    let accumulator_fee = fee.clone() * BigUint::from(4_000u64) / BigUint::from(BPS);
+    let reserves_before = state
+        .market_reserves(state.egld_market.clone())
+        .as_raw_units()
+        .clone();
+    let revenue_before = state
+        .market_revenue(state.egld_market.clone())
+        .as_raw_units()
+        .clone();
+
+    state.flash_loan(
+        &OWNER_ADDRESS,
+        &EGLD_TOKEN,
+        flash_amount_raw(),
+        state.flash_mock.clone(),
+        ManagedBuffer::from("flash"),
+        ManagedArgBuffer::new(),
+    );
+
+    let reserves_after = state
+        .market_reserves(state.egld_market.clone())
+        .as_raw_units()
+        .clone();
+    let revenue_after = state
+        .market_revenue(state.egld_market.clone())
+        .as_raw_units()
+        .clone();
+    assert_eq!(
+        reserves_after - reserves_before,
+        fee - accumulator_fee,
+        "Pool should keep only the remainder of the fee",
+    );
+    assert!(
+        revenue_after > revenue_before,
+        "Remainder should still accrue as pool revenue",
+    );
+
+    // The whole fee can be routed to the accumulator
+    state.set_flash_loan_protocol_share(BPS as u64);
+    let reserves_before = reserves_after;
+    state.flash_loan(
+        &OWNER_ADDRESS,
+        &EGLD_TOKEN,
+        flash_amount_raw(),
+        state.flash_mock.clone(),
+        ManagedBuffer::from("flash"),
+        ManagedArgBuffer::new(),
+    );
+    let reserves_after = state
+        .market_reserves(state.egld_market.clone())
+        .as_raw_units()
+        .clone();
+    assert_eq!(
+        reserves_after, reserves_before,
+        "Pool should keep none of the fee",
+    );
+}
+
 /// Tests flash loan failure when borrower doesn't repay.
 ///
 /// Covers:
@@ -329,3 +559,74 @@ fn flash_loan_builtin_functions_blocked_error() {
         );
     }
 }
+
+/// Tests that the flash loan fee stays in the pool when no accumulator is configured.
+///
+/// Covers:
+/// - Controller::flashLoan endpoint with a protocol share but no accumulator
+/// - Pool keeping the whole flash loan fee as revenue
+#[test]
+fn flash_loan_protocol_share_without_accumulator_keeps_fee_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.set_flash_loan_protocol_share(4_000);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(state.lending_sc.clone())
+        .whitebox(controller::contract_obj, |sc| {
+            sc.accumulator_address().clear();
+        });
+
+    let fee = state.flash_loan_fee(EGLD_TOKEN, flash_amount_raw());
+    let reserves_before = state
+        .market_reserves(state.egld_market.clone())
+        .as_raw_units()
+        .clone();
+    let revenue_before = state
+        .market_revenue(state.egld_market.clone())
+        .as_raw_units()
+        .clone();
+
+    state.flash_loan(
+        &OWNER_ADDRESS,
+        &EGLD_TOKEN,
+        flash_amount_raw(),
+        state.flash_mock.clone(),
+        ManagedBuffer::from("flash"),
+        ManagedArgBuffer::new(),
+    );
+
+    let reserves_after = state
+        .market_reserves(state.egld_market.clone())
+        .as_raw_units()
+        .clone();
+    let revenue_after = state
+        .market_revenue(state.egld_market.clone())
+        .as_raw_units()
+        .clone();
+    assert_eq!(
+        reserves_after - reserves_before,
+        fee,
+        "Pool should keep the whole fee without an accumulator",
+    );
+    assert!(
+        revenue_after > revenue_before,
+        "The fee should accrue as pool revenue",
+    );
+}
@@ -254,6 +254,65 @@ fn isolated_borrow_exceeds_debt_ceiling_error() {
     state.assert_no_borrow_entry(2, &USDC_TOKEN);
 }
 
+/// Tests the combined debt ceiling usage view of an isolated asset.
+///
+/// Covers:
+/// - Controller::getIsolatedDebtInfo before and after an isolated borrow
+/// - Remaining headroom equal to the ceiling minus the used debt
+#[test]
+fn isolated_debt_info_reports_remaining_headroom() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: ISOLATED_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: ISOLATED_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Nothing borrowed yet: the whole $1000 ceiling is available
+    let ceiling_usd = ManagedDecimal::from_raw_units(scaled_amount(1000, 18), WAD_PRECISION);
+    let (used, ceiling, remaining) = state.isolated_debt_info(&ISOLATED_TOKEN);
+    assert_eq!(
+        used,
+        ManagedDecimal::from_raw_units(BigUint::zero(), WAD_PRECISION)
+    );
+    assert_eq!(ceiling, ceiling_usd);
+    assert_eq!(remaining, ceiling_usd);
+
+    state.borrow_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(100u64),
+        2,
+        USDC_DECIMALS,
+    );
+
+    let (used, ceiling, remaining) = state.isolated_debt_info(&ISOLATED_TOKEN);
+    assert_eq!(used, state.used_isolated_asset_debt_usd(&ISOLATED_TOKEN));
+    assert!(used > ManagedDecimal::from_raw_units(BigUint::zero(), WAD_PRECISION));
+    assert_eq!(ceiling, ceiling_usd);
+    assert_eq!(remaining + used, ceiling);
+}
+
 /// Tests debt ceiling tracking with interest accrual on isolated collateral.
 ///
 /// Covers:
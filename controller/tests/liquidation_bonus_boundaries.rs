@@ -1,4 +1,5 @@
 use common_constants::BPS_PRECISION;
+use controller::ERROR_INVALID_LIQUIDATEE_REBATE;
 use multiversx_sc::types::{
     EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, ManagedDecimal, ManagedVec,
 };
@@ -73,6 +74,48 @@ fn setup_unhealthy_single_asset_position() -> (
     (state, borrower, borrower_nonce, liquidator)
 }
 
+/// The estimate reports the liquidator's profit after the bonus and protocol fees,
+/// and how much of each debt token a bot can repay on its own.
+#[test]
+fn liquidation_estimate_reports_profit_and_max_repayable_per_token() {
+    let (mut state, _borrower, nonce, _liq) = setup_unhealthy_single_asset_position();
+
+    let empty = ManagedVec::<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>::new();
+    let estimate = state.liquidation_estimations(nonce, empty);
+
+    // EGLD is both collateral and debt, so token amounts equal their EGLD value
+    let seized = estimate.seized_collaterals.get(0).amount.clone();
+    let fees = estimate.protocol_fees.get(0).amount.clone();
+    let max_payment = estimate.max_egld_payment_wad.as_raw_units().clone();
+    assert!(fees > BigUint::zero());
+    assert!(estimate.liquidator_profit_egld_wad.as_raw_units() > &BigUint::zero());
+    assert_eq!(
+        estimate.liquidator_profit_egld_wad.as_raw_units(),
+        &(seized - fees - max_payment.clone())
+    );
+
+    assert_eq!(estimate.max_repayable_per_debt_token.len(), 1);
+    let repayable = estimate.max_repayable_per_debt_token.get(0);
+    assert_eq!(
+        repayable.token_identifier,
+        EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN.to_esdt_token_identifier())
+    );
+    assert_eq!(repayable.amount, max_payment);
+
+    // Repaying the sized amount keeps the same profitable outcome
+    let mut payments = ManagedVec::new();
+    payments.push(EgldOrEsdtTokenPayment::new(
+        repayable.token_identifier.clone(),
+        0,
+        repayable.amount.clone(),
+    ));
+    let sized = state.liquidation_estimations(nonce, payments);
+    assert_eq!(
+        sized.liquidator_profit_egld_wad,
+        estimate.liquidator_profit_egld_wad
+    );
+}
+
 /// When repayment is capped but within the 100 bps tolerance, the effective bonus
 /// should remain the scaled (dynamic) bonus – equal to the uncapped simulation.
 #[test]
@@ -186,3 +229,43 @@ fn liquidation_zero_payment_estimation_matches_empty() {
     assert!(est_zero.bonus_rate_bps == est_empty.bonus_rate_bps);
     assert!(est_zero.max_egld_payment_wad == est_empty.max_egld_payment_wad);
 }
+
+/// With a liquidatee rebate configured, the liquidator seizes less collateral for the
+/// same repayment and the rebated share of the bonus stays in the borrower's position.
+#[test]
+fn liquidation_with_liquidatee_rebate_credits_borrower() {
+    let (mut base_state, _borrower, nonce, base_liq) = setup_unhealthy_single_asset_position();
+    let (mut rebate_state, _borrower, _, rebate_liq) = setup_unhealthy_single_asset_position();
+    rebate_state.set_liquidatee_rebate(5_000);
+
+    let empty = ManagedVec::<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>::new();
+    let base_est = base_state.liquidation_estimations(nonce, empty.clone());
+    let rebate_est = rebate_state.liquidation_estimations(nonce, empty);
+
+    // The rebate does not change how much debt is repaid nor the applied bonus
+    assert!(rebate_est.max_egld_payment_wad == base_est.max_egld_payment_wad);
+    assert!(rebate_est.bonus_rate_bps == base_est.bonus_rate_bps);
+
+    // Half of the bonus portion is no longer seized
+    let base_seized = base_est.seized_collaterals.get(0).amount.clone();
+    let rebate_seized = rebate_est.seized_collaterals.get(0).amount.clone();
+    assert!(rebate_seized < base_seized);
+
+    let amount = base_est.max_egld_payment_wad.as_raw_units().clone();
+    base_state.liquidate_account_dem(&base_liq, &EGLD_TOKEN, amount.clone(), nonce);
+    rebate_state.liquidate_account_dem(&rebate_liq, &EGLD_TOKEN, amount, nonce);
+
+    let base_collateral = base_state.collateral_amount_for_token(nonce, EGLD_TOKEN);
+    let rebate_collateral = rebate_state.collateral_amount_for_token(nonce, EGLD_TOKEN);
+    assert!(
+        rebate_collateral > base_collateral,
+        "Rebated bonus share should remain in the liquidated account"
+    );
+}
+
+/// The liquidatee rebate is a share of the bonus and cannot exceed 100%.
+#[test]
+fn set_liquidatee_rebate_above_bps_error() {
+    let mut state = LendingPoolTestState::new();
+    state.set_liquidatee_rebate_error(10_001, ERROR_INVALID_LIQUIDATEE_REBATE);
+}
@@ -1,13 +1,19 @@
-use common_constants::RAY;
 pub use common_constants::{BPS_PRECISION, RAY_PRECISION, WAD_PRECISION};
+use common_constants::{LIQUIDATION_HISTORY_CAPACITY, MIN_FIRST_TOLERANCE, RAY};
 
-use controller::ERROR_INSUFFICIENT_COLLATERAL;
+use controller::{
+    storage::Storage, ERROR_DELISTED_PRICE_TOO_OLD, ERROR_INSUFFICIENT_COLLATERAL,
+    ERROR_INVALID_LIQUIDATION_CLOSE_FACTOR, ERROR_INVALID_LIQUIDATION_HAIRCUT,
+    ERROR_INVALID_MAX_LIQUIDATION_PRICE_DROP, ERROR_POST_LIQUIDATION_GRACE,
+    ERROR_SEIZED_COLLATERAL_BELOW_MINIMUM, ERROR_THRESHOLD_UPDATE_GRACE,
+};
 
 use multiversx_sc::types::{
     EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, ManagedDecimal, ManagedVec,
-    MultiValueEncoded,
+    MultiValueEncoded, TimestampMillis, TimestampSeconds,
 };
 use multiversx_sc_scenario::imports::{BigUint, OptionalValue, StaticApi, TestAddress};
+use multiversx_sc_scenario::ScenarioTxWhitebox;
 pub mod constants;
 pub mod proxys;
 pub mod setup;
@@ -18,6 +24,37 @@ fn small_ray_tolerance() -> BigUint<StaticApi> {
     BigUint::from(10u64).pow(22)
 }
 
+fn egld_edit_params() -> EditAssetConfigParams {
+    edit_params_from(get_egld_config())
+}
+
+fn edit_params_from(config: SetupConfig) -> EditAssetConfigParams {
+    EditAssetConfigParams {
+        loan_to_value: config.config.loan_to_value_bps.as_raw_units().clone(),
+        liquidation_threshold: config
+            .config
+            .liquidation_threshold_bps
+            .as_raw_units()
+            .clone(),
+        liquidation_bonus: config.config.liquidation_bonus_bps.as_raw_units().clone(),
+        liquidation_fees: config.config.liquidation_fees_bps.as_raw_units().clone(),
+        is_isolated_asset: config.config.is_isolated_asset,
+        isolation_debt_ceiling_usd: config
+            .config
+            .isolation_debt_ceiling_usd_wad
+            .as_raw_units()
+            .clone(),
+        is_siloed_borrowing: config.config.is_siloed_borrowing,
+        is_flashloanable: config.config.is_flashloanable,
+        flashloan_fee: config.config.flashloan_fee_bps.as_raw_units().clone(),
+        is_collateralizable: config.config.is_collateralizable,
+        is_borrowable: config.config.is_borrowable,
+        isolation_borrow_enabled: config.config.isolation_borrow_enabled,
+        borrow_cap: config.config.borrow_cap_wad.unwrap_or(BigUint::zero()),
+        supply_cap: config.config.supply_cap_wad.unwrap_or(BigUint::zero()),
+    }
+}
+
 /// Tests basic liquidation flow with multiple debt positions.
 ///
 /// Covers:
@@ -879,6 +916,284 @@ fn liquidate_single_asset_position_high_interest_success() {
     state.assert_health_factor_at_least(2, RAY);
 }
 
+/// Tests that liquidation never seizes a supply-only deposit.
+///
+/// Covers:
+/// - Controller::liquidate with a deposit excluded from collateral
+/// - Seizure limited to collateral positions
+/// - Supply-only deposit left untouched in the account
+#[test]
+fn liquidate_skips_supply_only_deposit_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    let liquidator = TestAddress::new("liquidator");
+
+    state.world.account(liquidator).nonce(1).esdt_balance(
+        EGLD_TOKEN,
+        BigUint::from(1000u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+    );
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.disable_as_collateral(&borrower, 2, USDC_TOKEN);
+
+    // Only the EGLD deposit backs the loan
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(75u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // Interest pushes the account below a health factor of 1.0
+    state.change_timestamp(SECONDS_PER_YEAR + SECONDS_PER_DAY * 1500);
+    let mut markets = MultiValueEncoded::new();
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN));
+    state.update_markets(&borrower, markets);
+
+    state.liquidate_account(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(105u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    state.assert_total_collateral_raw_within(
+        2,
+        BigUint::zero(),
+        small_ray_tolerance(),
+        "Liquidation should release virtually all EGLD collateral",
+    );
+    state.assert_collateral_raw_eq(
+        2,
+        &USDC_TOKEN,
+        scaled_amount(1000, USDC_DECIMALS),
+        "Supply-only USDC deposit must not be seized",
+    );
+}
+
+/// Tests that dust collateral left after a liquidation clearing all debt is claimable.
+///
+/// Covers:
+/// - Controller::setLiquidationDustThreshold endpoint functionality
+/// - Dust collateral credited to the account once no debt remains
+/// - Controller::claimDustCollateral paying the current NFT owner, not the stored holder
+#[test]
+fn liquidate_full_debt_clear_credits_dust_collateral_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    let liquidator = TestAddress::new("liquidator");
+
+    state.world.account(liquidator).nonce(1).esdt_balance(
+        EGLD_TOKEN,
+        BigUint::from(1000u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+    );
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    // Return up to $5 of collateral left after a full debt clear
+    state.set_liquidation_dust_threshold(BigUint::from(5u64) * BigUint::from(WAD));
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(75u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    assert_eq!(state.account_holder(2), borrower.to_managed_address());
+
+    // Accrue interest until the position is fully liquidatable
+    state.change_timestamp(SECONDS_PER_YEAR + SECONDS_PER_DAY * 1500);
+    let mut markets = MultiValueEncoded::new();
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN));
+    state.update_markets(&borrower, markets);
+
+    state.liquidate_account(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(105u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // No debt remains and the collateral remainder left the account
+    state.assert_total_borrow_raw_within(
+        2,
+        BigUint::zero(),
+        BigUint::zero(),
+        "Liquidation should clear all EGLD debt",
+    );
+    let custom_error_message = format!("Token not existing in the account {}", EGLD_TOKEN.as_str());
+    state.collateral_amount_for_token_non_existing(2, EGLD_TOKEN, custom_error_message.as_bytes());
+
+    // The remainder is credited to the account, not pushed to the stored holder
+    let credits = state.dust_collateral(2);
+    assert_eq!(credits.len(), 1);
+    let (credited_token, credited_amount) = credits[0].clone();
+    assert_eq!(
+        credited_token,
+        EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN.to_esdt_token_identifier())
+    );
+    assert!(credited_amount > BigUint::zero());
+
+    // The NFT changes hands outside the protocol; its new owner claims the credit
+    let buyer = TestAddress::new("buyer");
+    state.world.account(buyer).nonce(1);
+    state.transfer_account(&borrower, &buyer, 2);
+    state.claim_dust_collateral(&buyer, 2);
+
+    state
+        .world
+        .check_account(buyer)
+        .esdt_balance(EGLD_TOKEN, credited_amount);
+    assert!(state.dust_collateral(2).is_empty());
+}
+
+/// Tests that crediting dust collateral after a debt-clearing liquidation skips supply-only deposits.
+///
+/// Covers:
+/// - Dust collateral valued and credited from collateral deposits only
+/// - Supply-only deposit left untouched in the account
+#[test]
+fn liquidate_full_debt_clear_keeps_supply_only_deposit_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    let liquidator = TestAddress::new("liquidator");
+
+    state.world.account(liquidator).nonce(1).esdt_balance(
+        EGLD_TOKEN,
+        BigUint::from(1000u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+    );
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+    state.set_liquidation_dust_threshold(BigUint::from(5u64) * BigUint::from(WAD));
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.disable_as_collateral(&borrower, 2, USDC_TOKEN);
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(75u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    state.change_timestamp(SECONDS_PER_YEAR + SECONDS_PER_DAY * 1500);
+    let mut markets = MultiValueEncoded::new();
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN));
+    state.update_markets(&borrower, markets);
+
+    state.liquidate_account(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(105u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // Only the EGLD collateral remainder is credited, the USDC deposit stays in the account
+    let credits = state.dust_collateral(2);
+    assert_eq!(credits.len(), 1);
+    assert_eq!(
+        credits[0].0,
+        EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN.to_esdt_token_identifier())
+    );
+    state.assert_collateral_raw_eq(
+        2,
+        &USDC_TOKEN,
+        scaled_amount(1000, USDC_DECIMALS),
+        "Supply-only USDC deposit must stay in the account",
+    );
+}
+
 /// Tests liquidation creating bad debt that cannot be fully recovered.
 ///
 /// Covers:
@@ -1133,10 +1448,14 @@ fn liquidation_estimate_primary_vs_secondary() {
     assert!(b.max_egld_payment_wad >= a.max_egld_payment_wad);
 }
 
-/// Verifies repeated tiny partial liquidations cannot enter a death spiral: health factor
-/// does not decrease across iterations even when repayments are capped far below estimate.
+/// Tests that the simulated liquidation impact matches the effect of an actual liquidation.
+///
+/// Covers:
+/// - Controller::simulateLiquidationImpact view
+/// - Collateral lost and debt repaid by a maximal liquidation
+/// - Health factor left on the account after the liquidation
 #[test]
-fn liquidation_no_death_spiral_under_tiny_payments() {
+fn simulate_liquidation_impact_matches_liquidation_success() {
     let mut state = LendingPoolTestState::new();
     let supplier = TestAddress::new("supplier");
     let borrower = TestAddress::new("borrower");
@@ -1144,25 +1463,31 @@ fn liquidation_no_death_spiral_under_tiny_payments() {
     state.change_timestamp(0);
     setup_accounts(&mut state, supplier, borrower);
 
-    // Liquidity
+    // Widen the last oracle tolerance so the crashed aggregator price is averaged, not rejected
+    state.edit_token_oracle_tolerance(
+        &EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN),
+        BigUint::from(MIN_FIRST_TOLERANCE),
+        BigUint::from(10_000u64),
+    );
+
     state.supply_asset(
         &supplier,
         SupplyParams {
             token_id: EGLD_TOKEN,
-            amount: BigUint::from(200u64),
+            amount: BigUint::from(100u64),
             asset_decimals: EGLD_DECIMALS,
             account_nonce: OptionalValue::None,
             e_mode_category: OptionalValue::None,
         },
     );
 
-    // Borrower supplies and borrows same-asset to keep math simple
+    // Borrower: $1000 XOXNO collateral, $720 EGLD debt (health factor ~1.11)
     state.supply_asset(
         &borrower,
         SupplyParams {
-            token_id: EGLD_TOKEN,
-            amount: BigUint::from(100u64),
-            asset_decimals: EGLD_DECIMALS,
+            token_id: XOXNO_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: XOXNO_DECIMALS,
             account_nonce: OptionalValue::None,
             e_mode_category: OptionalValue::None,
         },
@@ -1170,22 +1495,132 @@ fn liquidation_no_death_spiral_under_tiny_payments() {
     state.borrow_asset(
         &borrower,
         EGLD_TOKEN,
-        BigUint::from(75u64),
+        BigUint::from(18u64),
         2,
         EGLD_DECIMALS,
     );
 
-    // Make unhealthy (extreme interest period similar to other tests)
-    state.change_timestamp(SECONDS_PER_YEAR + SECONDS_PER_DAY * 1500);
-    let mut markets = MultiValueEncoded::new();
-    markets.push(EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN));
-    state.update_markets(&borrower, markets.clone());
+    // Aggregator crashes XOXNO to $0.6, the averaged final price is $0.8 (health factor ~0.89)
+    let crashed_price = BigUint::from(6u64) * BigUint::from(10u64).pow(17);
+    state.change_price_denominated(XOXNO_TICKER, crashed_price, 0);
 
-    // Iteratively liquidate with tiny payments and verify HF never decreases
-    let liq = TestAddress::new("liquidator");
-    state.world.account(liq).nonce(1).esdt_balance(
-        EGLD_TOKEN,
-        BigUint::from(100000u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+    let (collateral_lost, debt_repaid, resulting_hf) = state.simulate_liquidation_impact(2);
+    assert!(
+        collateral_lost > debt_repaid,
+        "seized collateral should include the bonus"
+    );
+    assert!(resulting_hf > state.account_health_factor(2));
+
+    let collateral_before = state.total_collateral_in_egld(2);
+    let borrow_before = state.total_borrow_in_egld(2);
+
+    // Overpay so the liquidation repays the maximum allowed debt
+    let liquidator = TestAddress::new("liquidator");
+    state.world.account(liquidator).nonce(1).esdt_balance(
+        EGLD_TOKEN,
+        BigUint::from(100u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+    );
+    state.liquidate_account(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(100u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    let wad_tolerance = BigUint::from(10u64).pow(12);
+    let within = |a: &BigUint<StaticApi>, b: &BigUint<StaticApi>, tol: &BigUint<StaticApi>| {
+        if a >= b {
+            (a.clone() - b).le(tol)
+        } else {
+            (b.clone() - a).le(tol)
+        }
+    };
+
+    let actual_collateral_lost = collateral_before - state.total_collateral_in_egld(2);
+    assert!(
+        within(
+            actual_collateral_lost.as_raw_units(),
+            collateral_lost.as_raw_units(),
+            &wad_tolerance,
+        ),
+        "collateral lost mismatch: simulated={collateral_lost:?} actual={actual_collateral_lost:?}"
+    );
+
+    let actual_debt_repaid = borrow_before - state.total_borrow_in_egld(2);
+    assert!(
+        within(
+            actual_debt_repaid.as_raw_units(),
+            debt_repaid.as_raw_units(),
+            &wad_tolerance,
+        ),
+        "debt repaid mismatch: simulated={debt_repaid:?} actual={actual_debt_repaid:?}"
+    );
+
+    let actual_hf = state.account_health_factor(2);
+    assert!(
+        within(
+            actual_hf.as_raw_units(),
+            resulting_hf.as_raw_units(),
+            &small_ray_tolerance(),
+        ),
+        "health factor mismatch: simulated={resulting_hf:?} actual={actual_hf:?}"
+    );
+}
+
+/// Verifies repeated tiny partial liquidations cannot enter a death spiral: health factor
+/// does not decrease across iterations even when repayments are capped far below estimate.
+#[test]
+fn liquidation_no_death_spiral_under_tiny_payments() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    // Liquidity
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(200u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Borrower supplies and borrows same-asset to keep math simple
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(75u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // Make unhealthy (extreme interest period similar to other tests)
+    state.change_timestamp(SECONDS_PER_YEAR + SECONDS_PER_DAY * 1500);
+    let mut markets = MultiValueEncoded::new();
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN));
+    state.update_markets(&borrower, markets.clone());
+
+    // Iteratively liquidate with tiny payments and verify HF never decreases
+    let liq = TestAddress::new("liquidator");
+    state.world.account(liq).nonce(1).esdt_balance(
+        EGLD_TOKEN,
+        BigUint::from(100000u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
     );
     let mut last_hf = state.account_health_factor(2);
     for _ in 0..3 {
@@ -1198,6 +1633,89 @@ fn liquidation_no_death_spiral_under_tiny_payments() {
     }
 }
 
+/// Tests that the liquidation history keeps the most recent liquidations of an account.
+///
+/// Covers:
+/// - Controller::getLiquidationHistory view functionality
+/// - Liquidation records appended by every liquidation
+/// - Oldest records overwritten once the history is full
+#[test]
+fn liquidation_history_retains_recent_liquidations_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(200u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(75u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    assert!(state.liquidation_history(2).is_empty());
+
+    // Interest pushes the account below a health factor of 1.0
+    let start = SECONDS_PER_YEAR + SECONDS_PER_DAY * 1500;
+    state.change_timestamp(start);
+    let mut markets = MultiValueEncoded::new();
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN));
+    state.update_markets(&borrower, markets);
+
+    let liquidator = TestAddress::new("liquidator");
+    state.world.account(liquidator).nonce(1).esdt_balance(
+        EGLD_TOKEN,
+        BigUint::from(100000u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+    );
+
+    // Tiny partial liquidations, one per second, keep the account liquidatable
+    let liquidations = LIQUIDATION_HISTORY_CAPACITY as u64 + 2;
+    for offset in 0..liquidations {
+        state.change_timestamp(start + offset);
+        let borrowed = state.borrow_amount_for_token(2, EGLD_TOKEN);
+        let tiny = borrowed.as_raw_units().clone() / 2000u64;
+        state.liquidate_account_dem(&liquidator, &EGLD_TOKEN, tiny, 2);
+    }
+
+    // Only the most recent liquidations are kept, oldest first
+    let history = state.liquidation_history(2);
+    assert_eq!(history.len(), LIQUIDATION_HISTORY_CAPACITY);
+    for (position, record) in history.iter().enumerate() {
+        let timestamp =
+            start + liquidations - LIQUIDATION_HISTORY_CAPACITY as u64 + position as u64;
+        assert_eq!(record.timestamp, TimestampMillis::new(timestamp * 1000));
+        assert_eq!(record.liquidator, liquidator.to_managed_address());
+        assert!(
+            record.debt_repaid_egld_wad
+                > ManagedDecimal::from_raw_units(BigUint::zero(), WAD_PRECISION)
+        );
+        // The liquidation bonus makes the seized collateral worth more than the repaid debt
+        assert!(record.collateral_seized_egld_wad > record.debt_repaid_egld_wad);
+    }
+}
+
 /// Verifies refund handling in the simulation when overpaying with multiple tokens.
 /// Ensures the view reports non-empty refunds for excess payments.
 #[test]
@@ -1774,3 +2292,1207 @@ fn e_mode_liquidate_leave_bad_debt_success() {
     println!("final_collateral: {final_collateral:?}");
     assert!(final_collateral == ManagedDecimal::from_raw_units(BigUint::from(0u64), WAD_PRECISION));
 }
+
+/// Tests that a liquidation on a suspicious collateral price crash is deferred.
+///
+/// Covers:
+/// - Controller::setMaxLiquidationPriceDrop endpoint functionality
+/// - Reference price seeding through Controller::updateIndexes
+/// - Liquidation deferral when collateral price drops more than allowed in one update
+/// - Liquidation execution in a later block once the new price persists
+#[test]
+fn liquidate_deferred_on_collateral_price_regression_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    // Widen the last oracle tolerance so the crashed aggregator price is averaged, not rejected
+    state.edit_token_oracle_tolerance(
+        &EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN),
+        BigUint::from(MIN_FIRST_TOLERANCE),
+        BigUint::from(10_000u64),
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Borrower: $1000 XOXNO collateral, $720 EGLD debt (health factor ~1.11)
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XOXNO_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: XOXNO_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(18u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    state.assert_health_factor_at_least(2, RAY);
+
+    // Allow at most a 10% collateral price drop per update and seed the reference price
+    state.set_max_liquidation_price_drop(BigUint::from(1_000u64));
+    let mut markets = MultiValueEncoded::new();
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN));
+    state.update_markets(&borrower, markets);
+
+    // Aggregator crashes XOXNO to $0.6, the averaged final price is $0.8 (a 20% drop)
+    let crashed_price = BigUint::from(6u64) * BigUint::from(10u64).pow(17);
+    state.change_price_denominated(XOXNO_TICKER, crashed_price.clone(), 0);
+    let health = state.account_health_factor(2);
+    assert!(health < ManagedDecimal::from_raw_units(BigUint::from(RAY), RAY_PRECISION));
+
+    let liquidator = TestAddress::new("liquidator");
+    state.world.account(liquidator).nonce(1).esdt_balance(
+        EGLD_TOKEN,
+        BigUint::from(100u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+    );
+
+    let borrowed_before = state.borrow_amount_for_token(2, EGLD_TOKEN);
+    let collateral_before = state.collateral_amount_for_token(2, XOXNO_TOKEN);
+
+    // Same block as the crash: the liquidation is deferred and the payment refunded
+    state.liquidate_account_dem(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(5u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+        2,
+    );
+    assert_eq!(
+        state.borrow_amount_for_token(2, EGLD_TOKEN),
+        borrowed_before
+    );
+    assert_eq!(
+        state.collateral_amount_for_token(2, XOXNO_TOKEN),
+        collateral_before
+    );
+
+    // Next block: the crashed price is now the reference, so the liquidation executes
+    state.change_timestamp(1);
+    state.change_price_denominated(XOXNO_TICKER, crashed_price, 1);
+    state.liquidate_account_dem(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(5u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+        2,
+    );
+    assert!(state.borrow_amount_for_token(2, EGLD_TOKEN) < borrowed_before);
+    assert!(state.collateral_amount_for_token(2, XOXNO_TOKEN) < collateral_before);
+}
+
+/// Tests that repeated liquidation attempts within a deferral window keep its reference price.
+///
+/// Covers:
+/// - Liquidation deferral on a collateral price crash
+/// - Further attempts in the same block stay deferred without moving the reference price
+/// - The reference price is refreshed once the deferral expires and the liquidation executes
+#[test]
+fn liquidate_deferred_repeated_attempts_keep_reference_price_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    // Widen the last oracle tolerance so the crashed aggregator price is averaged, not rejected
+    state.edit_token_oracle_tolerance(
+        &EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN),
+        BigUint::from(MIN_FIRST_TOLERANCE),
+        BigUint::from(10_000u64),
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XOXNO_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: XOXNO_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(18u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    state.set_max_liquidation_price_drop(BigUint::from(1_000u64));
+    let mut markets = MultiValueEncoded::new();
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN));
+    state.update_markets(&borrower, markets);
+
+    let liquidator = TestAddress::new("liquidator");
+    state.world.account(liquidator).nonce(1).esdt_balance(
+        EGLD_TOKEN,
+        BigUint::from(100u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+    );
+    let repayment = BigUint::from(5u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32);
+    let borrowed_before = state.borrow_amount_for_token(2, EGLD_TOKEN);
+
+    // The crash starts a deferral and records the crashed price as the reference
+    let crashed_price = BigUint::from(6u64) * BigUint::from(10u64).pow(17);
+    state.change_price_denominated(XOXNO_TICKER, crashed_price, 0);
+    state.liquidate_account_dem(&liquidator, &EGLD_TOKEN, repayment.clone(), 2);
+    assert_eq!(
+        state.borrow_amount_for_token(2, EGLD_TOKEN),
+        borrowed_before
+    );
+    let reference_price = state.liquidation_reference_price(XOXNO_TOKEN);
+
+    // Further attempts in the same block, even on a lower price, neither execute nor move it
+    let lower_price = BigUint::from(5u64) * BigUint::from(10u64).pow(17);
+    state.change_price_denominated(XOXNO_TICKER, lower_price.clone(), 0);
+    for _ in 0..3 {
+        state.liquidate_account_dem(&liquidator, &EGLD_TOKEN, repayment.clone(), 2);
+        assert_eq!(
+            state.borrow_amount_for_token(2, EGLD_TOKEN),
+            borrowed_before
+        );
+        assert_eq!(
+            state.liquidation_reference_price(XOXNO_TOKEN),
+            reference_price
+        );
+    }
+
+    // Next block: the deferral expired, the current price becomes the reference
+    state.change_timestamp(1);
+    state.change_price_denominated(XOXNO_TICKER, lower_price, 1);
+    state.liquidate_account_dem(&liquidator, &EGLD_TOKEN, repayment, 2);
+    assert!(state.borrow_amount_for_token(2, EGLD_TOKEN) < borrowed_before);
+    assert!(state.liquidation_reference_price(XOXNO_TOKEN) < reference_price);
+}
+
+/// Tests that `updateIndexes` cannot move the reference price ahead of a liquidation.
+///
+/// Covers:
+/// - Controller::updateIndexes only seeding an empty reference price
+/// - Liquidation deferral when `updateIndexes` runs on the crashed price first
+#[test]
+fn liquidate_deferred_after_update_indexes_on_crashed_price_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    // Widen the last oracle tolerance so the crashed aggregator price is averaged, not rejected
+    state.edit_token_oracle_tolerance(
+        &EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN),
+        BigUint::from(MIN_FIRST_TOLERANCE),
+        BigUint::from(10_000u64),
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XOXNO_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: XOXNO_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(18u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    state.set_max_liquidation_price_drop(BigUint::from(1_000u64));
+    let mut markets = MultiValueEncoded::new();
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN));
+    state.update_markets(&borrower, markets.clone());
+    let reference_price = state.liquidation_reference_price(XOXNO_TOKEN);
+
+    // The crashed price is synced through updateIndexes right before the liquidation
+    let crashed_price = BigUint::from(6u64) * BigUint::from(10u64).pow(17);
+    state.change_price_denominated(XOXNO_TICKER, crashed_price, 0);
+    let liquidator = TestAddress::new("liquidator");
+    state.update_markets(&liquidator, markets);
+    assert_eq!(
+        state.liquidation_reference_price(XOXNO_TOKEN),
+        reference_price
+    );
+
+    state.world.account(liquidator).nonce(1).esdt_balance(
+        EGLD_TOKEN,
+        BigUint::from(100u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+    );
+    let borrowed_before = state.borrow_amount_for_token(2, EGLD_TOKEN);
+    let collateral_before = state.collateral_amount_for_token(2, XOXNO_TOKEN);
+
+    // The crash is still measured against the original reference: the liquidation is deferred
+    state.liquidate_account_dem(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(5u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+        2,
+    );
+    assert_eq!(
+        state.borrow_amount_for_token(2, EGLD_TOKEN),
+        borrowed_before
+    );
+    assert_eq!(
+        state.collateral_amount_for_token(2, XOXNO_TOKEN),
+        collateral_before
+    );
+}
+
+/// Tests the grace period that protects an account right after a liquidation.
+///
+/// Covers:
+/// - Controller::setPostLiquidationGrace endpoint functionality
+/// - ERROR_POST_LIQUIDATION_GRACE on a second liquidation within the grace period
+/// - Repayment of the remaining debt allowed during the grace period
+/// - Liquidation allowed again once the grace period has elapsed
+#[test]
+fn liquidate_within_post_liquidation_grace_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+    state.set_post_liquidation_grace(600);
+
+    // Widen the last oracle tolerance so the crashed aggregator price is averaged, not rejected
+    state.edit_token_oracle_tolerance(
+        &EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN),
+        BigUint::from(MIN_FIRST_TOLERANCE),
+        BigUint::from(10_000u64),
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Borrower: $1000 XOXNO collateral, $720 EGLD debt (health factor ~1.11)
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XOXNO_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: XOXNO_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(18u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // Aggregator crashes XOXNO to $0.6, the averaged final price is $0.8 (health factor ~0.89)
+    let crashed_price = BigUint::from(6u64) * BigUint::from(10u64).pow(17);
+    state.change_price_denominated(XOXNO_TICKER, crashed_price.clone(), 0);
+
+    let liquidator = TestAddress::new("liquidator");
+    state.world.account(liquidator).nonce(1).esdt_balance(
+        EGLD_TOKEN,
+        BigUint::from(100u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+    );
+
+    // A small partial liquidation leaves the account unhealthy
+    state.liquidate_account(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    let health = state.account_health_factor(2);
+    assert!(health < ManagedDecimal::from_raw_units(BigUint::from(RAY), RAY_PRECISION));
+
+    // Within the grace period the account cannot be liquidated again
+    state.liquidate_account_error(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+        ERROR_POST_LIQUIDATION_GRACE,
+    );
+
+    // The liquidatee can still repay during the grace period
+    let borrowed_before_repay = state.borrow_amount_for_token(2, EGLD_TOKEN);
+    state.repay_asset(
+        &borrower,
+        &EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    assert!(state.borrow_amount_for_token(2, EGLD_TOKEN) < borrowed_before_repay);
+
+    let health = state.account_health_factor(2);
+    assert!(health < ManagedDecimal::from_raw_units(BigUint::from(RAY), RAY_PRECISION));
+
+    // Once the grace period has elapsed the account can be liquidated again
+    state.change_timestamp(601);
+    state.change_price_denominated(XOXNO_TICKER, crashed_price, 601);
+    let borrowed_before = state.borrow_amount_for_token(2, EGLD_TOKEN);
+    state.liquidate_account(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    assert!(state.borrow_amount_for_token(2, EGLD_TOKEN) < borrowed_before);
+}
+
+/// Tests the minimum collateral value a single liquidation must seize.
+///
+/// Covers:
+/// - Controller::setMinSeizedCollateralEgld endpoint functionality
+/// - ERROR_SEIZED_COLLATERAL_BELOW_MINIMUM on a liquidation seizing sub-minimum collateral
+/// - Liquidation seizing at least the minimum still executes
+#[test]
+fn liquidate_below_min_seized_collateral_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+    // Liquidations must seize at least 2 EGLD worth of collateral
+    state.set_min_seized_collateral_egld(BigUint::from(2u64) * BigUint::from(WAD));
+
+    // Widen the last oracle tolerance so the crashed aggregator price is averaged, not rejected
+    state.edit_token_oracle_tolerance(
+        &EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN),
+        BigUint::from(MIN_FIRST_TOLERANCE),
+        BigUint::from(10_000u64),
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Borrower: $1000 XOXNO collateral, $720 EGLD debt (health factor ~1.11)
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XOXNO_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: XOXNO_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(18u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // Aggregator crashes XOXNO to $0.6, the averaged final price is $0.8 (health factor ~0.89)
+    let crashed_price = BigUint::from(6u64) * BigUint::from(10u64).pow(17);
+    state.change_price_denominated(XOXNO_TICKER, crashed_price, 0);
+
+    let liquidator = TestAddress::new("liquidator");
+    state.world.account(liquidator).nonce(1).esdt_balance(
+        EGLD_TOKEN,
+        BigUint::from(100u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+    );
+
+    // Repaying 1 EGLD seizes about 1 EGLD of collateral plus the bonus, below the minimum
+    let borrowed_before = state.borrow_amount_for_token(2, EGLD_TOKEN);
+    state.liquidate_account_error(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+        ERROR_SEIZED_COLLATERAL_BELOW_MINIMUM,
+    );
+    assert_eq!(
+        state.borrow_amount_for_token(2, EGLD_TOKEN),
+        borrowed_before
+    );
+
+    // Repaying 3 EGLD seizes more than the minimum and executes
+    let collateral_before = state.collateral_amount_for_token(2, XOXNO_TOKEN);
+    state.liquidate_account(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(3u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    assert!(state.borrow_amount_for_token(2, EGLD_TOKEN) < borrowed_before);
+    assert!(state.collateral_amount_for_token(2, XOXNO_TOKEN) < collateral_before);
+}
+
+/// Tests that a liquidator can choose which collateral is seized first.
+///
+/// Covers:
+/// - Controller::liquidate with a preferred collateral list
+/// - Preferred collateral absorbs the whole seizure while it has enough value
+/// - Other collateral positions stay untouched
+#[test]
+fn liquidate_with_preferred_collateral_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    // Widen the last oracle tolerance so the crashed aggregator price is averaged, not rejected
+    state.edit_token_oracle_tolerance(
+        &EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN),
+        BigUint::from(MIN_FIRST_TOLERANCE),
+        BigUint::from(10_000u64),
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Borrower: $1000 XOXNO and $200 USDC collateral, $880 EGLD debt
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XOXNO_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: XOXNO_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(200u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(22u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // Aggregator crashes XOXNO to $0.6, the averaged final price is $0.8
+    let crashed_price = BigUint::from(6u64) * BigUint::from(10u64).pow(17);
+    state.change_price_denominated(XOXNO_TICKER, crashed_price, 0);
+
+    let liquidator = TestAddress::new("liquidator");
+    state.world.account(liquidator).nonce(1).esdt_balance(
+        EGLD_TOKEN,
+        BigUint::from(100u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+    );
+
+    // Repaying 1 EGLD ($40) fits within the USDC position once the bonus is added
+    let borrowed_before = state.borrow_amount_for_token(2, EGLD_TOKEN);
+    let xoxno_before = state.collateral_amount_for_token(2, XOXNO_TOKEN);
+    let usdc_before = state.collateral_amount_for_token(2, USDC_TOKEN);
+    state.liquidate_account_with_preference(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+        &[USDC_TOKEN],
+    );
+
+    assert!(state.borrow_amount_for_token(2, EGLD_TOKEN) < borrowed_before);
+    assert!(state.collateral_amount_for_token(2, USDC_TOKEN) < usdc_before);
+    assert_eq!(
+        state.collateral_amount_for_token(2, XOXNO_TOKEN),
+        xoxno_before
+    );
+}
+
+/// Tests that a liquidation haircut discounts only the liquidation collateral.
+///
+/// Covers:
+/// - Controller::setLiquidationHaircut endpoint functionality
+/// - Haircut lowers liquidation collateral and health factor for the affected asset
+/// - LTV collateral is unchanged by the haircut
+/// - ERROR_INVALID_LIQUIDATION_HAIRCUT error condition
+#[test]
+fn liquidation_haircut_reduces_liquidation_collateral_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Borrower: $1000 XOXNO (25 EGLD) and $1000 USDC collateral, 10 EGLD debt
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XOXNO_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: XOXNO_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(10u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    let liquidation_collateral_before = state.liquidation_collateral_available(2);
+    let ltv_collateral_before = state.ltv_collateral_in_egld(2);
+    let health_factor_before = state.account_health_factor(2);
+
+    state.set_liquidation_haircut_error(
+        XOXNO_TOKEN,
+        BigUint::from(10_000u64),
+        ERROR_INVALID_LIQUIDATION_HAIRCUT,
+    );
+
+    // A 20% haircut on XOXNO removes 25 EGLD * 80% threshold * 20% = 4 EGLD
+    state.set_liquidation_haircut(XOXNO_TOKEN, BigUint::from(2_000u64));
+
+    let liquidation_collateral_after = state.liquidation_collateral_available(2);
+    assert_eq!(
+        liquidation_collateral_before.into_raw_units()
+            - liquidation_collateral_after.into_raw_units(),
+        scaled_amount(4, EGLD_DECIMALS)
+    );
+    assert_eq!(state.ltv_collateral_in_egld(2), ltv_collateral_before);
+    assert!(state.account_health_factor(2) < health_factor_before);
+
+    // Clearing the haircut restores the mark-price valuation
+    state.set_liquidation_haircut(XOXNO_TOKEN, BigUint::zero());
+    assert_eq!(
+        state.liquidation_collateral_available(2),
+        liquidation_collateral_before
+    );
+}
+
+/// Tests the liquidation grace period that follows a liquidation threshold reduction.
+///
+/// Covers:
+/// - Controller::setThresholdUpdateGrace endpoint functionality
+/// - Controller::updateAccountThreshold starting the grace when a threshold is lowered
+/// - Controller::canBeLiquidated returning false during the grace period
+/// - ERROR_THRESHOLD_UPDATE_GRACE on a liquidation during the grace period
+/// - Liquidation allowed once the grace period has elapsed
+#[test]
+fn liquidate_within_threshold_update_grace_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+    state.set_threshold_update_grace(600);
+
+    // Widen the last oracle tolerance so the crashed aggregator price is averaged, not rejected
+    state.edit_token_oracle_tolerance(
+        &EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN),
+        BigUint::from(MIN_FIRST_TOLERANCE),
+        BigUint::from(10_000u64),
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Borrower: $1000 XOXNO collateral, $720 EGLD debt (health factor ~1.11)
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XOXNO_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: XOXNO_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(18u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // Raising the threshold does not start a grace period
+    let mut params = edit_params_from(get_xoxno_config());
+    params.liquidation_threshold = BigUint::from(8_200u64);
+    state.edit_asset_config(EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN), params, None);
+    let mut accounts = MultiValueEncoded::new();
+    accounts.push(2u64);
+    state.update_account_threshold(
+        EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN),
+        true,
+        accounts,
+        None,
+    );
+    assert_eq!(state.liquidation_grace_until(2), TimestampSeconds::zero());
+
+    // Governance lowers the XOXNO threshold to 77% (health factor ~1.07)
+    let mut params = edit_params_from(get_xoxno_config());
+    params.liquidation_threshold = BigUint::from(7_700u64);
+    state.edit_asset_config(EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN), params, None);
+    let mut accounts = MultiValueEncoded::new();
+    accounts.push(2u64);
+    state.update_account_threshold(
+        EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN),
+        true,
+        accounts,
+        None,
+    );
+    assert_eq!(state.liquidation_grace_until(2), TimestampSeconds::new(600));
+
+    // Aggregator crashes XOXNO to $0.6, the averaged final price is $0.8 (health factor ~0.86)
+    let crashed_price = BigUint::from(6u64) * BigUint::from(10u64).pow(17);
+    state.change_price_denominated(XOXNO_TICKER, crashed_price.clone(), 0);
+    let health = state.account_health_factor(2);
+    assert!(health < ManagedDecimal::from_raw_units(BigUint::from(RAY), RAY_PRECISION));
+    assert!(!state.can_be_liquidated(2));
+
+    let liquidator = TestAddress::new("liquidator");
+    state.world.account(liquidator).nonce(1).esdt_balance(
+        EGLD_TOKEN,
+        BigUint::from(100u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+    );
+
+    state.liquidate_account_error(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+        ERROR_THRESHOLD_UPDATE_GRACE,
+    );
+
+    // Once the grace period has elapsed the account can be liquidated
+    state.change_timestamp(600);
+    state.change_price_denominated(XOXNO_TICKER, crashed_price, 600);
+    assert!(state.can_be_liquidated(2));
+    let borrowed_before = state.borrow_amount_for_token(2, EGLD_TOKEN);
+    state.liquidate_account(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    assert!(state.borrow_amount_for_token(2, EGLD_TOKEN) < borrowed_before);
+}
+
+/// Tests that the maximum liquidation price drop cannot exceed 100%.
+///
+/// Covers:
+/// - Controller::setMaxLiquidationPriceDrop endpoint error path
+/// - ERROR_INVALID_MAX_LIQUIDATION_PRICE_DROP error condition
+#[test]
+fn liquidate_set_max_liquidation_price_drop_invalid_error() {
+    let mut state = LendingPoolTestState::new();
+
+    state.set_max_liquidation_price_drop_error(
+        BigUint::from(10_001u64),
+        ERROR_INVALID_MAX_LIQUIDATION_PRICE_DROP,
+    );
+}
+
+/// Tests that a single liquidation cannot repay more than the close factor share of the debt.
+///
+/// Covers:
+/// - Controller::editAssetConfig close factor override
+/// - Controller::liquidate trimming payments above the close factor
+/// - Refund of the trimmed excess to the liquidator
+#[test]
+fn liquidate_capped_by_close_factor_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    // Widen the last oracle tolerance so the crashed aggregator price is averaged, not rejected
+    state.edit_token_oracle_tolerance(
+        &EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN),
+        BigUint::from(MIN_FIRST_TOLERANCE),
+        BigUint::from(10_000u64),
+    );
+
+    // Liquidations may repay at most half of the EGLD debt at once
+    state.edit_asset_config_with_close_factor(
+        EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN),
+        egld_edit_params(),
+        Some(5_000),
+        None,
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Borrower: $1000 XOXNO collateral, $720 EGLD debt
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XOXNO_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: XOXNO_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(18u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // XOXNO crashes, the account becomes liquidatable
+    let crashed_price = BigUint::from(6u64) * BigUint::from(10u64).pow(17);
+    state.change_price_denominated(XOXNO_TICKER, crashed_price, 0);
+    let health = state.account_health_factor(2);
+    assert!(health < ManagedDecimal::from_raw_units(BigUint::from(RAY), RAY_PRECISION));
+
+    let liquidator = TestAddress::new("liquidator");
+    let liquidator_balance = BigUint::from(100u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32);
+    state
+        .world
+        .account(liquidator)
+        .nonce(1)
+        .esdt_balance(EGLD_TOKEN, liquidator_balance);
+
+    // Liquidator offers the full debt, only half of it is accepted
+    let borrowed_before = state.borrow_amount_for_token(2, EGLD_TOKEN);
+    state.liquidate_account(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(18u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    let borrowed_after = state.borrow_amount_for_token(2, EGLD_TOKEN);
+
+    let tolerance = BigUint::from(10u64).pow(6);
+    let remaining_doubled = borrowed_after.as_raw_units() * 2u64;
+    assert!(&remaining_doubled + &tolerance >= *borrowed_before.as_raw_units());
+    assert!(remaining_doubled <= borrowed_before.as_raw_units() + &tolerance);
+}
+
+/// Tests that the liquidation estimate sizes the repayable debt by the close factor.
+///
+/// Covers:
+/// - Controller::liquidationEstimations max repayable per debt token
+/// - Close factor capping the repayable share of the debt
+#[test]
+fn liquidation_estimations_max_repayable_capped_by_close_factor_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.edit_token_oracle_tolerance(
+        &EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN),
+        BigUint::from(MIN_FIRST_TOLERANCE),
+        BigUint::from(10_000u64),
+    );
+
+    // Liquidations may repay at most a tenth of the EGLD debt at once
+    state.edit_asset_config_with_close_factor(
+        EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN),
+        egld_edit_params(),
+        Some(1_000),
+        None,
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XOXNO_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: XOXNO_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(18u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    let crashed_price = BigUint::from(6u64) * BigUint::from(10u64).pow(17);
+    state.change_price_denominated(XOXNO_TICKER, crashed_price, 0);
+
+    let estimate = state.liquidation_estimations(2, ManagedVec::new());
+    let debt = state.borrow_amount_for_token(2, EGLD_TOKEN);
+    let capped_debt = debt.as_raw_units() / 10u64;
+
+    // EGLD is the debt token, so the EGLD payment equals its token amount
+    let max_payment = estimate.max_egld_payment_wad.as_raw_units().clone();
+    assert!(capped_debt < max_payment);
+
+    assert_eq!(estimate.max_repayable_per_debt_token.len(), 1);
+    let repayable = estimate.max_repayable_per_debt_token.get(0).amount.clone();
+    let tolerance = BigUint::from(10u64).pow(6);
+    assert!(&repayable + &tolerance >= capped_debt);
+    assert!(repayable <= capped_debt + &tolerance);
+}
+
+/// Tests that the liquidation close factor must lie within (0, 100%].
+///
+/// Covers:
+/// - Controller::editAssetConfig close factor validation
+/// - ERROR_INVALID_LIQUIDATION_CLOSE_FACTOR error condition
+#[test]
+fn edit_asset_config_invalid_close_factor_error() {
+    let mut state = LendingPoolTestState::new();
+
+    state.edit_asset_config_with_close_factor(
+        EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN),
+        egld_edit_params(),
+        Some(0),
+        Some(ERROR_INVALID_LIQUIDATION_CLOSE_FACTOR),
+    );
+    state.edit_asset_config_with_close_factor(
+        EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN),
+        egld_edit_params(),
+        Some(10_001),
+        Some(ERROR_INVALID_LIQUIDATION_CLOSE_FACTOR),
+    );
+}
+
+/// Tests that liquidators can repay native EGLD debt with a native EGLD payment.
+///
+/// Covers:
+/// - Controller::liquidate with a plain EGLD transfer instead of a wrapped token
+/// - Repayment routed to the native EGLD market
+/// - Collateral seizure and the excess refunded in native EGLD
+#[test]
+fn liquidate_native_egld_debt_with_egld_payment_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    let liquidator = TestAddress::new("liquidator");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.add_new_market(
+        EgldOrEsdtTokenIdentifier::egld(),
+        get_egld_config().config,
+        MarketRateParams {
+            max_borrow_rate: BigUint::from(R_MAX),
+            base_borrow_rate: BigUint::from(R_BASE),
+            slope1: BigUint::from(R_SLOPE1),
+            slope2: BigUint::from(R_SLOPE2),
+            slope3: BigUint::from(R_SLOPE3),
+            mid_utilization: BigUint::from(U_MID),
+            optimal_utilization: BigUint::from(U_OPTIMAL),
+            reserve_factor: BigUint::from(RESERVE_FACTOR),
+        },
+        EGLD_DECIMALS,
+        OptionalValue::<BigUint<StaticApi>>::None,
+    );
+
+    // Widen the last oracle tolerance so the crashed aggregator price is averaged, not rejected
+    state.edit_token_oracle_tolerance(
+        &EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN),
+        BigUint::from(MIN_FIRST_TOLERANCE),
+        BigUint::from(10_000u64),
+    );
+
+    // Liquidations may repay at most half of the native EGLD debt at once
+    state.edit_asset_config_with_close_factor(
+        EgldOrEsdtTokenIdentifier::egld(),
+        egld_edit_params(),
+        Some(5_000),
+        None,
+    );
+
+    state.world.set_egld_balance(
+        supplier.to_managed_address(),
+        scaled_amount(1_000, EGLD_DECIMALS),
+    );
+    state.supply_asset_egld(
+        &supplier,
+        scaled_amount(100, EGLD_DECIMALS),
+        OptionalValue::None,
+        OptionalValue::None,
+    );
+
+    // Borrower: $1000 XOXNO collateral, $720 native EGLD debt
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XOXNO_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: XOXNO_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset_egld(&borrower, scaled_amount(18, EGLD_DECIMALS), 2);
+
+    // XOXNO crashes, the account becomes liquidatable
+    let crashed_price = BigUint::from(6u64) * BigUint::from(10u64).pow(17);
+    state.change_price_denominated(XOXNO_TICKER, crashed_price, 0);
+    let health = state.account_health_factor(2);
+    assert!(health < ManagedDecimal::from_raw_units(BigUint::from(RAY), RAY_PRECISION));
+
+    let liquidator_balance = scaled_amount(100, EGLD_DECIMALS);
+    state
+        .world
+        .account(liquidator)
+        .nonce(1)
+        .balance(liquidator_balance.clone());
+
+    let collateral_before = state.collateral_amount_for_token(2, XOXNO_TOKEN);
+    state.liquidate_account_egld(&liquidator, scaled_amount(18, EGLD_DECIMALS), 2);
+
+    // Only half of the debt was accepted, the rest of the payment came back as EGLD
+    let debt_after = state.total_borrow_in_egld(2);
+    assert!(debt_after.into_raw_units() > &BigUint::zero());
+    let repaid = scaled_amount(18, EGLD_DECIMALS) - debt_after.into_raw_units();
+    state
+        .world
+        .check_account(liquidator)
+        .balance(liquidator_balance - repaid);
+
+    // The liquidator was paid out of the borrower's XOXNO collateral
+    let collateral_after = state.collateral_amount_for_token(2, XOXNO_TOKEN);
+    assert!(collateral_after < collateral_before);
+}
+
+/// Tests that accounts holding an asset removed from the oracle stay liquidatable.
+///
+/// Covers:
+/// - Controller::setDelistedPriceMaxAge configuration
+/// - Controller::updateIndexes recording the last known price
+/// - Controller::liquidate pricing the delisted collateral at its last known price
+/// - ERROR_DELISTED_PRICE_TOO_OLD once the last known price leaves the fallback window
+#[test]
+fn liquidate_delisted_collateral_with_last_known_price_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    let liquidator = TestAddress::new("liquidator");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+    state.set_delisted_price_max_age(SECONDS_PER_HOUR);
+
+    // Widen the last oracle tolerance so the crashed aggregator price is averaged, not rejected
+    state.edit_token_oracle_tolerance(
+        &EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN),
+        BigUint::from(MIN_FIRST_TOLERANCE),
+        BigUint::from(10_000u64),
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Borrower: $1000 XOXNO collateral, $720 EGLD debt
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XOXNO_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: XOXNO_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(18u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // XOXNO crashes and a keeper records its price right before it is delisted
+    let crashed_price = BigUint::from(6u64) * BigUint::from(10u64).pow(17);
+    state.change_price_denominated(XOXNO_TICKER, crashed_price, 0);
+    let mut markets = MultiValueEncoded::new();
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN));
+    state.update_markets(&borrower, markets);
+    let health = state.account_health_factor(2);
+    assert!(health < ManagedDecimal::from_raw_units(BigUint::from(RAY), RAY_PRECISION));
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(state.lending_sc.clone())
+        .whitebox(controller::contract_obj, |sc| {
+            sc.token_oracle(&EgldOrEsdtTokenIdentifier::esdt(
+                XOXNO_TOKEN.to_esdt_token_identifier(),
+            ))
+            .clear();
+        });
+
+    state.world.account(liquidator).nonce(1).esdt_balance(
+        EGLD_TOKEN,
+        BigUint::from(100u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+    );
+
+    // The delisted collateral is still seized at its last known price
+    let borrowed_before = state.borrow_amount_for_token(2, EGLD_TOKEN);
+    let collateral_before = state.collateral_amount_for_token(2, XOXNO_TOKEN);
+    state.liquidate_account(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(5u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    assert!(state.borrow_amount_for_token(2, EGLD_TOKEN) < borrowed_before);
+    assert!(state.collateral_amount_for_token(2, XOXNO_TOKEN) < collateral_before);
+
+    // Past the fallback window the last known price is no longer trusted
+    state.change_timestamp(SECONDS_PER_HOUR * 2);
+    state.liquidate_account_error(
+        &liquidator,
+        &EGLD_TOKEN,
+        BigUint::from(5u64),
+        2,
+        EGLD_DECIMALS,
+        ERROR_DELISTED_PRICE_TOO_OLD,
+    );
+}
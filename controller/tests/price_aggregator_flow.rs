@@ -1,14 +1,22 @@
 use multiversx_sc::types::ManagedBuffer;
 use multiversx_sc_scenario::imports::{
-    BigUint, EgldOrEsdtTokenIdentifier, ScenarioTxRun, TestAddress, TimestampSeconds,
+    BigUint, DurationSeconds, EgldOrEsdtTokenIdentifier, OptionalValue, ScenarioTxRun, TestAddress,
+    TimestampMillis, TimestampSeconds,
 };
 
 pub mod constants;
 pub mod proxys;
 pub mod setup;
 use common_constants::EGLD_TICKER;
+use common_errors::{
+    ERROR_INVALID_EGLD_MAX_PRICE_STALE_SECONDS, ERROR_INVALID_ORACLE_QUORUM,
+    ERROR_INVALID_PRICE_STALENESS_OVERRIDE, ERROR_ORACLE_QUORUM_NOT_MET, ERROR_PRICE_FEED_STALE,
+};
+use common_structs::PricingMethod;
 use constants::*;
+use controller::storage::Storage;
 use multiversx_sc_scenario::imports::ReturnsResult;
+use multiversx_sc_scenario::ScenarioTxWhitebox;
 use setup::*;
 
 #[test]
@@ -313,6 +321,39 @@ fn market_index_marks_zero_timestamp_aggregator_feed_as_stale() {
     assert!(v.is_stale);
 }
 
+#[test]
+fn token_price_with_age_reports_round_timestamp_and_staleness() {
+    let mut state = LendingPoolTestState::new();
+    let round_timestamp = SECONDS_PER_DAY;
+
+    state
+        .world
+        .current_block()
+        .block_timestamp_seconds(round_timestamp);
+    state.change_price(USDC_TICKER, USDC_PRICE_IN_DOLLARS, round_timestamp);
+
+    // Fresh aggregator round: timestamp surfaced in milliseconds, not stale
+    let (price, last_update, is_stale) = state.token_price_with_age(USDC_TOKEN);
+    assert_eq!(price, state.egld_price(USDC_TOKEN));
+    assert_eq!(last_update, TimestampMillis::new(round_timestamp * 1000));
+    assert!(!is_stale);
+
+    // Past the feed's max staleness the same round is flagged as stale
+    state
+        .world
+        .current_block()
+        .block_timestamp_seconds(round_timestamp + SECONDS_PER_HOUR * 1000);
+    let (_, last_update, is_stale) = state.token_price_with_age(USDC_TOKEN);
+    assert_eq!(last_update, TimestampMillis::new(round_timestamp * 1000));
+    assert!(is_stale);
+
+    // EGLD is not priced from the aggregator
+    let (egld_price, egld_last_update, egld_is_stale) = state.token_price_with_age(EGLD_TOKEN);
+    assert_eq!(egld_price, state.egld_price(EGLD_TOKEN));
+    assert_eq!(egld_last_update, TimestampMillis::new(0));
+    assert!(!egld_is_stale);
+}
+
 #[test]
 fn oracle_lp_high_deviation_returns_average() {
     let mut state = LendingPoolTestState::new();
@@ -421,3 +462,434 @@ fn lxoxno_view_prices_consistency_and_usd_median() {
     let xoxno_egld = state.egld_price(XOXNO_TOKEN);
     assert_eq!(lx_egld.as_raw_units(), xoxno_egld.as_raw_units());
 }
+
+#[test]
+fn oracle_quorum_enforced_on_agreeing_sources() {
+    let mut state = LendingPoolTestState::new();
+    let usdc = EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN.to_esdt_token_identifier());
+
+    // Aggregator and safe price agree: two sources satisfy a quorum of two
+    state.edit_token_oracle_quorum(&usdc, 2);
+    let usdc_price = state.usd_price(USDC_TOKEN);
+    assert!(usdc_price.into_raw_units() > &BigUint::zero());
+
+    // Only two sources exist for a Mix token, a quorum of three can never be met
+    state.edit_token_oracle_quorum(&usdc, 3);
+    state.usd_price_error(USDC_TOKEN, ERROR_ORACLE_QUORUM_NOT_MET);
+
+    // Aggregator deviates beyond the last tolerance: only the safe price agrees
+    state.change_price(USDC_TICKER, USDC_PRICE_IN_DOLLARS * 2, 0u64);
+    state.edit_token_oracle_quorum(&usdc, 2);
+    state.usd_price_error(USDC_TOKEN, ERROR_ORACLE_QUORUM_NOT_MET);
+
+    // A quorum of one accepts the single agreeing source
+    state.edit_token_oracle_quorum(&usdc, 1);
+    let fallback_price = state.usd_price(USDC_TOKEN);
+    assert!(fallback_price.into_raw_units() > &BigUint::zero());
+}
+
+#[test]
+fn oracle_quorum_derived_token_single_source() {
+    let mut state = LendingPoolTestState::new();
+    let xegld = EgldOrEsdtTokenIdentifier::esdt(XEGLD_TOKEN.to_esdt_token_identifier());
+
+    // Derived prices come from a single exchange rate source
+    state.edit_token_oracle_quorum(&xegld, 1);
+    let xegld_price = state.egld_price(XEGLD_TOKEN);
+    assert!(xegld_price.into_raw_units() > &BigUint::zero());
+
+    state.edit_token_oracle_quorum(&xegld, 2);
+    state.usd_price_error(XEGLD_TOKEN, ERROR_ORACLE_QUORUM_NOT_MET);
+}
+
+#[test]
+fn oracle_twap_falls_back_when_aggregator_deviates() {
+    let mut state = LendingPoolTestState::new();
+    let usdc = EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN.to_esdt_token_identifier());
+
+    let mix_price = state.egld_price(USDC_TOKEN);
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(state.lending_sc.clone())
+        .whitebox(controller::contract_obj, |sc| {
+            sc.token_oracle(&EgldOrEsdtTokenIdentifier::esdt(
+                USDC_TOKEN.to_esdt_token_identifier(),
+            ))
+            .update(|oracle| oracle.pricing_method = PricingMethod::Twap);
+        });
+    state.edit_token_oracle_twap_rounds(&usdc, 1);
+
+    // A fresh aggregator within tolerance is validated exactly like Mix
+    let twap_price = state.egld_price(USDC_TOKEN);
+    assert_eq!(twap_price.as_raw_units(), mix_price.as_raw_units());
+
+    // Aggregator deviates beyond the last tolerance: a single round TWAP equals the safe price
+    state.change_price(USDC_TICKER, USDC_PRICE_IN_DOLLARS * 2, 0u64);
+    let fallback_price = state.egld_price(USDC_TOKEN);
+    assert_eq!(fallback_price.as_raw_units(), mix_price.as_raw_units());
+}
+
+#[test]
+fn oracle_quorum_twap_token_counts_twap_source() {
+    let mut state = LendingPoolTestState::new();
+    let usdc = EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN.to_esdt_token_identifier());
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(state.lending_sc.clone())
+        .whitebox(controller::contract_obj, |sc| {
+            sc.token_oracle(&EgldOrEsdtTokenIdentifier::esdt(
+                USDC_TOKEN.to_esdt_token_identifier(),
+            ))
+            .update(|oracle| oracle.pricing_method = PricingMethod::Twap);
+        });
+    state.edit_token_oracle_twap_rounds(&usdc, 1);
+    state.edit_token_oracle_quorum(&usdc, 2);
+
+    // Aggregator, safe price and TWAP all agree
+    let twap_price = state.egld_price(USDC_TOKEN);
+    assert!(twap_price.into_raw_units() > &BigUint::zero());
+
+    // Aggregator deviates: the safe price and the TWAP fallback still make a quorum of two
+    state.change_price(USDC_TICKER, USDC_PRICE_IN_DOLLARS * 2, 0u64);
+    let fallback_price = state.egld_price(USDC_TOKEN);
+    assert!(fallback_price.into_raw_units() > &BigUint::zero());
+
+    // Without the aggregator only two sources agree, a quorum of three is not met
+    state.edit_token_oracle_quorum(&usdc, 3);
+    state.usd_price_error(USDC_TOKEN, ERROR_ORACLE_QUORUM_NOT_MET);
+}
+
+#[test]
+fn oracle_quorum_above_supported_sources_error() {
+    let mut state = LendingPoolTestState::new();
+    let usdc = EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN.to_esdt_token_identifier());
+
+    state.edit_token_oracle_quorum_error(&usdc, 4, ERROR_INVALID_ORACLE_QUORUM);
+}
+
+#[test]
+fn egld_reference_price_stale_reverts_value_operations() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(10u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // The EGLD oracle still accepts the feed; only the dedicated bound expires it
+    state.set_egld_max_price_stale_seconds(600);
+    state.world.current_block().block_timestamp_seconds(601);
+
+    state.supply_asset_error(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+        ERROR_PRICE_FEED_STALE,
+    );
+    state.borrow_asset_error(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+        ERROR_PRICE_FEED_STALE,
+    );
+    state.withdraw_asset_error(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(100u64),
+        2,
+        USDC_DECIMALS,
+        ERROR_PRICE_FEED_STALE,
+    );
+    state.repay_asset_error(
+        &borrower,
+        &EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+        ERROR_PRICE_FEED_STALE,
+    );
+    state.liquidate_account_error(
+        &supplier,
+        &EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+        ERROR_PRICE_FEED_STALE,
+    );
+    state.usd_price_error(USDC_TOKEN, ERROR_PRICE_FEED_STALE);
+
+    // A fresh EGLD round restores every operation
+    submit_price(
+        &mut state.world,
+        &state.price_aggregator_sc,
+        EGLD_TICKER,
+        EGLD_PRICE_IN_DOLLARS,
+        601,
+    );
+    state.borrow_asset(&borrower, EGLD_TOKEN, BigUint::from(1u64), 2, EGLD_DECIMALS);
+    let debt = state.borrow_amount_for_token(2, EGLD_TOKEN);
+    assert!(
+        debt.into_raw_units() >= &scaled_amount(11, EGLD_DECIMALS),
+        "borrow must resume once the EGLD reference price is fresh",
+    );
+}
+
+#[test]
+fn egld_reference_price_max_age_zero_error() {
+    let mut state = LendingPoolTestState::new();
+
+    state.set_egld_max_price_stale_seconds_error(0, ERROR_INVALID_EGLD_MAX_PRICE_STALE_SECONDS);
+}
+
+#[test]
+fn price_staleness_override_extends_tolerance_until_expiry() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Tighten the USDC tolerance so the round submitted at setup goes stale
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(state.lending_sc.clone())
+        .whitebox(controller::contract_obj, |sc| {
+            sc.token_oracle(&EgldOrEsdtTokenIdentifier::esdt(
+                USDC_TOKEN.to_esdt_token_identifier(),
+            ))
+            .update(|oracle| oracle.max_price_stale_seconds = DurationSeconds::new(600));
+        });
+    state.world.current_block().block_timestamp_seconds(601);
+
+    state.borrow_asset_error(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+        ERROR_PRICE_FEED_STALE,
+    );
+
+    // The override must expire in the future
+    state.set_price_staleness_override_error(
+        USDC_TOKEN,
+        600,
+        601,
+        ERROR_INVALID_PRICE_STALENESS_OVERRIDE,
+    );
+
+    // Within the override window the stale USDC round is accepted
+    state.set_price_staleness_override(USDC_TOKEN, 600, 1000);
+    state.borrow_asset(&borrower, EGLD_TOKEN, BigUint::from(1u64), 2, EGLD_DECIMALS);
+
+    // Once expired, the configured tolerance applies again without any owner action
+    state.world.current_block().block_timestamp_seconds(1000);
+    state.borrow_asset_error(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+        ERROR_PRICE_FEED_STALE,
+    );
+}
+
+#[test]
+fn aggregator_max_deviation_excludes_outlier_submission() {
+    let mut state = LendingPoolTestState::new();
+    let agg = state.price_aggregator_sc.clone();
+    let ticker: &[u8] = b"OUTL";
+    let price_of = |state: &mut LendingPoolTestState| {
+        state
+            .world
+            .query()
+            .to(agg.clone())
+            .typed(proxys::proxy_aggregator::PriceAggregatorProxy)
+            .latest_price_feed(
+                ManagedBuffer::from(ticker),
+                ManagedBuffer::from(DOLLAR_TICKER),
+            )
+            .returns(ReturnsResult)
+            .run()
+            .price
+    };
+
+    // Without the filter the 10x submission drags the even-sized median up
+    submit_oracle_prices(&mut state.world, &agg, ticker, [100, 102, 104, 1_000], 0);
+    assert_eq!(
+        price_of(&mut state),
+        BigUint::from(103u64) * BigUint::from(WAD)
+    );
+
+    // A deviation above 100% is rejected
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(agg.clone())
+        .typed(proxys::proxy_aggregator::PriceAggregatorProxy)
+        .set_max_deviation_bps(10_001u64)
+        .returns(multiversx_sc_scenario::imports::ExpectMessage(
+            "Invalid max deviation",
+        ))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(agg.clone())
+        .typed(proxys::proxy_aggregator::PriceAggregatorProxy)
+        .set_max_deviation_bps(1_000u64)
+        .run();
+
+    // With a 10% bound the outlier is dropped and the median uses the three honest prices
+    submit_oracle_prices(&mut state.world, &agg, ticker, [100, 102, 104, 1_000], 0);
+    assert_eq!(
+        price_of(&mut state),
+        BigUint::from(102u64) * BigUint::from(WAD)
+    );
+}
+
+#[test]
+fn aggregator_weighted_median_excludes_outlier_submission() {
+    let mut state = LendingPoolTestState::new();
+    let agg = state.price_aggregator_sc.clone();
+    let ticker: &[u8] = b"WGHT";
+    let price_of = |state: &mut LendingPoolTestState| {
+        state
+            .world
+            .query()
+            .to(agg.clone())
+            .typed(proxys::proxy_aggregator::PriceAggregatorProxy)
+            .latest_price_feed(
+                ManagedBuffer::from(ticker),
+                ManagedBuffer::from(DOLLAR_TICKER),
+            )
+            .returns(ReturnsResult)
+            .run()
+            .price
+    };
+
+    // Weights must be positive and only registered oracles can be weighted
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(agg.clone())
+        .typed(proxys::proxy_aggregator::PriceAggregatorProxy)
+        .set_oracle_weight(ORACLE_ADDRESS_1.to_managed_address(), 0u64)
+        .returns(multiversx_sc_scenario::imports::ExpectMessage(
+            "Invalid oracle weight",
+        ))
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(agg.clone())
+        .typed(proxys::proxy_aggregator::PriceAggregatorProxy)
+        .set_oracle_weight(OWNER_ADDRESS.to_managed_address(), 3u64)
+        .returns(multiversx_sc_scenario::imports::ExpectMessage(
+            "Oracle not found",
+        ))
+        .run();
+
+    // The first oracle counts three times
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(agg.clone())
+        .typed(proxys::proxy_aggregator::PriceAggregatorProxy)
+        .set_oracle_weight(ORACLE_ADDRESS_1.to_managed_address(), 3u64)
+        .run();
+
+    // Half of the total weight sits on 100, so the median averages it with the next price
+    submit_oracle_prices(&mut state.world, &agg, ticker, [100, 102, 104, 1_000], 0);
+    assert_eq!(
+        price_of(&mut state),
+        BigUint::from(101u64) * BigUint::from(WAD)
+    );
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(agg.clone())
+        .typed(proxys::proxy_aggregator::PriceAggregatorProxy)
+        .set_max_deviation_bps(1_000u64)
+        .run();
+
+    // Without the outlier the weighted majority sits on 100
+    submit_oracle_prices(&mut state.world, &agg, ticker, [100, 102, 104, 1_000], 0);
+    assert_eq!(
+        price_of(&mut state),
+        BigUint::from(100u64) * BigUint::from(WAD)
+    );
+}
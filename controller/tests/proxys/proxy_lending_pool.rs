@@ -132,6 +132,36 @@ where
             .original_result()
     }
 
+    /// Supplies collateral to an existing account on behalf of its holder.
+    ///
+    /// Purpose: Lets keepers, relayers and vault aggregators top up a user's position
+    /// without holding the account NFT. The collateral comes from the caller and is
+    /// credited to the account held by `beneficiary`.
+    ///
+    /// # Arguments
+    /// - `beneficiary`: Address holding the account NFT.
+    /// - `account_nonce`: Existing account NFT nonce to credit.
+    ///
+    /// # Payment
+    /// - Accepts one or more collateral tokens; the account NFT is not required.
+    ///
+    /// # Errors
+    /// - `ERROR_ACCOUNT_NOT_HELD_BY_BENEFICIARY`: If `beneficiary` is not the holder of the account.
+    pub fn supply_for<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        beneficiary: Arg0,
+        account_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("supplyFor")
+            .argument(&beneficiary)
+            .argument(&account_nonce)
+            .original_result()
+    }
+
     /// Withdraws collateral from the lending pool.
     ///
     /// Purpose: Transfers requested collateral amounts from the user's deposit
@@ -160,6 +190,26 @@ where
             .original_result()
     }
 
+    /// Claims the dust collateral credited to an account by a debt-clearing liquidation.
+    ///
+    /// Purpose: Pays the collateral set aside by `liquidate` to the current holder of
+    /// the account NFT. `withdraw` pays it out as well.
+    ///
+    /// Methodology:
+    /// 1. Validates the account NFT
+    /// 2. Transfers every credited token to the caller
+    /// 3. Burns the NFT if the account holds no position, otherwise returns it
+    ///
+    /// Payment
+    /// - Requires the account NFT as payment (first and only NFT).
+    pub fn claim_dust_collateral(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("claimDustCollateral")
+            .original_result()
+    }
+
     /// Borrows assets from the lending pool.
     ///
     /// Purpose: Creates or scales borrow positions for the account, with
@@ -168,8 +218,9 @@ where
     /// Methodology:
     /// 1. Validates account NFT and syncs indexes/prices
     /// 2. Computes LTV collateral value from current deposits
-    /// 3. Validates bulk position limits for all requested borrows
-    /// 4. For each token: validates borrowability, caps, LTV, updates position
+    /// 3. Merges duplicate tokens so each asset is borrowed once with the summed amount
+    /// 4. Validates bulk position limits for all requested borrows
+    /// 5. For each token: validates borrowability, caps, LTV, updates position
     ///
     /// Payment
     /// - Requires the account NFT as payment.
@@ -188,26 +239,206 @@ where
             .original_result()
     }
 
+    /// Borrows one or more assets and requires a minimum health factor afterwards.
+    ///
+    /// Purpose: Lets cautious borrowers keep a safety buffer above the liquidation
+    /// threshold without computing the maximum safe amount themselves. Behaves like
+    /// `borrow`, then checks the resulting health factor once after all borrows.
+    ///
+    /// Payment
+    /// - Requires the account NFT as payment.
+    ///
+    /// Arguments
+    /// - `min_health_factor_after`: Minimum health factor after the borrows, in RAY precision
+    /// - `borrowed_tokens`: List of tokens and amounts to borrow
+    ///
+    /// Errors
+    /// - `ERROR_HEALTH_FACTOR_BELOW_REQUESTED_MINIMUM`: If the resulting health factor is
+    ///   below `min_health_factor_after`
+    pub fn borrow_with_min_health_factor<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenPayment<Env::Api>>>,
+    >(
+        self,
+        min_health_factor_after: Arg0,
+        borrowed_tokens: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("borrowWithMinHealthFactor")
+            .argument(&min_health_factor_after)
+            .argument(&borrowed_tokens)
+            .original_result()
+    }
+
+    /// Borrows one or more assets and caps the borrow rate they leave behind.
+    ///
+    /// Purpose: Interest rate slippage protection for programmatic borrowers. Behaves
+    /// like `borrow`, then reverts if the annual borrow rate of any borrowed market,
+    /// at its post-borrow utilization, exceeds the caller-supplied ceiling.
+    ///
+    /// Payment
+    /// - Requires the account NFT as payment.
+    ///
+    /// Arguments
+    /// - `max_acceptable_rate`: Maximum annual borrow rate after the borrows, in RAY precision
+    /// - `borrowed_tokens`: List of tokens and amounts to borrow
+    ///
+    /// Errors
+    /// - `ERROR_BORROW_RATE_ABOVE_MAX_ACCEPTABLE`: If a borrowed market's resulting borrow
+    ///   rate is above `max_acceptable_rate`
+    pub fn borrow_with_max_rate<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenPayment<Env::Api>>>,
+    >(
+        self,
+        max_acceptable_rate: Arg0,
+        borrowed_tokens: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("borrowWithMaxRate")
+            .argument(&max_acceptable_rate)
+            .argument(&borrowed_tokens)
+            .original_result()
+    }
+
     /// Repays borrowed assets for an account.
     ///
     /// Purpose: Decreases or clears debt positions for one or more assets.
     ///
     /// Methodology:
-    /// 1. Validates account and caller
+    /// 1. Validates account and caller, deriving the account from the attached NFT if present
     /// 2. For each payment: validates asset/amount, converts to decimals and EGLD value
     /// 3. Calls process_repayment to update pool and position, tracking isolated debt
+    /// 4. Returns the account NFT to the caller when it was attached
     ///
     /// Arguments
-    /// - `account_nonce`: NFT nonce of the account
+    /// - `opt_account_nonce`: NFT nonce of the account; optional when the account NFT
+    ///   is sent as the first payment, in which case the remaining transfers are repaid
     pub fn repay<
-        Arg0: ProxyArg<u64>,
+        Arg0: ProxyArg<OptionalValue<u64>>,
     >(
         self,
-        account_nonce: Arg0,
+        opt_account_nonce: Arg0,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
             .raw_call("repay")
-            .argument(&account_nonce)
+            .argument(&opt_account_nonce)
+            .original_result()
+    }
+
+    /// Repays debt with the account's deposit of the same token.
+    ///
+    /// Purpose: Lets accounts that both supply and borrow an asset net the two
+    /// positions without a withdrawal followed by a separate repayment.
+    ///
+    /// Methodology:
+    /// 1. Validates the account NFT and the requested amount
+    /// 2. Calls process_self_repayment to burn the deposit and the debt in the pool
+    /// 3. Validates health factor and returns or burns the account NFT
+    ///
+    /// Payment
+    /// - Requires the account NFT as payment.
+    ///
+    /// Arguments
+    /// - `token_id`: Token both supplied and borrowed by the account
+    /// - `amount`: Amount to repay in asset decimals, capped at the outstanding debt
+    pub fn self_repay_from_supply<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("selfRepayFromSupply")
+            .argument(&token_id)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Closes an account in a single transaction.
+    ///
+    /// Purpose: Repays all outstanding debt with the provided payments,
+    /// withdraws every remaining collateral position to the caller and
+    /// burns the account NFT.
+    ///
+    /// Methodology:
+    /// 1. Validates the account NFT and extracts the repayment payments
+    /// 2. Repays each payment against the matching borrow position
+    /// 3. Requires that no borrow position remains open
+    /// 4. Withdraws the full balance of each deposit position and burns the NFT
+    ///
+    /// Payment
+    /// - Requires the account NFT as the first payment, followed by the repayment tokens.
+    pub fn close_account(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("closeAccount")
+            .original_result()
+    }
+
+    /// Repays debt and withdraws collateral in a single transaction.
+    ///
+    /// Purpose: Reduces a position proportionally; repaying first frees up
+    /// collateral so the withdrawal can go through with a single health check.
+    ///
+    /// Methodology:
+    /// 1. Validates the account NFT and extracts the repayment payments
+    /// 2. Repays each payment against the matching borrow position
+    /// 3. Withdraws each requested collateral amount
+    /// 4. Validates health factor once after both steps and returns or burns the NFT
+    ///
+    /// Payment
+    /// - Requires the account NFT as the first payment, followed by the repayment tokens.
+    ///
+    /// Arguments
+    /// - `collaterals`: List of token identifiers and amounts to withdraw
+    pub fn repay_and_withdraw<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenPayment<Env::Api>>>,
+    >(
+        self,
+        collaterals: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("repayAndWithdraw")
+            .argument(&collaterals)
+            .original_result()
+    }
+
+    /// Moves an account to another e-mode category in a single transaction.
+    ///
+    /// Purpose: Migrates an account between e-mode categories that do not share all
+    /// assets; debt not borrowable in the new category is repaid and collateral can be
+    /// topped up so the account is valid once the switch is applied.
+    ///
+    /// Methodology:
+    /// 1. Validates the account NFT and splits the payments into repayments and collateral
+    /// 2. Repays the repayment payments under the current category
+    /// 3. Switches the category, re-applying its risk parameters to every position
+    /// 4. Supplies the collateral payments under the new category
+    /// 5. Validates health factor once and returns the NFT with the updated attributes
+    ///
+    /// Payment
+    /// - Requires the account NFT as the first payment, followed by `repay_count`
+    ///   repayment tokens and then the additional collateral tokens.
+    ///
+    /// Arguments
+    /// - `new_category`: E-mode category to switch to (0 = disable e-mode)
+    /// - `repay_count`: Number of payments following the account NFT that repay debt
+    pub fn roll_e_mode<
+        Arg0: ProxyArg<u8>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        new_category: Arg0,
+        repay_count: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("rollEMode")
+            .argument(&new_category)
+            .argument(&repay_count)
             .original_result()
     }
 
@@ -221,17 +452,24 @@ where
     /// 2. Executes liquidation core to compute repayments and seized collateral
     /// 3. Refunds excess payments, processes repayments and transfers collateral
     ///
+    /// Debt in the native EGLD market can be repaid with a plain EGLD transfer; any
+    /// excess is refunded to the liquidator in native EGLD as well.
+    ///
     /// Arguments
     /// - `account_nonce`: NFT nonce identifying the liquidated account
+    /// - `preferred_collaterals`: Optional collateral tokens to seize first, in order
     pub fn liquidate<
         Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<OptionalValue<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>>,
     >(
         self,
         account_nonce: Arg0,
+        preferred_collaterals: Arg1,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
             .raw_call("liquidate")
             .argument(&account_nonce)
+            .argument(&preferred_collaterals)
             .original_result()
     }
 
@@ -308,6 +546,9 @@ where
     /// Updates interest rate indexes for specified assets.
     ///
     /// Purpose: Synchronizes supply/borrow indexes using current prices.
+    /// When the liquidation price regression check is enabled, also seeds its
+    /// reference prices while they are empty. When the delisted price fallback
+    /// is enabled, records the current prices as the last known ones.
     ///
     /// Arguments
     /// - `assets`: Asset identifiers to update
@@ -324,6 +565,105 @@ where
             .original_result()
     }
 
+    /// Opts an account in or out of explicit compounding of supply interest.
+    ///
+    /// Purpose: Lets integrations that track principal receive explicit
+    /// compounding events instead of deriving interest from index changes.
+    ///
+    /// Methodology:
+    /// 1. Validates the account NFT and returns it to the caller
+    /// 2. On opt-in, snapshots the current balance of every deposit as its principal
+    /// 3. On opt-out, clears the tracked principals
+    ///
+    /// Arguments
+    /// - `enabled`: Whether auto-compounding should be active
+    pub fn set_auto_compound<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("setAutoCompound")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    /// Stops a deposit from counting as collateral.
+    ///
+    /// Purpose: Lets users earn supply yield on an asset without exposing it
+    /// to liquidation. Supply-only deposits are excluded from LTV, health
+    /// factor and liquidation seizure.
+    ///
+    /// Methodology:
+    /// 1. Validates the account NFT and returns it to the caller
+    /// 2. Marks the deposit as supply-only
+    /// 3. Validates the health factor stays at or above 1.0 without it
+    ///
+    /// Arguments
+    /// - `asset_id`: Deposited asset to exclude from collateral
+    pub fn disable_as_collateral<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("disableAsCollateral")
+            .argument(&asset_id)
+            .original_result()
+    }
+
+    /// Makes a supply-only deposit count as collateral again.
+    ///
+    /// Methodology:
+    /// 1. Validates the account NFT and returns it to the caller
+    /// 2. Marks the deposit as collateral
+    ///
+    /// Arguments
+    /// - `asset_id`: Deposited asset to include in collateral
+    pub fn enable_as_collateral<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("enableAsCollateral")
+            .argument(&asset_id)
+            .original_result()
+    }
+
+    /// Compounds the accrued supply interest of a deposit into its principal.
+    ///
+    /// Purpose: Keeper-callable compounding event for accounts that opted in.
+    /// Index math already compounds balances; this re-bases the tracked
+    /// principal to the current balance and emits the realized interest.
+    ///
+    /// Methodology:
+    /// 1. Validates the account opted in and holds a deposit of the asset
+    /// 2. Synchronizes the market indexes
+    /// 3. Emits the interest accrued since the last re-base and stores the new principal
+    ///
+    /// Arguments
+    /// - `account_nonce`: NFT nonce of the account
+    /// - `asset_id`: Deposited asset to compound
+    pub fn compound_interest<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        account_nonce: Arg0,
+        asset_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("compoundInterest")
+            .argument(&account_nonce)
+            .argument(&asset_id)
+            .original_result()
+    }
+
     /// Cleans bad debt from an account.
     ///
     /// Purpose: Seizes all remaining collateral and marks remaining debt
@@ -435,6 +775,10 @@ where
     /// - `asset_decimals`: Token decimal precision
     /// - `borrow_cap`: Maximum total borrows (0 = unlimited)
     /// - `supply_cap`: Maximum total supply (0 = unlimited)
+    /// - `max_liquidation_close_factor`: Share of the debt a single liquidation can repay
+    ///   (basis points, defaults to 10000)
+    /// - `min_initial_supply`: Reserve buffer in asset units burned from the first deposit,
+    ///   so the pool never returns to an empty supply (defaults to 0, disabled)
     ///
     /// # Returns
     /// Address of the newly deployed liquidity pool contract
@@ -443,6 +787,7 @@ where
     /// - `ERROR_ASSET_ALREADY_SUPPORTED`: Asset already has an active pool
     /// - `ERROR_INVALID_TICKER`: Invalid asset identifier format
     /// - `ERROR_INVALID_LIQUIDATION_THRESHOLD`: Threshold not greater than LTV
+    /// - `ERROR_INVALID_LIQUIDATION_CLOSE_FACTOR`: Close factor zero or above 10000
     pub fn create_liquidity_pool<
         Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
         Arg1: ProxyArg<BigUint<Env::Api>>,
@@ -468,6 +813,8 @@ where
         Arg21: ProxyArg<usize>,
         Arg22: ProxyArg<BigUint<Env::Api>>,
         Arg23: ProxyArg<BigUint<Env::Api>>,
+        Arg24: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg25: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
     >(
         self,
         base_asset: Arg0,
@@ -494,6 +841,8 @@ where
         asset_decimals: Arg21,
         borrow_cap_wad: Arg22,
         supply_cap_wad: Arg23,
+        max_liquidation_close_factor: Arg24,
+        min_initial_supply: Arg25,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
@@ -522,11 +871,16 @@ where
             .argument(&asset_decimals)
             .argument(&borrow_cap_wad)
             .argument(&supply_cap_wad)
+            .argument(&max_liquidation_close_factor)
+            .argument(&min_initial_supply)
             .original_result()
     }
 
     /// Upgrades an existing liquidity pool with new parameters.
     /// Adjusts interest rate model and reserve settings.
+    /// Interest accrual of the market is paused for the upgrade and resumed by the
+    /// upgraded pool, so indexes never advance mid-upgrade. Pausing needs no price,
+    /// so a stale oracle never blocks an upgrade.
     ///
     /// # Arguments
     /// - `base_asset`: Token identifier (EGLD or ESDT) of the asset.
@@ -600,47 +954,223 @@ where
             .original_result()
     }
 
-    /// Collects protocol revenue from liquidity pools and routes to accumulator.
-    ///
-    /// **Purpose**: Harvests accumulated protocol fees and interest spreads from
-    /// multiple liquidity pools in a single transaction for gas efficiency.
-    /// Routes collected revenue to the accumulator for proper distribution.
-    ///
-    /// **How it works**:
-    /// 1. **Initialization**: Creates cache and validates accumulator address exists
-    /// 2. **Multi-asset iteration**: Processes each specified asset sequentially
-    /// 3. **Revenue claiming**: Calls each pool's claim_revenue with latest price
-    /// 4. **Revenue routing**: Deposits non-zero revenue into accumulator contract
-    /// 5. **Gas optimization**: Batches multiple claims in single transaction
+    /// Sets the minimum initial supply burned on the first deposit into a market.
     ///
-    /// **Revenue sources collected**:
-    /// - **Interest rate spread**: Difference between borrow and supply rates
-    /// - **Reserve factor**: Percentage of interest reserved for protocol
-    /// - **Liquidation fees**: Fees collected during position liquidations
-    /// - **Flash loan fees**: Fees from flash loan operations
+    /// Purpose: Seed an empty pool with unowned supply so a first depositor cannot
+    /// inflate the supply index through donations and dilute later suppliers.
     ///
-    /// **Price-aware collection**:
-    /// Passes latest oracle price to each pool for accurate revenue calculation:
-    /// ```
-    /// revenue_value = pool_balance * latest_price
-    /// revenue_amount = calculate_claimable_amount(revenue_value)
-    /// ```
+    /// Arguments
+    /// - `base_asset`: Market asset identifier
+    /// - `min_initial_supply`: Amount in asset units burned from the first deposit (0 disables)
+    pub fn set_market_min_initial_supply<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        base_asset: Arg0,
+        min_initial_supply: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMarketMinInitialSupply")
+            .argument(&base_asset)
+            .argument(&min_initial_supply)
+            .original_result()
+    }
+
+    /// Chooses how a market absorbs debt written off by bad debt cleanup.
     ///
-    /// **Zero-revenue optimization**:
-    /// Only deposits revenue into accumulator if amount > 0, preventing
-    /// unnecessary transactions and gas costs for pools with no accrued revenue.
+    /// Purpose: By default the loss is socialized across all suppliers by reducing the
+    /// supply index proportionally. When enabled, the market's protocol revenue is burned
+    /// first and only the uncovered remainder reduces the supply index.
     ///
-    /// **Multi-asset efficiency**:
-    /// - Single transaction processes multiple assets
-    /// - Cached price feeds reduce oracle query costs
-    /// - Batched accumulator deposits minimize transaction overhead
-    /// - Early termination for zero-revenue assets
+    /// Arguments
+    /// - `base_asset`: Market asset identifier
+    /// - `enabled`: `true` to cover bad debt with protocol revenue before suppliers
+    pub fn set_market_revenue_absorbs_bad_debt<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        base_asset: Arg0,
+        enabled: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMarketRevenueAbsorbsBadDebt")
+            .argument(&base_asset)
+            .argument(&enabled)
+            .original_result()
+    }
+
+    /// Selects the borrow rate curve of a market.
     ///
-    /// **Security considerations**:
-    /// - Owner-only access ensures governance control over revenue collection
-    /// - Accumulator address validation prevents revenue loss
-    /// - Latest price usage prevents stale price manipulation
-    /// - Cache consistency ensures accurate revenue calculations
+    /// Purpose: Switch a market between the three-slope and the kink rate model while
+    /// keeping its rate parameters. Interest accrued so far is settled under the
+    /// previous model before the switch.
+    ///
+    /// Arguments
+    /// - `base_asset`: Market asset identifier
+    /// - `rate_model_type`: Rate model applied to the market's parameters
+    pub fn set_market_rate_model_type<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<common_structs::RateModelType>,
+    >(
+        self,
+        base_asset: Arg0,
+        rate_model_type: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMarketRateModelType")
+            .argument(&base_asset)
+            .argument(&rate_model_type)
+            .original_result()
+    }
+
+    /// Sets the elevated reserve factor of a market.
+    ///
+    /// Purpose: Let the protocol take a larger share of interest while a market runs
+    /// above its optimal utilization. Zero keeps the reserve factor flat. Interest
+    /// accrued so far is settled under the previous reserve factor.
+    ///
+    /// Arguments
+    /// - `base_asset`: Market asset identifier
+    /// - `reserve_factor_high`: Reserve factor above the optimal utilization (BPS)
+    pub fn set_market_reserve_factor_high<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        base_asset: Arg0,
+        reserve_factor_high: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMarketReserveFactorHigh")
+            .argument(&base_asset)
+            .argument(&reserve_factor_high)
+            .original_result()
+    }
+
+    /// Sets the absolute borrow rate ceiling of a market enforced at accrual.
+    ///
+    /// Purpose: Final safety against punitive interest at extreme utilization. The
+    /// borrow rate accrues at most at this ceiling whatever the rate curve yields.
+    /// Zero disables it; otherwise it must lie above the base borrow rate and at most
+    /// at the max borrow rate. Interest accrued so far is settled under the previous ceiling.
+    ///
+    /// Arguments
+    /// - `base_asset`: Market asset identifier
+    /// - `max_accrual_rate`: Annual borrow rate ceiling (RAY)
+    pub fn set_market_max_accrual_rate<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        base_asset: Arg0,
+        max_accrual_rate: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMarketMaxAccrualRate")
+            .argument(&base_asset)
+            .argument(&max_accrual_rate)
+            .original_result()
+    }
+
+    /// Sets the minimum time between two index recomputations of a market.
+    ///
+    /// Purpose: Save gas on high-frequency `updateIndexes` calls. Syncs within the
+    /// interval keep serving the last computed indexes and leave the last update
+    /// timestamp untouched, so the skipped time accrues on the next real sync.
+    /// Zero recomputes the indexes on every sync.
+    ///
+    /// Arguments
+    /// - `base_asset`: Market asset identifier
+    /// - `min_accrual_interval_ms`: Minimum interval between index updates (ms), at most one hour
+    pub fn set_market_min_accrual_interval<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<DurationMillis>,
+    >(
+        self,
+        base_asset: Arg0,
+        min_accrual_interval_ms: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMarketMinAccrualInterval")
+            .argument(&base_asset)
+            .argument(&min_accrual_interval_ms)
+            .original_result()
+    }
+
+    /// Pauses or resumes interest accrual of a market.
+    ///
+    /// Purpose: Freeze a market's indexes outside of `upgradeLiquidityPool`, e.g. to
+    /// resume a market whose upgrade did not complete. Interest accrued so far is
+    /// settled before pausing, and the paused window never accrues interest.
+    ///
+    /// Arguments
+    /// - `base_asset`: Market asset identifier
+    /// - `paused`: `true` to freeze the indexes, `false` to resume accrual
+    pub fn set_market_accrual_paused<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        base_asset: Arg0,
+        paused: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMarketAccrualPaused")
+            .argument(&base_asset)
+            .argument(&paused)
+            .original_result()
+    }
+
+    /// Collects protocol revenue from liquidity pools and routes to accumulator.
+    ///
+    /// **Purpose**: Harvests accumulated protocol fees and interest spreads from
+    /// multiple liquidity pools in a single transaction for gas efficiency.
+    /// Routes collected revenue to the accumulator for proper distribution.
+    ///
+    /// **How it works**:
+    /// 1. **Initialization**: Creates cache and validates accumulator address exists
+    /// 2. **Multi-asset iteration**: Processes each specified asset sequentially
+    /// 3. **Revenue claiming**: Calls each pool's claim_revenue with latest price
+    /// 4. **Revenue routing**: Deposits non-zero revenue into accumulator contract
+    /// 5. **Gas optimization**: Batches multiple claims in single transaction
+    ///
+    /// **Revenue sources collected**:
+    /// - **Interest rate spread**: Difference between borrow and supply rates
+    /// - **Reserve factor**: Percentage of interest reserved for protocol
+    /// - **Liquidation fees**: Fees collected during position liquidations
+    /// - **Flash loan fees**: Fees from flash loan operations
+    ///
+    /// **Price-aware collection**:
+    /// Passes latest oracle price to each pool for accurate revenue calculation:
+    /// ```
+    /// revenue_value = pool_balance * latest_price
+    /// revenue_amount = calculate_claimable_amount(revenue_value)
+    /// ```
+    ///
+    /// **Zero-revenue optimization**:
+    /// Only deposits revenue into accumulator if amount > 0, preventing
+    /// unnecessary transactions and gas costs for pools with no accrued revenue.
+    ///
+    /// **Multi-asset efficiency**:
+    /// - Single transaction processes multiple assets
+    /// - Cached price feeds reduce oracle query costs
+    /// - Batched accumulator deposits minimize transaction overhead
+    /// - Early termination for zero-revenue assets
+    ///
+    /// **Security considerations**:
+    /// - Owner-only access ensures governance control over revenue collection
+    /// - Accumulator address validation prevents revenue loss
+    /// - Latest price usage prevents stale price manipulation
+    /// - Cache consistency ensures accurate revenue calculations
     ///
     /// **Revenue flow pattern**:
     /// ```
@@ -741,8 +1271,12 @@ where
     /// - `source`: Exchange source (e.g., XExchange).
     /// - `first_tolerance`, `last_tolerance`: Tolerance values for price fluctuations.
     ///
+    /// The `Twap` pricing method averages `DEFAULT_TWAP_ROUNDS` safe price rounds in its
+    /// fallback until `editTokenOracleTwapRounds` changes the window.
+    ///
     /// # Errors
     /// - `ERROR_ORACLE_TOKEN_NOT_FOUND`: If oracle already exists for the token.
+    /// - `ERROR_INVALID_EXCHANGE_SOURCE`: If the `Twap` pricing method is used without xExchange.
     pub fn set_token_oracle<
         Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
         Arg1: ProxyArg<usize>,
@@ -783,6 +1317,29 @@ where
             .original_result()
     }
 
+    /// Configures the oracles for several tokens in a single transaction.
+    /// Applies the same validation as `setTokenOracle` to every entry.
+    ///
+    /// # Arguments
+    /// - `entries`: Oracle configurations as `(market_token, decimals, contract_address,
+    ///   pricing_method, token_type, source, first_tolerance, last_tolerance,
+    ///   max_price_stale_seconds, one_dex_pair_id)`; use `0` as pair id for non-Onedex sources.
+    ///
+    /// # Errors
+    /// - `ERROR_ORACLE_TOKEN_EXISTING`: If an oracle already exists for any entry's token.
+    pub fn batch_set_token_oracle<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue10<EgldOrEsdtTokenIdentifier<Env::Api>, usize, ManagedAddress<Env::Api>, common_structs::PricingMethod, common_structs::OracleType, common_structs::ExchangeSource, BigUint<Env::Api>, BigUint<Env::Api>, DurationSeconds, usize>>>,
+    >(
+        self,
+        entries: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("batchSetTokenOracle")
+            .argument(&entries)
+            .original_result()
+    }
+
     /// Updates the tolerance settings for a token’s oracle.
     /// Adjusts acceptable price deviation ranges.
     ///
@@ -811,6 +1368,58 @@ where
             .original_result()
     }
 
+    /// Updates the oracle quorum requirement for a token’s oracle.
+    /// Requires that many independent price sources to agree within the last tolerance.
+    ///
+    /// # Arguments
+    /// - `market_token`: Token identifier (EGLD or ESDT).
+    /// - `min_oracle_quorum`: Number of agreeing sources required (0 disables the requirement).
+    ///
+    /// # Errors
+    /// - `ERROR_ORACLE_TOKEN_NOT_FOUND`: If no oracle exists for the token.
+    /// - `ERROR_INVALID_ORACLE_QUORUM`: If the quorum exceeds the number of supported sources.
+    pub fn edit_token_oracle_quorum<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u8>,
+    >(
+        self,
+        market_token: Arg0,
+        min_oracle_quorum: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("editTokenOracleQuorum")
+            .argument(&market_token)
+            .argument(&min_oracle_quorum)
+            .original_result()
+    }
+
+    /// Updates the number of safe price rounds averaged by a token's TWAP fallback.
+    /// Only used by the `Twap` pricing method, when the primary price is unavailable.
+    ///
+    /// # Arguments
+    /// - `market_token`: Token identifier (EGLD or ESDT).
+    /// - `twap_rounds`: Number of 15-minute safe price rounds to average.
+    ///
+    /// # Errors
+    /// - `ERROR_ORACLE_TOKEN_NOT_FOUND`: If no oracle exists for the token.
+    /// - `ERROR_INVALID_TWAP_ROUNDS`: If the rounds are zero or exceed `MAX_TWAP_ROUNDS`.
+    pub fn edit_token_oracle_twap_rounds<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u8>,
+    >(
+        self,
+        market_token: Arg0,
+        twap_rounds: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("editTokenOracleTwapRounds")
+            .argument(&market_token)
+            .argument(&twap_rounds)
+            .original_result()
+    }
+
     /// Sets the price aggregator contract address.
     /// Configures the source for aggregated price data.
     ///
@@ -853,6 +1462,38 @@ where
             .original_result()
     }
 
+    /// Sets the default swap router steps for converting one token into another.
+    ///
+    /// **Purpose**: Lets strategy calls omit the swap steps for common pairs. When a
+    /// strategy swaps `from_token` into `to_token` with empty steps, the router is
+    /// called with these steps instead (e.g. the preferred pools for the pair).
+    ///
+    /// # Arguments
+    /// - `from_token`: Token identifier (EGLD or ESDT) being swapped
+    /// - `to_token`: Token identifier (EGLD or ESDT) received from the swap
+    /// - `steps`: Raw swap router arguments; empty removes the default
+    ///
+    /// # Errors
+    /// - `ERROR_ASSETS_ARE_THE_SAME`: If both tokens are the same
+    pub fn set_default_swap_steps<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        from_token: Arg0,
+        to_token: Arg1,
+        steps: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDefaultSwapSteps")
+            .argument(&from_token)
+            .argument(&to_token)
+            .argument(&steps)
+            .original_result()
+    }
+
     /// Sets the accumulator contract address.
     /// Configures where protocol revenue is collected.
     ///
@@ -1026,7 +1667,8 @@ where
     }
 
     /// Adds an asset to an e-mode category with usage flags.
-    /// Configures collateral and borrowability in e-mode.
+    /// Configures collateral and borrowability in e-mode; the asset can back borrows on its own
+    /// until `setEModeCompanionCollateralRequired` requires a companion collateral.
     ///
     /// # Arguments
     /// - `asset`: Token identifier (EGLD or ESDT).
@@ -1090,6 +1732,36 @@ where
             .original_result()
     }
 
+    /// Sets whether an e-mode collateral needs another eligible collateral to back a borrow.
+    /// A flagged asset is rejected as the sole e-mode collateral of a borrowing account.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT).
+    /// - `category_id`: E-mode category ID.
+    /// - `required`: Whether a companion collateral is required.
+    ///
+    /// # Errors
+    /// - `ERROR_EMODE_CATEGORY_NOT_FOUND`: If the category ID does not exist.
+    /// - `ERROR_ASSET_NOT_SUPPORTED_IN_EMODE`: If the asset is not in the category.
+    pub fn set_e_mode_companion_collateral_required<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u8>,
+        Arg2: ProxyArg<bool>,
+    >(
+        self,
+        asset: Arg0,
+        category_id: Arg1,
+        required: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEModeCompanionCollateralRequired")
+            .argument(&asset)
+            .argument(&category_id)
+            .argument(&required)
+            .original_result()
+    }
+
     /// Removes an asset from an e-mode category.
     /// Disables the asset’s e-mode capabilities for the category.
     ///
@@ -1134,12 +1806,15 @@ where
     /// - `is_collateralizable`: Flag for collateral usability.
     /// - `is_borrowable`: Flag for borrowability.
     /// - `isolation_borrow_enabled`: Flag for borrowing in isolation mode.
-    /// - `borrow_cap`: New borrow cap (zero for no cap).
+    /// - `borrow_cap`: New borrow cap (zero for no cap), in the current cap denomination.
     /// - `supply_cap`: New supply cap (zero for no cap).
+    /// - `max_liquidation_close_factor`: Share of the debt a single liquidation can repay in
+    ///   BPS; keeps the current value when omitted.
     ///
     /// # Errors
     /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool or config.
     /// - `ERROR_INVALID_LIQUIDATION_THRESHOLD`: If threshold is not greater than LTV.
+    /// - `ERROR_INVALID_LIQUIDATION_CLOSE_FACTOR`: If the close factor is zero or above 10000.
     pub fn edit_asset_config<
         Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
         Arg1: ProxyArg<BigUint<Env::Api>>,
@@ -1156,6 +1831,7 @@ where
         Arg12: ProxyArg<bool>,
         Arg13: ProxyArg<BigUint<Env::Api>>,
         Arg14: ProxyArg<BigUint<Env::Api>>,
+        Arg15: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
     >(
         self,
         asset: Arg0,
@@ -1173,6 +1849,7 @@ where
         isolation_borrow_enabled: Arg12,
         borrow_cap: Arg13,
         supply_cap: Arg14,
+        max_liquidation_close_factor: Arg15,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
@@ -1192,655 +1869,3164 @@ where
             .argument(&isolation_borrow_enabled)
             .argument(&borrow_cap)
             .argument(&supply_cap)
+            .argument(&max_liquidation_close_factor)
             .original_result()
     }
 
-    /// Sets the position limits for NFT accounts.
-    /// Configures maximum number of borrow and supply positions per NFT.
-    ///
-    /// **Purpose**: Controls the maximum number of positions an NFT can hold to optimize
-    /// gas costs during liquidations and prevent excessive complexity in position management.
+    /// Sets the borrow cap of an asset together with the unit it is expressed in.
     ///
-    /// **Gas Optimization**: By limiting positions per NFT, liquidation operations remain
-    /// within reasonable gas limits, preventing failed liquidations due to gas constraints.
+    /// **Purpose**: A cap in token units drifts in value as the asset's price moves.
+    /// Denominating it in EGLD keeps the cap value-stable: new borrows are checked against
+    /// the EGLD value of the market's outstanding debt at the current price.
     ///
-    /// **Default Configuration**: 10 borrow positions + 10 supply positions = 20 total positions
+    /// **Default Configuration**: token units, as set by `createLiquidityPool`
     ///
     /// # Arguments
-    /// - `max_borrow_positions`: Maximum number of borrow positions per NFT
-    /// - `max_supply_positions`: Maximum number of supply positions per NFT
+    /// - `asset`: Token identifier (EGLD or ESDT).
+    /// - `borrow_cap`: New borrow cap, in asset decimals for `Token` or EGLD WAD for `Egld`;
+    ///   `0` removes the cap
+    /// - `denomination`: Unit the cap is expressed in
     ///
-    /// # Security
-    /// - Only contract owner can modify position limits
-    /// - Changes affect new positions only, existing positions remain valid
-    /// - Limits are enforced in supply and borrow operations
-    pub fn set_position_limits<
-        Arg0: ProxyArg<u8>,
-        Arg1: ProxyArg<u8>,
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool or config.
+    pub fn set_borrow_cap<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<common_structs::CapDenomination>,
     >(
         self,
-        max_borrow_positions: Arg0,
-        max_supply_positions: Arg1,
+        asset: Arg0,
+        borrow_cap: Arg1,
+        denomination: Arg2,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setPositionLimits")
-            .argument(&max_borrow_positions)
-            .argument(&max_supply_positions)
+            .raw_call("setBorrowCap")
+            .argument(&asset)
+            .argument(&borrow_cap)
+            .argument(&denomination)
             .original_result()
     }
 
-    /// Disables the oracle for a token.
-    /// Prevents the token from being used as a price feed.
+    /// Sets the dust threshold under which leftover debt is written off on repay.
+    ///
+    /// **Purpose**: A near-full repayment can leave a few wei of debt that keeps the
+    /// borrow position, and the account NFT, alive. Remainders worth less than this
+    /// threshold are absorbed by the market, so a single repay fully closes the position.
+    ///
+    /// **Default Configuration**: `0`, dust is never written off
     ///
     /// # Arguments
-    /// - `token_id`: Token identifier (EGLD or ESDT).
+    /// - `asset`: Token identifier (EGLD or ESDT).
+    /// - `dust_threshold`: EGLD value of the largest absorbed remainder (WAD); `0` disables it
     ///
     /// # Errors
-    /// - `ERROR_ORACLE_TOKEN_NOT_FOUND`: If oracle exists for the token.
-    pub fn disable_token_oracle<
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool.
+    pub fn set_dust_threshold<
         Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
-        token_id: Arg0,
+        asset: Arg0,
+        dust_threshold: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("disableTokenOracle")
-            .argument(&token_id)
+            .raw_call("setDustThreshold")
+            .argument(&asset)
+            .argument(&dust_threshold)
             .original_result()
     }
 
-    /// Get the set of allowed pools
-    /// This storage mapper holds the addresses of pools that are allowed to participate in the lending protocol.
-    pub fn pools(
+    /// Sets the liquidation haircut of a collateral asset.
+    ///
+    /// **Purpose**: The mark price of thinly-traded collateral overstates what a liquidator
+    /// can sell it for. The haircut discounts the asset's value in the liquidation collateral,
+    /// and so in the health factor, to account for that slippage. Borrow-side LTV math keeps
+    /// the full value.
+    ///
+    /// **Default Configuration**: `0`, collateral is valued at its mark price
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT).
+    /// - `haircut_bps`: Discount applied to the collateral value (BPS); `0` disables it
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool.
+    /// - `ERROR_INVALID_LIQUIDATION_HAIRCUT`: If `haircut_bps` is 10000 or more.
+    pub fn set_liquidation_haircut<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        asset: Arg0,
+        haircut_bps: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getPools")
+            .raw_call("setLiquidationHaircut")
+            .argument(&asset)
+            .argument(&haircut_bps)
             .original_result()
     }
 
-    /// Get the account token
-    /// This storage mapper holds the logic of the account token, which is a non-fungible token (NFT).
-    pub fn account(
+    /// Sets the position limits for NFT accounts.
+    /// Configures maximum number of borrow and supply positions per NFT.
+    ///
+    /// **Purpose**: Controls the maximum number of positions an NFT can hold to optimize
+    /// gas costs during liquidations and prevent excessive complexity in position management.
+    ///
+    /// **Gas Optimization**: By limiting positions per NFT, liquidation operations remain
+    /// within reasonable gas limits, preventing failed liquidations due to gas constraints.
+    ///
+    /// **Default Configuration**: 10 borrow positions + 10 supply positions = 20 total positions
+    ///
+    /// # Arguments
+    /// - `max_borrow_positions`: Maximum number of borrow positions per NFT
+    /// - `max_supply_positions`: Maximum number of supply positions per NFT
+    ///
+    /// # Security
+    /// - Only contract owner can modify position limits
+    /// - Changes affect new positions only, existing positions remain valid
+    /// - Limits are enforced in supply and borrow operations
+    pub fn set_position_limits<
+        Arg0: ProxyArg<u8>,
+        Arg1: ProxyArg<u8>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenIdentifier<Env::Api>> {
+        max_borrow_positions: Arg0,
+        max_supply_positions: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAccount")
+            .raw_call("setPositionLimits")
+            .argument(&max_borrow_positions)
+            .argument(&max_supply_positions)
             .original_result()
     }
 
-    /// Get the account nonce
-    /// This storage mapper holds the nonce of the account, which is a non-fungible token (NFT).
-    pub fn account_nonce(
+    /// Sets how many account NFTs a single batch strategy call can operate on.
+    ///
+    /// **Purpose**: Lets power users managing several accounts top them up together in one
+    /// atomic call, while bounding the gas a batch can consume.
+    ///
+    /// **Default Configuration**: 0 (batch strategies are disabled)
+    ///
+    /// # Arguments
+    /// - `max_accounts`: Maximum number of account NFTs per batch; `0` disables batching
+    ///
+    /// # Errors
+    /// - `ERROR_INVALID_MAX_BATCH_ACCOUNTS`: If `max_accounts` exceeds the maximum
+    pub fn set_max_batch_accounts<
+        Arg0: ProxyArg<usize>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        max_accounts: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAccountNonce")
+            .raw_call("setMaxBatchAccounts")
+            .argument(&max_accounts)
             .original_result()
     }
 
-    /// Get the account positions
-    /// This storage mapper holds a list of account positions as a set. A position represents a nonce of an account (NFT nonce).
-    pub fn accounts(
+    /// Sets the share of the liquidation bonus returned to the liquidated account.
+    ///
+    /// **Purpose**: Softens the liquidation penalty by leaving a fraction of the
+    /// seized-beyond-debt collateral in the borrower's deposit position instead of
+    /// transferring it to the liquidator.
+    ///
+    /// **Default Configuration**: 0 (the liquidator keeps the full bonus)
+    ///
+    /// # Arguments
+    /// - `rebate_bps`: Share of the bonus portion credited back, in basis points
+    ///
+    /// # Errors
+    /// - `ERROR_INVALID_LIQUIDATEE_REBATE`: If `rebate_bps` exceeds 10000
+    pub fn set_liquidatee_rebate<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        rebate_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAccounts")
+            .raw_call("setLiquidateeRebate")
+            .argument(&rebate_bps)
             .original_result()
     }
 
-    /// Get the account attributes
-    /// This storage mapper maps each minted NFT to account attributes, useful for retrieving attributes without having the NFT in hand.
-    pub fn account_attributes<
+    /// Marks an account as protocol-owned, exempting it from per-account caps.
+    ///
+    /// **Purpose**: Lets accounts held by the protocol itself (e.g. for revenue
+    /// reinvestment) open as many positions as needed without being blocked by
+    /// the limits meant to keep user liquidations within gas bounds. The account
+    /// also skips its borrow allowance and the protocol-wide maximum position value.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: Account NFT nonce to whitelist
+    ///
+    /// # Errors
+    /// - `ERROR_ACCOUNT_NOT_IN_THE_MARKET`: If the account does not exist
+    pub fn add_protocol_owned_account<
         Arg0: ProxyArg<u64>,
     >(
         self,
-        nonce: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common_structs::AccountAttributes<Env::Api>> {
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAccountAttributes")
-            .argument(&nonce)
+            .raw_call("addProtocolOwnedAccount")
+            .argument(&account_nonce)
             .original_result()
     }
 
-    /// Get the deposit positions
-    /// This storage mapper maps each deposit position to an account nonce, holding a list of assets and their corresponding structs.
-    pub fn positions<
+    /// Removes an account from the protocol-owned whitelist.
+    /// Position limits and per-account value caps apply again to any new positions it opens.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: Account NFT nonce to remove from the whitelist
+    pub fn remove_protocol_owned_account<
         Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<common_structs::AccountPositionType>,
     >(
         self,
-        nonce: Arg0,
-        position_type: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, common_structs::AccountPosition<Env::Api>>>> {
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getPositions")
-            .argument(&nonce)
-            .argument(&position_type)
+            .raw_call("removeProtocolOwnedAccount")
+            .argument(&account_nonce)
             .original_result()
     }
 
-    /// Get the liquidity pool template address
-    /// This storage mapper holds the address of the liquidity pool template, used to create new liquidity pools.
-    pub fn liq_pool_template_address(
+    /// Adds or removes addresses from the borrow cap exemption whitelist.
+    ///
+    /// **Purpose**: Lets protocol-operated borrowers (e.g. the treasury or a market
+    /// maker providing protocol-owned liquidity) borrow beyond the asset-wide borrow
+    /// caps. Account-level borrow cap overrides still apply to their accounts.
+    ///
+    /// # Arguments
+    /// - `exempt`: `true` to whitelist the addresses, `false` to remove them
+    /// - `addresses`: Borrower addresses to update
+    pub fn set_borrow_cap_exempt_addresses<
+        Arg0: ProxyArg<bool>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        exempt: Arg0,
+        addresses: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getLiqPoolTemplateAddress")
+            .raw_call("setBorrowCapExemptAddresses")
+            .argument(&exempt)
+            .argument(&addresses)
             .original_result()
     }
 
-    /// Get the accumulator address
-    /// This storage mapper holds the address of the accumulator, used to claim revenue from the liquidity pools.
-    pub fn accumulator_address(
+    /// Sets the minimum health factor required after leveraged strategy operations.
+    ///
+    /// **Purpose**: Makes `multiply`, `swapDebt` and `swapCollateral` leave a larger
+    /// buffer above the liquidation threshold than regular borrows and withdrawals,
+    /// reducing the risk of a position being liquidatable right after leveraging.
+    ///
+    /// **Default Configuration**: unset (strategies use the regular 1.0 threshold)
+    ///
+    /// # Arguments
+    /// - `min_hf_ray`: Minimum health factor in RAY precision (e.g. 1.1 = 1.1e27)
+    ///
+    /// # Errors
+    /// - `ERROR_INVALID_MIN_STRATEGY_HEALTH_FACTOR`: If `min_hf_ray` is below 1.0
+    pub fn set_min_strategy_health_factor<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        min_hf_ray: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAccumulatorAddress")
+            .raw_call("setMinStrategyHealthFactor")
+            .argument(&min_hf_ray)
             .original_result()
     }
 
-    /// Get the pools map
-    /// This storage mapper holds a map of pools, used to get the address of a pool given a token ID.
-    pub fn pools_map<
-        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    /// Sets the origination fee charged when opening leverage with `multiply`.
+    ///
+    /// **Purpose**: Prices leverage as a premium feature. The fee is taken from the
+    /// flash-loaned debt on top of the market's flash loan fee and added to the debt
+    /// market's protocol revenue.
+    ///
+    /// **Default Configuration**: 0 (only the flash loan fee applies)
+    ///
+    /// # Arguments
+    /// - `fee_bps`: Origination fee in basis points of the flash-loaned debt
+    ///
+    /// # Errors
+    /// - `ERROR_INVALID_MULTIPLY_ORIGINATION_FEE`: If `fee_bps` is not below 10000
+    pub fn set_multiply_origination_fee<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
-        asset: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        fee_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getPoolAddress")
-            .argument(&asset)
+            .raw_call("setMultiplyOriginationFee")
+            .argument(&fee_bps)
             .original_result()
     }
 
-    /// Get the price aggregator address
-    /// This storage mapper holds the address of the price aggregator, used to get the price of a token in USD.
-    pub fn price_aggregator_address(
+    /// Sets the share of each flash loan fee routed to the accumulator.
+    ///
+    /// **Purpose**: Lets flash loan revenue flow to the protocol fee sink like the
+    /// revenue collected through `claimRevenue`. The share is sent to the accumulator
+    /// in the same transaction as the flash loan; the remainder stays as pool revenue.
+    ///
+    /// **Default Configuration**: 0 (the whole fee stays in the pool)
+    ///
+    /// # Arguments
+    /// - `share_bps`: Share of the flash loan fee in basis points
+    ///
+    /// # Errors
+    /// - `ERROR_INVALID_FLASH_LOAN_PROTOCOL_SHARE`: If `share_bps` exceeds 10000
+    pub fn set_flash_loan_protocol_share<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        share_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getPriceAggregatorAddress")
+            .raw_call("setFlashLoanProtocolShare")
+            .argument(&share_bps)
             .original_result()
     }
 
-    /// Get the safe price view address
-    /// This storage mapper holds the address of the safe price view, used to get the price of a token out of the DEX pair.
-    pub fn safe_price_view(
+    /// Enables or disables the creation of new debt across every market.
+    ///
+    /// **Purpose**: Acts as a global kill-switch for a fast, coordinated incident
+    /// response. While disabled, `borrow`, `multiply` and `flashLoan` revert in every
+    /// market; supplies, repayments, withdrawals and liquidations keep working so
+    /// users can still reduce their risk.
+    ///
+    /// **Default Configuration**: enabled
+    ///
+    /// # Arguments
+    /// - `enabled`: `false` blocks new debt, `true` allows it again
+    pub fn set_debt_creation_enabled<
+        Arg0: ProxyArg<bool>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getSafePriceAddress")
+            .raw_call("setDebtCreationEnabled")
+            .argument(&enabled)
             .original_result()
     }
 
-    /// Get the swap router address
-    /// Configures the external router used for token conversions in strategies.
+    /// Pauses new supplies and borrows of a single market.
     ///
-    /// Returns
-    /// - `ManagedAddress`: Router smart contract address
-    pub fn swap_router(
+    /// **Purpose**: Lets risk managers freeze one risky asset without pausing the whole
+    /// controller. While paused, supplying or borrowing the asset reverts; repayments,
+    /// withdrawals and liquidations keep working so users can still exit the market.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market to pause
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool
+    pub fn pause_market<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getSwapRouterAddress")
+            .raw_call("pauseMarket")
+            .argument(&asset)
             .original_result()
     }
 
-    /// Get the asset config
-    /// This storage mapper holds the configuration of an asset, used to retrieve the config of an asset.
-    pub fn asset_config<
+    /// Resumes new supplies and borrows of a market paused with `pauseMarket`.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market to unpause
+    pub fn unpause_market<
         Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
         self,
         asset: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common_structs::AssetConfig<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAssetConfig")
+            .raw_call("unpauseMarket")
             .argument(&asset)
             .original_result()
     }
 
-    /// Get the last e-mode category ID
-    /// This storage mapper holds the ID of the last e-mode category, used to retrieve the last e-mode category.
-    pub fn last_e_mode_category_id(
+    /// Enables or disables new supplies of a single market.
+    ///
+    /// **Purpose**: Lets governance wind a market down without touching its
+    /// collateral eligibility. While disabled, supplying the asset reverts; existing
+    /// deposits keep backing their borrows and can still be withdrawn or liquidated.
+    ///
+    /// **Default Configuration**: enabled
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market
+    /// - `enabled`: `false` blocks new supplies, `true` allows them again
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool
+    pub fn set_new_supply_enabled<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<bool>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u8> {
+        asset: Arg0,
+        enabled: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("lastEModeCategoryId")
+            .raw_call("setNewSupplyEnabled")
+            .argument(&asset)
+            .argument(&enabled)
             .original_result()
     }
 
-    /// Get all e-mode categories
-    /// This storage mapper holds a map of e-mode categories, used to group assets into categories with different risk parameters.
-    pub fn e_mode_categories(
+    /// Enables or disables new borrows of a single market.
+    ///
+    /// **Purpose**: Stops new debt in one asset independently of its borrowability
+    /// flag and of new supplies. While disabled, borrowing the asset reverts; existing
+    /// debt keeps accruing and can still be repaid or liquidated.
+    ///
+    /// **Default Configuration**: enabled
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market
+    /// - `enabled`: `false` blocks new borrows, `true` allows them again
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool
+    pub fn set_new_borrow_enabled<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<bool>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u8, common_structs::EModeCategory<Env::Api>>>> {
+        asset: Arg0,
+        enabled: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getEModes")
+            .raw_call("setNewBorrowEnabled")
+            .argument(&asset)
+            .argument(&enabled)
             .original_result()
     }
 
-    /// Get the e-mode categories for a given asset
-    /// This storage mapper holds a set of e-mode categories for a given asset. One asset can have multiple e-mode categories.
-    pub fn asset_e_modes<
+    /// Chooses whether repayments of a market reduce principal or accrued interest first.
+    ///
+    /// **Purpose**: Sets how a partial repayment is split between the principal and the
+    /// accrued interest of a debt, as reported by `getPositionAccruedInterest`. The
+    /// outstanding debt itself is the same under both orders.
+    ///
+    /// **Default Configuration**: interest first
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market
+    /// - `enabled`: `true` applies repayments to principal first, `false` to interest first
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool
+    pub fn set_repay_principal_first<
         Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<bool>,
     >(
         self,
         asset: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u8>> {
+        enabled: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAssetEModes")
+            .raw_call("setRepayPrincipalFirst")
             .argument(&asset)
+            .argument(&enabled)
             .original_result()
     }
 
-    /// Get all assets for a given e-mode category
-    /// This storage mapper holds a map of assets for a given e-mode category, used to get the config for a given asset in a given e-mode category.
-    pub fn e_mode_assets<
-        Arg0: ProxyArg<u8>,
+    /// Enables or disables the index sync performed by health factor views.
+    ///
+    /// **Purpose**: Health factor views accrue interest up to the current timestamp
+    /// before valuing positions. Disabling the sync makes them report the value as of
+    /// each market's last on-chain update, which is cheaper for indexers that refresh
+    /// markets themselves. `getHealthFactorSynced` always syncs.
+    ///
+    /// **Default Configuration**: enabled
+    ///
+    /// # Arguments
+    /// - `enabled`: `false` reads indexes as of the last sync, `true` accrues them first
+    pub fn set_view_index_sync_enabled<
+        Arg0: ProxyArg<bool>,
     >(
         self,
-        id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, common_structs::EModeAssetConfig>>> {
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getEModesAssets")
-            .argument(&id)
+            .raw_call("setViewIndexSyncEnabled")
+            .argument(&enabled)
             .original_result()
     }
 
-    /// Get the debt in USD for isolated assets
-    /// This storage mapper holds the debt in USD for isolated assets.
-    pub fn isolated_asset_debt_usd<
+    /// Sets the minimum share of a market's liquidity that must stay unborrowed.
+    ///
+    /// **Purpose**: Keeps a liquidity buffer for withdrawals by rejecting borrows that
+    /// would push utilization above `(10000 - min_reserve_ratio_bps) / 10000`. This cap
+    /// is independent of the optimal utilization used by the rate curve.
+    ///
+    /// **Default Configuration**: 0 (borrows may use all available liquidity)
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market
+    /// - `min_reserve_ratio_bps`: Reserve ratio in basis points
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool
+    /// - `ERROR_INVALID_MIN_RESERVE_RATIO`: If `min_reserve_ratio_bps` exceeds 10000
+    pub fn set_min_reserve_ratio<
         Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
         asset: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        min_reserve_ratio_bps: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getIsolatedAssetDebtUsd")
+            .raw_call("setMinReserveRatio")
             .argument(&asset)
+            .argument(&min_reserve_ratio_bps)
             .original_result()
     }
 
-    /// Get the token oracle
-    /// This storage mapper holds the oracle of a token, used to get the price of a token.
-    pub fn token_oracle<
-        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    /// Sets the maximum collateral price drop tolerated by liquidations in a single update.
+    ///
+    /// **Purpose**: Protects borrowers from liquidations triggered by a suspicious price
+    /// crash. When a collateral price fell by more than this share since the last check,
+    /// the liquidation is deferred to the next block instead of executing.
+    ///
+    /// **Default Configuration**: 0 (no regression check)
+    ///
+    /// # Arguments
+    /// - `max_drop_bps`: Maximum price drop in basis points
+    ///
+    /// # Errors
+    /// - `ERROR_INVALID_MAX_LIQUIDATION_PRICE_DROP`: If `max_drop_bps` exceeds 10000
+    pub fn set_max_liquidation_price_drop<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
-        asset: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common_structs::OracleProvider<Env::Api>> {
+        max_drop_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getTokenOracle")
-            .argument(&asset)
+            .raw_call("setMaxLiquidationPriceDrop")
+            .argument(&max_drop_bps)
             .original_result()
     }
 
-    /// Reentrancy guard flag for flash loans
-    /// Indicates if a flash loan is currently in progress to block nested calls.
+    /// Sets the collateral value credited for claiming after a debt-clearing liquidation.
     ///
-    /// Returns
-    /// - `bool`: True if flash loan ongoing
-    pub fn flash_loan_ongoing(
+    /// **Purpose**: Avoids stranding dust collateral in accounts whose debt was fully
+    /// repaid by a liquidation. When no borrow remains and the leftover collateral is
+    /// worth at most this value, it is withdrawn from the pools and credited to the
+    /// account, to be claimed by whoever presents the account NFT next.
+    ///
+    /// **Default Configuration**: 0 (dust collateral stays in the account)
+    ///
+    /// # Arguments
+    /// - `threshold_usd_wad`: Maximum returned collateral value in USD, WAD precision
+    pub fn set_liquidation_dust_threshold<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        threshold_usd_wad: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("isFlashLoanOngoing")
+            .raw_call("setLiquidationDustThreshold")
+            .argument(&threshold_usd_wad)
             .original_result()
     }
 
-    /// Get the position limits configuration
-    /// This storage mapper holds the maximum number of borrow and supply positions per NFT
-    /// Used to optimize gas costs during liquidations and prevent excessive position complexity
-    pub fn position_limits(
+    /// Sets the borrow allowance of an account, acting as a credit line ceiling.
+    ///
+    /// **Purpose**: Lets governance cap the total debt of an institutional account
+    /// independently of its collateral. Borrows that would push the account's total
+    /// debt above the allowance are rejected, even when the collateral would allow them.
+    ///
+    /// **Default Configuration**: Unlimited (only collateral limits borrowing)
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account
+    /// - `egld_cap`: Maximum total debt in EGLD, WAD precision; `0` removes the allowance
+    ///
+    /// # Errors
+    /// - `ERROR_ACCOUNT_NOT_IN_THE_MARKET`: If the account is not active
+    pub fn set_account_borrow_allowance<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common_structs::PositionLimits> {
+        account_nonce: Arg0,
+        egld_cap: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getPositionLimits")
+            .raw_call("setAccountBorrowAllowance")
+            .argument(&account_nonce)
+            .argument(&egld_cap)
             .original_result()
     }
 
-    /// Estimates liquidation outcomes for a proposed set of debt payments.
+    /// Overrides the borrow cap of an asset for a single account.
     ///
-    /// Purpose: Simulate liquidation to preview seized collateral, protocol fees,
-    /// refunds, and applied bonus, without mutating state.
+    /// **Purpose**: Lets governance grant an institutional account more or less exposure
+    /// to an asset than the global cap allows. While an override exists, the account's own
+    /// debt in the asset is checked against it instead of the asset-wide borrow cap, so a
+    /// treasury account can borrow large amounts without raising the cap for everyone.
     ///
-    /// Arguments
-    /// - `account_nonce`: Account NFT nonce to simulate
-    /// - `debt_payments`: Candidate payments to apply in liquidation
+    /// **Default Configuration**: No override (the asset-wide borrow cap applies)
     ///
-    /// Returns
-    /// - `LiquidationEstimate` with seized collaterals, protocol fees, refunds,
-    ///   max repay amount (WAD), and bonus rate (BPS)
-    pub fn liquidation_estimations<
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account
+    /// - `asset`: Token identifier (EGLD or ESDT)
+    /// - `borrow_cap`: Maximum debt of the account in the asset, in asset decimals;
+    ///   `0` removes the override
+    ///
+    /// # Errors
+    /// - `ERROR_ACCOUNT_NOT_IN_THE_MARKET`: If the account is not active
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool
+    pub fn set_account_borrow_cap<
         Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<ManagedVec<Env::Api, EgldOrEsdtTokenPayment<Env::Api>>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
         account_nonce: Arg0,
-        debt_payments: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common_structs::LiquidationEstimate<Env::Api>> {
+        asset: Arg1,
+        borrow_cap: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("liquidationEstimations")
+            .raw_call("setAccountBorrowCap")
             .argument(&account_nonce)
-            .argument(&debt_payments)
+            .argument(&asset)
+            .argument(&borrow_cap)
             .original_result()
     }
 
-    /// Retrieves updated market indexes and price information for multiple assets.
+    /// Sets the maximum value a single account can reach on either side of its position.
     ///
-    /// Purpose: Provide monitoring data (supply/borrow indices and prices) for UIs.
-    /// Uses simulated index updates and price components with tolerances.
+    /// **Purpose**: Limits concentration risk by bounding how large any account can grow.
+    /// Deposits that would push the account's total collateral above the cap, and borrows
+    /// that would push its total debt above the cap, are rejected.
     ///
-    /// Arguments
-    /// - `assets`: Asset identifiers to fetch
+    /// **Default Configuration**: Unlimited
     ///
-    /// Returns
-    /// - `ManagedVec<MarketIndexView>` entries with indices and price data
-    pub fn all_market_indexes<
-        Arg0: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    /// # Arguments
+    /// - `egld_cap`: Maximum total collateral and total debt in EGLD, WAD precision;
+    ///   `0` removes the cap
+    pub fn set_max_position_value<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
-        assets: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, common_structs::MarketIndexView<Env::Api>>> {
+        egld_cap: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAllMarketIndexes")
-            .argument(&assets)
+            .raw_call("setMaxPositionValue")
+            .argument(&egld_cap)
             .original_result()
     }
 
-    /// Retrieves market indexes and price information plus aggregator staleness.
+    /// Sets the minimum value of collateral a single liquidation must seize.
     ///
-    /// Purpose: Same monitoring data as `getAllMarketIndexes`, extended with the
-    /// aggregator feed timestamp and staleness flag for consumers that need them.
+    /// **Purpose**: Stops griefing liquidations that seize economically meaningless
+    /// amounts of collateral. A liquidation whose seized collateral is worth less than
+    /// the minimum, in EGLD, reverts.
     ///
-    /// Arguments
-    /// - `assets`: Asset identifiers to fetch
+    /// **Default Configuration**: 0 (no minimum)
+    ///
+    /// # Arguments
+    /// - `min_egld_value`: Minimum EGLD value of the seized collateral, WAD precision;
+    ///   `0` removes the minimum
+    pub fn set_min_seized_collateral_egld<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        min_egld_value: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMinSeizedCollateralEgld")
+            .argument(&min_egld_value)
+            .original_result()
+    }
+
+    /// Sets the grace period that follows a liquidation.
+    ///
+    /// **Purpose**: Prevents back-to-back liquidations of the same account. After a
+    /// liquidation, the account cannot be liquidated again until the grace period has
+    /// elapsed, even if it is still unhealthy, giving its owner time to repay the
+    /// remaining debt. Repayments and other operations stay available during the period.
+    ///
+    /// **Default Configuration**: 0 (accounts can be liquidated again immediately)
+    ///
+    /// # Arguments
+    /// - `grace`: Length of the grace period in seconds; `0` disables it
+    pub fn set_post_liquidation_grace<
+        Arg0: ProxyArg<DurationSeconds>,
+    >(
+        self,
+        grace: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPostLiquidationGrace")
+            .argument(&grace)
+            .original_result()
+    }
+
+    /// Sets the grace period that follows a liquidation threshold reduction.
+    ///
+    /// **Purpose**: Tightening a liquidation threshold through `updateAccountThreshold` can
+    /// briefly push accounts below a health factor of 1, for instance while market indexes
+    /// lag after an oracle recovery. Accounts whose threshold was lowered cannot be
+    /// liquidated until the grace period has elapsed, giving owners time to react.
+    ///
+    /// **Default Configuration**: 0 (lowered thresholds apply to liquidations immediately)
+    ///
+    /// # Arguments
+    /// - `grace`: Length of the grace period in seconds; `0` disables it
+    pub fn set_threshold_update_grace<
+        Arg0: ProxyArg<DurationSeconds>,
+    >(
+        self,
+        grace: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setThresholdUpdateGrace")
+            .argument(&grace)
+            .original_result()
+    }
+
+    /// Sets how long the last known price of a delisted asset stays usable.
+    ///
+    /// **Purpose**: If an asset's oracle is removed while accounts still hold it, every
+    /// price read of that asset reverts and the accounts can neither be liquidated nor
+    /// withdrawn. With a fallback age set, the price last recorded from the live oracle
+    /// (`last_token_price`, refreshed by `updateIndexes`) is used instead while it is
+    /// younger than the age.
+    ///
+    /// **Default Configuration**: 0 (price reads of delisted assets revert)
+    ///
+    /// # Arguments
+    /// - `max_age`: Maximum age of the last known price in seconds; `0` disables the fallback
+    pub fn set_delisted_price_max_age<
+        Arg0: ProxyArg<DurationSeconds>,
+    >(
+        self,
+        max_age: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDelistedPriceMaxAge")
+            .argument(&max_age)
+            .original_result()
+    }
+
+    /// Temporarily extends the staleness tolerance of an asset's price feeds.
+    ///
+    /// **Purpose**: During an oracle outage, keeps the asset priceable so the protocol stays
+    /// operational without editing the oracle configuration. `extra_seconds` is added to the
+    /// configured `max_price_stale_seconds` until `expiry_timestamp`, after which the
+    /// configured tolerance applies again automatically. The EGLD reference price bound
+    /// (`egld_max_price_stale_seconds`) is not affected.
+    ///
+    /// **Default Configuration**: None (the configured tolerance applies)
+    ///
+    /// # Arguments
+    /// - `token`: Token identifier (EGLD or ESDT) of the priced asset
+    /// - `extra_seconds`: Seconds added to the configured tolerance; `0` removes the override
+    /// - `expiry_timestamp`: Block timestamp in seconds from which the override stops applying
+    ///
+    /// # Errors
+    /// - `ERROR_ORACLE_TOKEN_NOT_FOUND`: If the asset has no oracle configured
+    /// - `ERROR_INVALID_PRICE_STALENESS_OVERRIDE`: If the expiry is not in the future
+    pub fn set_price_staleness_override<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<DurationSeconds>,
+        Arg2: ProxyArg<TimestampSeconds>,
+    >(
+        self,
+        token: Arg0,
+        extra_seconds: Arg1,
+        expiry_timestamp: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPriceStalenessOverride")
+            .argument(&token)
+            .argument(&extra_seconds)
+            .argument(&expiry_timestamp)
+            .original_result()
+    }
+
+    /// Sets the minimum time a deposit must stay supplied to keep its rewards.
+    ///
+    /// **Purpose**: Deters supplying briefly to capture reward distributions and leaving
+    /// right after. A withdrawal made before the duration elapses since the last supply
+    /// forfeits the rewards the deposit accrued during that period to the protocol.
+    /// Principal and interest are never forfeited.
+    ///
+    /// **Default Configuration**: 0 (rewards are never forfeited)
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market
+    /// - `min_duration`: Minimum supply duration in seconds; `0` disables the rule
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool
+    pub fn set_min_supply_duration<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<DurationSeconds>,
+    >(
+        self,
+        asset: Arg0,
+        min_duration: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMinSupplyDuration")
+            .argument(&asset)
+            .argument(&min_duration)
+            .original_result()
+    }
+
+    /// Sets how many recent operations a market keeps in its on-chain activity buffer.
+    ///
+    /// **Purpose**: Lets light clients read recent supply, borrow, repay and withdraw
+    /// activity of a market without an indexer. Once full, each new operation
+    /// overwrites the oldest entry, keeping storage bounded. Changing the capacity
+    /// discards the activity recorded so far.
+    ///
+    /// **Default Configuration**: 0 (no activity is recorded)
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market
+    /// - `capacity`: Number of retained entries; `0` disables recording
+    ///
+    /// # Errors
+    /// - `ERROR_ASSET_NOT_SUPPORTED`: If the asset has no pool
+    /// - `ERROR_INVALID_MARKET_ACTIVITY_CAPACITY`: If `capacity` exceeds the maximum
+    pub fn set_market_activity_capacity<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        asset: Arg0,
+        capacity: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMarketActivityCapacity")
+            .argument(&asset)
+            .argument(&capacity)
+            .original_result()
+    }
+
+    /// Sets the minimum time between two index snapshots of a market.
+    ///
+    /// **Purpose**: Enables an index history used to reconstruct the interest accrued
+    /// by a position since a given timestamp, e.g. for tax and accounting exports.
+    /// Snapshots are taken by `updateIndexes`, at most once per interval and market.
+    /// Disabling snapshots keeps the history recorded so far.
+    ///
+    /// **Default Configuration**: 0 (no snapshots are recorded)
+    ///
+    /// # Arguments
+    /// - `interval`: Minimum snapshot spacing in seconds; `0` disables snapshots
+    pub fn set_index_snapshot_interval<
+        Arg0: ProxyArg<DurationSeconds>,
+    >(
+        self,
+        interval: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setIndexSnapshotInterval")
+            .argument(&interval)
+            .original_result()
+    }
+
+    /// Sets the maximum age of the EGLD reference price.
+    ///
+    /// **Purpose**: Every EGLD-denominated valuation depends on the EGLD/USD reference
+    /// price loaded into the cache. This bound applies to that feed only, on top of the
+    /// per-asset `max_price_stale_seconds` of the EGLD oracle, so that any value-dependent
+    /// operation reverts once the reference price is older than allowed.
+    ///
+    /// **Default Configuration**: Unset (the EGLD oracle bound applies alone)
+    ///
+    /// # Arguments
+    /// - `max_seconds_stale`: Maximum accepted age of the EGLD/USD feed, in seconds
+    ///
+    /// # Errors
+    /// - `ERROR_INVALID_EGLD_MAX_PRICE_STALE_SECONDS`: If the bound is zero
+    pub fn set_egld_max_price_stale_seconds<
+        Arg0: ProxyArg<DurationSeconds>,
+    >(
+        self,
+        max_seconds_stale: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEgldMaxPriceStaleSeconds")
+            .argument(&max_seconds_stale)
+            .original_result()
+    }
+
+    /// Disables the oracle for a token.
+    /// Prevents the token from being used as a price feed.
+    ///
+    /// # Arguments
+    /// - `token_id`: Token identifier (EGLD or ESDT).
+    ///
+    /// # Errors
+    /// - `ERROR_ORACLE_TOKEN_NOT_FOUND`: If oracle exists for the token.
+    pub fn disable_token_oracle<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("disableTokenOracle")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Get the set of allowed pools
+    /// This storage mapper holds the addresses of pools that are allowed to participate in the lending protocol.
+    pub fn pools(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPools")
+            .original_result()
+    }
+
+    /// Get the account token
+    /// This storage mapper holds the logic of the account token, which is a non-fungible token (NFT).
+    pub fn account(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccount")
+            .original_result()
+    }
+
+    /// Get the account nonce
+    /// This storage mapper holds the nonce of the account, which is a non-fungible token (NFT).
+    pub fn account_nonce(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountNonce")
+            .original_result()
+    }
+
+    /// Get the account positions
+    /// This storage mapper holds a list of account positions as a set. A position represents a nonce of an account (NFT nonce).
+    pub fn accounts(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccounts")
+            .original_result()
+    }
+
+    /// Get the account attributes
+    /// This storage mapper maps each minted NFT to account attributes, useful for retrieving attributes without having the NFT in hand.
+    pub fn account_attributes<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common_structs::AccountAttributes<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountAttributes")
+            .argument(&nonce)
+            .original_result()
+    }
+
+    /// Get the deposit positions
+    /// This storage mapper maps each deposit position to an account nonce, holding a list of assets and their corresponding structs.
+    pub fn positions<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<common_structs::AccountPositionType>,
+    >(
+        self,
+        nonce: Arg0,
+        position_type: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, common_structs::AccountPosition<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPositions")
+            .argument(&nonce)
+            .argument(&position_type)
+            .original_result()
+    }
+
+    /// Get the liquidity pool template address
+    /// This storage mapper holds the address of the liquidity pool template, used to create new liquidity pools.
+    pub fn liq_pool_template_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiqPoolTemplateAddress")
+            .original_result()
+    }
+
+    /// Get the accumulator address
+    /// This storage mapper holds the address of the accumulator, used to claim revenue from the liquidity pools.
+    pub fn accumulator_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccumulatorAddress")
+            .original_result()
+    }
+
+    /// Get the pools map
+    /// This storage mapper holds a map of pools, used to get the address of a pool given a token ID.
+    pub fn pools_map<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPoolAddress")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the price aggregator address
+    /// This storage mapper holds the address of the price aggregator, used to get the price of a token in USD.
+    pub fn price_aggregator_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPriceAggregatorAddress")
+            .original_result()
+    }
+
+    /// Get the safe price view address
+    /// This storage mapper holds the address of the safe price view, used to get the price of a token out of the DEX pair.
+    pub fn safe_price_view(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSafePriceAddress")
+            .original_result()
+    }
+
+    /// Get the swap router address
+    /// Configures the external router used for token conversions in strategies.
+    ///
+    /// Returns
+    /// - `ManagedAddress`: Router smart contract address
+    pub fn swap_router(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSwapRouterAddress")
+            .original_result()
+    }
+
+    /// Get the default swap router steps used to convert one token into another
+    /// Strategies fall back to these steps when called with empty steps for the pair
+    pub fn default_swap_steps<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        from_token: Arg0,
+        to_token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, ManagedBuffer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDefaultSwapSteps")
+            .argument(&from_token)
+            .argument(&to_token)
+            .original_result()
+    }
+
+    /// Get the asset config
+    /// This storage mapper holds the configuration of an asset, used to retrieve the config of an asset.
+    pub fn asset_config<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common_structs::AssetConfig<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAssetConfig")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the last e-mode category ID
+    /// This storage mapper holds the ID of the last e-mode category, used to retrieve the last e-mode category.
+    pub fn last_e_mode_category_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u8> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("lastEModeCategoryId")
+            .original_result()
+    }
+
+    /// Get all e-mode categories
+    /// This storage mapper holds a map of e-mode categories, used to group assets into categories with different risk parameters.
+    pub fn e_mode_categories(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u8, common_structs::EModeCategory<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEModes")
+            .original_result()
+    }
+
+    /// Get the e-mode categories for a given asset
+    /// This storage mapper holds a set of e-mode categories for a given asset. One asset can have multiple e-mode categories.
+    pub fn asset_e_modes<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u8>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAssetEModes")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get all assets for a given e-mode category
+    /// This storage mapper holds a map of assets for a given e-mode category, used to get the config for a given asset in a given e-mode category.
+    pub fn e_mode_assets<
+        Arg0: ProxyArg<u8>,
+    >(
+        self,
+        id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, common_structs::EModeAssetConfig>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEModesAssets")
+            .argument(&id)
+            .original_result()
+    }
+
+    /// Get whether an e-mode collateral needs another eligible collateral to back a borrow
+    /// An empty mapper lets the asset back e-mode borrows on its own
+    pub fn e_mode_companion_collateral_required<
+        Arg0: ProxyArg<u8>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        id: Arg0,
+        asset: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isEModeCompanionCollateralRequired")
+            .argument(&id)
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the debt in USD for isolated assets
+    /// This storage mapper holds the debt in USD for isolated assets.
+    pub fn isolated_asset_debt_usd<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getIsolatedAssetDebtUsd")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the token oracle
+    /// This storage mapper holds the oracle of a token, used to get the price of a token.
+    pub fn token_oracle<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common_structs::OracleProvider<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTokenOracle")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the number of agreeing price sources required before a token price is accepted
+    /// An empty mapper, 0 or 1 disables the requirement
+    pub fn min_oracle_quorum<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u8> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTokenOracleQuorum")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the number of safe price rounds averaged by the `Twap` fallback of a token
+    /// An empty mapper averages `DEFAULT_TWAP_ROUNDS` rounds
+    pub fn twap_rounds<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u8> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTokenOracleTwapRounds")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Reentrancy guard flag for flash loans
+    /// Indicates if a flash loan is currently in progress to block nested calls.
+    ///
+    /// Returns
+    /// - `bool`: True if flash loan ongoing
+    pub fn flash_loan_ongoing(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isFlashLoanOngoing")
+            .original_result()
+    }
+
+    /// Get the position limits configuration
+    /// This storage mapper holds the maximum number of borrow and supply positions per NFT
+    /// Used to optimize gas costs during liquidations and prevent excessive position complexity
+    pub fn position_limits(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common_structs::PositionLimits> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPositionLimits")
+            .original_result()
+    }
+
+    /// Get the maximum number of account NFTs a batch strategy call can operate on
+    /// An empty mapper disables batch strategies
+    pub fn max_batch_accounts(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxBatchAccounts")
+            .original_result()
+    }
+
+    /// Get the share of the liquidation bonus credited back to the liquidated account
+    /// Expressed in basis points of the bonus portion of each seized collateral
+    /// An empty mapper means no rebate is applied
+    pub fn liquidatee_rebate_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidateeRebateBps")
+            .original_result()
+    }
+
+    /// Get the account nonces owned by the protocol itself
+    /// These accounts (e.g. revenue reinvestment) are exempt from position limits, borrow allowances
+    /// and the maximum position value
+    pub fn protocol_owned_accounts(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolOwnedAccounts")
+            .original_result()
+    }
+
+    /// Get the addresses exempt from asset-wide borrow caps
+    /// Meant for protocol-operated borrowers such as the treasury or market makers
+    pub fn borrow_cap_exempt_addresses(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowCapExemptAddresses")
+            .original_result()
+    }
+
+    /// Get the minimum health factor required after strategy operations (RAY)
+    /// Applies to multiply, swap debt and swap collateral to leave a buffer above liquidation
+    /// An empty mapper falls back to the regular 1.0 threshold
+    pub fn min_strategy_hf_ray(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinStrategyHealthFactor")
+            .original_result()
+    }
+
+    /// Get the origination fee charged when opening leverage with multiply
+    /// Expressed in basis points of the flash-loaned debt and routed to the debt market revenue
+    /// An empty mapper means no origination fee
+    pub fn multiply_origination_fee_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMultiplyOriginationFeeBps")
+            .original_result()
+    }
+
+    /// Get the share of each flash loan fee routed to the accumulator
+    /// Expressed in basis points of the fee, the remainder stays as pool revenue
+    /// An empty mapper keeps the whole fee in the pool
+    pub fn flash_loan_protocol_share_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFlashLoanProtocolShareBps")
+            .original_result()
+    }
+
+    /// Get whether the global kill-switch blocking new debt is engaged
+    /// Stored inverted so that debt creation stays enabled by default
+    pub fn debt_creation_disabled(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isDebtCreationDisabled")
+            .original_result()
+    }
+
+    /// Get whether a single market is paused for new supplies and borrows
+    /// Repayments, withdrawals and liquidations of the asset keep working while paused
+    pub fn market_paused<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isMarketPaused")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get whether new supplies of an asset are disabled
+    /// Existing deposits keep counting as collateral and can still be withdrawn
+    /// Stored inverted so that new supplies stay enabled by default
+    pub fn new_supply_disabled<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isNewSupplyDisabled")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get whether new borrows of an asset are disabled
+    /// Existing debt keeps accruing and can still be repaid or liquidated
+    /// Stored inverted so that new borrows stay enabled by default
+    pub fn new_borrow_disabled<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isNewBorrowDisabled")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get whether health factor views skip accruing interest up to the current timestamp
+    /// Stored inverted so that views stay exactly current by default
+    pub fn view_index_sync_disabled(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isViewIndexSyncDisabled")
+            .original_result()
+    }
+
+    /// Get the minimum reserve ratio of a market
+    /// Share of supplied liquidity, in basis points, that borrows can never consume
+    /// An empty mapper means no reserve requirement
+    pub fn min_reserve_ratio_bps<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinReserveRatioBps")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the maximum share of the debt in an asset a single liquidation can repay (BPS)
+    /// An empty mapper lets a single liquidation repay the full debt
+    pub fn max_liquidation_close_factor_bps<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxLiquidationCloseFactorBps")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the unit the borrow cap of an asset is expressed in
+    /// An empty mapper keeps the cap in token units
+    pub fn borrow_cap_denomination<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common_structs::CapDenomination> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowCapDenomination")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the EGLD value (WAD) under which debt left after a repay is written off
+    /// An empty mapper never writes off dust debt
+    pub fn dust_threshold_wad<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDustThreshold")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the discount applied to a collateral asset's value in liquidation math (BPS)
+    /// An empty mapper values the collateral at its mark price
+    pub fn liquidation_haircut_bps<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidationHaircut")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the governance borrow allowance of an account in EGLD (WAD)
+    /// Caps the total debt of the account regardless of its collateral; empty means unlimited
+    pub fn account_borrow_allowance<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountBorrowAllowance")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Get the governance borrow cap override of an account for an asset, in asset decimals
+    /// Replaces the asset-wide borrow cap for this account's debt in the asset; empty means no override
+    pub fn account_borrow_cap_override<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        account_nonce: Arg0,
+        asset: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountBorrowCapOverride")
+            .argument(&account_nonce)
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the maximum total collateral and total debt of a single account in EGLD (WAD)
+    /// Bounds how large any position can grow; empty means unlimited
+    pub fn max_position_value(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxPositionValue")
+            .original_result()
+    }
+
+    /// Get the minimum EGLD value (WAD) of collateral a single liquidation must seize
+    /// Rejects liquidations of economically meaningless amounts; empty disables the floor
+    pub fn min_seized_collateral_egld(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinSeizedCollateralEgld")
+            .original_result()
+    }
+
+    /// Get whether an account opted into explicit compounding of supply interest
+    pub fn auto_compound<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isAutoCompoundEnabled")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Get the tracked principal of a deposit in an auto-compounding account
+    /// Re-based to the current balance on every position update and compounding event
+    pub fn compounded_principal<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        account_nonce: Arg0,
+        asset: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCompoundedPrincipal")
+            .argument(&account_nonce)
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the cost basis of a position, used to report its accrued interest
+    /// Re-weighted on every increase of the position and cleared once it is closed
+    pub fn position_entry<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<common_structs::AccountPositionType>,
+        Arg2: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        account_nonce: Arg0,
+        position_type: Arg1,
+        asset: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common_structs::PositionEntry<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPositionEntry")
+            .argument(&account_nonce)
+            .argument(&position_type)
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get whether repayments of an asset reduce principal before accrued interest
+    /// Stored so that repayments apply to accrued interest first by default
+    pub fn repay_principal_first<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isRepayPrincipalFirst")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the maximum collateral price drop between two liquidation price checks (BPS)
+    /// A larger drop in a single update defers liquidations holding that collateral to the next block
+    /// An empty mapper disables the check
+    pub fn max_liquidation_price_drop_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxLiquidationPriceDropBps")
+            .original_result()
+    }
+
+    /// Get the reference price of a token for the liquidation price regression check (WAD)
+    /// Only written by the check itself, so no other endpoint can move it
+    pub fn liquidation_reference_price<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidationReferencePrice")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the last price of a token recorded from a live oracle by `updateIndexes` (WAD)
+    pub fn last_token_price<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastTokenPrice")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the block timestamp at which the last token price was recorded from a live oracle
+    pub fn last_token_price_timestamp<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TimestampSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastTokenPriceTimestamp")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the maximum age of the last token price used for assets removed from the oracle
+    /// Keeps accounts holding a delisted asset liquidatable and withdrawable; empty disables the fallback
+    pub fn delisted_price_max_age(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelistedPriceMaxAge")
+            .original_result()
+    }
+
+    /// Get the collateral value under which a debt-free account is emptied after liquidation (USD, WAD)
+    /// Remaining collateral of an account fully cleared of debt is credited for its NFT holder to claim
+    /// An empty mapper keeps the dust collateral in the account
+    pub fn liquidation_dust_threshold_usd(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidationDustThreshold")
+            .original_result()
+    }
+
+    /// Get the maximum age of the EGLD reference price
+    /// Applied on top of the EGLD oracle bound when stricter; empty keeps the oracle bound
+    pub fn egld_max_price_stale_seconds(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEgldMaxPriceStaleSeconds")
+            .original_result()
+    }
+
+    /// Get the temporary staleness tolerance extension of an asset's price feeds
+    /// Ignored once its expiry is reached; empty applies the configured tolerance
+    pub fn price_staleness_override<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common_structs::PriceStalenessOverride> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPriceStalenessOverride")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the number of recent activity entries retained for a market
+    /// An empty mapper disables activity recording for the market
+    pub fn market_activity_capacity<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMarketActivityCapacity")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the minimum time between two index snapshots of a market
+    /// An empty mapper disables index snapshots
+    pub fn index_snapshot_interval(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getIndexSnapshotInterval")
+            .original_result()
+    }
+
+    /// Get the minimum time a deposit must stay supplied to keep the rewards it accrued
+    /// Withdrawing earlier forfeits those rewards to the protocol; empty disables the rule
+    pub fn min_supply_duration<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinSupplyDuration")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the cumulative rewards distributed per scaled unit supplied to a market (RAY)
+    pub fn market_reward_index<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMarketRewardIndex")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the block timestamp of the last supply starting or extending the minimum supply period of a deposit
+    pub fn supply_timestamp<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        account_nonce: Arg0,
+        asset: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TimestampSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSupplyTimestamp")
+            .argument(&account_nonce)
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get whether a deposit is supply-only and excluded from collateral
+    /// Stored inverted so deposits count as collateral unless explicitly disabled
+    pub fn position_collateral_disabled<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        account_nonce: Arg0,
+        asset: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPositionCollateralDisabled")
+            .argument(&account_nonce)
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Get the period after a liquidation during which the account cannot be liquidated again
+    /// Gives the liquidatee time to repay the remaining debt; empty disables the grace period
+    pub fn post_liquidation_grace(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPostLiquidationGrace")
+            .original_result()
+    }
+
+    /// Get the block timestamp of the last liquidation of an account
+    pub fn last_liquidation_timestamp<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TimestampSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastLiquidationTimestamp")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Get the period after a liquidation threshold reduction during which affected accounts
+    /// cannot be liquidated; empty disables the grace period
+    pub fn threshold_update_grace(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getThresholdUpdateGrace")
+            .original_result()
+    }
+
+    /// Get the block timestamp until which an account cannot be liquidated
+    /// Set when `updateAccountThreshold` lowers one of the account's liquidation thresholds
+    pub fn liquidation_grace_until<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TimestampSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidationGraceUntil")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Get the last address that presented the account NFT to the protocol
+    pub fn account_holder<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountHolder")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Get the account NFT nonces last presented by an address
+    /// Reverse index of the account holder, kept in sync whenever the holder changes
+    pub fn user_accounts<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        holder: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserAccounts")
+            .argument(&holder)
+            .original_result()
+    }
+
+    /// Get the dust collateral credited to an account after a liquidation cleared all of its debt
+    /// Paid out to the next address presenting the account NFT
+    pub fn dust_collateral<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDustCollateral")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Estimates liquidation outcomes for a proposed set of debt payments.
+    ///
+    /// Purpose: Simulate liquidation to preview seized collateral, protocol fees,
+    /// refunds, and applied bonus, without mutating state. Also reports whether the
+    /// liquidation is profitable and how much of each debt token can be repaid, so a
+    /// bot can size its payment without re-deriving the bonus math.
+    ///
+    /// Arguments
+    /// - `account_nonce`: Account NFT nonce to simulate
+    /// - `debt_payments`: Candidate payments to apply in liquidation
+    ///
+    /// Returns
+    /// - `LiquidationEstimate` with seized collaterals, protocol fees, refunds,
+    ///   max repay amount (WAD), bonus rate (BPS), liquidator profit (WAD) and
+    ///   the max repayable amount per debt token
+    pub fn liquidation_estimations<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedVec<Env::Api, EgldOrEsdtTokenPayment<Env::Api>>>,
+    >(
+        self,
+        account_nonce: Arg0,
+        debt_payments: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common_structs::LiquidationEstimate<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("liquidationEstimations")
+            .argument(&account_nonce)
+            .argument(&debt_payments)
+            .original_result()
+    }
+
+    /// Simulates a full liquidation from the account holder's point of view.
+    ///
+    /// Purpose: Show users their exposure by previewing the collateral they would lose,
+    /// the debt that would be cleared, and the health factor left afterwards when a
+    /// liquidator repays the maximum allowed debt. Does not mutate state.
+    ///
+    /// Arguments
+    /// - `account_nonce`: Account NFT nonce to simulate
+    ///
+    /// Returns
+    /// - `collateral_lost`: EGLD value of the seized collateral, bonus included (WAD)
+    /// - `debt_repaid`: EGLD value of the debt repaid by the liquidator (WAD)
+    /// - `resulting_hf`: Health factor of the account after the liquidation (RAY)
+    ///
+    /// Panics
+    /// - If the account is not active or is not liquidatable
+    pub fn simulate_liquidation_impact<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("simulateLiquidationImpact")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Previews the outcome of a `multiply` call without executing it.
+    ///
+    /// Purpose: Let users size a leveraged position before opening it by projecting the
+    /// final collateral, debt, leverage and health factor for a given flash loan amount
+    /// and the swap output they expect from the router. Does not mutate state.
+    ///
+    /// Methodology:
+    /// 1. Starts from the account's current collateral and debt (empty for a new account)
+    /// 2. Adds `expected_swap_out` of collateral, weighted by its e-mode aware threshold
+    /// 3. Adds `debt_to_flash_loan` of debt, as recorded by the flash borrow
+    /// 4. Leverage = collateral / (collateral - debt)
+    ///
+    /// Arguments
+    /// - `account_nonce`: Existing account to lever up, or 0 for a new account without e-mode
+    /// - `collateral_token`: Token deposited after the swap
+    /// - `debt_token`: Token borrowed through the flash loan
+    /// - `debt_to_flash_loan`: Debt amount to borrow (raw units)
+    /// - `expected_swap_out`: Collateral amount expected from the swap (raw units)
+    ///
+    /// Returns
+    /// - `final_collateral`: EGLD value of the account collateral after the multiply (WAD)
+    /// - `final_debt`: EGLD value of the account debt after the multiply (WAD)
+    /// - `leverage`: Collateral over equity (RAY); zero when the debt exceeds the collateral
+    /// - `health_factor`: Projected health factor (RAY)
+    pub fn preview_multiply<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        account_nonce: Arg0,
+        collateral_token: Arg1,
+        debt_token: Arg2,
+        debt_to_flash_loan: Arg3,
+        expected_swap_out: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue4<ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("previewMultiply")
+            .argument(&account_nonce)
+            .argument(&collateral_token)
+            .argument(&debt_token)
+            .argument(&debt_to_flash_loan)
+            .argument(&expected_swap_out)
+            .original_result()
+    }
+
+    /// Retrieves updated market indexes and price information for multiple assets.
+    ///
+    /// Purpose: Provide monitoring data (supply/borrow indices and prices) for UIs.
+    /// Uses simulated index updates and price components with tolerances.
+    ///
+    /// Arguments
+    /// - `assets`: Asset identifiers to fetch
+    ///
+    /// Returns
+    /// - `ManagedVec<MarketIndexView>` entries with indices and price data
+    pub fn all_market_indexes<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        assets: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, common_structs::MarketIndexView<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllMarketIndexes")
+            .argument(&assets)
+            .original_result()
+    }
+
+    /// Retrieves market indexes and price information plus aggregator staleness.
+    ///
+    /// Purpose: Same monitoring data as `getAllMarketIndexes`, extended with the
+    /// aggregator feed timestamp and staleness flag for consumers that need them.
+    ///
+    /// Arguments
+    /// - `assets`: Asset identifiers to fetch
+    ///
+    /// Returns
+    /// - `ManagedVec<MarketIndexExtendedView>` entries with indices, price data,
+    ///   aggregator timestamp and staleness flag
+    pub fn all_market_indexes_extended<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        assets: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, common_structs::MarketIndexExtendedView<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllMarketIndexesExtended")
+            .argument(&assets)
+            .original_result()
+    }
+
+    /// Retrieves extended configuration views for multiple assets.
+    /// Includes market addresses and current prices in EGLD and USD.
+    ///
+    /// # Arguments
+    /// - `assets`: List of token identifiers (EGLD or ESDT) to query.
+    ///
+    /// # Returns
+    /// - Vector of `AssetExtendedConfigView` structs for each asset.
+    pub fn all_markets<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        assets: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, common_structs::AssetExtendedConfigView<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllMarkets")
+            .argument(&assets)
+            .original_result()
+    }
+
+    /// Computes the total value locked across all markets of the protocol.
+    /// Sums the supplied amount of every market, including accrued interest, at current prices.
+    /// Reads the per-market supply aggregates kept by the pools, so no account is scanned.
+    ///
+    /// # Returns
+    /// - `MultiValue2` of the total value locked in EGLD and in USD, both in WAD precision.
+    pub fn total_value_locked(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalValueLocked")
+            .original_result()
+    }
+
+    /// Computes the protocol-wide collateral and debt across all markets.
+    /// Sums the supplied and borrowed amounts of every market, including accrued interest,
+    /// valued at current prices within a single cache so every market is priced consistently.
+    ///
+    /// # Returns
+    /// - `MultiValue2` of the total collateral and total debt in EGLD, both in WAD precision.
+    pub fn protocol_totals(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolTotals")
+            .original_result()
+    }
+
+    /// Retrieves the outstanding debt in EGLD grouped by e-mode category.
+    /// Category 0 aggregates accounts without e-mode, so risk teams can compare the
+    /// exposure held in high-leverage categories against regular borrowing.
+    /// Debt is valued at borrow, repay and e-mode roll time; accrued interest and later price moves
+    /// are not reflected.
+    ///
+    /// # Returns
+    /// - `MultiValueEncoded` of `(e_mode_category_id, debt)` pairs for every category up to the
+    ///   last one created, with the debt in EGLD (WAD precision).
+    pub fn aggregate_borrow_by_e_mode(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u8, ManagedDecimal<Env::Api, usize>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAggregateBorrowByEMode")
+            .original_result()
+    }
+
+    /// Computes how much of a market's supply cap is already used.
+    /// Divides the current supplied amount, including accrued interest, by the supply cap.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market.
+    ///
+    /// # Returns
+    /// - Supply cap utilization as a `ManagedDecimal` in BPS precision; zero when uncapped.
+    pub fn supply_cap_utilization<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSupplyCapUtilization")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Computes how much of a market's borrow cap is already used.
+    /// Divides the current borrowed amount, including accrued interest, by the borrow cap.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market.
+    ///
+    /// # Returns
+    /// - Borrow cap utilization as a `ManagedDecimal` in BPS precision; zero when uncapped.
+    pub fn borrow_cap_utilization<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowCapUtilization")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Retrieves the debt ceiling usage of an isolated asset in one call.
+    /// Combines the USD debt borrowed against the asset with its `isolation_debt_ceiling_usd_wad`,
+    /// letting front-ends disable isolated borrows once the remaining headroom is exhausted.
+    ///
+    /// # Arguments
+    /// - `token_id`: Token identifier (EGLD or ESDT) of the isolated collateral asset.
+    ///
+    /// # Returns
+    /// - `MultiValue3` of the used debt, the debt ceiling and the remaining headroom, all in USD
+    ///   (WAD precision); the remaining headroom is zero once the ceiling is reached.
+    pub fn isolated_debt_info<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getIsolatedDebtInfo")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Computes the overall collateralization of a market for systemic risk monitoring.
+    /// Compares the EGLD value of everything supplied as collateral in the market with the
+    /// EGLD value of the debt borrowed from it, both including accrued interest.
+    /// Reads the supply and borrow aggregates kept by the pool, so no account is scanned.
+    ///
+    /// # Arguments
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market.
+    ///
+    /// # Returns
+    /// - `MultiValue3` of the collateral value and debt value in EGLD (WAD precision), and their
+    ///   ratio in BPS precision; the ratio is zero when nothing is borrowed.
+    pub fn collateralization_ratio<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCollateralizationRatio")
+            .argument(&asset)
+            .original_result()
+    }
+
+    /// Determines if an account position is eligible for liquidation.
+    /// Checks if the health factor is below 1 (100% in WAD precision).
+    /// Accounts within the grace period that follows a liquidation threshold reduction
+    /// are never eligible.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the position can be liquidated.
+    pub fn can_be_liquidated<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("canBeLiquidated")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Computes the current health factor for an account position.
+    /// Indicates position safety; lower values increase liquidation risk.
+    /// Reads indexes as of the last market sync when view index sync is disabled.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - Health factor as a `ManagedDecimal` in WAD precision.
+    pub fn health_factor<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getHealthFactor")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Computes the health factor for an account position with interest accrued to now.
+    /// Always syncs market indexes, regardless of the view index sync setting.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - Health factor as a `ManagedDecimal` in WAD precision.
+    pub fn health_factor_synced<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getHealthFactorSynced")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Computes the health factors of many account positions in a single query.
+    /// Prices are cached across accounts, so each token is priced once per call.
+    /// Accounts without borrow positions are not priced and report the maximum health factor.
+    /// Reads indexes as of the last market sync when view index sync is disabled.
+    ///
+    /// # Arguments
+    /// - `account_nonces`: NFT nonces of the account positions.
+    ///
+    /// # Returns
+    /// - `MultiValueEncoded` of `(account_nonce, health_factor)` pairs in input order, with the
+    ///   health factor in RAY precision.
+    pub fn health_factors<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
+        self,
+        account_nonces: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, ManagedDecimal<Env::Api, usize>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getHealthFactors")
+            .argument(&account_nonces)
+            .original_result()
+    }
+
+    /// Simulates the health factor of an account after a sequence of hypothetical actions.
+    /// Applies each delta in order to an in-memory copy of the account's positions, so users
+    /// can preview the impact of a supply, withdraw, borrow or repay before submitting it.
+    /// New deposits take the asset's e-mode aware risk parameters; withdrawals and repayments
+    /// are capped at the simulated position. Does not mutate state.
+    /// Reads indexes as of the last market sync when view index sync is disabled.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `deltas`: `(token_id, amount_raw, action)` triples, with amounts in the token's base units.
+    ///
+    /// # Returns
+    /// - Simulated health factor as a `ManagedDecimal` in RAY precision.
+    pub fn simulate_health_factor<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>, common_structs::MarketActivityType>>>,
+    >(
+        self,
+        account_nonce: Arg0,
+        deltas: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("simulateHealthFactor")
+            .argument(&account_nonce)
+            .argument(&deltas)
+            .original_result()
+    }
+
+    /// Computes how much each collateral position contributes to an account's health factor.
+    /// Each contribution is the position's liquidation-threshold weighted value divided by the
+    /// total borrow value, so the contributions add up to the health factor.
+    /// Accounts without borrows report zero contributions, as their health factor is unbounded.
+    /// Reads indexes as of the last market sync when view index sync is disabled.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - `MultiValueEncoded` of `(token_id, hf_contribution)` pairs, in RAY precision.
+    pub fn position_health_contribution<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, ManagedDecimal<Env::Api, usize>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPositionHealthContribution")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Estimates the health factor gained by adding one whole token to each collateral position.
+    /// Uses the same threshold math as `getPositionHealthContribution`, applied to one unit
+    /// of the token instead of the held amount, to help users pick which collateral to add.
+    /// Reads indexes as of the last market sync when view index sync is disabled.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - `MultiValueEncoded` of `(token_id, hf_contribution)` pairs for one unit of each
+    ///   collateral token, in RAY precision.
+    pub fn marginal_health_contribution<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, ManagedDecimal<Env::Api, usize>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMarginalHealthContribution")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Lists healthy borrowing accounts ordered by how close they are to liquidation.
+    /// Scans one page of the account set and returns the accounts still at or above the
+    /// liquidation threshold, lowest health factor first, so keepers can pre-stage
+    /// positions about to become liquidatable.
+    /// Reads indexes as of the last market sync when view index sync is disabled.
+    ///
+    /// # Arguments
+    /// - `from`: Zero-based offset into the account set.
+    /// - `size`: Maximum number of accounts scanned from `from`.
+    ///
+    /// # Returns
+    /// - `MultiValueEncoded` of `(account_nonce, health_factor)` pairs sorted ascending by
+    ///   health factor, in RAY precision. Accounts without borrows or already liquidatable
+    ///   are skipped.
+    pub fn accounts_near_liquidation<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        from: Arg0,
+        size: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, ManagedDecimal<Env::Api, usize>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountsNearLiquidation")
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    /// Checks whether an account's debt is fully backed by collateral eligible under its mode.
+    /// Unlike the health factor, only collateral allowed to back debt under the account's
+    /// isolation and e-mode rules is counted, and every borrow must respect those rules.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if all borrows are allowed under the account's mode and the eligible
+    ///   collateral alone keeps the health factor at or above 1.
+    pub fn is_fully_backed<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isFullyBacked")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Computes the price of one collateral at which the account becomes liquidatable.
+    /// Solves the health factor equation for that token's price, every other price held constant.
+    /// A token that is also borrowed contributes to the debt side at the same price.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `collateral_token`: Token identifier (EGLD or ESDT) of the collateral to price.
+    ///
+    /// # Returns
+    /// - Liquidation price in USD as a `ManagedDecimal` in WAD precision; zero when no price
+    ///   drop of this collateral can bring the health factor down to 1.
+    ///
+    /// # Panics
+    /// - If the token is not in the account's collateral.
+    pub fn liquidation_price<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        account_nonce: Arg0,
+        collateral_token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidationPrice")
+            .argument(&account_nonce)
+            .argument(&collateral_token)
+            .original_result()
+    }
+
+    /// Retrieves the collateral amount for a specific token in an account position.
+    /// Fails if the token is not part of the position’s collateral.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `token_id`: Token identifier (EGLD or ESDT) to query.
+    ///
+    /// # Returns
+    /// - Collateral amount as a `ManagedDecimal`.
+    ///
+    /// # Panics
+    /// - If the token is not in the account’s collateral.
+    pub fn collateral_amount_for_token<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        account_nonce: Arg0,
+        token_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCollateralAmountForToken")
+            .argument(&account_nonce)
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Retrieves the borrowed amount for a specific token in an account position.
+    /// Fails if the token is not part of the position’s borrows.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `token_id`: Token identifier (EGLD or ESDT) to query.
+    ///
+    /// # Returns
+    /// - Borrowed amount as a `ManagedDecimal`.
+    ///
+    /// # Panics
+    /// - If the token is not in the account’s borrows.
+    pub fn borrow_amount_for_token<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        account_nonce: Arg0,
+        token_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowAmountForToken")
+            .argument(&account_nonce)
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Retrieves the interest accrued by a position since it was opened.
+    /// Compares the current value of the position with its principal at the entry index.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `token_id`: Token identifier of the position.
+    /// - `is_borrow`: Whether to read the borrow position instead of the deposit.
+    ///
+    /// # Returns
+    /// - Accrued interest in token units as a `ManagedDecimal` (WAD precision).
+    ///
+    /// # Panics
+    /// - If the token is not in the account's positions of the requested type.
+    pub fn position_accrued_interest<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<bool>,
+    >(
+        self,
+        account_nonce: Arg0,
+        token_id: Arg1,
+        is_borrow: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPositionAccruedInterest")
+            .argument(&account_nonce)
+            .argument(&token_id)
+            .argument(&is_borrow)
+            .original_result()
+    }
+
+    /// Computes the total borrow value in EGLD for an account position.
+    /// Sums the EGLD value of all borrowed assets.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - Total borrow value in EGLD as a `ManagedDecimal`.
+    pub fn total_borrow_in_egld<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalBorrowInEgld")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Computes the total collateral value in EGLD for an account position.
+    /// Sums the EGLD value of all collateral assets (unweighted), skipping supply-only deposits.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - Total collateral value in EGLD as a `ManagedDecimal`.
+    pub fn total_collateral_in_egld<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalCollateralInEgld")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Computes the liquidation collateral available in EGLD.
+    /// Represents collateral value weighted by liquidation thresholds.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - Liquidation collateral in EGLD as a `ManagedDecimal`.
+    pub fn liquidation_collateral_available<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidationCollateralAvailable")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Computes the LTV-weighted collateral value in EGLD.
+    /// Represents collateral value weighted by loan-to-value ratios.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - LTV-weighted collateral in EGLD as a `ManagedDecimal`.
+    pub fn ltv_collateral_in_egld<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLtvCollateralInEgld")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Estimates the largest amount of a token an account can borrow right now.
+    /// Mirrors the checks of `borrow`: the e-mode, isolation and siloed borrowing rules decide
+    /// whether the asset can be borrowed at all, then the remaining LTV collateral, the borrow
+    /// allowance, the maximum position value, the isolated debt ceiling and the borrow cap bound
+    /// the amount. Protocol-owned accounts skip the borrow allowance and the maximum position value.
+    /// The borrow cap exemption list is not applied, as it depends on the caller.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `token_id`: Token identifier (EGLD or ESDT) to borrow.
+    ///
+    /// # Returns
+    /// - Maximum borrowable amount in the token's decimals, rounded down; zero when the asset
+    ///   cannot be borrowed by the account.
+    pub fn max_borrowable<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        account_nonce: Arg0,
+        token_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxBorrowable")
+            .argument(&account_nonce)
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Computes the net worth of an account position in EGLD and USD.
+    /// Net worth is total collateral minus total borrow, clamped at zero for insolvent accounts.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - `MultiValue2` of the net worth in EGLD and in USD, both in WAD precision.
+    pub fn account_net_worth<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountNetWorth")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Summarizes the collateral, debt and health factor of an account position.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - `UserAccountSummary` with collateral and debt values in EGLD (WAD precision) and the
+    ///   health factor in RAY precision.
+    ///
+    /// # Panics
+    /// - If the account is not active in the market.
+    pub fn account_summary<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common_structs::UserAccountSummary<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountSummary")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Summarizes every account position held by a wallet in one paginated call.
+    /// Accounts are read from the holder reverse index, so an NFT appears under the
+    /// address that last presented it to the protocol.
+    ///
+    /// # Arguments
+    /// - `address`: Wallet address holding the account NFTs.
+    /// - `from`: Zero-based index of the first account to return.
+    /// - `size`: Maximum number of accounts to return.
+    ///
+    /// # Returns
+    /// - `MultiValueEncoded` of `(account_nonce, UserAccountSummary)` pairs; empty when `from`
+    ///   is past the wallet's last account.
+    pub fn wallet_summary<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        address: Arg0,
+        from: Arg1,
+        size: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, common_structs::UserAccountSummary<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWalletSummary")
+            .argument(&address)
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    /// Compares the health factor of an account under its e-mode category with the health
+    /// factor it would have under the base asset configuration.
+    /// Both values use the current risk parameters, so they may differ from the thresholds
+    /// stored on positions that were not yet synchronized.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - `MultiValue2` of the health factor with e-mode and without e-mode, both in RAY precision.
+    ///   For accounts without e-mode both values are equal.
+    ///
+    /// # Panics
+    /// - If the account is not active in the market.
+    pub fn e_mode_impact<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEModeImpact")
+            .argument(&account_nonce)
+            .original_result()
+    }
+
+    /// Retrieves the USD price of a token using oracle data.
+    /// Converts the token’s EGLD price to USD for standardization.
+    ///
+    /// # Arguments
+    /// - `token_id`: Token identifier (EGLD or ESDT) to query.
+    ///
+    /// # Returns
+    /// - USD price of the token as a `ManagedDecimal`.
+    pub fn usd_price<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTokenPriceUSD")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Retrieves the EGLD price of a token using oracle data.
+    /// Accesses the token's price feed directly.
+    ///
+    /// # Arguments
+    /// - `token_id`: Token identifier (EGLD or ESDT) to query.
+    ///
+    /// # Returns
+    /// - EGLD price of the token as a `ManagedDecimal`.
+    pub fn egld_price<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTokenPriceEGLD")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Retrieves the EGLD price of a token together with the age of its aggregator round.
+    /// Lets liquidation bots and risk monitors tell a fresh price from one close to going stale.
+    ///
+    /// # Arguments
+    /// - `token_id`: Token identifier (EGLD or ESDT) to query.
     ///
-    /// Returns
-    /// - `ManagedVec<MarketIndexExtendedView>` entries with indices, price data,
-    ///   aggregator timestamp and staleness flag
-    pub fn all_market_indexes_extended<
-        Arg0: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    /// # Returns
+    /// - `MultiValue3` of the EGLD price (WAD precision), the aggregator round timestamp in
+    ///   milliseconds, and whether that round is older than the oracle's
+    ///   `max_price_stale_seconds`. The timestamp is 0 and the price never stale when the
+    ///   token is not priced from the aggregator (EGLD, LP, derived and safe-price-only tokens).
+    pub fn token_price_with_age<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
         self,
-        assets: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, common_structs::MarketIndexExtendedView<Env::Api>>> {
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<ManagedDecimal<Env::Api, usize>, TimestampMillis, bool>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAllMarketIndexesExtended")
-            .argument(&assets)
+            .raw_call("getTokenPriceWithAge")
+            .argument(&token_id)
             .original_result()
     }
 
-    /// Retrieves extended configuration views for multiple assets.
-    /// Includes market addresses and current prices in EGLD and USD.
+    /// Retrieves the USD price of a token together with a confidence band.
+    /// The band is centered on the price and its half-width is half the absolute divergence
+    /// between the aggregator and safe prices, so its total width equals that divergence.
     ///
     /// # Arguments
-    /// - `assets`: List of token identifiers (EGLD or ESDT) to query.
+    /// - `token_id`: Token identifier (EGLD or ESDT) to query.
     ///
     /// # Returns
-    /// - Vector of `AssetExtendedConfigView` structs for each asset.
-    pub fn all_markets<
-        Arg0: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    /// - `MultiValue3` of the price, lower bound and upper bound in USD, WAD precision.
+    ///   The band is empty when the token is priced from a single source; the lower
+    ///   bound never goes below zero.
+    pub fn oracle_price_with_confidence<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
         self,
-        assets: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, common_structs::AssetExtendedConfigView<Env::Api>>> {
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAllMarkets")
-            .argument(&assets)
+            .raw_call("getOraclePriceWithConfidence")
+            .argument(&token_id)
             .original_result()
     }
 
-    /// Determines if an account position is eligible for liquidation.
-    /// Checks if the health factor is below 1 (100% in WAD precision).
+    /// Computes the annual borrow rate a market would charge at a given utilization.
+    /// Evaluates the market's piecewise linear rate curve, capped at the max borrow rate.
     ///
     /// # Arguments
-    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market.
+    /// - `utilization_ray`: Utilization ratio in RAY precision.
     ///
     /// # Returns
-    /// - `bool`: `true` if the position can be liquidated.
-    pub fn can_be_liquidated<
-        Arg0: ProxyArg<u64>,
+    /// - Annual borrow rate as a `ManagedDecimal` in RAY precision.
+    pub fn borrow_rate_at<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
-        account_nonce: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        asset: Arg0,
+        utilization_ray: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("canBeLiquidated")
-            .argument(&account_nonce)
+            .raw_call("getBorrowRateAt")
+            .argument(&asset)
+            .argument(&utilization_ray)
             .original_result()
     }
 
-    /// Computes the current health factor for an account position.
-    /// Indicates position safety; lower values increase liquidation risk.
+    /// Computes the utilization at which a market would charge a given annual borrow rate.
+    /// Inverts the market's piecewise linear rate curve; rates above the curve map to 100%.
     ///
     /// # Arguments
-    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market.
+    /// - `target_rate_ray`: Annual borrow rate in RAY precision.
     ///
     /// # Returns
-    /// - Health factor as a `ManagedDecimal` in WAD precision.
-    pub fn health_factor<
-        Arg0: ProxyArg<u64>,
+    /// - Utilization ratio as a `ManagedDecimal` in RAY precision.
+    pub fn utilization_for_borrow_rate<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
-        account_nonce: Arg0,
+        asset: Arg0,
+        target_rate_ray: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getHealthFactor")
-            .argument(&account_nonce)
+            .raw_call("getUtilizationForBorrowRate")
+            .argument(&asset)
+            .argument(&target_rate_ray)
             .original_result()
     }
 
-    /// Retrieves the collateral amount for a specific token in an account position.
-    /// Fails if the token is not part of the position’s collateral.
+    /// Quotes the fee a flash loan of a given amount would be charged.
+    /// Mirrors the pool's repayment rounding, so `amount_raw` plus the fee is the exact
+    /// amount the receiver endpoint must return.
     ///
     /// # Arguments
-    /// - `account_nonce`: NFT nonce of the account position.
-    /// - `token_id`: Token identifier (EGLD or ESDT) to query.
+    /// - `token_id`: Token identifier (EGLD or ESDT) of the market.
+    /// - `amount_raw`: Flash loan amount in the token's base units.
     ///
     /// # Returns
-    /// - Collateral amount as a `ManagedDecimal`.
-    ///
-    /// # Panics
-    /// - If the token is not in the account’s collateral.
-    pub fn collateral_amount_for_token<
-        Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    /// - Flash loan fee as a `BigUint` in the token's base units.
+    pub fn flash_loan_fee<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
-        account_nonce: Arg0,
-        token_id: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        token_id: Arg0,
+        amount_raw: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getCollateralAmountForToken")
-            .argument(&account_nonce)
+            .raw_call("getFlashLoanFee")
             .argument(&token_id)
+            .argument(&amount_raw)
             .original_result()
     }
 
-    /// Retrieves the borrowed amount for a specific token in an account position.
-    /// Fails if the token is not part of the position’s borrows.
+    /// Retrieves the cumulative rounding remainder a market retained from favorable rounding.
+    /// Makes the protocol's rounding revenue transparent for audits.
     ///
     /// # Arguments
-    /// - `account_nonce`: NFT nonce of the account position.
-    /// - `token_id`: Token identifier (EGLD or ESDT) to query.
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market.
     ///
     /// # Returns
-    /// - Borrowed amount as a `ManagedDecimal`.
-    ///
-    /// # Panics
-    /// - If the token is not in the account’s borrows.
-    pub fn borrow_amount_for_token<
-        Arg0: ProxyArg<u64>,
-        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    /// - Accumulated rounding remainder as a `BigUint` in asset units, RAY-scaled.
+    pub fn asset_accumulated_rounding<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
         self,
-        account_nonce: Arg0,
-        token_id: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getBorrowAmountForToken")
-            .argument(&account_nonce)
-            .argument(&token_id)
+            .raw_call("getAccumulatedRounding")
+            .argument(&asset)
             .original_result()
     }
 
-    /// Computes the total borrow value in EGLD for an account position.
-    /// Sums the EGLD value of all borrowed assets.
+    /// Previews the revenue `claimRevenue` would transfer from a market right now.
+    /// Accrues interest up to the current timestamp in memory, as the pool does before a claim,
+    /// and caps the result at the pool's reserves, so keepers can tell whether a claim is worth
+    /// the gas. Does not mutate state.
     ///
     /// # Arguments
-    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market.
     ///
     /// # Returns
-    /// - Total borrow value in EGLD as a `ManagedDecimal`.
-    pub fn total_borrow_in_egld<
-        Arg0: ProxyArg<u64>,
+    /// - Claimable revenue as a `ManagedDecimal` in the asset's decimals.
+    pub fn claimable_revenue<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
         self,
-        account_nonce: Arg0,
+        asset: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getTotalBorrowInEgld")
-            .argument(&account_nonce)
+            .raw_call("getClaimableRevenue")
+            .argument(&asset)
             .original_result()
     }
 
-    /// Computes the total collateral value in EGLD for an account position.
-    /// Sums the EGLD value of all collateral assets (unweighted).
+    /// Retrieves the recent supply, borrow, repay and withdraw operations of a market.
+    /// Reads the market's bounded activity buffer, so light clients need no indexer.
     ///
     /// # Arguments
-    /// - `account_nonce`: NFT nonce of the account position.
+    /// - `asset`: Token identifier (EGLD or ESDT) of the market.
     ///
     /// # Returns
-    /// - Total collateral value in EGLD as a `ManagedDecimal`.
-    pub fn total_collateral_in_egld<
-        Arg0: ProxyArg<u64>,
+    /// - `ManagedVec<MarketActivity>` ordered from oldest to newest; empty when recording is disabled.
+    pub fn recent_market_activity<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
         self,
-        account_nonce: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        asset: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, common_structs::MarketActivity<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getTotalCollateralInEgld")
-            .argument(&account_nonce)
+            .raw_call("getRecentMarketActivity")
+            .argument(&asset)
             .original_result()
     }
 
-    /// Computes the liquidation collateral available in EGLD.
-    /// Represents collateral value weighted by liquidation thresholds.
+    /// Retrieves the most recent liquidations of an account.
+    /// Reads the account's bounded liquidation history, so users need no indexer.
     ///
     /// # Arguments
     /// - `account_nonce`: NFT nonce of the account position.
     ///
     /// # Returns
-    /// - Liquidation collateral in EGLD as a `ManagedDecimal`.
-    pub fn liquidation_collateral_available<
+    /// - `ManagedVec<LiquidationRecord>` ordered from oldest to newest; empty when the account
+    ///   was never liquidated.
+    pub fn account_liquidation_history<
         Arg0: ProxyArg<u64>,
     >(
         self,
         account_nonce: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, common_structs::LiquidationRecord<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getLiquidationCollateralAvailable")
+            .raw_call("getLiquidationHistory")
             .argument(&account_nonce)
             .original_result()
     }
 
-    /// Computes the LTV-weighted collateral value in EGLD.
-    /// Represents collateral value weighted by loan-to-value ratios.
+    /// Computes the interest accrued by an account on a token since a given timestamp.
+    /// Uses the latest index snapshot at or before `since_timestamp` as the starting point,
+    /// so the result is exact for positions whose scaled amount did not change since then.
     ///
     /// # Arguments
     /// - `account_nonce`: NFT nonce of the account position.
+    /// - `token_id`: Token identifier (EGLD or ESDT) to query.
+    /// - `since_timestamp`: Start of the period, in seconds.
     ///
     /// # Returns
-    /// - LTV-weighted collateral in EGLD as a `ManagedDecimal`.
-    pub fn ltv_collateral_in_egld<
+    /// - `MultiValue2` of the borrow interest and the supply interest in asset decimals; zero
+    ///   for a side the account holds no position on.
+    ///
+    /// # Panics
+    /// - If no index snapshot of the market was recorded at or before `since_timestamp`.
+    pub fn interest_accrued_since<
         Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<TimestampSeconds>,
     >(
         self,
         account_nonce: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        token_id: Arg1,
+        since_timestamp: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<ManagedDecimal<Env::Api, usize>, ManagedDecimal<Env::Api, usize>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getLtvCollateralInEgld")
+            .raw_call("getInterestAccruedSince")
             .argument(&account_nonce)
+            .argument(&token_id)
+            .argument(&since_timestamp)
             .original_result()
     }
 
-    /// Retrieves the USD price of a token using oracle data.
-    /// Converts the token’s EGLD price to USD for standardization.
+    /// Breaks the projected annual yield of a market's suppliers down into interest and rewards.
+    /// Interest uses the supply rate at the current utilization; rewards are annualized from the
+    /// distributions received since the latest index snapshot at or before `since_timestamp`.
     ///
     /// # Arguments
-    /// - `token_id`: Token identifier (EGLD or ESDT) to query.
+    /// - `token_id`: Token identifier (EGLD or ESDT) of the market.
+    /// - `since_timestamp`: Start of the trailing reward period, in seconds.
     ///
     /// # Returns
-    /// - USD price of the token as a `ManagedDecimal`.
-    pub fn usd_price<
+    /// - `DepositYieldBreakdown` with the interest, reward and total annual yield (RAY).
+    ///
+    /// # Panics
+    /// - If no index snapshot of the market was recorded at or before `since_timestamp`.
+    pub fn deposit_yield_breakdown<
         Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<TimestampSeconds>,
     >(
         self,
         token_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        since_timestamp: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common_structs::DepositYieldBreakdown<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getTokenPriceUSD")
+            .raw_call("getDepositYieldBreakdown")
             .argument(&token_id)
+            .argument(&since_timestamp)
             .original_result()
     }
 
-    /// Retrieves the EGLD price of a token using oracle data.
-    /// Accesses the token's price feed directly.
+    /// Retrieves the spread between the borrow and supply rate of multiple markets.
+    /// The spread is the interest margin kept by the protocol and lost to idle liquidity.
     ///
     /// # Arguments
-    /// - `token_id`: Token identifier (EGLD or ESDT) to query.
+    /// - `assets`: List of token identifiers (EGLD or ESDT) to query.
     ///
     /// # Returns
-    /// - EGLD price of the token as a `ManagedDecimal`.
-    pub fn egld_price<
-        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    /// - Vector of `MarketRateSpreadView` with the annual rates and their spread (RAY).
+    pub fn market_rate_spread<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
     >(
         self,
-        token_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedDecimal<Env::Api, usize>> {
+        assets: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, common_structs::MarketRateSpreadView<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getTokenPriceEGLD")
-            .argument(&token_id)
+            .raw_call("getMarketRateSpread")
+            .argument(&assets)
+            .original_result()
+    }
+
+    /// Decodes the attributes of an active account NFT into their structured form.
+    /// Lets integrators read e-mode, isolation and position mode without decoding the NFT payload.
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account position.
+    ///
+    /// # Returns
+    /// - `AccountAttributes` with every attribute field of the account.
+    ///
+    /// # Panics
+    /// - If the account is not active in the market.
+    pub fn decode_account_attributes<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        account_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common_structs::AccountAttributes<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("decodeAccountAttributes")
+            .argument(&account_nonce)
             .original_result()
     }
 
@@ -2119,6 +5305,151 @@ where
             .original_result()
     }
 
+    /// **REPAY WITH SWAP: Repay Debt Using Funds in Another Token**
+    ///
+    /// # Purpose and Scope
+    /// Lets a user holding one token repay debt owed in another without swapping manually first.
+    /// Counterpart of `repayDebtWithCollateral` for funds sent with the call instead of collateral.
+    ///
+    /// # Methodology and Process
+    /// 1. **Payment Validation**: Requires a single payment in `payment_token` for an active account
+    /// 2. **Debt Validation**: Requires an open borrow position in `debt_token` before swapping
+    /// 3. **Token Conversion**: Swaps the payment to `debt_token` through the swap router
+    /// 4. **Debt Repayment**: Repays whatever the swap returned; any amount above the outstanding
+    ///    debt is refunded by the pool, and tokens the router returns unused are refunded to the caller
+    ///
+    /// # Security Checks Implemented
+    /// - **Reentrancy Protection**: Guards against flash loan reentrancy attacks
+    /// - **Token Validation**: Requires different payment and debt tokens
+    /// - **Swap Output Validation**: Reverts when the swap returns no debt token
+    ///
+    /// # Arguments
+    /// - `account_nonce`: NFT nonce of the account whose debt is repaid
+    /// - `debt_token`: Debt token repaid
+    /// - `payment_token`: Token sent with the call and swapped to the debt token
+    /// - `steps`: Swap router steps for payment token to debt token conversion; empty uses
+    ///   the default steps configured for the pair
+    ///
+    /// # Payment
+    /// - A single payment in `payment_token`
+    ///
+    /// # Risk Considerations
+    /// - Swap slippage reduces the repaid amount; a partial repayment leaves the rest of the debt open
+    pub fn repay_with_swap<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg3: ProxyArg<ManagedArgBuffer<Env::Api>>,
+    >(
+        self,
+        account_nonce: Arg0,
+        debt_token: Arg1,
+        payment_token: Arg2,
+        steps: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("repayWithSwap")
+            .argument(&account_nonce)
+            .argument(&debt_token)
+            .argument(&payment_token)
+            .argument(&steps)
+            .original_result()
+    }
+
+    /// **DELEVERAGE STRATEGY: Partial or Full Unwind of a Leveraged Position**
+    ///
+    /// # Purpose and Scope
+    /// Symmetric counterpart of `multiply`: reduces the debt of a leveraged position by a fraction
+    /// in one transaction, paying it back with the position's own collateral.
+    ///
+    /// # Methodology and Process
+    /// 1. **Debt Sizing**: Computes the debt to repay as `fraction_bps` of the current debt
+    /// 2. **Collateral Withdrawal**: Withdraws collateral worth the same amount at oracle prices (no bonus)
+    /// 3. **Token Conversion**: Swaps the withdrawn collateral to the debt token via the swap router
+    /// 4. **Debt Repayment**: Repays the debt fraction, refunding swap surplus to the caller
+    /// 5. **Position Closure**: On a full deleverage, withdraws the remaining collateral and burns the NFT
+    ///    when no other positions remain
+    /// 6. **Health Validation**: Ensures a partially deleveraged position stays healthy
+    ///
+    /// # Mathematical Formula
+    /// Debt Repaid = Current Debt * fraction_bps / 10000
+    /// Collateral Withdrawn = Debt Repaid * Debt Price / Collateral Price
+    ///
+    /// # Security Checks Implemented
+    /// - **Reentrancy Protection**: Guards against flash loan reentrancy attacks
+    /// - **Token Validation**: Ensures collateral and debt tokens are different assets
+    /// - **Position Mode Validation**: Ensures the position is in leverage mode
+    /// - **Fraction Validation**: Requires a fraction between 1 and 10000 BPS
+    /// - **Repayment Coverage**: Swap output plus extra payments must cover the debt fraction
+    /// - **Health Factor Validation**: Verifies the remaining position is healthy
+    ///
+    /// # Arguments
+    /// - `collateral_token`: Collateral token withdrawn and swapped
+    /// - `debt_token`: Debt token repaid
+    /// - `fraction_bps`: Fraction of the debt to repay, in BPS (10000 closes the debt)
+    /// - `steps`: Swap router steps for collateral token to debt token conversion
+    ///
+    /// # Payment
+    /// - Account NFT, optionally followed by debt token payments covering swap slippage
+    ///
+    /// # Risk Considerations
+    /// - Swap fees and slippage make the swap output fall short of the debt fraction; the
+    ///   shortfall must be covered by extra debt token payments or the call reverts
+    pub fn deleverage<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<ManagedArgBuffer<Env::Api>>,
+    >(
+        self,
+        collateral_token: Arg0,
+        debt_token: Arg1,
+        fraction_bps: Arg2,
+        steps: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("deleverage")
+            .argument(&collateral_token)
+            .argument(&debt_token)
+            .argument(&fraction_bps)
+            .argument(&steps)
+            .original_result()
+    }
+
+    /// **BATCH COLLATERAL TOP-UP: Multi-Account Supply**
+    ///
+    /// # Purpose and Scope
+    /// Lets users managing several accounts add collateral to all of them in a single call.
+    /// The operation is atomic: if any account or collateral payment is invalid, the whole
+    /// batch reverts and no account is modified.
+    ///
+    /// # Methodology and Process
+    /// 1. **Batch Validation**: Splits payments into account NFTs followed by one collateral payment per account
+    /// 2. **Limit Check**: Ensures the number of accounts does not exceed `max_batch_accounts`
+    /// 3. **Account Validation**: Verifies each NFT is active and its attributes match storage
+    /// 4. **Collateral Supply**: Deposits the i-th collateral payment into the i-th account
+    /// 5. **NFT Return**: Sends every account NFT back to the caller
+    ///
+    /// # Security Checks Implemented
+    /// - **Reentrancy Protection**: Guards against flash loan reentrancy attacks
+    /// - **Payment Layout Validation**: Requires exactly one collateral payment per account NFT
+    /// - **Account Validation**: Rejects inactive accounts and tampered attributes
+    /// - **Deposit Validation**: Applies the regular supply checks (caps, e-mode, isolation) to each account
+    ///
+    /// # Payment
+    /// - Account NFTs first, then one collateral payment per NFT in the same order
+    ///
+    /// # Risk Considerations
+    /// - Batch strategies are disabled until the owner sets `max_batch_accounts`
+    /// - A single invalid entry reverts the top-up of every account in the batch
+    pub fn batch_add_collateral(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("batchAddCollateral")
+            .original_result()
+    }
+
     pub fn pause_endpoint(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
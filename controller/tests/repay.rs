@@ -1,5 +1,11 @@
-use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, MultiValueEncoded};
-use multiversx_sc_scenario::imports::{BigUint, OptionalValue, TestAddress};
+use common_constants::RAY;
+use controller::{
+    ERROR_ACCOUNT_HAS_OUTSTANDING_DEBT, ERROR_HEALTH_FACTOR_WITHDRAW, ERROR_INSUFFICIENT_DEPOSIT,
+};
+use multiversx_sc::types::{
+    EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, ManagedVec, MultiValueEncoded,
+};
+use multiversx_sc_scenario::imports::{BigUint, OptionalValue, StaticApi, TestAddress};
 pub mod constants;
 pub mod proxys;
 pub mod setup;
@@ -126,3 +132,656 @@ fn repay_full_debt_with_interest_and_overpayment_success() {
         .clone();
     assert!(reserves_raw >= reserves_before);
 }
+
+/// Tests closing an account atomically with a single repayment payment.
+///
+/// Covers:
+/// - Controller::closeAccount endpoint functionality
+/// - Full debt repayment including accrued interest and overpayment refund
+/// - Withdrawal of all remaining collateral to the caller
+/// - Account NFT burn and removal from the active accounts registry
+#[test]
+fn close_account_repays_withdraws_and_burns_nft_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    // Supplier provides liquidity
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Borrower supplies collateral and borrows against it
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // Advance 10 days to accumulate interest on the EGLD debt
+    state.change_timestamp(SECONDS_PER_DAY * 10);
+
+    let mut repay_payments = ManagedVec::new();
+    repay_payments.push(EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN.to_esdt_token_identifier()),
+        0,
+        scaled_amount(51, EGLD_DECIMALS),
+    ));
+    state.close_account(&borrower, 2, repay_payments);
+
+    // Both sides of the account are gone and the NFT is burned
+    state.assert_no_borrow_entry(2, &EGLD_TOKEN);
+    state.assert_no_collateral_entry(2, &USDC_TOKEN);
+    assert!(
+        !state.accounts().into_iter().any(|nonce| nonce == 2),
+        "closed account should be removed from the active accounts",
+    );
+
+    // The full USDC collateral went back to the borrower
+    state
+        .world
+        .check_account(borrower)
+        .esdt_balance(USDC_TOKEN, scaled_amount(1_000_000, USDC_DECIMALS));
+}
+
+/// Tests that closing an account reverts while debt remains unpaid.
+///
+/// Covers:
+/// - Controller::closeAccount endpoint error path
+/// - ERROR_ACCOUNT_HAS_OUTSTANDING_DEBT when repayments do not cover the debt
+#[test]
+fn close_account_with_remaining_debt_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // Repay only part of the debt
+    let mut repay_payments = ManagedVec::new();
+    repay_payments.push(EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN.to_esdt_token_identifier()),
+        0,
+        scaled_amount(10, EGLD_DECIMALS),
+    ));
+    state.close_account_error(
+        &borrower,
+        2,
+        repay_payments,
+        ERROR_ACCOUNT_HAS_OUTSTANDING_DEBT,
+    );
+}
+
+/// Tests reducing a leveraged position by repaying and withdrawing in one transaction.
+///
+/// Covers:
+/// - Controller::repayAndWithdraw endpoint functionality
+/// - Repayment applied before the withdrawal frees up collateral
+/// - A withdrawal that would fail on its own succeeding after the repayment
+/// - Account NFT returned to the caller while positions remain open
+#[test]
+fn repay_and_withdraw_reduces_position_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // $5000 USDC collateral against $3600 EGLD debt
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(90u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // Withdrawing $1000 alone would leave the account unhealthy
+    state.withdraw_asset_error(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(1000u64),
+        2,
+        USDC_DECIMALS,
+        ERROR_HEALTH_FACTOR_WITHDRAW,
+    );
+
+    let borrowed_before = state.borrow_amount_for_token(2, EGLD_TOKEN);
+    let collateral_before = state.collateral_amount_for_token(2, USDC_TOKEN);
+
+    // Repaying 30 EGLD first frees enough collateral for the same withdrawal
+    let mut repay_payments = ManagedVec::new();
+    repay_payments.push(EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN.to_esdt_token_identifier()),
+        0,
+        scaled_amount(30, EGLD_DECIMALS),
+    ));
+    let mut collaterals = MultiValueEncoded::new();
+    collaterals.push(EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN.to_esdt_token_identifier()),
+        0,
+        scaled_amount(1000, USDC_DECIMALS),
+    ));
+    state.repay_and_withdraw(&borrower, 2, repay_payments, collaterals);
+
+    assert!(state.borrow_amount_for_token(2, EGLD_TOKEN) < borrowed_before);
+    assert!(state.collateral_amount_for_token(2, USDC_TOKEN) < collateral_before);
+    state.assert_health_factor_at_least(2, RAY);
+
+    // The account stays open and the NFT is back with the borrower
+    assert!(state.accounts().into_iter().any(|nonce| nonce == 2));
+    let account_attributes = state.account_attributes(2);
+    state
+        .world
+        .check_account(borrower)
+        .esdt_nft_balance_and_attributes(ACCOUNT_TOKEN, 2, BigUint::from(1u64), account_attributes);
+}
+
+/// Tests that the single final health check rejects an oversized withdrawal.
+///
+/// Covers:
+/// - Controller::repayAndWithdraw endpoint error path
+/// - ERROR_HEALTH_FACTOR_WITHDRAW when the repayment does not free enough collateral
+#[test]
+fn repay_and_withdraw_unhealthy_after_withdrawal_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(90u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // Repaying 10 EGLD cannot cover a $2000 withdrawal
+    let mut repay_payments = ManagedVec::new();
+    repay_payments.push(EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN.to_esdt_token_identifier()),
+        0,
+        scaled_amount(10, EGLD_DECIMALS),
+    ));
+    let mut collaterals = MultiValueEncoded::new();
+    collaterals.push(EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN.to_esdt_token_identifier()),
+        0,
+        scaled_amount(2000, USDC_DECIMALS),
+    ));
+    state.repay_and_withdraw_error(
+        &borrower,
+        2,
+        repay_payments,
+        collaterals,
+        ERROR_HEALTH_FACTOR_WITHDRAW,
+    );
+}
+
+/// Tests repaying with the account NFT attached instead of an explicit nonce.
+///
+/// Covers:
+/// - Controller::repay deriving the account from the attached NFT
+/// - Remaining transfers repaid as debt payments
+/// - Account NFT returned to the caller
+#[test]
+fn repay_with_attached_account_nft_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    state.repay_asset_with_account(
+        &borrower,
+        &EGLD_TOKEN,
+        BigUint::from(20u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(30, EGLD_DECIMALS),
+        "the debt of the attached account must be repaid",
+    );
+
+    // The NFT is back with the borrower
+    let account_attributes = state.account_attributes(2);
+    state
+        .world
+        .check_account(borrower)
+        .esdt_nft_balance_and_attributes(ACCOUNT_TOKEN, 2, BigUint::from(1u64), account_attributes);
+}
+
+/// Tests that a repayment leaving only dust debt closes the borrow position.
+///
+/// Covers:
+/// - Controller::setDustThreshold configuration
+/// - Controller::repay writing off a remainder below the dust threshold
+/// - Remainders above the threshold staying on the account
+#[test]
+fn repay_dust_remainder_below_threshold_closes_position_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    // Remainders worth less than 0.001 EGLD are written off
+    state.set_dust_threshold(EGLD_TOKEN, BigUint::from(10u64).pow(15));
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // Repay all but 0.000001 EGLD, the dust is absorbed and the position removed
+    let dust = BigUint::from(10u64).pow(12);
+    state.repay_asset_deno(
+        &borrower,
+        &EGLD_TOKEN,
+        scaled_amount(50, EGLD_DECIMALS) - &dust,
+        2,
+    );
+    state.assert_no_borrow_entry(2, &EGLD_TOKEN);
+    state.assert_total_borrow_raw_eq(2, BigUint::zero(), "dust debt should be written off");
+
+    // A remainder of 0.01 EGLD is above the threshold and stays on the account
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(10u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    let remainder = BigUint::from(10u64).pow(16);
+    state.repay_asset_deno(
+        &borrower,
+        &EGLD_TOKEN,
+        scaled_amount(10, EGLD_DECIMALS) - &remainder,
+        2,
+    );
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        remainder,
+        "remainder above the dust threshold should stay borrowed",
+    );
+}
+
+/// Opens an account holding a USDC and an EGLD deposit next to an EGLD borrow.
+fn setup_self_repay(
+    state: &mut LendingPoolTestState,
+    supplier: TestAddress,
+    borrower: TestAddress,
+) {
+    state.change_timestamp(0);
+    setup_accounts(state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(10u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(30u64),
+        2,
+        EGLD_DECIMALS,
+    );
+}
+
+/// Tests repaying a borrow with the deposit of the same token.
+///
+/// Covers:
+/// - Controller::selfRepayFromSupply endpoint functionality
+/// - Deposit and debt reduced by the same amount without moving pool reserves
+/// - Amount above the outstanding debt capped at the debt
+/// - Borrow position removed once fully repaid
+#[test]
+fn self_repay_from_supply_nets_positions_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    setup_self_repay(&mut state, supplier, borrower);
+
+    let reserves_before = state.market_reserves(state.egld_market.clone());
+
+    state.self_repay_from_supply(&borrower, EGLD_TOKEN, BigUint::from(4u64), 2, EGLD_DECIMALS);
+
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(26, EGLD_DECIMALS),
+        "debt should shrink by the netted amount",
+    );
+    state.assert_collateral_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(6, EGLD_DECIMALS),
+        "deposit should shrink by the netted amount",
+    );
+    assert_eq!(
+        state.market_reserves(state.egld_market.clone()),
+        reserves_before
+    );
+
+    // Top up the deposit, then net more than the outstanding debt
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(30u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.self_repay_from_supply(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    state.assert_no_borrow_entry(2, &EGLD_TOKEN);
+    state.assert_collateral_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(10, EGLD_DECIMALS),
+        "only the outstanding debt should be netted",
+    );
+}
+
+/// Tests that netting requires a deposit covering the repaid amount.
+///
+/// Covers:
+/// - Controller::selfRepayFromSupply endpoint error path
+/// - ERROR_INSUFFICIENT_DEPOSIT when the deposit is lower than the amount to repay
+#[test]
+fn self_repay_from_supply_insufficient_deposit_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    setup_self_repay(&mut state, supplier, borrower);
+
+    state.self_repay_from_supply_error(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(15u64),
+        2,
+        EGLD_DECIMALS,
+        ERROR_INSUFFICIENT_DEPOSIT,
+    );
+}
+
+/// Borrows 50 EGLD, lets interest accrue for a year and repays half of the accrued interest
+/// under the given repayment order.
+///
+/// Returns the accrued interest before and after the repayment and the remaining debt.
+fn repay_half_interest(
+    principal_first: bool,
+) -> (BigUint<StaticApi>, BigUint<StaticApi>, BigUint<StaticApi>) {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+    state.set_repay_principal_first(EGLD_TOKEN, principal_first);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    state.change_timestamp(SECONDS_PER_DAY * 365);
+    let interest_before = state
+        .position_accrued_interest(2, EGLD_TOKEN, true)
+        .into_raw_units()
+        .clone();
+    state.repay_asset_deno(&borrower, &EGLD_TOKEN, interest_before.clone() / 2u64, 2);
+
+    let interest_after = state
+        .position_accrued_interest(2, EGLD_TOKEN, true)
+        .into_raw_units()
+        .clone();
+    let debt_after = state
+        .borrow_amount_for_token(2, EGLD_TOKEN)
+        .into_raw_units()
+        .clone();
+    (interest_before, interest_after, debt_after)
+}
+
+/// Tests that a partial repayment is split according to the market's repayment order.
+///
+/// Covers:
+/// - Controller::setRepayPrincipalFirst endpoint
+/// - Interest-first repayments leave the principal untouched by default
+/// - Principal-first repayments leave the accrued interest untouched
+/// - The remaining debt is the same under both orders
+#[test]
+fn repay_partial_application_order_success() {
+    let principal = scaled_amount(50, EGLD_DECIMALS);
+    let within = |value: &BigUint<StaticApi>, expected: &BigUint<StaticApi>| {
+        value.clone() + 3u64 >= *expected && *value <= expected.clone() + 3u64
+    };
+
+    let (interest, interest_after, debt_after) = repay_half_interest(false);
+    let repaid = interest.clone() / 2u64;
+    assert!(interest > BigUint::zero());
+    assert!(within(
+        &interest_after,
+        &(interest.clone() - repaid.clone())
+    ));
+    assert!(within(&(debt_after.clone() - interest_after), &principal));
+
+    let (interest_pf, interest_after_pf, debt_after_pf) = repay_half_interest(true);
+    assert_eq!(interest_pf, interest);
+    assert!(within(&interest_after_pf, &interest));
+    assert!(within(
+        &(debt_after_pf.clone() - interest_after_pf),
+        &(principal - repaid)
+    ));
+    assert_eq!(debt_after_pf, debt_after);
+}
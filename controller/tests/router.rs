@@ -1,8 +1,12 @@
-use controller::ERROR_NO_POOL_FOUND;
-use multiversx_sc::types::EgldOrEsdtTokenIdentifier;
+use common_constants::{MILLISECONDS_PER_YEAR, RAY};
+use controller::{
+    ERROR_INVALID_MAX_ACCRUAL_RATE, ERROR_INVALID_MIN_ACCRUAL_INTERVAL, ERROR_NO_POOL_FOUND,
+    ERROR_PRICE_FEED_STALE,
+};
+use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, ManagedAddress, TimestampMillis};
 use multiversx_sc_scenario::imports::{
-    BigUint, ExpectMessage, MultiValueEncoded, OptionalValue, ScenarioTxRun, TestAddress,
-    TestTokenIdentifier,
+    BigUint, ExpectMessage, MultiValueEncoded, OptionalValue, ScenarioTxRun, StaticApi,
+    TestAddress, TestTokenIdentifier,
 };
 use multiversx_sc_scenario::ScenarioTxWhitebox;
 
@@ -11,6 +15,7 @@ pub mod proxys;
 pub mod setup;
 use constants::*;
 use controller::storage::Storage;
+use liquidity_layer::storage::Storage as PoolStorage;
 use setup::*;
 
 #[test]
@@ -187,6 +192,66 @@ fn router_claim_revenue_runs_successfully() {
     assert_eq!(post_reserves, pre_reserves);
 }
 
+#[test]
+fn router_claimable_revenue_preview_matches_claim() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5_000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // Interest accrued since the last sync is included in the preview
+    state.change_timestamp(SECONDS_PER_DAY * 30);
+    let preview = state.claimable_revenue(EGLD_TOKEN);
+    assert!(preview.into_raw_units() > &BigUint::zero());
+
+    let pre_reserves = state
+        .market_reserves(state.egld_market.clone())
+        .as_raw_units()
+        .clone();
+    state.claim_revenue(EGLD_TOKEN);
+    let post_reserves = state
+        .market_reserves(state.egld_market.clone())
+        .as_raw_units()
+        .clone();
+    assert_eq!(&(pre_reserves - post_reserves), preview.into_raw_units());
+
+    // Nothing is left to claim right after the claim
+    assert_eq!(
+        state.claimable_revenue(EGLD_TOKEN).into_raw_units(),
+        &BigUint::zero()
+    );
+}
+
 #[test]
 fn router_claim_revenue_no_accumulator_error() {
     let mut state = LendingPoolTestState::new();
@@ -278,6 +343,103 @@ fn router_claim_revenue_no_accumulator_error() {
     assert_eq!(reserves_after, reserves_before);
 }
 
+#[test]
+fn router_claim_revenue_zero_accumulator_retains_revenue() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5_000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(20u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    state.change_timestamp(SECONDS_PER_DAY);
+    let mut markets = MultiValueEncoded::new();
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN));
+    state.update_markets(&borrower, markets);
+    let revenue_before = state
+        .market_revenue(state.egld_market.clone())
+        .as_raw_units()
+        .clone();
+    assert!(revenue_before > BigUint::zero());
+
+    // Accumulator left as the zero address, as when deployed without one
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(state.lending_sc.clone())
+        .whitebox(controller::contract_obj, |sc| {
+            sc.accumulator_address().set(ManagedAddress::zero());
+        });
+
+    let mut array = multiversx_sc::types::MultiValueEncoded::new();
+    array.push(EgldOrEsdtTokenIdentifier::esdt(
+        EGLD_TOKEN.to_esdt_token_identifier(),
+    ));
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(state.lending_sc.clone())
+        .typed(proxys::proxy_lending_pool::ControllerProxy)
+        .claim_revenue(array)
+        .returns(ExpectMessage(
+            core::str::from_utf8(controller::ERROR_NO_ACCUMULATOR_FOUND).unwrap(),
+        ))
+        .run();
+
+    // The revenue stays in the pool instead of being sent to the zero address
+    let revenue_after = state
+        .market_revenue(state.egld_market.clone())
+        .as_raw_units()
+        .clone();
+    assert_eq!(revenue_after, revenue_before);
+
+    // Once a real accumulator is configured the retained revenue can be claimed
+    let accumulator = state.accumulator_sc.clone();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(state.lending_sc.clone())
+        .typed(proxys::proxy_lending_pool::ControllerProxy)
+        .set_accumulator(accumulator)
+        .run();
+    state.claim_revenue(EGLD_TOKEN);
+    assert!(
+        state
+            .market_revenue(state.egld_market.clone())
+            .as_raw_units()
+            .clone()
+            < revenue_before
+    );
+}
+
 #[test]
 fn router_upgrade_liquidity_pool_mid_usage_keeps_state_and_rates() {
     let mut state = LendingPoolTestState::new();
@@ -484,6 +646,370 @@ fn router_upgrade_liquidity_pool_mid_usage_keeps_state_and_rates() {
     );
 }
 
+/// Supplies EGLD liquidity and opens a USDC-backed EGLD borrow on account 2.
+fn setup_egld_borrow(state: &mut LendingPoolTestState) {
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(1_000u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(10_000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(100u64),
+        2,
+        EGLD_DECIMALS,
+    );
+}
+
+fn egld_markets() -> MultiValueEncoded<StaticApi, EgldOrEsdtTokenIdentifier<StaticApi>> {
+    let mut markets = MultiValueEncoded::new();
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN));
+    markets
+}
+
+#[test]
+fn router_market_accrual_paused_freezes_indexes_success() {
+    let mut state = LendingPoolTestState::new();
+    setup_egld_borrow(&mut state);
+    let egld = EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN);
+    let pool_addr = state.egld_market.clone();
+
+    state.set_market_accrual_paused(&egld, true);
+    assert!(state.market_accrual_paused(pool_addr.clone()));
+
+    let paused_borrow_index = state.market_borrow_index(pool_addr.clone());
+    let paused_supply_index = state.market_supply_index(pool_addr.clone());
+    let paused_debt = state.total_borrow_in_egld(2);
+
+    // Neither syncs nor views advance the indexes while accrual is paused
+    state.change_timestamp(SECONDS_PER_DAY);
+    assert_eq!(state.total_borrow_in_egld(2), paused_debt);
+    state.update_markets(&OWNER_ADDRESS, egld_markets());
+    assert_eq!(
+        state.market_borrow_index(pool_addr.clone()),
+        paused_borrow_index
+    );
+    assert_eq!(
+        state.market_supply_index(pool_addr.clone()),
+        paused_supply_index
+    );
+
+    // Resuming never accrues the paused window
+    state.set_market_accrual_paused(&egld, false);
+    assert!(!state.market_accrual_paused(pool_addr.clone()));
+    assert_eq!(
+        state.market_borrow_index(pool_addr.clone()),
+        paused_borrow_index
+    );
+
+    // Accrual continues from the moment it resumed
+    state.change_timestamp(SECONDS_PER_DAY * 2);
+    state.update_markets(&OWNER_ADDRESS, egld_markets());
+    assert!(state.market_borrow_index(pool_addr.clone()) > paused_borrow_index);
+    assert!(state.market_supply_index(pool_addr) > paused_supply_index);
+    assert!(state.total_borrow_in_egld(2) > paused_debt);
+}
+
+#[test]
+fn router_upgrade_liquidity_pool_resumes_accrual_success() {
+    let mut state = LendingPoolTestState::new();
+    setup_egld_borrow(&mut state);
+    let pool_addr = state.egld_market.clone();
+    let initial_borrow_index = state.market_borrow_index(pool_addr.clone());
+
+    state.change_timestamp(SECONDS_PER_DAY);
+    upgrade_market_pool(&mut state, EGLD_TOKEN);
+
+    // Interest up to the upgrade is settled and accrual resumes once upgraded
+    assert!(!state.market_accrual_paused(pool_addr.clone()));
+    let upgraded_borrow_index = state.market_borrow_index(pool_addr.clone());
+    assert!(upgraded_borrow_index > initial_borrow_index);
+
+    state.change_timestamp(SECONDS_PER_DAY * 2);
+    state.update_markets(&OWNER_ADDRESS, egld_markets());
+    assert!(state.market_borrow_index(pool_addr) > upgraded_borrow_index);
+}
+
+/// Supplies 100 EGLD and borrows all of it against USDC collateral on account 2.
+fn setup_egld_full_utilization(state: &mut LendingPoolTestState) {
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(10_000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(100u64),
+        2,
+        EGLD_DECIMALS,
+    );
+}
+
+/// Tests the absolute borrow rate ceiling enforced at accrual.
+///
+/// Covers:
+/// - Borrow rate capped below the curve at 100% utilization
+/// - One year of accrual never exceeding the ceiling
+#[test]
+fn router_market_max_accrual_rate_caps_full_utilization_success() {
+    let mut state = LendingPoolTestState::new();
+    let egld = EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN);
+    let pool_addr = state.egld_market.clone();
+    // 20% annual, well below the 69% the curve reaches at full utilization
+    let max_accrual_rate = BigUint::<StaticApi>::from(RAY) / BigUint::from(5u64);
+
+    state.set_market_max_accrual_rate(&egld, max_accrual_rate.clone());
+    setup_egld_full_utilization(&mut state);
+    assert_eq!(
+        state.market_utilization(pool_addr.clone()).into_raw_units(),
+        &BigUint::from(RAY)
+    );
+
+    // The per-millisecond rate never exceeds the ceiling
+    let max_rate_per_ms =
+        max_accrual_rate / BigUint::from(MILLISECONDS_PER_YEAR) + BigUint::from(1u64);
+    let borrow_rate = state.market_borrow_rate(pool_addr.clone());
+    assert!(borrow_rate.into_raw_units() <= &max_rate_per_ms);
+
+    // One year at the ceiling grows the borrow index by at most e^0.2 ≈ 1.2214
+    let initial_borrow_index = state.market_borrow_index(pool_addr.clone());
+    state.change_timestamp(SECONDS_PER_YEAR);
+    state.update_markets(&OWNER_ADDRESS, egld_markets());
+    let borrow_index = state.market_borrow_index(pool_addr.clone());
+    assert!(borrow_index > initial_borrow_index);
+    assert!(
+        borrow_index.into_raw_units()
+            <= &(BigUint::from(RAY) * BigUint::from(12_215u64) / BigUint::from(10_000u64))
+    );
+    assert!(state.market_borrow_rate(pool_addr).into_raw_units() <= &max_rate_per_ms);
+}
+
+/// Tests validation of the absolute borrow rate ceiling against the rate curve.
+///
+/// Covers:
+/// - Ceiling above the max borrow rate rejected
+/// - Ceiling at or below the base borrow rate rejected
+/// - Ceiling equal to the max borrow rate and zero (disabled) accepted
+#[test]
+fn router_market_max_accrual_rate_validation_error() {
+    let mut state = LendingPoolTestState::new();
+    let egld = EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN);
+
+    state.set_market_max_accrual_rate_error(
+        &egld,
+        BigUint::from(R_MAX + 1),
+        ERROR_INVALID_MAX_ACCRUAL_RATE,
+    );
+    state.set_market_max_accrual_rate_error(
+        &egld,
+        BigUint::from(R_BASE),
+        ERROR_INVALID_MAX_ACCRUAL_RATE,
+    );
+
+    state.set_market_max_accrual_rate(&egld, BigUint::from(R_MAX));
+    state.set_market_max_accrual_rate(&egld, BigUint::zero());
+}
+
+/// Tests the minimum interval between index recomputations.
+///
+/// Covers:
+/// - Syncs within the interval keeping the last indexes and update timestamp
+/// - Skipped time accruing on the next real sync, matching a market without interval
+#[test]
+fn router_market_min_accrual_interval_skips_short_syncs_success() {
+    let egld = EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN);
+
+    let mut state = LendingPoolTestState::new();
+    setup_egld_borrow(&mut state);
+    let pool_addr = state.egld_market.clone();
+    state.set_market_min_accrual_interval(&egld, SECONDS_PER_HOUR * 1_000);
+
+    let initial_borrow_index = state.market_borrow_index(pool_addr.clone());
+    let initial_supply_index = state.market_supply_index(pool_addr.clone());
+    let initial_debt = state.total_borrow_in_egld(2);
+    let initial_last_timestamp = state.market_last_timestamp(pool_addr.clone());
+
+    // Half an hour in, syncs and views keep serving the last indexes
+    state.change_timestamp(SECONDS_PER_HOUR / 2);
+    state.update_markets(&OWNER_ADDRESS, egld_markets());
+    assert_eq!(
+        state.market_borrow_index(pool_addr.clone()),
+        initial_borrow_index
+    );
+    assert_eq!(
+        state.market_supply_index(pool_addr.clone()),
+        initial_supply_index
+    );
+    assert_eq!(
+        state.market_last_timestamp(pool_addr.clone()),
+        initial_last_timestamp
+    );
+    assert_eq!(state.total_borrow_in_egld(2), initial_debt);
+
+    // Once the interval elapsed the whole hour accrues
+    state.change_timestamp(SECONDS_PER_HOUR);
+    state.update_markets(&OWNER_ADDRESS, egld_markets());
+    let borrow_index = state.market_borrow_index(pool_addr.clone());
+    let supply_index = state.market_supply_index(pool_addr.clone());
+    assert!(borrow_index > initial_borrow_index);
+    assert_eq!(
+        state.market_last_timestamp(pool_addr),
+        TimestampMillis::new(SECONDS_PER_HOUR * 1_000)
+    );
+
+    // A market recomputing on every sync ends up on the same indexes
+    let mut control = LendingPoolTestState::new();
+    setup_egld_borrow(&mut control);
+    let control_addr = control.egld_market.clone();
+    control.change_timestamp(SECONDS_PER_HOUR);
+    control.update_markets(&OWNER_ADDRESS, egld_markets());
+    assert_eq!(
+        control.market_borrow_index(control_addr.clone()),
+        borrow_index
+    );
+    assert_eq!(control.market_supply_index(control_addr), supply_index);
+}
+
+#[test]
+fn router_market_min_accrual_interval_too_long_error() {
+    let mut state = LendingPoolTestState::new();
+    let egld = EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN);
+
+    state.set_market_min_accrual_interval_error(
+        &egld,
+        SECONDS_PER_HOUR * 1_000 + 1,
+        ERROR_INVALID_MIN_ACCRUAL_INTERVAL,
+    );
+}
+
+#[test]
+fn router_upgrade_liquidity_pool_with_stale_oracle_success() {
+    let mut state = LendingPoolTestState::new();
+    setup_egld_borrow(&mut state);
+    let pool_addr = state.usdc_market.clone();
+
+    // The market price is stale, yet pausing the pool for the upgrade needs no price
+    state.set_egld_max_price_stale_seconds(600);
+    state.world.current_block().block_timestamp_seconds(601);
+    state.usd_price_error(USDC_TOKEN, ERROR_PRICE_FEED_STALE);
+
+    upgrade_market_pool(&mut state, USDC_TOKEN);
+
+    assert!(!state.market_accrual_paused(pool_addr.clone()));
+    assert_eq!(
+        state.market_last_timestamp(pool_addr),
+        TimestampMillis::new(601_000)
+    );
+}
+
+#[test]
+fn router_upgrade_liquidity_pool_with_old_storage_layout_success() {
+    let mut state = LendingPoolTestState::new();
+    setup_egld_borrow(&mut state);
+    let pool_addr = state.egld_market.clone();
+
+    // Drop every mapper the original pool layout did not have
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(pool_addr.clone())
+        .whitebox(liquidity_layer::contract_obj, |sc| {
+            sc.accumulated_rounding().clear();
+            sc.min_initial_supply().clear();
+            sc.accrual_paused().clear();
+            sc.revenue_absorbs_bad_debt().clear();
+            sc.rate_model_type().clear();
+            sc.reserve_factor_high().clear();
+            sc.max_accrual_rate().clear();
+            sc.min_accrual_interval().clear();
+        });
+
+    let pre_borrow_rate = state.market_borrow_rate(pool_addr.clone());
+    let pre_supplied = state.market_supplied_amount(pool_addr.clone());
+    let pre_borrowed = state.market_borrowed_amount(pool_addr.clone());
+    let pre_borrow_index = state.market_borrow_index(pool_addr.clone());
+    let pre_supply_index = state.market_supply_index(pool_addr.clone());
+
+    upgrade_market_pool(&mut state, EGLD_TOKEN);
+
+    // The upgraded pool reads the old storage and comes back with accrual running
+    assert!(!state.market_accrual_paused(pool_addr.clone()));
+    assert_eq!(state.market_borrow_rate(pool_addr.clone()), pre_borrow_rate);
+    assert_eq!(
+        state.market_supplied_amount(pool_addr.clone()),
+        pre_supplied
+    );
+    assert_eq!(
+        state.market_borrowed_amount(pool_addr.clone()),
+        pre_borrowed
+    );
+
+    // Accrual continues with the default rate settings
+    state.change_timestamp(SECONDS_PER_DAY);
+    state.update_markets(&OWNER_ADDRESS, egld_markets());
+    assert!(state.market_borrow_index(pool_addr.clone()) > pre_borrow_index);
+    assert!(state.market_supply_index(pool_addr) > pre_supply_index);
+}
+
+/// Upgrades the liquidity pool of a market through the controller.
+fn upgrade_market_pool(state: &mut LendingPoolTestState, token: TestTokenIdentifier) {
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(state.lending_sc.clone())
+        .typed(proxys::proxy_lending_pool::ControllerProxy)
+        .upgrade_liquidity_pool(EgldOrEsdtTokenIdentifier::esdt(
+            token.to_esdt_token_identifier(),
+        ))
+        .run();
+}
+
 #[test]
 fn router_create_liquidity_pool_asset_already_supported_error() {
     let mut state = LendingPoolTestState::new();
@@ -601,3 +1127,103 @@ fn router_create_liquidity_pool_invalid_liquidation_threshold_error() {
         controller::ERROR_INVALID_LIQUIDATION_THRESHOLD,
     );
 }
+
+/// Tests that a market created with a reserve buffer handles its very first operations.
+///
+/// Covers:
+/// - Controller::createLiquidityPool with `min_initial_supply`
+/// - First supply into an empty pool burning the buffer instead of dividing by zero supply
+/// - First borrow against the freshly seeded pool with exact accounting
+#[test]
+fn router_create_liquidity_pool_with_reserve_buffer_first_operations() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+    state.world.set_esdt_balance(
+        supplier.to_managed_address(),
+        WEGLD_TOKEN.as_bytes(),
+        scaled_amount(1_000, EGLD_DECIMALS),
+    );
+
+    // WEGLD has an oracle configured in setup but no pool yet
+    let wegld_market = state.add_new_market(
+        EgldOrEsdtTokenIdentifier::esdt(WEGLD_TOKEN.to_esdt_token_identifier()),
+        get_egld_config().config,
+        MarketRateParams {
+            max_borrow_rate: BigUint::from(R_MAX),
+            base_borrow_rate: BigUint::from(R_BASE),
+            slope1: BigUint::from(R_SLOPE1),
+            slope2: BigUint::from(R_SLOPE2),
+            slope3: BigUint::from(R_SLOPE3),
+            mid_utilization: BigUint::from(U_MID),
+            optimal_utilization: BigUint::from(U_OPTIMAL),
+            reserve_factor: BigUint::from(RESERVE_FACTOR),
+        },
+        EGLD_DECIMALS,
+        OptionalValue::Some(scaled_amount(1, EGLD_DECIMALS)),
+    );
+    assert_eq!(
+        state
+            .market_utilization(wegld_market.clone())
+            .into_raw_units(),
+        &BigUint::zero()
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: WEGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    // The buffer stays in the pool without an owner
+    state.assert_collateral_raw_eq(
+        1,
+        &WEGLD_TOKEN,
+        scaled_amount(99, EGLD_DECIMALS),
+        "first supplier must be credited the deposit minus the buffer",
+    );
+    assert_eq!(
+        state
+            .market_supplied_amount(wegld_market.clone())
+            .into_raw_units(),
+        &scaled_amount(100, EGLD_DECIMALS)
+    );
+
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        WEGLD_TOKEN,
+        BigUint::from(10u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    state.assert_borrow_raw_eq(
+        2,
+        &WEGLD_TOKEN,
+        scaled_amount(10, EGLD_DECIMALS),
+        "first borrow must be tracked exactly",
+    );
+    assert_eq!(
+        state
+            .market_borrowed_amount(wegld_market.clone())
+            .into_raw_units(),
+        &scaled_amount(10, EGLD_DECIMALS)
+    );
+    assert!(state.market_utilization(wegld_market).into_raw_units() > &BigUint::zero());
+}
@@ -8,11 +8,11 @@ use crate::{
 use common_constants::{EGLD_TICKER, MIN_FIRST_TOLERANCE, MIN_LAST_TOLERANCE};
 
 use multiversx_sc::{
-    imports::{MultiValue2, OptionalValue},
+    imports::{MultiValue10, MultiValue2, MultiValue3, OptionalValue},
     types::{
-        BigUint, DurationSeconds, EgldOrEsdtTokenPayment, ManagedAddress, ManagedArgBuffer,
-        ManagedBuffer, ManagedDecimal, MultiValueEncoded, NumDecimals, ReturnsNewManagedAddress,
-        ReturnsResult, TestTokenIdentifier,
+        BigUint, DurationMillis, DurationSeconds, EgldOrEsdtTokenPayment, ManagedAddress,
+        ManagedArgBuffer, ManagedBuffer, ManagedDecimal, MultiValueEncoded, NumDecimals,
+        ReturnsNewManagedAddress, ReturnsResult, TestTokenIdentifier,
     },
 };
 use multiversx_sc_scenario::{
@@ -31,7 +31,7 @@ use storage::Storage;
 use common_structs::{AccountAttributes, OracleProvider};
 use controller::*;
 use multiversx_sc::types::{
-    EgldOrEsdtTokenIdentifier, EsdtLocalRole, EsdtTokenPayment, ManagedVec,
+    EgldOrEsdtTokenIdentifier, EsdtLocalRole, EsdtTokenPayment, ManagedVec, TimestampMillis,
     TimestampSeconds,
 };
 use multiversx_sc_scenario::imports::{ExpectMessage, TestAddress};
@@ -106,6 +106,64 @@ pub struct TokenOracleParams {
     pub one_dex_pair_id: OptionalValue<usize>,
 }
 
+/// Oracle entry encoding accepted by `batchSetTokenOracle`.
+pub type TokenOracleEntry = MultiValue10<
+    EgldOrEsdtTokenIdentifier<StaticApi>,
+    usize,
+    ManagedAddress<StaticApi>,
+    PricingMethod,
+    OracleType,
+    ExchangeSource,
+    BigUint<StaticApi>,
+    BigUint<StaticApi>,
+    DurationSeconds,
+    usize,
+>;
+
+/// Encodes an ordered collateral preference for `liquidate`; an empty list lets the protocol choose.
+fn preferred_collaterals(
+    tokens: &[TestTokenIdentifier],
+) -> OptionalValue<MultiValueEncoded<StaticApi, EgldOrEsdtTokenIdentifier<StaticApi>>> {
+    if tokens.is_empty() {
+        return OptionalValue::None;
+    }
+
+    let mut encoded = MultiValueEncoded::new();
+    for token in tokens {
+        encoded.push(EgldOrEsdtTokenIdentifier::esdt(token.to_token_identifier()));
+    }
+    OptionalValue::Some(encoded)
+}
+
+/// Encodes `(market_token, params)` pairs as `batchSetTokenOracle` entries.
+fn token_oracle_entries(
+    entries: Vec<(EgldOrEsdtTokenIdentifier<StaticApi>, TokenOracleParams)>,
+) -> MultiValueEncoded<StaticApi, TokenOracleEntry> {
+    let mut encoded = MultiValueEncoded::new();
+    for (market_token, params) in entries {
+        let one_dex_pair_id = match params.one_dex_pair_id {
+            OptionalValue::Some(id) => id,
+            OptionalValue::None => 0,
+        };
+        encoded.push(
+            (
+                market_token,
+                params.decimals,
+                params.contract_address,
+                params.pricing_method,
+                params.oracle_type,
+                params.source,
+                params.first_tolerance,
+                params.last_tolerance,
+                params.max_price_stale_seconds,
+                one_dex_pair_id,
+            )
+                .into(),
+        );
+    }
+    encoded
+}
+
 /// Interest rate model parameters used when creating or upgrading a market.
 #[derive(Clone)]
 pub struct MarketRateParams {
@@ -202,6 +260,26 @@ pub struct RepayWithCollateralParams {
     pub account_payment: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
 }
 
+/// Parameters describing a repay-with-swap call in the test harness.
+#[derive(Clone)]
+pub struct RepayWithSwapParams {
+    pub account_nonce: u64,
+    pub debt_token: EgldOrEsdtTokenIdentifier<StaticApi>,
+    pub payment_token: EgldOrEsdtTokenIdentifier<StaticApi>,
+    pub steps: ManagedArgBuffer<StaticApi>,
+    pub payments: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+}
+
+/// Parameters describing a deleverage call in the test harness.
+#[derive(Clone)]
+pub struct DeleverageParams {
+    pub collateral_token: EgldOrEsdtTokenIdentifier<StaticApi>,
+    pub debt_token: EgldOrEsdtTokenIdentifier<StaticApi>,
+    pub fraction_bps: u64,
+    pub steps: ManagedArgBuffer<StaticApi>,
+    pub payments: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+}
+
 /// Token configuration for deploying a xExchange pair in the test harness.
 #[derive(Clone)]
 pub struct DeployPairParams {
@@ -359,6 +437,30 @@ impl LendingPoolTestState {
             .run();
     }
 
+    /// Supply native EGLD to the lending pool
+    pub fn supply_asset_egld(
+        &mut self,
+        from: &TestAddress,
+        amount: BigUint<StaticApi>,
+        account_nonce: OptionalValue<u64>,
+        e_mode_category: OptionalValue<u8>,
+    ) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .supply(
+                match account_nonce.into_option() {
+                    Some(nonce) => OptionalValue::Some(nonce),
+                    None => OptionalValue::Some(0),
+                },
+                e_mode_category,
+            )
+            .egld(amount)
+            .run();
+    }
+
     /// Supply asset with error expectation
     pub fn supply_asset_error(
         &mut self,
@@ -394,6 +496,54 @@ impl LendingPoolTestState {
             .run();
     }
 
+    /// Supply asset (base units) to an account held by another address
+    pub fn supply_for(
+        &mut self,
+        from: &TestAddress,
+        beneficiary: &TestAddress,
+        account_nonce: u64,
+        token_id: TestTokenIdentifier,
+        amount: BigUint<StaticApi>,
+    ) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .supply_for(beneficiary.to_managed_address(), account_nonce)
+            .payment(EsdtTokenPayment::new(
+                token_id.to_esdt_token_identifier(),
+                0,
+                amount,
+            ))
+            .run();
+    }
+
+    /// Supply asset (base units) to an account held by another address with error expectation
+    pub fn supply_for_error(
+        &mut self,
+        from: &TestAddress,
+        beneficiary: &TestAddress,
+        account_nonce: u64,
+        token_id: TestTokenIdentifier,
+        amount: BigUint<StaticApi>,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .supply_for(beneficiary.to_managed_address(), account_nonce)
+            .payment(EsdtTokenPayment::new(
+                token_id.to_esdt_token_identifier(),
+                0,
+                amount,
+            ))
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
     /// Supply multiple assets in bulk
     pub fn supply_bulk(
         &mut self,
@@ -696,6 +846,32 @@ impl LendingPoolTestState {
             .run();
     }
 
+    /// Borrow native EGLD from the lending pool
+    pub fn borrow_asset_egld(
+        &mut self,
+        from: &TestAddress,
+        amount: BigUint<StaticApi>,
+        account_nonce: u64,
+    ) {
+        let asset = EgldOrEsdtTokenPayment::new(EgldOrEsdtTokenIdentifier::egld(), 0, amount);
+        let mut array: MultiValueEncoded<StaticApi, EgldOrEsdtTokenPayment<StaticApi>> =
+            MultiValueEncoded::new();
+        array.push(asset);
+
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .borrow(array)
+            .payment(EsdtTokenPayment::new(
+                ACCOUNT_TOKEN.to_esdt_token_identifier(),
+                account_nonce,
+                BigUint::from(1u64),
+            ))
+            .run();
+    }
+
     pub fn borrow_asset_den(
         &mut self,
         from: &TestAddress,
@@ -804,6 +980,98 @@ impl LendingPoolTestState {
             .run();
     }
 
+    /// Borrow multiple assets with a minimum resulting health factor
+    pub fn borrow_assets_with_min_health_factor(
+        &mut self,
+        account_nonce: u64,
+        from: &TestAddress,
+        min_health_factor_after: BigUint<StaticApi>,
+        assets: MultiValueEncoded<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+    ) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .borrow_with_min_health_factor(min_health_factor_after, assets)
+            .payment(EsdtTokenPayment::new(
+                ACCOUNT_TOKEN.to_esdt_token_identifier(),
+                account_nonce,
+                BigUint::from(1u64),
+            ))
+            .run();
+    }
+
+    /// Borrow multiple assets with a minimum resulting health factor and error expectation
+    pub fn borrow_assets_with_min_health_factor_error(
+        &mut self,
+        account_nonce: u64,
+        from: &TestAddress,
+        min_health_factor_after: BigUint<StaticApi>,
+        assets: MultiValueEncoded<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .borrow_with_min_health_factor(min_health_factor_after, assets)
+            .payment(EsdtTokenPayment::new(
+                ACCOUNT_TOKEN.to_esdt_token_identifier(),
+                account_nonce,
+                BigUint::from(1u64),
+            ))
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Borrow multiple assets with a maximum resulting borrow rate
+    pub fn borrow_assets_with_max_rate(
+        &mut self,
+        account_nonce: u64,
+        from: &TestAddress,
+        max_acceptable_rate: BigUint<StaticApi>,
+        assets: MultiValueEncoded<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+    ) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .borrow_with_max_rate(max_acceptable_rate, assets)
+            .payment(EsdtTokenPayment::new(
+                ACCOUNT_TOKEN.to_esdt_token_identifier(),
+                account_nonce,
+                BigUint::from(1u64),
+            ))
+            .run();
+    }
+
+    /// Borrow multiple assets with a maximum resulting borrow rate and error expectation
+    pub fn borrow_assets_with_max_rate_error(
+        &mut self,
+        account_nonce: u64,
+        from: &TestAddress,
+        max_acceptable_rate: BigUint<StaticApi>,
+        assets: MultiValueEncoded<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .borrow_with_max_rate(max_acceptable_rate, assets)
+            .payment(EsdtTokenPayment::new(
+                ACCOUNT_TOKEN.to_esdt_token_identifier(),
+                account_nonce,
+                BigUint::from(1u64),
+            ))
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
     /// Repay borrowed asset
     pub fn repay_asset(
         &mut self,
@@ -819,7 +1087,7 @@ impl LendingPoolTestState {
             .from(from.to_managed_address())
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .repay(account_nonce)
+            .repay(OptionalValue::Some(account_nonce))
             .payment(EsdtTokenPayment::new(
                 token.to_esdt_token_identifier(),
                 0,
@@ -828,6 +1096,38 @@ impl LendingPoolTestState {
             .run();
     }
 
+    /// Repay borrowed asset attaching the account NFT instead of passing its nonce
+    pub fn repay_asset_with_account(
+        &mut self,
+        from: &TestAddress,
+        token: &TestTokenIdentifier,
+        amount: BigUint<StaticApi>,
+        account_nonce: u64,
+        asset_decimals: usize,
+    ) {
+        let amount_to_transfer = amount.mul(BigUint::from(10u64).pow(asset_decimals as u32));
+        let mut payments = ManagedVec::<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>::new();
+        payments.push(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(ACCOUNT_TOKEN.to_esdt_token_identifier()),
+            account_nonce,
+            BigUint::from(1u64),
+        ));
+        payments.push(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(token.to_esdt_token_identifier()),
+            0,
+            amount_to_transfer,
+        ));
+
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .repay(OptionalValue::<u64>::None)
+            .payment(payments)
+            .run();
+    }
+
     /// Repay asset denominated in base units
     pub fn repay_asset_deno(
         &mut self,
@@ -841,7 +1141,7 @@ impl LendingPoolTestState {
             .from(from.to_managed_address())
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .repay(account_nonce)
+            .repay(OptionalValue::Some(account_nonce))
             .payment(EsdtTokenPayment::new(
                 token.to_esdt_token_identifier(),
                 0,
@@ -866,7 +1166,7 @@ impl LendingPoolTestState {
             .from(from.to_managed_address())
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .repay(account_nonce)
+            .repay(OptionalValue::Some(account_nonce))
             .payment(EsdtTokenPayment::new(
                 token.to_esdt_token_identifier(),
                 0,
@@ -876,92 +1176,335 @@ impl LendingPoolTestState {
             .run();
     }
 
-    /// Liquidate an account position
-    pub fn liquidate_account(
+    /// Close an account: repay debt, withdraw all collateral and burn the NFT
+    pub fn close_account(
         &mut self,
         from: &TestAddress,
-        liquidator_payment: &TestTokenIdentifier,
-        amount: BigUint<StaticApi>,
         account_nonce: u64,
-        asset_decimals: usize,
+        repay_payments: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
     ) {
-        let amount_to_transfer = amount.mul(BigUint::from(10u64).pow(asset_decimals as u32));
-        let mut vec = ManagedVec::<StaticApi, EsdtTokenPayment<StaticApi>>::new();
-        vec.push(EsdtTokenPayment::new(
-            liquidator_payment.to_esdt_token_identifier(),
-            0,
-            amount_to_transfer,
+        let mut payments = ManagedVec::<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>::new();
+        payments.push(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(ACCOUNT_TOKEN.to_esdt_token_identifier()),
+            account_nonce,
+            BigUint::from(1u64),
         ));
+        payments.append_vec(repay_payments);
 
         self.world
             .tx()
             .from(from.to_managed_address())
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .liquidate(account_nonce)
-            .payment(vec)
+            .close_account()
+            .payment(payments)
             .run();
     }
 
-    /// Liquidate account denominated in base units
-    pub fn liquidate_account_den(
+    /// Close an account with error expectation
+    pub fn close_account_error(
         &mut self,
         from: &TestAddress,
-        liquidator_payment: &TestTokenIdentifier,
-        amount_to_transfer: BigUint<StaticApi>,
         account_nonce: u64,
+        repay_payments: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+        error_message: &[u8],
     ) {
-        let mut vec = ManagedVec::<StaticApi, EsdtTokenPayment<StaticApi>>::new();
-        vec.push(EsdtTokenPayment::new(
-            liquidator_payment.to_esdt_token_identifier(),
-            0,
-            amount_to_transfer,
+        let mut payments = ManagedVec::<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>::new();
+        payments.push(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(ACCOUNT_TOKEN.to_esdt_token_identifier()),
+            account_nonce,
+            BigUint::from(1u64),
         ));
+        payments.append_vec(repay_payments);
 
         self.world
             .tx()
             .from(from.to_managed_address())
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .liquidate(account_nonce)
-            .payment(vec)
+            .close_account()
+            .payment(payments)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
             .run();
     }
 
-    /// Liquidate account with multiple payments
-    pub fn liquidate_account_dem(
+    /// Repay debt and withdraw collateral in a single transaction
+    pub fn repay_and_withdraw(
         &mut self,
         from: &TestAddress,
-        liquidator_payment: &TestTokenIdentifier,
-        amount: BigUint<StaticApi>,
         account_nonce: u64,
+        repay_payments: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+        collaterals: MultiValueEncoded<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
     ) {
-        let mut vec = ManagedVec::<StaticApi, EsdtTokenPayment<StaticApi>>::new();
-        vec.push(EsdtTokenPayment::new(
-            liquidator_payment.to_esdt_token_identifier(),
-            0,
-            amount,
+        let mut payments = ManagedVec::<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>::new();
+        payments.push(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(ACCOUNT_TOKEN.to_esdt_token_identifier()),
+            account_nonce,
+            BigUint::from(1u64),
         ));
+        payments.append_vec(repay_payments);
 
         self.world
             .tx()
             .from(from.to_managed_address())
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .liquidate(account_nonce)
-            .payment(vec)
+            .repay_and_withdraw(collaterals)
+            .payment(payments)
             .run();
     }
 
-    /// Liquidate account with bulk payments
-    pub fn liquidate_account_dem_bulk(
+    /// Repay debt and withdraw collateral with error expectation
+    pub fn repay_and_withdraw_error(
         &mut self,
         from: &TestAddress,
-        payments: Vec<(&TestTokenIdentifier, &BigUint<StaticApi>)>,
         account_nonce: u64,
+        repay_payments: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+        collaterals: MultiValueEncoded<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+        error_message: &[u8],
     ) {
-        let mut vec = ManagedVec::<StaticApi, EsdtTokenPayment<StaticApi>>::new();
-        for (token, amount) in payments {
+        let mut payments = ManagedVec::<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>::new();
+        payments.push(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(ACCOUNT_TOKEN.to_esdt_token_identifier()),
+            account_nonce,
+            BigUint::from(1u64),
+        ));
+        payments.append_vec(repay_payments);
+
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .repay_and_withdraw(collaterals)
+            .payment(payments)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Repay debt with the account's deposit of the same token
+    pub fn self_repay_from_supply(
+        &mut self,
+        from: &TestAddress,
+        token_id: TestTokenIdentifier,
+        amount: BigUint<StaticApi>,
+        account_nonce: u64,
+        asset_decimals: usize,
+    ) {
+        let transfer = EsdtTokenPayment::new(
+            ACCOUNT_TOKEN.to_esdt_token_identifier(),
+            account_nonce,
+            BigUint::from(1u64),
+        );
+        let amount_to_repay = amount.mul(BigUint::from(10u64).pow(asset_decimals as u32));
+
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .self_repay_from_supply(
+                EgldOrEsdtTokenIdentifier::esdt(token_id.to_esdt_token_identifier()),
+                amount_to_repay,
+            )
+            .payment(transfer)
+            .run();
+    }
+
+    /// Repay debt with the account's deposit of the same token with error expectation
+    pub fn self_repay_from_supply_error(
+        &mut self,
+        from: &TestAddress,
+        token_id: TestTokenIdentifier,
+        amount: BigUint<StaticApi>,
+        account_nonce: u64,
+        asset_decimals: usize,
+        error_message: &[u8],
+    ) {
+        let transfer = EsdtTokenPayment::new(
+            ACCOUNT_TOKEN.to_esdt_token_identifier(),
+            account_nonce,
+            BigUint::from(1u64),
+        );
+        let amount_to_repay = amount.mul(BigUint::from(10u64).pow(asset_decimals as u32));
+
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .self_repay_from_supply(
+                EgldOrEsdtTokenIdentifier::esdt(token_id.to_esdt_token_identifier()),
+                amount_to_repay,
+            )
+            .payment(transfer)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Roll an account to another e-mode category, repaying debt and adding collateral
+    pub fn roll_e_mode(
+        &mut self,
+        from: &TestAddress,
+        account_nonce: u64,
+        new_category: u8,
+        repay_payments: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+        collaterals: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+    ) {
+        let repay_count = repay_payments.len();
+        let mut payments = ManagedVec::<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>::new();
+        payments.push(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(ACCOUNT_TOKEN.to_esdt_token_identifier()),
+            account_nonce,
+            BigUint::from(1u64),
+        ));
+        payments.append_vec(repay_payments);
+        payments.append_vec(collaterals);
+
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .roll_e_mode(new_category, repay_count)
+            .payment(payments)
+            .run();
+    }
+
+    /// Roll an account to another e-mode category with error expectation
+    pub fn roll_e_mode_error(
+        &mut self,
+        from: &TestAddress,
+        account_nonce: u64,
+        new_category: u8,
+        repay_payments: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+        collaterals: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+        error_message: &[u8],
+    ) {
+        let repay_count = repay_payments.len();
+        let mut payments = ManagedVec::<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>::new();
+        payments.push(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(ACCOUNT_TOKEN.to_esdt_token_identifier()),
+            account_nonce,
+            BigUint::from(1u64),
+        ));
+        payments.append_vec(repay_payments);
+        payments.append_vec(collaterals);
+
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .roll_e_mode(new_category, repay_count)
+            .payment(payments)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Liquidate an account position
+    pub fn liquidate_account(
+        &mut self,
+        from: &TestAddress,
+        liquidator_payment: &TestTokenIdentifier,
+        amount: BigUint<StaticApi>,
+        account_nonce: u64,
+        asset_decimals: usize,
+    ) {
+        let amount_to_transfer = amount.mul(BigUint::from(10u64).pow(asset_decimals as u32));
+        let mut vec = ManagedVec::<StaticApi, EsdtTokenPayment<StaticApi>>::new();
+        vec.push(EsdtTokenPayment::new(
+            liquidator_payment.to_esdt_token_identifier(),
+            0,
+            amount_to_transfer,
+        ));
+
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .liquidate(account_nonce, preferred_collaterals(&[]))
+            .payment(vec)
+            .run();
+    }
+
+    /// Liquidate an account position paying the debt in native EGLD
+    pub fn liquidate_account_egld(
+        &mut self,
+        from: &TestAddress,
+        amount: BigUint<StaticApi>,
+        account_nonce: u64,
+    ) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .liquidate(account_nonce, preferred_collaterals(&[]))
+            .egld(amount)
+            .run();
+    }
+
+    /// Liquidate account denominated in base units
+    pub fn liquidate_account_den(
+        &mut self,
+        from: &TestAddress,
+        liquidator_payment: &TestTokenIdentifier,
+        amount_to_transfer: BigUint<StaticApi>,
+        account_nonce: u64,
+    ) {
+        let mut vec = ManagedVec::<StaticApi, EsdtTokenPayment<StaticApi>>::new();
+        vec.push(EsdtTokenPayment::new(
+            liquidator_payment.to_esdt_token_identifier(),
+            0,
+            amount_to_transfer,
+        ));
+
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .liquidate(account_nonce, preferred_collaterals(&[]))
+            .payment(vec)
+            .run();
+    }
+
+    /// Liquidate account with multiple payments
+    pub fn liquidate_account_dem(
+        &mut self,
+        from: &TestAddress,
+        liquidator_payment: &TestTokenIdentifier,
+        amount: BigUint<StaticApi>,
+        account_nonce: u64,
+    ) {
+        let mut vec = ManagedVec::<StaticApi, EsdtTokenPayment<StaticApi>>::new();
+        vec.push(EsdtTokenPayment::new(
+            liquidator_payment.to_esdt_token_identifier(),
+            0,
+            amount,
+        ));
+
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .liquidate(account_nonce, preferred_collaterals(&[]))
+            .payment(vec)
+            .run();
+    }
+
+    /// Liquidate account with bulk payments
+    pub fn liquidate_account_dem_bulk(
+        &mut self,
+        from: &TestAddress,
+        payments: Vec<(&TestTokenIdentifier, &BigUint<StaticApi>)>,
+        account_nonce: u64,
+    ) {
+        let mut vec = ManagedVec::<StaticApi, EsdtTokenPayment<StaticApi>>::new();
+        for (token, amount) in payments {
             vec.push(EsdtTokenPayment::new(
                 token.to_esdt_token_identifier(),
                 0,
@@ -974,7 +1517,35 @@ impl LendingPoolTestState {
             .from(from.to_managed_address())
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .liquidate(account_nonce)
+            .liquidate(account_nonce, preferred_collaterals(&[]))
+            .payment(vec)
+            .run();
+    }
+
+    /// Liquidate account seizing the given collateral tokens first
+    pub fn liquidate_account_with_preference(
+        &mut self,
+        from: &TestAddress,
+        liquidator_payment: &TestTokenIdentifier,
+        amount: BigUint<StaticApi>,
+        account_nonce: u64,
+        asset_decimals: usize,
+        preferred: &[TestTokenIdentifier],
+    ) {
+        let amount_to_transfer = amount.mul(BigUint::from(10u64).pow(asset_decimals as u32));
+        let mut vec = ManagedVec::<StaticApi, EsdtTokenPayment<StaticApi>>::new();
+        vec.push(EsdtTokenPayment::new(
+            liquidator_payment.to_esdt_token_identifier(),
+            0,
+            amount_to_transfer,
+        ));
+
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .liquidate(account_nonce, preferred_collaterals(preferred))
             .payment(vec)
             .run();
     }
@@ -1002,7 +1573,7 @@ impl LendingPoolTestState {
             .from(from.to_managed_address())
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .liquidate(account_nonce)
+            .liquidate(account_nonce, preferred_collaterals(&[]))
             .payment(vec)
             .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
             .run();
@@ -1106,44 +1677,167 @@ impl LendingPoolTestState {
             .run();
     }
 
-    // ============================================
-    // CONFIGURATION ENDPOINTS
-    // ============================================
+    /// Opt an account in or out of auto-compounding
+    pub fn set_auto_compound(&mut self, from: &TestAddress, account_nonce: u64, enabled: bool) {
+        let transfer = EsdtTokenPayment::new(
+            ACCOUNT_TOKEN.to_esdt_token_identifier(),
+            account_nonce,
+            BigUint::from(1u64),
+        );
 
-    /// Register account token
-    pub fn register_account_token(
-        &mut self,
-        token_name: ManagedBuffer<StaticApi>,
-        ticker: ManagedBuffer<StaticApi>,
-        payment_amount: BigUint<StaticApi>,
-    ) {
         self.world
             .tx()
-            .from(OWNER_ADDRESS)
+            .from(from.to_managed_address())
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .register_account_token(token_name, ticker)
-            .egld(payment_amount)
+            .set_auto_compound(enabled)
+            .payment(transfer)
             .run();
     }
 
-    /// Set token oracle configuration
-    pub fn set_token_oracle(
+    /// Exclude a deposit from collateral
+    pub fn disable_as_collateral(
         &mut self,
-        market_token: &EgldOrEsdtTokenIdentifier<StaticApi>,
-        params: TokenOracleParams,
+        from: &TestAddress,
+        account_nonce: u64,
+        token_id: TestTokenIdentifier,
     ) {
+        let transfer = EsdtTokenPayment::new(
+            ACCOUNT_TOKEN.to_esdt_token_identifier(),
+            account_nonce,
+            BigUint::from(1u64),
+        );
+
         self.world
             .tx()
-            .from(OWNER_ADDRESS)
+            .from(from.to_managed_address())
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .set_token_oracle(
-                market_token.clone(),
-                params.decimals,
-                params.contract_address,
-                params.pricing_method,
-                params.oracle_type,
+            .disable_as_collateral(token_id)
+            .payment(transfer)
+            .run();
+    }
+
+    /// Exclude a deposit from collateral with error expectation
+    pub fn disable_as_collateral_error(
+        &mut self,
+        from: &TestAddress,
+        account_nonce: u64,
+        token_id: TestTokenIdentifier,
+        error_message: &[u8],
+    ) {
+        let transfer = EsdtTokenPayment::new(
+            ACCOUNT_TOKEN.to_esdt_token_identifier(),
+            account_nonce,
+            BigUint::from(1u64),
+        );
+
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .disable_as_collateral(token_id)
+            .payment(transfer)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Include a supply-only deposit in collateral again
+    pub fn enable_as_collateral(
+        &mut self,
+        from: &TestAddress,
+        account_nonce: u64,
+        token_id: TestTokenIdentifier,
+    ) {
+        let transfer = EsdtTokenPayment::new(
+            ACCOUNT_TOKEN.to_esdt_token_identifier(),
+            account_nonce,
+            BigUint::from(1u64),
+        );
+
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .enable_as_collateral(token_id)
+            .payment(transfer)
+            .run();
+    }
+
+    /// Compound accrued supply interest of a deposit
+    pub fn compound_interest(
+        &mut self,
+        from: &TestAddress,
+        account_nonce: u64,
+        token_id: TestTokenIdentifier,
+    ) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .compound_interest(account_nonce, token_id)
+            .run();
+    }
+
+    /// Compound accrued supply interest of a deposit with error expectation
+    pub fn compound_interest_error(
+        &mut self,
+        from: &TestAddress,
+        account_nonce: u64,
+        token_id: TestTokenIdentifier,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .compound_interest(account_nonce, token_id)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    // ============================================
+    // CONFIGURATION ENDPOINTS
+    // ============================================
+
+    /// Register account token
+    pub fn register_account_token(
+        &mut self,
+        token_name: ManagedBuffer<StaticApi>,
+        ticker: ManagedBuffer<StaticApi>,
+        payment_amount: BigUint<StaticApi>,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .register_account_token(token_name, ticker)
+            .egld(payment_amount)
+            .run();
+    }
+
+    /// Set token oracle configuration
+    pub fn set_token_oracle(
+        &mut self,
+        market_token: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        params: TokenOracleParams,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_token_oracle(
+                market_token.clone(),
+                params.decimals,
+                params.contract_address,
+                params.pricing_method,
+                params.oracle_type,
                 params.source,
                 params.first_tolerance,
                 params.last_tolerance,
@@ -1181,6 +1875,36 @@ impl LendingPoolTestState {
             .run();
     }
 
+    /// Set several token oracle configurations in one transaction
+    pub fn batch_set_token_oracle(
+        &mut self,
+        entries: Vec<(EgldOrEsdtTokenIdentifier<StaticApi>, TokenOracleParams)>,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .batch_set_token_oracle(token_oracle_entries(entries))
+            .run();
+    }
+
+    /// Set several token oracle configurations with error
+    pub fn batch_set_token_oracle_error(
+        &mut self,
+        entries: Vec<(EgldOrEsdtTokenIdentifier<StaticApi>, TokenOracleParams)>,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .batch_set_token_oracle(token_oracle_entries(entries))
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
     /// Edit token oracle tolerance
     pub fn edit_token_oracle_tolerance(
         &mut self,
@@ -1215,6 +1939,70 @@ impl LendingPoolTestState {
             .run();
     }
 
+    /// Edit token oracle quorum
+    pub fn edit_token_oracle_quorum(
+        &mut self,
+        market_token: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        min_oracle_quorum: u8,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .edit_token_oracle_quorum(market_token.clone(), min_oracle_quorum)
+            .run();
+    }
+
+    /// Edit token oracle quorum with error
+    pub fn edit_token_oracle_quorum_error(
+        &mut self,
+        market_token: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        min_oracle_quorum: u8,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .edit_token_oracle_quorum(market_token.clone(), min_oracle_quorum)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Edit token oracle TWAP rounds
+    pub fn edit_token_oracle_twap_rounds(
+        &mut self,
+        market_token: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        twap_rounds: u8,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .edit_token_oracle_twap_rounds(market_token.clone(), twap_rounds)
+            .run();
+    }
+
+    /// Edit token oracle TWAP rounds with error
+    pub fn edit_token_oracle_twap_rounds_error(
+        &mut self,
+        market_token: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        twap_rounds: u8,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .edit_token_oracle_twap_rounds(market_token.clone(), twap_rounds)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
     /// Disable token oracle
     pub fn disable_token_oracle(&mut self, market_token: &EgldOrEsdtTokenIdentifier<StaticApi>) {
         self.world
@@ -1361,942 +2149,2665 @@ impl LendingPoolTestState {
             .run();
     }
 
-    /// Set liquidity pool template address
-    pub fn set_liquidity_pool_template(&mut self, address: ManagedAddress<StaticApi>) {
+    /// Set the share of the liquidation bonus returned to the liquidatee
+    pub fn set_liquidatee_rebate(&mut self, rebate_bps: u64) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .set_liquidity_pool_template(address)
+            .set_liquidatee_rebate(BigUint::from(rebate_bps))
             .run();
     }
 
-    /// Set liquidity pool template address with error
-    pub fn set_liquidity_pool_template_error(
-        &mut self,
-        address: ManagedAddress<StaticApi>,
-        error_message: &[u8],
-    ) {
+    /// Set the liquidatee rebate with error expectation
+    pub fn set_liquidatee_rebate_error(&mut self, rebate_bps: u64, error_message: &[u8]) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .set_liquidity_pool_template(address)
+            .set_liquidatee_rebate(BigUint::from(rebate_bps))
             .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
             .run();
     }
 
-    // ============================================
-    // MARKET MANAGEMENT ENDPOINTS
-    // ============================================
+    /// Mark an account as protocol-owned (exempt from position limits)
+    pub fn add_protocol_owned_account(&mut self, account_nonce: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .add_protocol_owned_account(account_nonce)
+            .run();
+    }
 
-    /// Claim revenue from a market
-    pub fn claim_revenue(&mut self, token_id: TestTokenIdentifier) {
-        let mut array = MultiValueEncoded::new();
-        array.push(EgldOrEsdtTokenIdentifier::esdt(
-            token_id.to_esdt_token_identifier(),
-        ));
+    /// Mark an account as protocol-owned with error expectation
+    pub fn add_protocol_owned_account_error(&mut self, account_nonce: u64, error_message: &[u8]) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .add_protocol_owned_account(account_nonce)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
 
+    /// Remove an account from the protocol-owned whitelist
+    pub fn remove_protocol_owned_account(&mut self, account_nonce: u64) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .claim_revenue(array)
+            .remove_protocol_owned_account(account_nonce)
             .run();
     }
 
-    /// Add a new market (create liquidity pool)
-    pub fn add_new_market(
-        &mut self,
-        token_id: EgldOrEsdtTokenIdentifier<StaticApi>,
-        config: AssetConfig<StaticApi>,
-        rates: MarketRateParams,
-        asset_decimals: usize,
-    ) -> ManagedAddress<StaticApi> {
-        let pool_address = self
-            .world
+    /// Set the minimum health factor required after strategy operations
+    pub fn set_min_strategy_health_factor(&mut self, min_hf_ray: BigUint<StaticApi>) {
+        self.world
             .tx()
             .from(OWNER_ADDRESS)
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .create_liquidity_pool(
-                token_id.clone(),
-                rates.max_borrow_rate,
-                rates.base_borrow_rate,
-                rates.slope1,
-                rates.slope2,
-                rates.slope3,
-                rates.mid_utilization,
-                rates.optimal_utilization,
-                rates.reserve_factor,
-                config.loan_to_value_bps.as_raw_units(),
-                config.liquidation_threshold_bps.as_raw_units(),
-                config.liquidation_bonus_bps.as_raw_units(),
-                config.liquidation_fees_bps.as_raw_units(),
-                config.is_collateralizable,
-                config.is_borrowable,
-                config.is_isolated_asset,
-                config.isolation_debt_ceiling_usd_wad.as_raw_units(),
-                config.flashloan_fee_bps.as_raw_units(),
-                config.is_siloed_borrowing,
-                config.is_flashloanable,
-                config.isolation_borrow_enabled,
-                asset_decimals,
-                config.borrow_cap_wad.unwrap_or(BigUint::zero()),
-                config.supply_cap_wad.unwrap_or(BigUint::zero()),
-            )
-            .returns(ReturnsNewManagedAddress)
+            .set_min_strategy_health_factor(min_hf_ray)
             .run();
+    }
 
-        if token_id.is_egld() {
-            world().set_esdt_balance(
-                pool_address.clone(),
-                EGLD_TICKER,
-                &BigUint::from(10u64).pow(asset_decimals as u32) * 100000000u64,
-            );
-        } else {
-            let token_bytes = token_id
-                .as_esdt_option()
-                .unwrap()
-                .as_managed_buffer()
-                .to_boxed_bytes();
-            world().set_esdt_balance(
-                pool_address.clone(),
-                token_bytes.as_slice(),
-                &BigUint::from(10u64).pow(asset_decimals as u32) * 100000000u64,
-            );
-        }
-
-        pool_address
-    }
-
-    /// Create liquidity pool
-    pub fn create_liquidity_pool(
-        &mut self,
-        token_id: EgldOrEsdtTokenIdentifier<StaticApi>,
-        config: AssetConfig<StaticApi>,
-        rates: MarketRateParams,
-    ) -> ManagedAddress<StaticApi> {
+    /// Set the multiply origination fee
+    pub fn set_multiply_origination_fee(&mut self, fee_bps: u64) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .create_liquidity_pool(
-                token_id,
-                rates.max_borrow_rate,
-                rates.base_borrow_rate,
-                rates.slope1,
-                rates.slope2,
-                rates.slope3,
-                rates.mid_utilization,
-                rates.optimal_utilization,
-                rates.reserve_factor,
-                config.loan_to_value_bps.as_raw_units(),
-                config.liquidation_threshold_bps.as_raw_units(),
-                config.liquidation_bonus_bps.as_raw_units(),
-                config.liquidation_fees_bps.as_raw_units(),
-                config.is_collateralizable,
-                config.is_borrowable,
-                config.is_isolated_asset,
-                config.isolation_debt_ceiling_usd_wad.as_raw_units(),
-                config.flashloan_fee_bps.as_raw_units(),
-                config.is_siloed_borrowing,
-                config.is_flashloanable,
-                config.isolation_borrow_enabled,
-                18usize, // Default decimals, should be passed as parameter
-                config.borrow_cap_wad.unwrap_or(BigUint::zero()),
-                config.supply_cap_wad.unwrap_or(BigUint::zero()),
-            )
-            .returns(ReturnsNewManagedAddress)
-            .run()
+            .set_multiply_origination_fee(BigUint::from(fee_bps))
+            .run();
     }
 
-    /// Create liquidity pool with error
-    pub fn create_liquidity_pool_error(
-        &mut self,
-        token_id: EgldOrEsdtTokenIdentifier<StaticApi>,
-        rates: MarketRateParams,
-        risk: CreateLiquidityPoolRiskParams,
-        error_message: &[u8],
-    ) {
+    /// Set the share of flash loan fees routed to the accumulator
+    pub fn set_flash_loan_protocol_share(&mut self, share_bps: u64) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .create_liquidity_pool(
-                token_id,
-                rates.max_borrow_rate,
-                rates.base_borrow_rate,
-                rates.slope1,
-                rates.slope2,
-                rates.slope3,
-                rates.mid_utilization,
-                rates.optimal_utilization,
-                rates.reserve_factor,
-                risk.ltv,
-                risk.liquidation_threshold,
-                risk.liquidation_bonus,
-                risk.liquidation_fees,
-                risk.is_collateralizable,
-                risk.is_borrowable,
-                risk.is_isolated_asset,
-                risk.isolation_debt_ceiling_usd,
-                risk.flashloan_fee,
-                risk.is_siloed_borrowing,
-                risk.is_flashloanable,
-                risk.isolation_borrow_enabled,
-                risk.asset_decimals,
-                risk.borrow_cap,
-                risk.supply_cap,
-            )
-            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .set_flash_loan_protocol_share(BigUint::from(share_bps))
             .run();
     }
 
-    /// Upgrade liquidity pool configuration
-    pub fn upgrade_liquidity_pool_params(
-        &mut self,
-        base_asset: &EgldOrEsdtTokenIdentifier<StaticApi>,
-        rates: MarketRateParams,
-    ) {
+    /// Set the flash loan protocol share with error expectation
+    pub fn set_flash_loan_protocol_share_error(&mut self, share_bps: u64, error_message: &[u8]) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .upgrade_liquidity_pool_params(
-                base_asset.clone(),
-                rates.max_borrow_rate,
-                rates.base_borrow_rate,
-                rates.slope1,
-                rates.slope2,
-                rates.slope3,
-                rates.mid_utilization,
-                rates.optimal_utilization,
-                rates.reserve_factor,
-            )
+            .set_flash_loan_protocol_share(BigUint::from(share_bps))
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
             .run();
     }
 
-    /// Edit asset configuration
-    pub fn edit_asset_config(
-        &mut self,
-        asset: EgldOrEsdtTokenIdentifier<StaticApi>,
-        params: EditAssetConfigParams,
-        error_message: Option<&[u8]>,
-    ) {
-        let call = self
-            .world
-            .tx()
-            .from(OWNER_ADDRESS)
-            .to(self.lending_sc.clone())
-            .typed(proxy_lending_pool::ControllerProxy)
-            .edit_asset_config(
-                asset,
-                params.loan_to_value,
-                params.liquidation_threshold,
-                params.liquidation_bonus,
-                params.liquidation_fees,
-                params.is_isolated_asset,
-                params.isolation_debt_ceiling_usd,
-                params.is_siloed_borrowing,
-                params.is_flashloanable,
-                params.flashloan_fee,
-                params.is_collateralizable,
-                params.is_borrowable,
-                params.isolation_borrow_enabled,
-                params.borrow_cap,
-                params.supply_cap,
-            );
-
-        if let Some(err_msg) = error_message {
-            call.returns(ExpectMessage(core::str::from_utf8(err_msg).unwrap()))
-                .run();
-        } else {
-            call.run();
-        }
-    }
-
-    // ============================================
-    // E-MODE CONFIGURATION
-    // ============================================
-
-    /// Add e-mode category
-    pub fn add_e_mode_category(
-        &mut self,
-        ltv: BigUint<StaticApi>,
-        liquidation_threshold: BigUint<StaticApi>,
-        liquidation_bonus: BigUint<StaticApi>,
-    ) {
+    /// Toggle the global kill-switch for new debt
+    pub fn set_debt_creation_enabled(&mut self, enabled: bool) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
-            .to(&self.lending_sc)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .add_e_mode_category(ltv, liquidation_threshold, liquidation_bonus)
+            .set_debt_creation_enabled(enabled)
             .run();
     }
 
-    /// Add e-mode category with error
-    pub fn add_e_mode_category_error(
-        &mut self,
-        ltv: BigUint<StaticApi>,
-        liquidation_threshold: BigUint<StaticApi>,
-        liquidation_bonus: BigUint<StaticApi>,
-        error_message: &[u8],
-    ) {
+    /// Pause new supplies and borrows of a market
+    pub fn pause_market(&mut self, token_id: TestTokenIdentifier) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
-            .to(&self.lending_sc)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .add_e_mode_category(ltv, liquidation_threshold, liquidation_bonus)
-            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .pause_market(token_id)
             .run();
     }
 
-    /// Edit e-mode category
-    pub fn edit_e_mode_category(&mut self, category: EModeCategory<StaticApi>) {
+    /// Resume new supplies and borrows of a paused market
+    pub fn unpause_market(&mut self, token_id: TestTokenIdentifier) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
-            .to(&self.lending_sc)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .edit_e_mode_category(category)
+            .unpause_market(token_id)
             .run();
     }
 
-    /// Edit e-mode category with error
-    pub fn edit_e_mode_category_error(
-        &mut self,
-        category: EModeCategory<StaticApi>,
-        error_message: &[u8],
-    ) {
+    /// Enable or disable new supplies of a market
+    pub fn set_new_supply_enabled(&mut self, token_id: TestTokenIdentifier, enabled: bool) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
-            .to(&self.lending_sc)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .edit_e_mode_category(category)
-            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .set_new_supply_enabled(token_id, enabled)
             .run();
     }
 
-    /// Remove e-mode category
-    pub fn remove_e_mode_category(&mut self, category_id: u8) {
+    /// Enable or disable new borrows of a market
+    pub fn set_new_borrow_enabled(&mut self, token_id: TestTokenIdentifier, enabled: bool) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
-            .to(&self.lending_sc)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .remove_e_mode_category(category_id)
+            .set_new_borrow_enabled(token_id, enabled)
             .run();
     }
 
-    /// Remove e-mode category with error
-    pub fn remove_e_mode_category_error(&mut self, category_id: u8, error_message: &[u8]) {
+    /// Choose whether repayments of a market reduce principal before accrued interest
+    pub fn set_repay_principal_first(&mut self, token_id: TestTokenIdentifier, enabled: bool) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
-            .to(&self.lending_sc)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .remove_e_mode_category(category_id)
-            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .set_repay_principal_first(token_id, enabled)
             .run();
     }
 
-    /// Add asset to e-mode category
-    pub fn add_asset_to_e_mode_category(
-        &mut self,
-        asset: EgldOrEsdtTokenIdentifier<StaticApi>,
-        category_id: u8,
-        can_be_collateral: bool,
-        can_be_borrowed: bool,
-    ) {
+    /// Enable or disable the index sync performed by health factor views
+    pub fn set_view_index_sync_enabled(&mut self, enabled: bool) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
-            .to(&self.lending_sc)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .add_asset_to_e_mode_category(asset, category_id, can_be_collateral, can_be_borrowed)
+            .set_view_index_sync_enabled(enabled)
             .run();
     }
 
-    /// Add asset to e-mode category with error
-    pub fn add_asset_to_e_mode_category_error(
+    /// Set the minimum strategy health factor with error expectation
+    pub fn set_min_strategy_health_factor_error(
         &mut self,
-        asset: EgldOrEsdtTokenIdentifier<StaticApi>,
-        category_id: u8,
-        can_be_collateral: bool,
-        can_be_borrowed: bool,
+        min_hf_ray: BigUint<StaticApi>,
         error_message: &[u8],
     ) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
-            .to(&self.lending_sc)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .add_asset_to_e_mode_category(asset, category_id, can_be_collateral, can_be_borrowed)
+            .set_min_strategy_health_factor(min_hf_ray)
             .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
             .run();
     }
 
-    /// Edit asset in e-mode category
-    pub fn edit_asset_in_e_mode_category(
+    /// Set the minimum reserve ratio of a market
+    pub fn set_min_reserve_ratio(
         &mut self,
-        asset: EgldOrEsdtTokenIdentifier<StaticApi>,
-        category_id: u8,
-        config: EModeAssetConfig,
+        token_id: TestTokenIdentifier,
+        min_reserve_ratio_bps: BigUint<StaticApi>,
     ) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
-            .to(&self.lending_sc)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .edit_asset_in_e_mode_category(asset, category_id, config)
+            .set_min_reserve_ratio(token_id, min_reserve_ratio_bps)
             .run();
     }
 
-    /// Edit asset in e-mode category with error
-    pub fn edit_asset_in_e_mode_category_error(
+    /// Set the minimum reserve ratio of a market with error expectation
+    pub fn set_min_reserve_ratio_error(
         &mut self,
-        asset: EgldOrEsdtTokenIdentifier<StaticApi>,
-        category_id: u8,
-        config: EModeAssetConfig,
+        token_id: TestTokenIdentifier,
+        min_reserve_ratio_bps: BigUint<StaticApi>,
         error_message: &[u8],
     ) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
-            .to(&self.lending_sc)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .edit_asset_in_e_mode_category(asset, category_id, config)
+            .set_min_reserve_ratio(token_id, min_reserve_ratio_bps)
             .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
             .run();
     }
 
-    /// Remove asset from e-mode category
-    pub fn remove_asset_from_e_mode_category(
-        &mut self,
-        asset: EgldOrEsdtTokenIdentifier<StaticApi>,
-        category_id: u8,
-    ) {
+    /// Set the maximum collateral price drop tolerated by liquidations
+    pub fn set_max_liquidation_price_drop(&mut self, max_drop_bps: BigUint<StaticApi>) {
         self.world
             .tx()
             .from(OWNER_ADDRESS)
-            .to(&self.lending_sc)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .remove_asset_from_e_mode_category(asset, category_id)
+            .set_max_liquidation_price_drop(max_drop_bps)
             .run();
     }
 
-    /// Remove asset from e-mode category with error
-    pub fn remove_asset_from_e_mode_category_error(
+    /// Get the reference price of a token for the liquidation price regression check
+    pub fn liquidation_reference_price(
         &mut self,
-        asset: EgldOrEsdtTokenIdentifier<StaticApi>,
-        category_id: u8,
-        error_message: &[u8],
-    ) {
-        self.world
+        token_id: TestTokenIdentifier,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .liquidation_reference_price(token_id)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get the last price of a token recorded by `updateIndexes`
+    pub fn last_token_price(
+        &mut self,
+        token_id: TestTokenIdentifier,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .last_token_price(token_id)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Set the maximum collateral price drop tolerated by liquidations with error expectation
+    pub fn set_max_liquidation_price_drop_error(
+        &mut self,
+        max_drop_bps: BigUint<StaticApi>,
+        error_message: &[u8],
+    ) {
+        self.world
             .tx()
             .from(OWNER_ADDRESS)
-            .to(&self.lending_sc)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .remove_asset_from_e_mode_category(asset, category_id)
+            .set_max_liquidation_price_drop(max_drop_bps)
             .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
             .run();
     }
 
-    // ============================================
-    // STRATEGY ENDPOINTS
-    // ============================================
+    /// Set the collateral value credited for claiming after a debt-clearing liquidation
+    pub fn set_liquidation_dust_threshold(&mut self, threshold_usd_wad: BigUint<StaticApi>) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_liquidation_dust_threshold(threshold_usd_wad)
+            .run();
+    }
 
-    /// Multiply position (leverage)
-    pub fn multiply(&mut self, from: &TestAddress, params: MultiplyParams) {
+    /// Get the last holder of an account NFT
+    pub fn account_holder(&mut self, account_nonce: u64) -> ManagedAddress<StaticApi> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .account_holder(account_nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Claim the dust collateral credited to an account by presenting its NFT
+    pub fn claim_dust_collateral(&mut self, from: &TestAddress, account_nonce: u64) {
         self.world
             .tx()
             .from(from.to_managed_address())
-            .to(&self.lending_sc)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .multiply(
-                params.e_mode_category,
-                &params.collateral_token,
-                params.debt_to_flash_loan,
-                &params.debt_token,
-                params.mode,
-                params.steps,
-                params.steps_payment,
-            )
-            .payment(params.payments)
+            .claim_dust_collateral()
+            .payment(EsdtTokenPayment::new(
+                ACCOUNT_TOKEN.to_esdt_token_identifier(),
+                account_nonce,
+                BigUint::from(1u64),
+            ))
             .run();
     }
 
-    /// Multiply with error expectation
-    pub fn multiply_error(
+    /// Get the dust collateral credited to an account after a debt-clearing liquidation
+    pub fn dust_collateral(
         &mut self,
-        from: &TestAddress,
-        params: MultiplyParams,
-        error_message: &[u8],
-    ) {
+        account_nonce: u64,
+    ) -> Vec<(EgldOrEsdtTokenIdentifier<StaticApi>, BigUint<StaticApi>)> {
+        let credits = self
+            .world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .dust_collateral(account_nonce)
+            .returns(ReturnsResult)
+            .run();
+
+        credits
+            .into_iter()
+            .map(|credit| credit.into_tuple())
+            .collect()
+    }
+
+    /// Get the account NFT nonces last presented by an address
+    pub fn user_accounts(&mut self, holder: &TestAddress) -> Vec<u64> {
+        let accounts = self
+            .world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .user_accounts(holder.to_managed_address())
+            .returns(ReturnsResult)
+            .run();
+
+        let mut nonces = accounts.into_iter().collect::<Vec<u64>>();
+        nonces.sort();
+        nonces
+    }
+
+    /// Transfer an account NFT between wallets, outside the protocol
+    pub fn transfer_account(&mut self, from: &TestAddress, to: &TestAddress, account_nonce: u64) {
         self.world
             .tx()
             .from(from.to_managed_address())
-            .to(&self.lending_sc)
-            .typed(proxy_lending_pool::ControllerProxy)
-            .multiply(
-                params.e_mode_category,
-                &params.collateral_token,
-                params.debt_to_flash_loan,
-                &params.debt_token,
-                params.mode,
-                params.steps,
-                params.steps_payment,
-            )
-            .payment(params.payments)
-            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .to(to.to_managed_address())
+            .payment(EsdtTokenPayment::new(
+                ACCOUNT_TOKEN.to_esdt_token_identifier(),
+                account_nonce,
+                BigUint::from(1u64),
+            ))
             .run();
     }
 
-    /// Swap debt
-    pub fn swap_debt(
+    /// Set the borrow allowance of an account in EGLD (WAD)
+    pub fn set_account_borrow_allowance(
         &mut self,
-        from: &TestAddress,
-        existing_debt_token: &EgldOrEsdtTokenIdentifier<StaticApi>,
-        new_debt_amount_raw: &BigUint<StaticApi>,
-        new_debt_token: &EgldOrEsdtTokenIdentifier<StaticApi>,
-        steps: ManagedArgBuffer<StaticApi>,
-        account_payment: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+        account_nonce: u64,
+        egld_cap: BigUint<StaticApi>,
     ) {
         self.world
             .tx()
-            .from(from.to_managed_address())
-            .to(&self.lending_sc)
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .swap_debt(
-                existing_debt_token,
-                new_debt_amount_raw,
-                new_debt_token,
-                steps,
-            )
-            .payment(account_payment)
+            .set_account_borrow_allowance(account_nonce, egld_cap)
             .run();
     }
 
-    /// Swap debt with error expectation
-    pub fn swap_debt_error(
+    /// Set the borrow allowance of an account expecting an error
+    pub fn set_account_borrow_allowance_error(
         &mut self,
-        from: &TestAddress,
-        params: SwapDebtParams,
+        account_nonce: u64,
+        egld_cap: BigUint<StaticApi>,
         error_message: &[u8],
     ) {
         self.world
             .tx()
-            .from(from.to_managed_address())
-            .to(&self.lending_sc)
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .swap_debt(
-                &params.existing_debt_token,
-                &params.new_debt_amount_raw,
-                &params.new_debt_token,
-                params.steps,
-            )
-            .payment(params.account_payment)
+            .set_account_borrow_allowance(account_nonce, egld_cap)
             .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
             .run();
     }
 
-    /// Swap collateral
-    pub fn swap_collateral(
+    /// Override the borrow cap of an asset for a single account
+    pub fn set_account_borrow_cap(
         &mut self,
-        from: &TestAddress,
-        current_collateral: &EgldOrEsdtTokenIdentifier<StaticApi>,
-        from_amount: BigUint<StaticApi>,
-        new_collateral: &EgldOrEsdtTokenIdentifier<StaticApi>,
-        steps: ManagedArgBuffer<StaticApi>,
-        account_payment: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+        account_nonce: u64,
+        token_id: TestTokenIdentifier,
+        borrow_cap: BigUint<StaticApi>,
     ) {
         self.world
             .tx()
-            .from(from.to_managed_address())
-            .to(&self.lending_sc)
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .swap_collateral(current_collateral, from_amount, new_collateral, steps)
-            .payment(account_payment)
+            .set_account_borrow_cap(account_nonce, token_id, borrow_cap)
             .run();
     }
 
-    /// Swap collateral with error expectation
-    pub fn swap_collateral_error(
+    /// Override the borrow cap of an asset for a single account expecting an error
+    pub fn set_account_borrow_cap_error(
         &mut self,
-        from: &TestAddress,
-        params: SwapCollateralParams,
+        account_nonce: u64,
+        token_id: TestTokenIdentifier,
+        borrow_cap: BigUint<StaticApi>,
         error_message: &[u8],
     ) {
         self.world
             .tx()
-            .from(from.to_managed_address())
-            .to(&self.lending_sc)
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .swap_collateral(
-                &params.current_collateral,
-                params.from_amount,
-                &params.new_collateral,
-                params.steps,
-            )
-            .payment(params.account_payment)
+            .set_account_borrow_cap(account_nonce, token_id, borrow_cap)
             .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
             .run();
     }
 
-    /// Repay debt with collateral
-    pub fn repay_debt_with_collateral(&mut self, from: &TestAddress, params: RepayWithCollateralParams) {
+    /// Add or remove addresses from the borrow cap exemption whitelist
+    pub fn set_borrow_cap_exempt_addresses(&mut self, exempt: bool, addresses: &[TestAddress]) {
+        let mut addresses_arg = MultiValueEncoded::new();
+        for address in addresses {
+            addresses_arg.push(address.to_managed_address());
+        }
+
         self.world
             .tx()
-            .from(from.to_managed_address())
-            .to(&self.lending_sc)
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .repay_debt_with_collateral(
-                &params.from_token,
-                params.from_amount,
-                &params.to_token,
-                params.close_position,
-                params.steps,
-            )
-            .payment(params.account_payment)
+            .set_borrow_cap_exempt_addresses(exempt, addresses_arg)
             .run();
     }
 
-    // ============================================
-    // VIEW FUNCTIONS - PRICES AND HEALTH
-    // ============================================
+    /// Set the maximum total collateral and debt value of a single account
+    pub fn set_max_position_value(&mut self, egld_cap: BigUint<StaticApi>) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_max_position_value(egld_cap)
+            .run();
+    }
 
-    /// Get USD price
-    pub fn usd_price(
+    /// Set the borrow cap of an asset together with its denomination
+    pub fn set_borrow_cap(
         &mut self,
         token_id: TestTokenIdentifier,
-    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        borrow_cap: BigUint<StaticApi>,
+        denomination: CapDenomination,
+    ) {
         self.world
-            .query()
+            .tx()
+            .from(OWNER_ADDRESS)
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .usd_price(token_id)
-            .returns(ReturnsResult)
-            .run()
+            .set_borrow_cap(token_id, borrow_cap, denomination)
+            .run();
     }
 
-    /// Get EGLD price
-    pub fn egld_price(
+    /// Set the dust threshold under which leftover debt is written off on repay
+    pub fn set_dust_threshold(
         &mut self,
         token_id: TestTokenIdentifier,
-    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        dust_threshold: BigUint<StaticApi>,
+    ) {
         self.world
-            .query()
+            .tx()
+            .from(OWNER_ADDRESS)
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .egld_price(token_id)
-            .returns(ReturnsResult)
-            .run()
+            .set_dust_threshold(token_id, dust_threshold)
+            .run();
     }
 
-    /// Get USD price with error
-    pub fn usd_price_error(&mut self, token_id: TestTokenIdentifier, error_message: &[u8]) {
+    /// Set the liquidation haircut of a collateral asset
+    pub fn set_liquidation_haircut(
+        &mut self,
+        token_id: TestTokenIdentifier,
+        haircut_bps: BigUint<StaticApi>,
+    ) {
         self.world
-            .query()
+            .tx()
+            .from(OWNER_ADDRESS)
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .usd_price(token_id)
+            .set_liquidation_haircut(token_id, haircut_bps)
+            .run();
+    }
+
+    /// Set the liquidation haircut of a collateral asset expecting an error
+    pub fn set_liquidation_haircut_error(
+        &mut self,
+        token_id: TestTokenIdentifier,
+        haircut_bps: BigUint<StaticApi>,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_liquidation_haircut(token_id, haircut_bps)
             .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
             .run();
     }
 
-    // /// Get safe price by timestamp offset
-    // pub fn safe_price_by_timestamp_offset(
-    //     &mut self,
-    //     token_id: EgldOrEsdtTokenIdentifier<StaticApi>,
-    //     offset: u64,
-    // ) -> BigUint<StaticApi> {
-    //     self.world
-    //         .query()
-    //         .to(self.lending_sc.clone())
-    //         .typed(proxy_lending_pool::ControllerProxy)
+    /// Set the maximum age of the EGLD reference price
+    pub fn set_egld_max_price_stale_seconds(&mut self, max_seconds_stale: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_egld_max_price_stale_seconds(DurationSeconds::new(max_seconds_stale))
+            .run();
+    }
+
+    /// Set the maximum age of the EGLD reference price expecting an error
+    pub fn set_egld_max_price_stale_seconds_error(
+        &mut self,
+        max_seconds_stale: u64,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_egld_max_price_stale_seconds(DurationSeconds::new(max_seconds_stale))
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Temporarily extend the staleness tolerance of a token's price feeds
+    pub fn set_price_staleness_override(
+        &mut self,
+        token_id: TestTokenIdentifier,
+        extra_seconds: u64,
+        expiry_timestamp: u64,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_price_staleness_override(
+                token_id,
+                DurationSeconds::new(extra_seconds),
+                TimestampSeconds::new(expiry_timestamp),
+            )
+            .run();
+    }
+
+    /// Temporarily extend the staleness tolerance of a token's price feeds expecting an error
+    pub fn set_price_staleness_override_error(
+        &mut self,
+        token_id: TestTokenIdentifier,
+        extra_seconds: u64,
+        expiry_timestamp: u64,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_price_staleness_override(
+                token_id,
+                DurationSeconds::new(extra_seconds),
+                TimestampSeconds::new(expiry_timestamp),
+            )
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Set the minimum EGLD value (WAD) of collateral a liquidation must seize
+    pub fn set_min_seized_collateral_egld(&mut self, min_egld_value: BigUint<StaticApi>) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_min_seized_collateral_egld(min_egld_value)
+            .run();
+    }
+
+    /// Set the grace period protecting an account after a liquidation
+    pub fn set_post_liquidation_grace(&mut self, grace_seconds: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_post_liquidation_grace(DurationSeconds::new(grace_seconds))
+            .run();
+    }
+
+    /// Set the grace period protecting accounts after a liquidation threshold reduction
+    pub fn set_threshold_update_grace(&mut self, grace_seconds: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_threshold_update_grace(DurationSeconds::new(grace_seconds))
+            .run();
+    }
+
+    /// Get the block timestamp until which an account cannot be liquidated
+    pub fn liquidation_grace_until(&mut self, account_nonce: u64) -> TimestampSeconds {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .liquidation_grace_until(account_nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Set the maximum age of the last known price used for delisted assets
+    pub fn set_delisted_price_max_age(&mut self, max_age_seconds: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_delisted_price_max_age(DurationSeconds::new(max_age_seconds))
+            .run();
+    }
+
+    /// Set the minimum time between two index snapshots of a market
+    pub fn set_index_snapshot_interval(&mut self, interval_seconds: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_index_snapshot_interval(DurationSeconds::new(interval_seconds))
+            .run();
+    }
+
+    /// Set the minimum supply duration of a market
+    pub fn set_min_supply_duration(&mut self, token_id: TestTokenIdentifier, seconds: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_min_supply_duration(token_id, DurationSeconds::new(seconds))
+            .run();
+    }
+
+    /// Get the start of the running minimum supply period of a deposit
+    pub fn supply_timestamp(
+        &mut self,
+        account_nonce: u64,
+        token_id: TestTokenIdentifier,
+    ) -> TimestampSeconds {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .supply_timestamp(account_nonce, token_id)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Set the maximum number of account NFTs per batch strategy call
+    pub fn set_max_batch_accounts(&mut self, max_accounts: usize) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_max_batch_accounts(max_accounts)
+            .run();
+    }
+
+    /// Set the number of recent operations retained in a market's activity buffer
+    pub fn set_market_activity_capacity(&mut self, token_id: TestTokenIdentifier, capacity: usize) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_market_activity_capacity(token_id, capacity)
+            .run();
+    }
+
+    /// Set the market activity capacity expecting an error
+    pub fn set_market_activity_capacity_error(
+        &mut self,
+        token_id: TestTokenIdentifier,
+        capacity: usize,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_market_activity_capacity(token_id, capacity)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Set liquidity pool template address
+    pub fn set_liquidity_pool_template(&mut self, address: ManagedAddress<StaticApi>) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_liquidity_pool_template(address)
+            .run();
+    }
+
+    /// Set liquidity pool template address with error
+    pub fn set_liquidity_pool_template_error(
+        &mut self,
+        address: ManagedAddress<StaticApi>,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_liquidity_pool_template(address)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    // ============================================
+    // MARKET MANAGEMENT ENDPOINTS
+    // ============================================
+
+    /// Claim revenue from a market
+    pub fn claim_revenue(&mut self, token_id: TestTokenIdentifier) {
+        let mut array = MultiValueEncoded::new();
+        array.push(EgldOrEsdtTokenIdentifier::esdt(
+            token_id.to_esdt_token_identifier(),
+        ));
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .claim_revenue(array)
+            .run();
+    }
+
+    /// Add a new market (create liquidity pool)
+    pub fn add_new_market(
+        &mut self,
+        token_id: EgldOrEsdtTokenIdentifier<StaticApi>,
+        config: AssetConfig<StaticApi>,
+        rates: MarketRateParams,
+        asset_decimals: usize,
+        min_initial_supply: OptionalValue<BigUint<StaticApi>>,
+    ) -> ManagedAddress<StaticApi> {
+        let pool_address = self
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .create_liquidity_pool(
+                token_id.clone(),
+                rates.max_borrow_rate,
+                rates.base_borrow_rate,
+                rates.slope1,
+                rates.slope2,
+                rates.slope3,
+                rates.mid_utilization,
+                rates.optimal_utilization,
+                rates.reserve_factor,
+                config.loan_to_value_bps.as_raw_units(),
+                config.liquidation_threshold_bps.as_raw_units(),
+                config.liquidation_bonus_bps.as_raw_units(),
+                config.liquidation_fees_bps.as_raw_units(),
+                config.is_collateralizable,
+                config.is_borrowable,
+                config.is_isolated_asset,
+                config.isolation_debt_ceiling_usd_wad.as_raw_units(),
+                config.flashloan_fee_bps.as_raw_units(),
+                config.is_siloed_borrowing,
+                config.is_flashloanable,
+                config.isolation_borrow_enabled,
+                asset_decimals,
+                config.borrow_cap_wad.unwrap_or(BigUint::zero()),
+                config.supply_cap_wad.unwrap_or(BigUint::zero()),
+                OptionalValue::Some(BigUint::from(MAX_LIQUIDATION_CLOSE_FACTOR)),
+                min_initial_supply,
+            )
+            .returns(ReturnsNewManagedAddress)
+            .run();
+
+        if token_id.is_egld() {
+            world().set_esdt_balance(
+                pool_address.clone(),
+                EGLD_TICKER,
+                &BigUint::from(10u64).pow(asset_decimals as u32) * 100000000u64,
+            );
+        } else {
+            let token_bytes = token_id
+                .as_esdt_option()
+                .unwrap()
+                .as_managed_buffer()
+                .to_boxed_bytes();
+            world().set_esdt_balance(
+                pool_address.clone(),
+                token_bytes.as_slice(),
+                &BigUint::from(10u64).pow(asset_decimals as u32) * 100000000u64,
+            );
+        }
+
+        pool_address
+    }
+
+    /// Create liquidity pool
+    pub fn create_liquidity_pool(
+        &mut self,
+        token_id: EgldOrEsdtTokenIdentifier<StaticApi>,
+        config: AssetConfig<StaticApi>,
+        rates: MarketRateParams,
+    ) -> ManagedAddress<StaticApi> {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .create_liquidity_pool(
+                token_id,
+                rates.max_borrow_rate,
+                rates.base_borrow_rate,
+                rates.slope1,
+                rates.slope2,
+                rates.slope3,
+                rates.mid_utilization,
+                rates.optimal_utilization,
+                rates.reserve_factor,
+                config.loan_to_value_bps.as_raw_units(),
+                config.liquidation_threshold_bps.as_raw_units(),
+                config.liquidation_bonus_bps.as_raw_units(),
+                config.liquidation_fees_bps.as_raw_units(),
+                config.is_collateralizable,
+                config.is_borrowable,
+                config.is_isolated_asset,
+                config.isolation_debt_ceiling_usd_wad.as_raw_units(),
+                config.flashloan_fee_bps.as_raw_units(),
+                config.is_siloed_borrowing,
+                config.is_flashloanable,
+                config.isolation_borrow_enabled,
+                18usize, // Default decimals, should be passed as parameter
+                config.borrow_cap_wad.unwrap_or(BigUint::zero()),
+                config.supply_cap_wad.unwrap_or(BigUint::zero()),
+                OptionalValue::Some(BigUint::from(MAX_LIQUIDATION_CLOSE_FACTOR)),
+                OptionalValue::<BigUint<StaticApi>>::None,
+            )
+            .returns(ReturnsNewManagedAddress)
+            .run()
+    }
+
+    /// Create liquidity pool with error
+    pub fn create_liquidity_pool_error(
+        &mut self,
+        token_id: EgldOrEsdtTokenIdentifier<StaticApi>,
+        rates: MarketRateParams,
+        risk: CreateLiquidityPoolRiskParams,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .create_liquidity_pool(
+                token_id,
+                rates.max_borrow_rate,
+                rates.base_borrow_rate,
+                rates.slope1,
+                rates.slope2,
+                rates.slope3,
+                rates.mid_utilization,
+                rates.optimal_utilization,
+                rates.reserve_factor,
+                risk.ltv,
+                risk.liquidation_threshold,
+                risk.liquidation_bonus,
+                risk.liquidation_fees,
+                risk.is_collateralizable,
+                risk.is_borrowable,
+                risk.is_isolated_asset,
+                risk.isolation_debt_ceiling_usd,
+                risk.flashloan_fee,
+                risk.is_siloed_borrowing,
+                risk.is_flashloanable,
+                risk.isolation_borrow_enabled,
+                risk.asset_decimals,
+                risk.borrow_cap,
+                risk.supply_cap,
+                OptionalValue::<BigUint<StaticApi>>::None,
+                OptionalValue::<BigUint<StaticApi>>::None,
+            )
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Upgrade liquidity pool configuration
+    pub fn upgrade_liquidity_pool_params(
+        &mut self,
+        base_asset: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        rates: MarketRateParams,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .upgrade_liquidity_pool_params(
+                base_asset.clone(),
+                rates.max_borrow_rate,
+                rates.base_borrow_rate,
+                rates.slope1,
+                rates.slope2,
+                rates.slope3,
+                rates.mid_utilization,
+                rates.optimal_utilization,
+                rates.reserve_factor,
+            )
+            .run();
+    }
+
+    /// Set the minimum initial supply burned on the first deposit into a market
+    pub fn set_market_min_initial_supply(
+        &mut self,
+        base_asset: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        min_initial_supply: BigUint<StaticApi>,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_market_min_initial_supply(base_asset.clone(), min_initial_supply)
+            .run();
+    }
+
+    /// Choose whether a market's protocol revenue covers bad debt before suppliers
+    pub fn set_market_revenue_absorbs_bad_debt(
+        &mut self,
+        base_asset: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        enabled: bool,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_market_revenue_absorbs_bad_debt(base_asset.clone(), enabled)
+            .run();
+    }
+
+    /// Set the borrow rate model of a market
+    pub fn set_market_rate_model_type(
+        &mut self,
+        base_asset: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        rate_model_type: RateModelType,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_market_rate_model_type(base_asset.clone(), rate_model_type)
+            .run();
+    }
+
+    /// Set the elevated reserve factor of a market
+    pub fn set_market_reserve_factor_high(
+        &mut self,
+        base_asset: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        reserve_factor_high: u64,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_market_reserve_factor_high(base_asset.clone(), BigUint::from(reserve_factor_high))
+            .run();
+    }
+
+    /// Set the absolute borrow rate ceiling of a market enforced at accrual
+    pub fn set_market_max_accrual_rate(
+        &mut self,
+        base_asset: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        max_accrual_rate: BigUint<StaticApi>,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_market_max_accrual_rate(base_asset.clone(), max_accrual_rate)
+            .run();
+    }
+
+    /// Set the absolute borrow rate ceiling of a market with error expectation
+    pub fn set_market_max_accrual_rate_error(
+        &mut self,
+        base_asset: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        max_accrual_rate: BigUint<StaticApi>,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_market_max_accrual_rate(base_asset.clone(), max_accrual_rate)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Set the minimum interval between index recomputations of a market
+    pub fn set_market_min_accrual_interval(
+        &mut self,
+        base_asset: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        min_accrual_interval_ms: u64,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_market_min_accrual_interval(
+                base_asset.clone(),
+                DurationMillis::new(min_accrual_interval_ms),
+            )
+            .run();
+    }
+
+    /// Set the minimum interval between index recomputations of a market with error expectation
+    pub fn set_market_min_accrual_interval_error(
+        &mut self,
+        base_asset: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        min_accrual_interval_ms: u64,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_market_min_accrual_interval(
+                base_asset.clone(),
+                DurationMillis::new(min_accrual_interval_ms),
+            )
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Pause or resume interest accrual of a market
+    pub fn set_market_accrual_paused(
+        &mut self,
+        base_asset: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        paused: bool,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_market_accrual_paused(base_asset.clone(), paused)
+            .run();
+    }
+
+    /// Set the minimum initial supply of a market with error expectation
+    pub fn set_market_min_initial_supply_error(
+        &mut self,
+        base_asset: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        min_initial_supply: BigUint<StaticApi>,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_market_min_initial_supply(base_asset.clone(), min_initial_supply)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Edit asset configuration
+    pub fn edit_asset_config(
+        &mut self,
+        asset: EgldOrEsdtTokenIdentifier<StaticApi>,
+        params: EditAssetConfigParams,
+        error_message: Option<&[u8]>,
+    ) {
+        self.edit_asset_config_with_close_factor(asset, params, None, error_message);
+    }
+
+    /// Edit asset configuration, optionally overriding the liquidation close factor
+    pub fn edit_asset_config_with_close_factor(
+        &mut self,
+        asset: EgldOrEsdtTokenIdentifier<StaticApi>,
+        params: EditAssetConfigParams,
+        max_liquidation_close_factor: Option<u64>,
+        error_message: Option<&[u8]>,
+    ) {
+        let call = self
+            .world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .edit_asset_config(
+                asset,
+                params.loan_to_value,
+                params.liquidation_threshold,
+                params.liquidation_bonus,
+                params.liquidation_fees,
+                params.is_isolated_asset,
+                params.isolation_debt_ceiling_usd,
+                params.is_siloed_borrowing,
+                params.is_flashloanable,
+                params.flashloan_fee,
+                params.is_collateralizable,
+                params.is_borrowable,
+                params.isolation_borrow_enabled,
+                params.borrow_cap,
+                params.supply_cap,
+                match max_liquidation_close_factor {
+                    Some(close_factor) => OptionalValue::Some(BigUint::from(close_factor)),
+                    None => OptionalValue::None,
+                },
+            );
+
+        if let Some(err_msg) = error_message {
+            call.returns(ExpectMessage(core::str::from_utf8(err_msg).unwrap()))
+                .run();
+        } else {
+            call.run();
+        }
+    }
+
+    // ============================================
+    // E-MODE CONFIGURATION
+    // ============================================
+
+    /// Add e-mode category
+    pub fn add_e_mode_category(
+        &mut self,
+        ltv: BigUint<StaticApi>,
+        liquidation_threshold: BigUint<StaticApi>,
+        liquidation_bonus: BigUint<StaticApi>,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .add_e_mode_category(ltv, liquidation_threshold, liquidation_bonus)
+            .run();
+    }
+
+    /// Add e-mode category with error
+    pub fn add_e_mode_category_error(
+        &mut self,
+        ltv: BigUint<StaticApi>,
+        liquidation_threshold: BigUint<StaticApi>,
+        liquidation_bonus: BigUint<StaticApi>,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .add_e_mode_category(ltv, liquidation_threshold, liquidation_bonus)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Edit e-mode category
+    pub fn edit_e_mode_category(&mut self, category: EModeCategory<StaticApi>) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .edit_e_mode_category(category)
+            .run();
+    }
+
+    /// Edit e-mode category with error
+    pub fn edit_e_mode_category_error(
+        &mut self,
+        category: EModeCategory<StaticApi>,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .edit_e_mode_category(category)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Remove e-mode category
+    pub fn remove_e_mode_category(&mut self, category_id: u8) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .remove_e_mode_category(category_id)
+            .run();
+    }
+
+    /// Remove e-mode category with error
+    pub fn remove_e_mode_category_error(&mut self, category_id: u8, error_message: &[u8]) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .remove_e_mode_category(category_id)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Add asset to e-mode category
+    pub fn add_asset_to_e_mode_category(
+        &mut self,
+        asset: EgldOrEsdtTokenIdentifier<StaticApi>,
+        category_id: u8,
+        can_be_collateral: bool,
+        can_be_borrowed: bool,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .add_asset_to_e_mode_category(asset, category_id, can_be_collateral, can_be_borrowed)
+            .run();
+    }
+
+    /// Add asset to e-mode category with error
+    pub fn add_asset_to_e_mode_category_error(
+        &mut self,
+        asset: EgldOrEsdtTokenIdentifier<StaticApi>,
+        category_id: u8,
+        can_be_collateral: bool,
+        can_be_borrowed: bool,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .add_asset_to_e_mode_category(asset, category_id, can_be_collateral, can_be_borrowed)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Edit asset in e-mode category
+    pub fn edit_asset_in_e_mode_category(
+        &mut self,
+        asset: EgldOrEsdtTokenIdentifier<StaticApi>,
+        category_id: u8,
+        config: EModeAssetConfig,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .edit_asset_in_e_mode_category(asset, category_id, config)
+            .run();
+    }
+
+    /// Set whether an e-mode collateral requires a companion collateral
+    pub fn set_e_mode_companion_collateral_required(
+        &mut self,
+        asset: EgldOrEsdtTokenIdentifier<StaticApi>,
+        category_id: u8,
+        required: bool,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_e_mode_companion_collateral_required(asset, category_id, required)
+            .run();
+    }
+
+    /// Edit asset in e-mode category with error
+    pub fn edit_asset_in_e_mode_category_error(
+        &mut self,
+        asset: EgldOrEsdtTokenIdentifier<StaticApi>,
+        category_id: u8,
+        config: EModeAssetConfig,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .edit_asset_in_e_mode_category(asset, category_id, config)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Remove asset from e-mode category
+    pub fn remove_asset_from_e_mode_category(
+        &mut self,
+        asset: EgldOrEsdtTokenIdentifier<StaticApi>,
+        category_id: u8,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .remove_asset_from_e_mode_category(asset, category_id)
+            .run();
+    }
+
+    /// Remove asset from e-mode category with error
+    pub fn remove_asset_from_e_mode_category_error(
+        &mut self,
+        asset: EgldOrEsdtTokenIdentifier<StaticApi>,
+        category_id: u8,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .remove_asset_from_e_mode_category(asset, category_id)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    // ============================================
+    // STRATEGY ENDPOINTS
+    // ============================================
+
+    /// Multiply position (leverage)
+    pub fn multiply(&mut self, from: &TestAddress, params: MultiplyParams) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .multiply(
+                params.e_mode_category,
+                &params.collateral_token,
+                params.debt_to_flash_loan,
+                &params.debt_token,
+                params.mode,
+                params.steps,
+                params.steps_payment,
+            )
+            .payment(params.payments)
+            .run();
+    }
+
+    /// Multiply with error expectation
+    pub fn multiply_error(
+        &mut self,
+        from: &TestAddress,
+        params: MultiplyParams,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .multiply(
+                params.e_mode_category,
+                &params.collateral_token,
+                params.debt_to_flash_loan,
+                &params.debt_token,
+                params.mode,
+                params.steps,
+                params.steps_payment,
+            )
+            .payment(params.payments)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Swap debt
+    pub fn swap_debt(
+        &mut self,
+        from: &TestAddress,
+        existing_debt_token: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        new_debt_amount_raw: &BigUint<StaticApi>,
+        new_debt_token: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        steps: ManagedArgBuffer<StaticApi>,
+        account_payment: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+    ) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .swap_debt(
+                existing_debt_token,
+                new_debt_amount_raw,
+                new_debt_token,
+                steps,
+            )
+            .payment(account_payment)
+            .run();
+    }
+
+    /// Swap debt with error expectation
+    pub fn swap_debt_error(
+        &mut self,
+        from: &TestAddress,
+        params: SwapDebtParams,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .swap_debt(
+                &params.existing_debt_token,
+                &params.new_debt_amount_raw,
+                &params.new_debt_token,
+                params.steps,
+            )
+            .payment(params.account_payment)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Swap collateral
+    pub fn swap_collateral(
+        &mut self,
+        from: &TestAddress,
+        current_collateral: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        from_amount: BigUint<StaticApi>,
+        new_collateral: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        steps: ManagedArgBuffer<StaticApi>,
+        account_payment: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+    ) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .swap_collateral(current_collateral, from_amount, new_collateral, steps)
+            .payment(account_payment)
+            .run();
+    }
+
+    /// Set the default swap router steps used by strategies for a token pair
+    pub fn set_default_swap_steps(
+        &mut self,
+        from_token: &TestTokenIdentifier,
+        to_token: &TestTokenIdentifier,
+        steps: MultiValueEncoded<StaticApi, ManagedBuffer<StaticApi>>,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .set_default_swap_steps(
+                EgldOrEsdtTokenIdentifier::esdt(from_token.to_esdt_token_identifier()),
+                EgldOrEsdtTokenIdentifier::esdt(to_token.to_esdt_token_identifier()),
+                steps,
+            )
+            .run();
+    }
+
+    /// Swap collateral with error expectation
+    pub fn swap_collateral_error(
+        &mut self,
+        from: &TestAddress,
+        params: SwapCollateralParams,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .swap_collateral(
+                &params.current_collateral,
+                params.from_amount,
+                &params.new_collateral,
+                params.steps,
+            )
+            .payment(params.account_payment)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Repay debt with collateral
+    pub fn repay_debt_with_collateral(&mut self, from: &TestAddress, params: RepayWithCollateralParams) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .repay_debt_with_collateral(
+                &params.from_token,
+                params.from_amount,
+                &params.to_token,
+                params.close_position,
+                params.steps,
+            )
+            .payment(params.account_payment)
+            .run();
+    }
+
+    /// Unwind a fraction of a leveraged position
+    pub fn deleverage(&mut self, from: &TestAddress, params: DeleverageParams) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .deleverage(
+                &params.collateral_token,
+                &params.debt_token,
+                BigUint::from(params.fraction_bps),
+                params.steps,
+            )
+            .payment(params.payments)
+            .run();
+    }
+
+    /// Unwind a fraction of a leveraged position expecting an error
+    pub fn deleverage_error(
+        &mut self,
+        from: &TestAddress,
+        params: DeleverageParams,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .deleverage(
+                &params.collateral_token,
+                &params.debt_token,
+                BigUint::from(params.fraction_bps),
+                params.steps,
+            )
+            .payment(params.payments)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Repay debt with a payment in another token swapped through the router
+    pub fn repay_with_swap(&mut self, from: &TestAddress, params: RepayWithSwapParams) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .repay_with_swap(
+                params.account_nonce,
+                &params.debt_token,
+                &params.payment_token,
+                params.steps,
+            )
+            .payment(params.payments)
+            .run();
+    }
+
+    /// Repay debt with a payment in another token expecting an error
+    pub fn repay_with_swap_error(
+        &mut self,
+        from: &TestAddress,
+        params: RepayWithSwapParams,
+        error_message: &[u8],
+    ) {
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(&self.lending_sc)
+            .typed(proxy_lending_pool::ControllerProxy)
+            .repay_with_swap(
+                params.account_nonce,
+                &params.debt_token,
+                &params.payment_token,
+                params.steps,
+            )
+            .payment(params.payments)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Add collateral to several accounts in a single batch call
+    pub fn batch_add_collateral(
+        &mut self,
+        from: &TestAddress,
+        account_nonces: &[u64],
+        collaterals: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+    ) {
+        let payments = batch_payments(account_nonces, collaterals);
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .batch_add_collateral()
+            .payment(payments)
+            .run();
+    }
+
+    /// Add collateral to several accounts in a single batch call expecting an error
+    pub fn batch_add_collateral_error(
+        &mut self,
+        from: &TestAddress,
+        account_nonces: &[u64],
+        collaterals: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+        error_message: &[u8],
+    ) {
+        let payments = batch_payments(account_nonces, collaterals);
+        self.world
+            .tx()
+            .from(from.to_managed_address())
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .batch_add_collateral()
+            .payment(payments)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    // ============================================
+    // VIEW FUNCTIONS - PRICES AND HEALTH
+    // ============================================
+
+    /// Get USD price
+    pub fn usd_price(
+        &mut self,
+        token_id: TestTokenIdentifier,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .usd_price(token_id)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get EGLD price
+    pub fn egld_price(
+        &mut self,
+        token_id: TestTokenIdentifier,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .egld_price(token_id)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get the USD price of a token with its confidence band
+    pub fn oracle_price_with_confidence(
+        &mut self,
+        token_id: TestTokenIdentifier,
+    ) -> (
+        ManagedDecimal<StaticApi, NumDecimals>,
+        ManagedDecimal<StaticApi, NumDecimals>,
+        ManagedDecimal<StaticApi, NumDecimals>,
+    ) {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .oracle_price_with_confidence(token_id)
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple()
+    }
+
+    /// Get annual borrow rate at a given utilization
+    pub fn borrow_rate_at(
+        &mut self,
+        token_id: TestTokenIdentifier,
+        utilization_ray: BigUint<StaticApi>,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .borrow_rate_at(token_id, utilization_ray)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get utilization that produces a given annual borrow rate
+    pub fn utilization_for_borrow_rate(
+        &mut self,
+        token_id: TestTokenIdentifier,
+        target_rate_ray: BigUint<StaticApi>,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .utilization_for_borrow_rate(token_id, target_rate_ray)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get the fee a flash loan of the given amount would be charged
+    pub fn flash_loan_fee(
+        &mut self,
+        token_id: TestTokenIdentifier,
+        amount: BigUint<StaticApi>,
+    ) -> BigUint<StaticApi> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .flash_loan_fee(token_id, amount)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get token price with its aggregator round timestamp and staleness flag
+    pub fn token_price_with_age(
+        &mut self,
+        token_id: TestTokenIdentifier,
+    ) -> (
+        ManagedDecimal<StaticApi, NumDecimals>,
+        TimestampMillis,
+        bool,
+    ) {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .token_price_with_age(token_id)
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple()
+    }
+
+    /// Get cumulative rounding remainder retained by a market
+    pub fn accumulated_rounding(&mut self, token_id: TestTokenIdentifier) -> BigUint<StaticApi> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .asset_accumulated_rounding(token_id)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get the revenue a claim would transfer from a market right now
+    pub fn claimable_revenue(
+        &mut self,
+        token_id: TestTokenIdentifier,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .claimable_revenue(token_id)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get USD price with error
+    pub fn usd_price_error(&mut self, token_id: TestTokenIdentifier, error_message: &[u8]) {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .usd_price(token_id)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    // /// Get safe price by timestamp offset
+    // pub fn safe_price_by_timestamp_offset(
+    //     &mut self,
+    //     token_id: EgldOrEsdtTokenIdentifier<StaticApi>,
+    //     offset: u64,
+    // ) -> BigUint<StaticApi> {
+    //     self.world
+    //         .query()
+    //         .to(self.lending_sc.clone())
+    //         .typed(proxy_lending_pool::ControllerProxy)
     //         .get_safe_price_by_timestamp_offset(token_id, offset)
     //         .returns(ReturnsResult)
     //         .run()
     // }
 
-    /// Can be liquidated check
-    pub fn can_be_liquidated(&mut self, account_position: u64) -> bool {
+    /// Can be liquidated check
+    pub fn can_be_liquidated(&mut self, account_position: u64) -> bool {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .can_be_liquidated(account_position)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get account health factor
+    pub fn account_health_factor(
+        &mut self,
+        account_position: u64,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .health_factor(account_position)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get health factor with market indexes synced to the current timestamp
+    pub fn account_health_factor_synced(
+        &mut self,
+        account_position: u64,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .health_factor_synced(account_position)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get the health factors of many accounts in one query
+    pub fn account_health_factors(
+        &mut self,
+        account_positions: &[u64],
+    ) -> MultiValueEncoded<StaticApi, MultiValue2<u64, ManagedDecimal<StaticApi, NumDecimals>>>
+    {
+        let mut nonces = MultiValueEncoded::new();
+        for account_position in account_positions {
+            nonces.push(*account_position);
+        }
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .health_factors(nonces)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Simulate the health factor of an account after hypothetical position deltas
+    pub fn simulate_health_factor(
+        &mut self,
+        account_position: u64,
+        deltas: &[(TestTokenIdentifier, BigUint<StaticApi>, MarketActivityType)],
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        let mut encoded_deltas = MultiValueEncoded::new();
+        for (token_id, amount, action) in deltas {
+            encoded_deltas.push(MultiValue3::from((
+                EgldOrEsdtTokenIdentifier::from(token_id.as_bytes()),
+                amount.clone(),
+                action.clone(),
+            )));
+        }
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .simulate_health_factor(account_position, encoded_deltas)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get the health factor contribution of each collateral position
+    pub fn position_health_contribution(
+        &mut self,
+        account_position: u64,
+    ) -> MultiValueEncoded<
+        StaticApi,
+        MultiValue2<EgldOrEsdtTokenIdentifier<StaticApi>, ManagedDecimal<StaticApi, NumDecimals>>,
+    > {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .position_health_contribution(account_position)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get the health factor gained by adding one token to each collateral position
+    pub fn marginal_health_contribution(
+        &mut self,
+        account_position: u64,
+    ) -> MultiValueEncoded<
+        StaticApi,
+        MultiValue2<EgldOrEsdtTokenIdentifier<StaticApi>, ManagedDecimal<StaticApi, NumDecimals>>,
+    > {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .marginal_health_contribution(account_position)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get one page of healthy borrowing accounts sorted by health factor
+    pub fn accounts_near_liquidation(
+        &mut self,
+        from: usize,
+        size: usize,
+    ) -> MultiValueEncoded<StaticApi, MultiValue2<u64, ManagedDecimal<StaticApi, NumDecimals>>>
+    {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .accounts_near_liquidation(from, size)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Check whether an account's debt is fully backed by mode-eligible collateral
+    pub fn is_fully_backed(&mut self, account_position: u64) -> bool {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .is_fully_backed(account_position)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get the USD price of a collateral at which the account becomes liquidatable
+    pub fn liquidation_price(
+        &mut self,
+        account_position: u64,
+        token_id: TestTokenIdentifier,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .liquidation_price(account_position, token_id)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get the liquidation price of a collateral expecting an error
+    pub fn liquidation_price_error(
+        &mut self,
+        account_position: u64,
+        token_id: TestTokenIdentifier,
+        error_message: &[u8],
+    ) {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .liquidation_price(account_position, token_id)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    // ============================================
+    // VIEW FUNCTIONS - ACCOUNT POSITIONS
+    // ============================================
+
+    /// Get collateral amount for token
+    pub fn collateral_amount_for_token(
+        &mut self,
+        account_position: u64,
+        token_id: TestTokenIdentifier,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .collateral_amount_for_token(account_position, token_id)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get collateral amount for non-existing token
+    pub fn collateral_amount_for_token_non_existing(
+        &mut self,
+        account_position: u64,
+        token_id: TestTokenIdentifier,
+        error_message: &[u8],
+    ) {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .collateral_amount_for_token(account_position, token_id)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Get borrow amount for token
+    pub fn borrow_amount_for_token(
+        &mut self,
+        account_position: u64,
+        token_id: TestTokenIdentifier,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .borrow_amount_for_token(account_position, token_id)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get interest accrued by a position since it was opened
+    pub fn position_accrued_interest(
+        &mut self,
+        account_position: u64,
+        token_id: TestTokenIdentifier,
+        is_borrow: bool,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .position_accrued_interest(account_position, token_id, is_borrow)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get the cost basis tracked for a position
+    pub fn position_entry(
+        &mut self,
+        account_position: u64,
+        position_type: AccountPositionType,
+        token_id: TestTokenIdentifier,
+    ) -> PositionEntry<StaticApi> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .position_entry(account_position, position_type, token_id)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get borrow amount for non-existing token
+    pub fn borrow_amount_for_token_non_existing(
+        &mut self,
+        account_position: u64,
+        token_id: TestTokenIdentifier,
+        error_message: &[u8],
+    ) {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .borrow_amount_for_token(account_position, token_id)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
+    /// Get total borrow in EGLD
+    pub fn total_borrow_in_egld(
+        &mut self,
+        account_position: u64,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .total_borrow_in_egld(account_position)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get total borrow in EGLD (big)
+    pub fn total_borrow_in_egld_big(
+        &mut self,
+        account_position: u64,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .total_borrow_in_egld(account_position)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get total collateral in EGLD
+    pub fn total_collateral_in_egld(
+        &mut self,
+        account_position: u64,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .total_collateral_in_egld(account_position)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get total collateral in EGLD (big)
+    pub fn total_collateral_in_egld_big(
+        &mut self,
+        account_position: u64,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .total_collateral_in_egld(account_position)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get LTV collateral in EGLD
+    pub fn ltv_collateral_in_egld(
+        &mut self,
+        account_position: u64,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .ltv_collateral_in_egld(account_position)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get account net worth as (EGLD, USD)
+    pub fn account_net_worth(
+        &mut self,
+        account_position: u64,
+    ) -> (
+        ManagedDecimal<StaticApi, NumDecimals>,
+        ManagedDecimal<StaticApi, NumDecimals>,
+    ) {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .account_net_worth(account_position)
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple()
+    }
+
+    /// Get the collateral, debt and health factor summary of an account
+    pub fn account_summary(&mut self, account_nonce: u64) -> UserAccountSummary<StaticApi> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .account_summary(account_nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get a page of account summaries held by a wallet
+    pub fn wallet_summary(
+        &mut self,
+        address: &TestAddress,
+        from: usize,
+        size: usize,
+    ) -> MultiValueEncoded<StaticApi, MultiValue2<u64, UserAccountSummary<StaticApi>>> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .wallet_summary(address.to_managed_address(), from, size)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get account health factor with and without its e-mode category
+    pub fn e_mode_impact(
+        &mut self,
+        account_position: u64,
+    ) -> (
+        ManagedDecimal<StaticApi, NumDecimals>,
+        ManagedDecimal<StaticApi, NumDecimals>,
+    ) {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .e_mode_impact(account_position)
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple()
+    }
+
+    /// Get tracked principal of an auto-compounding deposit
+    pub fn compounded_principal(
+        &mut self,
+        account_position: u64,
+        token_id: TestTokenIdentifier,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .compounded_principal(account_position, token_id)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get liquidation collateral available
+    pub fn liquidation_collateral_available(
+        &mut self,
+        account_position: u64,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .liquidation_collateral_available(account_position)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get the maximum amount of a token an account can borrow
+    pub fn max_borrowable(
+        &mut self,
+        account_position: u64,
+        token_id: &TestTokenIdentifier,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .max_borrowable(
+                account_position,
+                EgldOrEsdtTokenIdentifier::esdt(token_id.to_esdt_token_identifier()),
+            )
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get deposit positions
+    pub fn deposit_positions(
+        &mut self,
+        nonce: u64,
+    ) -> MultiValueEncoded<
+        StaticApi,
+        MultiValue2<EgldOrEsdtTokenIdentifier<StaticApi>, AccountPosition<StaticApi>>,
+    > {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .positions(nonce, AccountPositionType::Deposit)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get borrow positions
+    pub fn borrow_positions(
+        &mut self,
+        nonce: u64,
+    ) -> MultiValueEncoded<
+        StaticApi,
+        MultiValue2<EgldOrEsdtTokenIdentifier<StaticApi>, AccountPosition<StaticApi>>,
+    > {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .positions(nonce, AccountPositionType::Borrow)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get liquidation estimations
+    pub fn liquidation_estimations(
+        &mut self,
+        account_nonce: u64,
+        debt_payments: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+    ) -> LiquidationEstimate<StaticApi> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .liquidation_estimations(account_nonce, debt_payments)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Simulate the impact of a full liquidation on the account holder
+    pub fn simulate_liquidation_impact(
+        &mut self,
+        account_nonce: u64,
+    ) -> (
+        ManagedDecimal<StaticApi, NumDecimals>,
+        ManagedDecimal<StaticApi, NumDecimals>,
+        ManagedDecimal<StaticApi, NumDecimals>,
+    ) {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .can_be_liquidated(account_position)
+            .simulate_liquidation_impact(account_nonce)
             .returns(ReturnsResult)
             .run()
+            .into_tuple()
     }
 
-    /// Get account health factor
-    pub fn account_health_factor(
+    /// Preview the outcome of a multiply call
+    pub fn preview_multiply(
         &mut self,
-        account_position: u64,
-    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        account_nonce: u64,
+        collateral_token: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        debt_token: &EgldOrEsdtTokenIdentifier<StaticApi>,
+        debt_to_flash_loan: BigUint<StaticApi>,
+        expected_swap_out: BigUint<StaticApi>,
+    ) -> (
+        ManagedDecimal<StaticApi, NumDecimals>,
+        ManagedDecimal<StaticApi, NumDecimals>,
+        ManagedDecimal<StaticApi, NumDecimals>,
+        ManagedDecimal<StaticApi, NumDecimals>,
+    ) {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .health_factor(account_position)
+            .preview_multiply(
+                account_nonce,
+                collateral_token,
+                debt_token,
+                debt_to_flash_loan,
+                expected_swap_out,
+            )
             .returns(ReturnsResult)
             .run()
+            .into_tuple()
     }
 
     // ============================================
-    // VIEW FUNCTIONS - ACCOUNT POSITIONS
+    // VIEW FUNCTIONS - MARKET DATA
     // ============================================
 
-    /// Get collateral amount for token
-    pub fn collateral_amount_for_token(
+    /// Get all market indexes
+    pub fn all_market_indexes(
         &mut self,
-        account_position: u64,
-        token_id: TestTokenIdentifier,
-    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        assets: MultiValueEncoded<StaticApi, EgldOrEsdtTokenIdentifier<StaticApi>>,
+    ) -> ManagedVec<StaticApi, MarketIndexView<StaticApi>> {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .collateral_amount_for_token(account_position, token_id)
+            .all_market_indexes(assets)
             .returns(ReturnsResult)
             .run()
     }
 
-    /// Get collateral amount for non-existing token
-    pub fn collateral_amount_for_token_non_existing(
+    /// Get all markets
+    pub fn all_markets(
         &mut self,
-        account_position: u64,
-        token_id: TestTokenIdentifier,
-        error_message: &[u8],
-    ) {
+        assets: MultiValueEncoded<StaticApi, EgldOrEsdtTokenIdentifier<StaticApi>>,
+    ) -> ManagedVec<StaticApi, AssetExtendedConfigView<StaticApi>> {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .collateral_amount_for_token(account_position, token_id)
-            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
-            .run();
+            .all_markets(assets)
+            .returns(ReturnsResult)
+            .run()
     }
 
-    /// Get borrow amount for token
-    pub fn borrow_amount_for_token(
+    /// Get the total value locked across all markets in EGLD and USD
+    pub fn total_value_locked(
         &mut self,
-        account_position: u64,
-        token_id: TestTokenIdentifier,
-    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+    ) -> (
+        ManagedDecimal<StaticApi, NumDecimals>,
+        ManagedDecimal<StaticApi, NumDecimals>,
+    ) {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .borrow_amount_for_token(account_position, token_id)
+            .total_value_locked()
             .returns(ReturnsResult)
             .run()
+            .into_tuple()
     }
 
-    /// Get borrow amount for non-existing token
-    pub fn borrow_amount_for_token_non_existing(
+    /// Get the protocol-wide collateral and debt across all markets in EGLD
+    pub fn protocol_totals(
         &mut self,
-        account_position: u64,
-        token_id: TestTokenIdentifier,
-        error_message: &[u8],
+    ) -> (
+        ManagedDecimal<StaticApi, NumDecimals>,
+        ManagedDecimal<StaticApi, NumDecimals>,
     ) {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .borrow_amount_for_token(account_position, token_id)
-            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
-            .run();
+            .protocol_totals()
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple()
     }
 
-    /// Get total borrow in EGLD
-    pub fn total_borrow_in_egld(
+    /// Get the outstanding debt in EGLD of each e-mode category
+    pub fn aggregate_borrow_by_e_mode(
         &mut self,
-        account_position: u64,
-    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+    ) -> Vec<(u8, ManagedDecimal<StaticApi, NumDecimals>)> {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .total_borrow_in_egld(account_position)
+            .aggregate_borrow_by_e_mode()
             .returns(ReturnsResult)
             .run()
+            .into_iter()
+            .map(|entry| entry.into_tuple())
+            .collect()
     }
 
-    /// Get total borrow in EGLD (big)
-    pub fn total_borrow_in_egld_big(
+    /// Get the supply cap utilization of a market in BPS
+    pub fn supply_cap_utilization(
         &mut self,
-        account_position: u64,
+        token_id: TestTokenIdentifier,
     ) -> ManagedDecimal<StaticApi, NumDecimals> {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .total_borrow_in_egld(account_position)
+            .supply_cap_utilization(EgldOrEsdtTokenIdentifier::esdt(
+                token_id.to_esdt_token_identifier(),
+            ))
             .returns(ReturnsResult)
             .run()
     }
 
-    /// Get total collateral in EGLD
-    pub fn total_collateral_in_egld(
+    /// Get the collateral value, debt value and collateralization ratio of a market
+    pub fn collateralization_ratio(
         &mut self,
-        account_position: u64,
-    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        token_id: TestTokenIdentifier,
+    ) -> (
+        ManagedDecimal<StaticApi, NumDecimals>,
+        ManagedDecimal<StaticApi, NumDecimals>,
+        ManagedDecimal<StaticApi, NumDecimals>,
+    ) {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .total_collateral_in_egld(account_position)
+            .collateralization_ratio(EgldOrEsdtTokenIdentifier::esdt(
+                token_id.to_esdt_token_identifier(),
+            ))
             .returns(ReturnsResult)
             .run()
+            .into_tuple()
     }
 
-    /// Get total collateral in EGLD (big)
-    pub fn total_collateral_in_egld_big(
+    /// Get the borrow cap utilization of a market in BPS
+    pub fn borrow_cap_utilization(
         &mut self,
-        account_position: u64,
+        token_id: TestTokenIdentifier,
     ) -> ManagedDecimal<StaticApi, NumDecimals> {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .total_collateral_in_egld(account_position)
+            .borrow_cap_utilization(EgldOrEsdtTokenIdentifier::esdt(
+                token_id.to_esdt_token_identifier(),
+            ))
             .returns(ReturnsResult)
             .run()
     }
 
-    /// Get LTV collateral in EGLD
-    pub fn ltv_collateral_in_egld(
+    /// Get the recent activity of a market, oldest first
+    pub fn recent_market_activity(
         &mut self,
-        account_position: u64,
-    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        token_id: TestTokenIdentifier,
+    ) -> ManagedVec<StaticApi, MarketActivity<StaticApi>> {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .ltv_collateral_in_egld(account_position)
+            .recent_market_activity(EgldOrEsdtTokenIdentifier::esdt(
+                token_id.to_esdt_token_identifier(),
+            ))
             .returns(ReturnsResult)
             .run()
     }
 
-    /// Get liquidation collateral available
-    pub fn liquidation_collateral_available(
+    /// Get the most recent liquidations of an account
+    pub fn liquidation_history(
         &mut self,
-        account_position: u64,
-    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+        account_nonce: u64,
+    ) -> ManagedVec<StaticApi, LiquidationRecord<StaticApi>> {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .liquidation_collateral_available(account_position)
+            .account_liquidation_history(account_nonce)
             .returns(ReturnsResult)
             .run()
     }
 
-    /// Get deposit positions
-    pub fn deposit_positions(
+    /// Get the borrow and supply interest accrued on a token since a timestamp
+    pub fn interest_accrued_since(
         &mut self,
-        nonce: u64,
-    ) -> MultiValueEncoded<
-        StaticApi,
-        MultiValue2<EgldOrEsdtTokenIdentifier<StaticApi>, AccountPosition<StaticApi>>,
-    > {
+        account_position: u64,
+        token_id: TestTokenIdentifier,
+        since_timestamp: u64,
+    ) -> (
+        ManagedDecimal<StaticApi, NumDecimals>,
+        ManagedDecimal<StaticApi, NumDecimals>,
+    ) {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .positions(nonce, AccountPositionType::Deposit)
+            .interest_accrued_since(
+                account_position,
+                token_id,
+                TimestampSeconds::new(since_timestamp),
+            )
             .returns(ReturnsResult)
             .run()
+            .into_tuple()
     }
 
-    /// Get borrow positions
-    pub fn borrow_positions(
+    /// Get the interest and reward yield breakdown of a market since a timestamp
+    pub fn deposit_yield_breakdown(
         &mut self,
-        nonce: u64,
-    ) -> MultiValueEncoded<
-        StaticApi,
-        MultiValue2<EgldOrEsdtTokenIdentifier<StaticApi>, AccountPosition<StaticApi>>,
-    > {
+        token_id: TestTokenIdentifier,
+        since_timestamp: u64,
+    ) -> DepositYieldBreakdown<StaticApi> {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .positions(nonce, AccountPositionType::Borrow)
+            .deposit_yield_breakdown(token_id, TimestampSeconds::new(since_timestamp))
             .returns(ReturnsResult)
             .run()
     }
 
-    /// Get liquidation estimations
-    pub fn liquidation_estimations(
+    /// Get the borrow and supply rate spread of multiple markets
+    pub fn market_rate_spread(
         &mut self,
-        account_nonce: u64,
-        debt_payments: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
-    ) -> LiquidationEstimate<StaticApi> {
+        assets: MultiValueEncoded<StaticApi, EgldOrEsdtTokenIdentifier<StaticApi>>,
+    ) -> ManagedVec<StaticApi, MarketRateSpreadView<StaticApi>> {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .liquidation_estimations(account_nonce, debt_payments)
+            .market_rate_spread(assets)
             .returns(ReturnsResult)
             .run()
     }
 
-    // ============================================
-    // VIEW FUNCTIONS - MARKET DATA
-    // ============================================
-
-    /// Get all market indexes
-    pub fn all_market_indexes(
+    /// Get the interest accrued since a timestamp expecting an error
+    pub fn interest_accrued_since_error(
         &mut self,
-        assets: MultiValueEncoded<StaticApi, EgldOrEsdtTokenIdentifier<StaticApi>>,
-    ) -> ManagedVec<StaticApi, MarketIndexView<StaticApi>> {
+        account_position: u64,
+        token_id: TestTokenIdentifier,
+        since_timestamp: u64,
+        error_message: &[u8],
+    ) {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .all_market_indexes(assets)
-            .returns(ReturnsResult)
-            .run()
+            .interest_accrued_since(
+                account_position,
+                token_id,
+                TimestampSeconds::new(since_timestamp),
+            )
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
     }
 
-    /// Get all markets
-    pub fn all_markets(
+    /// Get used isolated asset debt in USD
+    pub fn used_isolated_asset_debt_usd(
         &mut self,
-        assets: MultiValueEncoded<StaticApi, EgldOrEsdtTokenIdentifier<StaticApi>>,
-    ) -> ManagedVec<StaticApi, AssetExtendedConfigView<StaticApi>> {
+        token_id: &TestTokenIdentifier,
+    ) -> ManagedDecimal<StaticApi, NumDecimals> {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .all_markets(assets)
+            .isolated_asset_debt_usd(token_id)
             .returns(ReturnsResult)
             .run()
     }
 
-    /// Get used isolated asset debt in USD
-    pub fn used_isolated_asset_debt_usd(
+    /// Get used debt, debt ceiling and remaining headroom of an isolated asset
+    pub fn isolated_debt_info(
         &mut self,
         token_id: &TestTokenIdentifier,
-    ) -> ManagedDecimal<StaticApi, NumDecimals> {
+    ) -> (
+        ManagedDecimal<StaticApi, NumDecimals>,
+        ManagedDecimal<StaticApi, NumDecimals>,
+        ManagedDecimal<StaticApi, NumDecimals>,
+    ) {
         self.world
             .query()
             .to(self.lending_sc.clone())
             .typed(proxy_lending_pool::ControllerProxy)
-            .isolated_asset_debt_usd(token_id)
+            .isolated_debt_info(token_id)
             .returns(ReturnsResult)
             .run()
+            .into_tuple()
     }
 
     // ============================================
@@ -2358,6 +4869,31 @@ impl LendingPoolTestState {
             .run()
     }
 
+    /// Get decoded account attributes
+    pub fn decode_account_attributes(
+        &mut self,
+        account_nonce: u64,
+    ) -> AccountAttributes<StaticApi> {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .decode_account_attributes(account_nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Get decoded account attributes with error
+    pub fn decode_account_attributes_error(&mut self, account_nonce: u64, error_message: &[u8]) {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .decode_account_attributes(account_nonce)
+            .returns(ExpectMessage(core::str::from_utf8(error_message).unwrap()))
+            .run();
+    }
+
     pub fn market_borrow_index(
         &mut self,
         market_address: ManagedAddress<StaticApi>,
@@ -2384,6 +4920,42 @@ impl LendingPoolTestState {
             .run()
     }
 
+    pub fn market_last_timestamp(
+        &mut self,
+        market_address: ManagedAddress<StaticApi>,
+    ) -> TimestampMillis {
+        self.world
+            .query()
+            .to(market_address)
+            .typed(proxy_liquidity_pool::LiquidityPoolProxy)
+            .last_timestamp()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn market_accrual_paused(&mut self, market_address: ManagedAddress<StaticApi>) -> bool {
+        self.world
+            .query()
+            .to(market_address)
+            .typed(proxy_liquidity_pool::LiquidityPoolProxy)
+            .accrual_paused()
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn market_revenue_absorbs_bad_debt(
+        &mut self,
+        market_address: ManagedAddress<StaticApi>,
+    ) -> bool {
+        self.world
+            .query()
+            .to(market_address)
+            .typed(proxy_liquidity_pool::LiquidityPoolProxy)
+            .revenue_absorbs_bad_debt()
+            .returns(ReturnsResult)
+            .run()
+    }
+
     pub fn market_supplied(
         &mut self,
         market_address: ManagedAddress<StaticApi>,
@@ -2657,6 +5229,16 @@ impl LendingPoolTestState {
             .run()
     }
 
+    pub fn token_oracle_twap_rounds(&mut self, token: EgldOrEsdtTokenIdentifier<StaticApi>) -> u8 {
+        self.world
+            .query()
+            .to(self.lending_sc.clone())
+            .typed(proxy_lending_pool::ControllerProxy)
+            .twap_rounds(token)
+            .returns(ReturnsResult)
+            .run()
+    }
+
     /// Check if flash loan is ongoing
     pub fn is_flash_loan_ongoing(&mut self) -> bool {
         self.world
@@ -3631,6 +6213,37 @@ pub fn submit_price(
     }
 }
 
+/// Submit one price per oracle to aggregator, in oracle order
+pub fn submit_oracle_prices(
+    world: &mut ScenarioWorld,
+    price_aggregator_sc: &ManagedAddress<StaticApi>,
+    from: &[u8],
+    prices: [u64; 4],
+    timestamp: u64,
+) {
+    let oracles = [
+        ORACLE_ADDRESS_1,
+        ORACLE_ADDRESS_2,
+        ORACLE_ADDRESS_3,
+        ORACLE_ADDRESS_4,
+    ];
+
+    for (oracle, price) in oracles.into_iter().zip(prices) {
+        world
+            .tx()
+            .from(oracle)
+            .to(price_aggregator_sc)
+            .typed(proxy_aggregator::PriceAggregatorProxy)
+            .submit(
+                ManagedBuffer::from(from),
+                ManagedBuffer::from(DOLLAR_TICKER),
+                TimestampSeconds::new(timestamp),
+                BigUint::from(price).mul(BigUint::from(WAD)),
+            )
+            .run();
+    }
+}
+
 /// Setup template liquidity pool
 pub fn setup_template_liquidity_pool(world: &mut ScenarioWorld) -> ManagedAddress<StaticApi> {
     world
@@ -3847,6 +6460,8 @@ pub fn setup_market(
             config.asset_decimals,
             config.config.borrow_cap_wad.unwrap_or(BigUint::zero()),
             config.config.supply_cap_wad.unwrap_or(BigUint::zero()),
+            OptionalValue::Some(BigUint::from(MAX_LIQUIDATION_CLOSE_FACTOR)),
+            OptionalValue::<BigUint<StaticApi>>::None,
         )
         .returns(ReturnsResult)
         .run();
@@ -4270,6 +6885,25 @@ impl LendingPoolTestState {
             .run()
     }
 
+    /// Get market borrow and supply rates at a hypothetical utilization
+    pub fn market_rates_at_utilization(
+        &mut self,
+        market_address: ManagedAddress<StaticApi>,
+        utilization_ray: BigUint<StaticApi>,
+    ) -> (
+        ManagedDecimal<StaticApi, NumDecimals>,
+        ManagedDecimal<StaticApi, NumDecimals>,
+    ) {
+        self.world
+            .query()
+            .to(market_address)
+            .typed(proxy_liquidity_pool::LiquidityPoolProxy)
+            .get_rates_at_utilization(utilization_ray)
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple()
+    }
+
     /// Get market supply rate
     pub fn market_supply_rate(
         &mut self,
@@ -4318,6 +6952,23 @@ impl LendingPoolTestState {
 // ============================================
 
 /// Returns the on-chain base units for a human-readable amount.
+/// Builds batch strategy payments: account NFTs first, then one collateral per account.
+pub fn batch_payments(
+    account_nonces: &[u64],
+    collaterals: ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>,
+) -> ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>> {
+    let mut payments = ManagedVec::<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>::new();
+    for account_nonce in account_nonces {
+        payments.push(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(ACCOUNT_TOKEN.to_esdt_token_identifier()),
+            *account_nonce,
+            BigUint::from(1u64),
+        ));
+    }
+    payments.append_vec(collaterals);
+    payments
+}
+
 pub fn scaled_amount(amount: u128, decimals: usize) -> BigUint<StaticApi> {
     BigUint::from(amount) * BigUint::from(10u64).pow(decimals as u32)
 }
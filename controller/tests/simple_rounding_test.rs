@@ -137,3 +137,74 @@ fn rounding_precision_stays_stable_for_dust_flows() {
     state.assert_no_borrow_entry(borrower_nonce, &USDC_TOKEN);
     state.assert_no_borrow_entry(borrower_nonce, &EGLD_TOKEN);
 }
+
+/// Validates that protocol-favorable rounding is tracked per market so the
+/// retained dust is visible through `getAccumulatedRounding`.
+#[test]
+fn rounding_accumulator_grows_with_non_clean_amounts() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset_den(
+        &supplier,
+        USDC_TOKEN,
+        BigUint::from(10_000_000_000u64),
+        OptionalValue::None,
+        OptionalValue::None,
+        false,
+    );
+    state.supply_asset_den(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(1_000_000_000u64),
+        OptionalValue::None,
+        OptionalValue::None,
+        false,
+    );
+
+    let borrower_nonce = 2;
+    state.borrow_asset_den(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(500_000_000u64),
+        borrower_nonce,
+    );
+
+    // Accrue interest so both indexes move away from a clean 1.0
+    state.change_timestamp(SECONDS_PER_YEAR / 3 + 17);
+    let rounding_before = state.accumulated_rounding(USDC_TOKEN);
+
+    for cycle_number in 0..20u64 {
+        state.change_timestamp(SECONDS_PER_YEAR / 3 + 17 + (cycle_number + 1) * 3_607);
+        state.supply_asset_den(
+            &supplier,
+            USDC_TOKEN,
+            BigUint::from(1_000_003u64 + cycle_number * 7_919),
+            OptionalValue::Some(1),
+            OptionalValue::None,
+            false,
+        );
+        state.borrow_asset_den(
+            &borrower,
+            USDC_TOKEN,
+            BigUint::from(333_331u64 + cycle_number * 104_729),
+            borrower_nonce,
+        );
+        state.repay_asset_deno(
+            &borrower,
+            &USDC_TOKEN,
+            BigUint::from(123_457u64 + cycle_number * 65_537),
+            borrower_nonce,
+        );
+    }
+
+    let rounding_after = state.accumulated_rounding(USDC_TOKEN);
+    assert!(
+        rounding_after > rounding_before,
+        "Rounding accumulator should grow across non-clean operations",
+    );
+}
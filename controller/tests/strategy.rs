@@ -1,10 +1,15 @@
+use common_constants::{BPS, RAY};
 use controller::{
-    PositionMode, ERROR_ASSETS_ARE_THE_SAME, ERROR_INVALID_NUMBER_OF_ESDT_TRANSFERS,
-    ERROR_INVALID_POSITION_MODE, ERROR_MULTIPLY_REQUIRE_EXTRA_STEPS,
-    ERROR_SWAP_COLLATERAL_NOT_SUPPORTED, ERROR_SWAP_DEBT_NOT_SUPPORTED,
+    PositionMode, ERROR_ASSETS_ARE_THE_SAME, ERROR_BATCH_ACCOUNTS_LIMIT_EXCEEDED,
+    ERROR_DEBT_CREATION_DISABLED, ERROR_DELEVERAGE_SWAP_SHORTFALL, ERROR_HEALTH_FACTOR_WITHDRAW,
+    ERROR_INVALID_MIN_STRATEGY_HEALTH_FACTOR, ERROR_INVALID_NUMBER_OF_ESDT_TRANSFERS,
+    ERROR_INVALID_POSITION_MODE, ERROR_MAX_POSITION_VALUE_EXCEEDED, ERROR_MIX_ISOLATED_COLLATERAL,
+    ERROR_MULTIPLY_REQUIRE_EXTRA_STEPS, ERROR_SWAP_COLLATERAL_NOT_SUPPORTED,
+    ERROR_SWAP_DEBT_NOT_SUPPORTED,
 };
 use multiversx_sc::types::{
-    EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, ManagedArgBuffer, ManagedVec,
+    EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, ManagedArgBuffer, ManagedBuffer, ManagedVec,
+    MultiValueEncoded,
 };
 use multiversx_sc_scenario::{
     api::StaticApi,
@@ -356,6 +361,335 @@ fn multiply_strategy_success_payment_as_collateral_flow_increase_leverage() {
     );
 }
 
+/// Tests the multiply origination fee routed to the debt market revenue.
+///
+/// Covers:
+/// - Controller::setMultiplyOriginationFee configuration
+/// - Origination fee charged on the flash-loaned debt on top of the flash loan fee
+/// - Debt recorded at the full flash-loaned amount
+#[test]
+fn multiply_strategy_origination_fee_to_revenue() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+    // 1% origination fee
+    state.set_multiply_origination_fee(100);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+
+    let wanted_debt = scaled_amount(100, EGLD_DECIMALS);
+    state.multiply(
+        &borrower,
+        MultiplyParams {
+            e_mode_category: 1,
+            collateral_token: EgldOrEsdtTokenIdentifier::from(XEGLD_TOKEN.as_bytes()),
+            debt_to_flash_loan: wanted_debt.clone(),
+            debt_token: EgldOrEsdtTokenIdentifier::from(EGLD_TOKEN.as_bytes()),
+            mode: PositionMode::Multiply,
+            steps: leverage_steps(&XEGLD_TOKEN, scaled_amount(100, XEGLD_DECIMALS)),
+            steps_payment: OptionalValue::None,
+            payments: single_payment(&XEGLD_TOKEN, scaled_amount(20, XEGLD_DECIMALS)),
+        },
+    );
+
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        wanted_debt,
+        "Debt should include the full flash-loaned amount",
+    );
+    // 0.5 EGLD flash loan fee plus 1 EGLD origination fee
+    let market_revenue = state.market_revenue(state.egld_market.clone());
+    assert_eq!(
+        market_revenue.as_raw_units().clone(),
+        BigUint::from(WAD) * 3u64 / 2u64
+    );
+}
+
+/// Tests the global kill-switch blocking every debt-creating operation.
+///
+/// Covers:
+/// - Controller::setDebtCreationEnabled configuration
+/// - ERROR_DEBT_CREATION_DISABLED on borrow, multiply and flashLoan
+/// - Supply, repay and withdraw unaffected while debt creation is disabled
+/// - Borrowing allowed again once the switch is re-enabled
+#[test]
+fn debt_creation_disabled_blocks_only_new_debt() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(10u64),
+            asset_decimals: XEGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    state.borrow_asset(&borrower, EGLD_TOKEN, BigUint::from(2u64), 2, EGLD_DECIMALS);
+
+    state.set_debt_creation_enabled(false);
+
+    state.borrow_asset_error(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+        ERROR_DEBT_CREATION_DISABLED,
+    );
+    state.multiply_error(
+        &borrower,
+        MultiplyParams {
+            e_mode_category: 1,
+            collateral_token: EgldOrEsdtTokenIdentifier::from(XEGLD_TOKEN.as_bytes()),
+            debt_to_flash_loan: scaled_amount(10, EGLD_DECIMALS),
+            debt_token: EgldOrEsdtTokenIdentifier::from(EGLD_TOKEN.as_bytes()),
+            mode: PositionMode::Multiply,
+            steps: leverage_steps(&XEGLD_TOKEN, scaled_amount(10, XEGLD_DECIMALS)),
+            steps_payment: OptionalValue::None,
+            payments: single_payment(&XEGLD_TOKEN, scaled_amount(2, XEGLD_DECIMALS)),
+        },
+        ERROR_DEBT_CREATION_DISABLED,
+    );
+    state.flash_loan_error(
+        &OWNER_ADDRESS,
+        FlashLoanParams {
+            token: EGLD_TOKEN,
+            amount: scaled_amount(10, EGLD_DECIMALS),
+            contract: state.flash_mock.clone(),
+            endpoint: ManagedBuffer::from("flash"),
+            arguments: ManagedArgBuffer::new(),
+        },
+        ERROR_DEBT_CREATION_DISABLED,
+    );
+
+    // Operations that do not create debt keep working
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(1u64),
+            asset_decimals: XEGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    state.repay_asset(
+        &borrower,
+        &EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    state.withdraw_asset(
+        &borrower,
+        XEGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        XEGLD_DECIMALS,
+    );
+    state.assert_collateral_raw_eq(
+        2,
+        &XEGLD_TOKEN,
+        scaled_amount(10, XEGLD_DECIMALS),
+        "Supply and withdraw should succeed while debt creation is disabled",
+    );
+
+    state.set_debt_creation_enabled(true);
+    state.borrow_asset(&borrower, EGLD_TOKEN, BigUint::from(1u64), 2, EGLD_DECIMALS);
+    state.assert_health_factor_at_least(2, RAY);
+}
+
+#[test]
+fn multiply_strategy_min_health_factor_enforced() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+
+    // Size the swapped collateral so the position lands at HF 1.02:
+    // collateral = 1.02 * debt / (xegld_price * e_mode_liquidation_threshold)
+    let debt = BigUint::<StaticApi>::from(100u64) * BigUint::from(WAD);
+    let initial_collateral = BigUint::<StaticApi>::from(20u64) * BigUint::from(WAD);
+    let xegld_price_wad = state.egld_price(XEGLD_TOKEN).into_raw_units().clone();
+    let target_collateral =
+        debt.clone() * BigUint::from(102u64) * BigUint::from(BPS) * BigUint::from(WAD)
+            / (BigUint::from(100u64) * BigUint::from(E_MODE_LIQ_THRESHOLD) * xegld_price_wad);
+    let swapped_collateral = target_collateral - initial_collateral.clone();
+
+    let params = || MultiplyParams {
+        e_mode_category: 1,
+        collateral_token: EgldOrEsdtTokenIdentifier::from(XEGLD_TOKEN.as_bytes()),
+        debt_to_flash_loan: debt.clone(),
+        debt_token: EgldOrEsdtTokenIdentifier::from(EGLD_TOKEN.as_bytes()),
+        mode: PositionMode::Multiply,
+        steps: leverage_steps(&XEGLD_TOKEN, swapped_collateral.clone()),
+        steps_payment: OptionalValue::None,
+        payments: single_payment(&XEGLD_TOKEN, initial_collateral.clone()),
+    };
+
+    // Strategy minimum of 1.1 rejects the 1.02 landing
+    state.set_min_strategy_health_factor(BigUint::from(RAY + RAY / 10));
+    state.multiply_error(&borrower, params(), ERROR_HEALTH_FACTOR_WITHDRAW);
+
+    // Strategy minimum of 1.0 accepts the same leverage
+    state.set_min_strategy_health_factor(BigUint::from(RAY));
+    state.multiply(&borrower, params());
+
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        debt,
+        "Multiply should record EGLD debt once the strategy minimum allows it",
+    );
+    state.assert_health_factor_at_least(2, RAY);
+}
+
+/// Tests that the maximum position value also bounds debt opened through multiply.
+///
+/// Covers:
+/// - Controller::multiply endpoint with a maximum position value configured
+/// - ERROR_MAX_POSITION_VALUE_EXCEEDED on the strategy borrow
+/// - Multiply succeeding once the cap is lifted
+#[test]
+fn multiply_exceeds_max_position_value_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+
+    let debt = BigUint::<StaticApi>::from(50u64) * BigUint::from(WAD);
+    let initial_collateral = BigUint::<StaticApi>::from(20u64) * BigUint::from(WAD);
+    let params = || MultiplyParams {
+        e_mode_category: 1,
+        collateral_token: EgldOrEsdtTokenIdentifier::from(XEGLD_TOKEN.as_bytes()),
+        debt_to_flash_loan: debt.clone(),
+        debt_token: EgldOrEsdtTokenIdentifier::from(EGLD_TOKEN.as_bytes()),
+        mode: PositionMode::Multiply,
+        steps: leverage_steps(&XEGLD_TOKEN, debt.clone()),
+        steps_payment: OptionalValue::None,
+        payments: single_payment(&XEGLD_TOKEN, initial_collateral.clone()),
+    };
+
+    // A 40 EGLD cap is below the 50 EGLD strategy debt
+    state.set_max_position_value(scaled_amount(40, EGLD_DECIMALS));
+    state.multiply_error(&borrower, params(), ERROR_MAX_POSITION_VALUE_EXCEEDED);
+
+    state.set_max_position_value(BigUint::zero());
+    state.multiply(&borrower, params());
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        debt,
+        "Multiply should record EGLD debt once the cap is lifted",
+    );
+}
+
+#[test]
+fn set_min_strategy_health_factor_below_one_error() {
+    let mut state = LendingPoolTestState::new();
+
+    state.set_min_strategy_health_factor_error(
+        BigUint::from(RAY - 1),
+        ERROR_INVALID_MIN_STRATEGY_HEALTH_FACTOR,
+    );
+}
+
 #[test]
 fn multiply_strategy_invalid_mode_error() {
     let mut state = LendingPoolTestState::new();
@@ -745,6 +1079,111 @@ fn repay_debt_with_collateral_partial() {
     );
 }
 
+/// Tests unwinding a leveraged position in fractions with `deleverage`.
+///
+/// Covers:
+/// - Controller::deleverage repaying half the debt with collateral of equal value
+/// - ERROR_DELEVERAGE_SWAP_SHORTFALL when the swap output does not cover the debt fraction
+/// - Extra debt token payments covering swap slippage
+/// - Full deleverage closing the position and burning the account NFT
+#[test]
+fn deleverage_partial_then_full_closes_position() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(200u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+
+    // 20 XEGLD of equity levered with 100 EGLD of debt into 120 XEGLD of collateral
+    let wanted_debt = scaled_amount(100, EGLD_DECIMALS);
+    state.multiply(
+        &borrower,
+        MultiplyParams {
+            e_mode_category: 1,
+            collateral_token: EgldOrEsdtTokenIdentifier::from(XEGLD_TOKEN.as_bytes()),
+            debt_to_flash_loan: wanted_debt.clone(),
+            debt_token: EgldOrEsdtTokenIdentifier::from(EGLD_TOKEN.as_bytes()),
+            mode: PositionMode::Multiply,
+            steps: leverage_steps(&XEGLD_TOKEN, scaled_amount(100, XEGLD_DECIMALS)),
+            steps_payment: OptionalValue::None,
+            payments: single_payment(&XEGLD_TOKEN, scaled_amount(20, XEGLD_DECIMALS)),
+        },
+    );
+    state.assert_borrow_raw_eq(2, &EGLD_TOKEN, wanted_debt, "Multiply should open the debt");
+
+    let deleverage_params = |fraction_bps: u64, swap_output: u128, extra_egld: u128| {
+        let mut payments = account_nft_payment(2);
+        if extra_egld > 0 {
+            payments.push(EgldOrEsdtTokenPayment::new(
+                EgldOrEsdtTokenIdentifier::from(EGLD_TOKEN.as_bytes()),
+                0,
+                scaled_amount(extra_egld, EGLD_DECIMALS),
+            ));
+        }
+        DeleverageParams {
+            collateral_token: EgldOrEsdtTokenIdentifier::from(XEGLD_TOKEN.as_bytes()),
+            debt_token: EgldOrEsdtTokenIdentifier::from(EGLD_TOKEN.as_bytes()),
+            fraction_bps,
+            steps: leverage_steps(&EGLD_TOKEN, scaled_amount(swap_output, EGLD_DECIMALS)),
+            payments,
+        }
+    };
+
+    // The swap returns less than the half of the debt being unwound
+    state.deleverage_error(
+        &borrower,
+        deleverage_params(5_000, 49, 0),
+        ERROR_DELEVERAGE_SWAP_SHORTFALL,
+    );
+
+    // Half of the debt (50 EGLD) is repaid with 40 XEGLD ($2000 at $50 each)
+    state.deleverage(&borrower, deleverage_params(5_000, 50, 0));
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(50, EGLD_DECIMALS),
+        "Half of the debt should remain",
+    );
+    state.assert_collateral_raw_eq(
+        2,
+        &XEGLD_TOKEN,
+        scaled_amount(80, XEGLD_DECIMALS),
+        "Collateral of equal value should be removed",
+    );
+    state.assert_health_factor_at_least(2, RAY);
+
+    // Full deleverage with 1 EGLD of slippage covered by an extra payment
+    state.deleverage(&borrower, deleverage_params(BPS as u64, 49, 1));
+    state.assert_no_borrow_entry(2, &EGLD_TOKEN);
+    state.assert_no_collateral_entry(2, &XEGLD_TOKEN);
+    assert!(
+        !state.accounts().into_iter().any(|nonce| nonce == 2),
+        "fully deleveraged account should be burned",
+    );
+}
+
 #[test]
 fn swap_collateral() {
     let mut state = LendingPoolTestState::new();
@@ -848,6 +1287,77 @@ fn swap_collateral() {
     );
 }
 
+/// Tests swapping collateral with the default swap steps of the pair.
+///
+/// Covers:
+/// - Controller::setDefaultSwapSteps configuration
+/// - Controller::swapCollateral with empty steps falling back to the default steps
+#[test]
+fn swap_collateral_with_default_steps_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(20u64),
+            asset_decimals: XEGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+
+    // Default route for XEGLD -> EGLD, encoded as the router arguments
+    let swap_out = scaled_amount(10, EGLD_DECIMALS);
+    let mut default_steps = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+    default_steps.push(ManagedBuffer::from(EGLD_TOKEN.as_bytes()));
+    default_steps.push(swap_out.to_bytes_be_buffer());
+    state.set_default_swap_steps(&XEGLD_TOKEN, &EGLD_TOKEN, default_steps);
+
+    let mut nft_payment = ManagedVec::<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>::new();
+    nft_payment.push(EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::from(ACCOUNT_TOKEN.as_bytes()),
+        2,
+        BigUint::from(1u64),
+    ));
+    state.swap_collateral(
+        &borrower,
+        &EgldOrEsdtTokenIdentifier::from(XEGLD_TOKEN.as_bytes()),
+        scaled_amount(5, XEGLD_DECIMALS),
+        &EgldOrEsdtTokenIdentifier::from(EGLD_TOKEN.as_bytes()),
+        ManagedArgBuffer::new(),
+        nft_payment,
+    );
+
+    state.assert_collateral_raw_eq(
+        2,
+        &XEGLD_TOKEN,
+        scaled_amount(15, XEGLD_DECIMALS),
+        "swapped XEGLD should leave the account",
+    );
+    state.assert_collateral_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        swap_out,
+        "default steps should route the swap to EGLD",
+    );
+}
+
 /// Tests that calling a strategy endpoint that requires the account NFT
 /// with a non-NFT first payment triggers ERROR_INVALID_NUMBER_OF_ESDT_TRANSFERS
 /// via the else-branch in validate_supply_payment.
@@ -1181,3 +1691,351 @@ fn swap_collateral_target_isolated_asset_error() {
         ERROR_SWAP_COLLATERAL_NOT_SUPPORTED,
     );
 }
+
+/// Tests that a batch collateral top-up applies to every account NFT atomically.
+///
+/// Covers:
+/// - Controller::batchAddCollateral endpoint with two account NFTs
+/// - Whole batch reverted when one account's top-up is invalid
+/// - ERROR_BATCH_ACCOUNTS_LIMIT_EXCEEDED when the batch exceeds the configured limit
+#[test]
+fn batch_add_collateral_multiple_accounts_atomic_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+    state.set_max_batch_accounts(2);
+
+    // The supplier opens two separate accounts
+    for _ in 0..2 {
+        state.supply_asset(
+            &supplier,
+            SupplyParams {
+                token_id: USDC_TOKEN,
+                amount: BigUint::from(1000u64),
+                asset_decimals: USDC_DECIMALS,
+                account_nonce: OptionalValue::None,
+                e_mode_category: OptionalValue::None,
+            },
+        );
+    }
+
+    // Top up both accounts in a single call
+    let mut collaterals = ManagedVec::<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>::new();
+    collaterals.push(EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN),
+        0,
+        scaled_amount(100, USDC_DECIMALS),
+    ));
+    collaterals.push(EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN),
+        0,
+        scaled_amount(50, USDC_DECIMALS),
+    ));
+    state.batch_add_collateral(&supplier, &[1, 2], collaterals);
+
+    state.assert_collateral_raw_eq(
+        1,
+        &USDC_TOKEN,
+        scaled_amount(1100, USDC_DECIMALS),
+        "first account should receive its top-up",
+    );
+    state.assert_collateral_raw_eq(
+        2,
+        &USDC_TOKEN,
+        scaled_amount(1050, USDC_DECIMALS),
+        "second account should receive its top-up",
+    );
+
+    // An isolated asset cannot be added to the second account, so the whole batch reverts
+    let mut collaterals = ManagedVec::<StaticApi, EgldOrEsdtTokenPayment<StaticApi>>::new();
+    collaterals.push(EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN),
+        0,
+        scaled_amount(10, USDC_DECIMALS),
+    ));
+    collaterals.push(EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(ISOLATED_TOKEN),
+        0,
+        scaled_amount(10, ISOLATED_DECIMALS),
+    ));
+    state.batch_add_collateral_error(
+        &supplier,
+        &[1, 2],
+        collaterals.clone(),
+        ERROR_MIX_ISOLATED_COLLATERAL,
+    );
+
+    state.assert_collateral_raw_eq(
+        1,
+        &USDC_TOKEN,
+        scaled_amount(1100, USDC_DECIMALS),
+        "reverted batch must not top up the first account",
+    );
+    state.assert_no_collateral_entry(2, &ISOLATED_TOKEN);
+
+    // Batches larger than the configured limit are rejected
+    state.set_max_batch_accounts(1);
+    state.batch_add_collateral_error(
+        &supplier,
+        &[1, 2],
+        collaterals,
+        ERROR_BATCH_ACCOUNTS_LIMIT_EXCEEDED,
+    );
+}
+
+/// Tests that the multiply preview matches the outcome of the executed multiply.
+///
+/// Covers:
+/// - Controller::previewMultiply on an existing e-mode account
+/// - Projected collateral, debt and health factor against the actual multiply
+/// - Leverage computed as collateral over equity
+#[test]
+fn multiply_preview_matches_execution() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(200u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+
+    let collateral_token = EgldOrEsdtTokenIdentifier::from(XEGLD_TOKEN.as_bytes());
+    let debt_token = EgldOrEsdtTokenIdentifier::from(EGLD_TOKEN.as_bytes());
+    let debt = BigUint::<StaticApi>::from(50u64) * BigUint::from(WAD);
+    let swap_out = BigUint::<StaticApi>::from(45u64) * BigUint::from(WAD);
+    let params = |payments| MultiplyParams {
+        e_mode_category: 1,
+        collateral_token: collateral_token.clone(),
+        debt_to_flash_loan: debt.clone(),
+        debt_token: debt_token.clone(),
+        mode: PositionMode::Multiply,
+        steps: leverage_steps(&XEGLD_TOKEN, swap_out.clone()),
+        steps_payment: OptionalValue::None,
+        payments,
+    };
+    state.multiply(
+        &borrower,
+        params(single_payment(
+            &XEGLD_TOKEN,
+            BigUint::from(20u64) * BigUint::from(WAD),
+        )),
+    );
+
+    let (collateral, debt_value, leverage, health_factor) = state.preview_multiply(
+        2,
+        &collateral_token,
+        &debt_token,
+        debt.clone(),
+        swap_out.clone(),
+    );
+    state.multiply(&borrower, params(account_nft_payment(2)));
+
+    let within = |expected: &BigUint<StaticApi>, actual: &BigUint<StaticApi>, tolerance: u64| {
+        let diff = if expected > actual {
+            expected - actual
+        } else {
+            actual - expected
+        };
+        diff <= BigUint::from(tolerance)
+    };
+    let actual_collateral = state.total_collateral_in_egld(2);
+    let actual_debt = state.total_borrow_in_egld(2);
+    let actual_health_factor = state.account_health_factor(2);
+    assert!(within(
+        collateral.into_raw_units(),
+        actual_collateral.into_raw_units(),
+        1_000
+    ));
+    assert!(within(
+        debt_value.into_raw_units(),
+        actual_debt.into_raw_units(),
+        1_000
+    ));
+    assert!(within(
+        health_factor.into_raw_units(),
+        actual_health_factor.into_raw_units(),
+        1_000_000_000_000
+    ));
+
+    // Leverage is the collateral carried per unit of equity
+    let expected_leverage = actual_collateral.into_raw_units() * BigUint::from(RAY)
+        / (actual_collateral.into_raw_units() - actual_debt.into_raw_units());
+    assert!(within(
+        &expected_leverage,
+        leverage.into_raw_units(),
+        1_000_000_000_000
+    ));
+}
+
+/// Tests repaying debt with a payment in another token through `repayWithSwap`.
+///
+/// Covers:
+/// - Controller::repay_with_swap repaying the swap output of a USDC payment on EGLD debt
+/// - Swap output above the outstanding debt closing the borrow position
+/// - ERROR_ASSETS_ARE_THE_SAME when the payment token is the debt token
+#[test]
+fn repay_with_swap_partial_then_full_repayment() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(10u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    let repay_params =
+        |payment_token: &TestTokenIdentifier, swap_output: u128| RepayWithSwapParams {
+            account_nonce: 2,
+            debt_token: EgldOrEsdtTokenIdentifier::from(EGLD_TOKEN.as_bytes()),
+            payment_token: EgldOrEsdtTokenIdentifier::from(payment_token.as_bytes()),
+            steps: leverage_steps(&EGLD_TOKEN, scaled_amount(swap_output, EGLD_DECIMALS)),
+            payments: single_payment(payment_token, scaled_amount(500, USDC_DECIMALS)),
+        };
+
+    state.repay_with_swap_error(
+        &borrower,
+        repay_params(&EGLD_TOKEN, 4),
+        ERROR_ASSETS_ARE_THE_SAME,
+    );
+
+    // 500 USDC swapped to 4 EGLD repays part of the debt
+    state.repay_with_swap(&borrower, repay_params(&USDC_TOKEN, 4));
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(6, EGLD_DECIMALS),
+        "Swap output should be repaid",
+    );
+
+    // A swap output above the remaining debt closes the position, the pool refunds the excess
+    state.repay_with_swap(&borrower, repay_params(&USDC_TOKEN, 10));
+    let custom_error_message = format!("Token not existing in the account {}", EGLD_TOKEN.as_str());
+    state.borrow_amount_for_token_non_existing(2, EGLD_TOKEN, custom_error_message.as_bytes());
+}
+
+/// Tests that an account NFT attached to `repayWithSwap` is returned to the caller.
+///
+/// Covers:
+/// - Controller::repay_with_swap with the account NFT sent ahead of the payment
+/// - The NFT returned after the repayment, so the account stays usable
+#[test]
+fn repay_with_swap_returns_attached_account_nft() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(10u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    let mut payments = account_nft_payment(2);
+    payments.push(EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::from(USDC_TOKEN.as_bytes()),
+        0,
+        scaled_amount(500, USDC_DECIMALS),
+    ));
+
+    // 500 USDC swapped to 4 EGLD with the account NFT attached
+    state.repay_with_swap(
+        &borrower,
+        RepayWithSwapParams {
+            account_nonce: 2,
+            debt_token: EgldOrEsdtTokenIdentifier::from(EGLD_TOKEN.as_bytes()),
+            payment_token: EgldOrEsdtTokenIdentifier::from(USDC_TOKEN.as_bytes()),
+            steps: leverage_steps(&EGLD_TOKEN, scaled_amount(4, EGLD_DECIMALS)),
+            payments,
+        },
+    );
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(6, EGLD_DECIMALS),
+        "Swap output should be repaid",
+    );
+
+    // The borrower holds the NFT again and can keep using the account
+    state.withdraw_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(100u64),
+        2,
+        USDC_DECIMALS,
+    );
+}
@@ -1,12 +1,16 @@
+use common_constants::RAY;
 use controller::{
-    ERROR_ACCOUNT_NOT_IN_THE_MARKET, ERROR_BULK_SUPPLY_NOT_SUPPORTED,
-    ERROR_INVALID_NUMBER_OF_ESDT_TRANSFERS, ERROR_MIX_ISOLATED_COLLATERAL,
+    ERROR_ACCOUNT_NOT_HELD_BY_BENEFICIARY, ERROR_ACCOUNT_NOT_IN_THE_MARKET,
+    ERROR_AUTO_COMPOUND_NOT_ENABLED, ERROR_BULK_SUPPLY_NOT_SUPPORTED,
+    ERROR_HEALTH_FACTOR_DISABLE_COLLATERAL, ERROR_INITIAL_SUPPLY_TOO_LOW,
+    ERROR_INVALID_NUMBER_OF_ESDT_TRANSFERS, ERROR_MARKET_PAUSED, ERROR_MAX_POSITION_VALUE_EXCEEDED,
+    ERROR_MIX_ISOLATED_COLLATERAL, ERROR_NEW_SUPPLY_DISABLED, ERROR_NO_POOL_FOUND,
     ERROR_POSITION_LIMIT_EXCEEDED, ERROR_SUPPLY_CAP,
 };
-use multiversx_sc::types::{EsdtTokenPayment, ManagedVec};
+use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, EsdtTokenPayment, ManagedVec};
 use multiversx_sc_scenario::{
     api::StaticApi,
-    imports::{BigUint, OptionalValue, TestAddress},
+    imports::{BigUint, OptionalValue, TestAddress, TestTokenIdentifier},
 };
 pub mod constants;
 pub mod proxys;
@@ -449,6 +453,105 @@ fn supply_exceeds_position_limit_error() {
     );
 }
 
+/// Tests that protocol-owned accounts bypass position limits while regular accounts do not.
+///
+/// Covers:
+/// - Controller::addProtocolOwnedAccount / removeProtocolOwnedAccount endpoints
+/// - Whitelist exemption in validation::ValidationModule position limits
+/// - ERROR_POSITION_LIMIT_EXCEEDED for non-whitelisted accounts
+#[test]
+fn supply_protocol_owned_account_exceeds_position_limit_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.set_position_limits(10, 1); // 10 borrow, 1 supply
+
+    // Account 1 will be protocol-owned, account 2 is a regular account
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(10u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(10u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    state.add_protocol_owned_account(1);
+
+    // Whitelisted account can exceed the supply position limit
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.assert_collateral_raw_eq(
+        1,
+        &USDC_TOKEN,
+        scaled_amount(100, USDC_DECIMALS),
+        "protocol-owned account should hold a second supply position",
+    );
+
+    // Regular account is still capped
+    state.supply_asset_error(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+        ERROR_POSITION_LIMIT_EXCEEDED,
+    );
+
+    // Once removed from the whitelist, limits apply again
+    state.remove_protocol_owned_account(1);
+    state.supply_asset_error(
+        &supplier,
+        SupplyParams {
+            token_id: CAPPED_TOKEN,
+            amount: BigUint::from(1u64),
+            asset_decimals: CAPPED_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+        ERROR_POSITION_LIMIT_EXCEEDED,
+    );
+}
+
+/// Tests that only existing accounts can be marked as protocol-owned.
+///
+/// Covers:
+/// - Controller::addProtocolOwnedAccount endpoint error path
+/// - ERROR_ACCOUNT_NOT_IN_THE_MARKET error condition
+#[test]
+fn supply_protocol_owned_account_unknown_nonce_error() {
+    let mut state = LendingPoolTestState::new();
+
+    state.add_protocol_owned_account_error(1, ERROR_ACCOUNT_NOT_IN_THE_MARKET);
+}
+
 /// Tests that bulk supply exceeding position limits fails even when individual supplies would pass.
 ///
 /// Covers:
@@ -502,3 +605,649 @@ fn supply_bulk_exceeds_position_limit_error() {
         "bulk position limit breach must not register accounts",
     );
 }
+
+/// Tests explicit compounding of supply interest into the tracked principal.
+///
+/// Covers:
+/// - Controller::setAutoCompound endpoint (principal snapshot on opt-in)
+/// - Controller::compoundInterest endpoint called by a keeper
+/// - Principal increasing by exactly the accrued supply interest
+/// - ERROR_AUTO_COMPOUND_NOT_ENABLED for accounts that did not opt in
+#[test]
+fn supply_compound_interest_rebases_principal_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    // Outstanding debt makes the EGLD supply accrue interest
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    state.set_auto_compound(&supplier, 1, true);
+    let principal_before = state.compounded_principal(1, EGLD_TOKEN);
+    assert_eq!(
+        principal_before,
+        state.collateral_amount_for_token(1, EGLD_TOKEN)
+    );
+
+    state.change_timestamp(SECONDS_PER_DAY * 30);
+    let balance = state.collateral_amount_for_token(1, EGLD_TOKEN);
+    let accrued_interest = balance.clone() - principal_before.clone();
+    assert!(accrued_interest.into_raw_units() > &BigUint::zero());
+
+    // Any keeper may trigger the compounding event
+    state.compound_interest(&borrower, 1, EGLD_TOKEN);
+
+    let principal_after = state.compounded_principal(1, EGLD_TOKEN);
+    assert_eq!(principal_after, balance);
+    assert_eq!(principal_after - principal_before, accrued_interest);
+
+    state.compound_interest_error(&borrower, 2, USDC_TOKEN, ERROR_AUTO_COMPOUND_NOT_ENABLED);
+}
+
+/// Tests that the first deposit into a seeded market must exceed the minimum initial supply.
+///
+/// Covers:
+/// - Controller::setMarketMinInitialSupply endpoint
+/// - LiquidityPool::supply first-deposit seeding
+/// - ERROR_INITIAL_SUPPLY_TOO_LOW and ERROR_NO_POOL_FOUND error conditions
+#[test]
+fn supply_first_deposit_below_min_initial_supply_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+
+    state.change_timestamp(0);
+    setup_account(&mut state, supplier);
+
+    state.set_market_min_initial_supply_error(
+        &EgldOrEsdtTokenIdentifier::esdt(
+            TestTokenIdentifier::new("NOPOOL-123456").to_esdt_token_identifier(),
+        ),
+        BigUint::from(1u64),
+        ERROR_NO_POOL_FOUND,
+    );
+
+    state.set_market_min_initial_supply(
+        &EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN.to_esdt_token_identifier()),
+        scaled_amount(1, USDC_DECIMALS),
+    );
+
+    state.supply_asset_error(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(1u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+        ERROR_INITIAL_SUPPLY_TOO_LOW,
+    );
+}
+
+/// Tests that a first-depositor donation attack cannot dilute later suppliers.
+///
+/// Covers:
+/// - LiquidityPool::supply burning the minimum initial supply on the first deposit
+/// - Supply index inflation through addRewards on a near-empty pool
+/// - Later depositors keeping the full value of their supply
+#[test]
+fn supply_first_depositor_donation_attack_success() {
+    let mut state = LendingPoolTestState::new();
+    let attacker = TestAddress::new("supplier");
+    let victim = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, attacker, victim);
+
+    let seed = scaled_amount(1, USDC_DECIMALS);
+    state.set_market_min_initial_supply(
+        &EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN.to_esdt_token_identifier()),
+        seed.clone(),
+    );
+
+    // Attacker opens the market with the smallest deposit accepted: seed + 1 raw unit
+    state.supply_asset_den(
+        &attacker,
+        USDC_TOKEN,
+        seed.clone() + 1u64,
+        OptionalValue::None,
+        OptionalValue::None,
+        false,
+    );
+    // Only the amount above the burned seed is owned by the attacker
+    let attacker_before = state.collateral_amount_for_token(1, USDC_TOKEN);
+    assert_eq!(attacker_before.into_raw_units(), &BigUint::from(1u64));
+
+    // Donation inflates the supply index of the near-empty pool
+    state.add_rewards(
+        &OWNER_ADDRESS,
+        USDC_TOKEN,
+        scaled_amount(10_000, USDC_DECIMALS),
+    );
+
+    // Victim deposit keeps its full value
+    let deposit = scaled_amount(1000, USDC_DECIMALS);
+    state.supply_asset_den(
+        &victim,
+        USDC_TOKEN,
+        deposit.clone(),
+        OptionalValue::None,
+        OptionalValue::None,
+        false,
+    );
+    let victim_collateral = state.collateral_amount_for_token(2, USDC_TOKEN);
+    let victim_raw = victim_collateral.into_raw_units().clone();
+    let diff = if victim_raw > deposit {
+        victim_raw - deposit
+    } else {
+        deposit - victim_raw
+    };
+    assert!(diff <= BigUint::from(1u64));
+
+    // The attacker captures at most its pro-rata share of the donation
+    let attacker_after = state.collateral_amount_for_token(1, USDC_TOKEN);
+    assert!(attacker_after.into_raw_units() < &scaled_amount(1, USDC_DECIMALS));
+}
+
+/// Tests that the maximum position value caps an account's total collateral.
+///
+/// Covers:
+/// - Controller::setMaxPositionValue endpoint
+/// - Controller::supply growing a position exactly to the cap
+/// - ERROR_MAX_POSITION_VALUE_EXCEEDED error condition on further deposits
+#[test]
+fn supply_exceeds_max_position_value_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    // Accounts may hold at most 100 EGLD ($4000) of collateral
+    state.set_max_position_value(scaled_amount(100, EGLD_DECIMALS));
+
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(3000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.assert_collateral_raw_eq(
+        1,
+        &USDC_TOKEN,
+        scaled_amount(4000, USDC_DECIMALS),
+        "collateral must grow up to the maximum position value",
+    );
+
+    state.supply_asset_error(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(1u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+        ERROR_MAX_POSITION_VALUE_EXCEEDED,
+    );
+
+    // Other accounts are bounded independently
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Removing the cap allows the account to grow again
+    state.set_max_position_value(BigUint::zero());
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(1u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+}
+
+/// Tests that a supply-only deposit is excluded from collateral and can be re-enabled.
+///
+/// Covers:
+/// - Controller::disableAsCollateral endpoint functionality
+/// - Controller::enableAsCollateral endpoint functionality
+/// - Supply-only deposits excluded from collateral value and health factor
+#[test]
+fn supply_disable_as_collateral_excludes_deposit_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    let collateral_before = state.total_collateral_in_egld(2);
+    let health_before = state.account_health_factor(2);
+
+    state.disable_as_collateral(&borrower, 2, EGLD_TOKEN);
+
+    // The EGLD deposit keeps earning but no longer backs the debt
+    let collateral_after = state.total_collateral_in_egld(2);
+    assert_eq!(
+        collateral_after.into_raw_units().clone() + scaled_amount(100, EGLD_DECIMALS),
+        collateral_before.into_raw_units().clone()
+    );
+    assert!(state.account_health_factor(2) < health_before);
+    state.assert_collateral_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(100, EGLD_DECIMALS),
+        "supply-only deposit should remain in the account",
+    );
+
+    state.enable_as_collateral(&borrower, 2, EGLD_TOKEN);
+
+    assert_eq!(state.total_collateral_in_egld(2), collateral_before);
+    assert_eq!(state.account_health_factor(2), health_before);
+}
+
+/// Tests that excluding collateral the account borrows against is rejected.
+///
+/// Covers:
+/// - Controller::disableAsCollateral health factor validation
+/// - ERROR_HEALTH_FACTOR_DISABLE_COLLATERAL error condition
+#[test]
+fn supply_disable_as_collateral_health_factor_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(2500u64),
+        2,
+        USDC_DECIMALS,
+    );
+
+    // The EGLD deposit backs most of the debt
+    state.disable_as_collateral_error(
+        &borrower,
+        2,
+        EGLD_TOKEN,
+        ERROR_HEALTH_FACTOR_DISABLE_COLLATERAL,
+    );
+
+    // The USDC deposit is not needed to stay healthy
+    state.disable_as_collateral(&borrower, 2, USDC_TOKEN);
+    state.assert_health_factor_at_least(2, RAY);
+}
+
+/// Tests pausing a single market for new supplies and borrows.
+///
+/// Covers:
+/// - Controller::pauseMarket and Controller::unpauseMarket endpoints
+/// - ERROR_MARKET_PAUSED on supply and borrow of the paused asset
+/// - Repay and withdraw of the paused asset, and supply of other assets, unaffected
+/// - Borrowing allowed again once the market is unpaused
+#[test]
+fn supply_and_borrow_paused_market_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(10u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    state.pause_market(EGLD_TOKEN);
+
+    state.supply_asset_error(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(1u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+        ERROR_MARKET_PAUSED,
+    );
+    state.borrow_asset_error(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+        ERROR_MARKET_PAUSED,
+    );
+
+    // Users can still exit the paused market and use the other markets
+    state.repay_asset(
+        &borrower,
+        &EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    state.withdraw_asset(&supplier, EGLD_TOKEN, BigUint::from(1u64), 1, EGLD_DECIMALS);
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.assert_collateral_raw_eq(
+        2,
+        &USDC_TOKEN,
+        scaled_amount(5100, USDC_DECIMALS),
+        "supply of an unpaused market should succeed",
+    );
+
+    state.unpause_market(EGLD_TOKEN);
+    let borrowed_before = state.borrow_amount_for_token(2, EGLD_TOKEN);
+    state.borrow_asset(&borrower, EGLD_TOKEN, BigUint::from(1u64), 2, EGLD_DECIMALS);
+    assert!(state.borrow_amount_for_token(2, EGLD_TOKEN) > borrowed_before);
+}
+
+/// Tests disabling new supplies of a market while its deposits stay collateral.
+///
+/// Covers:
+/// - Controller::setNewSupplyEnabled endpoint
+/// - ERROR_NEW_SUPPLY_DISABLED on supply of the disabled asset
+/// - Existing deposits of the asset still backing new borrows and withdrawable
+/// - Supplying allowed again once new supplies are re-enabled
+#[test]
+fn supply_new_supply_disabled_keeps_existing_collateral_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    state.set_new_supply_enabled(EGLD_TOKEN, false);
+
+    state.supply_asset_error(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(1u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+        ERROR_NEW_SUPPLY_DISABLED,
+    );
+
+    // The existing EGLD deposit still backs new debt and can be withdrawn
+    state.borrow_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(100u64),
+        2,
+        USDC_DECIMALS,
+    );
+    state.assert_borrow_raw_eq(
+        2,
+        &USDC_TOKEN,
+        scaled_amount(100, USDC_DECIMALS),
+        "existing collateral should back a new borrow",
+    );
+    state.withdraw_asset(&borrower, EGLD_TOKEN, BigUint::from(1u64), 2, EGLD_DECIMALS);
+    state.assert_collateral_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(99, EGLD_DECIMALS),
+        "existing deposit should stay withdrawable",
+    );
+
+    state.set_new_supply_enabled(EGLD_TOKEN, true);
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(1u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.assert_collateral_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(100, EGLD_DECIMALS),
+        "supply should succeed once re-enabled",
+    );
+}
+
+/// Tests supplying to an account on behalf of its holder.
+///
+/// Covers:
+/// - Controller::supplyFor crediting the beneficiary's account with the caller's tokens
+/// - Account holder left unchanged and no account created for the caller
+/// - ERROR_ACCOUNT_NOT_HELD_BY_BENEFICIARY when the beneficiary does not hold the account
+#[test]
+fn supply_for_beneficiary_account_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    let relayer = TestAddress::new("relayer");
+
+    setup_accounts(&mut state, supplier, borrower);
+    state.world.account(relayer).nonce(1).esdt_balance(
+        EGLD_TOKEN,
+        BigUint::from(100u64) * BigUint::from(10u64).pow(EGLD_DECIMALS as u32),
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(1_000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    state.supply_for(
+        &relayer,
+        &supplier,
+        1,
+        EGLD_TOKEN,
+        scaled_amount(10, EGLD_DECIMALS),
+    );
+
+    state.assert_collateral_raw_eq(
+        1,
+        &EGLD_TOKEN,
+        scaled_amount(10, EGLD_DECIMALS),
+        "relayed supply should credit the beneficiary's account",
+    );
+    assert_eq!(state.account_holder(1), supplier.to_managed_address());
+    assert_eq!(state.user_accounts(&supplier), vec![1]);
+    assert!(state.user_accounts(&relayer).is_empty());
+
+    state.supply_for_error(
+        &relayer,
+        &borrower,
+        1,
+        EGLD_TOKEN,
+        scaled_amount(10, EGLD_DECIMALS),
+        ERROR_ACCOUNT_NOT_HELD_BY_BENEFICIARY,
+    );
+}
@@ -6,6 +6,7 @@ use controller::{
 };
 use multiversx_sc::types::{
     EgldOrEsdtTokenIdentifier, ManagedDecimal, ManagedOption, MultiValueEncoded, NumDecimals,
+    TimestampMillis,
 };
 use multiversx_sc_scenario::{
     api::StaticApi,
@@ -1704,3 +1705,108 @@ fn oracle_price_out_of_tolerance_lp_egld_averaging() {
         ERROR_UN_SAFE_PRICE_NOT_ALLOWED,
     );
 }
+
+/// Tests index synchronization over many markets in one call.
+///
+/// Covers:
+/// - Controller::updateIndexes syncing every listed market to the current block
+/// - Repeated assets synced once without failing the batch
+/// - Prices read once per asset and seeded as the liquidation reference price
+#[test]
+fn update_indexes_many_assets_syncs_all_markets() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+    state.set_max_liquidation_price_drop(BigUint::from(1_000u64));
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5_000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(1_000u64),
+        2,
+        USDC_DECIMALS,
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(10u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    let tokens = [
+        USDC_TOKEN,
+        EGLD_TOKEN,
+        XEGLD_TOKEN,
+        SEGLD_TOKEN,
+        LEGLD_TOKEN,
+        XOXNO_TOKEN,
+    ];
+    let markets = [
+        state.usdc_market.clone(),
+        state.egld_market.clone(),
+        state.xegld_market.clone(),
+        state.segld_market.clone(),
+        state.legld_market.clone(),
+        state.xoxno_market.clone(),
+    ];
+
+    state.change_timestamp(SECONDS_PER_DAY);
+    let mut assets = MultiValueEncoded::new();
+    for token in tokens.iter() {
+        assets.push(EgldOrEsdtTokenIdentifier::esdt(
+            token.to_esdt_token_identifier(),
+        ));
+    }
+    // Listing a market twice does not sync it twice
+    assets.push(EgldOrEsdtTokenIdentifier::esdt(
+        USDC_TOKEN.to_esdt_token_identifier(),
+    ));
+    state.update_markets(&supplier, assets);
+
+    for market in markets.iter() {
+        assert_eq!(
+            state.market_last_timestamp(market.clone()),
+            TimestampMillis::new(SECONDS_PER_DAY * 1_000)
+        );
+    }
+    let ray = ManagedDecimal::from_raw_units(BigUint::from(RAY), 27);
+    assert!(state.market_borrow_index(state.usdc_market.clone()) > ray);
+    assert!(state.market_borrow_index(state.egld_market.clone()) > ray);
+
+    for token in [
+        USDC_TOKEN,
+        XEGLD_TOKEN,
+        SEGLD_TOKEN,
+        LEGLD_TOKEN,
+        XOXNO_TOKEN,
+    ] {
+        assert_eq!(
+            state.liquidation_reference_price(token),
+            state.egld_price(token)
+        );
+    }
+}
@@ -1,10 +1,15 @@
-use common_constants::RAY;
 pub use common_constants::{BPS_PRECISION, RAY_PRECISION, WAD_PRECISION};
+use common_constants::{DOUBLE_RAY, MILLISECONDS_PER_YEAR, MIN_FIRST_TOLERANCE, RAY};
+use controller::{
+    MarketActivityType, PositionMode, RateModelType, ERROR_ACCOUNT_NOT_IN_THE_MARKET,
+    ERROR_INDEX_SNAPSHOT_NOT_FOUND, ERROR_INSUFFICIENT_COLLATERAL,
+    ERROR_INVALID_MARKET_ACTIVITY_CAPACITY,
+};
 use multiversx_sc::types::{
     EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, ManagedDecimal, ManagedVec,
-    MultiValueEncoded,
+    MultiValueEncoded, NumDecimals, TimestampMillis,
 };
-use multiversx_sc_scenario::imports::{BigUint, OptionalValue, TestAddress};
+use multiversx_sc_scenario::imports::{BigUint, OptionalValue, StaticApi, TestAddress};
 pub mod constants;
 pub mod proxys;
 pub mod setup;
@@ -485,21 +490,141 @@ fn views_token_prices_accuracy() {
     );
 }
 
-/// Tests view error cases for non-existent positions.
+/// Tests the inverse borrow rate curve view.
 ///
 /// Covers:
-/// - Error handling for non-existent collateral
-/// - View validation for missing data
+/// - Utilization to rate to utilization round-trip in every curve region
+/// - Rates at or below the base rate mapping to zero utilization
+/// - Rates above the max borrow rate mapping to full utilization
 #[test]
-fn views_error_handling_non_existent_data() {
+fn views_utilization_for_borrow_rate_round_trip() {
+    let mut state = LendingPoolTestState::new();
+    let ray = BigUint::from(RAY);
+
+    // 20% (region 1), 65% (mid kink), 80% (region 2), 95% (region 3)
+    for utilization_pct in [20u64, 65u64, 80u64, 95u64] {
+        let utilization_ray = ray.clone() * BigUint::from(utilization_pct) / BigUint::from(100u64);
+        let rate = state.borrow_rate_at(USDC_TOKEN, utilization_ray.clone());
+        let round_trip =
+            state.utilization_for_borrow_rate(USDC_TOKEN, rate.into_raw_units().clone());
+
+        let expected = ManagedDecimal::from_raw_units(utilization_ray, RAY_PRECISION);
+        let diff = if round_trip > expected {
+            round_trip.clone() - expected.clone()
+        } else {
+            expected.clone() - round_trip.clone()
+        };
+
+        // Tolerance of 1e-18 in RAY precision
+        let tolerance = ManagedDecimal::from_raw_units(BigUint::from(10u64).pow(9), RAY_PRECISION);
+        assert!(
+            diff <= tolerance,
+            "Round-trip deviation too large at {utilization_pct}% utilization"
+        );
+    }
+
+    let zero = ManagedDecimal::from_raw_units(BigUint::zero(), RAY_PRECISION);
+    let full = ManagedDecimal::from_raw_units(ray, RAY_PRECISION);
+    assert_eq!(
+        state.utilization_for_borrow_rate(USDC_TOKEN, BigUint::from(R_BASE)),
+        zero
+    );
+    assert_eq!(
+        state.utilization_for_borrow_rate(USDC_TOKEN, BigUint::from(R_MAX + 1)),
+        full
+    );
+}
+
+/// Tests the borrow rate curve of each rate model type.
+///
+/// Covers:
+/// - Three-slope curve as the default model of a market
+/// - Kink curve after switching the market model
+/// - Inverse curve view following the selected model
+#[test]
+fn views_borrow_rate_curve_per_rate_model_type() {
+    let mut state = LendingPoolTestState::new();
+    let usdc = EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN.to_esdt_token_identifier());
+    // Percentages with one decimal, as RAY values
+    let ray_pct =
+        |tenths: u64| BigUint::from(RAY) * BigUint::from(tenths) / BigUint::from(1_000u64);
+    let rate = |tenths: u64| ManagedDecimal::from_raw_units(ray_pct(tenths), RAY_PRECISION);
+
+    // Three-slope: 1% base, +5% up to 65%, +15% up to 90%, +50% up to 100%
+    let three_slope_curve = [
+        (0u64, 10u64),
+        (325, 35),
+        (650, 60),
+        (775, 135),
+        (900, 210),
+        (950, 460),
+    ];
+    for (utilization, expected) in three_slope_curve {
+        assert_eq!(
+            state.borrow_rate_at(USDC_TOKEN, ray_pct(utilization)),
+            rate(expected),
+            "three-slope rate mismatch at {utilization} tenths of a percent"
+        );
+    }
+
+    state.set_market_rate_model_type(&usdc, RateModelType::Kink);
+
+    // Kink: 1% base, +5% up to 90%, +15% up to 100%; mid utilization and slope3 ignored
+    let kink_curve = [
+        (0u64, 10u64),
+        (450, 35),
+        (900, 60),
+        (950, 135),
+        (1_000, 210),
+    ];
+    for (utilization, expected) in kink_curve {
+        assert_eq!(
+            state.borrow_rate_at(USDC_TOKEN, ray_pct(utilization)),
+            rate(expected),
+            "kink rate mismatch at {utilization} tenths of a percent"
+        );
+    }
+
+    // The inverse view follows the kink curve as well
+    assert_eq!(
+        state.utilization_for_borrow_rate(USDC_TOKEN, ray_pct(35)),
+        ManagedDecimal::from_raw_units(ray_pct(450), RAY_PRECISION)
+    );
+    assert_eq!(
+        state.utilization_for_borrow_rate(USDC_TOKEN, ray_pct(135)),
+        ManagedDecimal::from_raw_units(ray_pct(950), RAY_PRECISION)
+    );
+
+    state.set_market_rate_model_type(&usdc, RateModelType::ThreeSlope);
+    assert_eq!(state.borrow_rate_at(USDC_TOKEN, ray_pct(775)), rate(135));
+}
+
+/// Tests the paginated wallet summary view.
+///
+/// Covers:
+/// - One summary per account NFT held by the wallet
+/// - Each wallet entry matching the per-account summary
+/// - Pagination bounds of the wallet's accounts
+#[test]
+fn views_wallet_summary_matches_account_summaries() {
     let mut state = LendingPoolTestState::new();
     let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
 
-    setup_account(&mut state, supplier);
+    setup_accounts(&mut state, supplier, borrower);
 
-    // Create minimal position
     state.supply_asset(
         &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5_000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
         SupplyParams {
             token_id: EGLD_TOKEN,
             amount: BigUint::from(10u64),
@@ -508,135 +633,1407 @@ fn views_error_handling_non_existent_data() {
             e_mode_category: OptionalValue::None,
         },
     );
+    // Second account of the supplier, with debt
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(20u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &supplier,
+        USDC_TOKEN,
+        BigUint::from(200u64),
+        3,
+        USDC_DECIMALS,
+    );
 
-    // Test error for non-existent collateral token
-    let custom_error_message = format!("Token not existing in the account {}", USDC_TOKEN.as_str());
-    state.collateral_amount_for_token_non_existing(1, USDC_TOKEN, custom_error_message.as_bytes());
+    let summaries = state.wallet_summary(&supplier, 0, 10);
+    assert_eq!(summaries.len(), 2);
+
+    let mut nonces = Vec::new();
+    for item in summaries.into_iter() {
+        let (account_nonce, summary) = item.into_tuple();
+        let expected = state.account_summary(account_nonce);
+        assert_eq!(summary.total_collateral_wad, expected.total_collateral_wad);
+        assert_eq!(
+            summary.liquidation_collateral_wad,
+            expected.liquidation_collateral_wad
+        );
+        assert_eq!(summary.ltv_collateral_wad, expected.ltv_collateral_wad);
+        assert_eq!(summary.total_borrow_wad, expected.total_borrow_wad);
+        assert_eq!(summary.health_factor_ray, expected.health_factor_ray);
+        assert_eq!(
+            expected.total_collateral_wad,
+            state.total_collateral_in_egld(account_nonce)
+        );
+        assert_eq!(
+            expected.total_borrow_wad,
+            state.total_borrow_in_egld(account_nonce)
+        );
+        assert_eq!(
+            expected.health_factor_ray,
+            state.account_health_factor(account_nonce)
+        );
+        nonces.push(account_nonce);
+    }
+    nonces.sort();
+    assert_eq!(nonces, vec![1, 3]);
+
+    // Pages never cross the wallet's last account
+    assert_eq!(state.wallet_summary(&supplier, 1, 1).len(), 1);
+    assert_eq!(state.wallet_summary(&supplier, 1, 10).len(), 1);
+    assert_eq!(state.wallet_summary(&supplier, 2, 10).len(), 0);
+
+    let borrower_summaries = state.wallet_summary(&borrower, 0, 10);
+    assert_eq!(borrower_summaries.len(), 1);
+    let (account_nonce, _) = borrower_summaries.into_iter().next().unwrap().into_tuple();
+    assert_eq!(account_nonce, 2);
 }
 
-/// Tests complex liquidation estimation with bad debt scenario.
+/// Tests the per-address account index used by wallets.
 ///
 /// Covers:
-/// - Complex multi-asset liquidation estimation
-/// - Bad debt scenario handling
-/// - Full liquidation with insufficient collateral
-/// - Multiple collateral seizure calculations
-/// - Protocol fee distribution across assets
+/// - Every account created by an address listed under it
+/// - An account moving to the address that next presents the transferred NFT
+/// - A closed account dropping out of its holder's list
 #[test]
-fn views_complex_liquidation_bad_debt_scenario() {
+fn views_user_accounts_follow_account_holder() {
     let mut state = LendingPoolTestState::new();
     let supplier = TestAddress::new("supplier");
     let borrower = TestAddress::new("borrower");
+    let recipient = TestAddress::new("recipient");
 
-    state.change_timestamp(0);
     setup_accounts(&mut state, supplier, borrower);
+    state.world.account(recipient).nonce(1);
 
-    // Create large liquidity pools
     state.supply_asset(
         &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5_000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
         SupplyParams {
             token_id: EGLD_TOKEN,
-            amount: BigUint::from(500u64),
+            amount: BigUint::from(10u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(20u64),
             asset_decimals: EGLD_DECIMALS,
             account_nonce: OptionalValue::None,
             e_mode_category: OptionalValue::None,
         },
     );
 
+    assert_eq!(state.user_accounts(&supplier), vec![1, 3]);
+    assert_eq!(state.user_accounts(&borrower), vec![2]);
+    assert!(state.user_accounts(&recipient).is_empty());
+
+    // The index only moves once the new holder presents the NFT to the protocol
+    state.transfer_account(&supplier, &recipient, 3);
+    assert_eq!(state.user_accounts(&supplier), vec![1, 3]);
+
+    state.withdraw_asset(
+        &recipient,
+        EGLD_TOKEN,
+        BigUint::from(5u64),
+        3,
+        EGLD_DECIMALS,
+    );
+    assert_eq!(state.user_accounts(&supplier), vec![1]);
+    assert_eq!(state.user_accounts(&recipient), vec![3]);
+    assert_eq!(state.account_holder(3), recipient.to_managed_address());
+
+    // Closing the account burns the NFT and removes it from the holder's list
+    state.withdraw_asset(
+        &recipient,
+        EGLD_TOKEN,
+        BigUint::from(15u64),
+        3,
+        EGLD_DECIMALS,
+    );
+    assert!(state.user_accounts(&recipient).is_empty());
+    assert_eq!(state.user_accounts(&supplier), vec![1]);
+    assert_eq!(state.user_accounts(&borrower), vec![2]);
+}
+
+/// Tests the batched health factor view used by liquidation bots.
+///
+/// Covers:
+/// - Controller::health_factors view
+/// - Results returned in input order, including repeated nonces
+/// - Values matching the single-account health factor view
+/// - Maximum health factor for accounts without borrows
+#[test]
+fn views_health_factors_batch_matches_single_queries() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    setup_accounts(&mut state, supplier, borrower);
+
     state.supply_asset(
         &supplier,
         SupplyParams {
             token_id: USDC_TOKEN,
-            amount: BigUint::from(10000u64),
+            amount: BigUint::from(5_000u64),
             asset_decimals: USDC_DECIMALS,
-            account_nonce: OptionalValue::Some(1),
+            account_nonce: OptionalValue::None,
             e_mode_category: OptionalValue::None,
         },
     );
-
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(10u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(100u64),
+        2,
+        USDC_DECIMALS,
+    );
     state.supply_asset(
         &supplier,
         SupplyParams {
-            token_id: CAPPED_TOKEN,
-            amount: BigUint::from(100u64),
-            asset_decimals: CAPPED_DECIMALS,
-            account_nonce: OptionalValue::Some(1),
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(20u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
             e_mode_category: OptionalValue::None,
         },
     );
+    state.borrow_asset(
+        &supplier,
+        USDC_TOKEN,
+        BigUint::from(400u64),
+        3,
+        USDC_DECIMALS,
+    );
+
+    let requested = [3u64, 1, 2, 3];
+    let health_factors = state.account_health_factors(&requested);
+    assert_eq!(health_factors.len(), requested.len());
+
+    for (item, expected_nonce) in health_factors.into_iter().zip(requested) {
+        let (account_nonce, health_factor) = item.into_tuple();
+        assert_eq!(account_nonce, expected_nonce);
+        if account_nonce == 1 {
+            // Accounts without borrows report the maximum health factor
+            assert_eq!(health_factor.as_raw_units(), &BigUint::from(DOUBLE_RAY));
+        } else {
+            assert_eq!(health_factor, state.account_health_factor(account_nonce));
+        }
+    }
+}
+
+/// Tests simulating the health factor impact of hypothetical actions.
+///
+/// Covers:
+/// - Controller::simulateHealthFactor view
+/// - No deltas matching the current health factor
+/// - Simulated borrow, supply, withdraw and new deposit matching the executed actions
+/// - Repayments capped at the simulated debt
+#[test]
+fn views_simulate_health_factor_matches_executed_actions() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
 
-    // Borrower supplies multiple collaterals
     state.supply_asset(
-        &borrower,
+        &supplier,
         SupplyParams {
-            token_id: XEGLD_TOKEN,
-            amount: BigUint::from(30u64),
-            asset_decimals: XEGLD_DECIMALS,
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5_000u64),
+            asset_decimals: USDC_DECIMALS,
             account_nonce: OptionalValue::None,
             e_mode_category: OptionalValue::None,
         },
     );
-
     state.supply_asset(
         &borrower,
         SupplyParams {
-            token_id: SEGLD_TOKEN,
-            amount: BigUint::from(40u64),
-            asset_decimals: SEGLD_DECIMALS,
-            account_nonce: OptionalValue::Some(2),
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(10u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
             e_mode_category: OptionalValue::None,
         },
     );
-
-    // Borrower takes multiple loans
     state.borrow_asset(
         &borrower,
-        EGLD_TOKEN,
-        BigUint::from(20u64),
+        USDC_TOKEN,
+        BigUint::from(100u64),
         2,
-        EGLD_DECIMALS,
+        USDC_DECIMALS,
+    );
+
+    assert_eq!(
+        state.simulate_health_factor(2, &[]),
+        state.account_health_factor(2)
     );
 
+    let simulated = state.simulate_health_factor(
+        2,
+        &[(
+            USDC_TOKEN,
+            scaled_amount(50, USDC_DECIMALS),
+            MarketActivityType::Borrow,
+        )],
+    );
+    assert!(simulated < state.account_health_factor(2));
     state.borrow_asset(
         &borrower,
         USDC_TOKEN,
-        BigUint::from(500u64),
+        BigUint::from(50u64),
         2,
         USDC_DECIMALS,
     );
+    assert_eq!(simulated, state.account_health_factor(2));
 
-    state.borrow_asset(
-        &borrower,
-        CAPPED_TOKEN,
-        BigUint::from(10u64),
+    // Deltas apply in order, including a deposit of an asset the account does not hold yet
+    let simulated = state.simulate_health_factor(
         2,
-        CAPPED_DECIMALS,
+        &[
+            (
+                EGLD_TOKEN,
+                scaled_amount(5, EGLD_DECIMALS),
+                MarketActivityType::Supply,
+            ),
+            (
+                EGLD_TOKEN,
+                scaled_amount(2, EGLD_DECIMALS),
+                MarketActivityType::Withdraw,
+            ),
+            (
+                DAI_TOKEN,
+                scaled_amount(100, DAI_DECIMALS),
+                MarketActivityType::Supply,
+            ),
+        ],
     );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(5u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.withdraw_asset(&borrower, EGLD_TOKEN, BigUint::from(2u64), 2, EGLD_DECIMALS);
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: DAI_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: DAI_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    assert_eq!(simulated, state.account_health_factor(2));
 
-    // Advance time significantly to create bad debt
-    state.change_timestamp(SECONDS_PER_DAY * 15000);
-    let mut markets = MultiValueEncoded::new();
-    markets.push(EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN));
-    markets.push(EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN));
-    markets.push(EgldOrEsdtTokenIdentifier::esdt(CAPPED_TOKEN));
-    state.update_markets(&borrower, markets);
-
-    // Verify position is deeply underwater
-    let health_factor = state.account_health_factor(2);
-    let can_liquidate = state.can_be_liquidated(2);
-    assert!(can_liquidate);
-    assert!(
-        health_factor
-            < ManagedDecimal::from_raw_units(
-                BigUint::from(10u64).pow(RAY_PRECISION as u32),
-                RAY_PRECISION
-            )
+    // Repaying more than the debt clears it instead of going negative
+    let simulated = state.simulate_health_factor(
+        2,
+        &[(
+            USDC_TOKEN,
+            scaled_amount(10_000, USDC_DECIMALS),
+            MarketActivityType::Repay,
+        )],
     );
+    assert_eq!(simulated.as_raw_units(), &BigUint::from(DOUBLE_RAY));
+}
 
-    // Prepare full liquidation attempt
-    let borrowed_egld = state.borrow_amount_for_token(2, EGLD_TOKEN);
-    let borrowed_usdc = state.borrow_amount_for_token(2, USDC_TOKEN);
-    let borrowed_capped = state.borrow_amount_for_token(2, CAPPED_TOKEN);
+/// Tests listing healthy borrowing accounts by proximity to liquidation.
+///
+/// Covers:
+/// - Controller::accounts_near_liquidation view
+/// - Accounts closest to the threshold returned ahead of well-collateralized ones
+/// - Accounts without borrows skipped
+/// - Pagination over the account set
+#[test]
+fn views_accounts_near_liquidation_sorted_by_health_factor() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5_000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    // Well-collateralized borrower
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(10u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(100u64),
+        2,
+        USDC_DECIMALS,
+    );
+    // Borrower closer to the liquidation threshold
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(20u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &supplier,
+        USDC_TOKEN,
+        BigUint::from(400u64),
+        3,
+        USDC_DECIMALS,
+    );
+
+    let near_liquidation: Vec<(u64, ManagedDecimal<StaticApi, NumDecimals>)> = state
+        .accounts_near_liquidation(0, 10)
+        .into_iter()
+        .map(|item| item.into_tuple())
+        .collect();
+    assert_eq!(near_liquidation.len(), 2);
+    assert_eq!(near_liquidation[0].0, 3);
+    assert_eq!(near_liquidation[1].0, 2);
+    assert!(near_liquidation[0].1 < near_liquidation[1].1);
+    assert_eq!(near_liquidation[0].1, state.account_health_factor(3));
+    assert!(near_liquidation[0].1.into_raw_units() >= &BigUint::from(RAY));
+
+    // Each page only ranks the accounts it scans
+    let first_page = state.accounts_near_liquidation(0, 2);
+    assert_eq!(first_page.len(), 1);
+    let (account_nonce, _) = first_page.into_iter().next().unwrap().into_tuple();
+    assert_eq!(account_nonce, 2);
+
+    let last_page = state.accounts_near_liquidation(2, 10);
+    assert_eq!(last_page.len(), 1);
+    assert!(state.accounts_near_liquidation(3, 10).is_empty());
+}
+
+/// Accrues one year of USDC interest at the given utilization and returns the
+/// protocol revenue, optionally with an elevated reserve factor configured.
+fn usdc_revenue_after_year(borrowed_usdc: u64, reserve_factor_high: u64) -> BigUint<StaticApi> {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+    let usdc = EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN.to_esdt_token_identifier());
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+    state.set_market_reserve_factor_high(&usdc, reserve_factor_high);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(10_000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(1_000u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(borrowed_usdc),
+        2,
+        USDC_DECIMALS,
+    );
+
+    state.change_timestamp(SECONDS_PER_YEAR);
+    let mut markets = MultiValueEncoded::new();
+    markets.push(usdc);
+    state.update_markets(&supplier, markets);
+
+    state
+        .market_protocol_revenue(state.usdc_market.clone())
+        .as_raw_units()
+        .clone()
+}
+
+/// Tests the utilization-based elevated reserve factor.
+///
+/// Covers:
+/// - Flat reserve factor by default
+/// - Elevated reserve factor applied to interest accrued above the optimal utilization
+/// - Base reserve factor kept below the optimal utilization
+#[test]
+fn views_reserve_factor_high_above_optimal_utilization() {
+    // Base reserve factor is 10%, the elevated one 30%
+    let reserve_factor_high = 3_000u64;
+    let tolerance = BigUint::from(10u64);
+
+    // 95% utilization, above the 90% optimal utilization
+    let flat = usdc_revenue_after_year(9_500, 0);
+    let elevated = usdc_revenue_after_year(9_500, reserve_factor_high);
+    assert!(flat > BigUint::zero());
+    let expected = &flat * 3u64;
+    let diff = if elevated > expected {
+        &elevated - &expected
+    } else {
+        &expected - &elevated
+    };
+    assert!(
+        diff <= tolerance,
+        "elevated reserve factor should triple the protocol share of interest"
+    );
+
+    // 50% utilization stays on the base reserve factor
+    let flat = usdc_revenue_after_year(5_000, 0);
+    let elevated = usdc_revenue_after_year(5_000, reserve_factor_high);
+    assert!(flat > BigUint::zero());
+    assert_eq!(elevated, flat);
+}
+
+/// Tests the per-collateral liquidation price view.
+///
+/// Covers:
+/// - Health factor reaching exactly 1 at the returned collateral price
+/// - Zero price when a price drop cannot make the account liquidatable
+/// - Error for a token that is not part of the collateral
+#[test]
+fn views_liquidation_price_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Borrower: 100 EGLD ($4000) and 1000 USDC collateral, 2500 USDC debt
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(2500u64),
+        2,
+        USDC_DECIMALS,
+    );
+
+    // (2500 - 1000 * 80%) / (100 * 80%) = $21.25
+    let price = state.liquidation_price(2, EGLD_TOKEN);
+    let price_raw = price.into_raw_units().clone();
+    let expected_price = BigUint::from(2125u64) * BigUint::from(WAD) / BigUint::from(100u64);
+    let tolerance = BigUint::from(10u64).pow(10);
+    let price_diff = if price_raw > expected_price {
+        price_raw.clone() - expected_price
+    } else {
+        expected_price - price_raw.clone()
+    };
+    assert!(price_diff <= tolerance);
+
+    // Health factor at that price: weighted collateral in USD equals the debt in USD
+    let egld_usd = BigUint::from(EGLD_PRICE_IN_DOLLARS);
+    let weighted_egld = state.liquidation_collateral_available(2);
+    let debt_egld = state.total_borrow_in_egld(2);
+    // 100 EGLD at an 80% liquidation threshold, swapped from the current to the returned price
+    let egld_weight = BigUint::from(80u64);
+    let weighted_usd_at_price =
+        weighted_egld.into_raw_units().clone() * &egld_usd + &egld_weight * &price_raw;
+    let debt_usd_with_current_weight = debt_egld.into_raw_units().clone() * &egld_usd
+        + egld_weight * egld_usd * BigUint::from(WAD);
+    let hf_diff = if weighted_usd_at_price > debt_usd_with_current_weight {
+        weighted_usd_at_price - debt_usd_with_current_weight
+    } else {
+        debt_usd_with_current_weight - weighted_usd_at_price
+    };
+    assert!(hf_diff <= tolerance * BigUint::from(100u64));
+
+    // USDC is borrowed beyond its weighted collateral: a lower price only improves health
+    let zero = ManagedDecimal::from_raw_units(BigUint::zero(), WAD_PRECISION);
+    assert_eq!(state.liquidation_price(2, USDC_TOKEN), zero);
+
+    // Debt-free accounts have no liquidation price
+    assert_eq!(state.liquidation_price(1, USDC_TOKEN), zero);
+
+    state.liquidation_price_error(
+        1,
+        EGLD_TOKEN,
+        format!("Token not existing in the account {}", EGLD_TOKEN.as_str()).as_bytes(),
+    );
+}
+
+/// Tests account net worth in EGLD and USD.
+///
+/// Covers:
+/// - Net worth equal to collateral minus borrow in EGLD
+/// - USD conversion of the EGLD net worth
+/// - Accounts without debt reporting their full collateral
+#[test]
+fn views_account_net_worth_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    // $5000 of USDC collateral against $2000 of EGLD debt
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    let wad = |amount: u64| {
+        ManagedDecimal::from_raw_units(BigUint::from(amount) * BigUint::from(WAD), WAD_PRECISION)
+    };
+
+    let (net_worth_egld, net_worth_usd) = state.account_net_worth(2);
+    let total_collateral = state.total_collateral_in_egld(2);
+    let total_borrow = state.total_borrow_in_egld(2);
+    assert_eq!(net_worth_egld, total_collateral - total_borrow);
+    assert_eq!(net_worth_egld, wad(75));
+    assert_eq!(net_worth_usd, wad(3000));
+
+    let (supplier_net_worth_egld, supplier_net_worth_usd) = state.account_net_worth(1);
+    assert_eq!(supplier_net_worth_egld, state.total_collateral_in_egld(1));
+    assert_eq!(supplier_net_worth_usd, wad(4000));
+}
+
+/// Tests the protocol total value locked across all markets.
+///
+/// Covers:
+/// - TVL equal to the sum of per-market supplied values in EGLD
+/// - USD conversion of the EGLD total
+/// - Accrued supply interest included through the market supply index
+#[test]
+fn views_total_value_locked_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    let wad = |amount: u64| {
+        ManagedDecimal::from_raw_units(BigUint::from(amount) * BigUint::from(WAD), WAD_PRECISION)
+    };
+
+    let (empty_egld, empty_usd) = state.total_value_locked();
+    assert_eq!(empty_egld, wad(0));
+    assert_eq!(empty_usd, wad(0));
+
+    // 100 EGLD ($4000) and 5000 USDC (125 EGLD)
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    let (tvl_egld, tvl_usd) = state.total_value_locked();
+    assert_eq!(tvl_egld, wad(225));
+    assert_eq!(tvl_usd, wad(9000));
+
+    // Interest accrued on the EGLD market grows the TVL through its supply index
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    state.change_timestamp(SECONDS_PER_DAY * 30);
+    let mut markets = MultiValueEncoded::new();
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN));
+    state.update_markets(&supplier, markets);
+
+    let egld_supplied = state.market_supplied_amount(state.egld_market.clone());
+    let usdc_supplied = state.market_supplied_amount(state.usdc_market.clone());
+    let usdc_price = state.egld_price(USDC_TOKEN);
+    let expected_egld = egld_supplied.into_raw_units().clone()
+        + usdc_supplied.into_raw_units().clone()
+            * BigUint::from(10u64).pow((WAD_PRECISION - USDC_DECIMALS) as u32)
+            * usdc_price.into_raw_units().clone()
+            / BigUint::from(WAD);
+
+    let (tvl_egld, tvl_usd) = state.total_value_locked();
+    assert!(tvl_egld > wad(225));
+    let tolerance = BigUint::from(10u64).pow(10);
+    let tvl_egld_raw = tvl_egld.into_raw_units().clone();
+    let diff = if tvl_egld_raw > expected_egld {
+        tvl_egld_raw.clone() - expected_egld
+    } else {
+        expected_egld - tvl_egld_raw.clone()
+    };
+    assert!(diff <= tolerance);
+    assert_eq!(
+        tvl_usd.into_raw_units().clone(),
+        tvl_egld_raw * BigUint::from(40u64)
+    );
+}
+
+/// Tests the protocol-wide collateral and debt totals across all markets.
+///
+/// Covers:
+/// - Collateral total equal to the sum of per-market supplied values in EGLD
+/// - Debt total equal to the sum of per-market borrowed values in EGLD
+/// - Accrued interest included through the market indexes
+#[test]
+fn views_protocol_totals_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    let wad = |amount: u64| {
+        ManagedDecimal::from_raw_units(BigUint::from(amount) * BigUint::from(WAD), WAD_PRECISION)
+    };
+
+    let (empty_collateral, empty_debt) = state.protocol_totals();
+    assert_eq!(empty_collateral, wad(0));
+    assert_eq!(empty_debt, wad(0));
+
+    // 100 EGLD and 5000 USDC (125 EGLD) supplied, 50 EGLD borrowed
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    let (total_collateral, total_debt) = state.protocol_totals();
+    assert_eq!(total_collateral, wad(225));
+    assert_eq!(total_debt, wad(50));
+
+    // Interest grows both totals, the collateral side matching the TVL
+    state.change_timestamp(SECONDS_PER_DAY * 30);
+    let mut markets = MultiValueEncoded::new();
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN));
+    state.update_markets(&supplier, markets);
+
+    let (total_collateral, total_debt) = state.protocol_totals();
+    let (tvl_egld, _) = state.total_value_locked();
+    assert_eq!(total_collateral, tvl_egld);
+    assert!(total_debt > wad(50));
+    assert_eq!(total_debt, state.total_borrow_in_egld(2));
+}
+
+/// Tests the supply and borrow cap utilization views.
+///
+/// Covers:
+/// - Controller::getSupplyCapUtilization at several fill levels
+/// - Controller::getBorrowCapUtilization at several fill levels
+/// - Zero utilization reported for uncapped markets
+#[test]
+fn views_cap_utilization_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    let bps = |amount: u64| ManagedDecimal::from_raw_units(BigUint::from(amount), BPS_PRECISION);
+
+    // CAPPED market: 150 supply cap, 100 borrow cap
+    assert_eq!(state.supply_cap_utilization(CAPPED_TOKEN), bps(0));
+    assert_eq!(state.borrow_cap_utilization(CAPPED_TOKEN), bps(0));
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: CAPPED_TOKEN,
+            amount: BigUint::from(30u64),
+            asset_decimals: CAPPED_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    assert_eq!(state.supply_cap_utilization(CAPPED_TOKEN), bps(2_000));
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: CAPPED_TOKEN,
+            amount: BigUint::from(45u64),
+            asset_decimals: CAPPED_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    assert_eq!(state.supply_cap_utilization(CAPPED_TOKEN), bps(5_000));
+
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        CAPPED_TOKEN,
+        BigUint::from(25u64),
+        2,
+        CAPPED_DECIMALS,
+    );
+    assert_eq!(state.borrow_cap_utilization(CAPPED_TOKEN), bps(2_500));
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: CAPPED_TOKEN,
+            amount: BigUint::from(75u64),
+            asset_decimals: CAPPED_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    assert_eq!(state.supply_cap_utilization(CAPPED_TOKEN), bps(10_000));
+
+    state.borrow_asset(
+        &borrower,
+        CAPPED_TOKEN,
+        BigUint::from(50u64),
+        2,
+        CAPPED_DECIMALS,
+    );
+    assert_eq!(state.borrow_cap_utilization(CAPPED_TOKEN), bps(7_500));
+
+    // Accrued interest counts towards both caps
+    state.change_timestamp(SECONDS_PER_DAY * 30);
+    assert!(state.borrow_cap_utilization(CAPPED_TOKEN) > bps(7_500));
+    assert!(state.supply_cap_utilization(CAPPED_TOKEN) > bps(10_000));
+
+    // Uncapped markets report zero regardless of their size
+    assert_eq!(state.supply_cap_utilization(EGLD_TOKEN), bps(0));
+    assert_eq!(state.borrow_cap_utilization(EGLD_TOKEN), bps(0));
+}
+
+/// Tests the oracle price confidence band built from diverging price sources.
+///
+/// Covers:
+/// - Empty band for a token priced from a single source
+/// - Band width equal to the divergence between aggregator and safe prices
+/// - Reported price matching the regular USD price view
+#[test]
+fn views_oracle_price_with_confidence_success() {
+    let mut state = LendingPoolTestState::new();
+
+    state.change_timestamp(0);
+
+    // EGLD is the reference asset, so there is no divergence to report
+    let (price, lower_bound, upper_bound) = state.oracle_price_with_confidence(EGLD_TOKEN);
+    assert_eq!(price, state.usd_price(EGLD_TOKEN));
+    assert_eq!(lower_bound, price);
+    assert_eq!(upper_bound, price);
+
+    // Widen the last oracle tolerance so the diverging aggregator price is averaged, not rejected
+    state.edit_token_oracle_tolerance(
+        &EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN),
+        BigUint::from(MIN_FIRST_TOLERANCE),
+        BigUint::from(10_000u64),
+    );
+
+    // Aggregator reports XOXNO at $0.6 while the safe price stays around $1
+    let diverging_price = BigUint::from(6u64) * BigUint::from(10u64).pow(17);
+    state.change_price_denominated(XOXNO_TICKER, diverging_price, 0);
+
+    let mut assets = MultiValueEncoded::new();
+    assets.push(EgldOrEsdtTokenIdentifier::esdt(XOXNO_TOKEN));
+    let markets = state.all_market_indexes(assets);
+    let safe_price = markets.get(0).safe_price_usd_wad.clone();
+    let aggregator_price = markets.get(0).aggregator_price_usd_wad.clone();
+    let divergence = if safe_price > aggregator_price {
+        safe_price - aggregator_price
+    } else {
+        aggregator_price - safe_price
+    };
+    assert!(divergence > ManagedDecimal::from_raw_units(BigUint::zero(), WAD_PRECISION));
+
+    let (price, lower_bound, upper_bound) = state.oracle_price_with_confidence(XOXNO_TOKEN);
+    assert_eq!(price, state.usd_price(XOXNO_TOKEN));
+    assert!(lower_bound < price && price < upper_bound);
+    assert_eq!(upper_bound - lower_bound, divergence);
+}
+
+/// Tests the recent activity ring buffer of a market.
+///
+/// Covers:
+/// - No activity recorded while the market has no capacity
+/// - Supply, borrow, repay and withdraw recorded with amount and timestamp
+/// - Oldest entries overwritten once the buffer is full, keeping order
+/// - Error when the capacity exceeds the maximum
+#[test]
+fn views_recent_market_activity_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    // Recording is opt-in
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: CAPPED_TOKEN,
+            amount: BigUint::from(30u64),
+            asset_decimals: CAPPED_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    assert!(state.recent_market_activity(CAPPED_TOKEN).is_empty());
+
+    state.set_market_activity_capacity_error(
+        CAPPED_TOKEN,
+        51,
+        ERROR_INVALID_MARKET_ACTIVITY_CAPACITY,
+    );
+    state.set_market_activity_capacity(CAPPED_TOKEN, 3);
+
+    state.change_timestamp(1);
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: CAPPED_TOKEN,
+            amount: BigUint::from(40u64),
+            asset_decimals: CAPPED_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    state.change_timestamp(2);
+    state.borrow_asset(
+        &borrower,
+        CAPPED_TOKEN,
+        BigUint::from(20u64),
+        2,
+        CAPPED_DECIMALS,
+    );
+
+    state.change_timestamp(3);
+    state.repay_asset(
+        &borrower,
+        &CAPPED_TOKEN,
+        BigUint::from(5u64),
+        2,
+        CAPPED_DECIMALS,
+    );
+
+    // Only operations on the configured market are recorded
+    let activity = state.recent_market_activity(CAPPED_TOKEN);
+    assert_eq!(activity.len(), 3);
+    assert!(state.recent_market_activity(EGLD_TOKEN).is_empty());
+
+    let expected = [
+        (MarketActivityType::Supply, 40u64, 1u64),
+        (MarketActivityType::Borrow, 20u64, 2u64),
+        (MarketActivityType::Repay, 5u64, 3u64),
+    ];
+    for (entry, (activity_type, amount, timestamp)) in activity.iter().zip(expected) {
+        assert!(entry.activity_type == activity_type);
+        assert_eq!(
+            entry.amount,
+            ManagedDecimal::from_raw_units(
+                BigUint::from(amount) * BigUint::from(10u64).pow(CAPPED_DECIMALS as u32),
+                CAPPED_DECIMALS,
+            )
+        );
+        assert_eq!(entry.timestamp, TimestampMillis::new(timestamp * 1000));
+    }
+
+    // A full buffer drops the oldest entries first
+    state.change_timestamp(4);
+    state.withdraw_asset(
+        &supplier,
+        CAPPED_TOKEN,
+        BigUint::from(10u64),
+        1,
+        CAPPED_DECIMALS,
+    );
+
+    state.change_timestamp(5);
+    state.borrow_asset(
+        &borrower,
+        CAPPED_TOKEN,
+        BigUint::from(7u64),
+        2,
+        CAPPED_DECIMALS,
+    );
+
+    let activity = state.recent_market_activity(CAPPED_TOKEN);
+    assert_eq!(activity.len(), 3);
+
+    let expected = [
+        (MarketActivityType::Repay, 5u64, 3u64),
+        (MarketActivityType::Withdraw, 10u64, 4u64),
+        (MarketActivityType::Borrow, 7u64, 5u64),
+    ];
+    for (entry, (activity_type, amount, timestamp)) in activity.iter().zip(expected) {
+        assert!(entry.activity_type == activity_type);
+        assert_eq!(
+            entry.amount,
+            ManagedDecimal::from_raw_units(
+                BigUint::from(amount) * BigUint::from(10u64).pow(CAPPED_DECIMALS as u32),
+                CAPPED_DECIMALS,
+            )
+        );
+        assert_eq!(entry.timestamp, TimestampMillis::new(timestamp * 1000));
+    }
+
+    // Disabling the buffer discards the recorded activity
+    state.set_market_activity_capacity(CAPPED_TOKEN, 0);
+    assert!(state.recent_market_activity(CAPPED_TOKEN).is_empty());
+}
+
+/// Tests decoding account NFT attributes through the dedicated view.
+///
+/// Covers:
+/// - Isolation and e-mode attributes as set at account creation
+/// - Attributes unchanged after further supply and borrow operations
+/// - Error once the account has been closed
+#[test]
+fn views_decode_account_attributes_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    // Isolated account
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: ISOLATED_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: ISOLATED_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    // E-Mode account
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+
+    let isolated = state.decode_account_attributes(1);
+    assert!(isolated.is_isolated());
+    assert_eq!(isolated.emode_id(), 0);
+    assert!(isolated.mode == PositionMode::Normal);
+    assert_eq!(
+        isolated.isolated_token(),
+        EgldOrEsdtTokenIdentifier::from(ISOLATED_TOKEN.as_bytes())
+    );
+    assert!(isolated == state.account_attributes(1));
+
+    let e_mode = state.decode_account_attributes(2);
+    assert!(!e_mode.is_isolated());
+    assert_eq!(e_mode.emode_id(), 1);
+    assert!(e_mode.mode == PositionMode::Normal);
+    assert!(e_mode.isolated_token.is_none());
+
+    // Position changes must not alter the account attributes
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: ISOLATED_TOKEN,
+            amount: BigUint::from(50u64),
+            asset_decimals: ISOLATED_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    assert!(state.decode_account_attributes(1) == isolated);
+
+    // Closing the account removes its attributes
+    state.withdraw_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(100u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    state.decode_account_attributes_error(2, ERROR_ACCOUNT_NOT_IN_THE_MARKET);
+}
+
+/// Tests the e-mode impact view against the base configuration health factor.
+///
+/// Covers:
+/// - Controller::getEModeImpact view functionality
+/// - Health factor under e-mode matching the account health factor
+/// - Health factor without e-mode scaled by the base liquidation threshold
+#[test]
+fn views_e_mode_impact_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: XEGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::Some(1),
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        XEGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        XEGLD_DECIMALS,
+    );
+
+    let (hf_with_e_mode, hf_without_e_mode) = state.e_mode_impact(2);
+    assert_eq!(hf_with_e_mode, state.account_health_factor(2));
+    assert!(hf_with_e_mode > hf_without_e_mode);
+
+    // Only the liquidation threshold differs: 95% in e-mode versus 80% for EGLD
+    let scaled_with = hf_with_e_mode.into_raw_units().clone() * BigUint::from(8_000u64);
+    let scaled_without =
+        hf_without_e_mode.into_raw_units().clone() * BigUint::from(E_MODE_LIQ_THRESHOLD);
+    let diff = if scaled_with > scaled_without {
+        scaled_with - scaled_without
+    } else {
+        scaled_without - scaled_with
+    };
+    assert!(diff <= BigUint::from(RAY / 1_000_000_000));
+
+    // Accounts without debt report the same maximum health factor in both cases
+    let (supplier_with, supplier_without) = state.e_mode_impact(1);
+    assert_eq!(supplier_with, supplier_without);
+}
+
+/// Tests view error cases for non-existent positions.
+///
+/// Covers:
+/// - Error handling for non-existent collateral
+/// - View validation for missing data
+#[test]
+fn views_error_handling_non_existent_data() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+
+    setup_account(&mut state, supplier);
+
+    // Create minimal position
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(10u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Test error for non-existent collateral token
+    let custom_error_message = format!("Token not existing in the account {}", USDC_TOKEN.as_str());
+    state.collateral_amount_for_token_non_existing(1, USDC_TOKEN, custom_error_message.as_bytes());
+}
+
+/// Tests complex liquidation estimation with bad debt scenario.
+///
+/// Covers:
+/// - Complex multi-asset liquidation estimation
+/// - Bad debt scenario handling
+/// - Full liquidation with insufficient collateral
+/// - Multiple collateral seizure calculations
+/// - Protocol fee distribution across assets
+#[test]
+fn views_complex_liquidation_bad_debt_scenario() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    // Create large liquidity pools
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(500u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(10000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: CAPPED_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: CAPPED_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Borrower supplies multiple collaterals
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(30u64),
+            asset_decimals: XEGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: SEGLD_TOKEN,
+            amount: BigUint::from(40u64),
+            asset_decimals: SEGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Borrower takes multiple loans
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(20u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    state.borrow_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(500u64),
+        2,
+        USDC_DECIMALS,
+    );
+
+    state.borrow_asset(
+        &borrower,
+        CAPPED_TOKEN,
+        BigUint::from(10u64),
+        2,
+        CAPPED_DECIMALS,
+    );
+
+    // Advance time significantly to create bad debt
+    state.change_timestamp(SECONDS_PER_DAY * 15000);
+    let mut markets = MultiValueEncoded::new();
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN));
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN));
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(CAPPED_TOKEN));
+    state.update_markets(&borrower, markets);
+
+    // Verify position is deeply underwater
+    let health_factor = state.account_health_factor(2);
+    let can_liquidate = state.can_be_liquidated(2);
+    assert!(can_liquidate);
+    assert!(
+        health_factor
+            < ManagedDecimal::from_raw_units(
+                BigUint::from(10u64).pow(RAY_PRECISION as u32),
+                RAY_PRECISION
+            )
+    );
+
+    // Prepare full liquidation attempt
+    let borrowed_egld = state.borrow_amount_for_token(2, EGLD_TOKEN);
+    let borrowed_usdc = state.borrow_amount_for_token(2, USDC_TOKEN);
+    let borrowed_capped = state.borrow_amount_for_token(2, CAPPED_TOKEN);
 
     let mut debt_payments = ManagedVec::new();
     debt_payments.push(EgldOrEsdtTokenPayment::new(
@@ -655,31 +2052,789 @@ fn views_complex_liquidation_bad_debt_scenario() {
         borrowed_capped.as_raw_units().clone(),
     ));
 
-    // Get liquidation estimations
-    let liquidation_estimate = state.liquidation_estimations(2, debt_payments);
+    // Get liquidation estimations
+    let liquidation_estimate = state.liquidation_estimations(2, debt_payments);
+
+    // Full refund of the last paid debt since it was overpaid
+    assert_eq!(
+        liquidation_estimate.refunds.get(0).amount,
+        borrowed_capped.as_raw_units().clone()
+    );
+    // Partial refund of the second paid debt since it was overpaid
+    assert_eq!(
+        liquidation_estimate.refunds.get(1).amount,
+        borrowed_usdc.as_raw_units().clone()
+    );
+    // Partial refund of the first paid debt since it was underpaid
+    assert!(liquidation_estimate.refunds.get(2).amount < borrowed_egld.as_raw_units().clone());
+    // Verify complex liquidation results
+    assert_eq!(liquidation_estimate.seized_collaterals.len(), 2); // Both collateral types seized
+    assert_eq!(liquidation_estimate.protocol_fees.len(), 2); // Fees for each seized asset
+    assert_eq!(liquidation_estimate.refunds.len(), 3); // Refunds from the last to the first asset
+    assert!(
+        liquidation_estimate.max_egld_payment_wad
+            > ManagedDecimal::from_raw_units(BigUint::zero(), RAY_PRECISION)
+    );
+    assert!(
+        liquidation_estimate.bonus_rate_bps
+            > ManagedDecimal::from_raw_units(BigUint::from(100u64), BPS_PRECISION)
+    );
+}
+
+/// Tests the interest accrued since a timestamp reconstructed from index snapshots.
+///
+/// Covers:
+/// - Controller::setIndexSnapshotInterval configuration
+/// - Snapshots recorded by updateIndexes at most once per interval
+/// - Controller::getInterestAccruedSince matching the difference between two synced states
+/// - ERROR_INDEX_SNAPSHOT_NOT_FOUND before the first snapshot
+#[test]
+fn views_interest_accrued_since_matches_synced_states() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+    state.set_index_snapshot_interval(3_600);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(1_000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(500u64),
+        2,
+        USDC_DECIMALS,
+    );
+
+    let snapshot_timestamp = 86_400u64;
+    let mut markets = MultiValueEncoded::new();
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(
+        USDC_TOKEN.to_esdt_token_identifier(),
+    ));
+
+    state.change_timestamp(snapshot_timestamp);
+    state.update_markets(&supplier, markets.clone());
+    let borrow_start = state.borrow_amount_for_token(2, USDC_TOKEN);
+    let supply_start = state.collateral_amount_for_token(1, USDC_TOKEN);
+
+    // Within the interval, so no snapshot is recorded
+    state.change_timestamp(snapshot_timestamp + 60);
+    state.update_markets(&supplier, markets.clone());
+
+    state.change_timestamp(snapshot_timestamp + 30 * 86_400);
+    state.update_markets(&supplier, markets);
+    let borrow_end = state.borrow_amount_for_token(2, USDC_TOKEN);
+    let supply_end = state.collateral_amount_for_token(1, USDC_TOKEN);
+    assert!(borrow_end > borrow_start);
+
+    let (borrow_interest, supply_interest) =
+        state.interest_accrued_since(2, USDC_TOKEN, snapshot_timestamp);
+    assert_eq!(borrow_interest, borrow_end - borrow_start);
+    assert_eq!(supply_interest.as_raw_units(), &BigUint::zero());
+
+    let (borrow_interest, supply_interest) =
+        state.interest_accrued_since(1, USDC_TOKEN, snapshot_timestamp);
+    assert_eq!(borrow_interest.as_raw_units(), &BigUint::zero());
+    assert_eq!(supply_interest, supply_end.clone() - supply_start.clone());
+
+    // The latest snapshot at or before the timestamp is the first one
+    let (_, supply_interest) = state.interest_accrued_since(1, USDC_TOKEN, snapshot_timestamp + 60);
+    assert_eq!(supply_interest, supply_end - supply_start);
+
+    state.interest_accrued_since_error(
+        1,
+        USDC_TOKEN,
+        snapshot_timestamp - 1,
+        ERROR_INDEX_SNAPSHOT_NOT_FOUND,
+    );
+}
+
+/// Tests the borrow and supply rate spread across markets.
+///
+/// Covers:
+/// - Controller::getMarketRateSpread returning one entry per requested market
+/// - Rates matching the pool borrow and supply rates annualized
+/// - Spread equal to the borrow rate minus the supply rate
+#[test]
+fn views_market_rate_spread_equals_borrow_minus_supply() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5_000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(2_000u64),
+        2,
+        USDC_DECIMALS,
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(10u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    let mut assets = MultiValueEncoded::new();
+    assets.push(EgldOrEsdtTokenIdentifier::esdt(
+        USDC_TOKEN.to_esdt_token_identifier(),
+    ));
+    assets.push(EgldOrEsdtTokenIdentifier::esdt(
+        EGLD_TOKEN.to_esdt_token_identifier(),
+    ));
+    let spreads = state.market_rate_spread(assets);
+    assert_eq!(spreads.len(), 2);
+
+    let markets = [state.usdc_market.clone(), state.egld_market.clone()];
+    let milliseconds_per_year = BigUint::from(MILLISECONDS_PER_YEAR);
+    for (spread, market) in spreads.iter().zip(markets) {
+        let borrow_rate = state.market_borrow_rate(market.clone());
+        let supply_rate = state.market_supply_rate(market);
+        assert_eq!(
+            spread.borrow_rate_ray.into_raw_units(),
+            &(borrow_rate.into_raw_units() * &milliseconds_per_year)
+        );
+        assert_eq!(
+            spread.supply_rate_ray.into_raw_units(),
+            &(supply_rate.into_raw_units() * &milliseconds_per_year)
+        );
+        assert!(spread.spread_ray > ManagedDecimal::from_raw_units(BigUint::zero(), 27));
+        assert_eq!(
+            spread.spread_ray,
+            spread.borrow_rate_ray.clone() - spread.supply_rate_ray.clone()
+        );
+    }
+}
+
+/// Tests the deposit yield breakdown into interest and rewards.
+///
+/// Covers:
+/// - Controller::getDepositYieldBreakdown with interest and rewards both active
+/// - Interest component matching the pool supply rate annualized
+/// - Reward component annualized over the trailing snapshot period
+/// - Components summing to the total yield
+#[test]
+fn views_deposit_yield_breakdown_sums_interest_and_rewards() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+    state.set_index_snapshot_interval(3_600);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(1_000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(500u64),
+        2,
+        USDC_DECIMALS,
+    );
+
+    let snapshot_timestamp = SECONDS_PER_DAY;
+    let mut markets = MultiValueEncoded::new();
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(
+        USDC_TOKEN.to_esdt_token_identifier(),
+    ));
+    state.change_timestamp(snapshot_timestamp);
+    state.update_markets(&supplier, markets.clone());
+
+    // 10 USDC of rewards on ~1000 USDC supplied over 30 days
+    let reward = BigUint::from(10u64) * BigUint::from(10u64).pow(USDC_DECIMALS as u32);
+    state.add_rewards(&OWNER_ADDRESS, USDC_TOKEN, reward);
+    state.change_timestamp(snapshot_timestamp + 30 * SECONDS_PER_DAY);
+    state.update_markets(&supplier, markets);
+
+    let breakdown = state.deposit_yield_breakdown(USDC_TOKEN, snapshot_timestamp);
+    assert!(breakdown.interest_apy_ray > ManagedDecimal::from_raw_units(BigUint::zero(), 27));
+    assert!(breakdown.reward_apy_ray > ManagedDecimal::from_raw_units(BigUint::zero(), 27));
+    assert_eq!(
+        breakdown.total_apy_ray,
+        breakdown.interest_apy_ray.clone() + breakdown.reward_apy_ray.clone()
+    );
+
+    let supply_rate = state.market_supply_rate(state.usdc_market.clone());
+    assert_eq!(
+        breakdown.interest_apy_ray.into_raw_units(),
+        &(supply_rate.into_raw_units() * &BigUint::from(MILLISECONDS_PER_YEAR))
+    );
+
+    // ~1% over 30 days annualizes to ~12.2%
+    let ray = BigUint::from(RAY);
+    let reward_apy = breakdown.reward_apy_ray.into_raw_units();
+    assert!(reward_apy > &(&ray * &BigUint::from(11u64) / &BigUint::from(100u64)));
+    assert!(reward_apy < &(&ray * &BigUint::from(13u64) / &BigUint::from(100u64)));
+}
+
+/// Tests the market collateralization view as collateral and borrows shift.
+///
+/// Covers:
+/// - Controller::getCollateralizationRatio with no borrows
+/// - Ratio falling as more of the market is borrowed
+/// - Ratio recovering as more collateral is supplied
+#[test]
+fn views_collateralization_ratio_tracks_market_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    let bps = |amount: u64| ManagedDecimal::from_raw_units(BigUint::from(amount), BPS_PRECISION);
+    let egld = |amount: u128| {
+        ManagedDecimal::from_raw_units(scaled_amount(amount, EGLD_DECIMALS), WAD_PRECISION)
+    };
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    let (collateral, debt, ratio) = state.collateralization_ratio(EGLD_TOKEN);
+    assert_eq!(collateral, egld(100));
+    assert_eq!(debt, egld(0));
+    assert_eq!(ratio, bps(0));
+
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(10u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    let (collateral, debt, ratio) = state.collateralization_ratio(EGLD_TOKEN);
+    assert_eq!(collateral, egld(100));
+    assert_eq!(debt, egld(10));
+    assert_eq!(ratio, bps(100_000));
+
+    // More debt against the same collateral lowers the ratio
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(10u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    let (_, debt, ratio) = state.collateralization_ratio(EGLD_TOKEN);
+    assert_eq!(debt, egld(20));
+    assert_eq!(ratio, bps(50_000));
+
+    // More collateral restores it
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    let (collateral, _, ratio) = state.collateralization_ratio(EGLD_TOKEN);
+    assert_eq!(collateral, egld(200));
+    assert_eq!(ratio, bps(100_000));
+}
+
+/// Tests that accrued interest tracks index growth and ignores top-ups.
+///
+/// Covers:
+/// - ViewsModule::position_accrued_interest
+/// - Interest equals current balance minus principal for both sides
+/// - Increasing a position re-weights its entry index without adding interest
+#[test]
+fn views_position_accrued_interest_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
 
-    // Full refund of the last paid debt since it was overpaid
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    // Nothing accrued yet
+    let borrow_interest = state.position_accrued_interest(2, EGLD_TOKEN, true);
+    assert_eq!(borrow_interest.into_raw_units(), &BigUint::zero());
+
+    state.change_timestamp(SECONDS_PER_DAY * 30);
+
+    // Borrow interest matches the debt growth over the principal
+    let debt = state.borrow_amount_for_token(2, EGLD_TOKEN);
+    let borrow_interest = state.position_accrued_interest(2, EGLD_TOKEN, true);
+    let expected = debt.into_raw_units().clone() - scaled_amount(50, EGLD_DECIMALS);
+    assert!(borrow_interest.into_raw_units() > &BigUint::zero());
+    assert!(borrow_interest.into_raw_units().clone() + 1u64 >= expected);
+    assert!(borrow_interest.into_raw_units() <= &(expected.clone() + 1u64));
+
+    // Supply interest matches the deposit growth over the principal
+    let deposit = state.collateral_amount_for_token(1, EGLD_TOKEN);
+    let supply_interest = state.position_accrued_interest(1, EGLD_TOKEN, false);
+    let expected_supply = deposit.into_raw_units().clone() - scaled_amount(100, EGLD_DECIMALS);
+    assert!(supply_interest.into_raw_units() > &BigUint::zero());
+    assert!(supply_interest.into_raw_units().clone() + 1u64 >= expected_supply);
+    assert!(supply_interest.into_raw_units() <= &(expected_supply + 1u64));
+
+    // A top-up at the current index does not count as interest
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(10u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    let after_top_up = state.position_accrued_interest(2, EGLD_TOKEN, true);
+    assert!(after_top_up.into_raw_units().clone() + 2u64 >= expected);
+    assert!(after_top_up.into_raw_units() <= &(expected + 2u64));
+}
+
+/// Tests that the synced health factor accrues interest the unsynced one ignores.
+///
+/// Covers:
+/// - ViewsModule::health_factor_synced
+/// - ViewsModule::health_factor with view index sync disabled
+/// - Both agree again once the markets are updated on-chain
+#[test]
+fn views_health_factor_synced_vs_unsynced_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(50u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    state.set_view_index_sync_enabled(false);
+    let initial = state.account_health_factor(2);
+    assert_eq!(initial, state.account_health_factor_synced(2));
+
+    state.change_timestamp(SECONDS_PER_DAY * 30);
+
+    // Without a sync the view still reports the health factor as of the borrow
+    let unsynced = state.account_health_factor(2);
+    let synced = state.account_health_factor_synced(2);
+    assert_eq!(unsynced, initial);
+    assert!(synced < unsynced);
+
+    // Re-enabling the sync makes the default view exactly current
+    state.set_view_index_sync_enabled(true);
+    assert_eq!(state.account_health_factor(2), synced);
+
+    // After an on-chain update both modes agree
+    state.set_view_index_sync_enabled(false);
+    let mut markets = MultiValueEncoded::new();
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN));
+    markets.push(EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN));
+    state.update_markets(&supplier, markets);
     assert_eq!(
-        liquidation_estimate.refunds.get(0).amount,
-        borrowed_capped.as_raw_units().clone()
+        state.account_health_factor(2),
+        state.account_health_factor_synced(2)
     );
-    // Partial refund of the second paid debt since it was overpaid
+}
+
+/// Tests the rate model preview of a liquidity pool.
+///
+/// Covers:
+/// - LiquidityPool::getRatesAtUtilization view functionality
+/// - Preview at the current utilization matches the live rates
+/// - Rates increase along the utilization curve without changing pool state
+#[test]
+fn views_rates_at_utilization_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XEGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: XEGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(45u64),
+        2,
+        EGLD_DECIMALS,
+    );
+
+    let market = state.egld_market.clone();
+    let utilization = state.market_utilization(market.clone());
+    let borrow_rate = state.market_borrow_rate(market.clone());
+    let supply_rate = state.market_supply_rate(market.clone());
+
+    // The preview at the live utilization reproduces the live rates
+    let (preview_borrow, preview_supply) =
+        state.market_rates_at_utilization(market.clone(), utilization.as_raw_units().clone());
+    assert_eq!(preview_borrow, borrow_rate);
+    assert_eq!(preview_supply, supply_rate);
+
+    // An idle pool pays no supply yield
+    let (idle_borrow, idle_supply) =
+        state.market_rates_at_utilization(market.clone(), BigUint::zero());
     assert_eq!(
-        liquidation_estimate.refunds.get(1).amount,
-        borrowed_usdc.as_raw_units().clone()
+        idle_supply,
+        ManagedDecimal::from_raw_units(BigUint::zero(), RAY_PRECISION)
     );
-    // Partial refund of the first paid debt since it was underpaid
-    assert!(liquidation_estimate.refunds.get(2).amount < borrowed_egld.as_raw_units().clone());
-    // Verify complex liquidation results
-    assert_eq!(liquidation_estimate.seized_collaterals.len(), 2); // Both collateral types seized
-    assert_eq!(liquidation_estimate.protocol_fees.len(), 2); // Fees for each seized asset
-    assert_eq!(liquidation_estimate.refunds.len(), 3); // Refunds from the last to the first asset
-    assert!(
-        liquidation_estimate.max_egld_payment_wad
-            > ManagedDecimal::from_raw_units(BigUint::zero(), RAY_PRECISION)
+    assert!(idle_borrow < borrow_rate);
+
+    // Sampling the curve yields increasing rates
+    let mut last_borrow = idle_borrow;
+    let mut last_supply = idle_supply;
+    for percent in [25u64, 50, 75, 90, 100] {
+        let (borrow, supply) = state
+            .market_rates_at_utilization(market.clone(), BigUint::from(RAY) * percent / 100u64);
+        assert!(borrow > last_borrow);
+        assert!(supply > last_supply);
+        last_borrow = borrow;
+        last_supply = supply;
+    }
+
+    // Previews leave the pool untouched
+    assert_eq!(state.market_utilization(market.clone()), utilization);
+    assert_eq!(state.market_borrow_rate(market), borrow_rate);
+}
+
+/// Tests the per-collateral health factor contribution views.
+///
+/// Covers:
+/// - Controller::getPositionHealthContribution view functionality
+/// - Controller::getMarginalHealthContribution view functionality
+/// - Contributions add up to the health factor and the liquidation collateral
+/// - One unit of a pricier collateral token adds more health factor
+#[test]
+fn views_position_health_contribution_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(10000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
     );
-    assert!(
-        liquidation_estimate.bonus_rate_bps
-            > ManagedDecimal::from_raw_units(BigUint::from(100u64), BPS_PRECISION)
+
+    // Borrower: $400 EGLD and $1000 XOXNO collateral, $500 USDC debt
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(10u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: XOXNO_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: XOXNO_DECIMALS,
+            account_nonce: OptionalValue::Some(2),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Without debt every contribution is zero
+    for item in state.position_health_contribution(2) {
+        let (_, contribution) = item.into_tuple();
+        assert_eq!(contribution.into_raw_units(), &BigUint::zero());
+    }
+
+    state.borrow_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(500u64),
+        2,
+        USDC_DECIMALS,
+    );
+
+    let mut contribution_sum = BigUint::zero();
+    let mut egld_contribution = BigUint::zero();
+    let contributions = state.position_health_contribution(2);
+    assert_eq!(contributions.len(), 2);
+    for item in contributions {
+        let (token_id, contribution) = item.into_tuple();
+        assert!(contribution.into_raw_units() > &BigUint::zero());
+        if token_id == EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN) {
+            egld_contribution = contribution.into_raw_units().clone();
+        }
+        contribution_sum += contribution.into_raw_units();
+    }
+
+    // The contributions add up to the health factor, up to per-position rounding
+    let health_factor = state.account_health_factor(2).into_raw_units().clone();
+    let hf_diff = if contribution_sum > health_factor {
+        contribution_sum.clone() - health_factor
+    } else {
+        health_factor - contribution_sum.clone()
+    };
+    assert!(hf_diff <= BigUint::from(2u64));
+
+    // Scaled back by the debt they match the liquidation collateral
+    let total_borrow_wad = state.total_borrow_in_egld(2).into_raw_units().clone();
+    let weighted_from_contributions = contribution_sum * total_borrow_wad / BigUint::from(RAY);
+    let liquidation_collateral = state
+        .liquidation_collateral_available(2)
+        .into_raw_units()
+        .clone();
+    let collateral_diff = if weighted_from_contributions > liquidation_collateral {
+        weighted_from_contributions - liquidation_collateral
+    } else {
+        liquidation_collateral - weighted_from_contributions
+    };
+    assert!(collateral_diff <= BigUint::from(1000u64));
+
+    // One EGLD ($40) adds more health factor than one XOXNO ($1)
+    let mut marginal_egld = BigUint::zero();
+    let mut marginal_xoxno = BigUint::zero();
+    for item in state.marginal_health_contribution(2) {
+        let (token_id, contribution) = item.into_tuple();
+        if token_id == EgldOrEsdtTokenIdentifier::esdt(EGLD_TOKEN) {
+            marginal_egld = contribution.into_raw_units().clone();
+        } else {
+            marginal_xoxno = contribution.into_raw_units().clone();
+        }
+    }
+    assert!(marginal_xoxno > BigUint::zero());
+    assert!(marginal_egld > marginal_xoxno);
+    // Ten EGLD held contribute ten times the marginal contribution of one
+    let ten_units = marginal_egld * BigUint::from(10u64);
+    let egld_diff = if egld_contribution > ten_units {
+        egld_contribution - ten_units
+    } else {
+        ten_units - egld_contribution
+    };
+    assert!(egld_diff <= BigUint::from(10u64));
+}
+
+/// Tests the maximum borrowable amount view.
+///
+/// Covers:
+/// - Controller::getMaxBorrowable view functionality
+/// - Borrowing the reported amount succeeds and exhausts the LTV headroom
+/// - Siloed assets report zero once another asset is borrowed
+/// - Account borrow cap overrides bound the reported amount
+#[test]
+fn views_max_borrowable_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Borrower: $2000 USDC collateral, no debt yet
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(2000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // LTV collateral converted at $40 per EGLD
+    let ltv_collateral = state.ltv_collateral_in_egld(2).into_raw_units().clone();
+    let max_egld = state.max_borrowable(2, &EGLD_TOKEN);
+    assert_eq!(max_egld.scale(), EGLD_DECIMALS);
+    assert!(max_egld.into_raw_units() > &BigUint::zero());
+    assert!(max_egld.into_raw_units() <= &ltv_collateral);
+
+    // An account borrow cap override bounds the estimate
+    state.set_account_borrow_cap(2, EGLD_TOKEN, scaled_amount(5, EGLD_DECIMALS));
+    assert_eq!(
+        state.max_borrowable(2, &EGLD_TOKEN).into_raw_units(),
+        &scaled_amount(5, EGLD_DECIMALS)
+    );
+    state.set_account_borrow_cap(2, EGLD_TOKEN, BigUint::zero());
+
+    // Borrowing exactly the reported amount passes the borrow checks
+    let max_egld = state.max_borrowable(2, &EGLD_TOKEN);
+    state.borrow_asset_den(&borrower, EGLD_TOKEN, max_egld.into_raw_units().clone(), 2);
+    let remaining = state.max_borrowable(2, &EGLD_TOKEN);
+    assert!(remaining.into_raw_units() < &scaled_amount(1, EGLD_DECIMALS - 6));
+    state.borrow_asset_error(
+        &borrower,
+        EGLD_TOKEN,
+        BigUint::from(1u64),
+        2,
+        EGLD_DECIMALS,
+        ERROR_INSUFFICIENT_COLLATERAL,
+    );
+
+    // A siloed asset cannot be added next to the existing EGLD debt
+    assert_eq!(
+        state.max_borrowable(2, &SILOED_TOKEN).into_raw_units(),
+        &BigUint::zero()
     );
 }
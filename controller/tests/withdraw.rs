@@ -1,6 +1,6 @@
 use common_constants::RAY;
 use controller::{ERROR_HEALTH_FACTOR_WITHDRAW, ERROR_INSUFFICIENT_LIQUIDITY};
-use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, MultiValueEncoded};
+use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, MultiValueEncoded, TimestampSeconds};
 use multiversx_sc_scenario::imports::{BigUint, OptionalValue, StaticApi, TestAddress};
 pub mod constants;
 pub mod proxys;
@@ -562,6 +562,80 @@ fn withdraw_self_liquidation_protection_error() {
     );
 }
 
+/// Tests that the account NFT is never burned while a borrow remains open.
+///
+/// Covers:
+/// - Controller::withdraw refusing to empty the deposits of an account with debt
+/// - Account NFT and storage kept alive after the rejected withdrawal
+/// - Account NFT burned only once every position is closed
+#[test]
+fn withdraw_all_collateral_with_open_borrow_keeps_account_error() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    setup_accounts(&mut state, supplier, borrower);
+
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: EGLD_TOKEN,
+            amount: BigUint::from(100u64),
+            asset_decimals: EGLD_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.supply_asset(
+        &borrower,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(5000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::None,
+            e_mode_category: OptionalValue::None,
+        },
+    );
+    state.borrow_asset(&borrower, EGLD_TOKEN, BigUint::from(1u64), 2, EGLD_DECIMALS);
+
+    // Emptying the only deposit would leave the borrow behind a burned NFT
+    state.withdraw_asset_error(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(5000u64),
+        2,
+        USDC_DECIMALS,
+        ERROR_HEALTH_FACTOR_WITHDRAW,
+    );
+
+    assert!(state.accounts().into_iter().any(|nonce| nonce == 2));
+    assert_eq!(state.account_holder(2), borrower.to_managed_address());
+    state.assert_borrow_raw_eq(
+        2,
+        &EGLD_TOKEN,
+        scaled_amount(1, EGLD_DECIMALS),
+        "rejected withdrawal must keep the borrow",
+    );
+
+    // Once the debt is repaid, closing the last deposit burns the NFT
+    state.repay_asset(
+        &borrower,
+        &EGLD_TOKEN,
+        BigUint::from(2u64),
+        2,
+        EGLD_DECIMALS,
+    );
+    state.withdraw_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(5000u64),
+        2,
+        USDC_DECIMALS,
+    );
+
+    assert!(!state.accounts().into_iter().any(|nonce| nonce == 2));
+}
+
 /// Tests that withdrawing a non-deposited asset fails with appropriate error.
 ///
 /// Covers:
@@ -674,3 +748,188 @@ fn withdraw_non_deposited_asset_error() {
         "Total borrow should remain unchanged when withdrawing non-existent asset",
     );
 }
+
+/// Tests that withdrawing before the minimum supply duration forfeits accrued rewards only.
+///
+/// Covers:
+/// - Controller::setMinSupplyDuration configuration
+/// - Reward forfeiture in positions::withdraw::PositionWithdrawModule
+/// - Principal kept on early withdrawal and rewards kept after the duration
+#[test]
+fn withdraw_before_min_supply_duration_forfeits_rewards_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+    state.set_min_supply_duration(USDC_TOKEN, 86_400);
+
+    // Two accounts supply 1000 USDC each while the rule is active
+    for (address, nonce) in [(supplier, 1u64), (borrower, 2u64)] {
+        state.supply_asset(
+            &address,
+            SupplyParams {
+                token_id: USDC_TOKEN,
+                amount: BigUint::from(1000u64),
+                asset_decimals: USDC_DECIMALS,
+                account_nonce: OptionalValue::None,
+                e_mode_category: OptionalValue::None,
+            },
+        );
+        let supply_timestamp = state.supply_timestamp(nonce, USDC_TOKEN);
+        assert_eq!(
+            supply_timestamp,
+            TimestampSeconds::new(0),
+            "supply should start the minimum period"
+        );
+    }
+
+    // 100 USDC of rewards split evenly between both deposits
+    state.add_rewards(
+        &OWNER_ADDRESS,
+        USDC_TOKEN,
+        scaled_amount(100, USDC_DECIMALS),
+    );
+
+    let tolerance_raw = usdc_tolerance_raw(10);
+    let assert_near = |actual: BigUint<StaticApi>, expected: BigUint<StaticApi>, context: &str| {
+        let diff = if actual >= expected {
+            actual.clone() - expected.clone()
+        } else {
+            expected.clone() - actual.clone()
+        };
+        assert!(
+            diff <= tolerance_raw,
+            "{context}: expected≈{expected:?} actual={actual:?}"
+        );
+    };
+
+    assert_near(
+        state
+            .collateral_amount_for_token(1, USDC_TOKEN)
+            .as_raw_units()
+            .clone(),
+        scaled_amount(1050, USDC_DECIMALS),
+        "supplier should accrue half of the rewards",
+    );
+
+    // Early withdrawal forfeits the accrued rewards but keeps the principal
+    state.change_timestamp(3_600);
+    state.withdraw_asset(
+        &supplier,
+        USDC_TOKEN,
+        BigUint::from(10u64),
+        1,
+        USDC_DECIMALS,
+    );
+    assert_near(
+        state
+            .collateral_amount_for_token(1, USDC_TOKEN)
+            .as_raw_units()
+            .clone(),
+        scaled_amount(990, USDC_DECIMALS),
+        "early withdrawal should forfeit the rewards",
+    );
+    assert_near(
+        state
+            .collateral_amount_for_token(2, USDC_TOKEN)
+            .as_raw_units()
+            .clone(),
+        scaled_amount(1050, USDC_DECIMALS),
+        "forfeiture must not affect other suppliers",
+    );
+
+    // Once the minimum duration elapsed the rewards are kept
+    state.change_timestamp(86_401);
+    state.withdraw_asset(
+        &borrower,
+        USDC_TOKEN,
+        BigUint::from(10u64),
+        2,
+        USDC_DECIMALS,
+    );
+    assert_near(
+        state
+            .collateral_amount_for_token(2, USDC_TOKEN)
+            .as_raw_units()
+            .clone(),
+        scaled_amount(1040, USDC_DECIMALS),
+        "withdrawal after the minimum duration should keep the rewards",
+    );
+}
+
+/// Tests that a top-up during the minimum supply period is not charged for earlier rewards.
+///
+/// Covers:
+/// - Reward index snapshot weighted by scaled amount on a top-up
+/// - Early withdrawal forfeiting only the rewards accrued by the previous principal
+/// - Principal of both supplies returned in full
+#[test]
+fn withdraw_after_top_up_before_min_supply_duration_keeps_principal_success() {
+    let mut state = LendingPoolTestState::new();
+    let supplier = TestAddress::new("supplier");
+    let borrower = TestAddress::new("borrower");
+
+    state.change_timestamp(0);
+    setup_accounts(&mut state, supplier, borrower);
+    state.set_min_supply_duration(USDC_TOKEN, 86_400);
+
+    for address in [supplier, borrower] {
+        state.supply_asset(
+            &address,
+            SupplyParams {
+                token_id: USDC_TOKEN,
+                amount: BigUint::from(1000u64),
+                asset_decimals: USDC_DECIMALS,
+                account_nonce: OptionalValue::None,
+                e_mode_category: OptionalValue::None,
+            },
+        );
+    }
+
+    // 100 USDC of rewards split evenly between both deposits
+    state.add_rewards(
+        &OWNER_ADDRESS,
+        USDC_TOKEN,
+        scaled_amount(100, USDC_DECIMALS),
+    );
+
+    // The supplier doubles its deposit after the rewards were distributed
+    state.change_timestamp(1_800);
+    state.supply_asset(
+        &supplier,
+        SupplyParams {
+            token_id: USDC_TOKEN,
+            amount: BigUint::from(1000u64),
+            asset_decimals: USDC_DECIMALS,
+            account_nonce: OptionalValue::Some(1),
+            e_mode_category: OptionalValue::None,
+        },
+    );
+
+    // Only the 50 USDC earned by the first supply are forfeited
+    state.change_timestamp(3_600);
+    state.withdraw_asset(
+        &supplier,
+        USDC_TOKEN,
+        BigUint::from(10u64),
+        1,
+        USDC_DECIMALS,
+    );
+
+    let actual = state
+        .collateral_amount_for_token(1, USDC_TOKEN)
+        .as_raw_units()
+        .clone();
+    let expected = scaled_amount(1990, USDC_DECIMALS);
+    let diff = if actual >= expected {
+        actual.clone() - expected.clone()
+    } else {
+        expected.clone() - actual.clone()
+    };
+    assert!(
+        diff <= usdc_tolerance_raw(10),
+        "top-up principal must be returned in full: expected≈{expected:?} actual={actual:?}"
+    );
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           73
+// Endpoints:                          219
 // Async Callback:                       1
-// Total number of exported functions:  76
+// Total number of exported functions: 222
 
 #![no_std]
 
@@ -21,24 +21,47 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         supply => supply
+        supplyFor => supply_for
         withdraw => withdraw
+        claimDustCollateral => claim_dust_collateral
         borrow => borrow
+        borrowWithMinHealthFactor => borrow_with_min_health_factor
+        borrowWithMaxRate => borrow_with_max_rate
         repay => repay
+        selfRepayFromSupply => self_repay_from_supply
+        closeAccount => close_account
+        repayAndWithdraw => repay_and_withdraw
+        rollEMode => roll_e_mode
         liquidate => liquidate
         flashLoan => flash_loan
         updateAccountThreshold => update_account_threshold
         updateIndexes => update_indexes
+        setAutoCompound => set_auto_compound
+        disableAsCollateral => disable_as_collateral
+        enableAsCollateral => enable_as_collateral
+        compoundInterest => compound_interest
         cleanBadDebt => clean_bad_debt
         createLiquidityPool => create_liquidity_pool
         upgradeLiquidityPool => upgrade_liquidity_pool
         upgradeLiquidityPoolParams => upgrade_liquidity_pool_params
+        setMarketMinInitialSupply => set_market_min_initial_supply
+        setMarketRevenueAbsorbsBadDebt => set_market_revenue_absorbs_bad_debt
+        setMarketRateModelType => set_market_rate_model_type
+        setMarketReserveFactorHigh => set_market_reserve_factor_high
+        setMarketMaxAccrualRate => set_market_max_accrual_rate
+        setMarketMinAccrualInterval => set_market_min_accrual_interval
+        setMarketAccrualPaused => set_market_accrual_paused
         claimRevenue => claim_revenue
         addRewards => add_reward
         registerAccountToken => register_account_token
         setTokenOracle => set_token_oracle
+        batchSetTokenOracle => batch_set_token_oracle
         editTokenOracleTolerance => edit_token_oracle_tolerance
+        editTokenOracleQuorum => edit_token_oracle_quorum
+        editTokenOracleTwapRounds => edit_token_oracle_twap_rounds
         setAggregator => set_aggregator
         setSwapRouter => set_swap_router
+        setDefaultSwapSteps => set_default_swap_steps
         setAccumulator => set_accumulator
         setSafePriceView => set_safe_price_view
         setLiquidityPoolTemplate => set_liquidity_pool_template
@@ -47,9 +70,43 @@ multiversx_sc_wasm_adapter::endpoints! {
         removeEModeCategory => remove_e_mode_category
         addAssetToEModeCategory => add_asset_to_e_mode_category
         editAssetInEModeCategory => edit_asset_in_e_mode_category
+        setEModeCompanionCollateralRequired => set_e_mode_companion_collateral_required
         removeAssetFromEModeCategory => remove_asset_from_e_mode_category
         editAssetConfig => edit_asset_config
+        setBorrowCap => set_borrow_cap
+        setDustThreshold => set_dust_threshold
+        setLiquidationHaircut => set_liquidation_haircut
         setPositionLimits => set_position_limits
+        setMaxBatchAccounts => set_max_batch_accounts
+        setLiquidateeRebate => set_liquidatee_rebate
+        addProtocolOwnedAccount => add_protocol_owned_account
+        removeProtocolOwnedAccount => remove_protocol_owned_account
+        setBorrowCapExemptAddresses => set_borrow_cap_exempt_addresses
+        setMinStrategyHealthFactor => set_min_strategy_health_factor
+        setMultiplyOriginationFee => set_multiply_origination_fee
+        setFlashLoanProtocolShare => set_flash_loan_protocol_share
+        setDebtCreationEnabled => set_debt_creation_enabled
+        pauseMarket => pause_market
+        unpauseMarket => unpause_market
+        setNewSupplyEnabled => set_new_supply_enabled
+        setNewBorrowEnabled => set_new_borrow_enabled
+        setRepayPrincipalFirst => set_repay_principal_first
+        setViewIndexSyncEnabled => set_view_index_sync_enabled
+        setMinReserveRatio => set_min_reserve_ratio
+        setMaxLiquidationPriceDrop => set_max_liquidation_price_drop
+        setLiquidationDustThreshold => set_liquidation_dust_threshold
+        setAccountBorrowAllowance => set_account_borrow_allowance
+        setAccountBorrowCap => set_account_borrow_cap
+        setMaxPositionValue => set_max_position_value
+        setMinSeizedCollateralEgld => set_min_seized_collateral_egld
+        setPostLiquidationGrace => set_post_liquidation_grace
+        setThresholdUpdateGrace => set_threshold_update_grace
+        setDelistedPriceMaxAge => set_delisted_price_max_age
+        setPriceStalenessOverride => set_price_staleness_override
+        setMinSupplyDuration => set_min_supply_duration
+        setMarketActivityCapacity => set_market_activity_capacity
+        setIndexSnapshotInterval => set_index_snapshot_interval
+        setEgldMaxPriceStaleSeconds => set_egld_max_price_stale_seconds
         disableTokenOracle => disable_token_oracle
         getPools => pools
         getAccount => account
@@ -63,33 +120,122 @@ multiversx_sc_wasm_adapter::endpoints! {
         getPriceAggregatorAddress => price_aggregator_address
         getSafePriceAddress => safe_price_view
         getSwapRouterAddress => swap_router
+        getDefaultSwapSteps => default_swap_steps
         getAssetConfig => asset_config
         lastEModeCategoryId => last_e_mode_category_id
         getEModes => e_mode_categories
         getAssetEModes => asset_e_modes
         getEModesAssets => e_mode_assets
+        isEModeCompanionCollateralRequired => e_mode_companion_collateral_required
         getIsolatedAssetDebtUsd => isolated_asset_debt_usd
         getTokenOracle => token_oracle
+        getTokenOracleQuorum => min_oracle_quorum
+        getTokenOracleTwapRounds => twap_rounds
         isFlashLoanOngoing => flash_loan_ongoing
         getPositionLimits => position_limits
+        getMaxBatchAccounts => max_batch_accounts
+        getLiquidateeRebateBps => liquidatee_rebate_bps
+        getProtocolOwnedAccounts => protocol_owned_accounts
+        getBorrowCapExemptAddresses => borrow_cap_exempt_addresses
+        getMinStrategyHealthFactor => min_strategy_hf_ray
+        getMultiplyOriginationFeeBps => multiply_origination_fee_bps
+        getFlashLoanProtocolShareBps => flash_loan_protocol_share_bps
+        isDebtCreationDisabled => debt_creation_disabled
+        isMarketPaused => market_paused
+        isNewSupplyDisabled => new_supply_disabled
+        isNewBorrowDisabled => new_borrow_disabled
+        isViewIndexSyncDisabled => view_index_sync_disabled
+        getMinReserveRatioBps => min_reserve_ratio_bps
+        getMaxLiquidationCloseFactorBps => max_liquidation_close_factor_bps
+        getBorrowCapDenomination => borrow_cap_denomination
+        getDustThreshold => dust_threshold_wad
+        getLiquidationHaircut => liquidation_haircut_bps
+        getAccountBorrowAllowance => account_borrow_allowance
+        getAccountBorrowCapOverride => account_borrow_cap_override
+        getMaxPositionValue => max_position_value
+        getMinSeizedCollateralEgld => min_seized_collateral_egld
+        isAutoCompoundEnabled => auto_compound
+        getCompoundedPrincipal => compounded_principal
+        getPositionEntry => position_entry
+        isRepayPrincipalFirst => repay_principal_first
+        getMaxLiquidationPriceDropBps => max_liquidation_price_drop_bps
+        getLiquidationReferencePrice => liquidation_reference_price
+        getLastTokenPrice => last_token_price
+        getLastTokenPriceTimestamp => last_token_price_timestamp
+        getDelistedPriceMaxAge => delisted_price_max_age
+        getLiquidationDustThreshold => liquidation_dust_threshold_usd
+        getEgldMaxPriceStaleSeconds => egld_max_price_stale_seconds
+        getPriceStalenessOverride => price_staleness_override
+        getMarketActivityCapacity => market_activity_capacity
+        getIndexSnapshotInterval => index_snapshot_interval
+        getMinSupplyDuration => min_supply_duration
+        getMarketRewardIndex => market_reward_index
+        getSupplyTimestamp => supply_timestamp
+        isPositionCollateralDisabled => position_collateral_disabled
+        getPostLiquidationGrace => post_liquidation_grace
+        getLastLiquidationTimestamp => last_liquidation_timestamp
+        getThresholdUpdateGrace => threshold_update_grace
+        getLiquidationGraceUntil => liquidation_grace_until
+        getAccountHolder => account_holder
+        getUserAccounts => user_accounts
+        getDustCollateral => dust_collateral
         liquidationEstimations => liquidation_estimations
+        simulateLiquidationImpact => simulate_liquidation_impact
+        previewMultiply => preview_multiply
         getAllMarketIndexes => all_market_indexes
         getAllMarketIndexesExtended => all_market_indexes_extended
         getAllMarkets => all_markets
+        getTotalValueLocked => total_value_locked
+        getProtocolTotals => protocol_totals
+        getAggregateBorrowByEMode => aggregate_borrow_by_e_mode
+        getSupplyCapUtilization => supply_cap_utilization
+        getBorrowCapUtilization => borrow_cap_utilization
+        getIsolatedDebtInfo => isolated_debt_info
+        getCollateralizationRatio => collateralization_ratio
         canBeLiquidated => can_be_liquidated
         getHealthFactor => health_factor
+        getHealthFactorSynced => health_factor_synced
+        getHealthFactors => health_factors
+        simulateHealthFactor => simulate_health_factor
+        getPositionHealthContribution => position_health_contribution
+        getMarginalHealthContribution => marginal_health_contribution
+        getAccountsNearLiquidation => accounts_near_liquidation
+        isFullyBacked => is_fully_backed
+        getLiquidationPrice => liquidation_price
         getCollateralAmountForToken => collateral_amount_for_token
         getBorrowAmountForToken => borrow_amount_for_token
+        getPositionAccruedInterest => position_accrued_interest
         getTotalBorrowInEgld => total_borrow_in_egld
         getTotalCollateralInEgld => total_collateral_in_egld
         getLiquidationCollateralAvailable => liquidation_collateral_available
         getLtvCollateralInEgld => ltv_collateral_in_egld
+        getMaxBorrowable => max_borrowable
+        getAccountNetWorth => account_net_worth
+        getAccountSummary => account_summary
+        getWalletSummary => wallet_summary
+        getEModeImpact => e_mode_impact
         getTokenPriceUSD => usd_price
         getTokenPriceEGLD => egld_price
+        getTokenPriceWithAge => token_price_with_age
+        getOraclePriceWithConfidence => oracle_price_with_confidence
+        getBorrowRateAt => borrow_rate_at
+        getUtilizationForBorrowRate => utilization_for_borrow_rate
+        getFlashLoanFee => flash_loan_fee
+        getAccumulatedRounding => asset_accumulated_rounding
+        getClaimableRevenue => claimable_revenue
+        getRecentMarketActivity => recent_market_activity
+        getLiquidationHistory => account_liquidation_history
+        getInterestAccruedSince => interest_accrued_since
+        getDepositYieldBreakdown => deposit_yield_breakdown
+        getMarketRateSpread => market_rate_spread
+        decodeAccountAttributes => decode_account_attributes
         multiply => multiply
         swapDebt => swap_debt
         swapCollateral => swap_collateral
         repayDebtWithCollateral => repay_debt_with_collateral
+        repayWithSwap => repay_with_swap
+        deleverage => deleverage
+        batchAddCollateral => batch_add_collateral
         pause => pause_endpoint
         unpause => unpause_endpoint
         isPaused => paused_status
@@ -1,5 +1,5 @@
 use common_constants::RAY_PRECISION;
-use common_structs::MarketParams;
+use common_structs::{MarketParams, MarketRateSettings};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -28,6 +28,8 @@ where
     pub timestamp: TimestampMillis,
     /// The configuration parameters of the pool (e.g., interest rate slopes).
     pub parameters: MarketParams<C::Api>,
+    /// The rate settings kept outside of the parameters (e.g., the rate model type).
+    pub rate_settings: MarketRateSettings<C::Api>,
     /// The borrow index tracking compounded interest for borrowers.
     pub borrow_index_ray: ManagedDecimal<C::Api, NumDecimals>,
     /// The supply index tracking accrued rewards for suppliers.
@@ -36,6 +38,8 @@ where
     pub zero: ManagedDecimal<C::Api, NumDecimals>,
     /// The timestamp of the last state update (milliseconds since Unix epoch).
     pub last_timestamp: TimestampMillis,
+    /// The rounding remainder retained by the pool during this call, not yet committed (RAY-based).
+    pub rounding_ray: ManagedDecimal<C::Api, NumDecimals>,
 }
 
 impl<'a, C> Cache<'a, C>
@@ -65,9 +69,11 @@ where
             revenue_ray: sc_ref.revenue().get(),
             timestamp,
             parameters,
+            rate_settings: sc_ref.rate_settings(),
             borrow_index_ray: sc_ref.borrow_index().get(),
             supply_index_ray: sc_ref.supply_index().get(),
             last_timestamp: sc_ref.last_timestamp().get(),
+            rounding_ray: sc_ref.ray_zero(),
             sc_ref,
         }
     }
@@ -83,7 +89,8 @@ where
     ///
     /// **Goal**: Maintain consistency between in-memory cache and blockchain storage.
     ///
-    /// **Fields Updated**: `supplied`, `borrowed`, `revenue`, `borrow_index`, `supply_index`, `last_timestamp`.
+    /// **Fields Updated**: `supplied`, `borrowed`, `revenue`, `borrow_index`, `supply_index`, `last_timestamp`,
+    /// and `accumulated_rounding` when the call retained a rounding remainder.
     ///
     /// **Security Tip**: Assumes setters (`set()`) handle serialization correctly; no validation here.
     fn drop(&mut self) {
@@ -94,6 +101,11 @@ where
         self.sc_ref.borrow_index().set(&self.borrow_index_ray);
         self.sc_ref.supply_index().set(&self.supply_index_ray);
         self.sc_ref.last_timestamp().set(self.last_timestamp);
+        if self.rounding_ray.into_raw_units() > &BigUint::zero() {
+            self.sc_ref
+                .accumulated_rounding()
+                .update(|total| *total += self.rounding_ray.into_raw_units());
+        }
     }
 }
 
@@ -196,6 +208,21 @@ where
         self.parameters.asset_id == *asset
     }
 
+    /// Records the rounding remainder retained by the pool for a single operation.
+    /// `retained_ray` is the value the pool keeps (received or owed to it) and
+    /// `released_ray` the value it gives up (credited or sent); only a positive
+    /// difference, i.e. rounding in the pool's favor, is accumulated.
+    /// The total is committed to storage once, when the cache is dropped.
+    pub fn accumulate_rounding(
+        &mut self,
+        retained_ray: ManagedDecimal<C::Api, NumDecimals>,
+        released_ray: ManagedDecimal<C::Api, NumDecimals>,
+    ) {
+        if retained_ray > released_ray {
+            self.rounding_ray += retained_ray - released_ray;
+        }
+    }
+
     pub fn calculate_scaled_supply(
         &self,
         amount: &ManagedDecimal<C::Api, NumDecimals>,
@@ -4,8 +4,10 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 use cache::Cache;
+use common_constants::MAX_MIN_ACCRUAL_INTERVAL_MS;
 use common_errors::{
-    ERROR_INVALID_BORROW_RATE_PARAMS, ERROR_INVALID_RESERVE_FACTOR,
+    ERROR_INVALID_BORROW_RATE_PARAMS, ERROR_INVALID_MAX_ACCRUAL_RATE,
+    ERROR_INVALID_MIN_ACCRUAL_INTERVAL, ERROR_INVALID_RESERVE_FACTOR,
     ERROR_INVALID_UTILIZATION_RANGE, ERROR_OPTIMAL_UTILIZATION_TOO_HIGH,
 };
 pub mod cache;
@@ -26,10 +28,17 @@ pub trait LiquidityPool:
     + common_math::SharedMathModule
     + view::ViewModule
 {
-    /// Handles contract upgrade with empty implementation.
-    /// Allows code updates without state migration requirements.
+    /// Handles contract upgrade without state migration.
+    /// Resumes interest accrual paused by the controller for the upgrade,
+    /// without accruing interest for the time spent paused.
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        if self.accrual_paused().get() {
+            let timestamp_ms = self.blockchain().get_block_timestamp_millis();
+            self.last_timestamp().set(timestamp_ms);
+            self.accrual_paused().clear();
+        }
+    }
     /// Initializes a new liquidity pool with asset configuration and interest rate parameters.
     /// Sets up initial indexes (RAY), validates rate parameters, and records pool asset details.
     /// All supplied/borrowed/revenue amounts start at zero.
@@ -112,6 +121,7 @@ pub trait LiquidityPool:
         self.global_sync(&mut cache);
         self.emit_market_update(&cache, &asset_price);
 
+        let max_accrual_rate_ray = self.to_decimal_ray(self.max_accrual_rate().get());
         self.parameters().update(|parameters| {
             self.market_params_event(
                 &parameters.asset_id,
@@ -136,6 +146,12 @@ pub trait LiquidityPool:
                 parameters.max_borrow_rate_ray > parameters.base_borrow_rate_ray,
                 ERROR_INVALID_BORROW_RATE_PARAMS
             );
+            require!(
+                max_accrual_rate_ray == self.ray_zero()
+                    || (max_accrual_rate_ray > parameters.base_borrow_rate_ray
+                        && max_accrual_rate_ray <= parameters.max_borrow_rate_ray),
+                ERROR_INVALID_MAX_ACCRUAL_RATE
+            );
             require!(
                 parameters.optimal_utilization_ray > parameters.mid_utilization_ray,
                 ERROR_INVALID_UTILIZATION_RANGE
@@ -150,4 +166,122 @@ pub trait LiquidityPool:
             );
         });
     }
+
+    /// Switches the borrow rate curve used by the pool.
+    /// Accrues interest under the previous model before the new one applies.
+    /// Only callable by owner.
+    #[only_owner]
+    #[endpoint(setRateModelType)]
+    fn set_rate_model_type(
+        &self,
+        rate_model_type: RateModelType,
+        asset_price: ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        let mut cache = Cache::new(self);
+        self.global_sync(&mut cache);
+        self.emit_market_update(&cache, &asset_price);
+
+        self.rate_model_type().set(rate_model_type);
+    }
+
+    /// Sets the reserve factor applied while utilization is above the optimal utilization.
+    /// Accrues interest under the previous reserve factor before the new one applies.
+    /// Only callable by owner.
+    #[only_owner]
+    #[endpoint(setReserveFactorHigh)]
+    fn set_reserve_factor_high(
+        &self,
+        reserve_factor_high: BigUint,
+        asset_price: ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        let mut cache = Cache::new(self);
+        self.global_sync(&mut cache);
+        self.emit_market_update(&cache, &asset_price);
+
+        require!(
+            self.to_decimal_bps(reserve_factor_high.clone()) < self.bps(),
+            ERROR_INVALID_RESERVE_FACTOR
+        );
+
+        self.reserve_factor_high().set(reserve_factor_high);
+    }
+
+    /// Sets the absolute annual borrow rate ceiling enforced at accrual.
+    /// Accrues interest under the previous ceiling before the new one applies.
+    /// Only callable by owner.
+    #[only_owner]
+    #[endpoint(setMaxAccrualRate)]
+    fn set_max_accrual_rate(
+        &self,
+        max_accrual_rate: BigUint,
+        asset_price: ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        let mut cache = Cache::new(self);
+        self.global_sync(&mut cache);
+        self.emit_market_update(&cache, &asset_price);
+
+        let max_accrual_rate_ray = self.to_decimal_ray(max_accrual_rate.clone());
+        // Zero disables the ceiling, otherwise it must bind within the curve range
+        require!(
+            max_accrual_rate_ray == self.ray_zero()
+                || (max_accrual_rate_ray > cache.parameters.base_borrow_rate_ray
+                    && max_accrual_rate_ray <= cache.parameters.max_borrow_rate_ray),
+            ERROR_INVALID_MAX_ACCRUAL_RATE
+        );
+
+        self.max_accrual_rate().set(max_accrual_rate);
+    }
+
+    /// Sets the minimum time between two index recomputations.
+    /// Syncs within the interval serve the last indexes; the skipped time accrues on the next real sync.
+    /// Only callable by owner.
+    #[only_owner]
+    #[endpoint(setMinAccrualInterval)]
+    fn set_min_accrual_interval(
+        &self,
+        min_accrual_interval_ms: DurationMillis,
+        asset_price: ManagedDecimal<Self::Api, NumDecimals>,
+    ) {
+        require!(
+            min_accrual_interval_ms <= DurationMillis::new(MAX_MIN_ACCRUAL_INTERVAL_MS),
+            ERROR_INVALID_MIN_ACCRUAL_INTERVAL
+        );
+
+        let mut cache = Cache::new(self);
+        self.global_sync(&mut cache);
+        self.emit_market_update(&cache, &asset_price);
+
+        self.min_accrual_interval().set(min_accrual_interval_ms);
+    }
+
+    /// Pauses or resumes interest accrual.
+    /// Accrues interest up to now before pausing; resuming never accrues the paused window.
+    /// Takes no asset price, so a stale oracle never blocks pausing a market.
+    /// Only callable by owner.
+    #[only_owner]
+    #[endpoint(setAccrualPaused)]
+    fn set_accrual_paused(&self, paused: bool) {
+        let mut cache = Cache::new(self);
+        self.global_sync(&mut cache);
+        drop(cache);
+
+        self.accrual_paused().set(paused);
+    }
+
+    /// Sets the amount of the first deposit burned to seed an empty pool.
+    /// Protects later suppliers from first-depositor supply index inflation.
+    /// Only callable by owner.
+    #[only_owner]
+    #[endpoint(setMinInitialSupply)]
+    fn set_min_initial_supply(&self, min_initial_supply: BigUint) {
+        self.min_initial_supply().set(min_initial_supply);
+    }
+
+    /// Chooses whether protocol revenue covers bad debt before it is socialized.
+    /// Only callable by owner.
+    #[only_owner]
+    #[endpoint(setRevenueAbsorbsBadDebt)]
+    fn set_revenue_absorbs_bad_debt(&self, enabled: bool) {
+        self.revenue_absorbs_bad_debt().set(enabled);
+    }
 }
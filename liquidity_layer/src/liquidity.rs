@@ -87,6 +87,9 @@ new_supply_index = old_supply_index * (1 - loss_ratio)
 supplier_loss = supplier_scaled_tokens * old_supply_index * loss_ratio
 ```
 
+Markets configured with `setRevenueAbsorbsBadDebt` first burn protocol revenue to cover
+the loss; only the uncovered remainder reduces the supply index.
+
 ## Security Architecture
 
 ### 🔒 **Access Control**
@@ -149,8 +152,8 @@ repay(caller, position, price) -> Reduced debt + overpayment refund
 ### 🔄 **For Arbitrageurs**
 ```rust
 // Flash loan for atomic strategies
-flash_loan(token, amount, target, endpoint, args, fees, price)
-// Must repay loan + fees in same transaction
+flash_loan(token, amount, target, endpoint, args, fees, protocol_share, price)
+// Must repay loan + fees in same transaction, protocol share goes to the accumulator
 ```
 
 ### 📊 **For Integrators**
@@ -171,8 +174,8 @@ multiversx_sc::derive_imports!();
 
 pub use common_constants::{BPS_PRECISION, RAY_PRECISION, WAD_PRECISION};
 use common_errors::{
-    ERROR_FLASHLOAN_RESERVE_ASSET, ERROR_INSUFFICIENT_LIQUIDITY, ERROR_INVALID_ASSET,
-    ERROR_STRATEGY_FEE_EXCEEDS_AMOUNT,
+    ERROR_FLASHLOAN_RESERVE_ASSET, ERROR_INITIAL_SUPPLY_TOO_LOW, ERROR_INSUFFICIENT_LIQUIDITY,
+    ERROR_INVALID_ASSET, ERROR_STRATEGY_FEE_EXCEEDS_AMOUNT,
 };
 use common_structs::*;
 
@@ -210,6 +213,7 @@ pub trait LiquidityModule:
 
     /// Processes asset deposit, adding to reserves and updating supplier position.
     /// Validates payment asset and converts amount to scaled tokens.
+    /// The first deposit into an empty pool burns the minimum initial supply.
     /// Returns updated position with accrued interest.
     #[payable]
     #[only_owner]
@@ -227,7 +231,12 @@ pub trait LiquidityModule:
         self.global_sync(&mut cache);
 
         let scaled_amount = cache.calculate_scaled_supply(&amount);
-        position.scaled_amount_ray += &scaled_amount;
+        cache.accumulate_rounding(
+            self.rescale_half_up(&amount, RAY_PRECISION),
+            cache.calculate_original_supply_ray(&scaled_amount),
+        );
+        let burned_scaled = self.initial_supply_seed(&cache, &amount);
+        position.scaled_amount_ray += &(scaled_amount.clone() - burned_scaled);
         cache.supplied_ray += scaled_amount;
 
         self.emit_market_update(&cache, price);
@@ -255,6 +264,10 @@ pub trait LiquidityModule:
         require!(cache.has_reserves(amount), ERROR_INSUFFICIENT_LIQUIDITY);
 
         let scaled_amount = cache.calculate_scaled_borrow(amount);
+        cache.accumulate_rounding(
+            cache.calculate_original_borrow_ray(&scaled_amount),
+            self.rescale_half_up(amount, RAY_PRECISION),
+        );
         position.scaled_amount_ray += &scaled_amount;
 
         cache.borrowed_ray += scaled_amount;
@@ -293,6 +306,10 @@ pub trait LiquidityModule:
                 &position.scaled_amount_ray,
                 &amount, // `amount` is the requested_amount_actual
             );
+        cache.accumulate_rounding(
+            cache.calculate_original_supply_ray(&scaled_withdrawal_amount_gross),
+            self.rescale_half_up(&amount_to_transfer_net, RAY_PRECISION),
+        );
 
         self.process_liquidation_fee_details(
             &mut cache, // Pass cache as mutable
@@ -340,6 +357,13 @@ pub trait LiquidityModule:
         // 3. Determine scaled repayment amount and any overpayment
         let (amount_to_repay_scaled, over_paid_amount) =
             self.calculate_repayment_details(&cache, &position.scaled_amount_ray, &payment_amount);
+        cache.accumulate_rounding(
+            self.rescale_half_up(
+                &(payment_amount.clone() - over_paid_amount.clone()),
+                RAY_PRECISION,
+            ),
+            cache.calculate_original_borrow_ray(&amount_to_repay_scaled),
+        );
 
         // 5. Subtract the determined scaled repayment amount from the position's scaled amount
 
@@ -355,6 +379,67 @@ pub trait LiquidityModule:
         position
     }
 
+    /// Nets a deposit against a borrow of the same account without moving tokens.
+    /// Burns the scaled supply and scaled debt of the netted amount, capped at the
+    /// outstanding debt, so reserves are unchanged while both totals decrease.
+    /// Returns the updated deposit and borrow positions.
+    #[only_owner]
+    #[endpoint(netPositions)]
+    fn net_positions(
+        &self,
+        amount: ManagedDecimal<Self::Api, NumDecimals>,
+        mut deposit_position: AccountPosition<Self::Api>,
+        mut borrow_position: AccountPosition<Self::Api>,
+        price: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> MultiValue2<AccountPosition<Self::Api>, AccountPosition<Self::Api>> {
+        let mut cache = Cache::new(self);
+
+        self.global_sync(&mut cache);
+
+        require!(
+            cache.is_same_asset(&deposit_position.asset_id),
+            ERROR_INVALID_ASSET
+        );
+        require!(
+            cache.is_same_asset(&borrow_position.asset_id),
+            ERROR_INVALID_ASSET
+        );
+
+        let current_debt = cache.calculate_original_borrow(&borrow_position.scaled_amount_ray);
+        let requested_amount = self.min(amount, current_debt);
+
+        // 1. Burn the supply side, capped at the deposit value
+        let (scaled_withdrawal_amount, netted_amount) = self.calculate_gross_withdrawal_amounts(
+            &cache,
+            &deposit_position.scaled_amount_ray,
+            &requested_amount,
+        );
+        cache.accumulate_rounding(
+            cache.calculate_original_supply_ray(&scaled_withdrawal_amount),
+            self.rescale_half_up(&netted_amount, RAY_PRECISION),
+        );
+
+        // 2. Burn the debt side with the amount released from the deposit
+        let (scaled_repayment_amount, _) = self.calculate_repayment_details(
+            &cache,
+            &borrow_position.scaled_amount_ray,
+            &netted_amount,
+        );
+        cache.accumulate_rounding(
+            self.rescale_half_up(&netted_amount, RAY_PRECISION),
+            cache.calculate_original_borrow_ray(&scaled_repayment_amount),
+        );
+
+        cache.supplied_ray -= &scaled_withdrawal_amount;
+        deposit_position.scaled_amount_ray -= &scaled_withdrawal_amount;
+        cache.borrowed_ray -= &scaled_repayment_amount;
+        borrow_position.scaled_amount_ray -= &scaled_repayment_amount;
+
+        self.emit_market_update(&cache, price);
+
+        (deposit_position, borrow_position).into()
+    }
+
     /// Adds rewards to the pool.
     #[payable]
     #[only_owner]
@@ -378,6 +463,7 @@ pub trait LiquidityModule:
     /// Provides atomic flash loan with fee collection.
     /// Transfers amount to target contract, validates repayment, adds protocol revenue.
     /// Must be repaid with fees in same transaction.
+    /// Sends `protocol_share` of the collected fee back to the controller for the accumulator.
     #[only_owner]
     #[endpoint(flashLoan)]
     fn flash_loan(
@@ -388,8 +474,9 @@ pub trait LiquidityModule:
         endpoint: ManagedBuffer<Self::Api>,
         arguments: ManagedArgBuffer<Self::Api>,
         fees: &ManagedDecimal<Self::Api, NumDecimals>,
+        protocol_share: &ManagedDecimal<Self::Api, NumDecimals>,
         price: &ManagedDecimal<Self::Api, NumDecimals>,
-    ) {
+    ) -> EgldOrEsdtTokenPayment<Self::Api> {
         let mut cache = Cache::new(self);
         self.global_sync(&mut cache);
 
@@ -420,10 +507,21 @@ pub trait LiquidityModule:
             self.validate_flash_repayment(&last_cache, &back_transfers, &required_repayment);
 
         let protocol_fee = repayment - amount.clone();
+        let accumulator_fee = self.rescale_half_up(
+            &self.mul_half_up(&protocol_fee, protocol_share, RAY_PRECISION),
+            last_cache.parameters.asset_decimals,
+        );
+        let pool_fee = protocol_fee - accumulator_fee.clone();
 
-        self.internal_add_protocol_revenue(&mut last_cache, protocol_fee);
+        self.internal_add_protocol_revenue(&mut last_cache, pool_fee.clone());
+        self.flash_loan_fee_split_event(&asset, &pool_fee, &accumulator_fee);
+
+        let controller = self.blockchain().get_caller();
+        let payment = self.send_asset(&last_cache, &accumulator_fee, &controller);
 
         self.emit_market_update(&last_cache, price);
+
+        payment
     }
 
     /// Creates leveraged position by borrowing with upfront fee deduction.
@@ -460,6 +558,10 @@ pub trait LiquidityModule:
 
         // Only add the borrowed amount to debt (not the fee)
         let scaled_amount_to_add = cache.calculate_scaled_borrow(strategy_amount);
+        cache.accumulate_rounding(
+            cache.calculate_original_borrow_ray(&scaled_amount_to_add),
+            self.rescale_half_up(strategy_amount, RAY_PRECISION),
+        );
 
         position.scaled_amount_ray += &scaled_amount_to_add;
 
@@ -493,9 +595,15 @@ pub trait LiquidityModule:
 
         match position.position_type {
             AccountPositionType::Borrow => {
-                let current_debt_actual =
+                let mut current_debt_actual =
                     cache.calculate_original_borrow_ray(&position.scaled_amount_ray);
 
+                // Burn protocol revenue first when the market is configured to do so
+                if self.revenue_absorbs_bad_debt().get() {
+                    current_debt_actual =
+                        self.absorb_bad_debt_with_revenue(&mut cache, current_debt_actual);
+                }
+
                 // Apply immediate supply index reduction for bad debt socialization
                 self.apply_bad_debt_to_supply_index(&mut cache, current_debt_actual);
 
@@ -1,7 +1,8 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use common_structs::MarketParams;
+use common_constants::{BPS_PRECISION, RAY_PRECISION};
+use common_structs::{MarketParams, MarketRateSettings, RateModelType};
 
 /// The Storage trait provides on-chain storage mappers and view functions
 /// for accessing the core state variables of the liquidity pool.
@@ -73,4 +74,108 @@ pub trait Storage {
     #[view(getLastTimestamp)]
     #[storage_mapper("last_timestamp")]
     fn last_timestamp(&self) -> SingleValueMapper<TimestampMillis>;
+
+    /// Retrieves the cumulative rounding remainder retained by the pool.
+    ///
+    /// Every time a scaled conversion rounds in the pool's favor, the difference between
+    /// the value received (or owed) and the value credited (or sent) is added here.
+    ///
+    /// # Returns
+    /// - `BigUint`: The accumulated rounding remainder in asset units, RAY-scaled.
+    #[view(getAccumulatedRounding)]
+    #[storage_mapper("accumulated_rounding")]
+    fn accumulated_rounding(&self) -> SingleValueMapper<BigUint>;
+
+    /// Retrieves the minimum initial supply of the pool.
+    ///
+    /// When the pool holds no supply, this amount of the first deposit is burned
+    /// into the total supply without an owner, so the supply index can never be
+    /// inflated against a near-empty pool.
+    ///
+    /// # Returns
+    /// - `BigUint`: The minimum initial supply in asset units (0 disables seeding).
+    #[view(getMinInitialSupply)]
+    #[storage_mapper("min_initial_supply")]
+    fn min_initial_supply(&self) -> SingleValueMapper<BigUint>;
+
+    /// Retrieves whether interest accrual is paused.
+    ///
+    /// Set by the controller while the pool code is being upgraded. While paused,
+    /// syncs leave the indexes untouched and no interest accrues for the paused window.
+    ///
+    /// # Returns
+    /// - `bool`: `true` while accrual is paused.
+    #[view(isAccrualPaused)]
+    #[storage_mapper("accrual_paused")]
+    fn accrual_paused(&self) -> SingleValueMapper<bool>;
+
+    /// Retrieves whether protocol revenue absorbs bad debt before suppliers.
+    ///
+    /// When set, written-off debt first burns the protocol's revenue and only the
+    /// uncovered remainder is socialized across suppliers through the supply index.
+    ///
+    /// # Returns
+    /// - `bool`: `true` when revenue covers bad debt first.
+    #[view(isRevenueAbsorbingBadDebt)]
+    #[storage_mapper("revenue_absorbs_bad_debt")]
+    fn revenue_absorbs_bad_debt(&self) -> SingleValueMapper<bool>;
+
+    /// Retrieves the borrow rate curve applied to the market parameters.
+    ///
+    /// An empty mapper means the three-slope model.
+    ///
+    /// # Returns
+    /// - `RateModelType`: The rate model of the pool.
+    #[view(getRateModelType)]
+    #[storage_mapper("rate_model_type")]
+    fn rate_model_type(&self) -> SingleValueMapper<RateModelType>;
+
+    /// Retrieves the reserve factor applied while utilization is above the optimal utilization.
+    ///
+    /// Only applies when higher than the base reserve factor; an empty mapper keeps it flat.
+    ///
+    /// # Returns
+    /// - `BigUint`: The elevated reserve factor, BPS-scaled.
+    #[view(getReserveFactorHigh)]
+    #[storage_mapper("reserve_factor_high")]
+    fn reserve_factor_high(&self) -> SingleValueMapper<BigUint>;
+
+    /// Retrieves the absolute annual borrow rate ceiling enforced at accrual.
+    ///
+    /// Caps the rate curve, never above the max borrow rate; an empty mapper disables it.
+    ///
+    /// # Returns
+    /// - `BigUint`: The annual borrow rate ceiling, RAY-scaled.
+    #[view(getMaxAccrualRate)]
+    #[storage_mapper("max_accrual_rate")]
+    fn max_accrual_rate(&self) -> SingleValueMapper<BigUint>;
+
+    /// Retrieves the minimum time between two index recomputations.
+    ///
+    /// Syncs within the interval keep the last indexes; an empty mapper recomputes on every sync.
+    ///
+    /// # Returns
+    /// - `DurationMillis`: The minimum accrual interval in milliseconds.
+    #[view(getMinAccrualInterval)]
+    #[storage_mapper("min_accrual_interval")]
+    fn min_accrual_interval(&self) -> SingleValueMapper<DurationMillis>;
+
+    /// Assembles the rate settings kept outside of the market parameters.
+    ///
+    /// # Returns
+    /// - `MarketRateSettings<Self::Api>`: The rate settings of the pool.
+    fn rate_settings(&self) -> MarketRateSettings<Self::Api> {
+        MarketRateSettings {
+            rate_model_type: self.rate_model_type().get(),
+            reserve_factor_high_bps: ManagedDecimal::from_raw_units(
+                self.reserve_factor_high().get(),
+                BPS_PRECISION,
+            ),
+            max_accrual_rate_ray: ManagedDecimal::from_raw_units(
+                self.max_accrual_rate().get(),
+                RAY_PRECISION,
+            ),
+            min_accrual_interval_ms: self.min_accrual_interval().get(),
+        }
+    }
 }
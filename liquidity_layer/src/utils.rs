@@ -25,12 +25,26 @@ pub trait UtilsModule:
     /// Updates borrow and supply indexes based on time elapsed and current utilization.
     /// Distributes accrued interest between suppliers and protocol based on reserve factor.
     /// Synchronizes all pool state for accurate interest calculations.
+    /// Syncs within the market's minimum accrual interval keep the last indexes and leave
+    /// `last_timestamp` untouched, so the skipped time accrues on the next real sync.
     fn global_sync(&self, cache: &mut Cache<Self>) {
+        if self.accrual_paused().get() {
+            // Indexes stay frozen, the paused window never accrues interest
+            cache.last_timestamp = cache.timestamp;
+            return;
+        }
+
         let delta_ms = cache.timestamp - cache.last_timestamp;
 
-        if delta_ms > DurationMillis::zero() {
-            let borrow_rate =
-                self.calculate_borrow_rate(cache.calculate_utilization(), cache.parameters.clone());
+        if delta_ms > DurationMillis::zero()
+            && delta_ms >= cache.rate_settings.min_accrual_interval_ms
+        {
+            let utilization = cache.calculate_utilization();
+            let borrow_rate = self.calculate_borrow_rate(
+                utilization.clone(),
+                cache.parameters.clone(),
+                &cache.rate_settings,
+            );
             let borrow_factor = self.calculate_compounded_interest(borrow_rate.clone(), delta_ms);
             let (new_borrow_index, old_borrow_index) =
                 self.update_borrow_index(cache.borrow_index_ray.clone(), borrow_factor.clone());
@@ -38,6 +52,8 @@ pub trait UtilsModule:
             // Calculate supplier rewards and protocol fees directly
             let (supplier_rewards_ray, protocol_fee_ray) = self.calculate_supplier_rewards(
                 cache.parameters.clone(),
+                &cache.rate_settings,
+                &utilization,
                 &cache.borrowed_ray,
                 &new_borrow_index,
                 &old_borrow_index,
@@ -58,6 +74,32 @@ pub trait UtilsModule:
         }
     }
 
+    /// Covers bad debt by burning the protocol's scaled revenue.
+    /// Revenue is part of the total supply, so burning it removes the protocol's claim on reserves.
+    /// Returns the bad debt left uncovered, to be socialized across suppliers.
+    fn absorb_bad_debt_with_revenue(
+        &self,
+        cache: &mut Cache<Self>,
+        bad_debt_amount_ray: ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let revenue_value_ray = cache.calculate_original_supply_ray(&cache.revenue_ray);
+        if bad_debt_amount_ray >= revenue_value_ray {
+            let revenue_scaled = cache.revenue_ray.clone();
+            cache.supplied_ray -= &revenue_scaled;
+            cache.revenue_ray = self.ray_zero();
+            return bad_debt_amount_ray - revenue_value_ray;
+        }
+
+        let burned_scaled = self.min(
+            cache.calculate_scaled_supply(&bad_debt_amount_ray),
+            cache.revenue_ray.clone(),
+        );
+        cache.revenue_ray -= &burned_scaled;
+        cache.supplied_ray -= &burned_scaled;
+
+        self.ray_zero()
+    }
+
     /// Immediately socializes bad debt by reducing supply index proportionally.
     /// All suppliers share losses based on their scaled token holdings.
     /// Prevents supplier flight during insolvency events.
@@ -288,6 +330,26 @@ pub trait UtilsModule:
         }
     }
 
+    /// Computes the scaled share of a deposit burned to seed an empty pool.
+    /// The burned share stays in the total supply without an owner, so a first
+    /// depositor can never own the whole pool and inflate the supply index.
+    /// Returns zero once the pool holds supply or when seeding is disabled.
+    fn initial_supply_seed(
+        &self,
+        cache: &Cache<Self>,
+        amount: &ManagedDecimal<Self::Api, NumDecimals>,
+    ) -> ManagedDecimal<Self::Api, NumDecimals> {
+        let min_initial_supply = self.min_initial_supply().get();
+        if cache.supplied_ray != self.ray_zero() || min_initial_supply == 0 {
+            return self.ray_zero();
+        }
+
+        let seed = self.to_decimal(min_initial_supply, cache.parameters.asset_decimals);
+        require!(amount > &seed, ERROR_INITIAL_SUPPLY_TOO_LOW);
+
+        cache.calculate_scaled_supply(&seed)
+    }
+
     /// Converts revenue to scaled supply tokens and adds to protocol treasury.
     /// Mints treasury shares that appreciate with supply index.
     /// Revenue sources include fees, spreads, and liquidation penalties.
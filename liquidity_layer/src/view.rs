@@ -51,18 +51,18 @@ pub trait ViewModule:
     }
 
     /// Returns current annual percentage yield for suppliers.
-    /// Calculated as: borrow_rate * utilization * (1 - reserve_factor).
+    /// Calculated as: borrow_rate * utilization * (1 - reserve_factor), using the elevated
+    /// reserve factor above the optimal utilization.
     /// Higher utilization and borrow rates increase deposit yields.
     #[view(depositRate)]
     fn deposit_rate(&self) -> ManagedDecimal<Self::Api, NumDecimals> {
         let parameters = self.parameters().get();
+        let rate_settings = self.rate_settings();
         let utilization = self.capital_utilisation();
-        let borrow_rate = self.calculate_borrow_rate(utilization.clone(), parameters.clone());
-        self.calculate_deposit_rate(
-            utilization,
-            borrow_rate,
-            parameters.reserve_factor_bps.clone(),
-        )
+        let borrow_rate =
+            self.calculate_borrow_rate(utilization.clone(), parameters.clone(), &rate_settings);
+        let reserve_factor = self.reserve_factor_at(&utilization, &parameters, &rate_settings);
+        self.calculate_deposit_rate(utilization, borrow_rate, reserve_factor)
     }
 
     /// Returns current annual percentage rate for borrowers.
@@ -72,7 +72,29 @@ pub trait ViewModule:
     fn borrow_rate(&self) -> ManagedDecimal<Self::Api, NumDecimals> {
         let parameters = self.parameters().get();
         let utilization = self.capital_utilisation();
-        self.calculate_borrow_rate(utilization, parameters)
+        self.calculate_borrow_rate(utilization, parameters, &self.rate_settings())
+    }
+
+    /// Returns the borrow and deposit rates the pool would apply at a given utilization.
+    /// Runs the market's rate model on an arbitrary utilization without touching pool state,
+    /// letting front-ends sample the full rate curve and integrators check new parameters.
+    /// Rates are per millisecond, matching `borrowRate` and `depositRate`.
+    #[view(getRatesAtUtilization)]
+    fn get_rates_at_utilization(
+        &self,
+        utilization_ray: BigUint,
+    ) -> MultiValue2<ManagedDecimal<Self::Api, NumDecimals>, ManagedDecimal<Self::Api, NumDecimals>>
+    {
+        let parameters = self.parameters().get();
+        let rate_settings = self.rate_settings();
+        let utilization = self.to_decimal(utilization_ray, RAY_PRECISION);
+        let borrow_rate =
+            self.calculate_borrow_rate(utilization.clone(), parameters.clone(), &rate_settings);
+        let reserve_factor = self.reserve_factor_at(&utilization, &parameters, &rate_settings);
+        let supply_rate =
+            self.calculate_deposit_rate(utilization, borrow_rate.clone(), reserve_factor);
+
+        (borrow_rate, supply_rate).into()
     }
 
     /// Returns milliseconds elapsed since last pool synchronization.
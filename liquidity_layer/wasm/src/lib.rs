@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           26
+// Endpoints:                           43
 // Async Callback (empty):               1
-// Total number of exported functions:  29
+// Total number of exported functions:  46
 
 #![no_std]
 
@@ -21,6 +21,13 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         updateParams => update_params
+        setRateModelType => set_rate_model_type
+        setReserveFactorHigh => set_reserve_factor_high
+        setMaxAccrualRate => set_max_accrual_rate
+        setMinAccrualInterval => set_min_accrual_interval
+        setAccrualPaused => set_accrual_paused
+        setMinInitialSupply => set_min_initial_supply
+        setRevenueAbsorbsBadDebt => set_revenue_absorbs_bad_debt
         getSuppliedScaled => supplied
         getRevenueScaled => revenue
         getBorrowedScaled => borrowed
@@ -28,11 +35,20 @@ multiversx_sc_wasm_adapter::endpoints! {
         getBorrowIndex => borrow_index
         getSupplyIndex => supply_index
         getLastTimestamp => last_timestamp
+        getAccumulatedRounding => accumulated_rounding
+        getMinInitialSupply => min_initial_supply
+        isAccrualPaused => accrual_paused
+        isRevenueAbsorbingBadDebt => revenue_absorbs_bad_debt
+        getRateModelType => rate_model_type
+        getReserveFactorHigh => reserve_factor_high
+        getMaxAccrualRate => max_accrual_rate
+        getMinAccrualInterval => min_accrual_interval
         updateIndexes => update_indexes
         supply => supply
         borrow => borrow
         withdraw => withdraw
         repay => repay
+        netPositions => net_positions
         addRewards => add_reward
         flashLoan => flash_loan
         createStrategy => create_strategy
@@ -42,6 +58,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         reserves => reserves
         depositRate => deposit_rate
         borrowRate => borrow_rate
+        getRatesAtUtilization => get_rates_at_utilization
         deltaTime => delta_time
         protocolRevenue => protocol_revenue
         suppliedAmount => supplied_amount
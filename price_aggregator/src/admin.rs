@@ -1,6 +1,9 @@
 use crate::{
-    constants::{SUBMISSION_LIST_MAX_LEN, SUBMISSION_LIST_MIN_LEN},
-    errors::{SUBMISSION_LIST_CAPACITY_EXCEEDED_ERROR, SUBMISSION_LIST_MIN_LEN_ERROR},
+    constants::{BPS, MAX_ORACLE_WEIGHT, SUBMISSION_LIST_MAX_LEN, SUBMISSION_LIST_MIN_LEN},
+    errors::{
+        INVALID_MAX_DEVIATION_ERROR, INVALID_ORACLE_WEIGHT_ERROR, ORACLE_NOT_FOUND_ERROR,
+        SUBMISSION_LIST_CAPACITY_EXCEEDED_ERROR, SUBMISSION_LIST_MIN_LEN_ERROR,
+    },
     structs::OracleStatus,
 };
 
@@ -62,6 +65,7 @@ pub trait AdminModule:
         let mut oracle_mapper = self.oracle_status();
         for oracle in oracles {
             let _ = oracle_mapper.remove(&oracle);
+            self.oracle_weight(&oracle).clear();
         }
 
         self.set_submission_count(submission_count);
@@ -89,4 +93,30 @@ pub trait AdminModule:
         );
         self.submission_count().set(submission_count);
     }
+
+    /// Sets the maximum deviation (bps) of a submission from the provisional weighted median.
+    /// Outlying submissions are excluded before the weighted round median is recomputed.
+    /// Zero disables outlier rejection.
+    #[only_owner]
+    #[endpoint(setMaxDeviationBps)]
+    fn set_max_deviation_bps(&self, max_deviation_bps: u64) {
+        require!(max_deviation_bps <= BPS, INVALID_MAX_DEVIATION_ERROR);
+        self.max_deviation_bps().set(max_deviation_bps);
+    }
+
+    /// Sets the weight of a registered oracle's submissions in the round median.
+    /// Validates the weight lies within (0, `MAX_ORACLE_WEIGHT`].
+    #[only_owner]
+    #[endpoint(setOracleWeight)]
+    fn set_oracle_weight(&self, oracle: ManagedAddress, weight: u64) {
+        require!(
+            self.oracle_status().contains_key(&oracle),
+            ORACLE_NOT_FOUND_ERROR
+        );
+        require!(
+            weight > 0 && weight <= MAX_ORACLE_WEIGHT,
+            INVALID_ORACLE_WEIGHT_ERROR
+        );
+        self.oracle_weight(&oracle).set(weight);
+    }
 }
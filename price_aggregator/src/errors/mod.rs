@@ -12,3 +12,6 @@ pub const MEDIAN_2_INVALID_INDEX: &[u8] = b"Median2 invalid index";
 pub const MEDIAN_INVALID_INDEX: &[u8] = b"Median invalid index";
 pub const TOKEN_PAIR_NOT_FOUND_ERROR: &[u8] = b"Token pair not found";
 pub const SUBMISSION_LIST_MIN_LEN_ERROR: &[u8] = b"Submission list min length error";
+pub const INVALID_MAX_DEVIATION_ERROR: &[u8] = b"Invalid max deviation";
+pub const ORACLE_NOT_FOUND_ERROR: &[u8] = b"Oracle not found";
+pub const INVALID_ORACLE_WEIGHT_ERROR: &[u8] = b"Invalid oracle weight";
@@ -1,6 +1,9 @@
 use multiversx_sc::imports::*;
 
-use crate::errors::*;
+use crate::{
+    constants::{BPS, SUBMISSION_LIST_MAX_LEN},
+    errors::*,
+};
 
 /// Returns the sorted middle, or the average of the two middle indexed items if the
 /// vector has an even number of elements.
@@ -22,3 +25,63 @@ pub fn calculate<M: ManagedTypeApi>(
         Result::Ok(Some(median.clone()))
     }
 }
+
+/// Returns the weighted median of `(item, weight)` pairs: the first sorted item at which
+/// the cumulative weight reaches half of the total weight, or the average of it and the
+/// next item if the weight splits exactly in half. Equal weights give the same result as
+/// `calculate`.
+pub fn calculate_weighted<M: ManagedTypeApi>(
+    list: &mut [(BigUint<M>, u64)],
+) -> Result<Option<BigUint<M>>, StaticSCError> {
+    if list.is_empty() {
+        return Result::Ok(None);
+    }
+    list.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    let total_weight: u64 = list.iter().map(|(_, weight)| weight).sum();
+    let mut cumulative_weight = 0u64;
+    for (index, (item, weight)) in list.iter().enumerate() {
+        cumulative_weight += weight;
+        if cumulative_weight * 2 == total_weight {
+            let (next_item, _) = list.get(index + 1).ok_or(MEDIAN_2_INVALID_INDEX)?;
+            return Result::Ok(Some((item.clone() + next_item.clone()) / 2u64));
+        }
+        if cumulative_weight * 2 > total_weight {
+            return Result::Ok(Some(item.clone()));
+        }
+    }
+    Result::Err(MEDIAN_INVALID_INDEX.into())
+}
+
+/// Returns the weighted median of the items deviating at most `max_deviation_bps` from
+/// the provisional weighted median of all items. A zero deviation keeps every item.
+/// Falls back to the provisional median if no item is close enough to it.
+pub fn calculate_without_outliers<M: ManagedTypeApi>(
+    list: &mut [(BigUint<M>, u64)],
+    max_deviation_bps: u64,
+) -> Result<Option<BigUint<M>>, StaticSCError> {
+    let provisional_median = match calculate_weighted(list)? {
+        Some(median) => median,
+        None => return Result::Ok(None),
+    };
+    if max_deviation_bps == 0 {
+        return Result::Ok(Some(provisional_median));
+    }
+
+    let max_deviation = provisional_median.clone() * max_deviation_bps;
+    let mut kept = ArrayVec::<(BigUint<M>, u64), SUBMISSION_LIST_MAX_LEN>::new();
+    for (item, weight) in list.iter() {
+        let deviation = if *item > provisional_median {
+            item - &provisional_median
+        } else {
+            &provisional_median - item
+        };
+        if deviation * BPS <= max_deviation {
+            kept.push((item.clone(), *weight));
+        }
+    }
+
+    if kept.is_empty() {
+        return Result::Ok(Some(provisional_median));
+    }
+    calculate_weighted(kept.as_mut_slice())
+}
@@ -9,9 +9,21 @@ pub trait StorageModule {
     #[storage_mapper("submission_count")]
     fn submission_count(&self) -> SingleValueMapper<usize>;
 
+    /// Maximum deviation (bps) of a submission from the provisional weighted round median.
+    /// Submissions beyond it are left out of the round price; zero disables the filter.
+    #[view(getMaxDeviationBps)]
+    #[storage_mapper("max_deviation_bps")]
+    fn max_deviation_bps(&self) -> SingleValueMapper<u64>;
+
     #[storage_mapper("oracle_status")]
     fn oracle_status(&self) -> MapMapper<ManagedAddress, OracleStatus>;
 
+    /// Weight of an oracle's submissions in the weighted round median.
+    /// Oracles without a configured weight count with a weight of one.
+    #[view(getOracleWeight)]
+    #[storage_mapper("oracle_weight")]
+    fn oracle_weight(&self, oracle: &ManagedAddress) -> SingleValueMapper<u64>;
+
     #[storage_mapper("rounds")]
     fn rounds_new(
         &self,
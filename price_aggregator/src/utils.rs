@@ -130,7 +130,7 @@ pub trait UtilsModule:
     }
 
     /// Creates new price round when submission threshold is met.
-    /// Calculates median price, stores result, and clears submissions.
+    /// Calculates the oracle-weighted median price, stores result, and clears submissions.
     /// Emits round completion event for transparency.
     fn create_new_round(
         &self,
@@ -145,12 +145,15 @@ pub trait UtilsModule:
                 SUBMISSION_LIST_CAPACITY_EXCEEDED_ERROR
             );
 
-            let mut submissions_vec = ArrayVec::<BigUint, SUBMISSION_LIST_MAX_LEN>::new();
-            for submission_value in submissions.values() {
-                submissions_vec.push(submission_value);
+            let mut submissions_vec = ArrayVec::<(BigUint, u64), SUBMISSION_LIST_MAX_LEN>::new();
+            for (oracle, submission_value) in submissions.iter() {
+                submissions_vec.push((submission_value, self.oracle_submission_weight(&oracle)));
             }
 
-            let price_result = median::calculate(submissions_vec.as_mut_slice());
+            let price_result = median::calculate_without_outliers(
+                submissions_vec.as_mut_slice(),
+                self.max_deviation_bps().get(),
+            );
             let price_opt = price_result.unwrap_or_else(|err| sc_panic!(err.as_bytes()));
             let price = price_opt.unwrap_or_else(|| sc_panic!(NO_SUBMISSIONS_ERROR));
             let feed = TimestampedPrice {
@@ -168,6 +171,16 @@ pub trait UtilsModule:
         }
     }
 
+    /// Returns the weight of an oracle's submissions, one when none is configured.
+    fn oracle_submission_weight(&self, oracle: &ManagedAddress) -> u64 {
+        let weight_mapper = self.oracle_weight(oracle);
+        if weight_mapper.is_empty() {
+            1
+        } else {
+            weight_mapper.get()
+        }
+    }
+
     /// Clears all submissions and timestamps for a token pair.
     /// Used for cleanup after round completion or when discarding stale data.
     fn clear_submissions(&self, token_pair: &TokenPair<Self::Api>) {
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           12
+// Endpoints:                           16
 // Async Callback (empty):               1
-// Total number of exported functions:  15
+// Total number of exported functions:  19
 
 #![no_std]
 
@@ -26,12 +26,16 @@ multiversx_sc_wasm_adapter::endpoints! {
         unpause => unpause_endpoint
         isPaused => paused_status
         submission_count => submission_count
+        getMaxDeviationBps => max_deviation_bps
+        getOracleWeight => oracle_weight
         latestRoundData => latest_round_data
         latestPriceFeed => latest_price_feed
         getOracles => get_oracles
         addOracles => add_oracles
         removeOracles => remove_oracles
         setSubmissionCount => set_submission_count
+        setMaxDeviationBps => set_max_deviation_bps
+        setOracleWeight => set_oracle_weight
     )
 }
 
@@ -170,6 +170,32 @@ where
             .original_result()
     }
 
+    /// Maximum deviation (bps) of a submission from the provisional weighted round median. 
+    /// Submissions beyond it are left out of the round price; zero disables the filter. 
+    pub fn max_deviation_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxDeviationBps")
+            .original_result()
+    }
+
+    /// Weight of an oracle's submissions in the weighted round median. 
+    /// Oracles without a configured weight count with a weight of one. 
+    pub fn oracle_weight<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        oracle: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOracleWeight")
+            .argument(&oracle)
+            .original_result()
+    }
+
     /// Returns latest aggregated prices for multiple token pairs. 
     /// Skips pairs without available price data. 
     /// Enables batch price queries for efficiency. 
@@ -265,6 +291,40 @@ where
             .argument(&submission_count)
             .original_result()
     }
+
+    /// Sets the maximum deviation (bps) of a submission from the provisional weighted median. 
+    /// Outlying submissions are excluded before the weighted round median is recomputed. 
+    /// Zero disables outlier rejection. 
+    pub fn set_max_deviation_bps<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_deviation_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxDeviationBps")
+            .argument(&max_deviation_bps)
+            .original_result()
+    }
+
+    /// Sets the weight of a registered oracle's submissions in the round median. 
+    /// Validates the weight lies within (0, `MAX_ORACLE_WEIGHT`]. 
+    pub fn set_oracle_weight<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        oracle: Arg0,
+        weight: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setOracleWeight")
+            .argument(&oracle)
+            .argument(&weight)
+            .original_result()
+    }
 }
 
 #[type_abi]